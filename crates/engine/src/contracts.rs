@@ -0,0 +1,611 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    database::{
+        code_hash_of_key,
+        Database,
+    },
+    deposit::StorageDeposit,
+    ext::{
+        set_output,
+        Engine,
+        Error,
+        Result,
+    },
+    gas::per_item,
+    test_api::{
+        ContractTermination,
+        Snapshot,
+    },
+    trace::{
+        decode_balance,
+        EnvCall,
+    },
+    types::{
+        AccountId,
+        Balance,
+    },
+};
+use scale::Encode;
+use std::{
+    collections::HashMap,
+    panic::{
+        self,
+        AssertUnwindSafe,
+    },
+    sync::Once,
+    thread,
+};
+
+/// The entry points of a contract registered in the off-chain engine.
+///
+/// Just like the `deploy` and `call` exports of a Wasm contract, the entry points
+/// read their input via `seal_input` and return their output via `seal_return`.
+#[derive(Debug, Copy, Clone)]
+pub struct ContractCode {
+    /// Dispatches the constructor call in the input.
+    pub deploy: fn(),
+    /// Dispatches the message call in the input.
+    pub call: fn(),
+}
+
+/// The contract code registered in the off-chain engine by code hash.
+#[derive(Default)]
+pub struct ContractRegistry {
+    code: HashMap<Vec<u8>, ContractCode>,
+}
+
+impl ContractRegistry {
+    /// Creates a new contract registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `code` under `code_hash`, replacing any previously registered code.
+    pub fn register(&mut self, code_hash: Vec<u8>, code: ContractCode) {
+        self.code.insert(code_hash, code);
+    }
+
+    /// Returns the code registered under `code_hash`, if any.
+    pub fn get(&self, code_hash: &[u8]) -> Option<ContractCode> {
+        self.code.get(code_hash).copied()
+    }
+}
+
+/// The canned outputs of mocked contract messages by callee and selector.
+#[derive(Default)]
+pub struct MockedMessages {
+    outputs: HashMap<(Vec<u8>, [u8; 4]), Vec<u8>>,
+}
+
+impl MockedMessages {
+    /// Creates a new set of mocked messages.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mocks the message with `selector` of `callee` to return `output`, replacing
+    /// any previously mocked output.
+    pub fn mock(&mut self, callee: Vec<u8>, selector: [u8; 4], output: Vec<u8>) {
+        self.outputs.insert((callee, selector), output);
+    }
+
+    /// Returns the mocked output of the message of `callee` selected by `input`, if any.
+    pub fn get(&self, callee: &[u8], input: &[u8]) -> Option<&[u8]> {
+        let selector: [u8; 4] = input.get(..4)?.try_into().ok()?;
+        self.outputs
+            .get(&(callee.to_vec(), selector))
+            .map(Vec::as_slice)
+    }
+
+    /// Removes all mocked messages.
+    pub fn clear(&mut self) {
+        self.outputs.clear();
+    }
+}
+
+/// The resources consumed by a call or instantiation performed via [`Engine::dry_run`] or [`Engine::end_dry_run`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DryRunInfo {
+    /// The gas consumed by host function calls.
+    pub gas_consumed: u64,
+    /// The net storage deposit charged to or refunded to the origin.
+    pub storage_deposit: StorageDeposit,
+}
+
+/// A contract entry point whose call frame has been pushed by the engine.
+///
+/// The entry point is run via [`PendingExecution::run`], which does not access the
+/// engine, so that the contract is able to access the engine itself. Afterwards the
+/// execution is completed via [`Engine::finish_execution`].
+#[must_use]
+pub struct PendingExecution {
+    entry: fn(),
+    snapshot: (Database, StorageDeposit),
+    flags: u32,
+    instantiated: Option<Vec<u8>>,
+}
+
+impl PendingExecution {
+    /// Runs the entry point of the contract until it returns, traps or terminates.
+    pub fn run(self) -> FinishedExecution {
+        let outcome = panic::catch_unwind(AssertUnwindSafe(self.entry));
+        FinishedExecution {
+            pending: self,
+            outcome,
+        }
+    }
+}
+
+/// A contract entry point that has been run and awaits [`Engine::finish_execution`].
+#[must_use]
+pub struct FinishedExecution {
+    pending: PendingExecution,
+    outcome: thread::Result<()>,
+}
+
+/// The state of the engine from before a dry-run, see [`Engine::begin_dry_run`].
+#[must_use]
+pub struct DryRun {
+    snapshot: Snapshot,
+    instantiation_nonce: u64,
+    gas_consumed: u64,
+    storage_deposit: StorageDeposit,
+}
+
+/// The execution context of a caller that is suspended during a cross-contract call.
+pub struct CallFrame {
+    pub caller: Option<AccountId>,
+    pub callee: Option<AccountId>,
+    pub value_transferred: Balance,
+    pub input: Vec<u8>,
+}
+
+/// The payload of the panic with which the execution of a contract entry point
+/// is halted upon `seal_return`.
+#[derive(Debug)]
+pub struct ReturnValue {
+    /// The raw return flags.
+    pub flags: u32,
+    /// The SCALE encoded return value.
+    pub data: Vec<u8>,
+}
+
+impl ReturnValue {
+    /// The flag that indicates that the contract reverted its state.
+    const REVERT: u32 = 1;
+
+    /// Returns `true` if the contract reverted its state.
+    pub fn is_reverted(&self) -> bool {
+        self.flags & Self::REVERT != 0
+    }
+}
+
+/// The flags that make a call reuse the input of the current contract execution.
+const FORWARD_OR_CLONE_INPUT: u32 = 0b11;
+
+/// The flag that makes the caller return the output of the callee instead of resuming.
+const TAIL_CALL: u32 = 1 << 2;
+
+/// The flag that allows a call to re-enter a contract that is already on the call stack.
+const ALLOW_REENTRY: u32 = 1 << 3;
+
+/// Installs a panic hook that stays silent about [`ReturnValue`] and
+/// [`ContractTermination`] payloads.
+///
+/// Returning from a contract entry point and terminating a contract are implemented
+/// by unwinding and must not clutter the test output. All other panics are forwarded
+/// to the previous hook.
+pub(crate) fn silence_control_flow_panics() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let payload = info.payload();
+            if !payload.is::<ReturnValue>() && !payload.is::<ContractTermination>() {
+                previous(info)
+            }
+        }));
+    });
+}
+
+impl Engine {
+    /// Registers the entry points of a contract under `code_hash`.
+    pub fn register_contract(&mut self, code_hash: Vec<u8>, code: ContractCode) {
+        self.contracts.register(code_hash, code);
+    }
+
+    /// Mocks the message with `selector` of the contract at `callee`.
+    ///
+    /// Calls of the message return `output` instead of dispatching to the code of
+    /// `callee`, which therefore does not have to be a contract at all.
+    pub fn mock_message(&mut self, callee: Vec<u8>, selector: [u8; 4], output: Vec<u8>) {
+        self.mocked_messages.mock(callee, selector, output);
+    }
+
+    /// Returns the SCALE encoded input of the current contract execution in `output`.
+    pub fn input(&self, output: &mut &mut [u8]) {
+        self.gas_meter.charge(|_| 0);
+        set_output(output, &self.exec_context.input[..])
+    }
+
+    /// Halts the execution of the current contract entry point and returns `data`.
+    ///
+    /// # Panics
+    ///
+    /// If no contract entry point is executed, since there is nobody to return to.
+    pub fn return_value(&mut self, flags: u32, data: &[u8]) -> ! {
+        if self.call_stack.is_empty() {
+            panic!("cannot return a value outside of a contract call or instantiation")
+        }
+        silence_control_flow_panics();
+        panic::panic_any(ReturnValue {
+            flags,
+            data: data.to_vec(),
+        })
+    }
+
+    /// Returns the code hash of the contract at `account_id` in `output`.
+    pub fn code_hash(&self, account_id: &[u8], output: &mut [u8]) -> Result {
+        self.gas_meter.charge(|_| 0);
+        let code_hash = self
+            .database
+            .get_code_hash(account_id)
+            .ok_or(Error::KeyNotFound)?;
+        output[..code_hash.len()].copy_from_slice(code_hash);
+        Ok(())
+    }
+
+    /// Returns `true` if `account_id` is a contract.
+    pub fn is_contract(&self, account_id: &[u8]) -> bool {
+        self.gas_meter.charge(|_| 0);
+        self.database.get_code_hash(account_id).is_some()
+    }
+
+    /// Returns `true` if the caller of the current contract is the origin of the call stack.
+    pub fn caller_is_origin(&self) -> bool {
+        self.gas_meter.charge(|_| 0);
+        self.call_stack.is_empty()
+    }
+
+    /// Prepares the call of the contract at `callee` with the SCALE encoded `input`
+    /// and transfers `value` to it.
+    ///
+    /// If `flags` request to forward or clone the input, the input of the current
+    /// contract execution is used instead of `input`.
+    ///
+    /// Returns `None` if the called message is mocked, in which case its return value
+    /// is stored in `output`. Otherwise the returned [`PendingExecution`] has to be run
+    /// and finished via [`Engine::finish_execution`].
+    ///
+    /// # Panics
+    ///
+    /// If `callee` is already executed and `flags` do not allow reentry. Within a
+    /// contract execution this traps the calling contract.
+    pub fn prepare_call(
+        &mut self,
+        callee: &[u8],
+        flags: u32,
+        value: &[u8],
+        input: &[u8],
+        output: &mut &mut [u8],
+    ) -> core::result::Result<Option<PendingExecution>, Error> {
+        self.gas_meter.charge(|schedule| {
+            schedule.call + per_item(schedule.call_per_input_byte, input.len())
+        });
+        let input = if flags & FORWARD_OR_CLONE_INPUT != 0 {
+            self.exec_context.input.clone()
+        } else {
+            input.to_vec()
+        };
+        self.trace(|contract| {
+            EnvCall::Call {
+                contract,
+                callee: callee.to_vec(),
+                value: decode_balance(value),
+                input: input.clone(),
+            }
+        });
+        if let Some(mocked_output) = self.mocked_messages.get(callee, &input[..]) {
+            set_output(output, mocked_output);
+            return Ok(None)
+        }
+        let code_hash = self
+            .database
+            .get_code_hash(callee)
+            .ok_or(Error::NotCallable)?
+            .clone();
+        let code = self.contracts.get(&code_hash).ok_or(Error::CodeNotFound)?;
+        if flags & ALLOW_REENTRY == 0 && self.is_on_call_stack(callee) {
+            panic!("reentrant call into contract {:?} is denied", callee)
+        }
+        self.prepare_execution(code.call, callee.to_vec(), value, &input[..], flags)
+            .map(Some)
+    }
+
+    /// Prepares the call of the contract code at `code_hash` with the SCALE encoded
+    /// `input` in the context of the current contract.
+    ///
+    /// The called code operates on the storage and balance of the current contract
+    /// and observes its caller and transferred value. This allows to test proxy
+    /// contracts against the code registered via [`Engine::register_contract`].
+    ///
+    /// The `flags` are interpreted as for [`Engine::prepare_call`].
+    pub fn prepare_delegate_call(
+        &mut self,
+        flags: u32,
+        code_hash: &[u8],
+        input: &[u8],
+    ) -> core::result::Result<PendingExecution, Error> {
+        self.gas_meter.charge(|schedule| {
+            schedule.call + per_item(schedule.call_per_input_byte, input.len())
+        });
+        let code = self.contracts.get(code_hash).ok_or(Error::CodeNotFound)?;
+        let input = if flags & FORWARD_OR_CLONE_INPUT != 0 {
+            self.exec_context.input.clone()
+        } else {
+            input.to_vec()
+        };
+        self.trace(|contract| {
+            EnvCall::DelegateCall {
+                contract,
+                code_hash: code_hash.to_vec(),
+                input: input.clone(),
+            }
+        });
+        let snapshot = (self.database.clone(), self.storage_deposit);
+        self.call_stack.push(CallFrame {
+            caller: self.exec_context.caller.clone(),
+            callee: self.exec_context.callee.clone(),
+            value_transferred: self.exec_context.value_transferred,
+            input: core::mem::replace(&mut self.exec_context.input, input),
+        });
+        Ok(PendingExecution {
+            entry: code.call,
+            snapshot,
+            flags,
+            instantiated: None,
+        })
+    }
+
+    /// Prepares the instantiation of a contract from the code at `code_hash` with the
+    /// SCALE encoded `input` and transfers `endowment` to it.
+    ///
+    /// The address of the new contract is stored in `out_address`. The contract is
+    /// removed again if its constructor fails.
+    pub fn prepare_instantiate(
+        &mut self,
+        code_hash: &[u8],
+        endowment: &[u8],
+        input: &[u8],
+        out_address: &mut &mut [u8],
+        salt: &[u8],
+    ) -> core::result::Result<PendingExecution, Error> {
+        self.gas_meter.charge(|schedule| {
+            schedule.call + per_item(schedule.call_per_input_byte, input.len())
+        });
+        let code = self.contracts.get(code_hash).ok_or(Error::CodeNotFound)?;
+        let address = self.contract_address(code_hash, input, salt);
+        if self.database.get_code_hash(&address).is_some() {
+            panic!("a contract already exists at address {:?}", address)
+        }
+        self.trace(|contract| {
+            EnvCall::Instantiate {
+                contract,
+                code_hash: code_hash.to_vec(),
+                endowment: decode_balance(endowment),
+                input: input.to_vec(),
+                salt: salt.to_vec(),
+            }
+        });
+        self.database.set_code_hash(&address, code_hash);
+        self.exec_context.instantiation_nonce += 1;
+        set_output(out_address, &address[..]);
+        let pending =
+            self.prepare_execution(code.deploy, address.clone(), endowment, input, 0);
+        match pending {
+            Ok(pending) => {
+                Ok(PendingExecution {
+                    instantiated: Some(address),
+                    ..pending
+                })
+            }
+            Err(error) => {
+                self.database.remove(&code_hash_of_key(&address));
+                Err(error)
+            }
+        }
+    }
+
+    /// Completes the `execution` of a contract entry point and restores the
+    /// execution context of the caller.
+    ///
+    /// The state of the database and of the net storage deposit from before the
+    /// execution is restored if the execution trapped or reverted. The return value
+    /// of the contract is stored in `output`.
+    ///
+    /// Upon a tail call the caller returns the output of the callee instead of
+    /// resuming, unless the caller is not executed by the engine itself.
+    pub fn finish_execution(
+        &mut self,
+        execution: FinishedExecution,
+        output: &mut &mut [u8],
+    ) -> Result {
+        let FinishedExecution { pending, outcome } = execution;
+        let (result, data) = self.pop_call_frame(outcome, pending.snapshot);
+        if result.is_err() {
+            if let Some(address) = pending.instantiated {
+                self.database.remove(&code_hash_of_key(&address));
+            }
+        }
+        if pending.flags & TAIL_CALL != 0 && !self.call_stack.is_empty() {
+            let data = data.unwrap_or_default();
+            match result {
+                Ok(()) => self.return_value(0, &data[..]),
+                Err(Error::CalleeReverted) => {
+                    self.return_value(ReturnValue::REVERT, &data[..])
+                }
+                Err(error) => panic!("tail call failed: {:?}", error),
+            }
+        }
+        if let Some(data) = data {
+            set_output(output, &data[..]);
+        }
+        result
+    }
+
+    /// Performs `f` as a dry-run and reverts its effects afterwards.
+    ///
+    /// Returns the result of `f` together with the gas it consumed and the net
+    /// storage deposit it charged.
+    ///
+    /// Use [`Engine::begin_dry_run`] and [`Engine::end_dry_run`] if the dry-run
+    /// executes contract entry points.
+    pub fn dry_run<F, R>(&mut self, f: F) -> (R, DryRunInfo)
+    where
+        F: FnOnce(&mut Engine) -> R,
+    {
+        let dry_run = self.begin_dry_run();
+        let result = f(self);
+        (result, self.end_dry_run(dry_run))
+    }
+
+    /// Starts a dry-run whose effects are reverted by [`Engine::end_dry_run`].
+    pub fn begin_dry_run(&mut self) -> DryRun {
+        DryRun {
+            snapshot: self.snapshot(),
+            instantiation_nonce: self.exec_context.instantiation_nonce,
+            gas_consumed: self.gas_meter.consumed(),
+            storage_deposit: core::mem::take(&mut self.storage_deposit),
+        }
+    }
+
+    /// Ends the `dry_run` and returns the gas consumed and the net storage deposit
+    /// charged since it began.
+    ///
+    /// The contract storage, the balances, the emitted events and the instantiation
+    /// nonce are restored. The consumed gas stays charged just like for any other
+    /// host function call.
+    pub fn end_dry_run(&mut self, dry_run: DryRun) -> DryRunInfo {
+        let info = DryRunInfo {
+            gas_consumed: self.gas_meter.consumed() - dry_run.gas_consumed,
+            storage_deposit: core::mem::replace(
+                &mut self.storage_deposit,
+                dry_run.storage_deposit,
+            ),
+        };
+        self.restore(&dry_run.snapshot);
+        self.exec_context.instantiation_nonce = dry_run.instantiation_nonce;
+        info
+    }
+
+    /// Computes the address of a contract instantiated by the current contract.
+    ///
+    /// This mirrors the address derivation of `pallet-contracts`.
+    fn contract_address(&self, code_hash: &[u8], input: &[u8], salt: &[u8]) -> Vec<u8> {
+        let mut buffer = b"contract_addr_v1".to_vec();
+        buffer.extend_from_slice(&self.get_callee()[..]);
+        buffer.extend_from_slice(code_hash);
+        input.encode_to(&mut buffer);
+        salt.encode_to(&mut buffer);
+        let mut address = [0; 32];
+        Engine::hash_blake2_256(&buffer[..], &mut address);
+        address.to_vec()
+    }
+
+    /// Returns `true` if `account_id` is currently executed somewhere on the call stack.
+    fn is_on_call_stack(&self, account_id: &[u8]) -> bool {
+        let is_callee = |callee: &Option<AccountId>| {
+            callee
+                .as_ref()
+                .map(|callee| callee.as_bytes() == account_id)
+                .unwrap_or(false)
+        };
+        is_callee(&self.exec_context.callee)
+            || self.call_stack.iter().any(|frame| is_callee(&frame.callee))
+    }
+
+    /// Transfers `value` to `callee` and pushes the call frame for executing `entry`
+    /// as `callee`.
+    fn prepare_execution(
+        &mut self,
+        entry: fn(),
+        callee: Vec<u8>,
+        value: &[u8],
+        input: &[u8],
+        flags: u32,
+    ) -> core::result::Result<PendingExecution, Error> {
+        let snapshot = (self.database.clone(), self.storage_deposit);
+        let value_transferred = <Balance as scale::Decode>::decode(&mut &value[..])
+            .map_err(|_| Error::TransferFailed)?;
+        self.transfer_value(&callee[..], value)?;
+        let callee = AccountId::from_bytes(&callee[..]);
+        self.call_stack.push(CallFrame {
+            caller: core::mem::replace(
+                &mut self.exec_context.caller,
+                self.exec_context.callee.clone(),
+            ),
+            callee: self.exec_context.callee.replace(callee),
+            value_transferred: core::mem::replace(
+                &mut self.exec_context.value_transferred,
+                value_transferred,
+            ),
+            input: core::mem::replace(&mut self.exec_context.input, input.to_vec()),
+        });
+        Ok(PendingExecution {
+            entry,
+            snapshot,
+            flags,
+            instantiated: None,
+        })
+    }
+
+    /// Pops the call frame of a finished execution and restores the execution
+    /// context of the caller.
+    ///
+    /// Returns the result of the execution and the data returned via `seal_return`, if any.
+    fn pop_call_frame(
+        &mut self,
+        outcome: thread::Result<()>,
+        snapshot: (Database, StorageDeposit),
+    ) -> (Result, Option<Vec<u8>>) {
+        let frame = self.call_stack.pop().expect("missing call frame");
+        self.exec_context.caller = frame.caller;
+        self.exec_context.callee = frame.callee;
+        self.exec_context.value_transferred = frame.value_transferred;
+        self.exec_context.input = frame.input;
+        let payload = match outcome {
+            Ok(()) => return (Ok(()), None),
+            Err(payload) => payload,
+        };
+        match payload.downcast::<ReturnValue>() {
+            Ok(return_value) => {
+                if return_value.is_reverted() {
+                    (self.database, self.storage_deposit) = snapshot;
+                    return (Err(Error::CalleeReverted), Some(return_value.data))
+                }
+                (Ok(()), Some(return_value.data))
+            }
+            Err(payload) if payload.is::<ContractTermination>() => {
+                // The contract terminated itself via `seal_terminate`.
+                (Ok(()), None)
+            }
+            Err(_) => {
+                (self.database, self.storage_deposit) = snapshot;
+                (Err(Error::CalleeTrapped), None)
+            }
+        }
+    }
+}
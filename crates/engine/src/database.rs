@@ -18,6 +18,8 @@ use std::collections::HashMap;
 
 const BALANCE_OF: &[u8] = b"balance:";
 const STORAGE_OF: &[u8] = b"contract-storage:";
+const CODE_HASH_OF: &[u8] = b"code-hash:";
+const STORAGE_DEPOSIT_OF: &[u8] = b"storage-deposit:";
 
 /// Returns the database key under which to find the balance for account `who`.
 pub fn balance_of_key(who: &[u8]) -> [u8; 32] {
@@ -35,11 +37,28 @@ pub fn storage_of_contract_key(who: &[u8], key: &[u8]) -> [u8; 32] {
     hashed_key
 }
 
+/// Returns the database key under which to find the code hash of the contract `who`.
+pub fn code_hash_of_key(who: &[u8]) -> [u8; 32] {
+    let keyed = who.to_vec().to_keyed_vec(CODE_HASH_OF);
+    let mut hashed_key: [u8; 32] = [0; 32];
+    super::hashing::blake2b_256(&keyed[..], &mut hashed_key);
+    hashed_key
+}
+
+/// Returns the database key under which to find the storage deposit held by the
+/// contract `who`.
+pub fn storage_deposit_of_key(who: &[u8]) -> [u8; 32] {
+    let keyed = who.to_vec().to_keyed_vec(STORAGE_DEPOSIT_OF);
+    let mut hashed_key: [u8; 32] = [0; 32];
+    super::hashing::blake2b_256(&keyed[..], &mut hashed_key);
+    hashed_key
+}
+
 /// The chain database.
 ///
 /// Everything is stored in here: accounts, balances, contract storage, etc..
 /// Just like in Substrate a prefix hash is computed for every contract.
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct Database {
     hmap: HashMap<Vec<u8>, Vec<u8>>,
 }
@@ -119,6 +138,24 @@ impl Database {
         })
     }
 
+    /// Returns the storage deposit held by the contract `account_id`.
+    pub fn get_storage_deposit(&self, account_id: &[u8]) -> Balance {
+        let hashed_key = storage_deposit_of_key(account_id);
+        self.get(&hashed_key)
+            .map(|encoded_deposit| {
+                scale::Decode::decode(&mut &encoded_deposit[..])
+                    .expect("unable to decode storage deposit from database")
+            })
+            .unwrap_or_default()
+    }
+
+    /// Sets the storage deposit held by the contract `account_id` to `deposit`.
+    pub fn set_storage_deposit(&mut self, account_id: &[u8], deposit: Balance) {
+        let hashed_key = storage_deposit_of_key(account_id);
+        self.hmap
+            .insert(hashed_key.to_vec(), scale::Encode::encode(&deposit));
+    }
+
     /// Sets the balance of `account_id` to `new_balance`.
     pub fn set_balance(&mut self, account_id: &[u8], new_balance: Balance) {
         let hashed_key = balance_of_key(account_id);
@@ -128,6 +165,18 @@ impl Database {
             .and_modify(|v| *v = encoded_balance.clone())
             .or_insert(encoded_balance);
     }
+
+    /// Returns the code hash of the contract `account_id`, if it is a contract.
+    pub fn get_code_hash(&self, account_id: &[u8]) -> Option<&Vec<u8>> {
+        let hashed_key = code_hash_of_key(account_id);
+        self.get(&hashed_key)
+    }
+
+    /// Sets the code hash of the contract `account_id` to `code_hash`.
+    pub fn set_code_hash(&mut self, account_id: &[u8], code_hash: &[u8]) {
+        let hashed_key = code_hash_of_key(account_id);
+        self.hmap.insert(hashed_key.to_vec(), code_hash.to_vec());
+    }
 }
 
 #[cfg(test)]
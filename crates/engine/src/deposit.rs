@@ -0,0 +1,131 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    ext::Engine,
+    types::Balance,
+};
+
+/// The net storage deposit charged or refunded by a sequence of storage changes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StorageDeposit {
+    /// The origin was refunded the given amount.
+    Refund(Balance),
+    /// The origin was charged the given amount.
+    Charge(Balance),
+}
+
+impl Default for StorageDeposit {
+    fn default() -> Self {
+        Self::Charge(0)
+    }
+}
+
+impl StorageDeposit {
+    /// Returns the net deposit after additionally charging `amount`.
+    fn charge(self, amount: Balance) -> Self {
+        match self {
+            Self::Charge(charged) => Self::Charge(charged.saturating_add(amount)),
+            Self::Refund(refunded) if refunded > amount => {
+                Self::Refund(refunded - amount)
+            }
+            Self::Refund(refunded) => Self::Charge(amount - refunded),
+        }
+    }
+
+    /// Returns the net deposit after additionally refunding `amount`.
+    fn refund(self, amount: Balance) -> Self {
+        match self {
+            Self::Refund(refunded) => Self::Refund(refunded.saturating_add(amount)),
+            Self::Charge(charged) if charged >= amount => Self::Charge(charged - amount),
+            Self::Charge(charged) => Self::Refund(amount - charged),
+        }
+    }
+}
+
+impl Engine {
+    /// Returns the storage deposit of a storage cell holding `len` bytes.
+    ///
+    /// Returns `0` for `None`, i.e. a cell that does not exist.
+    fn storage_deposit(&self, len: Option<usize>) -> Balance {
+        len.map(|len| {
+            let per_byte = self
+                .chain_spec
+                .deposit_per_byte
+                .saturating_mul(len as Balance);
+            self.chain_spec.deposit_per_item.saturating_add(per_byte)
+        })
+        .unwrap_or_default()
+    }
+
+    /// Returns the account that pays the storage deposits of the current call.
+    ///
+    /// Just like on-chain this is the origin of the call stack, i.e. the account
+    /// that called into the first contract.
+    fn deposit_origin(&self) -> Option<Vec<u8>> {
+        let origin = match self.call_stack.first() {
+            Some(frame) => frame.callee.as_ref(),
+            None => self.exec_context.caller.as_ref(),
+        };
+        origin.map(|origin| origin.as_bytes().to_vec())
+    }
+
+    /// Charges or refunds the storage deposit of a storage cell of `contract`
+    /// whose length changed from `old_len` to `new_len`.
+    ///
+    /// The deposit is paid by the origin of the call stack and held by `contract`.
+    ///
+    /// # Panics
+    ///
+    /// If the origin cannot pay the deposit. Within a contract execution this
+    /// traps the contract, just like a failed deposit fails the call on-chain.
+    pub(crate) fn charge_storage_deposit(
+        &mut self,
+        contract: &[u8],
+        old_len: Option<usize>,
+        new_len: Option<usize>,
+    ) {
+        let old_deposit = self.storage_deposit(old_len);
+        let new_deposit = self.storage_deposit(new_len);
+        if old_deposit == new_deposit {
+            return
+        }
+        let origin = match self.deposit_origin() {
+            Some(origin) => origin,
+            None => return,
+        };
+        let origin_balance = self.database.get_balance(&origin).unwrap_or_default();
+        let held = self.database.get_storage_deposit(contract);
+        if new_deposit > old_deposit {
+            let charge = new_deposit - old_deposit;
+            let origin_balance =
+                origin_balance.checked_sub(charge).unwrap_or_else(|| {
+                    panic!(
+                        "origin {:?} cannot pay the storage deposit of {}",
+                        origin, charge
+                    )
+                });
+            self.database.set_balance(&origin, origin_balance);
+            self.database
+                .set_storage_deposit(contract, held.saturating_add(charge));
+            self.storage_deposit = self.storage_deposit.charge(charge);
+        } else {
+            let refund = core::cmp::min(old_deposit - new_deposit, held);
+            self.database
+                .set_balance(&origin, origin_balance.saturating_add(refund));
+            self.database.set_storage_deposit(contract, held - refund);
+            self.storage_deposit = self.storage_deposit.refund(refund);
+        }
+    }
+}
@@ -46,6 +46,16 @@ pub struct ExecContext {
     pub block_timestamp: BlockTimestamp,
     /// The randomization entropy for a block.
     pub entropy: Hash,
+    /// The number of contracts instantiated on the chain so far.
+    pub instantiation_nonce: u64,
+    /// The hashes of all finished blocks, indexed by their block number.
+    pub block_hashes: Vec<Hash>,
+    /// The input of the contract execution.
+    pub input: Vec<u8>,
+    /// The secret key sealed into the execution environment.
+    ///
+    /// Used to derive keys and sign messages on behalf of the contract.
+    pub sealed_key: [u8; 32],
 }
 
 impl Default for ExecContext {
@@ -59,6 +69,10 @@ impl Default for ExecContext {
             block_number: 0,
             block_timestamp: 0,
             entropy,
+            instantiation_nonce: 0,
+            block_hashes: Vec::new(),
+            input: Vec::new(),
+            sealed_key: [0x01; 32],
         }
     }
 }
@@ -19,26 +19,52 @@
 
 use crate::{
     chain_extension::ChainExtensionHandler,
-    database::Database,
+    contracts::{
+        silence_control_flow_panics,
+        CallFrame,
+        ContractRegistry,
+        MockedMessages,
+    },
+    database::{
+        balance_of_key,
+        code_hash_of_key,
+        storage_deposit_of_key,
+        Database,
+    },
+    deposit::StorageDeposit,
     exec_context::ExecContext,
+    gas::{
+        per_item,
+        GasMeter,
+    },
+    local_cache::LocalCache,
     test_api::{
+        ContractTermination,
         DebugInfo,
         EmittedEvent,
     },
+    trace::{
+        decode_balance,
+        CallTracer,
+        EnvCall,
+    },
     types::{
         AccountId,
         Balance,
+        BlockNumber,
         BlockTimestamp,
     },
 };
 use rand::{
+    rngs::StdRng,
     Rng,
+    RngCore,
     SeedableRng,
 };
 use scale::Encode;
 use std::panic::panic_any;
 
-type Result = core::result::Result<(), Error>;
+pub(crate) type Result = core::result::Result<(), Error>;
 
 macro_rules! define_error_codes {
     (
@@ -101,6 +127,12 @@ define_error_codes! {
     LoggingDisabled = 9,
     /// ECDSA public key recovery failed. Most probably wrong recovery id or signature.
     EcdsaRecoveryFailed = 11,
+    /// sr25519 signature verification failed. This may be because of an invalid
+    /// public key, invalid message or invalid signature.
+    Sr25519VerifyFailed = 12,
+    /// The sealed key of the execution environment is not a valid secret key
+    /// of the requested kind.
+    InvalidSealedKey = 16,
 }
 
 /// The raw return code returned by the host side.
@@ -128,8 +160,29 @@ pub struct Engine {
     pub chain_spec: ChainSpec,
     /// Handler for registered chain extensions.
     pub chain_extension_handler: ChainExtensionHandler,
+    /// The non-consensus scratch storage of contracts.
+    pub(crate) local_cache: LocalCache,
+    /// The entry points of the contracts that can be instantiated and called.
+    pub(crate) contracts: ContractRegistry,
+    /// The canned outputs of mocked contract messages.
+    pub(crate) mocked_messages: MockedMessages,
+    /// The execution contexts of the callers of the currently executed contract.
+    pub(crate) call_stack: Vec<CallFrame>,
+    /// The random number generator of the host.
+    ///
+    /// Seeded deterministically so that tests are reproducible.
+    pub(crate) host_rng: StdRng,
+    /// Accounts for the gas consumed by host function calls.
+    pub(crate) gas_meter: GasMeter,
+    /// The net storage deposit charged since the start of the current dry-run.
+    pub(crate) storage_deposit: StorageDeposit,
+    /// Records the calls into the environment if tracing is enabled.
+    pub(crate) tracer: CallTracer,
 }
 
+/// The seed of the host random number generator of a new [`Engine`].
+pub const DEFAULT_HOST_RNG_SEED: [u8; 32] = [0; 32];
+
 /// The chain specification.
 pub struct ChainSpec {
     /// The current gas price.
@@ -139,6 +192,12 @@ pub struct ChainSpec {
     pub minimum_balance: Balance,
     /// The targeted block time.
     pub block_time: BlockTimestamp,
+    /// The number of recent blocks for which the block hash can be queried.
+    pub block_hash_count: BlockNumber,
+    /// The storage deposit charged per storage cell.
+    pub deposit_per_item: Balance,
+    /// The storage deposit charged per byte of a storage cell.
+    pub deposit_per_byte: Balance,
 }
 
 /// The default values for the chain specification are:
@@ -146,6 +205,9 @@ pub struct ChainSpec {
 ///   * `gas_price`: 100
 ///   * `minimum_balance`: 42
 ///   * `block_time`: 6
+///   * `block_hash_count`: 2400
+///   * `deposit_per_item`: 0
+///   * `deposit_per_byte`: 0
 ///
 /// There is no particular reason behind choosing them this way.
 impl Default for ChainSpec {
@@ -154,6 +216,9 @@ impl Default for ChainSpec {
             gas_price: 100,
             minimum_balance: 1000000,
             block_time: 6,
+            block_hash_count: 2400,
+            deposit_per_item: 0,
+            deposit_per_byte: 0,
         }
     }
 }
@@ -167,6 +232,14 @@ impl Engine {
             debug_info: DebugInfo::new(),
             chain_spec: ChainSpec::default(),
             chain_extension_handler: ChainExtensionHandler::new(),
+            local_cache: LocalCache::new(),
+            contracts: ContractRegistry::new(),
+            mocked_messages: MockedMessages::new(),
+            call_stack: Vec::new(),
+            host_rng: StdRng::from_seed(DEFAULT_HOST_RNG_SEED),
+            gas_meter: GasMeter::new(),
+            storage_deposit: StorageDeposit::default(),
+            tracer: CallTracer::new(),
         }
     }
 }
@@ -179,7 +252,25 @@ impl Default for Engine {
 
 impl Engine {
     /// Transfers value from the contract to the destination account.
-    pub fn transfer(&mut self, account_id: &[u8], mut value: &[u8]) -> Result {
+    pub fn transfer(&mut self, account_id: &[u8], value: &[u8]) -> Result {
+        self.gas_meter.charge(|schedule| schedule.transfer);
+        self.trace(|contract| {
+            EnvCall::Transfer {
+                contract,
+                to: account_id.to_vec(),
+                value: decode_balance(value),
+            }
+        });
+        self.transfer_value(account_id, value)
+    }
+
+    /// Transfers value from the contract to the destination account without
+    /// charging any gas.
+    pub(crate) fn transfer_value(
+        &mut self,
+        account_id: &[u8],
+        mut value: &[u8],
+    ) -> Result {
         // Note that a transfer of `0` is allowed here
         let increment = <u128 as scale::Decode>::decode(&mut value)
             .map_err(|_| Error::TransferFailed)?;
@@ -193,8 +284,10 @@ impl Engine {
             .get_balance(contract.clone())
             .map_err(|_| Error::TransferFailed)?;
 
-        self.database
-            .set_balance(&contract, contract_old_balance - increment);
+        let contract_new_balance = contract_old_balance
+            .checked_sub(increment)
+            .ok_or(Error::TransferFailed)?;
+        self.database.set_balance(&contract, contract_new_balance);
         self.database
             .set_balance(&dest, dest_old_balance + increment);
         Ok(())
@@ -206,6 +299,11 @@ impl Engine {
         let topics_count: scale::Compact<u32> = scale::Decode::decode(&mut &topics[0..1])
             .expect("decoding number of topics failed");
         let topics_count = topics_count.0 as usize;
+        self.gas_meter.charge(|schedule| {
+            schedule.deposit_event
+                + per_item(schedule.event_per_topic, topics_count)
+                + per_item(schedule.event_per_byte, data.len())
+        });
 
         let topics_vec = if topics_count > 0 {
             // The rest of the slice contains the topics
@@ -221,6 +319,13 @@ impl Engine {
             Vec::new()
         };
 
+        self.trace(|contract| {
+            EnvCall::DepositEvent {
+                contract,
+                topics: topics_vec.clone(),
+                data: data.to_vec(),
+            }
+        });
         self.debug_info.record_event(EmittedEvent {
             topics: topics_vec,
             data: data.to_vec(),
@@ -229,6 +334,17 @@ impl Engine {
 
     /// Writes the encoded value into the storage at the given key.
     pub fn set_storage(&mut self, key: &[u8; 32], encoded_value: &[u8]) {
+        self.gas_meter.charge(|schedule| {
+            schedule.set_storage
+                + per_item(schedule.storage_per_byte, encoded_value.len())
+        });
+        self.trace(|contract| {
+            EnvCall::SetStorage {
+                contract,
+                key: key.to_vec(),
+                value: encoded_value.to_vec(),
+            }
+        });
         let callee = self.get_callee();
         let account_id = AccountId::from_bytes(&callee[..]);
 
@@ -236,6 +352,11 @@ impl Engine {
         self.debug_info
             .record_cell_for_account(account_id, key.to_vec());
 
+        let old_len = self
+            .database
+            .get_from_contract_storage(&callee, key)
+            .map(Vec::len);
+        self.charge_storage_deposit(&callee, old_len, Some(encoded_value.len()));
         // We ignore if storage is already set for this key
         let _ = self.database.insert_into_contract_storage(
             &callee,
@@ -246,11 +367,22 @@ impl Engine {
 
     /// Returns the decoded contract storage at the key if any.
     pub fn get_storage(&mut self, key: &[u8; 32], output: &mut &mut [u8]) -> Result {
+        self.trace(|contract| {
+            EnvCall::GetStorage {
+                contract,
+                key: key.to_vec(),
+            }
+        });
         let callee = self.get_callee();
         let account_id = AccountId::from_bytes(&callee[..]);
 
         self.debug_info.inc_reads(account_id);
-        match self.database.get_from_contract_storage(&callee, key) {
+        let value = self.database.get_from_contract_storage(&callee, key);
+        self.gas_meter.charge(|schedule| {
+            let len = value.map(|value| value.len()).unwrap_or_default();
+            schedule.get_storage + per_item(schedule.storage_per_byte, len)
+        });
+        match value {
             Some(val) => {
                 set_output(output, val);
                 Ok(())
@@ -261,13 +393,24 @@ impl Engine {
 
     /// Removes the storage entries at the given key.
     pub fn clear_storage(&mut self, key: &[u8; 32]) {
+        self.gas_meter.charge(|schedule| schedule.set_storage);
+        self.trace(|contract| {
+            EnvCall::ClearStorage {
+                contract,
+                key: key.to_vec(),
+            }
+        });
         let callee = self.get_callee();
         let account_id = AccountId::from_bytes(&callee[..]);
         self.debug_info.inc_writes(account_id.clone());
         let _ = self
             .debug_info
             .remove_cell_for_account(account_id, key.to_vec());
-        let _ = self.database.remove_contract_storage(&callee, key);
+        let old_len = self
+            .database
+            .remove_contract_storage(&callee, key)
+            .map(|value| value.len());
+        self.charge_storage_deposit(&callee, old_len, None);
     }
 
     /// Remove the calling account and transfer remaining balance.
@@ -275,23 +418,65 @@ impl Engine {
     /// This function never returns. Either the termination was successful and the
     /// execution of the destroyed contract is halted. Or it failed during the
     /// termination which is considered fatal.
+    ///
+    /// The balance, the code hash and the storage of the contract are removed and
+    /// the termination is recorded. If the contract is its own beneficiary the
+    /// remaining balance is burned, just like on-chain.
     pub fn terminate(&mut self, beneficiary: &[u8]) -> ! {
         // Send the remaining balance to the beneficiary
         let contract = self.get_callee();
-        let all = self.get_balance(contract).expect("could not get balance");
+        let all = self
+            .get_balance(contract.clone())
+            .expect("could not get balance");
         let value = &scale::Encode::encode(&all)[..];
-        self.transfer(beneficiary, value)
+        self.gas_meter.charge(|schedule| schedule.transfer);
+        self.transfer_value(beneficiary, value)
             .expect("transfer did not work");
+        // The storage deposit is released to the beneficiary as well
+        let deposit = self.database.get_storage_deposit(&contract);
+        if deposit > 0 {
+            let beneficiary_balance =
+                self.database.get_balance(beneficiary).unwrap_or_default();
+            self.database
+                .set_balance(beneficiary, beneficiary_balance.saturating_add(deposit));
+        }
+        self.trace(|contract| {
+            EnvCall::Terminate {
+                contract,
+                beneficiary: beneficiary.to_vec(),
+            }
+        });
+        self.remove_contract(&contract);
 
-        // Encode the result of the termination and panic with it.
+        // Record the termination and panic with it.
         // This enables testing for the proper result and makes sure this
         // method returns `Never`.
-        let res = (all, beneficiary.to_vec());
-        panic_any(scale::Encode::encode(&res));
+        let termination = ContractTermination {
+            contract,
+            beneficiary: beneficiary.to_vec(),
+            value_transferred: all.saturating_add(deposit),
+        };
+        self.debug_info.record_termination(termination.clone());
+        silence_control_flow_panics();
+        panic_any(termination);
+    }
+
+    /// Removes the balance, the code hash and the storage of the contract `account_id`.
+    fn remove_contract(&mut self, account_id: &[u8]) {
+        self.database.remove(&balance_of_key(account_id));
+        self.database.remove(&code_hash_of_key(account_id));
+        self.database.remove(&storage_deposit_of_key(account_id));
+        let keys = self
+            .debug_info
+            .remove_cells_for_account(AccountId::from_bytes(account_id));
+        for key in keys {
+            self.database.remove_contract_storage(account_id, &key);
+        }
     }
 
     /// Returns the address of the caller.
     pub fn caller(&self, output: &mut &mut [u8]) {
+        self.gas_meter.charge(|_| 0);
         let caller = self
             .exec_context
             .caller
@@ -303,6 +488,7 @@ impl Engine {
 
     /// Returns the balance of the executed contract.
     pub fn balance(&self, output: &mut &mut [u8]) {
+        self.gas_meter.charge(|_| 0);
         let contract = self
             .exec_context
             .callee
@@ -319,6 +505,7 @@ impl Engine {
 
     /// Returns the transferred value for the called contract.
     pub fn value_transferred(&self, output: &mut &mut [u8]) {
+        self.gas_meter.charge(|_| 0);
         let value_transferred: Vec<u8> =
             scale::Encode::encode(&self.exec_context.value_transferred);
         set_output(output, &value_transferred[..])
@@ -326,6 +513,7 @@ impl Engine {
 
     /// Returns the address of the executed contract.
     pub fn address(&self, output: &mut &mut [u8]) {
+        self.gas_meter.charge(|_| 0);
         let callee = self
             .exec_context
             .callee
@@ -337,6 +525,7 @@ impl Engine {
 
     /// Records the given debug message and appends to stdout.
     pub fn debug_message(&mut self, message: &str) {
+        self.gas_meter.charge(|_| 0);
         self.debug_info.record_debug_message(String::from(message));
         print!("{}", message);
     }
@@ -363,6 +552,7 @@ impl Engine {
 
     /// Returns the current block number.
     pub fn block_number(&self, output: &mut &mut [u8]) {
+        self.gas_meter.charge(|_| 0);
         let block_number: Vec<u8> =
             scale::Encode::encode(&self.exec_context.block_number);
         set_output(output, &block_number[..])
@@ -370,50 +560,66 @@ impl Engine {
 
     /// Returns the timestamp of the current block.
     pub fn block_timestamp(&self, output: &mut &mut [u8]) {
+        self.gas_meter.charge(|_| 0);
         let block_timestamp: Vec<u8> =
             scale::Encode::encode(&self.exec_context.block_timestamp);
         set_output(output, &block_timestamp[..])
     }
 
-    pub fn gas_left(&self, _output: &mut &mut [u8]) {
-        unimplemented!("off-chain environment does not yet support `gas_left`");
+    /// Returns the hash of the block with the given `block_number`.
+    ///
+    /// Only the hashes of the most recent `block_hash_count` finished blocks are
+    /// available, the hash of the current block is not yet known.
+    pub fn block_hash(&self, mut block_number: &[u8], output: &mut &mut [u8]) -> Result {
+        self.gas_meter.charge(|_| 0);
+        let block_number = <BlockNumber as scale::Decode>::decode(&mut block_number)
+            .map_err(|_| Error::KeyNotFound)?;
+        let current = self.exec_context.block_number;
+        if block_number >= current
+            || current - block_number > self.chain_spec.block_hash_count
+        {
+            return Err(Error::KeyNotFound)
+        }
+        let hash = self
+            .exec_context
+            .block_hashes
+            .get(block_number as usize)
+            .ok_or(Error::KeyNotFound)?;
+        set_output(output, &hash[..]);
+        Ok(())
+    }
+
+    /// Returns the instantiation nonce of the chain.
+    pub fn instantiation_nonce(&self) -> u64 {
+        self.gas_meter.charge(|_| 0);
+        self.exec_context.instantiation_nonce
+    }
+
+    /// Returns the gas left until the gas limit set via
+    /// [`set_gas_limit`][`Engine::set_gas_limit`] is exhausted.
+    ///
+    /// The gas is encoded as a weight whose proof size is not metered.
+    pub fn gas_left(&self, output: &mut &mut [u8]) {
+        self.gas_meter.charge(|_| 0);
+        let gas_left = scale::Encode::encode(&(
+            scale::Compact(self.gas_meter.gas_left()),
+            scale::Compact(u64::MAX),
+        ));
+        set_output(output, &gas_left[..])
     }
 
     /// Returns the minimum balance that is required for creating an account
     /// (i.e. the chain's existential deposit).
     pub fn minimum_balance(&self, output: &mut &mut [u8]) {
+        self.gas_meter.charge(|_| 0);
         let minimum_balance: Vec<u8> =
             scale::Encode::encode(&self.chain_spec.minimum_balance);
         set_output(output, &minimum_balance[..])
     }
 
-    #[allow(clippy::too_many_arguments)]
-    pub fn instantiate(
-        &mut self,
-        _code_hash: &[u8],
-        _gas_limit: u64,
-        _endowment: &[u8],
-        _input: &[u8],
-        _out_address: &mut &mut [u8],
-        _out_return_value: &mut &mut [u8],
-        _salt: &[u8],
-    ) -> Result {
-        unimplemented!("off-chain environment does not yet support `instantiate`");
-    }
-
-    pub fn call(
-        &mut self,
-        _callee: &[u8],
-        _gas_limit: u64,
-        _value: &[u8],
-        _input: &[u8],
-        _output: &mut &mut [u8],
-    ) -> Result {
-        unimplemented!("off-chain environment does not yet support `call`");
-    }
-
     /// Emulates gas price calculation.
     pub fn weight_to_fee(&self, gas: u64, output: &mut &mut [u8]) {
+        self.gas_meter.charge(|_| 0);
         let fee = self.chain_spec.gas_price.saturating_mul(gas.into());
         let fee: Vec<u8> = scale::Encode::encode(&fee);
         set_output(output, &fee[..])
@@ -437,6 +643,8 @@ impl Engine {
     ///    engine.random(&subject, &mut output.as_mut_slice());
     /// ```
     pub fn random(&self, subject: &[u8], output: &mut &mut [u8]) {
+        self.gas_meter
+            .charge(|schedule| per_item(schedule.hash_per_byte, subject.len()));
         let seed = (self.exec_context.entropy, subject).encode();
         let mut digest = [0u8; 32];
         Engine::hash_blake2_256(&seed, &mut digest);
@@ -454,6 +662,19 @@ impl Engine {
         input: &[u8],
         output: &mut &mut [u8],
     ) {
+        let res = self.eval_chain_extension(func_id, input);
+        let decoded: Vec<u8> = scale::Encode::encode(&res);
+        set_output(output, &decoded[..])
+    }
+
+    /// Calls the chain extension method registered at `func_id` with `input` and
+    /// returns its status code together with its output.
+    ///
+    /// Unlike [`Engine::call_chain_extension`] this does not require an output buffer
+    /// that is big enough for the output of the chain extension method.
+    pub fn eval_chain_extension(&mut self, func_id: u32, input: &[u8]) -> (u32, Vec<u8>) {
+        self.gas_meter
+            .charge(|schedule| schedule.call_chain_extension);
         let encoded_input = input.encode();
         let (status_code, out) = self
             .chain_extension_handler
@@ -464,9 +685,7 @@ impl Engine {
                     error
                 );
             });
-        let res = (status_code, out);
-        let decoded: Vec<u8> = scale::Encode::encode(&res);
-        set_output(output, &decoded[..])
+        (status_code, out.to_vec())
     }
 
     /// Recovers the compressed ECDSA public key for given `signature` and `message_hash`,
@@ -477,6 +696,7 @@ impl Engine {
         message_hash: &[u8; 32],
         output: &mut [u8; 33],
     ) -> Result {
+        self.gas_meter.charge(|schedule| schedule.signature);
         use secp256k1::{
             ecdsa::{
                 RecoverableSignature,
@@ -515,12 +735,162 @@ impl Engine {
             Err(_) => Err(Error::EcdsaRecoveryFailed),
         }
     }
+
+    /// Verifies the sr25519 `signature` of `message` against the given `pub_key`.
+    ///
+    /// # Note
+    ///
+    /// Uses the `substrate` signing context, just like the `contracts` pallet.
+    pub fn sr25519_verify(
+        &mut self,
+        signature: &[u8; 64],
+        message: &[u8],
+        pub_key: &[u8; 32],
+    ) -> Result {
+        self.gas_meter.charge(|schedule| schedule.signature);
+        use schnorrkel::{
+            PublicKey,
+            Signature,
+        };
+
+        // The signing context used by Substrate for sr25519 signatures.
+        let context = b"substrate";
+        let signature =
+            Signature::from_bytes(signature).map_err(|_| Error::Sr25519VerifyFailed)?;
+        let public_key =
+            PublicKey::from_bytes(pub_key).map_err(|_| Error::Sr25519VerifyFailed)?;
+        public_key
+            .verify_simple(context, message, &signature)
+            .map_err(|_| Error::Sr25519VerifyFailed)
+    }
+
+    /// Derives a secret key from the sealed key of the execution environment
+    /// and the given `salt`, and stores the result in `output`.
+    ///
+    /// The same `salt` always yields the same key.
+    pub fn derive_key(&self, salt: &[u8], output: &mut [u8; 32]) {
+        self.gas_meter
+            .charge(|schedule| schedule.call_chain_extension);
+        let input = [&self.exec_context.sealed_key[..], salt].concat();
+        super::hashing::blake2b_256(&input[..], output);
+    }
+
+    /// Signs `message` with the sr25519 key derived from the sealed key of the
+    /// execution environment, and stores the signature in `output`.
+    ///
+    /// # Note
+    ///
+    /// Uses the `substrate` signing context, just like
+    /// [`sr25519_verify`][`Engine::sr25519_verify`].
+    pub fn sign_sr25519(&self, message: &[u8], output: &mut [u8; 64]) -> Result {
+        self.gas_meter
+            .charge(|schedule| schedule.call_chain_extension + schedule.signature);
+        use schnorrkel::MiniSecretKey;
+
+        let keypair = MiniSecretKey::from_bytes(&self.exec_context.sealed_key[..])
+            .map_err(|_| Error::InvalidSealedKey)?
+            .expand_to_keypair(MiniSecretKey::ED25519_MODE);
+        let signature = keypair.sign_simple(b"substrate", message);
+        output.copy_from_slice(&signature.to_bytes()[..]);
+        Ok(())
+    }
+
+    /// Signs the BLAKE2-256 hash of `message` with the ECDSA key derived from the
+    /// sealed key of the execution environment, and stores the recoverable
+    /// signature in `output`.
+    ///
+    /// # Note
+    ///
+    /// The signature can be recovered via [`ecdsa_recover`][`Engine::ecdsa_recover`].
+    ///
+    /// # Errors
+    ///
+    /// If the sealed key is not a valid secp256k1 secret key, e.g. because it
+    /// exceeds the order of the curve.
+    pub fn sign_ecdsa(&self, message: &[u8], output: &mut [u8; 65]) -> Result {
+        self.gas_meter
+            .charge(|schedule| schedule.call_chain_extension + schedule.signature);
+        use secp256k1::{
+            Message,
+            SecretKey,
+            SECP256K1,
+        };
+
+        let secret_key = SecretKey::from_slice(&self.exec_context.sealed_key[..])
+            .map_err(|_| Error::InvalidSealedKey)?;
+        let mut message_hash = [0; 32];
+        super::hashing::blake2b_256(message, &mut message_hash);
+        let message = Message::from_slice(&message_hash[..])
+            .expect("message hash has the expected length");
+        let (recovery_id, signature) = SECP256K1
+            .sign_ecdsa_recoverable(&message, &secret_key)
+            .serialize_compact();
+        output[..64].copy_from_slice(&signature[..]);
+        output[64] = recovery_id.to_i32() as u8;
+        Ok(())
+    }
+
+    /// Stores `value` under `key` in the local cache of the contract.
+    ///
+    /// The entry expires after `ttl` has passed, measured in block timestamps.
+    pub fn cache_set(&mut self, key: &[u8], value: &[u8], ttl: BlockTimestamp) {
+        self.gas_meter
+            .charge(|schedule| schedule.call_chain_extension);
+        let callee = self.get_callee();
+        let expires_at = self.exec_context.block_timestamp.saturating_add(ttl);
+        self.local_cache.set(&callee, key, value, expires_at);
+    }
+
+    /// Returns the SCALE encoded value stored under `key` in the local cache of
+    /// the contract in `output`.
+    pub fn cache_get(&mut self, key: &[u8], output: &mut &mut [u8]) -> Result {
+        self.gas_meter
+            .charge(|schedule| schedule.call_chain_extension);
+        let callee = self.get_callee();
+        let now = self.exec_context.block_timestamp;
+        match self.local_cache.get(&callee, key, now) {
+            Some(value) => {
+                set_output(output, &value.encode()[..]);
+                Ok(())
+            }
+            None => Err(Error::KeyNotFound),
+        }
+    }
+
+    /// Removes the value stored under `key` from the local cache of the contract
+    /// and returns it SCALE encoded in `output`.
+    pub fn cache_remove(&mut self, key: &[u8], output: &mut &mut [u8]) -> Result {
+        self.gas_meter
+            .charge(|schedule| schedule.call_chain_extension);
+        let callee = self.get_callee();
+        let now = self.exec_context.block_timestamp;
+        match self.local_cache.remove(&callee, key, now) {
+            Some(value) => {
+                set_output(output, &value.encode()[..]);
+                Ok(())
+            }
+            None => Err(Error::KeyNotFound),
+        }
+    }
+
+    /// Fills `output` with random bytes of the host random number generator.
+    ///
+    /// # Note
+    ///
+    /// In contrast to [`random`][`Engine::random`] the bytes are not derived from
+    /// the on-chain entropy. The generator is seeded deterministically, see
+    /// [`set_host_rng_seed`][`Engine::set_host_rng_seed`].
+    pub fn getrandom(&mut self, output: &mut [u8]) {
+        self.gas_meter
+            .charge(|schedule| schedule.call_chain_extension);
+        self.host_rng.fill_bytes(output);
+    }
 }
 
 /// Copies the `slice` into `output`.
 ///
 /// Panics if the slice is too large and does not fit.
-fn set_output(output: &mut &mut [u8], slice: &[u8]) {
+pub(crate) fn set_output(output: &mut &mut [u8], slice: &[u8]) {
     assert!(
         slice.len() <= output.len(),
         "the output buffer is too small! the decoded storage is of size {} bytes, \
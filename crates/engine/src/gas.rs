@@ -0,0 +1,163 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::ext::Engine;
+use core::cell::Cell;
+
+/// The costs charged by the off-chain engine for calling host functions.
+///
+/// The costs are given in `ref_time` picoseconds and only approximate the weights
+/// of the `contracts` pallet. They are meant to catch gross gas regressions in
+/// tests and not to predict the exact fees of a real chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasSchedule {
+    /// The base cost charged for every host function call.
+    pub host_fn: u64,
+    /// The cost of reading a storage cell.
+    pub get_storage: u64,
+    /// The cost of writing or clearing a storage cell.
+    pub set_storage: u64,
+    /// The cost per byte read from or written to the storage.
+    pub storage_per_byte: u64,
+    /// The cost of depositing an event.
+    pub deposit_event: u64,
+    /// The cost per topic of a deposited event.
+    pub event_per_topic: u64,
+    /// The cost per byte of the data of a deposited event.
+    pub event_per_byte: u64,
+    /// The cost of transferring value.
+    pub transfer: u64,
+    /// The cost of calling or instantiating a contract.
+    pub call: u64,
+    /// The cost per byte of the input passed to a called or instantiated contract.
+    pub call_per_input_byte: u64,
+    /// The cost per byte of hashed input.
+    pub hash_per_byte: u64,
+    /// The cost of verifying a signature or recovering a public key.
+    pub signature: u64,
+    /// The cost of calling a chain extension method.
+    pub call_chain_extension: u64,
+}
+
+/// The default costs are:
+///
+///   * `host_fn`: 500_000
+///   * `get_storage`: 50_000_000
+///   * `set_storage`: 100_000_000
+///   * `storage_per_byte`: 20_000
+///   * `deposit_event`: 20_000_000
+///   * `event_per_topic`: 5_000_000
+///   * `event_per_byte`: 10_000
+///   * `transfer`: 100_000_000
+///   * `call`: 200_000_000
+///   * `call_per_input_byte`: 10_000
+///   * `hash_per_byte`: 5_000
+///   * `signature`: 50_000_000
+///   * `call_chain_extension`: 10_000_000
+///
+/// They are in the same order of magnitude as the weights of the `contracts` pallet.
+impl Default for GasSchedule {
+    fn default() -> Self {
+        Self {
+            host_fn: 500_000,
+            get_storage: 50_000_000,
+            set_storage: 100_000_000,
+            storage_per_byte: 20_000,
+            deposit_event: 20_000_000,
+            event_per_topic: 5_000_000,
+            event_per_byte: 10_000,
+            transfer: 100_000_000,
+            call: 200_000_000,
+            call_per_input_byte: 10_000,
+            hash_per_byte: 5_000,
+            signature: 50_000_000,
+            call_chain_extension: 10_000_000,
+        }
+    }
+}
+
+/// Accounts for the gas consumed by host function calls.
+#[derive(Default)]
+pub struct GasMeter {
+    /// The costs of the host functions.
+    pub schedule: GasSchedule,
+    /// The gas consumed so far.
+    ///
+    /// Host functions that only read from the engine take `&self`, hence the cell.
+    consumed: Cell<u64>,
+    /// The amount of consumed gas at which the gas limit is exhausted, if any.
+    exhausted_at: Option<u64>,
+}
+
+impl GasMeter {
+    /// Creates a new gas meter using the default gas schedule.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the gas consumed so far.
+    pub fn consumed(&self) -> u64 {
+        self.consumed.get()
+    }
+
+    /// Returns the gas left until the gas limit is exhausted.
+    ///
+    /// Returns `u64::MAX` if no gas limit has been set.
+    pub fn gas_left(&self) -> u64 {
+        self.exhausted_at
+            .map(|exhausted_at| exhausted_at.saturating_sub(self.consumed()))
+            .unwrap_or(u64::MAX)
+    }
+
+    /// Limits the gas that can be consumed from now on to `gas_limit`.
+    ///
+    /// The limit is only reported via [`gas_left`][`GasMeter::gas_left`] and
+    /// not enforced.
+    pub fn set_gas_limit(&mut self, gas_limit: u64) {
+        self.exhausted_at = Some(self.consumed().saturating_add(gas_limit));
+    }
+
+    /// Removes the gas limit.
+    pub fn reset_gas_limit(&mut self) {
+        self.exhausted_at = None;
+    }
+
+    /// Charges the base cost of a host function call plus the cost computed by `f`.
+    pub fn charge<F>(&self, f: F)
+    where
+        F: FnOnce(&GasSchedule) -> u64,
+    {
+        let amount = self.schedule.host_fn.saturating_add(f(&self.schedule));
+        self.consumed
+            .set(self.consumed.get().saturating_add(amount));
+    }
+}
+
+/// Returns the cost of `len` items each costing `per_item`.
+pub(crate) fn per_item(per_item: u64, len: usize) -> u64 {
+    per_item.saturating_mul(len as u64)
+}
+
+impl Engine {
+    /// Charges the base cost of a host function call plus the cost computed by `f`.
+    ///
+    /// Allows the environment to charge for host functions that it does not
+    /// forward to the engine, e.g. hashing.
+    pub fn charge_gas<F>(&self, f: F)
+    where
+        F: FnOnce(&GasSchedule) -> u64,
+    {
+        self.gas_meter.charge(f)
+    }
+}
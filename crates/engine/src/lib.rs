@@ -16,15 +16,31 @@ pub mod ext;
 pub mod test_api;
 
 mod chain_extension;
+mod contracts;
 mod database;
+mod deposit;
 mod exec_context;
+mod gas;
 mod hashing;
+mod local_cache;
+mod trace;
 mod types;
 
 #[cfg(test)]
 mod tests;
 
 pub use chain_extension::ChainExtension;
+pub use contracts::{
+    ContractCode,
+    DryRun,
+    DryRunInfo,
+    FinishedExecution,
+    PendingExecution,
+    ReturnValue,
+};
+pub use deposit::StorageDeposit;
+pub use gas::GasSchedule;
+pub use trace::EnvCall;
 pub use types::AccountError;
 
 use derive_more::From;
@@ -0,0 +1,84 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::types::BlockTimestamp;
+use std::collections::HashMap;
+
+/// An entry of the local cache.
+struct Entry {
+    value: Vec<u8>,
+    /// The block timestamp from which on the entry is expired.
+    expires_at: BlockTimestamp,
+}
+
+/// The non-consensus scratch storage of contracts.
+///
+/// In contrast to the [`Database`][`crate::database::Database`] entries expire after
+/// their time to live has passed. Entries are separated by contract.
+#[derive(Default)]
+pub struct LocalCache {
+    entries: HashMap<(Vec<u8>, Vec<u8>), Entry>,
+}
+
+impl LocalCache {
+    /// Creates a new local cache instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stores `value` under `key` for the contract `who`.
+    ///
+    /// The entry expires once the block timestamp reaches `expires_at`.
+    pub fn set(
+        &mut self,
+        who: &[u8],
+        key: &[u8],
+        value: &[u8],
+        expires_at: BlockTimestamp,
+    ) {
+        let entry = Entry {
+            value: value.to_vec(),
+            expires_at,
+        };
+        self.entries.insert((who.to_vec(), key.to_vec()), entry);
+    }
+
+    /// Returns the value stored under `key` for the contract `who`.
+    ///
+    /// Expired entries are removed and `None` is returned for them.
+    pub fn get(&mut self, who: &[u8], key: &[u8], now: BlockTimestamp) -> Option<&[u8]> {
+        self.remove_expired(now);
+        self.entries
+            .get(&(who.to_vec(), key.to_vec()))
+            .map(|entry| &entry.value[..])
+    }
+
+    /// Removes the value stored under `key` for the contract `who` and returns it.
+    pub fn remove(
+        &mut self,
+        who: &[u8],
+        key: &[u8],
+        now: BlockTimestamp,
+    ) -> Option<Vec<u8>> {
+        self.remove_expired(now);
+        self.entries
+            .remove(&(who.to_vec(), key.to_vec()))
+            .map(|entry| entry.value)
+    }
+
+    /// Removes all entries that expired at `now`.
+    fn remove_expired(&mut self, now: BlockTimestamp) {
+        self.entries.retain(|_, entry| entry.expires_at > now);
+    }
+}
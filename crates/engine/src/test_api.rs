@@ -13,14 +13,23 @@
 // limitations under the License.
 
 use crate::{
+    database::Database,
     ext::Engine,
+    gas::GasSchedule,
     types::{
         AccountId,
         Balance,
+        BlockNumber,
+        BlockTimestamp,
     },
     AccountError,
+    EnvCall,
     Error,
 };
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
 use std::collections::HashMap;
 
 /// Record for an emitted event.
@@ -32,6 +41,21 @@ pub struct EmittedEvent {
     pub data: Vec<u8>,
 }
 
+/// Record for a terminated contract.
+///
+/// Also the payload of the panic with which the execution of the contract is
+/// halted upon `seal_terminate`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractTermination {
+    /// The terminated contract.
+    pub contract: Vec<u8>,
+    /// The beneficiary of the remaining balance of the contract.
+    pub beneficiary: Vec<u8>,
+    /// The remaining balance and the released storage deposit transferred to the
+    /// beneficiary.
+    pub value_transferred: Balance,
+}
+
 #[derive(Clone)]
 pub struct RecordedDebugMessages {
     debug_messages: Vec<String>,
@@ -83,6 +107,8 @@ pub struct DebugInfo {
     count_writes: HashMap<AccountId, usize>,
     /// The number of storage cells used by each account id.
     cells_per_account: HashMap<AccountId, HashMap<Vec<u8>, bool>>,
+    /// Terminated contracts recorder.
+    terminations: Vec<ContractTermination>,
 }
 
 impl Default for DebugInfo {
@@ -100,6 +126,7 @@ impl DebugInfo {
             count_reads: HashMap::new(),
             count_writes: HashMap::new(),
             cells_per_account: HashMap::new(),
+            terminations: Vec::new(),
         }
     }
 
@@ -110,6 +137,7 @@ impl DebugInfo {
         self.emitted_events.clear();
         self.emitted_debug_messages.clear();
         self.cells_per_account.clear();
+        self.terminations.clear();
     }
 
     /// Increases the number of storage writes for the supplied account by one.
@@ -168,6 +196,32 @@ impl DebugInfo {
     pub fn record_event(&mut self, event: EmittedEvent) {
         self.emitted_events.push(event);
     }
+
+    /// Records the termination of a contract.
+    pub fn record_termination(&mut self, termination: ContractTermination) {
+        self.terminations.push(termination);
+    }
+
+    /// Removes the storage cells recorded for the account and returns their keys.
+    pub fn remove_cells_for_account(&mut self, account_id: AccountId) -> Vec<Vec<u8>> {
+        self.cells_per_account
+            .remove(&account_id)
+            .map(|cells| cells.into_keys().collect())
+            .unwrap_or_default()
+    }
+}
+
+/// A snapshot of the state of the engine.
+///
+/// Taken via [`Engine::snapshot`] and restored via [`Engine::restore`].
+#[derive(Clone)]
+pub struct Snapshot {
+    /// The contract storage, balances and code hashes.
+    database: Database,
+    /// The events emitted so far.
+    emitted_events: Vec<EmittedEvent>,
+    /// The storage cells used by each account.
+    cells_per_account: HashMap<AccountId, HashMap<Vec<u8>, bool>>,
 }
 
 impl Engine {
@@ -175,7 +229,30 @@ impl Engine {
     pub fn initialize_or_reset(&mut self) {
         self.exec_context.reset();
         self.database.clear();
+        self.mocked_messages.clear();
         self.debug_info.reset();
+        self.gas_meter.reset_gas_limit();
+        self.tracer.disable();
+        self.set_storage_deposit_prices(0, 0);
+    }
+
+    /// Takes a snapshot of the contract storage, the balances and the emitted events.
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            database: self.database.clone(),
+            emitted_events: self.debug_info.emitted_events.clone(),
+            cells_per_account: self.debug_info.cells_per_account.clone(),
+        }
+    }
+
+    /// Reverts the contract storage, the balances and the emitted events to the
+    /// state of the `snapshot`.
+    ///
+    /// The same snapshot can be restored multiple times.
+    pub fn restore(&mut self, snapshot: &Snapshot) {
+        self.database = snapshot.database.clone();
+        self.debug_info.emitted_events = snapshot.emitted_events.clone();
+        self.debug_info.cells_per_account = snapshot.cells_per_account.clone();
     }
 
     /// Returns the total number of reads and writes of the contract's storage.
@@ -220,12 +297,94 @@ impl Engine {
         Ok(cells.len())
     }
 
+    /// Returns the raw value stored under `key` in the storage of `account_id`.
+    ///
+    /// In contrast to [`Engine::get_storage`] neither reads nor gas are recorded.
+    pub fn get_contract_storage_raw(
+        &self,
+        account_id: &[u8],
+        key: &[u8],
+    ) -> Option<Vec<u8>> {
+        self.database
+            .get_from_contract_storage(account_id, key)
+            .cloned()
+    }
+
+    /// Returns the keys and raw values of all storage cells used by `account_id`,
+    /// ordered by their keys.
+    pub fn contract_storage_cells(&self, account_id: &[u8]) -> Vec<(Vec<u8>, Vec<u8>)> {
+        let mut cells = self
+            .debug_info
+            .cells_per_account
+            .get(&account_id.to_owned().into())
+            .map(|cells| {
+                cells
+                    .keys()
+                    .filter_map(|key| {
+                        self.get_contract_storage_raw(account_id, key)
+                            .map(|value| (key.clone(), value))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        cells.sort();
+        cells
+    }
+
     /// Advances the chain by a single block.
     pub fn advance_block(&mut self) {
+        self.finish_block();
         self.exec_context.block_number += 1;
         self.exec_context.block_timestamp += self.chain_spec.block_time;
     }
 
+    /// Records the hash of the current block before a new block is started.
+    ///
+    /// The entropy of the new block is derived from the entropy of the finished
+    /// block, so that seeded tests observe the same entropy across blocks.
+    fn finish_block(&mut self) {
+        let finished_block = (
+            self.exec_context.block_number,
+            self.exec_context.block_timestamp,
+            self.exec_context.entropy,
+        );
+        let mut block_hash = [0u8; 32];
+        Engine::hash_blake2_256(&scale::Encode::encode(&finished_block), &mut block_hash);
+        self.exec_context.block_hashes.push(block_hash);
+        let mut entropy = [0u8; 32];
+        Engine::hash_blake2_256(&self.exec_context.entropy, &mut entropy);
+        self.exec_context.entropy = entropy;
+    }
+
+    /// Advances the chain by `n` blocks.
+    pub fn advance_blocks(&mut self, n: BlockNumber) {
+        for _ in 0..n {
+            self.advance_block();
+        }
+    }
+
+    /// Sets the number of the current block.
+    ///
+    /// Hashes are recorded for blocks that are skipped, while the hashes of blocks
+    /// that are rewound are forgotten.
+    pub fn set_block_number(&mut self, block_number: BlockNumber) {
+        self.exec_context
+            .block_hashes
+            .truncate(block_number as usize);
+        while self.exec_context.block_number < block_number {
+            self.finish_block();
+            self.exec_context.block_number += 1;
+        }
+        self.exec_context.block_number = block_number;
+    }
+
+    /// Sets the timestamp of the current block.
+    ///
+    /// Subsequent blocks advance from this timestamp.
+    pub fn set_block_timestamp(&mut self, block_timestamp: BlockTimestamp) {
+        self.exec_context.block_timestamp = block_timestamp;
+    }
+
     /// Returns the callee, i.e. the currently executing contract.
     pub fn get_callee(&self) -> Vec<u8> {
         self.exec_context.callee()
@@ -241,6 +400,28 @@ impl Engine {
         self.debug_info.emitted_events.clone().into_iter()
     }
 
+    /// Returns the recorded contract terminations in order.
+    pub fn recorded_terminations(&self) -> impl Iterator<Item = ContractTermination> {
+        self.debug_info.terminations.clone().into_iter()
+    }
+
+    /// Starts recording the calls into the environment.
+    ///
+    /// Calls recorded by a previous trace are discarded.
+    pub fn start_tracing(&mut self) {
+        self.tracer.enable();
+    }
+
+    /// Stops recording the calls into the environment and returns the trace.
+    pub fn stop_tracing(&mut self) -> Vec<EnvCall> {
+        self.tracer.disable()
+    }
+
+    /// Returns the calls into the environment recorded since tracing was started.
+    pub fn recorded_calls(&self) -> Vec<EnvCall> {
+        self.tracer.calls().to_vec()
+    }
+
     /// Returns the current balance of `account_id`.
     pub fn get_balance(&self, account_id: Vec<u8>) -> Result<Balance, Error> {
         self.database
@@ -257,6 +438,66 @@ impl Engine {
     pub fn set_value_transferred(&mut self, value: Balance) {
         self.exec_context.value_transferred = value;
     }
+
+    /// Sets the instantiation nonce of the chain.
+    pub fn set_instantiation_nonce(&mut self, nonce: u64) {
+        self.exec_context.instantiation_nonce = nonce;
+    }
+
+    /// Returns the storage deposit held by the contract `account_id`.
+    pub fn storage_deposit_of(&self, account_id: &[u8]) -> Balance {
+        self.database.get_storage_deposit(account_id)
+    }
+
+    /// Sets the storage deposit charged per storage cell and per byte of a cell.
+    ///
+    /// Both default to `0`, i.e. no storage deposits are charged.
+    pub fn set_storage_deposit_prices(&mut self, per_item: Balance, per_byte: Balance) {
+        self.chain_spec.deposit_per_item = per_item;
+        self.chain_spec.deposit_per_byte = per_byte;
+    }
+
+    /// Returns the gas consumed by host function calls so far.
+    pub fn gas_consumed(&self) -> u64 {
+        self.gas_meter.consumed()
+    }
+
+    /// Sets the costs charged for host function calls from now on.
+    pub fn set_gas_schedule(&mut self, schedule: GasSchedule) {
+        self.gas_meter.schedule = schedule;
+    }
+
+    /// Limits the gas reported by [`Engine::gas_left`] for the next call.
+    pub fn set_gas_limit(&mut self, gas_limit: u64) {
+        self.gas_meter.set_gas_limit(gas_limit);
+    }
+
+    /// Removes the gas limit reported by [`Engine::gas_left`].
+    pub fn reset_gas_limit(&mut self) {
+        self.gas_meter.reset_gas_limit();
+    }
+
+    /// Sets the entropy of the current block used by [`Engine::random`].
+    ///
+    /// The entropy of subsequent blocks is derived from it.
+    pub fn set_block_entropy(&mut self, entropy: [u8; 32]) {
+        self.exec_context.entropy = entropy;
+    }
+
+    /// Reseeds the host random number generator used by [`Engine::getrandom`].
+    pub fn set_host_rng_seed(&mut self, seed: [u8; 32]) {
+        self.host_rng = StdRng::from_seed(seed);
+    }
+
+    /// Marks `account_id` as a contract instantiated from the code at `code_hash`.
+    pub fn set_code_hash(&mut self, account_id: &[u8], code_hash: &[u8]) {
+        self.database.set_code_hash(account_id, code_hash);
+    }
+
+    /// Sets the secret key sealed into the execution environment.
+    pub fn set_sealed_key(&mut self, sealed_key: [u8; 32]) {
+        self.exec_context.sealed_key = sealed_key;
+    }
 }
 
 #[cfg(test)]
@@ -12,9 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::ext::{
-    Engine,
-    Error,
+use crate::{
+    ext::{
+        Engine,
+        Error,
+    },
+    test_api::ContractTermination,
+    EnvCall,
+    GasSchedule,
+    StorageDeposit,
 };
 use secp256k1::{
     ecdsa::RecoverableSignature,
@@ -272,3 +278,565 @@ fn ecdsa_recovery_with_secp256k1_crate() {
     // then
     assert_eq!(output, pubkey.serialize());
 }
+
+#[test]
+fn sr25519_verify_test() {
+    // given
+    let mut engine = Engine::new();
+    #[rustfmt::skip]
+    let signature: [u8; 64] = [
+        216, 116,  36,  32,  34, 237, 104, 140,  22, 184, 107,  86,  33, 234, 106, 188,
+        221,  18, 171, 114, 147, 153, 131, 167,   8, 208, 178, 248,  64, 159, 113,  85,
+        114, 103,  20, 185, 219,  23, 120, 200,  60,  82,  37,  40,  80,  61,  21, 157,
+         69, 198, 123, 197,  80, 185, 200, 194, 189,  12,   4, 206,  78, 133,  92, 139,
+    ];
+    #[rustfmt::skip]
+    let pub_key: [u8; 32] = [
+         96,  35, 208,  82, 113,  31,  71, 139, 199,  88, 145, 139, 221,  38, 226,  74,
+        197, 215, 115,  98, 159, 201,   2, 223, 176, 157, 226,  88, 139, 164, 171,   8,
+    ];
+    let message = b"hello ink!";
+
+    // when
+    let result = engine.sr25519_verify(&signature, message, &pub_key);
+
+    // then
+    assert_eq!(result, Ok(()));
+    assert_eq!(
+        engine.sr25519_verify(&signature, b"hello world!", &pub_key),
+        Err(Error::Sr25519VerifyFailed)
+    );
+    assert_eq!(
+        engine.sr25519_verify(&signature, message, &[0x01; 32]),
+        Err(Error::Sr25519VerifyFailed)
+    );
+}
+
+#[test]
+fn derive_key_is_deterministic() {
+    // given
+    let mut engine = Engine::new();
+    let mut key_a = [0; 32];
+    let mut key_b = [0; 32];
+    let mut other = [0; 32];
+
+    // when
+    engine.derive_key(b"salt", &mut key_a);
+    engine.derive_key(b"salt", &mut key_b);
+    engine.derive_key(b"pepper", &mut other);
+
+    // then
+    assert_eq!(key_a, key_b);
+    assert_ne!(key_a, other);
+    engine.set_sealed_key([0x02; 32]);
+    engine.derive_key(b"salt", &mut key_b);
+    assert_ne!(key_a, key_b);
+}
+
+#[test]
+fn sign_sr25519_verifies() {
+    use schnorrkel::MiniSecretKey;
+
+    // given
+    let mut engine = Engine::new();
+    let pub_key = MiniSecretKey::from_bytes(&[0x01; 32])
+        .unwrap()
+        .expand_to_keypair(MiniSecretKey::ED25519_MODE)
+        .public
+        .to_bytes();
+    let mut signature = [0; 64];
+
+    // when
+    let result = engine.sign_sr25519(b"hello ink!", &mut signature);
+
+    // then
+    assert_eq!(result, Ok(()));
+    assert_eq!(
+        engine.sr25519_verify(&signature, b"hello ink!", &pub_key),
+        Ok(())
+    );
+    assert_eq!(
+        engine.sr25519_verify(&signature, b"hello world!", &pub_key),
+        Err(Error::Sr25519VerifyFailed)
+    );
+}
+
+#[test]
+fn sign_ecdsa_recovers() {
+    // given
+    let mut engine = Engine::new();
+    let secret_key = SecretKey::from_slice(&[0x01; 32]).unwrap();
+    let pubkey = PublicKey::from_secret_key(SECP256K1, &secret_key);
+    let mut signature = [0; 65];
+    let mut message_hash = [0; 32];
+    Engine::hash_blake2_256(b"hello ink!", &mut message_hash);
+    let mut output = [0; 33];
+
+    // when
+    let result = engine.sign_ecdsa(b"hello ink!", &mut signature);
+
+    // then
+    assert_eq!(result, Ok(()));
+    engine
+        .ecdsa_recover(&signature, &message_hash, &mut output)
+        .expect("ecdsa recovery failed");
+    assert_eq!(output, pubkey.serialize());
+}
+
+#[test]
+fn sign_ecdsa_fails_for_invalid_sealed_key() {
+    // given
+    let mut engine = Engine::new();
+    // Exceeds the order of the secp256k1 curve.
+    engine.set_sealed_key([0xFF; 32]);
+    let mut signature = [0; 65];
+
+    // when
+    let result = engine.sign_ecdsa(b"hello ink!", &mut signature);
+
+    // then
+    assert_eq!(result, Err(Error::InvalidSealedKey));
+    assert_eq!(signature, [0; 65]);
+}
+
+#[test]
+fn setting_block_number_keeps_block_hashes() {
+    // given
+    let mut engine = Engine::new();
+    engine.advance_blocks(3);
+    let mut first = [0; 32];
+    engine
+        .block_hash(&scale::Encode::encode(&0u32), &mut &mut first[..])
+        .expect("hash of a past block must exist");
+
+    // when
+    engine.set_block_number(1);
+    let output = &mut &mut get_buffer()[..];
+    assert_eq!(
+        engine.block_hash(&scale::Encode::encode(&1u32), output),
+        Err(Error::KeyNotFound)
+    );
+    engine.set_block_number(3);
+
+    // then
+    let output = &mut &mut get_buffer()[..];
+    assert_eq!(
+        engine.block_hash(&scale::Encode::encode(&2u32), output),
+        Ok(())
+    );
+    let mut hash = [0; 32];
+    engine
+        .block_hash(&scale::Encode::encode(&0u32), &mut &mut hash[..])
+        .expect("hash of a past block must exist");
+    assert_eq!(first, hash);
+}
+
+#[test]
+fn setting_block_timestamp_works() {
+    use scale::Decode;
+
+    // given
+    let mut engine = Engine::new();
+    let output = &mut &mut get_buffer()[..];
+
+    // when
+    engine.set_block_timestamp(1_000);
+    engine.advance_block();
+
+    // then
+    engine.block_timestamp(output);
+    assert_eq!(<u64 as Decode>::decode(&mut &output[..]), Ok(1_006));
+}
+
+#[test]
+fn local_cache_entries_expire() {
+    use scale::Decode;
+
+    // given
+    let mut engine = Engine::new();
+    engine.set_callee(vec![1; 32]);
+    engine.cache_set(b"short", b"lived", 6);
+    engine.cache_set(b"long", b"lived", 60);
+
+    // when
+    engine.advance_block();
+
+    // then
+    let output = &mut &mut get_buffer()[..];
+    assert_eq!(engine.cache_get(b"short", output), Err(Error::KeyNotFound));
+    assert_eq!(engine.cache_get(b"long", output), Ok(()));
+    assert_eq!(
+        <Vec<u8> as Decode>::decode(&mut &output[..]),
+        Ok(b"lived".to_vec())
+    );
+}
+
+#[test]
+fn local_cache_is_separated_by_contract() {
+    // given
+    let mut engine = Engine::new();
+    engine.set_callee(vec![1; 32]);
+    engine.cache_set(b"key", b"value", 60);
+
+    // when
+    engine.set_callee(vec![2; 32]);
+
+    // then
+    let output = &mut &mut get_buffer()[..];
+    assert_eq!(engine.cache_get(b"key", output), Err(Error::KeyNotFound));
+    engine.set_callee(vec![1; 32]);
+    assert_eq!(engine.cache_remove(b"key", output), Ok(()));
+    assert_eq!(engine.cache_remove(b"key", output), Err(Error::KeyNotFound));
+}
+
+#[test]
+fn getrandom_is_deterministic() {
+    // given
+    let mut engine = Engine::new();
+    let mut other_engine = Engine::new();
+    let mut first = [0; 32];
+    let mut second = [0; 32];
+
+    // when
+    engine.getrandom(&mut first);
+    other_engine.getrandom(&mut second);
+
+    // then
+    assert_eq!(first, second);
+    engine.getrandom(&mut second);
+    assert_ne!(first, second);
+    engine.set_host_rng_seed([1; 32]);
+    engine.getrandom(&mut second);
+    assert_ne!(first, second);
+}
+
+#[test]
+fn seeded_block_entropy_is_deterministic() {
+    // given
+    let mut engine = Engine::new();
+    let mut other_engine = Engine::new();
+    engine.set_block_entropy([7; 32]);
+    other_engine.set_block_entropy([7; 32]);
+    let mut first = [0; 32];
+    let mut second = [0; 32];
+
+    // when
+    engine.random(b"subject", &mut &mut first[..]);
+    other_engine.random(b"subject", &mut &mut second[..]);
+
+    // then
+    assert_eq!(first, second);
+    engine.advance_block();
+    other_engine.advance_block();
+    engine.random(b"subject", &mut &mut first[..]);
+    assert_ne!(first, second);
+    other_engine.random(b"subject", &mut &mut second[..]);
+    assert_eq!(first, second);
+}
+
+#[test]
+fn calling_non_contract_accounts_fails() {
+    // given
+    let mut engine = Engine::new();
+    let code_hash = vec![0x11; 32];
+    let account_id = vec![0x42; 32];
+    let output = &mut &mut get_buffer()[..];
+
+    // when
+    assert!(!engine.is_contract(&account_id));
+    assert_eq!(
+        engine
+            .prepare_call(&account_id, 0, &[0; 16], &[], output)
+            .err(),
+        Some(Error::NotCallable)
+    );
+    engine.set_code_hash(&account_id, &code_hash);
+
+    // then
+    assert!(engine.is_contract(&account_id));
+    let mut hash = [0; 32];
+    engine
+        .code_hash(&account_id, &mut hash[..])
+        .expect("code hash must have been set");
+    assert_eq!(hash.to_vec(), code_hash);
+    let output = &mut &mut get_buffer()[..];
+    assert_eq!(
+        engine
+            .prepare_call(&account_id, 0, &[0; 16], &[], output)
+            .err(),
+        Some(Error::CodeNotFound)
+    );
+    assert!(engine.caller_is_origin());
+}
+
+#[test]
+fn gas_is_charged_for_host_functions() {
+    // given
+    let mut engine = Engine::new();
+    engine.set_callee(vec![1; 32]);
+    let key: &[u8; 32] = &[0x42; 32];
+    assert_eq!(engine.gas_consumed(), 0);
+
+    // when
+    engine.set_storage(key, &[0x05; 10]);
+    let small_write = engine.gas_consumed();
+    engine.set_storage(key, &[0x05; 1_000]);
+    let large_write = engine.gas_consumed() - small_write;
+
+    // then
+    let schedule = GasSchedule::default();
+    assert_eq!(
+        small_write,
+        schedule.host_fn + schedule.set_storage + 10 * schedule.storage_per_byte
+    );
+    assert!(large_write > small_write);
+    engine.set_gas_schedule(GasSchedule {
+        host_fn: 1,
+        ..GasSchedule::default()
+    });
+    let before = engine.gas_consumed();
+    engine.address(&mut &mut get_buffer()[..]);
+    assert_eq!(engine.gas_consumed() - before, 1);
+}
+
+#[test]
+fn gas_left_is_reported_against_gas_limit() {
+    // given
+    let mut engine = Engine::new();
+    engine.set_callee(vec![1; 32]);
+    engine.set_gas_schedule(GasSchedule {
+        host_fn: 1,
+        ..GasSchedule::default()
+    });
+    engine.address(&mut &mut get_buffer()[..]);
+
+    // when
+    engine.set_gas_limit(100);
+    engine.address(&mut &mut get_buffer()[..]);
+    let output = &mut &mut get_buffer()[..];
+    engine.gas_left(output);
+
+    // then
+    let (gas_left, _proof_size) =
+        <(scale::Compact<u64>, scale::Compact<u64>) as scale::Decode>::decode(
+            &mut &output[..],
+        )
+        .unwrap();
+    assert_eq!(gas_left.0, 98);
+}
+
+#[test]
+fn raw_contract_storage_can_be_inspected() {
+    // given
+    let mut engine = Engine::new();
+    let account_id = vec![1; 32];
+    engine.set_callee(account_id.clone());
+    engine.set_storage(&[0x02; 32], &[0x05; 2]);
+    engine.set_storage(&[0x01; 32], &[0x06; 1]);
+    engine.set_storage(&[0x03; 32], &[0x07; 3]);
+
+    // when
+    engine.clear_storage(&[0x03; 32]);
+
+    // then
+    assert_eq!(engine.count_reads(), 0);
+    assert_eq!(
+        engine.get_contract_storage_raw(&account_id, &[0x02; 32]),
+        Some(vec![0x05; 2])
+    );
+    assert_eq!(
+        engine.get_contract_storage_raw(&account_id, &[0x03; 32]),
+        None
+    );
+    assert_eq!(
+        engine.contract_storage_cells(&account_id),
+        vec![
+            (vec![0x01; 32], vec![0x06; 1]),
+            (vec![0x02; 32], vec![0x05; 2]),
+        ]
+    );
+    assert!(engine.contract_storage_cells(&[2; 32]).is_empty());
+}
+
+#[test]
+fn terminate_removes_contract() {
+    // given
+    let mut engine = Engine::new();
+    let contract = vec![1; 32];
+    let beneficiary = vec![2; 32];
+    engine.set_callee(contract.clone());
+    engine.set_balance(contract.clone(), 1_000);
+    engine.set_balance(beneficiary.clone(), 10);
+    engine.set_code_hash(&contract, &[0x11; 32]);
+    engine.set_storage(&[0x42; 32], &[0x05; 2]);
+
+    // when
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        engine.terminate(&beneficiary)
+    }));
+
+    // then
+    let termination = result
+        .expect_err("contract did not terminate")
+        .downcast::<ContractTermination>()
+        .expect("unexpected panic payload");
+    let expected = ContractTermination {
+        contract: contract.clone(),
+        beneficiary: beneficiary.clone(),
+        value_transferred: 1_000,
+    };
+    assert_eq!(*termination, expected);
+    assert_eq!(
+        engine.recorded_terminations().collect::<Vec<_>>(),
+        vec![expected]
+    );
+    assert_eq!(engine.get_balance(beneficiary).ok(), Some(1_010));
+    assert!(engine.get_balance(contract.clone()).is_err());
+    assert!(!engine.is_contract(&contract));
+    assert!(engine.contract_storage_cells(&contract).is_empty());
+    assert_eq!(
+        engine.get_contract_storage_raw(&contract, &[0x42; 32]),
+        None
+    );
+}
+
+#[test]
+fn storage_deposits_are_charged_and_refunded() {
+    // given
+    let mut engine = Engine::new();
+    let alice = vec![1; 32];
+    let contract = vec![2; 32];
+    engine.set_caller(alice.clone());
+    engine.set_callee(contract.clone());
+    engine.set_balance(alice.clone(), 1_000);
+    engine.set_storage_deposit_prices(100, 10);
+
+    // when
+    engine.set_storage(&[0x01; 32], &[0x05; 4]);
+    engine.set_storage(&[0x02; 32], &[0x06; 2]);
+
+    // then
+    assert_eq!(engine.storage_deposit_of(&contract), 260);
+    assert_eq!(engine.get_balance(alice.clone()), Ok(740));
+
+    // when
+    engine.set_storage(&[0x01; 32], &[0x05; 1]);
+    engine.clear_storage(&[0x02; 32]);
+
+    // then
+    assert_eq!(engine.storage_deposit_of(&contract), 110);
+    assert_eq!(engine.get_balance(alice), Ok(890));
+}
+
+#[test]
+fn dry_run_reports_storage_deposit_and_reverts_effects() {
+    // given
+    let mut engine = Engine::new();
+    let alice = vec![1; 32];
+    let contract = vec![2; 32];
+    engine.set_caller(alice.clone());
+    engine.set_callee(contract.clone());
+    engine.set_balance(alice.clone(), 1_000);
+    engine.set_storage_deposit_prices(100, 10);
+    engine.set_storage(&[0x01; 32], &[0x05; 4]);
+    let gas_consumed = engine.gas_consumed();
+
+    // when
+    let ((), charged) = engine.dry_run(|engine| {
+        engine.set_storage(&[0x02; 32], &[0x06; 2]);
+    });
+    let ((), refunded) = engine.dry_run(|engine| {
+        engine.set_storage(&[0x02; 32], &[0x06; 2]);
+        engine.clear_storage(&[0x01; 32]);
+    });
+
+    // then
+    assert_eq!(charged.storage_deposit, StorageDeposit::Charge(120));
+    assert_eq!(refunded.storage_deposit, StorageDeposit::Refund(20));
+    assert!(charged.gas_consumed > 0);
+    assert_eq!(
+        engine.gas_consumed(),
+        gas_consumed + charged.gas_consumed + refunded.gas_consumed
+    );
+    assert_eq!(engine.storage_deposit_of(&contract), 140);
+    assert_eq!(engine.get_balance(alice), Ok(860));
+    assert_eq!(engine.contract_storage_cells(&contract).len(), 1);
+}
+
+#[test]
+fn env_calls_are_traced_while_enabled() {
+    // given
+    let mut engine = Engine::new();
+    let contract = vec![1; 32];
+    let bob = vec![2; 32];
+    engine.set_callee(contract.clone());
+    engine.set_balance(contract.clone(), 1_000);
+    engine.set_storage(&[0x01; 32], &[0x05]);
+
+    // when
+    engine.start_tracing();
+    engine.set_storage(&[0x42; 32], &[0x06, 0x07]);
+    let _ = engine.get_storage(&[0x42; 32], &mut &mut get_buffer()[..]);
+    engine.clear_storage(&[0x42; 32]);
+    engine
+        .transfer(&bob, &scale::Encode::encode(&10u128)[..])
+        .expect("transfer failed");
+    let trace = engine.stop_tracing();
+    engine.set_storage(&[0x43; 32], &[0x08]);
+
+    // then
+    let key = vec![0x42; 32];
+    assert_eq!(
+        trace,
+        vec![
+            EnvCall::SetStorage {
+                contract: contract.clone(),
+                key: key.clone(),
+                value: vec![0x06, 0x07],
+            },
+            EnvCall::GetStorage {
+                contract: contract.clone(),
+                key: key.clone(),
+            },
+            EnvCall::ClearStorage {
+                contract: contract.clone(),
+                key,
+            },
+            EnvCall::Transfer {
+                contract,
+                to: bob,
+                value: 10,
+            },
+        ]
+    );
+    assert!(engine.recorded_calls().is_empty());
+}
+
+#[test]
+fn restoring_snapshot_reverts_state() {
+    // given
+    let mut engine = Engine::new();
+    engine.set_callee(vec![1; 32]);
+    engine.set_balance(vec![1; 32], 1_000);
+    let key: &[u8; 32] = &[0x42; 32];
+    engine.set_storage(key, &[0x05; 5]);
+    let snapshot = engine.snapshot();
+
+    // when
+    engine.set_storage(key, &[0x07; 5]);
+    engine.set_storage(&[0x43; 32], &[0x07; 5]);
+    engine
+        .transfer(&[2; 32], &scale::Encode::encode(&100u128))
+        .unwrap();
+    engine.deposit_event(&[0], &[0x01]);
+    engine.restore(&snapshot);
+
+    // then
+    let output = &mut &mut get_buffer()[..];
+    engine.get_storage(key, output).unwrap();
+    assert_eq!(&output[..5], &[0x05; 5]);
+    assert_eq!(engine.get_balance(vec![1; 32]), Ok(1_000));
+    assert!(engine.get_balance(vec![2; 32]).is_err());
+    assert_eq!(engine.count_used_storage_cells(&[1; 32]), Ok(1));
+    assert_eq!(engine.get_emitted_events().count(), 0);
+}
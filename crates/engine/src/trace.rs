@@ -0,0 +1,139 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    ext::Engine,
+    types::Balance,
+};
+
+/// A call into the environment with side effects.
+///
+/// Accounts, keys and inputs are given in their SCALE encoding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnvCall {
+    /// The contract read the storage cell at `key`.
+    GetStorage { contract: Vec<u8>, key: Vec<u8> },
+    /// The contract wrote `value` into the storage cell at `key`.
+    SetStorage {
+        contract: Vec<u8>,
+        key: Vec<u8>,
+        value: Vec<u8>,
+    },
+    /// The contract cleared the storage cell at `key`.
+    ClearStorage { contract: Vec<u8>, key: Vec<u8> },
+    /// The contract transferred `value` to the account `to`.
+    Transfer {
+        contract: Vec<u8>,
+        to: Vec<u8>,
+        value: Balance,
+    },
+    /// The contract emitted an event.
+    DepositEvent {
+        contract: Vec<u8>,
+        topics: Vec<Vec<u8>>,
+        data: Vec<u8>,
+    },
+    /// The contract called the contract `callee`.
+    Call {
+        contract: Vec<u8>,
+        callee: Vec<u8>,
+        value: Balance,
+        input: Vec<u8>,
+    },
+    /// The contract executed the code at `code_hash` in its own context.
+    DelegateCall {
+        contract: Vec<u8>,
+        code_hash: Vec<u8>,
+        input: Vec<u8>,
+    },
+    /// The contract instantiated a contract from the code at `code_hash`.
+    Instantiate {
+        contract: Vec<u8>,
+        code_hash: Vec<u8>,
+        endowment: Balance,
+        input: Vec<u8>,
+        salt: Vec<u8>,
+    },
+    /// The contract terminated itself in favour of `beneficiary`.
+    Terminate {
+        contract: Vec<u8>,
+        beneficiary: Vec<u8>,
+    },
+}
+
+/// Records the calls into the environment while tracing is enabled.
+#[derive(Default)]
+pub struct CallTracer {
+    /// The calls recorded so far or `None` if tracing is disabled.
+    calls: Option<Vec<EnvCall>>,
+}
+
+impl CallTracer {
+    /// Creates a new call tracer with tracing disabled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enables tracing and forgets the calls recorded so far.
+    pub fn enable(&mut self) {
+        self.calls = Some(Vec::new());
+    }
+
+    /// Disables tracing and returns the recorded calls.
+    pub fn disable(&mut self) -> Vec<EnvCall> {
+        self.calls.take().unwrap_or_default()
+    }
+
+    /// Returns `true` if tracing is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.calls.is_some()
+    }
+
+    /// Returns the calls recorded so far.
+    pub fn calls(&self) -> &[EnvCall] {
+        self.calls.as_deref().unwrap_or_default()
+    }
+
+    /// Records the call built by `f` if tracing is enabled.
+    pub fn record<F>(&mut self, f: F)
+    where
+        F: FnOnce() -> EnvCall,
+    {
+        if let Some(calls) = &mut self.calls {
+            calls.push(f())
+        }
+    }
+}
+
+/// Decodes a SCALE encoded balance for the trace.
+///
+/// Yields `0` for malformed balances, the engine reports those separately.
+pub(crate) fn decode_balance(mut encoded: &[u8]) -> Balance {
+    <Balance as scale::Decode>::decode(&mut encoded).unwrap_or_default()
+}
+
+impl Engine {
+    /// Records the call built by `f` if tracing is enabled.
+    ///
+    /// `f` is given the SCALE encoded account of the executed contract.
+    pub(crate) fn trace<F>(&mut self, f: F)
+    where
+        F: FnOnce(Vec<u8>) -> EnvCall,
+    {
+        if self.tracer.is_enabled() {
+            let contract = self.get_callee();
+            self.tracer.record(|| f(contract))
+        }
+    }
+}
@@ -17,14 +17,18 @@
 use crate::{
     backend::{
         EnvBackend,
+        ExistenceRequirement,
         ReturnFlags,
         TypedEnvBackend,
     },
     call::{
+        utils::ConstructorReturnType,
         Call,
         CallParams,
         CreateParams,
         DelegateCall,
+        Estimate,
+        InstantiateError,
     },
     engine::{
         EnvInstance,
@@ -35,12 +39,33 @@ use crate::{
         HashOutput,
     },
     topics::Topics,
-    types::Gas,
+    types::{
+        Gas,
+        Weight,
+    },
     Environment,
+    Error,
     Result,
 };
 use ink_primitives::Key;
 
+#[cfg(feature = "http-request")]
+use crate::http::{
+    HttpRequest,
+    HttpResponse,
+};
+#[cfg(feature = "sealed-key")]
+use crate::sealed_key::KeyKind;
+#[cfg(feature = "http-request")]
+use ink_prelude::string::String;
+#[cfg(any(
+    feature = "http-request",
+    feature = "sealed-key",
+    feature = "local-cache",
+    feature = "host-random"
+))]
+use ink_prelude::vec::Vec;
+
 /// Returns the address of the caller of the executed contract.
 ///
 /// # Errors
@@ -83,12 +108,17 @@ where
     })
 }
 
-/// Returns the amount of gas left for the contract execution.
+/// Returns the weight left for the contract execution.
+///
+/// # Note
+///
+/// The returned [`Weight`] carries both the remaining computational time
+/// (`ref_time`) and the remaining proof size.
 ///
 /// # Errors
 ///
 /// If the returned value cannot be properly decoded.
-pub fn gas_left<E>() -> Gas
+pub fn gas_left<E>() -> Weight
 where
     E: Environment,
 {
@@ -97,6 +127,27 @@ where
     })
 }
 
+/// Returns the hash of the block with the given `block_number`.
+///
+/// # Note
+///
+/// Only the hashes of a window of recent blocks are kept by the runtime. The size
+/// of that window is a runtime configuration (e.g. `BlockHashCount` of the system pallet).
+/// Returns `None` for blocks outside of that window as well as for the current and
+/// future blocks since their hashes are not yet known.
+///
+/// # Errors
+///
+/// If the returned value cannot be properly decoded.
+pub fn block_hash<E>(block_number: E::BlockNumber) -> Option<E::Hash>
+where
+    E: Environment,
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        TypedEnvBackend::block_hash::<E>(instance, block_number)
+    })
+}
+
 /// Returns the current block timestamp.
 ///
 /// # Errors
@@ -183,6 +234,27 @@ where
     })
 }
 
+/// Emits an event with the given topics and raw event data.
+///
+/// # Note
+///
+/// This is the low-level counterpart to [`emit_event`] for events whose set of
+/// topics is only known at runtime. The `data` is forwarded as is, so it should
+/// usually be the SCALE encoded event.
+///
+/// # Panics
+///
+/// The contracts pallet traps if `topics` contains duplicates or exceeds the
+/// maximum number of topics per event of the chain.
+pub fn emit_event_with_topics<E>(topics: &[E::Hash], data: &[u8])
+where
+    E: Environment,
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        TypedEnvBackend::emit_event_with_topics::<E>(instance, topics, data)
+    })
+}
+
 /// Writes the value to the contract storage under the given key.
 ///
 /// # Panics
@@ -232,6 +304,7 @@ pub fn clear_contract_storage(key: &Key) {
 /// - If arguments passed to the called contract message are invalid.
 /// - If the called contract execution has trapped.
 /// - If the called contract ran out of gas upon execution.
+/// - If the called ink! message could not be dispatched, see [`Error::LangError`].
 /// - If the returned value failed to decode properly.
 pub fn invoke_contract<E, Args, R>(params: &CallParams<E, Call<E>, Args, R>) -> Result<R>
 where
@@ -256,6 +329,7 @@ where
 /// - If the specified code hash does not exist.
 /// - If arguments passed to the called code message are invalid.
 /// - If the called code execution has trapped.
+/// - If the called ink! message could not be dispatched, see [`Error::LangError`].
 pub fn invoke_contract_delegate<E, Args, R>(
     params: &CallParams<E, DelegateCall<E>, Args, R>,
 ) -> Result<R>
@@ -284,16 +358,74 @@ where
 /// - If the instantiation process runs out of gas.
 /// - If given insufficient endowment.
 /// - If the returned account ID failed to decode properly.
-pub fn instantiate_contract<E, Args, Salt, C>(
-    params: &CreateParams<E, Args, Salt, C>,
-) -> Result<E::AccountId>
+/// - If the fallible constructor failed, see [`InstantiateError::Constructor`].
+pub fn instantiate_contract<E, Args, Salt, C, RetType>(
+    params: &CreateParams<E, Args, Salt, C, RetType>,
+) -> core::result::Result<E::AccountId, InstantiateError<RetType::Error>>
 where
     E: Environment,
     Args: scale::Encode,
     Salt: AsRef<[u8]>,
+    RetType: ConstructorReturnType<C>,
 {
     <EnvInstance as OnInstance>::on_instance(|instance| {
-        TypedEnvBackend::instantiate_contract::<E, Args, Salt, C>(instance, params)
+        TypedEnvBackend::instantiate_contract::<E, Args, Salt, C, RetType>(
+            instance, params,
+        )
+    })
+}
+
+/// Performs a contract message call as a dry-run and returns the resources it consumed.
+///
+/// All state changes of the call are reverted afterwards.
+///
+/// # Note
+///
+/// Only the off-chain environment supports dry-runs. Contracts executed on-chain
+/// cannot revert a successful call and always receive [`Error::DryRunUnavailable`].
+///
+/// # Errors
+///
+/// - If the execution environment cannot perform dry-runs.
+/// - For all errors of [`invoke_contract`] except for decoding the returned value.
+pub fn estimate_contract<E, Args, R>(
+    params: &CallParams<E, Call<E>, Args, R>,
+) -> Result<Estimate<E::Balance>>
+where
+    E: Environment,
+    Args: scale::Encode,
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        TypedEnvBackend::estimate_contract::<E, Args, R>(instance, params)
+    })
+}
+
+/// Performs a contract instantiation as a dry-run and returns the resources it consumed.
+///
+/// The instantiated contract and all other state changes are reverted afterwards.
+///
+/// # Note
+///
+/// Only the off-chain environment supports dry-runs. Contracts executed on-chain
+/// cannot revert a successful instantiation and always receive
+/// [`Error::DryRunUnavailable`].
+///
+/// # Errors
+///
+/// - If the execution environment cannot perform dry-runs.
+/// - For all errors of [`instantiate_contract`].
+pub fn estimate_instantiate_contract<E, Args, Salt, C, RetType>(
+    params: &CreateParams<E, Args, Salt, C, RetType>,
+) -> Result<Estimate<E::Balance>>
+where
+    E: Environment,
+    Args: scale::Encode,
+    Salt: AsRef<[u8]>,
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        TypedEnvBackend::estimate_instantiate_contract::<E, Args, Salt, C, RetType>(
+            instance, params,
+        )
     })
 }
 
@@ -339,6 +471,43 @@ where
     })
 }
 
+/// Transfers value from the contract to the destination account ID while
+/// respecting the given [`ExistenceRequirement`].
+///
+/// # Note
+///
+/// - With [`ExistenceRequirement::KeepAlive`] the transfer is refused up front if it
+///   would leave less than the minimum balance in the contract.
+/// - With [`ExistenceRequirement::AllowDeath`] this is a plain [`transfer`] of `value`
+///   that may leave less than the minimum balance in the contract. Whether the contract
+///   is actually allowed to be reaped is up to the chain.
+///
+/// # Errors
+///
+/// - If the contract does not have sufficient free funds.
+/// - [`Error::TransferWouldReap`] if the transfer would bring the contract's balance
+///   below the minimum balance and [`ExistenceRequirement::KeepAlive`] was requested.
+pub fn transfer_with_existence<E>(
+    destination: E::AccountId,
+    value: E::Balance,
+    existence: ExistenceRequirement,
+) -> Result<()>
+where
+    E: Environment,
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        let balance = TypedEnvBackend::balance::<E>(instance);
+        let minimum_balance = TypedEnvBackend::minimum_balance::<E>(instance);
+        if existence == ExistenceRequirement::KeepAlive
+            && value <= balance
+            && balance - value < minimum_balance
+        {
+            return Err(Error::TransferWouldReap)
+        }
+        TypedEnvBackend::transfer::<E>(instance, destination, value)
+    })
+}
+
 /// Returns the execution input to the executed contract and decodes it as `T`.
 ///
 /// # Note
@@ -500,6 +669,251 @@ pub fn ecdsa_recover(
     })
 }
 
+/// Verifies a sr25519 `signature` of the given `message` against the public key `pub_key`.
+///
+/// # Note
+///
+/// The signature is expected to have been created with the `substrate` signing context,
+/// which is the case for signatures produced by Substrate accounts.
+///
+/// # Example
+///
+/// ```
+/// const signature: [u8; 64] = [
+///     216, 116,  36,  32,  34, 237, 104, 140,  22, 184, 107,  86,  33, 234, 106, 188,
+///     221,  18, 171, 114, 147, 153, 131, 167,   8, 208, 178, 248,  64, 159, 113,  85,
+///     114, 103,  20, 185, 219,  23, 120, 200,  60,  82,  37,  40,  80,  61,  21, 157,
+///      69, 198, 123, 197,  80, 185, 200, 194, 189,  12,   4, 206,  78, 133,  92, 139,
+/// ];
+/// const pub_key: [u8; 32] = [
+///      96,  35, 208,  82, 113,  31,  71, 139, 199,  88, 145, 139, 221,  38, 226,  74,
+///     197, 215, 115,  98, 159, 201,   2, 223, 176, 157, 226,  88, 139, 164, 171,   8,
+/// ];
+/// let message = b"hello ink!";
+/// assert!(ink_env::sr25519_verify(&signature, message, &pub_key).is_ok());
+/// assert_eq!(
+///     ink_env::sr25519_verify(&signature, b"hello world!", &pub_key),
+///     Err(ink_env::Error::Sr25519VerifyFailed),
+/// );
+/// ```
+///
+/// # Errors
+///
+/// - If the signature verification failed.
+pub fn sr25519_verify(
+    signature: &[u8; 64],
+    message: &[u8],
+    pub_key: &[u8; 32],
+) -> Result<()> {
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance.sr25519_verify(signature, message, pub_key)
+    })
+}
+
+/// Performs an HTTP request and returns the response.
+///
+/// This is only supported by runtimes that execute contracts off-chain and provide the
+/// chain extension method with the [`HTTP_REQUEST_FUNC_ID`][`crate::http::HTTP_REQUEST_FUNC_ID`].
+/// The off-chain environment dispatches the request to the chain extension method
+/// registered for this ID. Real HTTP requests are only performed off-chain after
+/// `test::register_http_client` which requires the `http-client` crate feature.
+///
+/// # Example
+///
+/// ```no_run
+/// let response = ink_env::http_request(
+///     "GET",
+///     "http://localhost:8080/price",
+///     &[("Accept".into(), "application/json".into())],
+///     &[],
+/// )
+/// .expect("HTTP request failed");
+/// assert_eq!(response.status_code, 200);
+/// ```
+///
+/// # Errors
+///
+/// - If the request could not be performed, e.g. due to an unreachable host.
+/// - [`Error::BufferTooSmall`] if the encoded response does not fit into the static
+///   buffer of the environment. Such responses trap the contract on-chain instead.
+/// - If the response could not be decoded.
+///
+/// # Note
+///
+/// Requires the `http-request` crate feature.
+#[cfg(feature = "http-request")]
+pub fn http_request(
+    method: &str,
+    url: &str,
+    headers: &[(String, String)],
+    body: &[u8],
+) -> Result<HttpResponse> {
+    let request = HttpRequest {
+        method: method.into(),
+        url: url.into(),
+        headers: headers.to_vec(),
+        body: body.to_vec(),
+    };
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        EnvBackend::http_request(instance, &request)
+    })
+}
+
+/// Signs `message` with the key sealed into the execution environment.
+///
+/// The signature scheme is selected via `key_kind`. All signatures of a contract are
+/// made with keys derived from the same sealed key, so that they can be verified
+/// against the public keys of the contract.
+///
+/// # Example
+///
+/// ```
+/// use ink_env::sealed_key::KeyKind;
+///
+/// let signature = ink_env::sign(KeyKind::Sr25519, b"hello ink!")
+///     .expect("no sealed key available");
+/// assert_eq!(signature.len(), 64);
+/// ```
+///
+/// # Errors
+///
+/// - If the execution environment provides no sealed key.
+/// - [`Error::InvalidSealedKey`] if the sealed key is not a valid secret key for
+///   `key_kind`.
+///
+/// # Note
+///
+/// Requires the `sealed-key` crate feature.
+#[cfg(feature = "sealed-key")]
+pub fn sign(key_kind: KeyKind, message: &[u8]) -> Result<Vec<u8>> {
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        EnvBackend::sign(instance, key_kind, message)
+    })
+}
+
+/// Derives a secret key from the key sealed into the execution environment.
+///
+/// The same `salt` always yields the same key, while different salts yield
+/// unrelated keys.
+///
+/// # Example
+///
+/// ```
+/// let key = ink_env::derive_key(b"my salt").expect("no sealed key available");
+/// assert_eq!(ink_env::derive_key(b"my salt"), Ok(key));
+/// assert_ne!(ink_env::derive_key(b"other salt"), Ok(key));
+/// ```
+///
+/// # Errors
+///
+/// - If the execution environment provides no sealed key.
+///
+/// # Note
+///
+/// Requires the `sealed-key` crate feature.
+#[cfg(feature = "sealed-key")]
+pub fn derive_key(salt: &[u8]) -> Result<[u8; 32]> {
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        EnvBackend::derive_key(instance, salt)
+    })
+}
+
+/// Stores `value` under `key` in the local cache of the contract.
+///
+/// The local cache holds non-consensus scratch data that is not part of the chain
+/// state. The entry expires after `ttl` has passed, measured in the unit of the
+/// block timestamp. Storing a value under an existing `key` replaces the entry.
+///
+/// # Example
+///
+/// ```
+/// # ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+/// ink_env::cache_set(b"price", b"42", 60_000)?;
+/// assert_eq!(ink_env::cache_get(b"price")?, Some(b"42".to_vec()));
+/// assert_eq!(ink_env::cache_remove(b"price")?, Some(b"42".to_vec()));
+/// assert_eq!(ink_env::cache_get(b"price")?, None);
+/// # Ok(())
+/// # }).unwrap();
+/// ```
+///
+/// # Errors
+///
+/// - If the execution environment refused to store the value.
+///
+/// # Note
+///
+/// Requires the `local-cache` crate feature.
+#[cfg(feature = "local-cache")]
+pub fn cache_set(key: &[u8], value: &[u8], ttl: u64) -> Result<()> {
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        EnvBackend::cache_set(instance, key, value, ttl)
+    })
+}
+
+/// Returns the value stored under `key` in the local cache of the contract.
+///
+/// Returns `None` if there is no entry or if it has expired.
+///
+/// # Errors
+///
+/// If the cached value could not be decoded.
+///
+/// # Note
+///
+/// Requires the `local-cache` crate feature.
+#[cfg(feature = "local-cache")]
+pub fn cache_get(key: &[u8]) -> Result<Option<Vec<u8>>> {
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        EnvBackend::cache_get(instance, key)
+    })
+}
+
+/// Removes the value stored under `key` from the local cache of the contract and
+/// returns it.
+///
+/// Returns `None` if there is no entry or if it has expired.
+///
+/// # Errors
+///
+/// If the cached value could not be decoded.
+///
+/// # Note
+///
+/// Requires the `local-cache` crate feature.
+#[cfg(feature = "local-cache")]
+pub fn cache_remove(key: &[u8]) -> Result<Option<Vec<u8>>> {
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        EnvBackend::cache_remove(instance, key)
+    })
+}
+
+/// Returns `len` random bytes of the random number generator of the host.
+///
+/// This is meant for execution environments that run contracts off-chain, e.g. inside
+/// a TEE, and provide secure randomness. In contrast to [`random`] the bytes are not
+/// derived from the chain state and are therefore not reproducible by other nodes.
+///
+/// The off-chain environment returns deterministic bytes, see
+/// [`set_host_rng_seed`][`crate::test::set_host_rng_seed`].
+///
+/// # Example
+///
+/// ```
+/// let nonce = ink_env::getrandom(16);
+/// assert_eq!(nonce.len(), 16);
+/// assert_ne!(ink_env::getrandom(16), nonce);
+/// ```
+///
+/// # Note
+///
+/// Requires the `host-random` crate feature.
+#[cfg(feature = "host-random")]
+pub fn getrandom(len: u32) -> Vec<u8> {
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        EnvBackend::getrandom(instance, len)
+    })
+}
+
 /// Checks whether the specified account is a contract.
 ///
 /// # Errors
@@ -563,3 +977,66 @@ where
         TypedEnvBackend::caller_is_origin::<E>(instance)
     })
 }
+
+/// Returns the instantiation nonce of the chain.
+///
+/// The nonce is incremented with every contract instantiation on the chain, which makes
+/// it suitable for deriving unique salts in factory contracts without keeping a counter
+/// in storage.
+pub fn instantiation_nonce<E>() -> u64
+where
+    E: Environment,
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        TypedEnvBackend::instantiation_nonce::<E>(instance)
+    })
+}
+
+/// Executes the XCM message `msg` locally with the contract as the origin.
+///
+/// The message is expected to be a `VersionedXcm` of the runtime, i.e. it is
+/// SCALE encoded and handed over to the XCM executor of the chain as is.
+///
+/// # Errors
+///
+/// - If the message could not be decoded by the runtime.
+/// - If the execution of the message failed, e.g. because of insufficient weight
+///   or funds. This is reported as [`Error::XcmExecutionFailed`][`crate::Error::XcmExecutionFailed`].
+///
+/// # Note
+///
+/// This function is not supported by the off-chain environment.
+pub fn xcm_execute<E, M>(msg: &M) -> Result<()>
+where
+    E: Environment,
+    M: scale::Encode,
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        TypedEnvBackend::xcm_execute::<E, M>(instance, msg)
+    })
+}
+
+/// Sends the XCM message `msg` to the destination `dest` with the contract as the origin.
+///
+/// The destination is expected to be a `VersionedMultiLocation` and the message a
+/// `VersionedXcm` of the runtime. Returns the hash of the sent XCM message.
+///
+/// # Errors
+///
+/// - If the destination or message could not be decoded by the runtime.
+/// - If the message could not be delivered to the destination.
+///   This is reported as [`Error::XcmSendFailed`][`crate::Error::XcmSendFailed`].
+///
+/// # Note
+///
+/// This function is not supported by the off-chain environment.
+pub fn xcm_send<E, D, M>(dest: &D, msg: &M) -> Result<[u8; 32]>
+where
+    E: Environment,
+    D: scale::Encode,
+    M: scale::Encode,
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        TypedEnvBackend::xcm_send::<E, D, M>(instance, dest, msg)
+    })
+}
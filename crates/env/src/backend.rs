@@ -14,10 +14,13 @@
 
 use crate::{
     call::{
+        utils::ConstructorReturnType,
         Call,
         CallParams,
         CreateParams,
         DelegateCall,
+        Estimate,
+        InstantiateError,
     },
     hash::{
         CryptoHash,
@@ -26,6 +29,7 @@ use crate::{
     topics::Topics,
     Environment,
     Result,
+    Weight,
 };
 use ink_primitives::Key;
 
@@ -47,7 +51,6 @@ impl ReturnFlags {
     }
 
     /// Returns the underlying `u32` representation.
-    #[cfg(not(feature = "std"))]
     pub(crate) fn into_u32(self) -> u32 {
         self.value
     }
@@ -160,6 +163,24 @@ impl CallFlags {
     }
 }
 
+/// Whether a balance transfer may reap the sending contract.
+///
+/// # Note
+///
+/// A contract account is reaped once its balance falls below the minimum
+/// balance of the chain.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ExistenceRequirement {
+    /// The transfer must leave at least the minimum balance in the contract.
+    #[default]
+    KeepAlive,
+    /// The transfer may reap the contract.
+    ///
+    /// Only the transferred value is moved, even if it leaves less than the
+    /// minimum balance in the contract.
+    AllowDeath,
+}
+
 /// Environmental contract functionality that does not require `Environment`.
 pub trait EnvBackend {
     /// Writes the value to the contract storage under the given key.
@@ -249,6 +270,18 @@ pub trait EnvBackend {
         output: &mut [u8; 33],
     ) -> Result<()>;
 
+    /// Verifies a sr25519 signature.
+    ///
+    /// # Errors
+    ///
+    /// - If the signature verification failed.
+    fn sr25519_verify(
+        &mut self,
+        signature: &[u8; 64],
+        message: &[u8],
+        pub_key: &[u8; 32],
+    ) -> Result<()>;
+
     /// Low-level interface to call a chain extension method.
     ///
     /// Returns the output of the chain extension of the specified type.
@@ -282,6 +315,69 @@ pub trait EnvBackend {
         E: From<ErrorCode>,
         F: FnOnce(u32) -> ::core::result::Result<(), ErrorCode>,
         D: FnOnce(&[u8]) -> ::core::result::Result<T, E>;
+
+    /// Performs the given HTTP request and returns the response.
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`http_request`][`crate::http_request`]
+    #[cfg(feature = "http-request")]
+    fn http_request(
+        &mut self,
+        request: &crate::http::HttpRequest,
+    ) -> Result<crate::http::HttpResponse>;
+
+    /// Signs `message` with the sealed key of the execution environment.
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`sign`][`crate::sign`]
+    #[cfg(feature = "sealed-key")]
+    fn sign(
+        &mut self,
+        key_kind: crate::sealed_key::KeyKind,
+        message: &[u8],
+    ) -> Result<ink_prelude::vec::Vec<u8>>;
+
+    /// Derives a secret key from the sealed key of the execution environment.
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`derive_key`][`crate::derive_key`]
+    #[cfg(feature = "sealed-key")]
+    fn derive_key(&mut self, salt: &[u8]) -> Result<[u8; 32]>;
+
+    /// Stores `value` under `key` in the local cache of the contract.
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`cache_set`][`crate::cache_set`]
+    #[cfg(feature = "local-cache")]
+    fn cache_set(&mut self, key: &[u8], value: &[u8], ttl: u64) -> Result<()>;
+
+    /// Returns the value stored under `key` in the local cache of the contract.
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`cache_get`][`crate::cache_get`]
+    #[cfg(feature = "local-cache")]
+    fn cache_get(&mut self, key: &[u8]) -> Result<Option<ink_prelude::vec::Vec<u8>>>;
+
+    /// Removes the value stored under `key` from the local cache of the contract.
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`cache_remove`][`crate::cache_remove`]
+    #[cfg(feature = "local-cache")]
+    fn cache_remove(&mut self, key: &[u8]) -> Result<Option<ink_prelude::vec::Vec<u8>>>;
+
+    /// Returns `len` random bytes of the random number generator of the host.
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`getrandom`][`crate::getrandom`]
+    #[cfg(feature = "host-random")]
+    fn getrandom(&mut self, len: u32) -> ink_prelude::vec::Vec<u8>;
 }
 
 /// Environmental contract functionality.
@@ -307,12 +403,22 @@ pub trait TypedEnvBackend: EnvBackend {
     /// For more details visit: [`weight_to_fee`][`crate::weight_to_fee`]
     fn weight_to_fee<E: Environment>(&mut self, gas: u64) -> E::Balance;
 
-    /// Returns the amount of gas left for the contract execution.
+    /// Returns the weight left for the contract execution.
     ///
     /// # Note
     ///
     /// For more details visit: [`gas_left`][`crate::gas_left`]
-    fn gas_left<E: Environment>(&mut self) -> u64;
+    fn gas_left<E: Environment>(&mut self) -> Weight;
+
+    /// Returns the hash of the block with the given `block_number`.
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`block_hash`][`crate::block_hash`]
+    fn block_hash<E: Environment>(
+        &mut self,
+        block_number: E::BlockNumber,
+    ) -> Option<E::Hash>;
 
     /// Returns the timestamp of the current block.
     ///
@@ -360,6 +466,15 @@ pub trait TypedEnvBackend: EnvBackend {
         E: Environment,
         Event: Topics + scale::Encode;
 
+    /// Emits an event with the given topics and raw event data.
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`emit_event_with_topics`][`crate::emit_event_with_topics`]
+    fn emit_event_with_topics<E>(&mut self, topics: &[E::Hash], data: &[u8])
+    where
+        E: Environment;
+
     /// Invokes a contract message and returns its result.
     ///
     /// # Note
@@ -393,10 +508,38 @@ pub trait TypedEnvBackend: EnvBackend {
     /// # Note
     ///
     /// For more details visit: [`instantiate_contract`][`crate::instantiate_contract`]
-    fn instantiate_contract<E, Args, Salt, C>(
+    fn instantiate_contract<E, Args, Salt, C, RetType>(
         &mut self,
-        params: &CreateParams<E, Args, Salt, C>,
-    ) -> Result<E::AccountId>
+        params: &CreateParams<E, Args, Salt, C, RetType>,
+    ) -> core::result::Result<E::AccountId, InstantiateError<RetType::Error>>
+    where
+        E: Environment,
+        Args: scale::Encode,
+        Salt: AsRef<[u8]>,
+        RetType: ConstructorReturnType<C>;
+
+    /// Performs a contract message call as a dry-run and returns the resources it consumed.
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`estimate_contract`][`crate::estimate_contract`]
+    fn estimate_contract<E, Args, R>(
+        &mut self,
+        params: &CallParams<E, Call<E>, Args, R>,
+    ) -> Result<Estimate<E::Balance>>
+    where
+        E: Environment,
+        Args: scale::Encode;
+
+    /// Performs a contract instantiation as a dry-run and returns the resources it consumed.
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`estimate_instantiate_contract`][`crate::estimate_instantiate_contract`]
+    fn estimate_instantiate_contract<E, Args, Salt, C, RetType>(
+        &mut self,
+        params: &CreateParams<E, Args, Salt, C, RetType>,
+    ) -> Result<Estimate<E::Balance>>
     where
         E: Environment,
         Args: scale::Encode,
@@ -465,4 +608,34 @@ pub trait TypedEnvBackend: EnvBackend {
     fn own_code_hash<E>(&mut self) -> Result<E::Hash>
     where
         E: Environment;
+
+    /// Returns the instantiation nonce of the chain.
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`instantiation_nonce`][`crate::instantiation_nonce`]
+    fn instantiation_nonce<E>(&mut self) -> u64
+    where
+        E: Environment;
+
+    /// Executes the given XCM message locally.
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`xcm_execute`][`crate::xcm_execute`]
+    fn xcm_execute<E, M>(&mut self, msg: &M) -> Result<()>
+    where
+        E: Environment,
+        M: scale::Encode;
+
+    /// Sends the given XCM message to the given destination.
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`xcm_send`][`crate::xcm_send`]
+    fn xcm_send<E, D, M>(&mut self, dest: &D, msg: &M) -> Result<[u8; 32]>
+    where
+        E: Environment,
+        D: scale::Encode,
+        M: scale::Encode;
 }
@@ -0,0 +1,93 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    hash::{
+        Blake2x256,
+        HashOutput,
+    },
+    Environment,
+};
+
+/// Computes the address of a contract instantiated by `deployer` from `code_hash`
+/// with the given SCALE encoded `input` data and `salt`.
+///
+/// This mirrors the address derivation of `pallet-contracts` and therefore allows
+/// to know the address of a contract before it has been instantiated.
+///
+/// # Note
+///
+/// The `input` is the full instantiation input, i.e. the selector of the
+/// constructor followed by its encoded arguments as produced by
+/// [`ExecutionInput`][`crate::call::ExecutionInput`].
+///
+/// # Example
+///
+/// ```
+/// # use ink_env::{DefaultEnvironment, AccountId, Hash};
+/// # use ink_env::call::{contract_address, ExecutionInput, Selector};
+/// let deployer = AccountId::from([0x01; 32]);
+/// let code_hash = Hash::from([0x42; 32]);
+/// let input = ExecutionInput::new(Selector::new([0xDE, 0xAD, 0xBE, 0xEF]))
+///     .push_arg(true);
+/// let input = scale::Encode::encode(&input);
+/// let address = contract_address::<DefaultEnvironment>(
+///     &deployer,
+///     &code_hash,
+///     &input,
+///     b"salt",
+/// );
+/// assert_ne!(
+///     address,
+///     contract_address::<DefaultEnvironment>(&deployer, &code_hash, &input, b"pepper"),
+/// );
+/// ```
+pub fn contract_address<E>(
+    deployer: &E::AccountId,
+    code_hash: &E::Hash,
+    input: &[u8],
+    salt: &[u8],
+) -> E::AccountId
+where
+    E: Environment,
+{
+    let mut entropy = <Blake2x256 as HashOutput>::Type::default();
+    crate::hash_encoded::<Blake2x256, _>(
+        &(b"contract_addr_v1", deployer, code_hash, input, salt),
+        &mut entropy,
+    );
+    <E::AccountId as scale::Decode>::decode(&mut TrailingZeroInput(&entropy[..]))
+        .expect("decoding from a trailing zero input never fails")
+}
+
+/// Input that yields its bytes followed by an infinite stream of zeros.
+///
+/// Used to decode account IDs of arbitrary size from a 256-bit hash.
+struct TrailingZeroInput<'a>(&'a [u8]);
+
+impl<'a> scale::Input for TrailingZeroInput<'a> {
+    fn remaining_len(&mut self) -> Result<Option<usize>, scale::Error> {
+        Ok(None)
+    }
+
+    fn read(&mut self, into: &mut [u8]) -> Result<(), scale::Error> {
+        let len = core::cmp::min(into.len(), self.0.len());
+        into[..len].copy_from_slice(&self.0[..len]);
+        for byte in &mut into[len..] {
+            *byte = 0;
+        }
+        self.0 = &self.0[len..];
+        Ok(())
+    }
+}
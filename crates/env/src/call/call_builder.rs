@@ -17,11 +17,15 @@ use crate::{
     call::{
         utils::{
             EmptyArgumentList,
+            RawArgs,
             ReturnType,
             Set,
+            TypedOutput,
             Unset,
         },
+        Estimate,
         ExecutionInput,
+        Selector,
     },
     types::Gas,
     Clear,
@@ -76,10 +80,22 @@ where
         &self.call_type.callee
     }
 
-    /// Returns the chosen gas limit for the called contract execution.
+    /// Returns the chosen `ref_time` limit for the called contract execution.
     #[inline]
-    pub(crate) fn gas_limit(&self) -> Gas {
-        self.call_type.gas_limit
+    pub(crate) fn ref_time_limit(&self) -> u64 {
+        self.call_type.ref_time_limit
+    }
+
+    /// Returns the chosen `proof_size` limit for the called contract execution.
+    #[inline]
+    pub(crate) fn proof_size_limit(&self) -> u64 {
+        self.call_type.proof_size_limit
+    }
+
+    /// Returns the chosen storage deposit limit for the called contract execution.
+    #[inline]
+    pub(crate) fn storage_deposit_limit(&self) -> Option<&E::Balance> {
+        self.call_type.storage_deposit_limit.as_ref()
     }
 
     /// Returns the transferred value for the called contract.
@@ -109,9 +125,22 @@ where
     /// Invokes the contract with the given built-up call parameters.
     ///
     /// Returns the result of the contract execution.
+    ///
+    /// # Note
+    ///
+    /// The output of the called ink! message is decoded from its
+    /// [`MessageResult`][`ink_primitives::MessageResult`] envelope. Errors of
+    /// the ink! language are returned as [`Error::LangError`][`crate::Error::LangError`].
     pub fn invoke(&self) -> Result<R, crate::Error> {
         crate::invoke_contract(self)
     }
+
+    /// Performs the call with the given built-up call parameters as a dry-run.
+    ///
+    /// Returns the weight and the storage deposit consumed by the call.
+    pub fn estimate(&self) -> Result<Estimate<E::Balance>, crate::Error> {
+        crate::estimate_contract(self)
+    }
 }
 
 impl<E, Args, R> CallParams<E, DelegateCall<E>, Args, R>
@@ -133,8 +162,8 @@ where
 ///
 /// # Example
 ///
-/// **Note:** The shown examples panic because there is currently no cross-calling
-///           support in the off-chain testing environment. However, this code
+/// **Note:** The shown examples panic because no contract has been registered at the
+///           called account in the off-chain testing environment. However, this code
 ///           should work fine in on-chain environments.
 ///
 /// ## Example 1: No Return Value
@@ -182,7 +211,8 @@ where
 /// return a `i32` value back to its caller. The called function:
 ///
 /// - has a selector equal to `0xDEADBEEF`
-/// - is provided with 5000 units of gas for its execution
+/// - is provided with 5000 units of `ref_time` and 1024 bytes of proof size
+///   for its execution
 /// - is provided with 10 units of transferred value for the contract instance
 /// - receives the following arguments in order
 ///    1. an `i32` with value `42`
@@ -199,7 +229,8 @@ where
 /// let my_return_value: i32 = build_call::<DefaultEnvironment>()
 ///     .call_type(Call::new()
 ///                 .callee(AccountId::from([0x42; 32]))
-///                 .gas_limit(5000))
+///                 .ref_time_limit(5000)
+///                 .proof_size_limit(1024))
 ///     .transferred_value(10)
 ///     .exec_input(
 ///         ExecutionInput::new(Selector::new([0xDE, 0xAD, 0xBE, 0xEF]))
@@ -239,6 +270,117 @@ where
 ///     .fire()
 ///     .unwrap();
 /// ```
+///
+/// ## Example 4: Call Flags
+///
+/// The below example shows a proxy-like call that forwards the input of the current
+/// contract to the callee, returns the callee's output directly to the original caller
+/// and allows the callee to call back into the current contract.
+///
+/// ```should_panic
+/// # use ::ink_env::{
+/// #     Environment,
+/// #     DefaultEnvironment,
+/// #     call::{build_call, Call, CallFlags},
+/// # };
+/// # type AccountId = <DefaultEnvironment as Environment>::AccountId;
+/// build_call::<DefaultEnvironment>()
+///     .call_type(Call::new().callee(AccountId::from([0x42; 32])))
+///     .call_flags(
+///         CallFlags::default()
+///             .set_forward_input(true)
+///             .set_tail_call(true)
+///             .set_allow_reentry(true),
+///     )
+///     .fire()
+///     .unwrap();
+/// ```
+///
+/// The commonly used call flags can also be set individually on the call builder,
+/// e.g. on the long-hand calls of an ink! smart contract reference:
+///
+/// ```should_panic
+/// # use ::ink_env::{
+/// #     Environment,
+/// #     DefaultEnvironment,
+/// #     call::{build_call, Call},
+/// # };
+/// # type AccountId = <DefaultEnvironment as Environment>::AccountId;
+/// build_call::<DefaultEnvironment>()
+///     .call_type(Call::new().callee(AccountId::from([0x42; 32])))
+///     .allow_reentry()
+///     .tail_call()
+///     .fire()
+///     .unwrap();
+/// ```
+///
+/// Forwarder contracts can hand their entire input to the callee and return its output
+/// directly using [`CallBuilder::forward_input`], which never returns:
+///
+/// ```should_panic
+/// # use ::ink_env::{
+/// #     Environment,
+/// #     DefaultEnvironment,
+/// #     call::{build_call, Call},
+/// # };
+/// # type AccountId = <DefaultEnvironment as Environment>::AccountId;
+/// build_call::<DefaultEnvironment>()
+///     .call_type(Call::new().callee(AccountId::from([0x42; 32])))
+///     .forward_input()
+/// ```
+///
+/// ## Example 5: Raw Execution Input
+///
+/// The below example shows forwarding of a call that is only known at runtime,
+/// e.g. because it has been submitted by a user. The execution input is built from
+/// a selector and the already encoded arguments of the call.
+///
+/// ```should_panic
+/// # use ::ink_env::{
+/// #     Environment,
+/// #     DefaultEnvironment,
+/// #     call::{build_call, Selector, Call},
+/// # };
+/// # type AccountId = <DefaultEnvironment as Environment>::AccountId;
+/// # let selector = [0xDE, 0xAD, 0xBE, 0xEF];
+/// # let input = scale::Encode::encode(&(42u8, true));
+/// let my_return_value: i32 = build_call::<DefaultEnvironment>()
+///     .call_type(Call::new().callee(AccountId::from([0x42; 32])))
+///     .exec_input_raw(Selector::new(selector), &input)
+///     .returns::<i32>()
+///     .fire()
+///     .unwrap();
+/// ```
+///
+/// ## Example 6: Typed Revert Errors
+///
+/// The below example shows calling of a message of another contract that returns
+/// `Result<i32, MyError>`. Since the called message reverts with its encoded error
+/// upon failure, the output is decoded into the declared error type of the callee.
+///
+/// ```should_panic
+/// # use ::ink_env::{
+/// #     Environment,
+/// #     DefaultEnvironment,
+/// #     call::{build_call, Selector, ExecutionInput, Call},
+/// # };
+/// # type AccountId = <DefaultEnvironment as Environment>::AccountId;
+/// #[derive(scale::Decode)]
+/// enum MyError {
+///     InsufficientBalance,
+/// }
+///
+/// let result = build_call::<DefaultEnvironment>()
+///     .call_type(Call::new().callee(AccountId::from([0x42; 32])))
+///     .exec_input(ExecutionInput::new(Selector::new([0xDE, 0xAD, 0xBE, 0xEF])))
+///     .returns::<i32>()
+///     .fire_typed::<Result<i32, MyError>>()
+///     .unwrap();
+/// match result {
+///     Ok(value) => { /* the callee succeeded */ }
+///     Err(MyError::InsufficientBalance) => { /* the callee reverted */ }
+/// }
+/// ```
 #[allow(clippy::type_complexity)]
 pub fn build_call<E>() -> CallBuilder<
     E,
@@ -259,10 +401,19 @@ where
 }
 
 /// The default call type for cross-contract calls. Performs a cross-contract call to `callee`
-/// with gas limit `gas_limit`, transferring `transferred_value` of currency.
+/// with the weight limits `ref_time_limit` and `proof_size_limit`, transferring
+/// `transferred_value` of currency.
+///
+/// # Note
+///
+/// A limit of `0` allows the callee to use all of the remaining weight in that dimension.
+/// Without a `storage_deposit_limit` the callee may lock as much of the caller's balance
+/// for storage deposits as it needs.
 pub struct Call<E: Environment> {
     callee: E::AccountId,
-    gas_limit: Gas,
+    ref_time_limit: u64,
+    proof_size_limit: u64,
+    storage_deposit_limit: Option<E::Balance>,
     transferred_value: E::Balance,
 }
 
@@ -270,7 +421,9 @@ impl<E: Environment> Default for Call<E> {
     fn default() -> Self {
         Call {
             callee: Default::default(),
-            gas_limit: Default::default(),
+            ref_time_limit: Default::default(),
+            proof_size_limit: Default::default(),
+            storage_deposit_limit: None,
             transferred_value: E::Balance::zero(),
         }
     }
@@ -291,16 +444,57 @@ where
     pub fn callee(self, callee: E::AccountId) -> Self {
         Call {
             callee,
-            gas_limit: self.gas_limit,
+            ref_time_limit: self.ref_time_limit,
+            proof_size_limit: self.proof_size_limit,
+            storage_deposit_limit: self.storage_deposit_limit,
             transferred_value: self.transferred_value,
         }
     }
 
     /// Sets the `gas_limit` for the current cross-contract call.
+    ///
+    /// # Note
+    ///
+    /// This is equivalent to [`Call::ref_time_limit`] and is kept for chains
+    /// still using one-dimensional weights.
     pub fn gas_limit(self, gas_limit: Gas) -> Self {
+        self.ref_time_limit(gas_limit)
+    }
+
+    /// Sets the `ref_time_limit` part of the weight limit for the current
+    /// cross-contract call.
+    pub fn ref_time_limit(self, ref_time_limit: u64) -> Self {
+        Call {
+            callee: self.callee,
+            ref_time_limit,
+            proof_size_limit: self.proof_size_limit,
+            storage_deposit_limit: self.storage_deposit_limit,
+            transferred_value: self.transferred_value,
+        }
+    }
+
+    /// Sets the `proof_size_limit` part of the weight limit for the current
+    /// cross-contract call.
+    pub fn proof_size_limit(self, proof_size_limit: u64) -> Self {
+        Call {
+            callee: self.callee,
+            ref_time_limit: self.ref_time_limit,
+            proof_size_limit,
+            storage_deposit_limit: self.storage_deposit_limit,
+            transferred_value: self.transferred_value,
+        }
+    }
+
+    /// Sets the `storage_deposit_limit` for the current cross-contract call.
+    ///
+    /// This bounds how much of the caller's balance the callee may lock up
+    /// as storage deposit.
+    pub fn storage_deposit_limit(self, storage_deposit_limit: E::Balance) -> Self {
         Call {
             callee: self.callee,
-            gas_limit,
+            ref_time_limit: self.ref_time_limit,
+            proof_size_limit: self.proof_size_limit,
+            storage_deposit_limit: Some(storage_deposit_limit),
             transferred_value: self.transferred_value,
         }
     }
@@ -309,7 +503,9 @@ where
     pub fn transferred_value(self, transferred_value: E::Balance) -> Self {
         Call {
             callee: self.callee,
-            gas_limit: self.gas_limit,
+            ref_time_limit: self.ref_time_limit,
+            proof_size_limit: self.proof_size_limit,
+            storage_deposit_limit: self.storage_deposit_limit,
             transferred_value,
         }
     }
@@ -395,6 +591,31 @@ where
             _phantom: Default::default(),
         }
     }
+
+    /// Allows the callee to reenter into the current contract.
+    ///
+    /// # Note
+    ///
+    /// See [`CallFlags::set_allow_reentry`] for more information.
+    #[inline]
+    #[must_use]
+    pub fn allow_reentry(self) -> CallBuilder<E, CallType, Args, RetType> {
+        let call_flags = self.call_flags.set_allow_reentry(true);
+        self.call_flags(call_flags)
+    }
+
+    /// Returns the result of the callee directly to the caller of the current
+    /// contract instead of returning from the call.
+    ///
+    /// # Note
+    ///
+    /// See [`CallFlags::set_tail_call`] for more information.
+    #[inline]
+    #[must_use]
+    pub fn tail_call(self) -> CallBuilder<E, CallType, Args, RetType> {
+        let call_flags = self.call_flags.set_tail_call(true);
+        self.call_flags(call_flags)
+    }
 }
 
 impl<E, CallType, Args> CallBuilder<E, CallType, Args, Unset<ReturnType<()>>>
@@ -437,6 +658,23 @@ where
             _phantom: Default::default(),
         }
     }
+
+    /// Sets the execution input to the given selector and pre-encoded arguments.
+    ///
+    /// # Note
+    ///
+    /// This allows to forward calls that are only known at runtime, e.g. calls
+    /// submitted by the users of multisig or governance contracts.
+    pub fn exec_input_raw<T>(
+        self,
+        selector: Selector,
+        input: T,
+    ) -> CallBuilder<E, CallType, Set<ExecutionInput<RawArgs<T>>>, RetType>
+    where
+        T: AsRef<[u8]>,
+    {
+        self.exec_input(ExecutionInput::from_raw(selector, input))
+    }
 }
 
 impl<E, Args, RetType> CallBuilder<E, Set<Call<E>>, Args, RetType>
@@ -447,11 +685,7 @@ where
     pub fn callee(self, callee: E::AccountId) -> Self {
         let call_type = self.call_type.value();
         CallBuilder {
-            call_type: Set(Call {
-                callee,
-                gas_limit: call_type.gas_limit,
-                transferred_value: call_type.transferred_value,
-            }),
+            call_type: Set(call_type.callee(callee)),
             call_flags: self.call_flags,
             exec_input: self.exec_input,
             return_type: self.return_type,
@@ -460,14 +694,49 @@ where
     }
 
     /// Sets the `gas_limit` for the current cross-contract call.
+    ///
+    /// # Note
+    ///
+    /// This is equivalent to [`CallBuilder::ref_time_limit`] and is kept for chains
+    /// still using one-dimensional weights.
     pub fn gas_limit(self, gas_limit: Gas) -> Self {
+        self.ref_time_limit(gas_limit)
+    }
+
+    /// Sets the `ref_time_limit` part of the weight limit for the current
+    /// cross-contract call.
+    pub fn ref_time_limit(self, ref_time_limit: u64) -> Self {
+        let call_type = self.call_type.value();
+        CallBuilder {
+            call_type: Set(call_type.ref_time_limit(ref_time_limit)),
+            call_flags: self.call_flags,
+            exec_input: self.exec_input,
+            return_type: self.return_type,
+            _phantom: Default::default(),
+        }
+    }
+
+    /// Sets the `proof_size_limit` part of the weight limit for the current
+    /// cross-contract call.
+    pub fn proof_size_limit(self, proof_size_limit: u64) -> Self {
+        let call_type = self.call_type.value();
+        CallBuilder {
+            call_type: Set(call_type.proof_size_limit(proof_size_limit)),
+            call_flags: self.call_flags,
+            exec_input: self.exec_input,
+            return_type: self.return_type,
+            _phantom: Default::default(),
+        }
+    }
+
+    /// Sets the `storage_deposit_limit` for the current cross-contract call.
+    ///
+    /// This bounds how much of the caller's balance the callee may lock up
+    /// as storage deposit.
+    pub fn storage_deposit_limit(self, storage_deposit_limit: E::Balance) -> Self {
         let call_type = self.call_type.value();
         CallBuilder {
-            call_type: Set(Call {
-                callee: call_type.callee,
-                gas_limit,
-                transferred_value: call_type.transferred_value,
-            }),
+            call_type: Set(call_type.storage_deposit_limit(storage_deposit_limit)),
             call_flags: self.call_flags,
             exec_input: self.exec_input,
             return_type: self.return_type,
@@ -479,11 +748,7 @@ where
     pub fn transferred_value(self, transferred_value: E::Balance) -> Self {
         let call_type = self.call_type.value();
         CallBuilder {
-            call_type: Set(Call {
-                callee: call_type.callee,
-                gas_limit: call_type.gas_limit,
-                transferred_value,
-            }),
+            call_type: Set(call_type.transferred_value(transferred_value)),
             call_flags: self.call_flags,
             exec_input: self.exec_input,
             return_type: self.return_type,
@@ -600,6 +865,42 @@ where
     pub fn fire(self) -> Result<(), Error> {
         self.params().invoke()
     }
+
+    /// Performs the cross-chain function call as a dry-run and returns the weight
+    /// and the storage deposit it consumed.
+    ///
+    /// # Note
+    ///
+    /// All state changes of the call are reverted afterwards. Only the off-chain
+    /// environment supports dry-runs, on-chain this returns
+    /// [`Error::DryRunUnavailable`].
+    pub fn estimate(self) -> Result<Estimate<E::Balance>, Error> {
+        self.params().estimate()
+    }
+
+    /// Forwards the entire input of the current contract execution to the callee
+    /// and returns its output directly to the caller of the current contract.
+    ///
+    /// # Note
+    ///
+    /// This sets the `forward_input` and `tail_call` flags on top of the already
+    /// set call flags. The input is handed to the callee without being copied or
+    /// re-encoded, which makes this the cheapest way to implement thin forwarders.
+    /// Since the input is consumed the current contract must not read it afterwards.
+    ///
+    /// # Panics
+    ///
+    /// If the forwarded call fails.
+    pub fn forward_input(self) -> ! {
+        let call_flags = self.call_flags.set_forward_input(true).set_tail_call(true);
+        self.call_flags(call_flags).fire().unwrap_or_else(|error| {
+            panic!(
+                "forwarding the input to the callee failed due to {:?}",
+                error
+            )
+        });
+        unreachable!("the forwarded call will never return since `tail_call` was set")
+    }
 }
 
 impl<E>
@@ -616,6 +917,30 @@ where
     pub fn fire(self) -> Result<(), Error> {
         self.params().invoke()
     }
+
+    /// Forwards the entire input of the current contract execution to the callee
+    /// and returns its output directly to the caller of the current contract.
+    ///
+    /// # Note
+    ///
+    /// This sets the `forward_input` and `tail_call` flags on top of the already
+    /// set call flags. The input is handed to the callee without being copied or
+    /// re-encoded, which makes this the cheapest way to implement thin forwarders.
+    /// Since the input is consumed the current contract must not read it afterwards.
+    ///
+    /// # Panics
+    ///
+    /// If the forwarded call fails.
+    pub fn forward_input(self) -> ! {
+        let call_flags = self.call_flags.set_forward_input(true).set_tail_call(true);
+        self.call_flags(call_flags).fire().unwrap_or_else(|error| {
+            panic!(
+                "forwarding the input to the callee failed due to {:?}",
+                error
+            )
+        });
+        unreachable!("the forwarded call will never return since `tail_call` was set")
+    }
 }
 
 impl<E, Args, R>
@@ -629,6 +954,41 @@ where
     pub fn fire(self) -> Result<R, Error> {
         self.params().invoke()
     }
+
+    /// Performs the cross-chain function call as a dry-run and returns the weight
+    /// and the storage deposit it consumed.
+    ///
+    /// # Note
+    ///
+    /// All state changes of the call are reverted afterwards. Only the off-chain
+    /// environment supports dry-runs, on-chain this returns
+    /// [`Error::DryRunUnavailable`].
+    pub fn estimate(self) -> Result<Estimate<E::Balance>, Error> {
+        self.params().estimate()
+    }
+
+    /// Invokes the cross-chain function call and decodes its output into the
+    /// typed output `T`, e.g. `Result<R, Err>` where `Err` is the error type
+    /// declared by the called ink! message.
+    ///
+    /// # Note
+    ///
+    /// Upon failure an ink! message returning a `Result` reverts with its encoded
+    /// error, so this allows callers to branch on why the callee failed instead of
+    /// only seeing that it reverted.
+    pub fn fire_typed<T>(self) -> Result<T, Error>
+    where
+        T: TypedOutput<R>,
+    {
+        CallParams::<E, Call<E>, Args, T> {
+            call_type: self.call_type.value(),
+            call_flags: self.call_flags,
+            _return_type: Default::default(),
+            exec_input: self.exec_input.value(),
+            _phantom: self._phantom,
+        }
+        .invoke()
+    }
 }
 
 impl<E, Args, R>
@@ -642,4 +1002,27 @@ where
     pub fn fire(self) -> Result<R, Error> {
         self.params().invoke()
     }
+
+    /// Invokes the cross-chain function call and decodes its output into the
+    /// typed output `T`, e.g. `Result<R, Err>` where `Err` is the error type
+    /// declared by the called ink! message.
+    ///
+    /// # Note
+    ///
+    /// Upon failure an ink! message returning a `Result` reverts with its encoded
+    /// error, so this allows callers to branch on why the callee failed instead of
+    /// only seeing that it reverted.
+    pub fn fire_typed<T>(self) -> Result<T, Error>
+    where
+        T: TypedOutput<R>,
+    {
+        CallParams::<E, DelegateCall<E>, Args, T> {
+            call_type: self.call_type.value(),
+            call_flags: self.call_flags,
+            _return_type: Default::default(),
+            exec_input: self.exec_input.value(),
+            _phantom: self._phantom,
+        }
+        .invoke()
+    }
 }
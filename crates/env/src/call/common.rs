@@ -14,7 +14,10 @@
 
 //! Utilities, types and abstractions common to call and instantiation routines.
 
-use core::marker::PhantomData;
+use core::{
+    convert::Infallible,
+    marker::PhantomData,
+};
 
 /// Represents a return type.
 ///
@@ -38,6 +41,65 @@ impl<T> Default for ReturnType<T> {
     }
 }
 
+/// Implemented by the typed outputs into which the output of a call returning `R`
+/// can be decoded.
+///
+/// An ink! message returning `Result<R, Err>` reverts with its encoded `Err` upon
+/// failure, so a call that returns `R` upon success can be decoded into `Result<R, Err>`
+/// in order to inspect why the callee failed.
+pub trait TypedOutput<R>: scale::Decode {}
+
+impl<R, Err> TypedOutput<R> for Result<R, Err>
+where
+    R: scale::Decode,
+    Err: scale::Decode,
+{
+}
+
+/// Implemented by the return types of ink! constructors that instantiate contracts
+/// referenced by `C`.
+///
+/// Infallible constructors return `C` itself whereas fallible constructors return
+/// `Result<C, Err>` and revert with their encoded `Err` upon failure.
+pub trait ConstructorReturnType<C> {
+    /// The error type of the constructor.
+    ///
+    /// # Note
+    ///
+    /// For infallible constructors this is `core::convert::Infallible`.
+    type Error;
+
+    /// Decodes the error with which the constructor reverted from its `output`.
+    ///
+    /// Returns `None` if the constructor is infallible or if the `output` does not
+    /// hold an error of the constructor.
+    fn decode_error(output: &[u8]) -> Option<Self::Error>;
+}
+
+impl<C> ConstructorReturnType<C> for C {
+    type Error = Infallible;
+
+    #[inline]
+    fn decode_error(_output: &[u8]) -> Option<Self::Error> {
+        None
+    }
+}
+
+impl<C, Err> ConstructorReturnType<C> for Result<C, Err>
+where
+    Err: scale::Decode,
+{
+    type Error = Err;
+
+    #[inline]
+    fn decode_error(output: &[u8]) -> Option<Self::Error> {
+        match <Result<(), Err> as scale::Decode>::decode(&mut &output[..]) {
+            Ok(Err(error)) => Some(error),
+            Ok(Ok(())) | Err(_) => None,
+        }
+    }
+}
+
 /// A parameter that has been set to some value.
 #[derive(Debug, Copy, Clone)]
 pub struct Set<T>(pub T);
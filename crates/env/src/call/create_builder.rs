@@ -15,18 +15,24 @@
 use crate::{
     call::{
         utils::{
+            ConstructorReturnType,
             EmptyArgumentList,
             ReturnType,
             Set,
             Unset,
             Unwrap,
         },
+        Estimate,
         ExecutionInput,
     },
+    hash::Blake2x256,
     Environment,
     Error,
 };
-use core::marker::PhantomData;
+use core::{
+    convert::Infallible,
+    marker::PhantomData,
+};
 
 pub mod state {
     //! Type states that tell what state of a instantiation argument has not
@@ -50,16 +56,68 @@ where
     fn from_account_id(account_id: <T as Environment>::AccountId) -> Self;
 }
 
+/// The error of a failed contract instantiation.
+#[derive(Debug, PartialEq, Eq)]
+pub enum InstantiateError<Err> {
+    /// The fallible constructor of the instantiated contract failed with the
+    /// given error and reverted the instantiation.
+    Constructor(Err),
+    /// The instantiation failed for another reason, e.g. because the code hash
+    /// is unknown or the constructor trapped.
+    Env(Error),
+}
+
+impl<Err> InstantiateError<Err> {
+    /// Returns the error of an instantiation that was reverted by its constructor
+    /// with the given `output`.
+    pub(crate) fn reverted<C, RetType>(output: &[u8]) -> Self
+    where
+        RetType: ConstructorReturnType<C, Error = Err>,
+    {
+        RetType::decode_error(output)
+            .map(Self::Constructor)
+            .unwrap_or(Self::Env(Error::CalleeReverted))
+    }
+}
+
+impl<Err> From<Error> for InstantiateError<Err> {
+    #[inline]
+    fn from(error: Error) -> Self {
+        Self::Env(error)
+    }
+}
+
+impl From<InstantiateError<Infallible>> for Error {
+    #[inline]
+    fn from(error: InstantiateError<Infallible>) -> Self {
+        match error {
+            InstantiateError::Env(error) => error,
+            InstantiateError::Constructor(never) => match never {},
+        }
+    }
+}
+
+impl<Err> From<scale::Error> for InstantiateError<Err> {
+    #[inline]
+    fn from(error: scale::Error) -> Self {
+        Self::Env(error.into())
+    }
+}
+
 /// Builds up contract instantiations.
 #[derive(Debug)]
-pub struct CreateParams<E, Args, Salt, R>
+pub struct CreateParams<E, Args, Salt, R, RetType>
 where
     E: Environment,
 {
     /// The code hash of the created contract.
     code_hash: E::Hash,
-    /// The maximum gas costs allowed for the instantiation.
-    gas_limit: u64,
+    /// The maximum `ref_time` allowed for the instantiation.
+    ref_time_limit: u64,
+    /// The maximum proof size allowed for the instantiation.
+    proof_size_limit: u64,
+    /// The maximum balance that may be locked up as storage deposit.
+    storage_deposit_limit: Option<E::Balance>,
     /// The endowment for the instantiated contract.
     endowment: E::Balance,
     /// The input data for the instantiation.
@@ -67,10 +125,12 @@ where
     /// The salt for determining the hash for the contract account ID.
     salt_bytes: Salt,
     /// The type of the instantiated contract.
-    _return_type: ReturnType<R>,
+    _contract_ref: PhantomData<fn() -> R>,
+    /// The return type of the called constructor.
+    _return_type: ReturnType<RetType>,
 }
 
-impl<E, Args, Salt, R> CreateParams<E, Args, Salt, R>
+impl<E, Args, Salt, R, RetType> CreateParams<E, Args, Salt, R, RetType>
 where
     E: Environment,
 {
@@ -80,10 +140,22 @@ where
         &self.code_hash
     }
 
-    /// The gas limit for the contract instantiation.
+    /// The `ref_time` limit for the contract instantiation.
+    #[inline]
+    pub(crate) fn ref_time_limit(&self) -> u64 {
+        self.ref_time_limit
+    }
+
+    /// The proof size limit for the contract instantiation.
+    #[inline]
+    pub(crate) fn proof_size_limit(&self) -> u64 {
+        self.proof_size_limit
+    }
+
+    /// The storage deposit limit for the contract instantiation.
     #[inline]
-    pub(crate) fn gas_limit(&self) -> u64 {
-        self.gas_limit
+    pub(crate) fn storage_deposit_limit(&self) -> Option<&E::Balance> {
+        self.storage_deposit_limit.as_ref()
     }
 
     /// The endowment for the instantiated contract.
@@ -99,7 +171,7 @@ where
     }
 }
 
-impl<E, Args, Salt, R> CreateParams<E, Args, Salt, R>
+impl<E, Args, Salt, R, RetType> CreateParams<E, Args, Salt, R, RetType>
 where
     E: Environment,
     Salt: AsRef<[u8]>,
@@ -111,32 +183,48 @@ where
     }
 }
 
-impl<E, Args, Salt, R> CreateParams<E, Args, Salt, R>
+impl<E, Args, Salt, R, RetType> CreateParams<E, Args, Salt, R, RetType>
 where
     E: Environment,
     Args: scale::Encode,
     Salt: AsRef<[u8]>,
     R: FromAccountId<E>,
+    RetType: ConstructorReturnType<R>,
 {
     /// Instantiates the contract and returns its account ID back to the caller.
+    ///
+    /// # Note
+    ///
+    /// Upon failure of a fallible ink! constructor its error is decoded and returned
+    /// as [`InstantiateError::Constructor`].
     #[inline]
-    pub fn instantiate(&self) -> Result<R, crate::Error> {
+    pub fn instantiate(&self) -> Result<R, InstantiateError<RetType::Error>> {
         crate::instantiate_contract(self).map(FromAccountId::from_account_id)
     }
+
+    /// Performs the instantiation with the given instantiation parameters as a dry-run.
+    ///
+    /// Returns the weight and the storage deposit consumed by the instantiation.
+    #[inline]
+    pub fn estimate(&self) -> Result<Estimate<E::Balance>, crate::Error> {
+        crate::estimate_instantiate_contract(self)
+    }
 }
 
 /// Builds up contract instantiations.
-pub struct CreateBuilder<E, CodeHash, GasLimit, Endowment, Args, Salt, R>
+pub struct CreateBuilder<E, CodeHash, GasLimit, Endowment, Args, Salt, R, RetType>
 where
     E: Environment,
 {
     code_hash: CodeHash,
     gas_limit: GasLimit,
+    proof_size_limit: u64,
+    storage_deposit_limit: Option<E::Balance>,
     endowment: Endowment,
     exec_input: Args,
     salt: Salt,
-    return_type: ReturnType<R>,
-    _phantom: PhantomData<fn() -> E>,
+    return_type: RetType,
+    _phantom: PhantomData<fn() -> (E, R)>,
 }
 
 /// Returns a new [`CreateBuilder`] to build up the parameters to a cross-contract instantiation.
@@ -148,7 +236,8 @@ where
 /// The used constructor:
 ///
 /// - has a selector equal to `0xDEADBEEF`
-/// - is provided with 4000 units of gas for its execution
+/// - is provided with 4000 units of `ref_time` and 1024 bytes of proof size
+///   for its execution
 /// - is provided with 25 units of transferred value for the new contract instance
 /// - receives the following arguments in order
 ///    1. an `i32` with value `42`
@@ -170,7 +259,8 @@ where
 /// # }
 /// let my_contract: MyContract = build_create::<DefaultEnvironment, MyContract>()
 ///     .code_hash(Hash::from([0x42; 32]))
-///     .gas_limit(4000)
+///     .ref_time_limit(4000)
+///     .proof_size_limit(1024)
 ///     .endowment(25)
 ///     .exec_input(
 ///         ExecutionInput::new(Selector::new([0xDE, 0xAD, 0xBE, 0xEF]))
@@ -184,9 +274,9 @@ where
 ///     .unwrap();
 /// ```
 ///
-/// **Note:** The shown example panics because there is currently no cross-calling
-///           support in the off-chain testing environment. However, this code
-///           should work fine in on-chain environments.
+/// **Note:** The shown example panics because no contract code has been registered
+///           under the code hash in the off-chain testing environment. However, this
+///           code should work fine in on-chain environments.
 #[allow(clippy::type_complexity)]
 pub fn build_create<E, R>() -> CreateBuilder<
     E,
@@ -196,6 +286,7 @@ pub fn build_create<E, R>() -> CreateBuilder<
     Unset<ExecutionInput<EmptyArgumentList>>,
     Unset<state::Salt>,
     R,
+    Unset<ReturnType<R>>,
 >
 where
     E: Environment,
@@ -204,6 +295,8 @@ where
     CreateBuilder {
         code_hash: Default::default(),
         gas_limit: Default::default(),
+        proof_size_limit: Default::default(),
+        storage_deposit_limit: None,
         endowment: Default::default(),
         exec_input: Default::default(),
         salt: Default::default(),
@@ -212,8 +305,8 @@ where
     }
 }
 
-impl<E, GasLimit, Endowment, Args, Salt, R>
-    CreateBuilder<E, Unset<E::Hash>, GasLimit, Endowment, Args, Salt, R>
+impl<E, GasLimit, Endowment, Args, Salt, R, RetType>
+    CreateBuilder<E, Unset<E::Hash>, GasLimit, Endowment, Args, Salt, R, RetType>
 where
     E: Environment,
 {
@@ -222,10 +315,12 @@ where
     pub fn code_hash(
         self,
         code_hash: E::Hash,
-    ) -> CreateBuilder<E, Set<E::Hash>, GasLimit, Endowment, Args, Salt, R> {
+    ) -> CreateBuilder<E, Set<E::Hash>, GasLimit, Endowment, Args, Salt, R, RetType> {
         CreateBuilder {
             code_hash: Set(code_hash),
             gas_limit: self.gas_limit,
+            proof_size_limit: self.proof_size_limit,
+            storage_deposit_limit: self.storage_deposit_limit,
             endowment: self.endowment,
             exec_input: self.exec_input,
             salt: self.salt,
@@ -235,20 +330,36 @@ where
     }
 }
 
-impl<E, CodeHash, Endowment, Args, Salt, R>
-    CreateBuilder<E, CodeHash, Unset<u64>, Endowment, Args, Salt, R>
+impl<E, CodeHash, Endowment, Args, Salt, R, RetType>
+    CreateBuilder<E, CodeHash, Unset<u64>, Endowment, Args, Salt, R, RetType>
 where
     E: Environment,
 {
     /// Sets the maximum allowed gas costs for the contract instantiation.
+    ///
+    /// # Note
+    ///
+    /// This is equivalent to [`CreateBuilder::ref_time_limit`] and is kept for chains
+    /// still using one-dimensional weights.
     #[inline]
     pub fn gas_limit(
         self,
         gas_limit: u64,
-    ) -> CreateBuilder<E, CodeHash, Set<u64>, Endowment, Args, Salt, R> {
+    ) -> CreateBuilder<E, CodeHash, Set<u64>, Endowment, Args, Salt, R, RetType> {
+        self.ref_time_limit(gas_limit)
+    }
+
+    /// Sets the maximum allowed `ref_time` for the contract instantiation.
+    #[inline]
+    pub fn ref_time_limit(
+        self,
+        ref_time_limit: u64,
+    ) -> CreateBuilder<E, CodeHash, Set<u64>, Endowment, Args, Salt, R, RetType> {
         CreateBuilder {
             code_hash: self.code_hash,
-            gas_limit: Set(gas_limit),
+            gas_limit: Set(ref_time_limit),
+            proof_size_limit: self.proof_size_limit,
+            storage_deposit_limit: self.storage_deposit_limit,
             endowment: self.endowment,
             exec_input: self.exec_input,
             salt: self.salt,
@@ -258,20 +369,75 @@ where
     }
 }
 
-impl<E, CodeHash, GasLimit, Args, Salt, R>
-    CreateBuilder<E, CodeHash, GasLimit, Unset<E::Balance>, Args, Salt, R>
+impl<E, CodeHash, GasLimit, Endowment, Args, Salt, R, RetType>
+    CreateBuilder<E, CodeHash, GasLimit, Endowment, Args, Salt, R, RetType>
+where
+    E: Environment,
+{
+    /// Sets the maximum allowed proof size for the contract instantiation.
+    ///
+    /// # Note
+    ///
+    /// The default of `0` allows the instantiation to use all of the remaining proof size.
+    #[inline]
+    pub fn proof_size_limit(self, proof_size_limit: u64) -> Self {
+        CreateBuilder {
+            code_hash: self.code_hash,
+            gas_limit: self.gas_limit,
+            proof_size_limit,
+            storage_deposit_limit: self.storage_deposit_limit,
+            endowment: self.endowment,
+            exec_input: self.exec_input,
+            salt: self.salt,
+            return_type: self.return_type,
+            _phantom: Default::default(),
+        }
+    }
+
+    /// Sets the maximum balance that may be locked up as storage deposit
+    /// by the instantiation.
+    ///
+    /// # Note
+    ///
+    /// If not set, the instantiation may lock as much balance as it needs.
+    #[inline]
+    pub fn storage_deposit_limit(self, storage_deposit_limit: E::Balance) -> Self {
+        CreateBuilder {
+            code_hash: self.code_hash,
+            gas_limit: self.gas_limit,
+            proof_size_limit: self.proof_size_limit,
+            storage_deposit_limit: Some(storage_deposit_limit),
+            endowment: self.endowment,
+            exec_input: self.exec_input,
+            salt: self.salt,
+            return_type: self.return_type,
+            _phantom: Default::default(),
+        }
+    }
+}
+
+impl<E, CodeHash, GasLimit, Args, Salt, R, RetType>
+    CreateBuilder<E, CodeHash, GasLimit, Unset<E::Balance>, Args, Salt, R, RetType>
 where
     E: Environment,
 {
     /// Sets the value transferred upon the execution of the call.
+    ///
+    /// # Note
+    ///
+    /// If not set, the instantiated contract is endowed with the
+    /// [`minimum_balance`](crate::minimum_balance) of the environment.
     #[inline]
     pub fn endowment(
         self,
         endowment: E::Balance,
-    ) -> CreateBuilder<E, CodeHash, GasLimit, Set<E::Balance>, Args, Salt, R> {
+    ) -> CreateBuilder<E, CodeHash, GasLimit, Set<E::Balance>, Args, Salt, R, RetType>
+    {
         CreateBuilder {
             code_hash: self.code_hash,
             gas_limit: self.gas_limit,
+            proof_size_limit: self.proof_size_limit,
+            storage_deposit_limit: self.storage_deposit_limit,
             endowment: Set(endowment),
             exec_input: self.exec_input,
             salt: self.salt,
@@ -281,7 +447,7 @@ where
     }
 }
 
-impl<E, CodeHash, GasLimit, Endowment, Salt, R>
+impl<E, CodeHash, GasLimit, Endowment, Salt, R, RetType>
     CreateBuilder<
         E,
         CodeHash,
@@ -290,6 +456,7 @@ impl<E, CodeHash, GasLimit, Endowment, Salt, R>
         Unset<ExecutionInput<EmptyArgumentList>>,
         Salt,
         R,
+        RetType,
     >
 where
     E: Environment,
@@ -299,11 +466,21 @@ where
     pub fn exec_input<Args>(
         self,
         exec_input: ExecutionInput<Args>,
-    ) -> CreateBuilder<E, CodeHash, GasLimit, Endowment, Set<ExecutionInput<Args>>, Salt, R>
-    {
+    ) -> CreateBuilder<
+        E,
+        CodeHash,
+        GasLimit,
+        Endowment,
+        Set<ExecutionInput<Args>>,
+        Salt,
+        R,
+        RetType,
+    > {
         CreateBuilder {
             code_hash: self.code_hash,
             gas_limit: self.gas_limit,
+            proof_size_limit: self.proof_size_limit,
+            storage_deposit_limit: self.storage_deposit_limit,
             endowment: self.endowment,
             exec_input: Set(exec_input),
             salt: self.salt,
@@ -313,8 +490,8 @@ where
     }
 }
 
-impl<E, CodeHash, GasLimit, Endowment, Args, R>
-    CreateBuilder<E, CodeHash, GasLimit, Endowment, Args, Unset<state::Salt>, R>
+impl<E, CodeHash, GasLimit, Endowment, Args, R, RetType>
+    CreateBuilder<E, CodeHash, GasLimit, Endowment, Args, Unset<state::Salt>, R, RetType>
 where
     E: Environment,
 {
@@ -323,13 +500,15 @@ where
     pub fn salt_bytes<Salt>(
         self,
         salt: Salt,
-    ) -> CreateBuilder<E, CodeHash, GasLimit, Endowment, Args, Set<Salt>, R>
+    ) -> CreateBuilder<E, CodeHash, GasLimit, Endowment, Args, Set<Salt>, R, RetType>
     where
         Salt: AsRef<[u8]>,
     {
         CreateBuilder {
             code_hash: self.code_hash,
             gas_limit: self.gas_limit,
+            proof_size_limit: self.proof_size_limit,
+            storage_deposit_limit: self.storage_deposit_limit,
             endowment: self.endowment,
             exec_input: self.exec_input,
             salt: Set(salt),
@@ -337,56 +516,155 @@ where
             _phantom: Default::default(),
         }
     }
+
+    /// Sets a salt derived from the caller, the instantiation nonce and the given bytes.
+    ///
+    /// # Note
+    ///
+    /// The salt is the `Blake2x256` hash of the SCALE encoded tuple of the caller's
+    /// account ID, the current [`instantiation_nonce`](crate::instantiation_nonce)
+    /// and `bytes`. Since the nonce is incremented with every instantiation on the
+    /// chain, repeated instantiations of the same code with the same input and the
+    /// same `bytes` still yield distinct contract accounts.
+    #[inline]
+    pub fn salt_derived_from(
+        self,
+        bytes: &[u8],
+    ) -> CreateBuilder<E, CodeHash, GasLimit, Endowment, Args, Set<[u8; 32]>, R, RetType>
+    {
+        let caller = crate::caller::<E>();
+        let nonce = crate::instantiation_nonce::<E>();
+        let mut salt = [0x00; 32];
+        crate::hash_encoded::<Blake2x256, _>(&(caller, nonce, bytes), &mut salt);
+        self.salt_bytes(salt)
+    }
 }
 
-impl<E, GasLimit, Args, Salt, R>
+impl<E, CodeHash, GasLimit, Endowment, Args, Salt, R>
+    CreateBuilder<E, CodeHash, GasLimit, Endowment, Args, Salt, R, Unset<ReturnType<R>>>
+where
+    E: Environment,
+{
+    /// Sets the return type of the called constructor.
+    ///
+    /// # Note
+    ///
+    /// This is only required for fallible ink! constructors returning
+    /// `Result<R, Err>`, so that their error is decoded upon failure. Infallible
+    /// ink! constructors return `R` which is the default.
+    #[inline]
+    pub fn returns<Ret>(
+        self,
+    ) -> CreateBuilder<
+        E,
+        CodeHash,
+        GasLimit,
+        Endowment,
+        Args,
+        Salt,
+        R,
+        Set<ReturnType<Ret>>,
+    >
+    where
+        Ret: ConstructorReturnType<R>,
+    {
+        CreateBuilder {
+            code_hash: self.code_hash,
+            gas_limit: self.gas_limit,
+            proof_size_limit: self.proof_size_limit,
+            storage_deposit_limit: self.storage_deposit_limit,
+            endowment: self.endowment,
+            exec_input: self.exec_input,
+            salt: self.salt,
+            return_type: Set(Default::default()),
+            _phantom: Default::default(),
+        }
+    }
+}
+
+impl<E, GasLimit, Endowment, Args, Salt, R, RetType, Ret>
     CreateBuilder<
         E,
         Set<E::Hash>,
         GasLimit,
-        Set<E::Balance>,
+        Endowment,
         Set<ExecutionInput<Args>>,
         Set<Salt>,
         R,
+        RetType,
     >
 where
     E: Environment,
     GasLimit: Unwrap<Output = u64>,
+    Endowment: Unwrap<Output = E::Balance>,
+    RetType: Unwrap<Output = ReturnType<Ret>>,
 {
-    /// Sets the value transferred upon the execution of the call.
+    /// Finalizes the create builder, allowing it to instantiate a contract.
+    ///
+    /// # Note
+    ///
+    /// If no endowment has been set the instantiated contract is endowed with the
+    /// [`minimum_balance`](crate::minimum_balance) of the environment, so that its
+    /// account is kept alive.
     #[inline]
-    pub fn params(self) -> CreateParams<E, Args, Salt, R> {
+    pub fn params(self) -> CreateParams<E, Args, Salt, R, Ret> {
         CreateParams {
             code_hash: self.code_hash.value(),
-            gas_limit: self.gas_limit.unwrap_or_else(|| 0),
-            endowment: self.endowment.value(),
+            ref_time_limit: self.gas_limit.unwrap_or_else(|| 0),
+            proof_size_limit: self.proof_size_limit,
+            storage_deposit_limit: self.storage_deposit_limit,
+            endowment: self.endowment.unwrap_or_else(crate::minimum_balance::<E>),
             exec_input: self.exec_input.value(),
             salt_bytes: self.salt.value(),
-            _return_type: self.return_type,
+            _contract_ref: Default::default(),
+            _return_type: self.return_type.unwrap_or_else(Default::default),
         }
     }
 }
 
-impl<E, GasLimit, Args, Salt, R>
+impl<E, GasLimit, Endowment, Args, Salt, R, RetType, Ret>
     CreateBuilder<
         E,
         Set<E::Hash>,
         GasLimit,
-        Set<E::Balance>,
+        Endowment,
         Set<ExecutionInput<Args>>,
         Set<Salt>,
         R,
+        RetType,
     >
 where
     E: Environment,
     GasLimit: Unwrap<Output = u64>,
+    Endowment: Unwrap<Output = E::Balance>,
     Args: scale::Encode,
     Salt: AsRef<[u8]>,
     R: FromAccountId<E>,
+    RetType: Unwrap<Output = ReturnType<Ret>>,
+    Ret: ConstructorReturnType<R>,
 {
     /// Instantiates the contract using the given instantiation parameters.
+    ///
+    /// # Note
+    ///
+    /// Upon failure of a fallible ink! constructor its error is decoded and returned
+    /// as [`InstantiateError::Constructor`] instead of trapping the instantiating
+    /// contract.
     #[inline]
-    pub fn instantiate(self) -> Result<R, Error> {
+    pub fn instantiate(self) -> Result<R, InstantiateError<Ret::Error>> {
         self.params().instantiate()
     }
+
+    /// Performs the instantiation as a dry-run and returns the weight and the
+    /// storage deposit it consumed.
+    ///
+    /// # Note
+    ///
+    /// The instantiated contract and all other state changes are reverted
+    /// afterwards. Only the off-chain environment supports dry-runs, on-chain this
+    /// returns [`Error::DryRunUnavailable`].
+    #[inline]
+    pub fn estimate(self) -> Result<Estimate<E::Balance>, Error> {
+        self.params().estimate()
+    }
 }
@@ -0,0 +1,40 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::types::Weight;
+
+/// The net storage deposit charged or refunded by a call or instantiation.
+///
+/// # Note
+///
+/// This is a mirror of the `StorageDeposit` type used by PALLET contracts.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StorageDeposit<Balance> {
+    /// The origin is refunded the given amount.
+    Refund(Balance),
+    /// The origin is charged the given amount.
+    Charge(Balance),
+}
+
+/// The resources consumed by a call or instantiation that was performed as a dry-run.
+///
+/// Returned by the `estimate` methods of [`CallBuilder`][`crate::call::CallBuilder`]
+/// and [`CreateBuilder`][`crate::call::CreateBuilder`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Estimate<Balance> {
+    /// The weight consumed by the call or instantiation.
+    pub gas_consumed: Weight,
+    /// The net storage deposit charged or refunded by the call or instantiation.
+    pub storage_deposit: StorageDeposit<Balance>,
+}
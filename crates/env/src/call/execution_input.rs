@@ -49,6 +49,25 @@ impl ExecutionInput<EmptyArgumentList> {
     }
 }
 
+impl<T> ExecutionInput<RawArgs<T>>
+where
+    T: AsRef<[u8]>,
+{
+    /// Creates a new execution input with the given selector and pre-encoded arguments.
+    ///
+    /// # Note
+    ///
+    /// The arguments are forwarded as is, so they must already be the SCALE encoded
+    /// arguments expected by the callee, e.g. as provided by users at runtime.
+    #[inline]
+    pub fn from_raw(selector: Selector, input: T) -> Self {
+        Self {
+            selector,
+            args: RawArgs(input),
+        }
+    }
+}
+
 impl<'a, Head, Rest> ExecutionInput<ArgumentList<Argument<Head>, Rest>> {
     /// Pushes an argument to the execution input.
     #[inline]
@@ -157,6 +176,27 @@ where
     }
 }
 
+/// Pre-encoded arguments of an execution input.
+///
+/// The bytes are encoded as is, i.e. without a length prefix.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct RawArgs<T>(T);
+
+impl<T> scale::Encode for RawArgs<T>
+where
+    T: AsRef<[u8]>,
+{
+    #[inline]
+    fn size_hint(&self) -> usize {
+        self.0.as_ref().len()
+    }
+
+    #[inline]
+    fn encode_to<O: scale::Output + ?Sized>(&self, output: &mut O) {
+        output.write(self.0.as_ref())
+    }
+}
+
 impl scale::Encode for EmptyArgumentList {
     #[inline]
     fn size_hint(&self) -> usize {
@@ -218,6 +258,19 @@ mod tests {
         assert_eq!(decoded, selector);
     }
 
+    #[test]
+    fn raw_exec_input_works() {
+        let selector = Selector::new([0x01, 0x02, 0x03, 0x04]);
+        let args = scale::Encode::encode(&(42i32, true));
+        let exec_input = ExecutionInput::from_raw(selector, &args);
+        let typed_exec_input =
+            ExecutionInput::new(selector).push_arg(42i32).push_arg(true);
+        assert_eq!(
+            scale::Encode::encode(&exec_input),
+            scale::Encode::encode(&typed_exec_input)
+        );
+    }
+
     #[test]
     fn empty_args_works() {
         let empty_list = ArgumentList::empty();
@@ -14,18 +14,23 @@
 
 //! Utilities to call or instantiate contracts on the chain.
 
+mod address;
 mod call_builder;
 mod common;
 mod create_builder;
+mod estimate;
 mod execution_input;
+mod multicall;
 mod selector;
 
 /// Utility types for the cross-contract calling API.
 pub mod utils {
     pub use super::{
         common::{
+            ConstructorReturnType,
             ReturnType,
             Set,
+            TypedOutput,
             Unset,
             Unwrap,
         },
@@ -35,11 +40,15 @@ pub mod utils {
             ArgumentList,
             ArgumentListEnd,
             EmptyArgumentList,
+            RawArgs,
         },
     };
 }
 
+pub use crate::backend::CallFlags;
+
 pub use self::{
+    address::contract_address,
     call_builder::{
         build_call,
         Call,
@@ -53,7 +62,16 @@ pub use self::{
         CreateBuilder,
         CreateParams,
         FromAccountId,
+        InstantiateError,
+    },
+    estimate::{
+        Estimate,
+        StorageDeposit,
     },
     execution_input::ExecutionInput,
+    multicall::{
+        Multicall,
+        MulticallMode,
+    },
     selector::Selector,
 };
@@ -0,0 +1,190 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    call::{
+        Call,
+        CallParams,
+    },
+    Environment,
+    Error,
+};
+use ink_prelude::vec::Vec;
+
+/// Tells a [`Multicall`] how to proceed after one of its calls has failed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MulticallMode {
+    /// Stops the batch upon the first failed call.
+    ///
+    /// The outcome of the failed call is the last of the returned outcomes.
+    StopOnError,
+    /// Executes all calls of the batch regardless of failed calls.
+    CollectResults,
+}
+
+impl Default for MulticallMode {
+    #[inline]
+    fn default() -> Self {
+        Self::CollectResults
+    }
+}
+
+/// Executes a batch of prepared cross-contract calls sequentially.
+///
+/// # Note
+///
+/// All calls of a batch share the same argument and return types. Calls to
+/// different messages can be batched by building their execution inputs via
+/// [`CallBuilder::exec_input_raw`][`crate::call::CallBuilder::exec_input_raw`].
+///
+/// # Example
+///
+/// ```
+/// # use ::ink_env::{
+/// #     Environment,
+/// #     DefaultEnvironment,
+/// #     call::{build_call, Call, ExecutionInput, Multicall, MulticallMode, Selector},
+/// # };
+/// # type AccountId = <DefaultEnvironment as Environment>::AccountId;
+/// # ink_env::test::run_test::<DefaultEnvironment, _>(|_| {
+/// let token = AccountId::from([0x42; 32]);
+/// let transfer_selector = Selector::new([0xDE, 0xAD, 0xBE, 0xEF]);
+/// // Only the token at `token` accepts transfers.
+/// ink_env::test::mock_message::<DefaultEnvironment, bool>(
+///     token,
+///     transfer_selector,
+///     true,
+/// );
+/// let transfer = |token: AccountId, to: AccountId, value: u128| {
+///     build_call::<DefaultEnvironment>()
+///         .call_type(Call::new().callee(token))
+///         .exec_input(
+///             ExecutionInput::new(transfer_selector)
+///                 .push_arg(to)
+///                 .push_arg(value),
+///         )
+///         .returns::<bool>()
+///         .params()
+/// };
+/// let outcomes = Multicall::new()
+///     .mode(MulticallMode::StopOnError)
+///     .call(transfer(token, AccountId::from([0x01; 32]), 10))
+///     .call(transfer(AccountId::from([0x43; 32]), AccountId::from([0x02; 32]), 20))
+///     .call(transfer(token, AccountId::from([0x03; 32]), 30))
+///     .fire();
+/// // The batch stops at the failed call to the account that is not a contract.
+/// assert_eq!(outcomes.len(), 2);
+/// assert_eq!(outcomes[0], Ok(true));
+/// assert!(outcomes[1].is_err());
+/// # Ok(())
+/// # }).unwrap();
+/// ```
+pub struct Multicall<E, Args, R>
+where
+    E: Environment,
+{
+    /// The batched calls in the order of their execution.
+    calls: Vec<CallParams<E, Call<E>, Args, R>>,
+    /// Whether the batch continues after a failed call.
+    mode: MulticallMode,
+}
+
+impl<E, Args, R> Default for Multicall<E, Args, R>
+where
+    E: Environment,
+{
+    #[inline]
+    fn default() -> Self {
+        Self {
+            calls: Vec::new(),
+            mode: Default::default(),
+        }
+    }
+}
+
+impl<E, Args, R> Multicall<E, Args, R>
+where
+    E: Environment,
+{
+    /// Creates a new empty batch that collects the outcomes of all of its calls.
+    #[inline]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Sets how the batch proceeds after one of its calls has failed.
+    #[inline]
+    pub fn mode(mut self, mode: MulticallMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Appends the prepared call to the batch.
+    #[inline]
+    pub fn call(mut self, params: CallParams<E, Call<E>, Args, R>) -> Self {
+        self.calls.push(params);
+        self
+    }
+
+    /// Returns the number of batched calls.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.calls.len()
+    }
+
+    /// Returns `true` if no calls have been batched.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+}
+
+impl<E, Args, R> Extend<CallParams<E, Call<E>, Args, R>> for Multicall<E, Args, R>
+where
+    E: Environment,
+{
+    #[inline]
+    fn extend<I>(&mut self, calls: I)
+    where
+        I: IntoIterator<Item = CallParams<E, Call<E>, Args, R>>,
+    {
+        self.calls.extend(calls)
+    }
+}
+
+impl<E, Args, R> Multicall<E, Args, R>
+where
+    E: Environment,
+    Args: scale::Encode,
+    R: scale::Decode,
+{
+    /// Executes the batched calls in order and returns their outcomes.
+    ///
+    /// # Note
+    ///
+    /// With [`MulticallMode::StopOnError`] no further calls are executed after
+    /// the first failed call, so fewer outcomes than batched calls may be returned.
+    pub fn fire(self) -> Vec<Result<R, Error>> {
+        let mut outcomes = Vec::with_capacity(self.calls.len());
+        for params in &self.calls {
+            let outcome = params.invoke();
+            let failed = outcome.is_err();
+            outcomes.push(outcome);
+            if failed && self.mode == MulticallMode::StopOnError {
+                break
+            }
+        }
+        outcomes
+    }
+}
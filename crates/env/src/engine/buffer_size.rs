@@ -0,0 +1,103 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The default size of the static buffer in bytes.
+const DEFAULT_BUFFER_SIZE: usize = 1 << 14; // 16 kB
+
+/// The size of the static buffer in bytes that is used for the SCALE encoding and
+/// decoding of all values passed between the ink! smart contract and its environment.
+///
+/// # Note
+///
+/// Defaults to 16 kB and can be configured when compiling the ink! smart contract
+/// via the `INK_STATIC_BUFFER_SIZE` environment variable, e.g.
+/// `INK_STATIC_BUFFER_SIZE=32768 cargo contract build`.
+///
+/// Contracts with large storage values, inputs or outputs may require a larger
+/// buffer whereas contracts with only small values can reduce their memory
+/// footprint with a smaller one. Encoding a value that exceeds the buffer panics.
+pub const BUFFER_SIZE: usize = match option_env!("INK_STATIC_BUFFER_SIZE") {
+    Some(value) => parse_buffer_size(value),
+    None => DEFAULT_BUFFER_SIZE,
+};
+
+/// Parses the value of the `INK_STATIC_BUFFER_SIZE` environment variable.
+///
+/// # Panics
+///
+/// If the value is not a non-zero decimal number of bytes. Since this is
+/// evaluated at compile time this results in a compilation error.
+const fn parse_buffer_size(value: &str) -> usize {
+    let bytes = value.as_bytes();
+    if bytes.is_empty() {
+        panic!("the `INK_STATIC_BUFFER_SIZE` environment variable must not be empty")
+    }
+    let mut size: usize = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let digit = bytes[i];
+        if !digit.is_ascii_digit() {
+            panic!(
+                "the `INK_STATIC_BUFFER_SIZE` environment variable must be a decimal number of bytes"
+            )
+        }
+        size = match size.checked_mul(10) {
+            Some(size) => size,
+            None => {
+                panic!("the `INK_STATIC_BUFFER_SIZE` environment variable is too large")
+            }
+        };
+        size = match size.checked_add((digit - b'0') as usize) {
+            Some(size) => size,
+            None => {
+                panic!("the `INK_STATIC_BUFFER_SIZE` environment variable is too large")
+            }
+        };
+        i += 1;
+    }
+    if size == 0 {
+        panic!("the `INK_STATIC_BUFFER_SIZE` environment variable must not be zero")
+    }
+    size
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_buffer_size_works() {
+        assert_eq!(parse_buffer_size("16384"), 16384);
+        assert_eq!(parse_buffer_size("1"), 1);
+        assert_eq!(parse_buffer_size("0065536"), 65536);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be a decimal number of bytes")]
+    fn parse_buffer_size_non_decimal_fails() {
+        parse_buffer_size("16 kB");
+    }
+
+    #[test]
+    #[should_panic(expected = "must not be zero")]
+    fn parse_buffer_size_zero_fails() {
+        parse_buffer_size("0");
+    }
+
+    #[test]
+    #[should_panic(expected = "is too large")]
+    fn parse_buffer_size_overflow_fails() {
+        parse_buffer_size("100000000000000000000000");
+    }
+}
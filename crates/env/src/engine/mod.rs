@@ -18,6 +18,16 @@ use crate::backend::{
 };
 use cfg_if::cfg_if;
 
+mod buffer_size;
+
+/// The property cache is only used on-chain but is unit tested off-chain.
+#[cfg(all(test, feature = "std"))]
+#[allow(dead_code)]
+#[path = "on_chain/cache.rs"]
+mod property_cache;
+
+pub use self::buffer_size::BUFFER_SIZE;
+
 pub trait OnInstance: EnvBackend + TypedEnvBackend {
     fn on_instance<F, R>(f: F) -> R
     where
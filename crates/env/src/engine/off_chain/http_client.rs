@@ -0,0 +1,359 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A minimal blocking HTTP/1.1 client for the off-chain environment.
+//!
+//! Only plain `http://` URLs are supported. The connection is closed after
+//! every request, bodies are sent with a `Content-Length` header and responses
+//! with either a `Content-Length` or a chunked transfer encoding are understood.
+//!
+//! Requires the `http-client` crate feature.
+
+use crate::http::{
+    HttpRequest,
+    HttpResponse,
+};
+use std::{
+    io::{
+        Read,
+        Write,
+    },
+    net::TcpStream,
+    time::Duration,
+};
+
+/// The timeout applied to connecting, reading and writing.
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The status code returned by the chain extension method if the request failed.
+const HTTP_REQUEST_FAILED: u32 = 1;
+
+/// Errors that can occur upon performing an HTTP request.
+#[derive(Debug, PartialEq, Eq)]
+pub enum HttpError {
+    /// The request cannot be sent as is, e.g. because a header contains a line break.
+    InvalidRequest,
+    /// The URL is malformed or uses an unsupported scheme.
+    InvalidUrl,
+    /// The connection to the remote host failed.
+    Io,
+    /// The remote host sent a malformed response.
+    InvalidResponse,
+}
+
+impl From<std::io::Error> for HttpError {
+    fn from(_: std::io::Error) -> Self {
+        Self::Io
+    }
+}
+
+/// The parts of an `http://` URL required to perform a request.
+struct Url<'a> {
+    host: &'a str,
+    authority: &'a str,
+    path: &'a str,
+}
+
+impl<'a> Url<'a> {
+    fn parse(url: &'a str) -> Result<Self, HttpError> {
+        let rest = url.strip_prefix("http://").ok_or(HttpError::InvalidUrl)?;
+        if !rest.bytes().all(|byte| byte.is_ascii_graphic()) {
+            return Err(HttpError::InvalidUrl)
+        }
+        let (authority, path) = match rest.find('/') {
+            Some(index) => (&rest[..index], &rest[index..]),
+            None => (rest, "/"),
+        };
+        if authority.is_empty() {
+            return Err(HttpError::InvalidUrl)
+        }
+        let host = match authority.rsplit_once(':') {
+            Some((host, port)) if port.parse::<u16>().is_ok() => host,
+            Some(_) => return Err(HttpError::InvalidUrl),
+            None => authority,
+        };
+        Ok(Self {
+            host,
+            authority,
+            path,
+        })
+    }
+
+    /// Returns the address to connect to, defaulting to port 80.
+    fn socket_addr(&self) -> String {
+        if self.authority.len() == self.host.len() {
+            format!("{}:80", self.host)
+        } else {
+            self.authority.to_string()
+        }
+    }
+}
+
+/// Returns `true` if `token` is a valid HTTP token, e.g. a method or a header name.
+fn is_token(token: &str) -> bool {
+    !token.is_empty()
+        && token.bytes().all(|byte| {
+            byte.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&byte)
+        })
+}
+
+/// Returns `true` if `value` is a valid header value.
+///
+/// In particular the value must not contain line breaks that would allow to
+/// inject further headers or requests.
+fn is_header_value(value: &str) -> bool {
+    value
+        .bytes()
+        .all(|byte| byte == b'\t' || (byte >= b' ' && byte != 0x7F))
+}
+
+/// Performs the SCALE encoded [`HttpRequest`] in `input` and writes the SCALE
+/// encoded [`HttpResponse`] to `output`.
+///
+/// Returns the status code of the chain extension method.
+pub fn handle(mut input: &[u8], output: &mut Vec<u8>) -> u32 {
+    let response = <HttpRequest as scale::Decode>::decode(&mut input)
+        .map_err(|_| HttpError::InvalidRequest)
+        .and_then(|request| send(&request));
+    match response {
+        Ok(response) => {
+            scale::Encode::encode_to(&response, output);
+            0
+        }
+        Err(_) => HTTP_REQUEST_FAILED,
+    }
+}
+
+/// Performs the HTTP `request` and returns the response of the remote host.
+pub fn send(request: &HttpRequest) -> Result<HttpResponse, HttpError> {
+    let url = Url::parse(&request.url)?;
+    let head = request_head(request, &url)?;
+    let mut stream = TcpStream::connect(url.socket_addr())?;
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    stream.set_write_timeout(Some(TIMEOUT))?;
+    stream.write_all(head.as_bytes())?;
+    stream.write_all(&request.body)?;
+    stream.flush()?;
+
+    let mut raw = Vec::new();
+    stream.read_to_end(&mut raw)?;
+    parse_response(&raw)
+}
+
+/// Returns the request line and the headers of the `request`.
+///
+/// # Errors
+///
+/// If the method or a header is invalid.
+fn request_head(request: &HttpRequest, url: &Url) -> Result<String, HttpError> {
+    if !is_token(&request.method) {
+        return Err(HttpError::InvalidRequest)
+    }
+    let mut head = format!(
+        "{} {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\nContent-Length: {}\r\n",
+        request.method,
+        url.path,
+        url.authority,
+        request.body.len()
+    );
+    for (name, value) in &request.headers {
+        if !is_token(name) || !is_header_value(value) {
+            return Err(HttpError::InvalidRequest)
+        }
+        head.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    head.push_str("\r\n");
+    Ok(head)
+}
+
+/// Parses a raw HTTP/1.1 response.
+fn parse_response(raw: &[u8]) -> Result<HttpResponse, HttpError> {
+    let head_len = raw
+        .windows(4)
+        .position(|window| window == b"\r\n\r\n")
+        .ok_or(HttpError::InvalidResponse)?;
+    let head =
+        core::str::from_utf8(&raw[..head_len]).map_err(|_| HttpError::InvalidResponse)?;
+    let payload = &raw[head_len + 4..];
+
+    let mut lines = head.split("\r\n");
+    let status_line = lines.next().ok_or(HttpError::InvalidResponse)?;
+    let mut parts = status_line.splitn(3, ' ');
+    match parts.next() {
+        Some(version) if version.starts_with("HTTP/") => (),
+        _ => return Err(HttpError::InvalidResponse),
+    }
+    let status_code = parts
+        .next()
+        .and_then(|code| code.parse::<u16>().ok())
+        .ok_or(HttpError::InvalidResponse)?;
+    let reason_phrase = parts.next().unwrap_or_default().to_string();
+    let headers = lines
+        .map(|line| {
+            line.split_once(':')
+                .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+                .ok_or(HttpError::InvalidResponse)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let header = |name: &str| {
+        headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    };
+    let body = if header("Transfer-Encoding")
+        .map(|value| value.eq_ignore_ascii_case("chunked"))
+        .unwrap_or(false)
+    {
+        decode_chunked(payload)?
+    } else if let Some(len) = header("Content-Length") {
+        let len = len
+            .parse::<usize>()
+            .map_err(|_| HttpError::InvalidResponse)?;
+        payload
+            .get(..len)
+            .ok_or(HttpError::InvalidResponse)?
+            .to_vec()
+    } else {
+        payload.to_vec()
+    };
+    Ok(HttpResponse {
+        status_code,
+        reason_phrase,
+        headers,
+        body,
+    })
+}
+
+/// Decodes a body sent with `Transfer-Encoding: chunked`.
+fn decode_chunked(mut payload: &[u8]) -> Result<Vec<u8>, HttpError> {
+    let mut body = Vec::new();
+    loop {
+        let line_len = payload
+            .windows(2)
+            .position(|window| window == b"\r\n")
+            .ok_or(HttpError::InvalidResponse)?;
+        let size_line = core::str::from_utf8(&payload[..line_len])
+            .map_err(|_| HttpError::InvalidResponse)?;
+        // Chunk extensions following a `;` are ignored.
+        let size = size_line.split(';').next().unwrap_or_default().trim();
+        let size =
+            usize::from_str_radix(size, 16).map_err(|_| HttpError::InvalidResponse)?;
+        payload = &payload[line_len + 2..];
+        if size == 0 {
+            return Ok(body)
+        }
+        let chunk = payload.get(..size).ok_or(HttpError::InvalidResponse)?;
+        body.extend_from_slice(chunk);
+        payload = payload.get(size + 2..).ok_or(HttpError::InvalidResponse)?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(method: &str, headers: &[(&str, &str)]) -> HttpRequest {
+        HttpRequest {
+            method: method.to_string(),
+            url: "http://localhost/".to_string(),
+            headers: headers
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            body: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn parse_url_works() {
+        let url = Url::parse("http://localhost:8080/api?x=1").unwrap();
+        assert_eq!(url.host, "localhost");
+        assert_eq!(url.socket_addr(), "localhost:8080");
+        assert_eq!(url.path, "/api?x=1");
+
+        let url = Url::parse("http://example.com").unwrap();
+        assert_eq!(url.socket_addr(), "example.com:80");
+        assert_eq!(url.path, "/");
+
+        assert_eq!(
+            Url::parse("https://example.com").err(),
+            Some(HttpError::InvalidUrl)
+        );
+        assert_eq!(
+            Url::parse("http://example.com:port/").err(),
+            Some(HttpError::InvalidUrl)
+        );
+        assert_eq!(
+            Url::parse("http://example.com/ HTTP/1.1\r\nX-Injected: 1").err(),
+            Some(HttpError::InvalidUrl)
+        );
+    }
+
+    #[test]
+    fn request_head_works() {
+        let request = request("GET", &[("Accept", "text/plain")]);
+        let url = Url::parse(&request.url).unwrap();
+        assert_eq!(
+            request_head(&request, &url).unwrap(),
+            "GET / HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\
+            Content-Length: 0\r\nAccept: text/plain\r\n\r\n"
+        );
+    }
+
+    #[test]
+    fn invalid_request_head_is_denied() {
+        let url = Url::parse("http://localhost/").unwrap();
+        for request in [
+            request("GET /admin HTTP/1.1\r\n", &[]),
+            request("", &[]),
+            request("GET", &[("X-Foo: bar\r\nX-Injected", "1")]),
+            request("GET", &[("", "1")]),
+            request("GET", &[("X-Foo", "bar\r\nX-Injected: 1")]),
+            request("GET", &[("X-Foo", "bar\n")]),
+        ] {
+            assert_eq!(request_head(&request, &url), Err(HttpError::InvalidRequest));
+        }
+    }
+
+    #[test]
+    fn parse_response_works() {
+        let response = parse_response(
+            b"HTTP/1.1 404 Not Found\r\nContent-Length: 5\r\nX-Foo: bar\r\n\r\nhello",
+        )
+        .unwrap();
+        assert_eq!(response.status_code, 404);
+        assert_eq!(response.reason_phrase, "Not Found");
+        assert_eq!(
+            response.headers,
+            vec![
+                ("Content-Length".to_string(), "5".to_string()),
+                ("X-Foo".to_string(), "bar".to_string()),
+            ]
+        );
+        assert_eq!(response.body, b"hello");
+    }
+
+    #[test]
+    fn parse_chunked_response_works() {
+        let response = parse_response(
+            b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n\
+            5\r\nhello\r\n6;ext=1\r\n world\r\n0\r\n\r\n",
+        )
+        .unwrap();
+        assert_eq!(response.status_code, 200);
+        assert_eq!(response.body, b"hello world");
+    }
+}
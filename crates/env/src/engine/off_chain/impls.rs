@@ -15,10 +15,14 @@
 use super::EnvInstance;
 use crate::{
     call::{
+        utils::ConstructorReturnType,
         Call,
         CallParams,
         CreateParams,
         DelegateCall,
+        Estimate,
+        InstantiateError,
+        StorageDeposit,
     },
     hash::{
         Blake2x128,
@@ -39,17 +43,19 @@ use crate::{
     Result,
     ReturnFlags,
     TypedEnvBackend,
+    Weight,
+    BUFFER_SIZE,
 };
 use ink_engine::{
     ext,
     ext::Engine,
+    DryRunInfo,
+    PendingExecution,
+};
+use ink_primitives::{
+    Key,
+    MessageResult,
 };
-use ink_primitives::Key;
-
-/// The capacity of the static buffer.
-/// This is the same size as the ink! on-chain environment. We chose to use the same size
-/// to be as close to the on-chain behavior as possible.
-const BUFFER_SIZE: usize = 1 << 14; // 16 kB
 
 impl CryptoHash for Blake2x128 {
     fn hash(input: &[u8], output: &mut <Self as HashOutput>::Type) {
@@ -113,8 +119,87 @@ impl From<ext::Error> for crate::Error {
             ext::Error::NotCallable => Self::NotCallable,
             ext::Error::LoggingDisabled => Self::LoggingDisabled,
             ext::Error::EcdsaRecoveryFailed => Self::EcdsaRecoveryFailed,
+            ext::Error::Sr25519VerifyFailed => Self::Sr25519VerifyFailed,
+            ext::Error::InvalidSealedKey => Self::InvalidSealedKey,
+        }
+    }
+}
+
+impl EnvInstance {
+    /// Calls the contract at `callee`, see [`Engine::prepare_call`].
+    fn call(
+        &mut self,
+        callee: &[u8],
+        flags: u32,
+        value: &[u8],
+        input: &[u8],
+        output: &mut &mut [u8],
+    ) -> core::result::Result<(), ext::Error> {
+        let pending = self
+            .engine()
+            .prepare_call(callee, flags, value, input, output)?;
+        match pending {
+            Some(pending) => self.execute(pending, output),
+            None => Ok(()),
         }
     }
+
+    /// Instantiates a contract from the code at `code_hash`, see
+    /// [`Engine::prepare_instantiate`].
+    fn instantiate(
+        &mut self,
+        code_hash: &[u8],
+        endowment: &[u8],
+        input: &[u8],
+        out_address: &mut &mut [u8],
+        out_return_value: &mut &mut [u8],
+        salt: &[u8],
+    ) -> core::result::Result<(), ext::Error> {
+        let pending = self.engine().prepare_instantiate(
+            code_hash,
+            endowment,
+            input,
+            out_address,
+            salt,
+        )?;
+        self.execute(pending, out_return_value)
+    }
+
+    /// Runs the `pending` contract execution and completes it.
+    ///
+    /// The engine is not borrowed while the entry point of the contract runs, since
+    /// the contract accesses the environment itself.
+    fn execute(
+        &mut self,
+        pending: PendingExecution,
+        output: &mut &mut [u8],
+    ) -> core::result::Result<(), ext::Error> {
+        let finished = pending.run();
+        self.engine().finish_execution(finished, output)
+    }
+}
+
+/// Converts the resources consumed by a dry-run of the engine into an [`Estimate`].
+fn into_estimate<E>(info: DryRunInfo) -> Result<Estimate<E::Balance>>
+where
+    E: Environment,
+{
+    let decode_balance = |balance: u128| -> Result<E::Balance> {
+        let encoded = scale::Encode::encode(&balance);
+        scale::Decode::decode(&mut &encoded[..]).map_err(Into::into)
+    };
+    let storage_deposit = match info.storage_deposit {
+        ink_engine::StorageDeposit::Refund(amount) => {
+            StorageDeposit::Refund(decode_balance(amount)?)
+        }
+        ink_engine::StorageDeposit::Charge(amount) => {
+            StorageDeposit::Charge(decode_balance(amount)?)
+        }
+    };
+    Ok(Estimate {
+        gas_consumed: Weight::from_parts(info.gas_consumed, 0),
+        storage_deposit,
+    })
 }
 
 #[derive(Default)]
@@ -178,7 +263,7 @@ impl EnvInstance {
     {
         let mut full_scope: [u8; BUFFER_SIZE] = [0; BUFFER_SIZE];
         let full_scope = &mut &mut full_scope[..];
-        ext_fn(&self.engine, full_scope);
+        ext_fn(&self.engine(), full_scope);
         scale::Decode::decode(&mut &full_scope[..]).map_err(Into::into)
     }
 }
@@ -189,7 +274,7 @@ impl EnvBackend for EnvInstance {
         V: scale::Encode,
     {
         let v = scale::Encode::encode(value);
-        self.engine.set_storage(key.as_ref(), &v[..]);
+        self.engine().set_storage(key.as_ref(), &v[..]);
     }
 
     fn get_contract_storage<R>(&mut self, key: &Key) -> Result<Option<R>>
@@ -197,7 +282,10 @@ impl EnvBackend for EnvInstance {
         R: scale::Decode,
     {
         let mut output: [u8; 9600] = [0; 9600];
-        match self.engine.get_storage(key.as_ref(), &mut &mut output[..]) {
+        match self
+            .engine()
+            .get_storage(key.as_ref(), &mut &mut output[..])
+        {
             Ok(_) => (),
             Err(ext::Error::KeyNotFound) => return Ok(None),
             Err(_) => panic!("encountered unexpected error"),
@@ -207,31 +295,38 @@ impl EnvBackend for EnvInstance {
     }
 
     fn clear_contract_storage(&mut self, key: &Key) {
-        self.engine.clear_storage(key.as_ref())
+        self.engine().clear_storage(key.as_ref())
     }
 
     fn decode_input<T>(&mut self) -> Result<T>
     where
         T: scale::Decode,
     {
-        unimplemented!("the off-chain env does not implement `seal_input`")
+        let mut output: [u8; BUFFER_SIZE] = [0; BUFFER_SIZE];
+        self.engine().input(&mut &mut output[..]);
+        scale::Decode::decode(&mut &output[..]).map_err(Into::into)
     }
 
-    fn return_value<R>(&mut self, _flags: ReturnFlags, _return_value: &R) -> !
+    fn return_value<R>(&mut self, flags: ReturnFlags, return_value: &R) -> !
     where
         R: scale::Encode,
     {
-        unimplemented!("the off-chain env does not implement `seal_return_value`")
+        let enc_return_value = &scale::Encode::encode(return_value)[..];
+        self.engine()
+            .return_value(flags.into_u32(), enc_return_value)
     }
 
     fn debug_message(&mut self, message: &str) {
-        self.engine.debug_message(message)
+        self.engine().debug_message(message)
     }
 
     fn hash_bytes<H>(&mut self, input: &[u8], output: &mut <H as HashOutput>::Type)
     where
         H: CryptoHash,
     {
+        self.engine().charge_gas(|schedule| {
+            schedule.hash_per_byte.saturating_mul(input.len() as u64)
+        });
         <H as CryptoHash>::hash(input, output)
     }
 
@@ -241,6 +336,11 @@ impl EnvBackend for EnvInstance {
         T: scale::Encode,
     {
         let enc_input = &scale::Encode::encode(input)[..];
+        self.engine().charge_gas(|schedule| {
+            schedule
+                .hash_per_byte
+                .saturating_mul(enc_input.len() as u64)
+        });
         <H as CryptoHash>::hash(enc_input, output)
     }
 
@@ -259,6 +359,8 @@ impl EnvBackend for EnvInstance {
             SECP256K1,
         };
 
+        self.engine().charge_gas(|schedule| schedule.signature);
+
         // In most implementations, the v is just 0 or 1 internally, but 27 was added
         // as an arbitrary number for signing Bitcoin messages and Ethereum adopted that as well.
         let recovery_byte = if signature[64] > 26 {
@@ -287,6 +389,17 @@ impl EnvBackend for EnvInstance {
         }
     }
 
+    fn sr25519_verify(
+        &mut self,
+        signature: &[u8; 64],
+        message: &[u8],
+        pub_key: &[u8; 32],
+    ) -> Result<()> {
+        self.engine()
+            .sr25519_verify(signature, message, pub_key)
+            .map_err(Into::into)
+    }
+
     fn call_chain_extension<I, T, E, ErrorCode, F, D>(
         &mut self,
         func_id: u32,
@@ -304,7 +417,7 @@ impl EnvBackend for EnvInstance {
         let enc_input = &scale::Encode::encode(input)[..];
         let mut output: [u8; BUFFER_SIZE] = [0; BUFFER_SIZE];
 
-        self.engine
+        self.engine()
             .call_chain_extension(func_id, enc_input, &mut &mut output[..]);
         let (status, out): (u32, Vec<u8>) = scale::Decode::decode(&mut &output[..])
             .unwrap_or_else(|error| {
@@ -318,6 +431,91 @@ impl EnvBackend for EnvInstance {
         let decoded = decode_to_result(&out[..])?;
         Ok(decoded)
     }
+
+    #[cfg(feature = "http-request")]
+    fn http_request(
+        &mut self,
+        request: &crate::http::HttpRequest,
+    ) -> Result<crate::http::HttpResponse> {
+        let enc_request = &scale::Encode::encode(request)[..];
+        let (status_code, output) = self
+            .engine()
+            .eval_chain_extension(crate::http::HTTP_REQUEST_FUNC_ID, enc_request);
+        if status_code != 0 {
+            return Err(Error::HttpRequestFailed)
+        }
+        // On-chain the response has to fit into the static buffer.
+        if output.len() > BUFFER_SIZE {
+            return Err(Error::BufferTooSmall)
+        }
+        scale::Decode::decode(&mut &output[..]).map_err(Into::into)
+    }
+
+    #[cfg(feature = "sealed-key")]
+    fn sign(
+        &mut self,
+        key_kind: crate::sealed_key::KeyKind,
+        message: &[u8],
+    ) -> Result<Vec<u8>> {
+        use crate::sealed_key::KeyKind;
+        let signature = match key_kind {
+            KeyKind::Sr25519 => {
+                let mut output = [0; 64];
+                self.engine().sign_sr25519(message, &mut output)?;
+                output.to_vec()
+            }
+            KeyKind::Ecdsa => {
+                let mut output = [0; 65];
+                self.engine().sign_ecdsa(message, &mut output)?;
+                output.to_vec()
+            }
+        };
+        Ok(signature)
+    }
+
+    #[cfg(feature = "sealed-key")]
+    fn derive_key(&mut self, salt: &[u8]) -> Result<[u8; 32]> {
+        let mut output = [0; 32];
+        self.engine().derive_key(salt, &mut output);
+        Ok(output)
+    }
+
+    #[cfg(feature = "local-cache")]
+    fn cache_set(&mut self, key: &[u8], value: &[u8], ttl: u64) -> Result<()> {
+        self.engine().cache_set(key, value, ttl);
+        Ok(())
+    }
+
+    #[cfg(feature = "local-cache")]
+    fn cache_get(&mut self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        let mut output: [u8; BUFFER_SIZE] = [0; BUFFER_SIZE];
+        match self.engine().cache_get(key, &mut &mut output[..]) {
+            Ok(_) => (),
+            Err(ext::Error::KeyNotFound) => return Ok(None),
+            Err(actual_error) => return Err(actual_error.into()),
+        }
+        let decoded = scale::Decode::decode(&mut &output[..])?;
+        Ok(Some(decoded))
+    }
+
+    #[cfg(feature = "host-random")]
+    fn getrandom(&mut self, len: u32) -> Vec<u8> {
+        let mut output = vec![0; len as usize];
+        self.engine().getrandom(&mut output[..]);
+        output
+    }
+
+    #[cfg(feature = "local-cache")]
+    fn cache_remove(&mut self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        let mut output: [u8; BUFFER_SIZE] = [0; BUFFER_SIZE];
+        match self.engine().cache_remove(key, &mut &mut output[..]) {
+            Ok(_) => (),
+            Err(ext::Error::KeyNotFound) => return Ok(None),
+            Err(actual_error) => return Err(actual_error.into()),
+        }
+        let decoded = scale::Decode::decode(&mut &output[..])?;
+        Ok(Some(decoded))
+    }
 }
 
 impl TypedEnvBackend for EnvInstance {
@@ -335,13 +533,33 @@ impl TypedEnvBackend for EnvInstance {
             })
     }
 
-    fn gas_left<E: Environment>(&mut self) -> u64 {
-        self.get_property::<u64>(Engine::gas_left)
+    fn gas_left<E: Environment>(&mut self) -> Weight {
+        self.get_property::<Weight>(Engine::gas_left)
             .unwrap_or_else(|error| {
                 panic!("could not read `gas_left` property: {:?}", error)
             })
     }
 
+    fn block_hash<E: Environment>(
+        &mut self,
+        block_number: E::BlockNumber,
+    ) -> Option<E::Hash> {
+        let enc_block_number = &scale::Encode::encode(&block_number)[..];
+        let mut output: [u8; 32] = [0; 32];
+        match self
+            .engine()
+            .block_hash(enc_block_number, &mut &mut output[..])
+        {
+            Ok(()) => {
+                let hash = scale::Decode::decode(&mut &output[..])
+                    .expect("could not decode block hash");
+                Some(hash)
+            }
+            Err(ext::Error::KeyNotFound) => None,
+            Err(_) => panic!("encountered unexpected error"),
+        }
+    }
+
     fn block_timestamp<E: Environment>(&mut self) -> E::Timestamp {
         self.get_property::<E::Timestamp>(Engine::block_timestamp)
             .unwrap_or_else(|error| {
@@ -385,7 +603,15 @@ impl TypedEnvBackend for EnvInstance {
         let builder = TopicsBuilder::default();
         let enc_topics = event.topics::<E, _>(builder.into());
         let enc_data = &scale::Encode::encode(&event)[..];
-        self.engine.deposit_event(&enc_topics[..], enc_data);
+        self.engine().deposit_event(&enc_topics[..], enc_data);
+    }
+
+    fn emit_event_with_topics<E>(&mut self, topics: &[E::Hash], data: &[u8])
+    where
+        E: Environment,
+    {
+        let enc_topics = &scale::Encode::encode(topics)[..];
+        self.engine().deposit_event(enc_topics, data);
     }
 
     fn invoke_contract<E, Args, R>(
@@ -397,12 +623,28 @@ impl TypedEnvBackend for EnvInstance {
         Args: scale::Encode,
         R: scale::Decode,
     {
-        let _gas_limit = params.gas_limit();
-        let _callee = params.callee();
-        let _call_flags = params.call_flags().into_u32();
-        let _transferred_value = params.transferred_value();
-        let _input = params.exec_input();
-        unimplemented!("off-chain environment does not support contract invocation")
+        let _ref_time_limit = params.ref_time_limit();
+        let _proof_size_limit = params.proof_size_limit();
+        let _storage_deposit_limit = params.storage_deposit_limit();
+        let enc_callee = &scale::Encode::encode(params.callee())[..];
+        let enc_transferred_value =
+            &scale::Encode::encode(params.transferred_value())[..];
+        let enc_input = &scale::Encode::encode(params.exec_input())[..];
+        let mut output: [u8; BUFFER_SIZE] = [0; BUFFER_SIZE];
+        let call_result = self.call(
+            enc_callee,
+            params.call_flags().into_u32(),
+            enc_transferred_value,
+            enc_input,
+            &mut &mut output[..],
+        );
+        match call_result {
+            Ok(()) | Err(ext::Error::CalleeReverted) => {
+                let decoded: MessageResult<R> = scale::Decode::decode(&mut &output[..])?;
+                decoded.map_err(Into::into)
+            }
+            Err(actual_error) => Err(actual_error.into()),
+        }
     }
 
     fn invoke_contract_delegate<E, Args, R>(
@@ -414,27 +656,122 @@ impl TypedEnvBackend for EnvInstance {
         Args: scale::Encode,
         R: scale::Decode,
     {
-        let _code_hash = params.code_hash();
-        unimplemented!(
-            "off-chain environment does not support delegated contract invocation"
-        )
+        let enc_code_hash = &scale::Encode::encode(params.code_hash())[..];
+        let enc_input = &scale::Encode::encode(params.exec_input())[..];
+        let mut output: [u8; BUFFER_SIZE] = [0; BUFFER_SIZE];
+        let pending = self.engine().prepare_delegate_call(
+            params.call_flags().into_u32(),
+            enc_code_hash,
+            enc_input,
+        );
+        let call_result =
+            pending.and_then(|pending| self.execute(pending, &mut &mut output[..]));
+        match call_result {
+            Ok(()) | Err(ext::Error::CalleeReverted) => {
+                let decoded: MessageResult<R> = scale::Decode::decode(&mut &output[..])?;
+                decoded.map_err(Into::into)
+            }
+            Err(actual_error) => Err(actual_error.into()),
+        }
     }
 
-    fn instantiate_contract<E, Args, Salt, C>(
+    fn instantiate_contract<E, Args, Salt, C, RetType>(
         &mut self,
-        params: &CreateParams<E, Args, Salt, C>,
-    ) -> Result<E::AccountId>
+        params: &CreateParams<E, Args, Salt, C, RetType>,
+    ) -> core::result::Result<E::AccountId, InstantiateError<RetType::Error>>
     where
         E: Environment,
         Args: scale::Encode,
         Salt: AsRef<[u8]>,
+        RetType: ConstructorReturnType<C>,
     {
-        let _code_hash = params.code_hash();
-        let _gas_limit = params.gas_limit();
-        let _endowment = params.endowment();
-        let _input = params.exec_input();
-        let _salt_bytes = params.salt_bytes();
-        unimplemented!("off-chain environment does not support contract instantiation")
+        let _ref_time_limit = params.ref_time_limit();
+        let _proof_size_limit = params.proof_size_limit();
+        let _storage_deposit_limit = params.storage_deposit_limit();
+        let enc_code_hash = &scale::Encode::encode(params.code_hash())[..];
+        let enc_endowment = &scale::Encode::encode(params.endowment())[..];
+        let enc_input = &scale::Encode::encode(params.exec_input())[..];
+        let mut out_address: [u8; BUFFER_SIZE] = [0; BUFFER_SIZE];
+        let mut out_return_value: [u8; BUFFER_SIZE] = [0; BUFFER_SIZE];
+        let instantiate_result = self.instantiate(
+            enc_code_hash,
+            enc_endowment,
+            enc_input,
+            &mut &mut out_address[..],
+            &mut &mut out_return_value[..],
+            params.salt_bytes().as_ref(),
+        );
+        match instantiate_result {
+            Ok(()) => (),
+            Err(ext::Error::CalleeReverted) => {
+                return Err(InstantiateError::reverted::<C, RetType>(&out_return_value))
+            }
+            Err(actual_error) => return Err(Error::from(actual_error).into()),
+        }
+        let account_id = scale::Decode::decode(&mut &out_address[..])?;
+        Ok(account_id)
+    }
+
+    fn estimate_contract<E, Args, R>(
+        &mut self,
+        params: &CallParams<E, Call<E>, Args, R>,
+    ) -> Result<Estimate<E::Balance>>
+    where
+        E: Environment,
+        Args: scale::Encode,
+    {
+        let enc_callee = &scale::Encode::encode(params.callee())[..];
+        let enc_transferred_value =
+            &scale::Encode::encode(params.transferred_value())[..];
+        let enc_input = &scale::Encode::encode(params.exec_input())[..];
+        let mut output: [u8; BUFFER_SIZE] = [0; BUFFER_SIZE];
+        let dry_run = self.engine().begin_dry_run();
+        let call_result = self.call(
+            enc_callee,
+            params.call_flags().into_u32(),
+            enc_transferred_value,
+            enc_input,
+            &mut &mut output[..],
+        );
+        let info = self.engine().end_dry_run(dry_run);
+        match call_result {
+            Ok(()) => into_estimate::<E>(info),
+            Err(ext::Error::CalleeReverted) => {
+                // Only the dispatch result is of interest, not the returned value.
+                let decoded: MessageResult<()> = scale::Decode::decode(&mut &output[..])?;
+                decoded?;
+                Err(Error::CalleeReverted)
+            }
+            Err(actual_error) => Err(actual_error.into()),
+        }
+    }
+
+    fn estimate_instantiate_contract<E, Args, Salt, C, RetType>(
+        &mut self,
+        params: &CreateParams<E, Args, Salt, C, RetType>,
+    ) -> Result<Estimate<E::Balance>>
+    where
+        E: Environment,
+        Args: scale::Encode,
+        Salt: AsRef<[u8]>,
+    {
+        let enc_code_hash = &scale::Encode::encode(params.code_hash())[..];
+        let enc_endowment = &scale::Encode::encode(params.endowment())[..];
+        let enc_input = &scale::Encode::encode(params.exec_input())[..];
+        let mut out_address: [u8; BUFFER_SIZE] = [0; BUFFER_SIZE];
+        let mut out_return_value: [u8; BUFFER_SIZE] = [0; BUFFER_SIZE];
+        let dry_run = self.engine().begin_dry_run();
+        let instantiate_result = self.instantiate(
+            enc_code_hash,
+            enc_endowment,
+            enc_input,
+            &mut &mut out_address[..],
+            &mut &mut out_return_value[..],
+            params.salt_bytes().as_ref(),
+        );
+        let info = self.engine().end_dry_run(dry_run);
+        instantiate_result?;
+        into_estimate::<E>(info)
     }
 
     fn terminate_contract<E>(&mut self, beneficiary: E::AccountId) -> !
@@ -442,7 +779,7 @@ impl TypedEnvBackend for EnvInstance {
         E: Environment,
     {
         let buffer = scale::Encode::encode(&beneficiary);
-        self.engine.terminate(&buffer[..])
+        self.engine().terminate(&buffer[..])
     }
 
     fn transfer<E>(&mut self, destination: E::AccountId, value: E::Balance) -> Result<()>
@@ -451,14 +788,14 @@ impl TypedEnvBackend for EnvInstance {
     {
         let enc_destination = &scale::Encode::encode(&destination)[..];
         let enc_value = &scale::Encode::encode(&value)[..];
-        self.engine
+        self.engine()
             .transfer(enc_destination, enc_value)
             .map_err(Into::into)
     }
 
     fn weight_to_fee<E: Environment>(&mut self, gas: u64) -> E::Balance {
         let mut output: [u8; BUFFER_SIZE] = [0; BUFFER_SIZE];
-        self.engine.weight_to_fee(gas, &mut &mut output[..]);
+        self.engine().weight_to_fee(gas, &mut &mut output[..]);
         scale::Decode::decode(&mut &output[..]).unwrap_or_else(|error| {
             panic!("could not read `weight_to_fee` property: {:?}", error)
         })
@@ -469,35 +806,72 @@ impl TypedEnvBackend for EnvInstance {
         E: Environment,
     {
         let mut output: [u8; BUFFER_SIZE] = [0; BUFFER_SIZE];
-        self.engine.random(subject, &mut &mut output[..]);
-        scale::Decode::decode(&mut &output[..]).map_err(Into::into)
+        self.engine().random(subject, &mut &mut output[..]);
+        let seed = <E::Hash as scale::Decode>::decode(&mut &output[..])?;
+        // The seed is derived from the entropy of the current block and therefore
+        // only known since the current block.
+        let known_since = self.get_property::<E::BlockNumber>(Engine::block_number)?;
+        Ok((seed, known_since))
     }
 
-    fn is_contract<E>(&mut self, _account: &E::AccountId) -> bool
+    fn is_contract<E>(&mut self, account: &E::AccountId) -> bool
     where
         E: Environment,
     {
-        unimplemented!("off-chain environment does not support contract instantiation")
+        self.engine()
+            .is_contract(&scale::Encode::encode(account)[..])
     }
 
     fn caller_is_origin<E>(&mut self) -> bool
     where
         E: Environment,
     {
-        unimplemented!("off-chain environment does not support cross-contract calls")
+        self.engine().caller_is_origin()
     }
 
-    fn code_hash<E>(&mut self, _account: &E::AccountId) -> Result<E::Hash>
+    fn code_hash<E>(&mut self, account: &E::AccountId) -> Result<E::Hash>
     where
         E: Environment,
     {
-        unimplemented!("off-chain environment does not support `code_hash`")
+        let mut output: [u8; 32] = [0; 32];
+        self.engine()
+            .code_hash(&scale::Encode::encode(account)[..], &mut output[..])?;
+        let hash = scale::Decode::decode(&mut &output[..])?;
+        Ok(hash)
     }
 
     fn own_code_hash<E>(&mut self) -> Result<E::Hash>
     where
         E: Environment,
     {
-        unimplemented!("off-chain environment does not support `own_code_hash`")
+        let callee = self.engine().get_callee();
+        let mut output: [u8; 32] = [0; 32];
+        self.engine().code_hash(&callee[..], &mut output[..])?;
+        let hash = scale::Decode::decode(&mut &output[..])?;
+        Ok(hash)
+    }
+
+    fn instantiation_nonce<E>(&mut self) -> u64
+    where
+        E: Environment,
+    {
+        self.engine().instantiation_nonce()
+    }
+
+    fn xcm_execute<E, M>(&mut self, _msg: &M) -> Result<()>
+    where
+        E: Environment,
+        M: scale::Encode,
+    {
+        unimplemented!("off-chain environment does not support `xcm_execute`")
+    }
+
+    fn xcm_send<E, D, M>(&mut self, _dest: &D, _msg: &M) -> Result<[u8; 32]>
+    where
+        E: Environment,
+        D: scale::Encode,
+        M: scale::Encode,
+    {
+        unimplemented!("off-chain environment does not support `xcm_send`")
     }
 }
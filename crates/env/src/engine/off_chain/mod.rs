@@ -13,6 +13,8 @@
 // limitations under the License.
 
 mod call_data;
+#[cfg(feature = "http-client")]
+mod http_client;
 mod impls;
 pub mod test_api;
 mod types;
@@ -25,12 +27,33 @@ pub use call_data::CallData;
 use super::OnInstance;
 use crate::Error;
 
+use core::cell::{
+    RefCell,
+    RefMut,
+};
 use derive_more::From;
 use ink_engine::ext::Engine;
+use std::rc::Rc;
 
 /// The off-chain environment.
+///
+/// Every access to the environment is handed a new instance which shares the
+/// engine of the current thread. The engine is only borrowed for the duration
+/// of a single operation, so that the entry points of called contracts are able
+/// to access the environment themselves.
 pub struct EnvInstance {
-    engine: Engine,
+    engine: Rc<RefCell<Engine>>,
+}
+
+impl EnvInstance {
+    /// Returns the engine of the off-chain environment.
+    ///
+    /// # Panics
+    ///
+    /// If the engine is already borrowed by another operation of the environment.
+    fn engine(&self) -> RefMut<'_, Engine> {
+        self.engine.borrow_mut()
+    }
 }
 
 impl OnInstance for EnvInstance {
@@ -38,15 +61,15 @@ impl OnInstance for EnvInstance {
     where
         F: FnOnce(&mut Self) -> R,
     {
-        use core::cell::RefCell;
         thread_local!(
-            static INSTANCE: RefCell<EnvInstance> = RefCell::new(
-                EnvInstance {
-                    engine: Engine::new()
-                }
-            )
+            static ENGINE: Rc<RefCell<Engine>> = Rc::new(RefCell::new(Engine::new()))
         );
-        INSTANCE.with(|instance| f(&mut instance.borrow_mut()))
+        let mut instance = ENGINE.with(|engine| {
+            EnvInstance {
+                engine: Rc::clone(engine),
+            }
+        });
+        f(&mut instance)
     }
 }
 
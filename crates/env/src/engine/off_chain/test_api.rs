@@ -15,19 +15,37 @@
 //! Operations on the off-chain testing environment.
 
 use super::{
+    impls::TopicsBuilder,
     EnvInstance,
     OnInstance,
 };
 use crate::{
+    call::Selector,
+    topics::{
+        Topics,
+        TopicsBuilderBackend,
+    },
     Environment,
     Result,
 };
 use core::fmt::Debug;
 use ink_engine::test_api::RecordedDebugMessages;
+use ink_primitives::{
+    Key,
+    MessageResult,
+};
 use std::panic::UnwindSafe;
 
 pub use super::call_data::CallData;
-pub use ink_engine::ChainExtension;
+pub use ink_engine::{
+    test_api::{
+        ContractTermination,
+        Snapshot,
+    },
+    ChainExtension,
+    EnvCall,
+    GasSchedule,
+};
 
 /// Record for an emitted event.
 #[derive(Clone)]
@@ -56,7 +74,7 @@ where
 {
     <EnvInstance as OnInstance>::on_instance(|instance| {
         instance
-            .engine
+            .engine()
             .set_balance(scale::Encode::encode(&account_id), new_balance);
     })
 }
@@ -79,7 +97,7 @@ where
 {
     <EnvInstance as OnInstance>::on_instance(|instance| {
         instance
-            .engine
+            .engine()
             .get_balance(scale::Encode::encode(&account_id))
             .map_err(Into::into)
     })
@@ -92,28 +110,151 @@ where
 {
     <EnvInstance as OnInstance>::on_instance(|instance| {
         instance
-            .engine
+            .engine()
             .chain_extension_handler
             .register(Box::new(extension));
     })
 }
 
+/// Registers the `handler` closure as the chain extension method with the given `func_id`.
+///
+/// The `handler` receives the SCALE encoded input of the chain extension method call and
+/// may fill the output buffer with a SCALE encoded result. It returns the status code of
+/// the call which is then converted via the `FromStatusCode` implementation of the
+/// chain extension's error code.
+///
+/// # Note
+///
+/// Replaces a chain extension method that has previously been registered for `func_id`.
+///
+/// # Example
+///
+/// ```
+/// # use ink_env::chain_extension::ChainExtensionMethod;
+/// ink_env::test::register_chain_extension_fn(42, |input, output| {
+///     let value = <u32 as scale::Decode>::decode(&mut &input[..]).unwrap();
+///     scale::Encode::encode_to(&(value * 2), output);
+///     0
+/// });
+/// let result = ChainExtensionMethod::build(42)
+///     .input::<u32>()
+///     .output::<u32>()
+///     .ignore_error_code()
+///     .call(&21);
+/// assert_eq!(result, 42);
+/// ```
+pub fn register_chain_extension_fn<F>(func_id: u32, handler: F)
+where
+    F: FnMut(&[u8], &mut Vec<u8>) -> u32 + 'static,
+{
+    struct ChainExtensionFn<F> {
+        func_id: u32,
+        handler: F,
+    }
+
+    impl<F> ChainExtension for ChainExtensionFn<F>
+    where
+        F: FnMut(&[u8], &mut Vec<u8>) -> u32,
+    {
+        fn func_id(&self) -> u32 {
+            self.func_id
+        }
+
+        fn call(&mut self, input: &[u8], output: &mut Vec<u8>) -> u32 {
+            // Chain extensions receive their input as a SCALE encoded byte vector.
+            let input = <Vec<u8> as scale::Decode>::decode(&mut &input[..])
+                .expect("chain extension input must be a SCALE encoded byte vector");
+            (self.handler)(&input[..], output)
+        }
+    }
+
+    register_chain_extension(ChainExtensionFn { func_id, handler })
+}
+
+/// Registers a chain extension method that performs the requests of
+/// [`http_request`][`crate::http_request`] as real HTTP requests.
+///
+/// Only plain `http://` URLs are supported. Requests with invalid methods or headers
+/// are refused. Without this, requests are dispatched to whatever chain extension method
+/// has been registered for [`HTTP_REQUEST_FUNC_ID`][`crate::http::HTTP_REQUEST_FUNC_ID`],
+/// e.g. via [`register_chain_extension_fn`].
+///
+/// # Note
+///
+/// Requires the `http-client` crate feature.
+#[cfg(feature = "http-client")]
+pub fn register_http_client() {
+    register_chain_extension_fn(
+        crate::http::HTTP_REQUEST_FUNC_ID,
+        super::http_client::handle,
+    )
+}
+
 /// Set the entropy hash of the current block.
 ///
 /// # Note
 ///
 /// This allows to control what [`random`][`crate::random`] returns.
-pub fn set_block_entropy<T>(_entropy: T::Hash) -> Result<()>
+/// The entropy of subsequent blocks is derived from it.
+///
+/// # Errors
+///
+/// If the hash cannot be converted into 32 bytes of entropy.
+pub fn set_block_entropy<T>(entropy: T::Hash) -> Result<()>
 where
     T: Environment,
 {
-    unimplemented!("off-chain environment does not yet support `set_block_entropy`");
+    let entropy =
+        <[u8; 32] as scale::Decode>::decode(&mut &scale::Encode::encode(&entropy)[..])?;
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance.engine().set_block_entropy(entropy);
+    });
+    Ok(())
+}
+
+/// Seeds the randomness returned by [`random`][`crate::random`].
+///
+/// Tests using the same seed observe the same sequence of random values,
+/// also across [`advance_block`] calls.
+///
+/// # Example
+///
+/// ```
+/// # use ink_env::DefaultEnvironment;
+/// ink_env::test::seed_randomness([0x42; 32]);
+/// let (first, _) = ink_env::random::<DefaultEnvironment>(b"subject").unwrap();
+/// ink_env::test::seed_randomness([0x42; 32]);
+/// let (second, _) = ink_env::random::<DefaultEnvironment>(b"subject").unwrap();
+/// assert_eq!(first, second);
+/// ```
+pub fn seed_randomness(seed: [u8; 32]) {
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance.engine().set_block_entropy(seed);
+    })
 }
 
 /// Returns the contents of the past performed environmental debug messages in order.
+///
+/// Every message passed to [`debug_message`][`crate::debug_message`] since the start
+/// of the test is recorded, including the messages of called contracts.
+///
+/// # Example
+///
+/// ```
+/// # ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+/// ink_env::debug_message("first\n");
+/// ink_env::debug_message("second\n");
+///
+/// let recorded = ink_env::test::recorded_debug_messages()
+///     .into_iter()
+///     .collect::<Vec<_>>();
+/// assert_eq!(recorded, vec!["first\n", "second\n"]);
+/// # Ok(())
+/// # }).unwrap();
+/// ```
 pub fn recorded_debug_messages() -> RecordedDebugMessages {
     <EnvInstance as OnInstance>::on_instance(|instance| {
-        instance.engine.get_emitted_debug_messages()
+        instance.engine().get_emitted_debug_messages()
     })
 }
 
@@ -135,29 +276,67 @@ where
     T: Environment,
 {
     <EnvInstance as OnInstance>::on_instance(|instance| {
-        instance.engine.advance_block();
+        instance.engine().advance_block();
+    })
+}
+
+/// Advances the chain by `n` blocks.
+///
+/// Each block increases the block timestamp by the block time of the chain.
+pub fn advance_blocks<T>(n: T::BlockNumber)
+where
+    T: Environment<BlockNumber = u32>, // Just temporary for the MVP!
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance.engine().advance_blocks(n);
+    })
+}
+
+/// Sets the number of the current block returned by
+/// [`block_number`][`crate::block_number`].
+pub fn set_block_number<T>(block_number: T::BlockNumber)
+where
+    T: Environment<BlockNumber = u32>, // Just temporary for the MVP!
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance.engine().set_block_number(block_number);
+    })
+}
+
+/// Sets the timestamp of the current block returned by
+/// [`block_timestamp`][`crate::block_timestamp`].
+///
+/// Blocks advanced afterwards continue from this timestamp.
+pub fn set_block_timestamp<T>(block_timestamp: T::Timestamp)
+where
+    T: Environment<Timestamp = u64>, // Just temporary for the MVP!
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance.engine().set_block_timestamp(block_timestamp);
     })
 }
 
 /// Sets a caller for the next call.
+#[deprecated(note = "use ExecutionContext")]
 pub fn set_caller<T>(caller: T::AccountId)
 where
     T: Environment,
     <T as Environment>::AccountId: From<[u8; 32]>,
 {
     <EnvInstance as OnInstance>::on_instance(|instance| {
-        instance.engine.set_caller(scale::Encode::encode(&caller));
+        instance.engine().set_caller(scale::Encode::encode(&caller));
     })
 }
 
 /// Sets the callee for the next call.
+#[deprecated(note = "use ExecutionContext")]
 pub fn set_callee<T>(callee: T::AccountId)
 where
     T: Environment,
     <T as Environment>::AccountId: From<[u8; 32]>,
 {
     <EnvInstance as OnInstance>::on_instance(|instance| {
-        instance.engine.set_callee(scale::Encode::encode(&callee));
+        instance.engine().set_callee(scale::Encode::encode(&callee));
     })
 }
 
@@ -169,7 +348,7 @@ where
     T: Environment,
 {
     <EnvInstance as OnInstance>::on_instance(|instance| {
-        let callee = instance.engine.get_callee();
+        let callee = instance.engine().get_callee();
         scale::Decode::decode(&mut &callee[..]).expect("encoding failed")
     })
 }
@@ -181,7 +360,7 @@ where
 {
     <EnvInstance as OnInstance>::on_instance(|instance| {
         instance
-            .engine
+            .engine()
             .get_contract_storage_rw(scale::Encode::encode(&account_id))
     })
 }
@@ -194,18 +373,321 @@ where
 {
     <EnvInstance as OnInstance>::on_instance(|instance| {
         instance
-            .engine
+            .engine()
             .set_balance(scale::Encode::encode(&account_id), new_balance);
     })
 }
 
 /// Sets the value transferred from the caller to the callee as part of the call.
+#[deprecated(note = "use ExecutionContext")]
 pub fn set_value_transferred<T>(value: T::Balance)
 where
     T: Environment<Balance = u128>, // Just temporary for the MVP!
 {
     <EnvInstance as OnInstance>::on_instance(|instance| {
-        instance.engine.set_value_transferred(value);
+        instance.engine().set_value_transferred(value);
+    })
+}
+
+/// Builds the execution context of the next message called in a test.
+///
+/// Upon [`apply`][`ExecutionContext::apply`] the caller, the transferred value and
+/// the gas limit are reset to their defaults unless they have been set explicitly,
+/// so no state of a previously called message leaks into the next one. The callee
+/// is the contract under test and only changed if set explicitly.
+///
+/// The defaults are `ALICE` as caller, no transferred value and no gas limit.
+///
+/// # Example
+///
+/// ```
+/// # use ink_env::DefaultEnvironment;
+/// # ink_env::test::run_test::<DefaultEnvironment, _>(|accounts| {
+/// ink_env::test::ExecutionContext::<DefaultEnvironment>::build()
+///     .caller(accounts.bob)
+///     .value(10)
+///     .gas(1_000_000_000)
+///     .apply();
+/// assert_eq!(ink_env::caller::<DefaultEnvironment>(), accounts.bob);
+/// assert_eq!(ink_env::transferred_value::<DefaultEnvironment>(), 10);
+///
+/// ink_env::test::ExecutionContext::<DefaultEnvironment>::build().apply();
+/// assert_eq!(ink_env::caller::<DefaultEnvironment>(), accounts.alice);
+/// assert_eq!(ink_env::transferred_value::<DefaultEnvironment>(), 0);
+/// # Ok(())
+/// # }).unwrap();
+/// ```
+pub struct ExecutionContext<T>
+where
+    T: Environment,
+{
+    caller: Option<T::AccountId>,
+    callee: Option<T::AccountId>,
+    value: T::Balance,
+    gas: Option<u64>,
+}
+
+impl<T> ExecutionContext<T>
+where
+    T: Environment<Balance = u128>, // Just temporary for the MVP!
+    <T as Environment>::AccountId: From<[u8; 32]>,
+{
+    /// Starts building the execution context of the next message.
+    pub fn build() -> Self {
+        Self {
+            caller: None,
+            callee: None,
+            value: 0,
+            gas: None,
+        }
+    }
+
+    /// Sets the caller of the next message.
+    pub fn caller(mut self, caller: T::AccountId) -> Self {
+        self.caller = Some(caller);
+        self
+    }
+
+    /// Sets the callee of the next message.
+    pub fn callee(mut self, callee: T::AccountId) -> Self {
+        self.callee = Some(callee);
+        self
+    }
+
+    /// Sets the value transferred as part of the next message.
+    pub fn value(mut self, value: T::Balance) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Sets the `ref_time` gas limit reported by [`gas_left`][`crate::gas_left`].
+    pub fn gas(mut self, gas_limit: u64) -> Self {
+        self.gas = Some(gas_limit);
+        self
+    }
+
+    /// Applies the execution context to the off-chain environment.
+    pub fn apply(self) {
+        let caller = self.caller.unwrap_or_else(|| default_accounts::<T>().alice);
+        <EnvInstance as OnInstance>::on_instance(|instance| {
+            instance.engine().set_caller(scale::Encode::encode(&caller));
+            if let Some(callee) = self.callee {
+                instance.engine().set_callee(scale::Encode::encode(&callee));
+            }
+            instance.engine().set_value_transferred(self.value);
+            match self.gas {
+                Some(gas_limit) => instance.engine().set_gas_limit(gas_limit),
+                None => instance.engine().reset_gas_limit(),
+            }
+        })
+    }
+}
+
+/// Sets the instantiation nonce returned by [`instantiation_nonce`][`crate::instantiation_nonce`].
+pub fn set_instantiation_nonce(nonce: u64) {
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance.engine().set_instantiation_nonce(nonce);
+    })
+}
+
+/// Reseeds the random number generator of the host used by
+/// [`getrandom`][`crate::getrandom`].
+///
+/// The generator of a new test environment is always seeded with the same seed.
+#[cfg(feature = "host-random")]
+pub fn set_host_rng_seed(seed: [u8; 32]) {
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance.engine().set_host_rng_seed(seed);
+    })
+}
+
+/// Takes a snapshot of the contract storage, the balances and the emitted events.
+///
+/// Use [`restore`] in order to revert the off-chain environment to the snapshot,
+/// e.g. to run multiple scenarios from a common setup.
+///
+/// # Example
+///
+/// ```
+/// # use ink_env::DefaultEnvironment;
+/// # ink_env::test::run_test::<DefaultEnvironment, _>(|accounts| {
+/// ink_env::test::set_account_balance::<DefaultEnvironment>(accounts.bob, 100);
+/// let snapshot = ink_env::test::snapshot();
+///
+/// ink_env::test::set_account_balance::<DefaultEnvironment>(accounts.bob, 0);
+/// ink_env::test::restore(&snapshot);
+///
+/// assert_eq!(
+///     ink_env::test::get_account_balance::<DefaultEnvironment>(accounts.bob),
+///     Ok(100)
+/// );
+/// # Ok(())
+/// # }).unwrap();
+/// ```
+pub fn snapshot() -> Snapshot {
+    <EnvInstance as OnInstance>::on_instance(|instance| instance.engine().snapshot())
+}
+
+/// Reverts the contract storage, the balances and the emitted events to the
+/// state of the `snapshot`.
+///
+/// The same snapshot can be restored multiple times.
+pub fn restore(snapshot: &Snapshot) {
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance.engine().restore(snapshot);
+    })
+}
+
+/// Returns the gas consumed by the host functions called so far.
+///
+/// # Note
+///
+/// The off-chain environment only approximates the gas costs of a real chain
+/// using its [`GasSchedule`]. Use this to catch gross gas regressions, e.g. by
+/// comparing the gas consumed before and after calling a message.
+pub fn gas_consumed() -> u64 {
+    <EnvInstance as OnInstance>::on_instance(|instance| instance.engine().gas_consumed())
+}
+
+/// Sets the costs charged for calling host functions from now on.
+pub fn set_gas_schedule(schedule: GasSchedule) {
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance.engine().set_gas_schedule(schedule);
+    })
+}
+
+/// Sets the storage deposit charged per storage cell and per byte of a cell.
+///
+/// Both default to `0` at the start of every test, i.e. no storage deposits are
+/// charged. Once set, creating or growing a storage cell charges the origin of the
+/// call, i.e. the account that called into the first contract, and removing or
+/// shrinking a cell refunds it. The deposits are held by the contract, see
+/// [`storage_deposit_of`]. When the contract terminates its deposit is released
+/// to the beneficiary.
+///
+/// Calls whose origin cannot pay the deposit trap.
+pub fn set_storage_deposit_prices<T>(per_item: T::Balance, per_byte: T::Balance)
+where
+    T: Environment<Balance = u128>, // Just temporary for the MVP!
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance
+            .engine()
+            .set_storage_deposit_prices(per_item, per_byte);
+    })
+}
+
+/// Returns the storage deposit held by the contract `account_id`.
+///
+/// # Example
+///
+/// ```
+/// # use ink_env::DefaultEnvironment;
+/// # use ink_primitives::Key;
+/// # ink_env::test::run_test::<DefaultEnvironment, _>(|accounts| {
+/// let contract = ink_env::AccountId::from([0x42; 32]);
+/// ink_env::test::ExecutionContext::<DefaultEnvironment>::build()
+///     .callee(contract)
+///     .apply();
+/// ink_env::test::set_storage_deposit_prices::<DefaultEnvironment>(100, 1);
+///
+/// ink_env::set_contract_storage(&Key::from([0x01; 32]), &[0u8; 32]);
+/// assert_eq!(ink_env::test::storage_deposit_of::<DefaultEnvironment>(contract), 132);
+///
+/// ink_env::clear_contract_storage(&Key::from([0x01; 32]));
+/// assert_eq!(ink_env::test::storage_deposit_of::<DefaultEnvironment>(contract), 0);
+/// # Ok(())
+/// # }).unwrap();
+/// ```
+pub fn storage_deposit_of<T>(account_id: T::AccountId) -> T::Balance
+where
+    T: Environment<Balance = u128>, // Just temporary for the MVP!
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance
+            .engine()
+            .storage_deposit_of(&scale::Encode::encode(&account_id))
+    })
+}
+
+/// Registers the entry points of a contract under `code_hash`.
+///
+/// Contracts instantiated from `code_hash` via
+/// [`instantiate_contract`][`crate::instantiate_contract`] and called via
+/// [`invoke_contract`][`crate::invoke_contract`] are dispatched to `deploy` and
+/// `call` respectively, just like the exports of a Wasm contract.
+///
+/// Use `ink_lang::utils::register_contract` for ink! smart contracts.
+///
+/// # Note
+///
+/// The called contract operates on its own storage, balance and execution context.
+/// Its storage and balance changes are reverted if it traps or reverts.
+/// Reentrant calls into contracts that are already executed trap the calling contract
+/// unless [`CallFlags::set_allow_reentry`][`crate::CallFlags::set_allow_reentry`] is set.
+pub fn register_contract<T>(code_hash: T::Hash, deploy: fn(), call: fn())
+where
+    T: Environment,
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance.engine().register_contract(
+            scale::Encode::encode(&code_hash),
+            ink_engine::ContractCode { deploy, call },
+        );
+    })
+}
+
+/// Mocks the ink! message with `selector` of the contract at `callee` to return `output`.
+///
+/// Calls of the message via [`invoke_contract`][`crate::invoke_contract`] return `output`
+/// regardless of their arguments instead of being dispatched to the code of `callee`,
+/// which therefore does not need to be registered or even be a contract. No value is
+/// transferred by mocked calls. Mocking the same message again replaces its output.
+///
+/// Use the `Mock` generated for ink! smart contracts for typed mocks of their messages.
+///
+/// # Note
+///
+/// All mocked messages are removed when the off-chain environment is reset.
+pub fn mock_message<T, R>(callee: T::AccountId, selector: Selector, output: R)
+where
+    T: Environment,
+    R: scale::Encode,
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance.engine().mock_message(
+            scale::Encode::encode(&callee),
+            selector.to_bytes(),
+            scale::Encode::encode(&MessageResult::Ok(output)),
+        );
+    })
+}
+
+/// Marks `account_id` as a contract instantiated from the code at `code_hash`.
+///
+/// This makes the contract under test known to [`code_hash`][`crate::code_hash`]
+/// and [`is_contract`][`crate::is_contract`], and allows other contracts to call it
+/// if `code_hash` has been registered via [`register_contract`].
+pub fn set_code_hash<T>(account_id: T::AccountId, code_hash: T::Hash)
+where
+    T: Environment,
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance.engine().set_code_hash(
+            &scale::Encode::encode(&account_id)[..],
+            &scale::Encode::encode(&code_hash)[..],
+        );
+    })
+}
+
+/// Sets the secret key sealed into the execution environment.
+///
+/// Used by [`sign`][`crate::sign`] and [`derive_key`][`crate::derive_key`].
+/// Defaults to `[0x01; 32]`.
+#[cfg(feature = "sealed-key")]
+pub fn set_sealed_key(sealed_key: [u8; 32]) {
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance.engine().set_sealed_key(sealed_key);
     })
 }
 
@@ -218,12 +700,54 @@ where
 {
     <EnvInstance as OnInstance>::on_instance(|instance| {
         instance
-            .engine
+            .engine()
             .count_used_storage_cells(&scale::Encode::encode(&account_id))
             .map_err(Into::into)
     })
 }
 
+/// Returns the raw encoded value stored under `key` in the storage of `account_id`.
+///
+/// In contrast to [`get_contract_storage`][`crate::get_contract_storage`] the value
+/// is not decoded and the read is not recorded.
+pub fn get_contract_storage_raw<T>(
+    account_id: &T::AccountId,
+    key: &Key,
+) -> Option<Vec<u8>>
+where
+    T: Environment,
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance
+            .engine()
+            .get_contract_storage_raw(&scale::Encode::encode(&account_id), key.as_ref())
+    })
+}
+
+/// Returns an iterator over the keys and raw encoded values of all storage cells
+/// used by `account_id`, ordered by their keys.
+///
+/// This allows to assert exactly which cells a contract wrote, e.g. in order to
+/// verify storage layout assumptions before an upgrade.
+pub fn contract_storage_cells<T>(
+    account_id: &T::AccountId,
+) -> impl Iterator<Item = (Key, Vec<u8>)>
+where
+    T: Environment,
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance
+            .engine()
+            .contract_storage_cells(&scale::Encode::encode(&account_id))
+            .into_iter()
+            .map(|(key, value)| {
+                let key = <[u8; 32]>::try_from(&key[..])
+                    .expect("encountered storage key of invalid length");
+                (Key::from(key), value)
+            })
+    })
+}
+
 /// Runs the given closure test function with the default configuration
 /// for the off-chain environment.
 pub fn run_test<T, F>(f: F) -> Result<()>
@@ -234,35 +758,77 @@ where
 {
     let default_accounts = default_accounts::<T>();
     <EnvInstance as OnInstance>::on_instance(|instance| {
-        instance.engine.initialize_or_reset();
+        instance.engine().initialize_or_reset();
 
         let encoded_alice = scale::Encode::encode(&default_accounts.alice);
-        instance.engine.set_caller(encoded_alice.clone());
-        instance.engine.set_callee(encoded_alice.clone());
+        instance.engine().set_caller(encoded_alice.clone());
+        instance.engine().set_callee(encoded_alice.clone());
 
         // set up the funds for the default accounts
         let substantial = 1_000_000;
         let some = 1_000;
-        instance.engine.set_balance(encoded_alice, substantial);
+        instance.engine().set_balance(encoded_alice, substantial);
         instance
-            .engine
+            .engine()
             .set_balance(scale::Encode::encode(&default_accounts.bob), some);
         instance
-            .engine
+            .engine()
             .set_balance(scale::Encode::encode(&default_accounts.charlie), some);
         instance
-            .engine
+            .engine()
             .set_balance(scale::Encode::encode(&default_accounts.django), 0);
         instance
-            .engine
+            .engine()
             .set_balance(scale::Encode::encode(&default_accounts.eve), 0);
         instance
-            .engine
+            .engine()
             .set_balance(scale::Encode::encode(&default_accounts.frank), 0);
     });
     f(default_accounts)
 }
 
+/// Runs the given future to completion on the current thread.
+///
+/// # Note
+///
+/// This is a minimal executor used by `#[ink::test]` for `async fn` test bodies.
+/// The current thread is parked while the future is pending and unparked once it
+/// is woken, so futures driven by other threads, e.g. of an e2e client, make
+/// progress as well.
+pub fn block_on<F>(future: F) -> F::Output
+where
+    F: core::future::Future,
+{
+    use std::{
+        sync::Arc,
+        task::{
+            Context,
+            Poll,
+            Wake,
+            Waker,
+        },
+        thread::Thread,
+    };
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark()
+        }
+    }
+
+    let mut future = Box::pin(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+    let mut context = Context::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => std::thread::park(),
+        }
+    }
+}
+
 /// Returns the default accounts for testing purposes:
 /// Alice, Bob, Charlie, Django, Eve and Frank.
 pub fn default_accounts<T>() -> DefaultAccounts<T>
@@ -303,13 +869,108 @@ where
 pub fn recorded_events() -> impl Iterator<Item = EmittedEvent> {
     <EnvInstance as OnInstance>::on_instance(|instance| {
         instance
-            .engine
+            .engine()
             .get_emitted_events()
             .into_iter()
             .map(|evt: ink_engine::test_api::EmittedEvent| evt.into())
     })
 }
 
+/// Starts recording the calls of contracts into the environment.
+///
+/// Storage accesses, transfers, emitted events, cross-contract calls,
+/// instantiations and terminations are recorded until [`stop_call_trace`] is
+/// called. A trace recorded previously is discarded.
+pub fn start_call_trace() {
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance.engine().start_tracing();
+    })
+}
+
+/// Stops recording the calls into the environment and returns them in order.
+///
+/// # Example
+///
+/// ```
+/// # use ink_env::test::EnvCall;
+/// # ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+/// ink_env::test::start_call_trace();
+/// ink_env::set_contract_storage(&ink_primitives::Key::from([0x42; 32]), &1u8);
+/// let trace = ink_env::test::stop_call_trace();
+/// assert!(matches!(trace[..], [EnvCall::SetStorage { .. }]));
+/// # Ok(())
+/// # }).unwrap();
+/// ```
+pub fn stop_call_trace() -> Vec<EnvCall> {
+    <EnvInstance as OnInstance>::on_instance(|instance| instance.engine().stop_tracing())
+}
+
+/// Returns the calls into the environment recorded since [`start_call_trace`].
+pub fn recorded_call_trace() -> Vec<EnvCall> {
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance.engine().recorded_calls()
+    })
+}
+
+/// Decodes the data of the recorded `event`.
+///
+/// Usually `Ev` is the base event enum of the contract that emitted the event.
+///
+/// # Errors
+///
+/// If the recorded data cannot be decoded into `Ev`.
+pub fn decode_event<Ev>(event: &EmittedEvent) -> Result<Ev>
+where
+    Ev: scale::Decode,
+{
+    <Ev as scale::Decode>::decode(&mut &event.data[..]).map_err(Into::into)
+}
+
+/// Returns the topics that are recorded when `event` is emitted.
+///
+/// This allows to verify the [`topics`][`EmittedEvent::topics`] of recorded events.
+pub fn event_topics<T, Ev>(event: &Ev) -> Vec<Vec<u8>>
+where
+    T: Environment,
+    Ev: Topics,
+{
+    event.topics::<T, _>(RecordingTopicsBuilder::default().into())
+}
+
+/// Records the topics of an event as they are recorded by the off-chain environment.
+#[derive(Default)]
+struct RecordingTopicsBuilder {
+    builder: TopicsBuilder,
+}
+
+impl<T> TopicsBuilderBackend<T> for RecordingTopicsBuilder
+where
+    T: Environment,
+{
+    type Output = Vec<Vec<u8>>;
+
+    fn expect(&mut self, expected_topics: usize) {
+        <TopicsBuilder as TopicsBuilderBackend<T>>::expect(
+            &mut self.builder,
+            expected_topics,
+        )
+    }
+
+    fn push_topic<V>(&mut self, topic_value: &V)
+    where
+        V: scale::Encode,
+    {
+        <TopicsBuilder as TopicsBuilderBackend<T>>::push_topic(
+            &mut self.builder,
+            topic_value,
+        )
+    }
+
+    fn output(self) -> Self::Output {
+        self.builder.topics
+    }
+}
+
 /// Tests if a contract terminates successfully after `self.env().terminate()`
 /// has been called.
 ///
@@ -320,6 +981,11 @@ pub fn recorded_events() -> impl Iterator<Item = EmittedEvent> {
 ///    remaining value in the contract
 /// * `expected_value_transferred_to_beneficiary`: The value which should have been transferred
 ///   to the `expected_beneficiary`.
+///
+/// Besides the recorded termination this also checks that the balance of the
+/// beneficiary increased by the transferred value and that the contract has been
+/// removed. If the contract is its own beneficiary the value is burned instead.
+///
 /// # Usage
 ///
 /// ```no_compile
@@ -342,16 +1008,48 @@ pub fn assert_contract_termination<T, F>(
     <T as Environment>::AccountId: Debug,
     <T as Environment>::Balance: Debug,
 {
-    let value_any = ::std::panic::catch_unwind(should_terminate)
+    let encoded_beneficiary = scale::Encode::encode(&expected_beneficiary);
+    let beneficiary_balance = || {
+        <EnvInstance as OnInstance>::on_instance(|instance| {
+            instance
+                .engine()
+                .get_balance(encoded_beneficiary.clone())
+                .ok()
+        })
+    };
+    let balance_before = beneficiary_balance().unwrap_or_default();
+
+    let payload = ::std::panic::catch_unwind(should_terminate)
         .expect_err("contract did not terminate");
-    let encoded_input = value_any
-        .downcast_ref::<Vec<u8>>()
-        .expect("panic object can not be cast");
-    let (value_transferred, encoded_beneficiary): (T::Balance, Vec<u8>) =
-        scale::Decode::decode(&mut &encoded_input[..]).expect("input can not be decoded");
+    let termination = match payload.downcast::<ContractTermination>() {
+        Ok(termination) => termination,
+        Err(payload) => ::std::panic::resume_unwind(payload),
+    };
     let beneficiary =
-        <T::AccountId as scale::Decode>::decode(&mut &encoded_beneficiary[..])
-            .expect("input can not be decoded");
+        <T::AccountId as scale::Decode>::decode(&mut &termination.beneficiary[..])
+            .expect("beneficiary can not be decoded");
+    let value_transferred = <T::Balance as scale::Decode>::decode(
+        &mut &scale::Encode::encode(&termination.value_transferred)[..],
+    )
+    .expect("transferred value can not be decoded");
     assert_eq!(value_transferred, expected_value_transferred_to_beneficiary);
     assert_eq!(beneficiary, expected_beneficiary);
+
+    let expected_balance = if termination.beneficiary == termination.contract {
+        None
+    } else {
+        Some(balance_before + termination.value_transferred)
+    };
+    assert_eq!(
+        beneficiary_balance(),
+        expected_balance,
+        "beneficiary did not receive the remaining balance"
+    );
+    let contract_removed = <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance
+            .engine()
+            .get_balance(termination.contract.clone())
+            .is_err()
+    });
+    assert!(contract_removed, "terminated contract has not been removed");
 }
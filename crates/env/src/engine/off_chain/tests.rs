@@ -15,6 +15,8 @@
 use crate::{
     engine::off_chain::impls::TopicsBuilder,
     topics::TopicsBuilderBackend,
+    Error,
+    ExistenceRequirement,
     Result,
 };
 
@@ -41,3 +43,1340 @@ fn topics_builder() -> Result<()> {
         Ok(())
     })
 }
+
+#[test]
+fn transfer_with_existence_keep_alive() -> Result<()> {
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|accounts| {
+        // given
+        let minimum_balance = crate::minimum_balance::<crate::DefaultEnvironment>();
+        crate::test::set_account_balance::<crate::DefaultEnvironment>(
+            accounts.alice,
+            minimum_balance + 10,
+        );
+
+        // when
+        let reaping = crate::transfer_with_existence::<crate::DefaultEnvironment>(
+            accounts.bob,
+            11,
+            ExistenceRequirement::KeepAlive,
+        );
+        let keeping_alive = crate::transfer_with_existence::<crate::DefaultEnvironment>(
+            accounts.bob,
+            10,
+            ExistenceRequirement::KeepAlive,
+        );
+
+        // then
+        assert_eq!(reaping, Err(Error::TransferWouldReap));
+        assert_eq!(keeping_alive, Ok(()));
+        assert_eq!(
+            crate::balance::<crate::DefaultEnvironment>(),
+            minimum_balance
+        );
+        Ok(())
+    })
+}
+
+#[test]
+fn transfer_with_existence_allow_death() -> Result<()> {
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|accounts| {
+        // given
+        let minimum_balance = crate::minimum_balance::<crate::DefaultEnvironment>();
+        crate::test::set_account_balance::<crate::DefaultEnvironment>(
+            accounts.alice,
+            minimum_balance + 10,
+        );
+        let bob_balance =
+            crate::test::get_account_balance::<crate::DefaultEnvironment>(accounts.bob)?;
+
+        // when
+        crate::transfer_with_existence::<crate::DefaultEnvironment>(
+            accounts.bob,
+            11,
+            ExistenceRequirement::AllowDeath,
+        )?;
+
+        // then
+        assert_eq!(
+            crate::test::get_account_balance::<crate::DefaultEnvironment>(accounts.alice),
+            Ok(minimum_balance - 1)
+        );
+        assert_eq!(
+            crate::test::get_account_balance::<crate::DefaultEnvironment>(accounts.bob),
+            Ok(bob_balance + 11)
+        );
+        Ok(())
+    })
+}
+
+#[test]
+fn terminate_contract_removes_contract() -> Result<()> {
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|accounts| {
+        // given
+        let contract = crate::AccountId::from([0x42; 32]);
+        crate::test::ExecutionContext::<crate::DefaultEnvironment>::build()
+            .callee(contract)
+            .apply();
+        crate::test::set_account_balance::<crate::DefaultEnvironment>(contract, 100);
+        crate::set_contract_storage(&ink_primitives::Key::from([0x01; 32]), &true);
+        let bob = accounts.bob;
+
+        // when
+        let should_terminate =
+            move || crate::terminate_contract::<crate::DefaultEnvironment>(bob);
+
+        // then
+        crate::test::assert_contract_termination::<crate::DefaultEnvironment, _>(
+            should_terminate,
+            bob,
+            100,
+        );
+        assert_eq!(
+            crate::test::get_account_balance::<crate::DefaultEnvironment>(bob),
+            Ok(1_100)
+        );
+        assert_eq!(
+            crate::test::contract_storage_cells::<crate::DefaultEnvironment>(&contract)
+                .count(),
+            0
+        );
+        Ok(())
+    })
+}
+
+#[test]
+fn debug_messages_are_recorded_per_test() -> Result<()> {
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|_| {
+        crate::debug_message("left over\n");
+        Ok(())
+    })?;
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|_| {
+        // when
+        crate::debug_message("first\n");
+        crate::debug_message("second\n");
+
+        // then
+        let recorded = crate::test::recorded_debug_messages()
+            .into_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(recorded, vec!["first\n", "second\n"]);
+        Ok(())
+    })
+}
+
+#[test]
+fn emit_event_with_topics() -> Result<()> {
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|_| {
+        // given
+        let topics = [crate::Hash::from([0x01; 32]), crate::Hash::from([0x02; 32])];
+        let data = scale::Encode::encode(&42u32);
+
+        // when
+        crate::emit_event_with_topics::<crate::DefaultEnvironment>(&topics, &data);
+
+        // then
+        let events = crate::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].topics, vec![vec![0x01; 32], vec![0x02; 32]]);
+        assert_eq!(events[0].data, data);
+        Ok(())
+    })
+}
+
+#[test]
+fn recorded_events_can_be_decoded() -> Result<()> {
+    use crate::topics::{
+        state::{
+            HasRemainingTopics,
+            Uninit,
+        },
+        Topics,
+        TopicsBuilder,
+        TopicsBuilderBackend,
+    };
+
+    #[derive(Debug, PartialEq, scale::Encode, scale::Decode)]
+    struct Transferred {
+        value: u128,
+    }
+
+    impl Topics for Transferred {
+        type RemainingTopics = [HasRemainingTopics; 2];
+
+        fn topics<E, B>(
+            &self,
+            builder: TopicsBuilder<Uninit, E, B>,
+        ) -> <B as TopicsBuilderBackend<E>>::Output
+        where
+            E: crate::Environment,
+            B: TopicsBuilderBackend<E>,
+        {
+            builder
+                .build::<Self>()
+                .push_topic(b"Transferred")
+                .push_topic(&self.value)
+                .finish()
+        }
+    }
+
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|_| {
+        let event = Transferred { value: 42 };
+        crate::emit_event::<crate::DefaultEnvironment, _>(Transferred { value: 42 });
+
+        let recorded = crate::test::recorded_events()
+            .next()
+            .expect("an event must have been recorded");
+        assert_eq!(crate::test::decode_event::<Transferred>(&recorded)?, event);
+        assert_eq!(
+            crate::test::event_topics::<crate::DefaultEnvironment, _>(&event),
+            recorded.topics
+        );
+        assert!(crate::test::decode_event::<(u128, u128)>(&recorded).is_err());
+        Ok(())
+    })
+}
+
+#[test]
+fn instantiation_nonce() -> Result<()> {
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|_| {
+        assert_eq!(crate::instantiation_nonce::<crate::DefaultEnvironment>(), 0);
+        crate::test::set_instantiation_nonce(42);
+        assert_eq!(
+            crate::instantiation_nonce::<crate::DefaultEnvironment>(),
+            42
+        );
+        Ok(())
+    })
+}
+
+#[test]
+fn block_hash() -> Result<()> {
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|_| {
+        assert_eq!(crate::block_hash::<crate::DefaultEnvironment>(0), None);
+        crate::test::advance_block::<crate::DefaultEnvironment>();
+        crate::test::advance_block::<crate::DefaultEnvironment>();
+        let first = crate::block_hash::<crate::DefaultEnvironment>(0);
+        let second = crate::block_hash::<crate::DefaultEnvironment>(1);
+        assert!(first.is_some());
+        assert!(second.is_some());
+        assert_ne!(first, second);
+        // The hashes of the current and future blocks are not yet known.
+        assert_eq!(crate::block_hash::<crate::DefaultEnvironment>(2), None);
+        assert_eq!(crate::block_hash::<crate::DefaultEnvironment>(3), None);
+        Ok(())
+    })
+}
+
+#[test]
+fn block_number_and_timestamp_can_be_set() -> Result<()> {
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|_| {
+        crate::test::set_block_number::<crate::DefaultEnvironment>(10);
+        crate::test::set_block_timestamp::<crate::DefaultEnvironment>(1_000);
+        crate::test::advance_blocks::<crate::DefaultEnvironment>(5);
+        assert_eq!(crate::block_number::<crate::DefaultEnvironment>(), 15);
+        assert_eq!(crate::block_timestamp::<crate::DefaultEnvironment>(), 1_030);
+        assert!(crate::block_hash::<crate::DefaultEnvironment>(9).is_some());
+        Ok(())
+    })
+}
+
+#[test]
+fn block_on_drives_futures_woken_by_other_threads() {
+    use core::{
+        future::Future,
+        pin::Pin,
+        task::{
+            Context,
+            Poll,
+        },
+    };
+
+    /// Completes after it has been woken once by another thread.
+    struct WokenByThread {
+        spawned: bool,
+        done: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    }
+
+    impl Future for WokenByThread {
+        type Output = u32;
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<u32> {
+            if self.done.load(std::sync::atomic::Ordering::SeqCst) {
+                return Poll::Ready(42)
+            }
+            if !self.spawned {
+                self.spawned = true;
+                let done = self.done.clone();
+                let waker = cx.waker().clone();
+                std::thread::spawn(move || {
+                    done.store(true, std::sync::atomic::Ordering::SeqCst);
+                    waker.wake();
+                });
+            }
+            Poll::Pending
+        }
+    }
+
+    assert_eq!(crate::test::block_on(async { 1 }), 1);
+    assert_eq!(
+        crate::test::block_on(WokenByThread {
+            spawned: false,
+            done: Default::default(),
+        }),
+        42
+    );
+}
+
+#[test]
+fn seeded_randomness_is_reproducible() -> Result<()> {
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|_| {
+        let random_sequence = || {
+            let mut sequence = Vec::new();
+            for _ in 0..3 {
+                sequence.push(crate::random::<crate::DefaultEnvironment>(b"subject"));
+                crate::test::advance_block::<crate::DefaultEnvironment>();
+            }
+            sequence
+        };
+        crate::test::seed_randomness([0x42; 32]);
+        let first = random_sequence();
+        crate::test::set_block_number::<crate::DefaultEnvironment>(0);
+        crate::test::seed_randomness([0x42; 32]);
+        let second = random_sequence();
+        assert_eq!(first, second);
+        assert_ne!(first[0], first[1]);
+
+        crate::test::set_block_entropy::<crate::DefaultEnvironment>([0x42; 32].into())?;
+        // The same entropy yields the same seed in a later block.
+        assert_eq!(
+            crate::random::<crate::DefaultEnvironment>(b"subject")?.0,
+            first[0]
+                .as_ref()
+                .expect("the first seed must be available")
+                .0
+        );
+        Ok(())
+    })
+}
+
+#[test]
+fn execution_context_resets_between_messages() -> Result<()> {
+    use crate::test::ExecutionContext;
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|accounts| {
+        ExecutionContext::<crate::DefaultEnvironment>::build()
+            .caller(accounts.bob)
+            .callee(accounts.charlie)
+            .value(10)
+            .gas(1_000_000_000)
+            .apply();
+        assert_eq!(crate::caller::<crate::DefaultEnvironment>(), accounts.bob);
+        assert_eq!(crate::transferred_value::<crate::DefaultEnvironment>(), 10);
+        assert!(
+            crate::gas_left::<crate::DefaultEnvironment>().ref_time() < 1_000_000_000
+        );
+
+        ExecutionContext::<crate::DefaultEnvironment>::build().apply();
+        assert_eq!(crate::caller::<crate::DefaultEnvironment>(), accounts.alice);
+        assert_eq!(
+            crate::account_id::<crate::DefaultEnvironment>(),
+            accounts.charlie
+        );
+        assert_eq!(crate::transferred_value::<crate::DefaultEnvironment>(), 0);
+        assert_eq!(
+            crate::gas_left::<crate::DefaultEnvironment>().ref_time(),
+            u64::MAX
+        );
+        Ok(())
+    })
+}
+
+#[test]
+fn raw_contract_storage_can_be_inspected() -> Result<()> {
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|accounts| {
+        let first = ink_primitives::Key::from([0x01; 32]);
+        let second = ink_primitives::Key::from([0x02; 32]);
+        crate::set_contract_storage(&second, &42u32);
+        crate::set_contract_storage(&first, &true);
+        assert_eq!(
+            crate::test::get_contract_storage_raw::<crate::DefaultEnvironment>(
+                &accounts.alice,
+                &second
+            ),
+            Some(vec![42, 0, 0, 0])
+        );
+        assert_eq!(
+            crate::test::contract_storage_cells::<crate::DefaultEnvironment>(
+                &accounts.alice
+            )
+            .collect::<Vec<_>>(),
+            vec![(first, vec![1]), (second, vec![42, 0, 0, 0])]
+        );
+        assert_eq!(
+            crate::test::contract_storage_cells::<crate::DefaultEnvironment>(
+                &accounts.bob
+            )
+            .count(),
+            0
+        );
+        Ok(())
+    })
+}
+
+#[test]
+fn gas_consumed_is_recorded() -> Result<()> {
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|_| {
+        let key = ink_primitives::Key::from([0x42; 32]);
+        let before = crate::test::gas_consumed();
+        crate::set_contract_storage(&key, &[0u8; 32]);
+        let small_write = crate::test::gas_consumed() - before;
+        crate::set_contract_storage(&key, &[0u8; 1024]);
+        let large_write = crate::test::gas_consumed() - before - small_write;
+        assert!(small_write > 0);
+        assert!(large_write > small_write);
+
+        crate::test::set_gas_schedule(crate::test::GasSchedule {
+            host_fn: 0,
+            hash_per_byte: 1,
+            ..Default::default()
+        });
+        let before = crate::test::gas_consumed();
+        let mut output = [0; 32];
+        crate::hash_bytes::<crate::hash::Sha2x256>(&[0; 100], &mut output);
+        assert_eq!(crate::test::gas_consumed() - before, 100);
+        Ok(())
+    })
+}
+
+#[test]
+fn chain_extension_fn_works() -> Result<()> {
+    use crate::chain_extension::ChainExtensionMethod;
+
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|_| {
+        crate::test::register_chain_extension_fn(1, |input, output| {
+            let (a, b) = <(u8, u8) as scale::Decode>::decode(&mut &input[..])
+                .expect("input must be a pair of `u8`");
+            scale::Encode::encode_to(&(a as u16 + b as u16), output);
+            0
+        });
+        let sum = ChainExtensionMethod::build(1)
+            .input::<(u8, u8)>()
+            .output::<u16>()
+            .ignore_error_code()
+            .call(&(200, 100));
+        assert_eq!(sum, 300);
+        Ok(())
+    })
+}
+
+#[test]
+fn chain_extension_receives_encoded_input() -> Result<()> {
+    use crate::chain_extension::ChainExtensionMethod;
+
+    struct Echo;
+
+    impl crate::test::ChainExtension for Echo {
+        fn func_id(&self) -> u32 {
+            2
+        }
+
+        fn call(&mut self, input: &[u8], output: &mut Vec<u8>) -> u32 {
+            scale::Encode::encode_to(&input.to_vec(), output);
+            0
+        }
+    }
+
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|_| {
+        crate::test::register_chain_extension(Echo);
+        let input = ChainExtensionMethod::build(2)
+            .input::<(u8, u8)>()
+            .output::<Vec<u8>>()
+            .ignore_error_code()
+            .call(&(200, 100));
+        // The input is handed over as a SCALE encoded byte vector.
+        assert_eq!(input, scale::Encode::encode(&vec![200u8, 100]));
+        Ok(())
+    })
+}
+
+#[test]
+#[cfg(feature = "http-request")]
+fn http_request_is_dispatched_to_chain_extension() -> Result<()> {
+    use crate::http::{
+        HttpRequest,
+        HttpResponse,
+        HTTP_REQUEST_FUNC_ID,
+    };
+
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|_| {
+        crate::test::register_chain_extension_fn(
+            HTTP_REQUEST_FUNC_ID,
+            |input, output| {
+                let request = <HttpRequest as scale::Decode>::decode(&mut &input[..])
+                    .expect("input must be an HTTP request");
+                if request.url != "http://localhost/price" {
+                    return 1
+                }
+                let response = HttpResponse {
+                    status_code: 200,
+                    reason_phrase: "OK".into(),
+                    headers: Vec::new(),
+                    body: request.body,
+                };
+                scale::Encode::encode_to(&response, output);
+                0
+            },
+        );
+
+        let response = crate::http_request("POST", "http://localhost/price", &[], b"42")?;
+        assert_eq!(response.status_code, 200);
+        assert_eq!(response.body, b"42");
+        assert_eq!(
+            crate::http_request("GET", "http://localhost/unknown", &[], &[]),
+            Err(Error::HttpRequestFailed)
+        );
+        Ok(())
+    })
+}
+
+#[test]
+#[cfg(feature = "http-request")]
+fn oversized_http_response_is_an_error() -> Result<()> {
+    use crate::http::{
+        HttpResponse,
+        HTTP_REQUEST_FUNC_ID,
+    };
+
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|_| {
+        crate::test::register_chain_extension_fn(HTTP_REQUEST_FUNC_ID, |_, output| {
+            let response = HttpResponse {
+                status_code: 200,
+                reason_phrase: "OK".into(),
+                headers: Vec::new(),
+                body: vec![0x00; crate::BUFFER_SIZE],
+            };
+            scale::Encode::encode_to(&response, output);
+            0
+        });
+        assert_eq!(
+            crate::http_request("GET", "http://localhost/", &[], &[]),
+            Err(Error::BufferTooSmall)
+        );
+        Ok(())
+    })
+}
+
+#[test]
+#[cfg(feature = "http-client")]
+fn http_client_works() -> Result<()> {
+    use std::{
+        io::{
+            Read,
+            Write,
+        },
+        net::TcpListener,
+    };
+
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|_| {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("failed to bind listener");
+        let addr = listener.local_addr().expect("failed to get local address");
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().expect("failed to accept connection");
+            let mut received = Vec::new();
+            let mut buf = [0; 1024];
+            while !received.ends_with(b"ping") {
+                let len = stream.read(&mut buf).expect("failed to read request");
+                received.extend_from_slice(&buf[..len]);
+            }
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 4\r\n\r\npong")
+                .expect("failed to write response");
+            received
+        });
+        crate::test::register_http_client();
+
+        let response = crate::http_request(
+            "POST",
+            &format!("http://{}/echo", addr),
+            &[("X-Test".into(), "1".into())],
+            b"ping",
+        )?;
+        assert_eq!(response.status_code, 200);
+        assert_eq!(response.body, b"pong");
+        let received = String::from_utf8(server.join().unwrap()).unwrap();
+        assert!(received.starts_with("POST /echo HTTP/1.1\r\n"));
+        assert!(received.contains("X-Test: 1\r\n"));
+        assert!(received.contains("Content-Length: 4\r\n"));
+
+        // Headers that would inject further headers are refused.
+        assert_eq!(
+            crate::http_request(
+                "GET",
+                &format!("http://{}/", addr),
+                &[("X-Test".into(), "1\r\nX-Injected: 1".into())],
+                &[],
+            ),
+            Err(Error::HttpRequestFailed)
+        );
+        Ok(())
+    })
+}
+
+#[test]
+#[cfg(feature = "sealed-key")]
+fn sign_with_sealed_key_works() -> Result<()> {
+    use crate::{
+        hash::Blake2x256,
+        sealed_key::KeyKind,
+    };
+    use secp256k1::{
+        PublicKey,
+        SecretKey,
+        SECP256K1,
+    };
+
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|_| {
+        crate::test::set_sealed_key([0x2A; 32]);
+        let signature = crate::sign(KeyKind::Ecdsa, b"hello ink!")?;
+        let signature: [u8; 65] = signature.try_into().expect("invalid signature length");
+        let mut message_hash = [0; 32];
+        crate::hash_bytes::<Blake2x256>(b"hello ink!", &mut message_hash);
+        let mut output = [0; 33];
+        crate::ecdsa_recover(&signature, &message_hash, &mut output)?;
+        let secret_key = SecretKey::from_slice(&[0x2A; 32]).unwrap();
+        let pub_key = PublicKey::from_secret_key(SECP256K1, &secret_key);
+        assert_eq!(output, pub_key.serialize());
+        assert_ne!(crate::derive_key(b"salt")?, [0x2A; 32]);
+
+        // Exceeds the order of the secp256k1 curve.
+        crate::test::set_sealed_key([0xFF; 32]);
+        assert_eq!(
+            crate::sign(KeyKind::Ecdsa, b"hello ink!"),
+            Err(Error::InvalidSealedKey)
+        );
+        Ok(())
+    })
+}
+
+mod cross_contract {
+    use crate::{
+        call::{
+            build_call,
+            build_create,
+            Call,
+            DelegateCall,
+            ExecutionInput,
+            FromAccountId,
+            InstantiateError,
+            Multicall,
+            MulticallMode,
+            Selector,
+            StorageDeposit,
+        },
+        AccountId,
+        CallFlags,
+        DefaultEnvironment,
+        Error,
+        ReturnFlags,
+    };
+    use ink_primitives::{
+        Key,
+        LangError,
+        MessageResult,
+    };
+
+    const CODE_HASH: [u8; 32] = [0x11; 32];
+    const PROXY_CODE_HASH: [u8; 32] = [0x22; 32];
+    const FORWARDER_CODE_HASH: [u8; 32] = [0x33; 32];
+    const GET: [u8; 4] = [0x00, 0x00, 0x00, 0x01];
+    const SET_AND_REVERT: [u8; 4] = [0x00, 0x00, 0x00, 0x02];
+    const REENTER: [u8; 4] = [0x00, 0x00, 0x00, 0x03];
+    const NEW: [u8; 4] = [0x00, 0x00, 0x00, 0x04];
+    const CHECKED_GET: [u8; 4] = [0x00, 0x00, 0x00, 0x05];
+    const FALLIBLE_NEW: [u8; 4] = [0x00, 0x00, 0x00, 0x06];
+    const ZERO_ARGUMENT: u8 = 1;
+    const KEY: Key = Key::new([0x00; 32]);
+
+    /// The account ID of an instantiated test contract.
+    struct ContractRef(AccountId);
+
+    impl FromAccountId<DefaultEnvironment> for ContractRef {
+        fn from_account_id(account_id: AccountId) -> Self {
+            Self(account_id)
+        }
+    }
+
+    fn deploy() {
+        let (selector, value) = crate::decode_input::<([u8; 4], u32)>()
+            .expect("input must be a selector followed by a `u32`");
+        if selector == FALLIBLE_NEW && value == 0 {
+            crate::return_value(
+                ReturnFlags::default().set_reverted(true),
+                &Result::<(), u8>::Err(ZERO_ARGUMENT),
+            )
+        }
+        crate::set_contract_storage(&KEY, &value);
+    }
+
+    fn call() {
+        let (selector, value) = crate::decode_input::<([u8; 4], u32)>()
+            .expect("input must be a selector followed by a `u32`");
+        match selector {
+            GET => {
+                let stored = crate::get_contract_storage::<u32>(&KEY)
+                    .expect("stored value must be a `u32`")
+                    .expect("value must have been stored upon instantiation");
+                let caller = crate::caller::<DefaultEnvironment>();
+                let transferred = crate::transferred_value::<DefaultEnvironment>();
+                crate::return_value(
+                    ReturnFlags::default(),
+                    &MessageResult::Ok((stored, caller, transferred)),
+                )
+            }
+            SET_AND_REVERT => {
+                crate::set_contract_storage(&KEY, &value);
+                crate::return_value(
+                    ReturnFlags::default().set_reverted(true),
+                    &MessageResult::Ok(()),
+                )
+            }
+            CHECKED_GET => {
+                let stored = crate::get_contract_storage::<u32>(&KEY)
+                    .expect("stored value must be a `u32`")
+                    .expect("value must have been stored upon instantiation");
+                let (flags, result) = match value {
+                    0 => {
+                        (
+                            ReturnFlags::default().set_reverted(true),
+                            Err(ZERO_ARGUMENT),
+                        )
+                    }
+                    _ => (ReturnFlags::default(), Ok(stored)),
+                };
+                crate::return_value(flags, &MessageResult::Ok(result))
+            }
+            REENTER => {
+                let caller = crate::caller::<DefaultEnvironment>();
+                let _ = build_call::<DefaultEnvironment>()
+                    .call_type(Call::new().callee(caller))
+                    .exec_input(ExecutionInput::new(Selector::new(GET)).push_arg(0u32))
+                    .returns::<()>()
+                    .fire();
+            }
+            _ => {
+                crate::return_value(
+                    ReturnFlags::default().set_reverted(true),
+                    &MessageResult::<()>::Err(LangError::CouldNotReadInput),
+                )
+            }
+        }
+    }
+
+    /// Delegates every call to the code at `CODE_HASH` with the forwarded input.
+    fn proxy() {
+        let _ = build_call::<DefaultEnvironment>()
+            .call_type(DelegateCall::new().code_hash(CODE_HASH.into()))
+            .call_flags(
+                CallFlags::default()
+                    .set_forward_input(true)
+                    .set_tail_call(true),
+            )
+            .fire();
+        unreachable!("the tail call returns from the proxy")
+    }
+
+    /// Forwards every call to the code at `CODE_HASH` via the call builder.
+    fn forwarder() {
+        build_call::<DefaultEnvironment>()
+            .call_type(DelegateCall::new().code_hash(CODE_HASH.into()))
+            .forward_input()
+    }
+
+    fn instantiate(value: u32, salt: &[u8]) -> crate::Result<AccountId> {
+        instantiate_code(CODE_HASH, value, salt)
+    }
+
+    fn instantiate_code(
+        code_hash: [u8; 32],
+        value: u32,
+        salt: &[u8],
+    ) -> crate::Result<AccountId> {
+        build_create::<DefaultEnvironment, ContractRef>()
+            .code_hash(code_hash.into())
+            .gas_limit(0)
+            .endowment(0)
+            .exec_input(ExecutionInput::new(Selector::new(NEW)).push_arg(value))
+            .salt_bytes(salt)
+            .params()
+            .instantiate()
+            .map(|contract| contract.0)
+            .map_err(Into::into)
+    }
+
+    #[test]
+    fn instantiate_and_call_works() -> crate::Result<()> {
+        crate::test::run_test::<DefaultEnvironment, _>(|accounts| {
+            crate::test::register_contract::<DefaultEnvironment>(
+                CODE_HASH.into(),
+                deploy,
+                call,
+            );
+            let contract = instantiate(42, b"salt")?;
+
+            // The address is derived from the deployer, the input and the salt.
+            let input = scale::Encode::encode(
+                &ExecutionInput::new(Selector::new(NEW)).push_arg(42u32),
+            );
+            assert_eq!(
+                contract,
+                crate::call::contract_address::<DefaultEnvironment>(
+                    &accounts.alice,
+                    &CODE_HASH.into(),
+                    &input,
+                    b"salt",
+                )
+            );
+            assert!(crate::is_contract::<DefaultEnvironment>(&contract));
+            assert_eq!(
+                crate::code_hash::<DefaultEnvironment>(&contract),
+                Ok(CODE_HASH.into())
+            );
+
+            let (stored, caller, transferred) = build_call::<DefaultEnvironment>()
+                .call_type(Call::new().callee(contract).transferred_value(10))
+                .exec_input(ExecutionInput::new(Selector::new(GET)).push_arg(0u32))
+                .returns::<(u32, AccountId, u128)>()
+                .fire()?;
+            assert_eq!(stored, 42);
+            assert_eq!(caller, accounts.alice);
+            assert_eq!(transferred, 10);
+            assert_eq!(
+                crate::test::get_account_balance::<DefaultEnvironment>(contract),
+                Ok(10)
+            );
+            // The execution context of the caller is restored.
+            assert_eq!(crate::caller::<DefaultEnvironment>(), accounts.alice);
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn instantiate_with_derived_salt_and_default_endowment_works() -> crate::Result<()> {
+        crate::test::run_test::<DefaultEnvironment, _>(|accounts| {
+            let minimum_balance = crate::minimum_balance::<DefaultEnvironment>();
+            crate::test::set_account_balance::<DefaultEnvironment>(
+                accounts.alice,
+                2 * minimum_balance,
+            );
+            crate::test::register_contract::<DefaultEnvironment>(
+                CODE_HASH.into(),
+                deploy,
+                call,
+            );
+            let instantiate = || {
+                build_create::<DefaultEnvironment, ContractRef>()
+                    .code_hash(CODE_HASH.into())
+                    .exec_input(ExecutionInput::new(Selector::new(NEW)).push_arg(42u32))
+                    .salt_derived_from(b"salt")
+                    .instantiate()
+                    .map(|contract| contract.0)
+            };
+            // The same input and salt bytes yield distinct contracts since every
+            // instantiation increments the nonce the salt is derived from.
+            let first = instantiate()?;
+            let second = instantiate()?;
+            assert_ne!(first, second);
+            // Without an explicit endowment the minimum balance is transferred.
+            for contract in [first, second] {
+                assert_eq!(
+                    crate::test::get_account_balance::<DefaultEnvironment>(contract),
+                    Ok(minimum_balance)
+                );
+            }
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn reverted_call_restores_storage() -> crate::Result<()> {
+        crate::test::run_test::<DefaultEnvironment, _>(|_| {
+            crate::test::register_contract::<DefaultEnvironment>(
+                CODE_HASH.into(),
+                deploy,
+                call,
+            );
+            let contract = instantiate(42, b"salt")?;
+
+            // The output of a reverted call is decoded just like on-chain.
+            build_call::<DefaultEnvironment>()
+                .call_type(Call::new().callee(contract))
+                .exec_input(
+                    ExecutionInput::new(Selector::new(SET_AND_REVERT)).push_arg(7u32),
+                )
+                .returns::<()>()
+                .fire()?;
+
+            let (stored, _, _) = build_call::<DefaultEnvironment>()
+                .call_type(Call::new().callee(contract))
+                .exec_input(ExecutionInput::new(Selector::new(GET)).push_arg(0u32))
+                .returns::<(u32, AccountId, u128)>()
+                .fire()?;
+            assert_eq!(stored, 42);
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn typed_revert_errors_are_decoded() -> crate::Result<()> {
+        crate::test::run_test::<DefaultEnvironment, _>(|_| {
+            crate::test::register_contract::<DefaultEnvironment>(
+                CODE_HASH.into(),
+                deploy,
+                call,
+            );
+            let contract = instantiate(42, b"salt")?;
+            let checked_get = |value: u32| {
+                build_call::<DefaultEnvironment>()
+                    .call_type(Call::new().callee(contract))
+                    .exec_input(
+                        ExecutionInput::new(Selector::new(CHECKED_GET)).push_arg(value),
+                    )
+                    .returns::<u32>()
+                    .fire_typed::<core::result::Result<u32, u8>>()
+            };
+            assert_eq!(checked_get(1)?, Ok(42));
+            assert_eq!(checked_get(0)?, Err(ZERO_ARGUMENT));
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn multicall_works() -> crate::Result<()> {
+        crate::test::run_test::<DefaultEnvironment, _>(|accounts| {
+            crate::test::register_contract::<DefaultEnvironment>(
+                CODE_HASH.into(),
+                deploy,
+                call,
+            );
+            let contract = instantiate(42, b"salt")?;
+            let call = |selector: [u8; 4]| {
+                build_call::<DefaultEnvironment>()
+                    .call_type(Call::new().callee(contract))
+                    .exec_input(
+                        ExecutionInput::new(Selector::new(selector)).push_arg(0u32),
+                    )
+                    .returns::<(u32, AccountId, u128)>()
+                    .params()
+            };
+            let batch = |mode| {
+                Multicall::new()
+                    .mode(mode)
+                    .call(call(GET))
+                    .call(call([0xFF; 4]))
+                    .call(call(GET))
+                    .fire()
+            };
+            let success = || Ok((42, accounts.alice, 0));
+            let failure = || Err(Error::LangError(LangError::CouldNotReadInput));
+
+            assert_eq!(
+                batch(MulticallMode::CollectResults),
+                vec![success(), failure(), success()]
+            );
+            assert_eq!(
+                batch(MulticallMode::StopOnError),
+                vec![success(), failure()]
+            );
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn mocked_messages_return_canned_outputs() -> crate::Result<()> {
+        crate::test::run_test::<DefaultEnvironment, _>(|accounts| {
+            crate::test::register_contract::<DefaultEnvironment>(
+                CODE_HASH.into(),
+                deploy,
+                call,
+            );
+            let contract = instantiate(42, b"salt")?;
+            let get = |callee: AccountId, value: u32| {
+                build_call::<DefaultEnvironment>()
+                    .call_type(Call::new().callee(callee))
+                    .exec_input(ExecutionInput::new(Selector::new(GET)).push_arg(value))
+                    .returns::<(u32, AccountId, u128)>()
+                    .fire()
+            };
+            // The callee of a mocked message does not have to be a contract.
+            crate::test::mock_message::<DefaultEnvironment, _>(
+                accounts.bob,
+                Selector::new(GET),
+                (1u32, accounts.charlie, 2u128),
+            );
+            assert_eq!(get(accounts.bob, 0), Ok((1, accounts.charlie, 2)));
+            assert_eq!(get(accounts.bob, 7), Ok((1, accounts.charlie, 2)));
+
+            // Mocked messages take precedence over the code of the contract.
+            assert_eq!(get(contract, 0), Ok((42, accounts.alice, 0)));
+            crate::test::mock_message::<DefaultEnvironment, _>(
+                contract,
+                Selector::new(GET),
+                (7u32, accounts.django, 0u128),
+            );
+            assert_eq!(get(contract, 0), Ok((7, accounts.django, 0)));
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn lang_error_is_returned_as_error() -> crate::Result<()> {
+        crate::test::run_test::<DefaultEnvironment, _>(|_| {
+            crate::test::register_contract::<DefaultEnvironment>(
+                CODE_HASH.into(),
+                deploy,
+                call,
+            );
+            let contract = instantiate(42, b"salt")?;
+            let result = build_call::<DefaultEnvironment>()
+                .call_type(Call::new().callee(contract))
+                .exec_input(ExecutionInput::new(Selector::new([0xFF; 4])).push_arg(0u32))
+                .returns::<()>()
+                .fire();
+            assert_eq!(result, Err(Error::LangError(LangError::CouldNotReadInput)));
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn fallible_constructor_errors_are_decoded() -> crate::Result<()> {
+        crate::test::run_test::<DefaultEnvironment, _>(|_| {
+            crate::test::register_contract::<DefaultEnvironment>(
+                CODE_HASH.into(),
+                deploy,
+                call,
+            );
+            let create = |value: u32| {
+                build_create::<DefaultEnvironment, ContractRef>()
+                    .code_hash(CODE_HASH.into())
+                    .endowment(0)
+                    .exec_input(
+                        ExecutionInput::new(Selector::new(FALLIBLE_NEW)).push_arg(value),
+                    )
+                    .salt_bytes(b"salt")
+            };
+
+            let result = create(0)
+                .returns::<Result<ContractRef, u8>>()
+                .instantiate()
+                .map(|contract| contract.0);
+            assert_eq!(result, Err(InstantiateError::Constructor(ZERO_ARGUMENT)));
+            // Without the return type of the constructor its error cannot be decoded.
+            let result = create(0).instantiate().map(|contract| contract.0);
+            assert_eq!(result, Err(InstantiateError::Env(Error::CalleeReverted)));
+
+            let contract = create(42)
+                .returns::<Result<ContractRef, u8>>()
+                .instantiate()
+                .expect("instantiation must succeed")
+                .0;
+            assert!(crate::is_contract::<DefaultEnvironment>(&contract));
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn estimate_reports_consumed_resources_without_effects() -> crate::Result<()> {
+        crate::test::run_test::<DefaultEnvironment, _>(|accounts| {
+            crate::test::register_contract::<DefaultEnvironment>(
+                CODE_HASH.into(),
+                deploy,
+                call,
+            );
+            crate::test::set_storage_deposit_prices::<DefaultEnvironment>(100, 1);
+            let create = || {
+                build_create::<DefaultEnvironment, ContractRef>()
+                    .code_hash(CODE_HASH.into())
+                    .endowment(0)
+                    .exec_input(ExecutionInput::new(Selector::new(NEW)).push_arg(42u32))
+                    .salt_bytes(b"salt")
+            };
+            let balance =
+                crate::test::get_account_balance::<DefaultEnvironment>(accounts.alice)?;
+
+            // The instantiation stores a single `u32` cell.
+            let estimate = create().estimate()?;
+            assert!(estimate.gas_consumed.ref_time() > 0);
+            assert_eq!(estimate.storage_deposit, StorageDeposit::Charge(100 + 4));
+            // Neither the contract nor the storage deposit is left behind.
+            let contract = create().instantiate()?.0;
+            assert_eq!(
+                crate::test::get_account_balance::<DefaultEnvironment>(accounts.alice),
+                Ok(balance - 104)
+            );
+
+            let set_and_revert = build_call::<DefaultEnvironment>()
+                .call_type(Call::new().callee(contract))
+                .exec_input(
+                    ExecutionInput::new(Selector::new(SET_AND_REVERT)).push_arg(7u32),
+                )
+                .returns::<()>();
+            assert_eq!(set_and_revert.estimate(), Err(Error::CalleeReverted));
+
+            let estimate = build_call::<DefaultEnvironment>()
+                .call_type(Call::new().callee(contract))
+                .exec_input(ExecutionInput::new(Selector::new(GET)).push_arg(0u32))
+                .returns::<(u32, AccountId, u128)>()
+                .estimate()?;
+            assert!(estimate.gas_consumed.ref_time() > 0);
+            assert_eq!(estimate.storage_deposit, StorageDeposit::Charge(0));
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn instantiate_unknown_code_hash_fails() -> crate::Result<()> {
+        crate::test::run_test::<DefaultEnvironment, _>(|accounts| {
+            assert_eq!(instantiate(42, b"salt").err(), Some(Error::CodeNotFound));
+            assert!(!crate::is_contract::<DefaultEnvironment>(&accounts.bob));
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn reentrant_call_without_allow_reentry_traps() -> crate::Result<()> {
+        crate::test::run_test::<DefaultEnvironment, _>(|accounts| {
+            crate::test::register_contract::<DefaultEnvironment>(
+                CODE_HASH.into(),
+                deploy,
+                call,
+            );
+            // The calling account is a contract itself that is called back.
+            crate::test::set_code_hash::<DefaultEnvironment>(
+                accounts.alice,
+                CODE_HASH.into(),
+            );
+            let contract = instantiate(42, b"salt")?;
+            let result = build_call::<DefaultEnvironment>()
+                .call_type(Call::new().callee(contract))
+                .call_flags(CallFlags::default())
+                .exec_input(ExecutionInput::new(Selector::new(REENTER)).push_arg(0u32))
+                .returns::<()>()
+                .fire();
+            assert_eq!(result, Err(Error::CalleeTrapped));
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn cross_contract_calls_are_traced() -> crate::Result<()> {
+        use crate::test::EnvCall;
+        use scale::Encode as _;
+
+        crate::test::run_test::<DefaultEnvironment, _>(|accounts| {
+            crate::test::register_contract::<DefaultEnvironment>(
+                CODE_HASH.into(),
+                deploy,
+                call,
+            );
+            let contract = instantiate(42, b"salt")?;
+            crate::test::start_call_trace();
+            let _ = build_call::<DefaultEnvironment>()
+                .call_type(Call::new().callee(contract))
+                .exec_input(ExecutionInput::new(Selector::new(GET)).push_arg(0u32))
+                .returns::<(u32, AccountId, u128)>()
+                .fire()?;
+            let trace = crate::test::stop_call_trace();
+            assert_eq!(
+                trace,
+                vec![
+                    EnvCall::Call {
+                        contract: accounts.alice.encode(),
+                        callee: contract.encode(),
+                        value: 0,
+                        input: (GET, 0u32).encode(),
+                    },
+                    EnvCall::GetStorage {
+                        contract: contract.encode(),
+                        key: KEY.encode(),
+                    },
+                ]
+            );
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn delegate_call_executes_in_context_of_proxy() -> crate::Result<()> {
+        crate::test::run_test::<DefaultEnvironment, _>(|accounts| {
+            crate::test::register_contract::<DefaultEnvironment>(
+                CODE_HASH.into(),
+                deploy,
+                call,
+            );
+            crate::test::register_contract::<DefaultEnvironment>(
+                PROXY_CODE_HASH.into(),
+                deploy,
+                proxy,
+            );
+            let proxy = instantiate_code(PROXY_CODE_HASH, 42, b"salt")?;
+
+            // The delegated code reads the storage of the proxy and observes its caller.
+            let (stored, caller, transferred) = build_call::<DefaultEnvironment>()
+                .call_type(Call::new().callee(proxy).transferred_value(10))
+                .exec_input(ExecutionInput::new(Selector::new(GET)).push_arg(0u32))
+                .returns::<(u32, AccountId, u128)>()
+                .fire()?;
+            assert_eq!(stored, 42);
+            assert_eq!(caller, accounts.alice);
+            assert_eq!(transferred, 10);
+
+            // A revert of the delegated code reverts the proxy.
+            build_call::<DefaultEnvironment>()
+                .call_type(Call::new().callee(proxy))
+                .exec_input(
+                    ExecutionInput::new(Selector::new(SET_AND_REVERT)).push_arg(7u32),
+                )
+                .returns::<()>()
+                .fire()?;
+            let stored =
+                crate::test::get_contract_storage_raw::<DefaultEnvironment>(&proxy, &KEY);
+            assert_eq!(stored, Some(scale::Encode::encode(&42u32)));
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn forward_input_returns_output_of_callee() -> crate::Result<()> {
+        crate::test::run_test::<DefaultEnvironment, _>(|accounts| {
+            crate::test::register_contract::<DefaultEnvironment>(
+                CODE_HASH.into(),
+                deploy,
+                call,
+            );
+            crate::test::register_contract::<DefaultEnvironment>(
+                FORWARDER_CODE_HASH.into(),
+                deploy,
+                forwarder,
+            );
+            let forwarder = instantiate_code(FORWARDER_CODE_HASH, 42, b"salt")?;
+
+            let (stored, caller, transferred) = build_call::<DefaultEnvironment>()
+                .call_type(Call::new().callee(forwarder))
+                .exec_input(ExecutionInput::new(Selector::new(GET)).push_arg(0u32))
+                .returns::<(u32, AccountId, u128)>()
+                .fire()?;
+            assert_eq!(stored, 42);
+            assert_eq!(caller, accounts.alice);
+            assert_eq!(transferred, 0);
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn storage_deposits_are_paid_by_origin() -> crate::Result<()> {
+        crate::test::run_test::<DefaultEnvironment, _>(|accounts| {
+            crate::test::register_contract::<DefaultEnvironment>(
+                CODE_HASH.into(),
+                deploy,
+                call,
+            );
+            crate::test::set_storage_deposit_prices::<DefaultEnvironment>(100, 1);
+            let balance_before =
+                crate::test::get_account_balance::<DefaultEnvironment>(accounts.alice)?;
+
+            // The constructor stores a `u32`.
+            let contract = instantiate(42, b"salt")?;
+            assert_eq!(
+                crate::test::storage_deposit_of::<DefaultEnvironment>(contract),
+                104
+            );
+            assert_eq!(
+                crate::test::get_account_balance::<DefaultEnvironment>(accounts.alice),
+                Ok(balance_before - 104)
+            );
+
+            // An origin that cannot pay the deposit traps the call.
+            crate::test::set_storage_deposit_prices::<DefaultEnvironment>(u128::MAX, 0);
+            assert_eq!(
+                instantiate(42, b"other salt").err(),
+                Some(Error::CalleeTrapped)
+            );
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn storage_deposit_limit_is_passed_through_call_params() -> crate::Result<()> {
+        crate::test::run_test::<DefaultEnvironment, _>(|_| {
+            crate::test::register_contract::<DefaultEnvironment>(
+                CODE_HASH.into(),
+                deploy,
+                call,
+            );
+            let contract = instantiate(42, b"salt")?;
+            let get = || ExecutionInput::new(Selector::new(GET)).push_arg(0u32);
+
+            let unlimited = build_call::<DefaultEnvironment>()
+                .call_type(Call::new().callee(contract))
+                .exec_input(get())
+                .returns::<(u32, AccountId, u128)>()
+                .params();
+            assert_eq!(unlimited.storage_deposit_limit(), None);
+
+            // The limit is kept by all subsequent calls of the builder.
+            let limited = build_call::<DefaultEnvironment>()
+                .call_type(Call::new().storage_deposit_limit(100).callee(contract))
+                .ref_time_limit(5000)
+                .proof_size_limit(1024)
+                .transferred_value(10)
+                .exec_input(get())
+                .returns::<(u32, AccountId, u128)>()
+                .params();
+            assert_eq!(limited.storage_deposit_limit(), Some(&100));
+            assert_eq!(limited.invoke()?.0, 42);
+
+            let overridden = build_call::<DefaultEnvironment>()
+                .call_type(Call::new().callee(contract).storage_deposit_limit(100))
+                .storage_deposit_limit(200)
+                .exec_input(get())
+                .returns::<(u32, AccountId, u128)>()
+                .params();
+            assert_eq!(overridden.storage_deposit_limit(), Some(&200));
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn storage_deposit_limit_is_passed_through_create_params() -> crate::Result<()> {
+        crate::test::run_test::<DefaultEnvironment, _>(|_| {
+            crate::test::register_contract::<DefaultEnvironment>(
+                CODE_HASH.into(),
+                deploy,
+                call,
+            );
+            let new = || ExecutionInput::new(Selector::new(NEW)).push_arg(42u32);
+
+            let unlimited = build_create::<DefaultEnvironment, ContractRef>()
+                .code_hash(CODE_HASH.into())
+                .endowment(0)
+                .exec_input(new())
+                .salt_bytes(b"unlimited")
+                .params();
+            assert_eq!(unlimited.storage_deposit_limit(), None);
+
+            // The limit is kept by all subsequent calls of the builder.
+            let limited = build_create::<DefaultEnvironment, ContractRef>()
+                .storage_deposit_limit(100)
+                .code_hash(CODE_HASH.into())
+                .ref_time_limit(5000)
+                .proof_size_limit(1024)
+                .endowment(0)
+                .exec_input(new())
+                .salt_bytes(b"limited")
+                .params();
+            assert_eq!(limited.storage_deposit_limit(), Some(&100));
+            let contract = limited.instantiate()?.0;
+            assert!(crate::is_contract::<DefaultEnvironment>(&contract));
+            Ok(())
+        })
+    }
+}
@@ -12,15 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-/// A static buffer with 16 kB of capacity.
+/// A static buffer with a capacity of [`BUFFER_SIZE`](crate::BUFFER_SIZE) bytes.
 pub struct StaticBuffer {
-    /// The static buffer with a total capacity of 16 kB.
+    /// The static buffer with a total capacity of `BUFFER_SIZE` bytes.
     buffer: [u8; Self::CAPACITY],
 }
 
 impl StaticBuffer {
     /// The capacity of the static buffer.
-    const CAPACITY: usize = 1 << 14; // 16 kB
+    const CAPACITY: usize = crate::BUFFER_SIZE;
 
     /// Creates a new static buffer.
     pub const fn new() -> Self {
@@ -77,12 +77,9 @@ impl<'a> EncodeScope<'a> {
 
 impl<'a> scale::Output for EncodeScope<'a> {
     fn write(&mut self, bytes: &[u8]) {
-        debug_assert!(
-            self.len() + bytes.len() <= self.capacity(),
-            "encode scope buffer overflowed. capacity is {} but last write index is {}",
-            self.capacity(),
-            self.len() + bytes.len(),
-        );
+        if self.len() + bytes.len() > self.capacity() {
+            buffer_overflow()
+        }
         let start = self.len;
         let len_bytes = bytes.len();
         self.buffer[start..(start + len_bytes)].copy_from_slice(bytes);
@@ -90,22 +87,28 @@ impl<'a> scale::Output for EncodeScope<'a> {
     }
 
     fn push_byte(&mut self, byte: u8) {
-        debug_assert_ne!(
-            self.len(),
-            self.capacity(),
-            "encode scope buffer overflowed. capacity is {} and buffer is already full",
-            self.capacity(),
-        );
+        if self.len() == self.capacity() {
+            buffer_overflow()
+        }
         self.buffer[self.len] = byte;
         self.len += 1;
     }
 }
 
+/// Panics because an encoding exceeded the capacity of the static buffer.
+#[cold]
+fn buffer_overflow() -> ! {
+    panic!(
+        "the static buffer overflowed while encoding, \
+         consider increasing its size via the `INK_STATIC_BUFFER_SIZE` environment variable"
+    )
+}
+
 /// Scoped access to an underlying bytes buffer.
 ///
 /// # Note
 ///
-/// This is used to efficiently chunk up ink!'s internal static 16 kB buffer
+/// This is used to efficiently chunk up ink!'s internal static buffer
 /// into smaller sub buffers for processing different parts of computations.
 #[derive(Debug)]
 pub struct ScopedBuffer<'a> {
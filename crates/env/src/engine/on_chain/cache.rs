@@ -0,0 +1,183 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Environmental properties that do not change during a single contract execution.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Property {
+    Caller = 0,
+    Address = 1,
+    ValueTransferred = 2,
+    BlockNumber = 3,
+    BlockTimestamp = 4,
+}
+
+impl Property {
+    /// The number of cacheable properties.
+    const COUNT: usize = 5;
+}
+
+/// The raw bytes of a cached property.
+#[derive(Copy, Clone)]
+struct Entry {
+    bytes: [u8; Entry::CAPACITY],
+    len: usize,
+}
+
+impl Entry {
+    /// The maximum size of a cached property in bytes.
+    ///
+    /// Properties that are bigger than this are never cached.
+    const CAPACITY: usize = 32;
+}
+
+/// Lazily populated cache for immutable environmental properties.
+///
+/// Every contract execution runs in a fresh Wasm instance and thus starts
+/// with an empty cache. The cache stores the raw bytes as returned by the
+/// host so that it is independent of the environmental types in use.
+pub struct PropertyCache {
+    entries: [Option<Entry>; Property::COUNT],
+}
+
+impl PropertyCache {
+    /// Creates a new empty property cache.
+    pub const fn new() -> Self {
+        Self {
+            entries: [None; Property::COUNT],
+        }
+    }
+
+    /// Writes the bytes of the `property` into `output`.
+    ///
+    /// Queries the host through `ext_fn` only upon the first access or if the
+    /// cached property does not fit into `output`.
+    pub fn get_or_fetch(
+        &mut self,
+        property: Property,
+        output: &mut [u8],
+        ext_fn: fn(output: &mut &mut [u8]),
+    ) {
+        let slot = &mut self.entries[property as usize];
+        if let Some(entry) = slot {
+            if entry.len <= output.len() {
+                output[..entry.len].copy_from_slice(&entry.bytes[..entry.len]);
+                return
+            }
+        }
+        let mut fetched = &mut output[..];
+        ext_fn(&mut fetched);
+        let len = fetched.len();
+        // An existing entry holds the full encoded property and is kept even if
+        // it did not fit into this output.
+        if slot.is_none() && len <= Entry::CAPACITY {
+            let mut bytes = [0x00; Entry::CAPACITY];
+            bytes[..len].copy_from_slice(fetched);
+            *slot = Some(Entry { bytes, len });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+
+    std::thread_local! {
+        static FETCHES: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn fetches() -> usize {
+        FETCHES.with(Cell::get)
+    }
+
+    fn fetch<const N: usize>(output: &mut &mut [u8], value: u8) {
+        FETCHES.with(|fetches| fetches.set(fetches.get() + 1));
+        let taken = core::mem::take(output);
+        let (head, _) = taken.split_at_mut(N);
+        head.fill(value);
+        *output = head;
+    }
+
+    fn fetch_account(output: &mut &mut [u8]) {
+        fetch::<32>(output, 0x01)
+    }
+
+    fn fetch_block_number(output: &mut &mut [u8]) {
+        fetch::<4>(output, 0x02)
+    }
+
+    fn fetch_oversized(output: &mut &mut [u8]) {
+        fetch::<33>(output, 0x03)
+    }
+
+    #[test]
+    fn cache_hit_does_not_query_host() {
+        let mut cache = PropertyCache::new();
+        let before = fetches();
+        let mut output = [0x00; 64];
+        cache.get_or_fetch(Property::Caller, &mut output, fetch_account);
+        assert_eq!(output[..32], [0x01; 32]);
+        assert_eq!(fetches(), before + 1);
+
+        let mut output = [0x00; 64];
+        cache.get_or_fetch(Property::Caller, &mut output, fetch_account);
+        assert_eq!(output[..32], [0x01; 32]);
+        assert_eq!(output[32..], [0x00; 32]);
+        assert_eq!(fetches(), before + 1);
+    }
+
+    #[test]
+    fn properties_are_cached_separately() {
+        let mut cache = PropertyCache::new();
+        let before = fetches();
+        let mut output = [0x00; 32];
+        cache.get_or_fetch(Property::Caller, &mut output, fetch_account);
+        let mut output = [0x00; 32];
+        cache.get_or_fetch(Property::BlockNumber, &mut output, fetch_block_number);
+        assert_eq!(output[..4], [0x02; 4]);
+        assert_eq!(fetches(), before + 2);
+    }
+
+    #[test]
+    fn entry_not_fitting_output_is_refetched() {
+        let mut cache = PropertyCache::new();
+        let before = fetches();
+        let mut output = [0x00; 32];
+        cache.get_or_fetch(Property::Address, &mut output, fetch_account);
+        let mut output = [0x00; 16];
+        cache.get_or_fetch(Property::Address, &mut output, |output| {
+            fetch::<16>(output, 0x04)
+        });
+        assert_eq!(output, [0x04; 16]);
+        assert_eq!(fetches(), before + 2);
+
+        // The cached entry still holds the full property for larger reads.
+        let mut output = [0x00; 32];
+        cache.get_or_fetch(Property::Address, &mut output, fetch_account);
+        assert_eq!(output, [0x01; 32]);
+        assert_eq!(fetches(), before + 2);
+    }
+
+    #[test]
+    fn oversized_property_is_never_cached() {
+        let mut cache = PropertyCache::new();
+        let before = fetches();
+        for _ in 0..2 {
+            let mut output = [0x00; 64];
+            cache.get_or_fetch(Property::ValueTransferred, &mut output, fetch_oversized);
+            assert_eq!(output[..33], [0x03; 33]);
+        }
+        assert_eq!(fetches(), before + 2);
+    }
+}
@@ -78,6 +78,14 @@ define_error_codes! {
     LoggingDisabled = 9,
     /// ECDSA public key recovery failed. Most probably wrong recovery id or signature.
     EcdsaRecoveryFailed = 11,
+    /// sr25519 signature verification failed. This may be because of an invalid
+    /// public key, invalid message or invalid signature.
+    Sr25519VerifyFailed = 12,
+    /// The XCM execution failed.
+    XcmExecutionFailed = 13,
+    /// The XCM message could not be sent. Most probably the destination is
+    /// unreachable or the message could not be routed.
+    XcmSendFailed = 14,
 }
 
 /// Thin-wrapper around a `u32` representing a pointer for Wasm32.
@@ -252,7 +260,6 @@ mod sys {
             output_ptr: Ptr32Mut<[u8]>,
             output_len_ptr: Ptr32Mut<u32>,
         );
-        pub fn seal_gas_left(output_ptr: Ptr32Mut<[u8]>, output_len_ptr: Ptr32Mut<u32>);
         pub fn seal_value_transferred(
             output_ptr: Ptr32Mut<[u8]>,
             output_len_ptr: Ptr32Mut<u32>,
@@ -302,10 +309,27 @@ mod sys {
     }
 
     #[link(wasm_import_module = "seal1")]
+    extern "C" {
+        pub fn seal_terminate(beneficiary_ptr: Ptr32<[u8]>) -> !;
+
+        pub fn seal_random(
+            subject_ptr: Ptr32<[u8]>,
+            subject_len: u32,
+            output_ptr: Ptr32Mut<[u8]>,
+            output_len_ptr: Ptr32Mut<u32>,
+        );
+
+        // Writes the SCALE encoded two-dimensional `Weight` into the output.
+        pub fn seal_gas_left(output_ptr: Ptr32Mut<[u8]>, output_len_ptr: Ptr32Mut<u32>);
+    }
+
+    #[link(wasm_import_module = "seal2")]
     extern "C" {
         pub fn seal_instantiate(
             init_code_ptr: Ptr32<[u8]>,
-            gas: u64,
+            ref_time_limit: u64,
+            proof_size_limit: u64,
+            deposit_limit_ptr: Ptr32<[u8]>,
             endowment_ptr: Ptr32<[u8]>,
             input_ptr: Ptr32<[u8]>,
             input_len: u32,
@@ -317,19 +341,12 @@ mod sys {
             salt_len: u32,
         ) -> ReturnCode;
 
-        pub fn seal_terminate(beneficiary_ptr: Ptr32<[u8]>) -> !;
-
-        pub fn seal_random(
-            subject_ptr: Ptr32<[u8]>,
-            subject_len: u32,
-            output_ptr: Ptr32Mut<[u8]>,
-            output_len_ptr: Ptr32Mut<u32>,
-        );
-
         pub fn seal_call(
             flags: u32,
             callee_ptr: Ptr32<[u8]>,
-            gas: u64,
+            ref_time_limit: u64,
+            proof_size_limit: u64,
+            deposit_limit_ptr: Ptr32<[u8]>,
             transferred_value_ptr: Ptr32<[u8]>,
             input_data_ptr: Ptr32<[u8]>,
             input_data_len: u32,
@@ -348,6 +365,15 @@ mod sys {
             output_ptr: Ptr32Mut<[u8]>,
         ) -> ReturnCode;
 
+        pub fn seal_sr25519_verify(
+            // 64 bytes of sr25519 signature
+            signature_ptr: Ptr32<[u8]>,
+            // 32 bytes of sr25519 public key
+            public_key_ptr: Ptr32<[u8]>,
+            message_len: u32,
+            message_ptr: Ptr32<[u8]>,
+        ) -> ReturnCode;
+
         pub fn seal_code_hash(
             account_id_ptr: Ptr32<[u8]>,
             output_ptr: Ptr32Mut<[u8]>,
@@ -358,6 +384,24 @@ mod sys {
             output_ptr: Ptr32Mut<[u8]>,
             output_len_ptr: Ptr32Mut<u32>,
         );
+
+        pub fn seal_instantiation_nonce() -> u64;
+
+        pub fn seal_block_hash(
+            block_number_ptr: Ptr32<[u8]>,
+            output_ptr: Ptr32Mut<[u8]>,
+            output_len_ptr: Ptr32Mut<u32>,
+        ) -> ReturnCode;
+
+        pub fn seal_xcm_execute(msg_ptr: Ptr32<[u8]>, msg_len: u32) -> ReturnCode;
+
+        pub fn seal_xcm_send(
+            dest_ptr: Ptr32<[u8]>,
+            msg_ptr: Ptr32<[u8]>,
+            msg_len: u32,
+            // 32 bytes of the XCM message hash
+            output_ptr: Ptr32Mut<[u8]>,
+        ) -> ReturnCode;
     }
 }
 
@@ -367,9 +411,25 @@ fn extract_from_slice(output: &mut &mut [u8], new_len: usize) {
     *output = &mut tmp[..new_len];
 }
 
+/// Pointer value signalling to the host that an optional argument was not provided.
+///
+/// This is used for the storage deposit limit which is unbounded if not set.
+const SENTINEL: u32 = u32::MAX;
+
+/// Returns a pointer to the given slice or the [`SENTINEL`] pointer if there is none.
+fn ptr_or_sentinel(data: Option<&[u8]>) -> Ptr32<'_, [u8]> {
+    match data {
+        Some(data) => Ptr32::from_slice(data),
+        None => Ptr32::new(SENTINEL),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn instantiate(
     code_hash: &[u8],
-    gas_limit: u64,
+    ref_time_limit: u64,
+    proof_size_limit: u64,
+    storage_deposit_limit: Option<&[u8]>,
     endowment: &[u8],
     input: &[u8],
     out_address: &mut &mut [u8],
@@ -382,7 +442,9 @@ pub fn instantiate(
         unsafe {
             sys::seal_instantiate(
                 Ptr32::from_slice(code_hash),
-                gas_limit,
+                ref_time_limit,
+                proof_size_limit,
+                ptr_or_sentinel(storage_deposit_limit),
                 Ptr32::from_slice(endowment),
                 Ptr32::from_slice(input),
                 input.len() as u32,
@@ -400,10 +462,13 @@ pub fn instantiate(
     ret_code.into()
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn call(
     flags: u32,
     callee: &[u8],
-    gas_limit: u64,
+    ref_time_limit: u64,
+    proof_size_limit: u64,
+    storage_deposit_limit: Option<&[u8]>,
     value: &[u8],
     input: &[u8],
     output: &mut &mut [u8],
@@ -414,7 +479,9 @@ pub fn call(
             sys::seal_call(
                 flags,
                 Ptr32::from_slice(callee),
-                gas_limit,
+                ref_time_limit,
+                proof_size_limit,
+                ptr_or_sentinel(storage_deposit_limit),
                 Ptr32::from_slice(value),
                 Ptr32::from_slice(input),
                 input.len() as u32,
@@ -678,6 +745,22 @@ pub fn ecdsa_recover(
     ret_code.into()
 }
 
+pub fn sr25519_verify(
+    signature: &[u8; 64],
+    message: &[u8],
+    pub_key: &[u8; 32],
+) -> Result {
+    let ret_code = unsafe {
+        sys::seal_sr25519_verify(
+            Ptr32::from_slice(signature),
+            Ptr32::from_slice(pub_key),
+            message.len() as u32,
+            Ptr32::from_slice(message),
+        )
+    };
+    ret_code.into()
+}
+
 pub fn is_contract(account_id: &[u8]) -> bool {
     let ret_val = unsafe { sys::seal_is_contract(Ptr32::from_slice(account_id)) };
     ret_val.into_bool()
@@ -709,3 +792,38 @@ pub fn own_code_hash(output: &mut [u8]) {
         )
     }
 }
+
+pub fn instantiation_nonce() -> u64 {
+    unsafe { sys::seal_instantiation_nonce() }
+}
+
+pub fn block_hash(block_number: &[u8], output: &mut &mut [u8]) -> Result {
+    let mut output_len = output.len() as u32;
+    let ret_code = unsafe {
+        sys::seal_block_hash(
+            Ptr32::from_slice(block_number),
+            Ptr32Mut::from_slice(output),
+            Ptr32Mut::from_ref(&mut output_len),
+        )
+    };
+    extract_from_slice(output, output_len as usize);
+    ret_code.into()
+}
+
+pub fn xcm_execute(msg: &[u8]) -> Result {
+    let ret_code =
+        unsafe { sys::seal_xcm_execute(Ptr32::from_slice(msg), msg.len() as u32) };
+    ret_code.into()
+}
+
+pub fn xcm_send(dest: &[u8], msg: &[u8], output: &mut [u8; 32]) -> Result {
+    let ret_code = unsafe {
+        sys::seal_xcm_send(
+            Ptr32::from_slice(dest),
+            Ptr32::from_slice(msg),
+            msg.len() as u32,
+            Ptr32Mut::from_slice(output),
+        )
+    };
+    ret_code.into()
+}
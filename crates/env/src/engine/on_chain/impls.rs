@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use super::{
+    cache::Property,
     ext,
     EnvInstance,
     Error as ExtError,
@@ -20,10 +21,13 @@ use super::{
 };
 use crate::{
     call::{
+        utils::ConstructorReturnType,
         Call,
         CallParams,
         CreateParams,
         DelegateCall,
+        Estimate,
+        InstantiateError,
     },
     hash::{
         Blake2x128,
@@ -45,8 +49,12 @@ use crate::{
     Result,
     ReturnFlags,
     TypedEnvBackend,
+    Weight,
+};
+use ink_primitives::{
+    Key,
+    MessageResult,
 };
-use ink_primitives::Key;
 
 impl CryptoHash for Blake2x128 {
     fn hash(input: &[u8], output: &mut <Self as HashOutput>::Type) {
@@ -110,10 +118,22 @@ impl From<ext::Error> for Error {
             ext::Error::NotCallable => Self::NotCallable,
             ext::Error::LoggingDisabled => Self::LoggingDisabled,
             ext::Error::EcdsaRecoveryFailed => Self::EcdsaRecoveryFailed,
+            ext::Error::Sr25519VerifyFailed => Self::Sr25519VerifyFailed,
+            ext::Error::XcmExecutionFailed => Self::XcmExecutionFailed,
+            ext::Error::XcmSendFailed => Self::XcmSendFailed,
         }
     }
 }
 
+/// Maps the status code of the sealed key chain extension methods to a result.
+#[cfg(feature = "sealed-key")]
+fn sealed_key_status_to_result(status_code: u32) -> Result<()> {
+    match status_code {
+        0 => Ok(()),
+        _ => Err(Error::SealedKeyUnavailable),
+    }
+}
+
 pub struct TopicsBuilder<'a, E> {
     scoped_buffer: ScopedBuffer<'a>,
     marker: core::marker::PhantomData<fn() -> E>,
@@ -179,17 +199,21 @@ impl EnvInstance {
         ScopedBuffer::from(&mut self.buffer[..])
     }
 
-    /// Returns the contract property value into the given result buffer.
+    /// Returns the immutable contract property value into the given result buffer.
     ///
     /// # Note
     ///
-    /// This skips the potentially costly decoding step that is often equivalent to a `memcpy`.
-    fn get_property_inplace<T>(&mut self, ext_fn: fn(output: &mut &mut [u8])) -> T
+    /// The host is queried only upon the first access during a contract execution.
+    fn get_cached_property_inplace<T>(
+        &mut self,
+        property: Property,
+        ext_fn: fn(output: &mut &mut [u8]),
+    ) -> T
     where
         T: Default + AsMut<[u8]>,
     {
         let mut result = T::default();
-        ext_fn(&mut result.as_mut());
+        self.cache.get_or_fetch(property, result.as_mut(), ext_fn);
         result
     }
 
@@ -207,6 +231,24 @@ impl EnvInstance {
         <T as FromLittleEndian>::from_le_bytes(result)
     }
 
+    /// Returns the immutable contract property value from its little-endian representation.
+    ///
+    /// # Note
+    ///
+    /// The host is queried only upon the first access during a contract execution.
+    fn get_cached_property_little_endian<T>(
+        &mut self,
+        property: Property,
+        ext_fn: fn(output: &mut &mut [u8]),
+    ) -> T
+    where
+        T: FromLittleEndian,
+    {
+        let mut result = <T as FromLittleEndian>::Bytes::default();
+        self.cache.get_or_fetch(property, result.as_mut(), ext_fn);
+        <T as FromLittleEndian>::from_le_bytes(result)
+    }
+
     /// Returns the contract property value.
     fn get_property<T>(&mut self, ext_fn: fn(output: &mut &mut [u8])) -> Result<T>
     where
@@ -291,6 +333,15 @@ impl EnvBackend for EnvInstance {
         ext::ecdsa_recover(signature, message_hash, output).map_err(Into::into)
     }
 
+    fn sr25519_verify(
+        &mut self,
+        signature: &[u8; 64],
+        message: &[u8],
+        pub_key: &[u8; 32],
+    ) -> Result<()> {
+        ext::sr25519_verify(signature, message, pub_key).map_err(Into::into)
+    }
+
     fn call_chain_extension<I, T, E, ErrorCode, F, D>(
         &mut self,
         func_id: u32,
@@ -312,27 +363,140 @@ impl EnvBackend for EnvInstance {
         let decoded = decode_to_result(&output[..])?;
         Ok(decoded)
     }
+
+    #[cfg(feature = "http-request")]
+    fn http_request(
+        &mut self,
+        request: &crate::http::HttpRequest,
+    ) -> Result<crate::http::HttpResponse> {
+        self.call_chain_extension::<_, _, Error, Error, _, _>(
+            crate::http::HTTP_REQUEST_FUNC_ID,
+            request,
+            |status_code| {
+                match status_code {
+                    0 => Ok(()),
+                    _ => Err(Error::HttpRequestFailed),
+                }
+            },
+            |mut output| scale::Decode::decode(&mut output).map_err(Into::into),
+        )
+    }
+
+    #[cfg(feature = "sealed-key")]
+    fn sign(
+        &mut self,
+        key_kind: crate::sealed_key::KeyKind,
+        message: &[u8],
+    ) -> Result<ink_prelude::vec::Vec<u8>> {
+        self.call_chain_extension::<_, _, Error, Error, _, _>(
+            crate::sealed_key::SIGN_FUNC_ID,
+            &(key_kind as u8, message),
+            sealed_key_status_to_result,
+            |mut output| scale::Decode::decode(&mut output).map_err(Into::into),
+        )
+    }
+
+    #[cfg(feature = "sealed-key")]
+    fn derive_key(&mut self, salt: &[u8]) -> Result<[u8; 32]> {
+        self.call_chain_extension::<_, _, Error, Error, _, _>(
+            crate::sealed_key::DERIVE_KEY_FUNC_ID,
+            &salt,
+            sealed_key_status_to_result,
+            |mut output| scale::Decode::decode(&mut output).map_err(Into::into),
+        )
+    }
+
+    #[cfg(feature = "local-cache")]
+    fn cache_set(&mut self, key: &[u8], value: &[u8], ttl: u64) -> Result<()> {
+        self.call_chain_extension::<_, (), Error, Error, _, _>(
+            crate::local_cache::CACHE_SET_FUNC_ID,
+            &(key, value, ttl),
+            |status_code| {
+                match status_code {
+                    0 => Ok(()),
+                    _ => Err(Error::LocalCacheFailed),
+                }
+            },
+            |_| Ok(()),
+        )
+    }
+
+    #[cfg(feature = "local-cache")]
+    fn cache_get(&mut self, key: &[u8]) -> Result<Option<ink_prelude::vec::Vec<u8>>> {
+        self.call_chain_extension::<_, _, Error, Error, _, _>(
+            crate::local_cache::CACHE_GET_FUNC_ID,
+            &key,
+            |_| Ok(()),
+            |mut output| scale::Decode::decode(&mut output).map_err(Into::into),
+        )
+    }
+
+    #[cfg(feature = "host-random")]
+    fn getrandom(&mut self, len: u32) -> ink_prelude::vec::Vec<u8> {
+        self.call_chain_extension::<_, _, Error, Error, _, _>(
+            crate::host_random::GETRANDOM_FUNC_ID,
+            &len,
+            |_| Ok(()),
+            |mut output| scale::Decode::decode(&mut output).map_err(Into::into),
+        )
+        .expect("could not decode random bytes")
+    }
+
+    #[cfg(feature = "local-cache")]
+    fn cache_remove(&mut self, key: &[u8]) -> Result<Option<ink_prelude::vec::Vec<u8>>> {
+        self.call_chain_extension::<_, _, Error, Error, _, _>(
+            crate::local_cache::CACHE_REMOVE_FUNC_ID,
+            &key,
+            |_| Ok(()),
+            |mut output| scale::Decode::decode(&mut output).map_err(Into::into),
+        )
+    }
 }
 
 impl TypedEnvBackend for EnvInstance {
     fn caller<E: Environment>(&mut self) -> E::AccountId {
-        self.get_property_inplace::<E::AccountId>(ext::caller)
+        self.get_cached_property_inplace::<E::AccountId>(Property::Caller, ext::caller)
     }
 
     fn transferred_value<E: Environment>(&mut self) -> E::Balance {
-        self.get_property_little_endian::<E::Balance>(ext::value_transferred)
+        self.get_cached_property_little_endian::<E::Balance>(
+            Property::ValueTransferred,
+            ext::value_transferred,
+        )
     }
 
-    fn gas_left<E: Environment>(&mut self) -> u64 {
-        self.get_property_little_endian::<u64>(ext::gas_left)
+    fn gas_left<E: Environment>(&mut self) -> Weight {
+        self.get_property::<Weight>(ext::gas_left)
+            .expect("could not decode `gas_left` weight")
+    }
+
+    fn block_hash<E: Environment>(
+        &mut self,
+        block_number: E::BlockNumber,
+    ) -> Option<E::Hash> {
+        let mut scope = self.scoped_buffer();
+        let enc_block_number = scope.take_encoded(&block_number);
+        let output = &mut scope.take_rest();
+        match ext::block_hash(enc_block_number, output) {
+            Ok(()) => {
+                let hash = scale::Decode::decode(&mut &output[..])
+                    .expect("could not decode block hash");
+                Some(hash)
+            }
+            Err(ExtError::KeyNotFound) => None,
+            Err(_) => panic!("encountered unexpected error"),
+        }
     }
 
     fn block_timestamp<E: Environment>(&mut self) -> E::Timestamp {
-        self.get_property_little_endian::<E::Timestamp>(ext::now)
+        self.get_cached_property_little_endian::<E::Timestamp>(
+            Property::BlockTimestamp,
+            ext::now,
+        )
     }
 
     fn account_id<E: Environment>(&mut self) -> E::AccountId {
-        self.get_property_inplace::<E::AccountId>(ext::address)
+        self.get_cached_property_inplace::<E::AccountId>(Property::Address, ext::address)
     }
 
     fn balance<E: Environment>(&mut self) -> E::Balance {
@@ -340,7 +504,10 @@ impl TypedEnvBackend for EnvInstance {
     }
 
     fn block_number<E: Environment>(&mut self) -> E::BlockNumber {
-        self.get_property_little_endian::<E::BlockNumber>(ext::block_number)
+        self.get_cached_property_little_endian::<E::BlockNumber>(
+            Property::BlockNumber,
+            ext::block_number,
+        )
     }
 
     fn minimum_balance<E: Environment>(&mut self) -> E::Balance {
@@ -358,6 +525,15 @@ impl TypedEnvBackend for EnvInstance {
         ext::deposit_event(enc_topics, enc_data);
     }
 
+    fn emit_event_with_topics<E>(&mut self, topics: &[E::Hash], data: &[u8])
+    where
+        E: Environment,
+    {
+        let mut scope = self.scoped_buffer();
+        let enc_topics = scope.take_encoded(&topics);
+        ext::deposit_event(enc_topics, data);
+    }
+
     fn invoke_contract<E, Args, R>(
         &mut self,
         params: &CallParams<E, Call<E>, Args, R>,
@@ -368,8 +544,12 @@ impl TypedEnvBackend for EnvInstance {
         R: scale::Decode,
     {
         let mut scope = self.scoped_buffer();
-        let gas_limit = params.gas_limit();
+        let ref_time_limit = params.ref_time_limit();
+        let proof_size_limit = params.proof_size_limit();
         let enc_callee = scope.take_encoded(params.callee());
+        let enc_storage_limit = params
+            .storage_deposit_limit()
+            .map(|limit| &*scope.take_encoded(limit));
         let enc_transferred_value = scope.take_encoded(params.transferred_value());
         let call_flags = params.call_flags();
         let enc_input = if !call_flags.forward_input() && !call_flags.clone_input() {
@@ -382,15 +562,17 @@ impl TypedEnvBackend for EnvInstance {
         let call_result = ext::call(
             flags,
             enc_callee,
-            gas_limit,
+            ref_time_limit,
+            proof_size_limit,
+            enc_storage_limit,
             enc_transferred_value,
             enc_input,
             output,
         );
         match call_result {
             Ok(()) | Err(ext::Error::CalleeReverted) => {
-                let decoded = scale::Decode::decode(&mut &output[..])?;
-                Ok(decoded)
+                let decoded: MessageResult<R> = scale::Decode::decode(&mut &output[..])?;
+                decoded.map_err(Into::into)
             }
             Err(actual_error) => Err(actual_error.into()),
         }
@@ -418,25 +600,30 @@ impl TypedEnvBackend for EnvInstance {
         let call_result = ext::delegate_call(flags, enc_code_hash, enc_input, output);
         match call_result {
             Ok(()) | Err(ext::Error::CalleeReverted) => {
-                let decoded = scale::Decode::decode(&mut &output[..])?;
-                Ok(decoded)
+                let decoded: MessageResult<R> = scale::Decode::decode(&mut &output[..])?;
+                decoded.map_err(Into::into)
             }
             Err(actual_error) => Err(actual_error.into()),
         }
     }
 
-    fn instantiate_contract<E, Args, Salt, C>(
+    fn instantiate_contract<E, Args, Salt, C, RetType>(
         &mut self,
-        params: &CreateParams<E, Args, Salt, C>,
-    ) -> Result<E::AccountId>
+        params: &CreateParams<E, Args, Salt, C, RetType>,
+    ) -> core::result::Result<E::AccountId, InstantiateError<RetType::Error>>
     where
         E: Environment,
         Args: scale::Encode,
         Salt: AsRef<[u8]>,
+        RetType: ConstructorReturnType<C>,
     {
         let mut scoped = self.scoped_buffer();
-        let gas_limit = params.gas_limit();
+        let ref_time_limit = params.ref_time_limit();
+        let proof_size_limit = params.proof_size_limit();
         let enc_code_hash = scoped.take_encoded(params.code_hash());
+        let enc_storage_limit = params
+            .storage_deposit_limit()
+            .map(|limit| &*scoped.take_encoded(limit));
         let enc_endowment = scoped.take_encoded(params.endowment());
         let enc_input = scoped.take_encoded(params.exec_input());
         // We support `AccountId` types with an encoding that requires up to
@@ -445,23 +632,54 @@ impl TypedEnvBackend for EnvInstance {
         let out_address = &mut scoped.take(1024);
         let salt = params.salt_bytes().as_ref();
         let out_return_value = &mut scoped.take_rest();
-        // We currently do nothing with the `out_return_value` buffer.
-        // This should change in the future but for that we need to add support
-        // for constructors that may return values.
-        // This is useful to support fallible constructors for example.
-        ext::instantiate(
+        // The `out_return_value` buffer is only of interest if the constructor
+        // reverted, in which case a fallible constructor returned its error.
+        let instantiate_result = ext::instantiate(
             enc_code_hash,
-            gas_limit,
+            ref_time_limit,
+            proof_size_limit,
+            enc_storage_limit,
             enc_endowment,
             enc_input,
             out_address,
             out_return_value,
             salt,
-        )?;
+        );
+        match instantiate_result {
+            Ok(()) => (),
+            Err(ext::Error::CalleeReverted) => {
+                return Err(InstantiateError::reverted::<C, RetType>(out_return_value))
+            }
+            Err(actual_error) => return Err(Error::from(actual_error).into()),
+        }
         let account_id = scale::Decode::decode(&mut &out_address[..])?;
         Ok(account_id)
     }
 
+    fn estimate_contract<E, Args, R>(
+        &mut self,
+        _params: &CallParams<E, Call<E>, Args, R>,
+    ) -> Result<Estimate<E::Balance>>
+    where
+        E: Environment,
+        Args: scale::Encode,
+    {
+        // A contract cannot revert the effects of a successful call on-chain.
+        Err(Error::DryRunUnavailable)
+    }
+
+    fn estimate_instantiate_contract<E, Args, Salt, C, RetType>(
+        &mut self,
+        _params: &CreateParams<E, Args, Salt, C, RetType>,
+    ) -> Result<Estimate<E::Balance>>
+    where
+        E: Environment,
+        Args: scale::Encode,
+        Salt: AsRef<[u8]>,
+    {
+        Err(Error::DryRunUnavailable)
+    }
+
     fn terminate_contract<E>(&mut self, beneficiary: E::AccountId) -> !
     where
         E: Environment,
@@ -536,4 +754,35 @@ impl TypedEnvBackend for EnvInstance {
         let hash = scale::Decode::decode(&mut &output[..])?;
         Ok(hash)
     }
+
+    fn instantiation_nonce<E>(&mut self) -> u64
+    where
+        E: Environment,
+    {
+        ext::instantiation_nonce()
+    }
+
+    fn xcm_execute<E, M>(&mut self, msg: &M) -> Result<()>
+    where
+        E: Environment,
+        M: scale::Encode,
+    {
+        let mut scope = self.scoped_buffer();
+        let enc_msg = scope.take_encoded(msg);
+        ext::xcm_execute(enc_msg).map_err(Into::into)
+    }
+
+    fn xcm_send<E, D, M>(&mut self, dest: &D, msg: &M) -> Result<[u8; 32]>
+    where
+        E: Environment,
+        D: scale::Encode,
+        M: scale::Encode,
+    {
+        let mut scope = self.scoped_buffer();
+        let enc_dest = scope.take_encoded(dest);
+        let enc_msg = scope.take_encoded(msg);
+        let mut output = [0u8; 32];
+        ext::xcm_send(enc_dest, enc_msg, &mut output)?;
+        Ok(output)
+    }
 }
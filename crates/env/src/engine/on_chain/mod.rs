@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod buffer;
+mod cache;
 mod ext;
 mod impls;
 
@@ -21,6 +22,7 @@ use self::{
         ScopedBuffer,
         StaticBuffer,
     },
+    cache::PropertyCache,
     ext::Error,
 };
 use super::OnInstance;
@@ -37,6 +39,9 @@ pub struct EnvInstance {
     /// might change. Users should generally avoid storing too big values
     /// into single storage entries.
     buffer: StaticBuffer,
+    /// Cache for environmental properties that cannot change during a single
+    /// contract execution, e.g. the caller or the current block number.
+    cache: PropertyCache,
 }
 
 impl OnInstance for EnvInstance {
@@ -46,6 +51,7 @@ impl OnInstance for EnvInstance {
     {
         static mut INSTANCE: EnvInstance = EnvInstance {
             buffer: StaticBuffer::new(),
+            cache: PropertyCache::new(),
         };
         f(unsafe { &mut INSTANCE })
     }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use derive_more::From;
+use ink_primitives::LangError;
 
 #[cfg(any(feature = "std", test, doc))]
 use crate::engine::off_chain::OffChainError;
@@ -29,6 +30,9 @@ pub enum Error {
     CalleeTrapped,
     /// The call to another contract has been reverted.
     CalleeReverted,
+    /// The called ink! message could not be dispatched by the ink! language,
+    /// e.g. because its input could not be decoded.
+    LangError(LangError),
     /// The queried contract storage entry is missing.
     KeyNotFound,
     /// Deprecated and no longer returned: There is only the minimum balance.
@@ -36,6 +40,10 @@ pub enum Error {
     /// Transfer failed for other not further specified reason. Most probably
     /// reserved or locked balance of the sender that was preventing the transfer.
     TransferFailed,
+    /// The transfer would have brought the contract's balance below the minimum
+    /// balance while [`ExistenceRequirement::KeepAlive`][`crate::ExistenceRequirement::KeepAlive`]
+    /// was requested.
+    TransferWouldReap,
     /// Deprecated and no longer returned: Endowment is no longer required.
     _EndowmentTooLow,
     /// No code could be found at the supplied code hash.
@@ -49,6 +57,25 @@ pub enum Error {
     LoggingDisabled,
     /// ECDSA pubkey recovery failed. Most probably wrong recovery id or signature.
     EcdsaRecoveryFailed,
+    /// sr25519 signature verification failed.
+    Sr25519VerifyFailed,
+    /// The XCM execution failed.
+    XcmExecutionFailed,
+    /// The XCM message could not be sent.
+    XcmSendFailed,
+    /// The HTTP request could not be performed.
+    HttpRequestFailed,
+    /// The returned value does not fit into the buffer of the environment.
+    BufferTooSmall,
+    /// The execution environment provides no sealed key.
+    SealedKeyUnavailable,
+    /// The sealed key of the execution environment is not a valid secret key
+    /// of the requested kind.
+    InvalidSealedKey,
+    /// The value could not be stored in the local cache.
+    LocalCacheFailed,
+    /// The execution environment cannot perform calls or instantiations as a dry-run.
+    DryRunUnavailable,
 }
 
 /// A result of environmental operations.
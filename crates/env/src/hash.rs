@@ -44,6 +44,138 @@ pub enum Blake2x256 {}
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Blake2x128 {}
 
+/// Types that are usable as incremental cryptographic hashes.
+///
+/// # Note
+///
+/// Incremental hashing is performed inside the contract instead of the host,
+/// since the `contracts` pallet only provides hash functions over contiguous input.
+/// Prefer [`CryptoHash`] if the whole input is already available in a single buffer.
+pub trait IncrementalHash: HashOutput + private::Sealed {
+    /// The intermediate state of the hash computation.
+    #[doc(hidden)]
+    type State: Default;
+
+    /// Feeds the given raw byte input into the hash `state`.
+    #[doc(hidden)]
+    fn update(state: &mut Self::State, input: &[u8]);
+
+    /// Consumes the hash `state` and copies the result into `output`.
+    #[doc(hidden)]
+    fn finalize(state: Self::State, output: &mut <Self as HashOutput>::Type);
+}
+
+/// Incrementally computes the crypto hash `H` over chunked input.
+///
+/// This avoids having to allocate and concatenate the whole preimage
+/// into a single contiguous buffer before hashing it.
+///
+/// # Example
+///
+/// ```
+/// use ink_env::hash::{HashOutput, HashOutputBuilder, Sha2x256};
+///
+/// let mut builder = HashOutputBuilder::<Sha2x256>::new();
+/// builder.update(&[13, 14]);
+/// builder.update(&[15]);
+/// let streamed = builder.finalize();
+///
+/// let mut expected = <Sha2x256 as HashOutput>::Type::default();
+/// ink_env::hash_bytes::<Sha2x256>(&[13, 14, 15], &mut expected);
+/// assert_eq!(streamed, expected);
+/// ```
+pub struct HashOutputBuilder<H>
+where
+    H: IncrementalHash,
+{
+    state: <H as IncrementalHash>::State,
+}
+
+impl<H> Default for HashOutputBuilder<H>
+where
+    H: IncrementalHash,
+{
+    fn default() -> Self {
+        Self {
+            state: Default::default(),
+        }
+    }
+}
+
+impl<H> HashOutputBuilder<H>
+where
+    H: IncrementalHash,
+{
+    /// Creates a new builder for the crypto hash `H`.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Feeds the given raw byte input into the hash computation.
+    pub fn update(&mut self, input: &[u8]) -> &mut Self {
+        <H as IncrementalHash>::update(&mut self.state, input);
+        self
+    }
+
+    /// Feeds the SCALE encoding of `value` into the hash computation.
+    ///
+    /// # Note
+    ///
+    /// The encoding is streamed into the hash and never buffered as a whole.
+    pub fn update_encoded<T>(&mut self, value: &T) -> &mut Self
+    where
+        T: scale::Encode + ?Sized,
+    {
+        value.encode_to(self);
+        self
+    }
+
+    /// Finalizes the hash computation and stores the result in `output`.
+    pub fn finalize_into(self, output: &mut <H as HashOutput>::Type) {
+        <H as IncrementalHash>::finalize(self.state, output)
+    }
+
+    /// Finalizes the hash computation and returns the result.
+    pub fn finalize(self) -> <H as HashOutput>::Type {
+        let mut output = <H as HashOutput>::Type::default();
+        self.finalize_into(&mut output);
+        output
+    }
+}
+
+impl<H> scale::Output for HashOutputBuilder<H>
+where
+    H: IncrementalHash,
+{
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+}
+
+macro_rules! impl_incremental_hash {
+    ( $( $hash:ty => $state:ty ),* $(,)? ) => {
+        $(
+            impl IncrementalHash for $hash {
+                type State = $state;
+
+                fn update(state: &mut Self::State, input: &[u8]) {
+                    ::blake2::digest::Digest::update(state, input)
+                }
+
+                fn finalize(state: Self::State, output: &mut <Self as HashOutput>::Type) {
+                    output.copy_from_slice(&::blake2::digest::Digest::finalize(state))
+                }
+            }
+        )*
+    };
+}
+impl_incremental_hash!(
+    Sha2x256 => ::sha2::Sha256,
+    Keccak256 => ::sha3::Keccak256,
+    Blake2x256 => ::blake2::Blake2b<::blake2::digest::consts::U32>,
+    Blake2x128 => ::blake2::Blake2b<::blake2::digest::consts::U16>,
+);
+
 mod private {
     /// Seals the implementation of `CryptoHash` and `HashOutput`.
     pub trait Sealed {}
@@ -0,0 +1,28 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Definitions for the random number generator of the host.
+//!
+//! Execution environments that run contracts off-chain, e.g. inside a TEE, can provide
+//! secure randomness that is not derived from the chain state. On-chain the request is
+//! forwarded to the chain extension method with the [`GETRANDOM_FUNC_ID`] which has to
+//! be provided by the runtime. It receives the requested length as `u32` and is expected
+//! to return the SCALE encoded `Vec<u8>` of random bytes. The off-chain environment
+//! uses a deterministically seeded generator, see
+//! [`set_host_rng_seed`][`crate::test::set_host_rng_seed`].
+//!
+//! Requires the `host-random` crate feature.
+
+/// The `func_id` of the chain extension method that returns random bytes.
+pub const GETRANDOM_FUNC_ID: u32 = 0xFF00_0007;
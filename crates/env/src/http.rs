@@ -0,0 +1,59 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Types for performing HTTP requests from within a contract.
+//!
+//! This is only meaningful for runtimes that execute contracts off-chain, e.g. in a
+//! worker that is able to access the network. On-chain the request is forwarded to
+//! the chain extension method with the [`HTTP_REQUEST_FUNC_ID`] which has to be
+//! provided by the runtime. The off-chain environment dispatches the request to the
+//! chain extension method registered for the same ID.
+//!
+//! Requires the `http-request` crate feature.
+
+use ink_prelude::{
+    string::String,
+    vec::Vec,
+};
+
+/// The `func_id` of the chain extension method that performs HTTP requests.
+pub const HTTP_REQUEST_FUNC_ID: u32 = 0xFF00_0001;
+
+/// An HTTP request performed via [`http_request`][`crate::http_request`].
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct HttpRequest {
+    /// The HTTP method, e.g. `GET` or `POST`.
+    pub method: String,
+    /// The requested URL.
+    pub url: String,
+    /// The request headers as name and value pairs.
+    pub headers: Vec<(String, String)>,
+    /// The request body.
+    pub body: Vec<u8>,
+}
+
+/// The response to an [`HttpRequest`].
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct HttpResponse {
+    /// The HTTP status code, e.g. `200`.
+    pub status_code: u16,
+    /// The reason phrase accompanying the status code, e.g. `OK`.
+    pub reason_phrase: String,
+    /// The response headers as name and value pairs.
+    pub headers: Vec<(String, String)>,
+    /// The response body.
+    pub body: Vec<u8>,
+}
@@ -73,6 +73,16 @@ pub mod chain_extension;
 mod engine;
 mod error;
 pub mod hash;
+#[cfg(feature = "host-random")]
+pub mod host_random;
+#[cfg(feature = "http-request")]
+pub mod http;
+#[cfg(feature = "local-cache")]
+pub mod local_cache;
+#[cfg(feature = "sealed-key")]
+pub mod sealed_key;
+#[cfg(feature = "std")]
+pub mod ss58;
 #[doc(hidden)]
 pub mod topics;
 mod types;
@@ -92,8 +102,10 @@ pub use self::{
     api::*,
     backend::{
         CallFlags,
+        ExistenceRequirement,
         ReturnFlags,
     },
+    engine::BUFFER_SIZE,
     error::{
         Error,
         Result,
@@ -107,6 +119,7 @@ pub use self::{
         FromLittleEndian,
         Hash,
         NoChainExtension,
+        Weight,
     },
 };
 
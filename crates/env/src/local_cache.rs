@@ -0,0 +1,36 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Definitions for the local cache of contracts.
+//!
+//! The local cache holds non-consensus scratch data of contracts that are executed
+//! off-chain. In contrast to the contract storage its entries are not part of the chain
+//! state, may differ between workers and expire after their time to live has passed.
+//!
+//! On-chain the operations are forwarded to the chain extension methods with the
+//! [`CACHE_SET_FUNC_ID`], [`CACHE_GET_FUNC_ID`] and [`CACHE_REMOVE_FUNC_ID`] which have to
+//! be provided by the runtime. The get and remove methods are expected to return the
+//! SCALE encoded `Option<Vec<u8>>` of the entry. The off-chain environment keeps the
+//! entries in memory and measures their time to live in block timestamps.
+//!
+//! Requires the `local-cache` crate feature.
+
+/// The `func_id` of the chain extension method that stores a value in the local cache.
+pub const CACHE_SET_FUNC_ID: u32 = 0xFF00_0004;
+
+/// The `func_id` of the chain extension method that queries a value from the local cache.
+pub const CACHE_GET_FUNC_ID: u32 = 0xFF00_0005;
+
+/// The `func_id` of the chain extension method that removes a value from the local cache.
+pub const CACHE_REMOVE_FUNC_ID: u32 = 0xFF00_0006;
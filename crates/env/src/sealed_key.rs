@@ -0,0 +1,45 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Types for using the key sealed into the execution environment.
+//!
+//! Some execution environments, e.g. TEE-based runtimes, give contracts access to a
+//! secret key that never leaves the environment. On-chain the operations are forwarded
+//! to the chain extension methods with the [`SIGN_FUNC_ID`] and [`DERIVE_KEY_FUNC_ID`]
+//! which have to be provided by the runtime. The off-chain environment uses a mock
+//! key that can be changed via [`set_sealed_key`][`crate::test::set_sealed_key`].
+//!
+//! Requires the `sealed-key` crate feature.
+
+/// The `func_id` of the chain extension method that signs messages.
+pub const SIGN_FUNC_ID: u32 = 0xFF00_0002;
+
+/// The `func_id` of the chain extension method that derives keys.
+pub const DERIVE_KEY_FUNC_ID: u32 = 0xFF00_0003;
+
+/// The kind of key used to sign a message via [`sign`][`crate::sign`].
+///
+/// The discriminant is passed as `u8` to the chain extension.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u8)]
+pub enum KeyKind {
+    /// A 64 bytes sr25519 signature using the `substrate` signing context.
+    ///
+    /// Can be verified via [`sr25519_verify`][`crate::sr25519_verify`].
+    Sr25519 = 0,
+    /// A 65 bytes recoverable ECDSA signature of the BLAKE2-256 hash of the message.
+    ///
+    /// Can be recovered via [`ecdsa_recover`][`crate::ecdsa_recover`].
+    Ecdsa = 1,
+}
@@ -0,0 +1,115 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Rendering of account identifiers in the SS58 address format.
+//!
+//! Only available with the `std` feature since it is meant for logs and test output
+//! of off-chain code, not for use within contracts.
+
+use blake2::{
+    digest::Digest as _,
+    Blake2b512,
+};
+
+/// The address type used for the generic Substrate network.
+pub const SUBSTRATE_ADDRESS_TYPE: u8 = 42;
+
+/// The prefix hashed together with the payload to compute the SS58 checksum.
+const CHECKSUM_PREFIX: &[u8] = b"SS58PRE";
+
+/// The number of checksum bytes appended to 32 byte account identifiers.
+const CHECKSUM_LEN: usize = 2;
+
+/// The base58 alphabet as used by Bitcoin and Substrate.
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Encodes the account identifier bytes as SS58 address of the generic Substrate network.
+pub fn encode(account: &[u8]) -> String {
+    encode_with_address_type(SUBSTRATE_ADDRESS_TYPE, account)
+}
+
+/// Encodes the account identifier bytes as SS58 address with the given address type.
+///
+/// # Panics
+///
+/// If `address_type` does not fit into the simple single byte format, i.e. is `64` or larger.
+pub fn encode_with_address_type(address_type: u8, account: &[u8]) -> String {
+    assert!(
+        address_type < 64,
+        "encountered unsupported SS58 address type: {}",
+        address_type
+    );
+    let mut payload = Vec::with_capacity(1 + account.len() + CHECKSUM_LEN);
+    payload.push(address_type);
+    payload.extend_from_slice(account);
+    let checksum = Blake2b512::new()
+        .chain_update(CHECKSUM_PREFIX)
+        .chain_update(&payload)
+        .finalize();
+    payload.extend_from_slice(&checksum[..CHECKSUM_LEN]);
+    to_base58(&payload)
+}
+
+/// Encodes the bytes in base58, preserving leading zero bytes as leading `1`s.
+fn to_base58(input: &[u8]) -> String {
+    // Digits in base 58, least significant first.
+    let mut digits: Vec<u8> = Vec::with_capacity(input.len() * 138 / 100 + 1);
+    for &byte in input {
+        let mut carry = byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+    let leading_zeros = input.iter().take_while(|&&byte| byte == 0).count();
+    core::iter::repeat_n(ALPHABET[0], leading_zeros)
+        .chain(digits.iter().rev().map(|&digit| ALPHABET[digit as usize]))
+        .map(char::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_works() {
+        // The public key of the well-known `//Alice` development account.
+        let alice = [
+            0xd4, 0x35, 0x93, 0xc7, 0x15, 0xfd, 0xd3, 0x1c, 0x61, 0x14, 0x1a, 0xbd, 0x04,
+            0xa9, 0x9f, 0xd6, 0x82, 0x2c, 0x85, 0x58, 0x85, 0x4c, 0xcd, 0xe3, 0x9a, 0x56,
+            0x84, 0xe7, 0xa5, 0x6d, 0xa2, 0x7d,
+        ];
+        assert_eq!(
+            encode(&alice),
+            "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"
+        );
+        assert_eq!(
+            encode_with_address_type(0, &alice),
+            "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5"
+        );
+    }
+
+    #[test]
+    fn base58_preserves_leading_zeros() {
+        assert_eq!(to_base58(&[]), "");
+        assert_eq!(to_base58(&[0x00, 0x00, 0x01]), "112");
+        assert_eq!(to_base58(&[0xFF]), "5Q");
+    }
+}
@@ -63,6 +63,57 @@ fn test_hash_blake2_128() {
     );
 }
 
+#[test]
+fn test_hash_output_builder() {
+    use crate::hash::{
+        Blake2x128,
+        Blake2x256,
+        CryptoHash,
+        HashOutput,
+        HashOutputBuilder,
+        IncrementalHash,
+        Keccak256,
+        Sha2x256,
+    };
+
+    fn assert_streamed_eq_one_shot<H>()
+    where
+        H: CryptoHash + IncrementalHash,
+        <H as HashOutput>::Type: PartialEq + core::fmt::Debug,
+    {
+        let mut expected = <H as HashOutput>::Type::default();
+        crate::hash_bytes::<H>(TEST_INPUT, &mut expected);
+
+        let mut builder = HashOutputBuilder::<H>::new();
+        for chunk in TEST_INPUT.chunks(2) {
+            builder.update(chunk);
+        }
+        assert_eq!(builder.finalize(), expected);
+    }
+
+    assert_streamed_eq_one_shot::<Keccak256>();
+    assert_streamed_eq_one_shot::<Sha2x256>();
+    assert_streamed_eq_one_shot::<Blake2x256>();
+    assert_streamed_eq_one_shot::<Blake2x128>();
+}
+
+#[test]
+fn test_hash_output_builder_encoded() {
+    use crate::hash::{
+        HashOutput,
+        HashOutputBuilder,
+        Sha2x256,
+    };
+
+    let encodable = (42, "foo", true);
+    let mut expected = <Sha2x256 as HashOutput>::Type::default();
+    crate::hash_encoded::<Sha2x256, _>(&encodable, &mut expected);
+
+    let mut builder = HashOutputBuilder::<Sha2x256>::new();
+    builder.update_encoded(&encodable);
+    assert_eq!(builder.finalize(), expected);
+}
+
 #[test]
 fn test_call_flags() {
     let flags = crate::CallFlags::default();
@@ -101,3 +152,53 @@ fn test_call_flags() {
     assert!(!flags.forward_input());
     assert_eq!(flags.into_u32(), 0b0000_0000);
 }
+
+#[test]
+fn weight_encoding_is_compact() {
+    use crate::Weight;
+    use scale::{
+        Decode,
+        Encode,
+    };
+
+    let weight = Weight::from_parts(1, 1 << 14);
+    assert_eq!(weight.ref_time(), 1);
+    assert_eq!(weight.proof_size(), 1 << 14);
+    let encoded = weight.encode();
+    assert_eq!(encoded, vec![0x04, 0x02, 0x00, 0x01, 0x00]);
+    assert_eq!(Weight::decode(&mut &encoded[..]), Ok(weight));
+}
+
+#[test]
+fn contract_address_matches_pallet_derivation() {
+    use crate::{
+        call::contract_address,
+        hash::Blake2x256,
+        AccountId,
+        DefaultEnvironment,
+        Hash,
+    };
+
+    let deployer = AccountId::from([0x01; 32]);
+    let code_hash = Hash::from([0x02; 32]);
+    let input = [0xDE, 0xAD, 0xBE, 0xEF];
+    let salt = [0x03; 2];
+
+    let mut preimage = b"contract_addr_v1".to_vec();
+    preimage.extend_from_slice(&[0x01; 32]);
+    preimage.extend_from_slice(&[0x02; 32]);
+    preimage.push(4 << 2);
+    preimage.extend_from_slice(&input);
+    preimage.push(2 << 2);
+    preimage.extend_from_slice(&salt);
+    let mut expected = [0x00_u8; 32];
+    crate::hash_bytes::<Blake2x256>(&preimage, &mut expected);
+
+    let address =
+        contract_address::<DefaultEnvironment>(&deployer, &code_hash, &input, &salt);
+    assert_eq!(address, AccountId::from(expected));
+    assert_ne!(
+        address,
+        contract_address::<DefaultEnvironment>(&deployer, &code_hash, &input, &[])
+    );
+}
@@ -192,6 +192,45 @@ pub type Timestamp = u64;
 /// The default gas type.
 pub type Gas = u64;
 
+/// The two-dimensional weight used by chains with Weights V2.
+///
+/// # Note
+///
+/// This is a mirror of the `Weight` type used by PALLET contracts. A limit of `0`
+/// in either dimension means that all of the remaining resources may be used.
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Hash, Encode, Decode, Default,
+)]
+#[cfg_attr(feature = "std", derive(TypeInfo))]
+pub struct Weight {
+    /// The computational time used for execution, in picoseconds.
+    #[codec(compact)]
+    ref_time: u64,
+    /// The size of the storage proof needed to execute, in bytes.
+    #[codec(compact)]
+    proof_size: u64,
+}
+
+impl Weight {
+    /// Creates a new weight from its two components.
+    pub const fn from_parts(ref_time: u64, proof_size: u64) -> Self {
+        Self {
+            ref_time,
+            proof_size,
+        }
+    }
+
+    /// Returns the computational time part of the weight.
+    pub const fn ref_time(&self) -> u64 {
+        self.ref_time
+    }
+
+    /// Returns the proof size part of the weight.
+    pub const fn proof_size(&self) -> u64 {
+        self.proof_size
+    }
+}
+
 /// The default block number type.
 pub type BlockNumber = u32;
 
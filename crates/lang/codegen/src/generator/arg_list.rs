@@ -28,6 +28,11 @@ pub fn output_ident(message_name: &syn::Ident) -> syn::Ident {
     format_ident!("{}Output", message_name.to_string().to_lower_camel_case())
 }
 
+/// Returns the identifier of the default implementation of an ink! trait message.
+pub fn default_impl_ident(message_name: &syn::Ident) -> syn::Ident {
+    format_ident!("__ink_default_{}", message_name)
+}
+
 /// Returns the sequence of artificial input parameter bindings for the message.
 ///
 /// # Note
@@ -95,6 +95,8 @@ impl CallBuilder<'_> {
                 ::core::hash::Hash,
                 ::core::cmp::PartialEq,
                 ::core::cmp::Eq,
+                ::core::cmp::PartialOrd,
+                ::core::cmp::Ord,
                 ::core::clone::Clone,
             )]
             pub struct #cb_ident {
@@ -119,8 +121,23 @@ impl CallBuilder<'_> {
     /// These are required to properly interoperate with the call builder.
     fn generate_auxiliary_trait_impls(&self) -> TokenStream2 {
         let span = self.contract.module().storage().span();
+        let storage_ident = self.contract.module().storage().ident();
         let cb_ident = Self::call_builder_ident();
         quote_spanned!(span=>
+            /// Renders the called smart contract as its name followed by its
+            /// SS58 encoded account ID, e.g. `Flipper(5GrwvaEF...)`.
+            #[cfg(feature = "std")]
+            impl ::core::fmt::Display for #cb_ident {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    ::core::write!(
+                        f,
+                        "{}({})",
+                        <#storage_ident as ::ink_lang::reflect::ContractName>::NAME,
+                        ::ink_env::ss58::encode(<AccountId as ::core::convert::AsRef<[::core::primitive::u8]>>::as_ref(&self.account_id)),
+                    )
+                }
+            }
+
             impl ::ink_env::call::FromAccountId<Environment> for #cb_ident {
                 #[inline]
                 fn from_account_id(account_id: AccountId) -> Self {
@@ -383,8 +400,12 @@ impl CallBuilder<'_> {
                 ::ink_env::call::utils::Set< ::ink_env::call::utils::ReturnType<#return_type> >,
             >
         );
+        let deprecated = message
+            .deprecated()
+            .map(|note| quote_spanned!(span=> #[deprecated(note = #note)]));
         quote_spanned!(span=>
             #( #attrs )*
+            #deprecated
             #[allow(clippy::type_complexity)]
             #[inline]
             pub fn #message_ident(
@@ -51,12 +51,16 @@ impl GenerateCode for ContractRef<'_> {
         let contract_ref_inherent_impls = self.generate_contract_inherent_impls();
         let call_builder_trait_impl = self.generate_call_builder_trait_impl();
         let auxiliary_trait_impls = self.generate_auxiliary_trait_impls();
+        let call_overrides = self.generate_call_overrides();
+        let trait_ref_casts = self.generate_trait_ref_casts();
         quote! {
             #contract_ref
             #contract_ref_trait_impls
             #contract_ref_inherent_impls
             #call_builder_trait_impl
             #auxiliary_trait_impls
+            #call_overrides
+            #trait_ref_casts
         }
     }
 }
@@ -67,12 +71,21 @@ impl ContractRef<'_> {
         quote::format_ident!("{}Ref", self.contract.module().storage().ident())
     }
 
+    /// Generates the identifier of the short-hand caller with gas and value overrides.
+    fn generate_call_overrides_ident(&self) -> syn::Ident {
+        quote::format_ident!("{}Overrides", self.generate_contract_ref_ident())
+    }
+
     /// Generates the code for the struct representing the contract reference.
     ///
     /// The generated struct is the type onto which everything is implemented.
     /// It is also the type that is going to be used by other smart contract
     /// dynamically depending on the smart contract. It mirrors the smart contract
     /// API but is just a typed thin-wrapper around an `AccountId`.
+    ///
+    /// The wrapped `AccountId` is accessed via `ink_lang::ToAccountId::to_account_id`
+    /// since the struct provides no inherent accessor that could collide with the
+    /// ink! messages of the smart contract.
     fn generate_struct(&self) -> TokenStream2 {
         let span = self.contract.module().storage().span();
         let doc_attrs = self
@@ -91,7 +104,6 @@ impl ContractRef<'_> {
                 ::ink_storage::traits::StorageLayout,
             ))]
             #[derive(
-                ::core::fmt::Debug,
                 ::ink_storage::traits::SpreadLayout,
                 ::ink_storage::traits::PackedLayout,
                 ::scale::Encode,
@@ -99,6 +111,8 @@ impl ContractRef<'_> {
                 ::core::hash::Hash,
                 ::core::cmp::PartialEq,
                 ::core::cmp::Eq,
+                ::core::cmp::PartialOrd,
+                ::core::cmp::Ord,
                 ::core::clone::Clone,
             )]
             #( #doc_attrs )*
@@ -114,6 +128,19 @@ impl ContractRef<'_> {
                 impl ::ink_lang::reflect::ContractEnv for #ref_ident {
                     type Env = <#storage_ident as ::ink_lang::reflect::ContractEnv>::Env;
                 }
+
+                impl ::ink_lang::reflect::ContractVersion for #ref_ident {
+                    const VERSION: &'static str = <#storage_ident as ::ink_lang::reflect::ContractVersion>::VERSION;
+                }
+
+                impl ::core::fmt::Debug for #ref_ident {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct(::core::stringify!(#ref_ident))
+                            .field("version", &<Self as ::ink_lang::reflect::ContractVersion>::VERSION)
+                            .field("inner", &self.inner)
+                            .finish()
+                    }
+                }
             };
         )
     }
@@ -122,6 +149,7 @@ impl ContractRef<'_> {
     /// smart contract reference type.
     ///
     /// These are required to properly interoperate with the contract reference.
+    /// Its account ID is only accessible via the `ToAccountId` implementation.
     fn generate_auxiliary_trait_impls(&self) -> TokenStream2 {
         let span = self.contract.module().storage().span();
         let storage_ident = self.contract.module().storage().ident();
@@ -137,6 +165,7 @@ impl ContractRef<'_> {
                 }
             }
 
+            /// Returns the account ID of the referenced smart contract.
             impl ::ink_lang::ToAccountId<Environment> for #ref_ident {
                 #[inline]
                 fn to_account_id(&self) -> AccountId {
@@ -144,6 +173,16 @@ impl ContractRef<'_> {
                         as ::ink_lang::ToAccountId<Environment>>::to_account_id(&self.inner)
                 }
             }
+
+            /// Renders the referenced smart contract as its name followed by its
+            /// SS58 encoded account ID, e.g. `Flipper(5GrwvaEF...)`.
+            #[cfg(feature = "std")]
+            impl ::core::fmt::Display for #ref_ident {
+                #[inline]
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    ::core::fmt::Display::fmt(&self.inner, f)
+                }
+            }
         )
     }
 
@@ -218,6 +257,78 @@ impl ContractRef<'_> {
         )
     }
 
+    /// Generates the conversions between the contract reference and the references
+    /// of all ink! traits implemented by the ink! smart contract.
+    ///
+    /// # Note
+    ///
+    /// For every implemented ink! trait `Trait` this generates an `as_trait` method
+    /// returning the ink! trait reference to the same contract and a `from_trait_ref`
+    /// constructor converting the ink! trait reference back. The ink! IR ensures that
+    /// their names are unique.
+    fn generate_trait_ref_casts(&self) -> TokenStream2 {
+        let ref_ident = self.generate_contract_ref_ident();
+        let casts = self
+            .contract
+            .module()
+            .impls()
+            .filter_map(|impl_block| {
+                impl_block.trait_path().map(|trait_path| (impl_block, trait_path))
+            })
+            .map(|(impl_block, trait_path)| {
+                let span = impl_block.span();
+                let trait_ident = impl_block
+                    .trait_ident()
+                    .expect("encountered empty ink! trait path");
+                let trait_name = impl_block
+                    .trait_snake_name()
+                    .expect("encountered empty ink! trait path");
+                let as_ident = quote::format_ident!("as_{}", trait_name);
+                let from_ident = quote::format_ident!("from_{}_ref", trait_name);
+                let as_doc = format!(
+                    " Returns a reference to the same contract for calling its `{}` ink! trait messages.",
+                    trait_ident
+                );
+                let from_doc = format!(
+                    " Converts the `{}` ink! trait reference back into the contract reference.",
+                    trait_ident
+                );
+                let trait_ref = quote_spanned!(span=>
+                    <<::ink_lang::reflect::TraitDefinitionRegistry<Environment>
+                        as #trait_path>::__ink_TraitInfo
+                        as ::ink_lang::codegen::TraitCallForwarder>::Forwarder
+                );
+                quote_spanned!(span=>
+                    #[doc = #as_doc]
+                    #[inline]
+                    pub fn #as_ident(&self) -> #trait_ref {
+                        <#trait_ref as ::ink_env::call::FromAccountId<Environment>>::from_account_id(
+                            <Self as ::ink_lang::ToAccountId<Environment>>::to_account_id(self)
+                        )
+                    }
+
+                    #[doc = #from_doc]
+                    #[inline]
+                    pub fn #from_ident(trait_ref: #trait_ref) -> Self {
+                        <Self as ::ink_env::call::FromAccountId<Environment>>::from_account_id(
+                            <#trait_ref as ::ink_lang::ToAccountId<Environment>>::to_account_id(
+                                &trait_ref
+                            )
+                        )
+                    }
+                )
+            })
+            .collect::<Vec<_>>();
+        if casts.is_empty() {
+            return quote! {}
+        }
+        quote! {
+            impl #ref_ident {
+                #( #casts )*
+            }
+        }
+    }
+
     /// Generates the code for all messages of a single ink! trait implementation of
     /// the ink! smart contract.
     fn generate_contract_trait_impl_messages(
@@ -347,8 +458,15 @@ impl ContractRef<'_> {
         let input_bindings = message.inputs().map(|input| &input.pat).collect::<Vec<_>>();
         let input_types = message.inputs().map(|input| &input.ty).collect::<Vec<_>>();
         let output_type = message.output().map(|ty| quote! { -> #ty });
+        let deprecated = message.deprecated().map(|note| {
+            quote_spanned!(span=>
+                #[deprecated(note = #note)]
+                #[allow(deprecated)]
+            )
+        });
         quote_spanned!(span=>
             #( #attrs )*
+            #deprecated
             #[inline]
             pub fn #message_ident(
                 & #mut_token self
@@ -367,6 +485,19 @@ impl ContractRef<'_> {
         )
     }
 
+    /// Generates the output type of the message for the fallible short-hand caller.
+    fn generate_try_output_type(
+        message: &ir::CallableWithSelector<ir::Message>,
+    ) -> TokenStream2 {
+        let span = message.span();
+        let output = message
+            .output()
+            .map_or_else(|| quote! { () }, |output| quote! { #output });
+        quote_spanned!(span=>
+            ::core::result::Result<#output, ::ink_env::Error>
+        )
+    }
+
     /// Generates the code for a single ink! inherent constructor of the contract itself.
     ///
     /// # Note
@@ -389,6 +520,32 @@ impl ContractRef<'_> {
         let input_bindings = generator::input_bindings(constructor.inputs());
         let input_types = generator::input_types(constructor.inputs());
         let arg_list = generator::generate_argument_list(input_types.iter().cloned());
+        // Fallible constructors set their return type so that their errors are
+        // decoded upon failure, infallible constructors keep the default.
+        let (ret_type, returns) = match constructor.error_type() {
+            Some(error_type) => {
+                (
+                    quote! {
+                        ::ink_env::call::utils::Set<
+                            ::ink_env::call::utils::ReturnType<
+                                ::core::result::Result<Self, #error_type>
+                            >
+                        >
+                    },
+                    quote! {
+                        .returns::<::core::result::Result<Self, #error_type>>()
+                    },
+                )
+            }
+            None => {
+                (
+                    quote! {
+                        ::ink_env::call::utils::Unset<::ink_env::call::utils::ReturnType<Self>>
+                    },
+                    quote! {},
+                )
+            }
+        };
         quote_spanned!(span =>
             #( #attrs )*
             #[inline]
@@ -403,6 +560,7 @@ impl ContractRef<'_> {
                 ::ink_env::call::utils::Set<::ink_env::call::ExecutionInput<#arg_list>>,
                 ::ink_env::call::utils::Unset<::ink_env::call::state::Salt>,
                 Self,
+                #ret_type,
             > {
                 ::ink_env::call::build_create::<Environment, Self>()
                     .exec_input(
@@ -413,6 +571,188 @@ impl ContractRef<'_> {
                             .push_arg(#input_bindings)
                         )*
                     )
+                    #returns
+            }
+        )
+    }
+
+    /// Generates the short-hand caller of the contract reference that overrides the
+    /// gas limit, proof size limit and transferred value of its calls.
+    ///
+    /// # Note
+    ///
+    /// The short-hand caller is created via the `ink_lang::CallOverrides` trait that is
+    /// implemented by the contract reference and by the short-hand caller itself. Using
+    /// a trait allows ink! messages to have the same names as the overrides. The
+    /// short-hand caller provides all inherent ink! messages of the ink! smart contract
+    /// and returns the errors of failed calls, also without any overrides via `try_call`.
+    fn generate_call_overrides(&self) -> TokenStream2 {
+        let span = self.contract.module().storage().span();
+        let storage_ident = self.contract.module().storage().ident();
+        let ref_ident = self.generate_contract_ref_ident();
+        let overrides_ident = self.generate_call_overrides_ident();
+        let messages = self
+            .contract
+            .module()
+            .impls()
+            .filter(|impl_block| impl_block.trait_path().is_none())
+            .flat_map(|impl_block| impl_block.iter_messages())
+            .map(|message| self.generate_call_overrides_for_message(message));
+        quote_spanned!(span=>
+            /// Short-hand caller of the ink! smart contract that performs calls with
+            /// overridden gas limit, proof size limit or transferred value.
+            ///
+            /// Created via the `ink_lang::CallOverrides` implementation of the
+            #[doc = ::core::concat!("[`", ::core::stringify!(#ref_ident), "`].")]
+            /// Its ink! messages return the error of the call instead of panicking.
+            #[derive(::core::fmt::Debug, ::core::clone::Clone)]
+            pub struct #overrides_ident {
+                inner: <#storage_ident as ::ink_lang::codegen::ContractCallBuilder>::Type,
+                gas_limit: ::core::option::Option<::core::primitive::u64>,
+                proof_size_limit: ::core::option::Option<::core::primitive::u64>,
+                transferred_value: ::core::option::Option<Balance>,
+            }
+
+            impl ::core::convert::From<&#ref_ident> for #overrides_ident {
+                #[inline]
+                fn from(contract_ref: &#ref_ident) -> Self {
+                    Self {
+                        inner: ::core::clone::Clone::clone(
+                            <#ref_ident as ::ink_lang::codegen::TraitCallBuilder>::call(contract_ref),
+                        ),
+                        gas_limit: ::core::option::Option::None,
+                        proof_size_limit: ::core::option::Option::None,
+                        transferred_value: ::core::option::Option::None,
+                    }
+                }
+            }
+
+            impl ::ink_lang::CallOverrides<Environment> for #ref_ident {
+                type Caller = #overrides_ident;
+
+                #[inline]
+                fn try_call(&self) -> Self::Caller {
+                    #overrides_ident::from(self)
+                }
+
+                #[inline]
+                fn with_gas_limit(&self, gas_limit: ::core::primitive::u64) -> Self::Caller {
+                    <#overrides_ident as ::ink_lang::CallOverrides<Environment>>::with_gas_limit(
+                        &#overrides_ident::from(self),
+                        gas_limit,
+                    )
+                }
+
+                #[inline]
+                fn with_proof_size_limit(
+                    &self,
+                    proof_size_limit: ::core::primitive::u64,
+                ) -> Self::Caller {
+                    <#overrides_ident as ::ink_lang::CallOverrides<Environment>>::with_proof_size_limit(
+                        &#overrides_ident::from(self),
+                        proof_size_limit,
+                    )
+                }
+
+                #[inline]
+                fn with_value(&self, transferred_value: Balance) -> Self::Caller {
+                    <#overrides_ident as ::ink_lang::CallOverrides<Environment>>::with_value(
+                        &#overrides_ident::from(self),
+                        transferred_value,
+                    )
+                }
+            }
+
+            impl ::ink_lang::CallOverrides<Environment> for #overrides_ident {
+                type Caller = Self;
+
+                #[inline]
+                fn try_call(&self) -> Self {
+                    ::core::clone::Clone::clone(self)
+                }
+
+                #[inline]
+                fn with_gas_limit(&self, gas_limit: ::core::primitive::u64) -> Self {
+                    Self {
+                        gas_limit: ::core::option::Option::Some(gas_limit),
+                        ..::core::clone::Clone::clone(self)
+                    }
+                }
+
+                #[inline]
+                fn with_proof_size_limit(&self, proof_size_limit: ::core::primitive::u64) -> Self {
+                    Self {
+                        proof_size_limit: ::core::option::Option::Some(proof_size_limit),
+                        ..::core::clone::Clone::clone(self)
+                    }
+                }
+
+                #[inline]
+                fn with_value(&self, transferred_value: Balance) -> Self {
+                    Self {
+                        transferred_value: ::core::option::Option::Some(transferred_value),
+                        ..::core::clone::Clone::clone(self)
+                    }
+                }
+            }
+
+            impl #overrides_ident {
+                #( #messages )*
+            }
+        )
+    }
+
+    /// Generates the code for a single ink! inherent message of the short-hand caller
+    /// with overridden call parameters.
+    fn generate_call_overrides_for_message(
+        &self,
+        message: ir::CallableWithSelector<ir::Message>,
+    ) -> TokenStream2 {
+        use ir::Callable as _;
+        let span = message.span();
+        let attrs = self
+            .contract
+            .config()
+            .whitelisted_attributes()
+            .filter_attr(message.attrs().to_vec());
+        let message_ident = message.ident();
+        let input_bindings = message.inputs().map(|input| &input.pat).collect::<Vec<_>>();
+        let input_types = message.inputs().map(|input| &input.ty).collect::<Vec<_>>();
+        let try_output_type = Self::generate_try_output_type(&message);
+        let deprecated = message.deprecated().map(|note| {
+            quote_spanned!(span=>
+                #[deprecated(note = #note)]
+                #[allow(deprecated)]
+            )
+        });
+        quote_spanned!(span=>
+            #( #attrs )*
+            #deprecated
+            #[inline]
+            #[allow(unused_mut)]
+            pub fn #message_ident(
+                self
+                #( , #input_bindings : #input_types )*
+            ) -> #try_output_type {
+                let mut inner = self.inner;
+                let call = inner.#message_ident( #( #input_bindings ),* );
+                let call = match self.gas_limit {
+                    ::core::option::Option::Some(gas_limit) => call.gas_limit(gas_limit),
+                    ::core::option::Option::None => call,
+                };
+                let call = match self.proof_size_limit {
+                    ::core::option::Option::Some(proof_size_limit) => {
+                        call.proof_size_limit(proof_size_limit)
+                    }
+                    ::core::option::Option::None => call,
+                };
+                let call = match self.transferred_value {
+                    ::core::option::Option::Some(transferred_value) => {
+                        call.transferred_value(transferred_value)
+                    }
+                    ::core::option::Option::None => call,
+                };
+                call.fire()
             }
         )
     }
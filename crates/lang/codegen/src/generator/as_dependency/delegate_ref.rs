@@ -0,0 +1,212 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    generator,
+    GenerateCode,
+};
+use derive_more::From;
+use ir::{
+    Callable,
+    IsDocAttribute as _,
+};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    format_ident,
+    quote,
+    quote_spanned,
+};
+use syn::spanned::Spanned as _;
+
+/// Generates code for the delegate reference of the ink! smart contract.
+///
+/// The delegate reference is the counterpart of the contract reference that is
+/// constructed from a code hash instead of an account id. Its message methods
+/// perform delegate calls into the referenced code which executes against the
+/// storage of the calling contract. This is useful for logic libraries that are
+/// shared by many contracts.
+#[derive(From)]
+pub struct DelegateRef<'a> {
+    contract: &'a ir::Contract,
+}
+
+impl GenerateCode for DelegateRef<'_> {
+    fn generate_code(&self) -> TokenStream2 {
+        let delegate_ref = self.generate_struct();
+        let delegate_ref_trait_impls = self.generate_trait_impls();
+        let delegate_ref_inherent_impl = self.generate_inherent_impl();
+        quote! {
+            #delegate_ref
+            #delegate_ref_trait_impls
+            #delegate_ref_inherent_impl
+        }
+    }
+}
+
+impl DelegateRef<'_> {
+    /// Generates the identifier of the delegate reference struct.
+    fn generate_delegate_ref_ident(&self) -> syn::Ident {
+        format_ident!("{}DelegateRef", self.contract.module().storage().ident())
+    }
+
+    /// Generates the code for the struct representing the delegate reference.
+    fn generate_struct(&self) -> TokenStream2 {
+        let span = self.contract.module().storage().span();
+        let doc_attrs = self
+            .contract
+            .module()
+            .storage()
+            .attrs()
+            .iter()
+            .filter(|attr| attr.is_doc_attribute())
+            .cloned();
+        let storage_ident = self.contract.module().storage().ident();
+        let delegate_ref_ident = self.generate_delegate_ref_ident();
+        quote_spanned!(span=>
+            #[cfg_attr(feature = "std", derive(
+                ::scale_info::TypeInfo,
+                ::ink_storage::traits::StorageLayout,
+            ))]
+            #[derive(
+                ::core::fmt::Debug,
+                ::ink_storage::traits::SpreadLayout,
+                ::ink_storage::traits::PackedLayout,
+                ::scale::Encode,
+                ::scale::Decode,
+                ::core::hash::Hash,
+                ::core::cmp::PartialEq,
+                ::core::cmp::Eq,
+                ::core::clone::Clone,
+            )]
+            #( #doc_attrs )*
+            #[allow(dead_code)]
+            pub struct #delegate_ref_ident {
+                code_hash: Hash,
+            }
+
+            const _: () = {
+                impl ::ink_lang::reflect::ContractEnv for #delegate_ref_ident {
+                    type Env = <#storage_ident as ::ink_lang::reflect::ContractEnv>::Env;
+                }
+            };
+        )
+    }
+
+    /// Generates the conversions between the delegate reference and code hashes.
+    ///
+    /// # Note
+    ///
+    /// These are trait implementations so that ink! messages may share their names.
+    fn generate_trait_impls(&self) -> TokenStream2 {
+        let span = self.contract.module().storage().span();
+        let delegate_ref_ident = self.generate_delegate_ref_ident();
+        quote_spanned!(span=>
+            impl ::ink_lang::FromCodeHash<Environment> for #delegate_ref_ident {
+                #[inline]
+                fn from_code_hash(code_hash: Hash) -> Self {
+                    Self { code_hash }
+                }
+            }
+
+            impl ::ink_lang::ToCodeHash<Environment> for #delegate_ref_ident {
+                #[inline]
+                fn to_code_hash(&self) -> Hash {
+                    <Hash as ::core::clone::Clone>::clone(&self.code_hash)
+                }
+            }
+        )
+    }
+
+    /// Generates the inherent implementation of the delegate reference.
+    ///
+    /// # Note
+    ///
+    /// The generated implementation must live outside of an artificial `const` block
+    /// in order to properly show its documentation using `rustdoc`.
+    fn generate_inherent_impl(&self) -> TokenStream2 {
+        let span = self.contract.module().storage().span();
+        let delegate_ref_ident = self.generate_delegate_ref_ident();
+        let messages = self
+            .contract
+            .module()
+            .impls()
+            .filter(|impl_block| impl_block.trait_path().is_none())
+            .flat_map(|impl_block| impl_block.iter_messages())
+            .map(|message| self.generate_message(message));
+        quote_spanned!(span=>
+            #[allow(dead_code)]
+            impl #delegate_ref_ident {
+                #( #messages )*
+            }
+        )
+    }
+
+    /// Generates the code for a single inherent ink! message of the delegate reference.
+    ///
+    /// # Note
+    ///
+    /// The message returns the error of the delegate call instead of panicking. It
+    /// takes `&self` since the delegate call executes against the storage of the
+    /// calling contract.
+    fn generate_message(
+        &self,
+        message: ir::CallableWithSelector<ir::Message>,
+    ) -> TokenStream2 {
+        let span = message.span();
+        let callable = message.callable();
+        let attrs = self
+            .contract
+            .config()
+            .whitelisted_attributes()
+            .filter_attr(message.attrs().to_vec());
+        let message_ident = message.ident();
+        let selector_bytes = message.composed_selector().hex_lits();
+        let input_bindings = generator::input_bindings(callable.inputs());
+        let input_types = generator::input_types(message.inputs());
+        let return_type = message
+            .output()
+            .map_or_else(|| quote! { () }, |output| quote! { #output });
+        let deprecated = message.deprecated().map(|note| {
+            quote_spanned!(span=>
+                #[deprecated(note = #note)]
+                #[allow(deprecated)]
+            )
+        });
+        quote_spanned!(span=>
+            #( #attrs )*
+            #deprecated
+            #[inline]
+            pub fn #message_ident(
+                &self
+                #( , #input_bindings : #input_types )*
+            ) -> ::core::result::Result<#return_type, ::ink_env::Error> {
+                ::ink_env::call::build_call::<Environment>()
+                    .call_type(
+                        ::ink_env::call::DelegateCall::<Environment>::new()
+                            .code_hash(<Self as ::ink_lang::ToCodeHash<Environment>>::to_code_hash(self)),
+                    )
+                    .exec_input(
+                        ::ink_env::call::ExecutionInput::new(
+                            ::ink_env::call::Selector::new([ #( #selector_bytes ),* ])
+                        )
+                        #(
+                            .push_arg(#input_bindings)
+                        )*
+                    )
+                    .returns::<#return_type>()
+                    .fire()
+            }
+        )
+    }
+}
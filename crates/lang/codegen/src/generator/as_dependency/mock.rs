@@ -0,0 +1,158 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::GenerateCode;
+use derive_more::From;
+use heck::ToSnakeCase as _;
+use ir::Callable as _;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    format_ident,
+    quote_spanned,
+};
+use syn::spanned::Spanned as _;
+
+/// Generates code for the mock of the ink! smart contract.
+///
+/// The mock allows unit tests of dependent contracts to substitute canned outputs
+/// for the ink! messages of the contract at some account. Calls of the mocked
+/// messages via the contract reference then return these outputs in the off-chain
+/// environment without the contract having to be instantiated.
+#[derive(From)]
+pub struct Mock<'a> {
+    contract: &'a ir::Contract,
+}
+
+impl GenerateCode for Mock<'_> {
+    fn generate_code(&self) -> TokenStream2 {
+        let span = self.contract.module().storage().span();
+        let storage_ident = self.contract.module().storage().ident();
+        let mock_ident = format_ident!("{}Mock", storage_ident);
+        let ref_ident = format_ident!("{}Ref", storage_ident);
+        let mock_doc = format!(
+            " Mocks the ink! messages of a `{}` contract in off-chain unit tests.",
+            storage_ident
+        );
+        let messages = self.contract.module().impls().flat_map(|impl_block| {
+            let trait_ident = impl_block.trait_path().map(|trait_path| {
+                &trait_path
+                    .segments
+                    .last()
+                    .expect("encountered empty ink! trait path")
+                    .ident
+            });
+            impl_block
+                .iter_messages()
+                .map(move |message| self.generate_message(trait_ident, message))
+        });
+        quote_spanned!(span=>
+            #[cfg(feature = "std")]
+            #[doc = #mock_doc]
+            #[derive(::core::fmt::Debug, ::core::clone::Clone)]
+            #[allow(dead_code)]
+            pub struct #mock_ident {
+                account_id: AccountId,
+            }
+
+            #[cfg(feature = "std")]
+            #[allow(dead_code)]
+            impl #mock_ident {
+                /// Creates a mock of the contract at the given account.
+                ///
+                /// The account does not need to be an instantiated contract. Only
+                /// ink! messages that have been mocked can be called.
+                #[inline]
+                pub fn new(account_id: AccountId) -> Self {
+                    Self { account_id }
+                }
+
+                /// Returns a reference to the mocked contract.
+                #[inline]
+                pub fn contract_ref(&self) -> #ref_ident {
+                    <#ref_ident as ::ink_env::call::FromAccountId<Environment>>::from_account_id(
+                        <AccountId as ::core::clone::Clone>::clone(&self.account_id)
+                    )
+                }
+
+                #( #messages )*
+            }
+        )
+    }
+}
+
+impl Mock<'_> {
+    /// Generates the method that mocks a single ink! message.
+    ///
+    /// # Note
+    ///
+    /// The method is named after the ink! message with a `mock_` prefix in order to
+    /// avoid collisions with the other methods of the mock. Messages of ink! trait
+    /// implementations are additionally prefixed with the snake case name of their
+    /// ink! trait in order to avoid collisions between the messages of different
+    /// ink! traits.
+    fn generate_message(
+        &self,
+        trait_ident: Option<&syn::Ident>,
+        message: ir::CallableWithSelector<ir::Message>,
+    ) -> TokenStream2 {
+        let span = message.span();
+        let message_ident = message.ident();
+        let mock_ident = match trait_ident {
+            Some(trait_ident) => {
+                format_ident!(
+                    "mock_{}_{}",
+                    trait_ident.to_string().to_snake_case(),
+                    message_ident
+                )
+            }
+            None => format_ident!("mock_{}", message_ident),
+        };
+        let selector_bytes = message.composed_selector().hex_lits();
+        let (output_param, output) = match message.output() {
+            Some(output_type) => {
+                (
+                    Some(quote_spanned!(span=> , output: #output_type)),
+                    quote_spanned!(span=> output),
+                )
+            }
+            None => (None, quote_spanned!(span=> ())),
+        };
+        let doc = match message.output() {
+            Some(_) => {
+                format!(
+                    " Mocks the `{}` ink! message to return `output` for all of its calls.",
+                    message_ident
+                )
+            }
+            None => {
+                format!(
+                    " Mocks the `{}` ink! message to succeed for all of its calls.",
+                    message_ident
+                )
+            }
+        };
+        quote_spanned!(span=>
+            #[doc = #doc]
+            #[inline]
+            pub fn #mock_ident(&self #output_param) -> &Self {
+                ::ink_env::test::mock_message::<Environment, _>(
+                    <AccountId as ::core::clone::Clone>::clone(&self.account_id),
+                    ::ink_env::call::Selector::new([ #( #selector_bytes ),* ]),
+                    #output,
+                );
+                self
+            }
+        )
+    }
+}
@@ -14,10 +14,14 @@
 
 mod call_builder;
 mod contract_ref;
+mod delegate_ref;
+mod mock;
 
 use self::{
     call_builder::CallBuilder,
     contract_ref::ContractRef,
+    delegate_ref::DelegateRef,
+    mock::Mock,
 };
 use crate::{
     traits::GenerateCodeUsing,
@@ -44,9 +48,13 @@ impl GenerateCode for ContractReference<'_> {
     fn generate_code(&self) -> TokenStream2 {
         let call_builder = self.generate_code_using::<CallBuilder>();
         let call_forwarder = self.generate_code_using::<ContractRef>();
+        let delegate_ref = self.generate_code_using::<DelegateRef>();
+        let mock = self.generate_code_using::<Mock>();
         quote! {
             #call_builder
             #call_forwarder
+            #delegate_ref
+            #mock
         }
     }
 }
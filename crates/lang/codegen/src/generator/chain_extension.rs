@@ -12,9 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::GenerateCode;
+use crate::{
+    generator,
+    GenerateCode,
+};
 use derive_more::From;
-use ir::ChainExtensionMethod;
+use ir::{
+    ChainExtensionMethod,
+    IsDocAttribute as _,
+};
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{
     format_ident,
@@ -136,6 +142,92 @@ impl ChainExtension<'_> {
             }
         )
     }
+
+    /// Generates the ink! metadata of the chain extension.
+    fn generate_metadata(&self) -> TokenStream2 {
+        let span = self.extension.span();
+        let ident = self.extension.ident();
+        let error_code = self.extension.error_code();
+        let error_code_spec = generator::Metadata::generate_type_spec(error_code);
+        let docs = self
+            .extension
+            .attrs()
+            .iter()
+            .filter_map(|attr| attr.extract_docs())
+            .collect::<Vec<_>>();
+        let methods = self
+            .extension
+            .iter_methods()
+            .map(Self::generate_method_metadata);
+        quote_spanned!(span =>
+            #[cfg(feature = "std")]
+            const _: () = {
+                impl ::ink_metadata::ChainExtensionMetadata for #ident {
+                    fn chain_extension_spec() -> ::ink_metadata::ChainExtensionSpec {
+                        #[allow(unused_imports)]
+                        use ::ink_metadata::StatusCodeSpecsFallback as _;
+
+                        ::ink_metadata::ChainExtensionSpec::new(
+                            ::core::stringify!(#ident),
+                            #error_code_spec,
+                        )
+                        .methods([
+                            #( #methods ),*
+                        ])
+                        .status_codes(::ink_metadata::StatusCodeSpecs::<#error_code>::specs())
+                        .docs([
+                            #( #docs ),*
+                        ])
+                        .done()
+                    }
+                }
+            };
+        )
+    }
+
+    /// Generates the ink! metadata of a single chain extension method.
+    fn generate_method_metadata(method: &ChainExtensionMethod) -> TokenStream2 {
+        let span = method.span();
+        let ident = method.ident();
+        let func_id = method.id().into_u32();
+        let handle_status = method.handle_status();
+        let returns_result = method.returns_result();
+        let docs = method
+            .attrs()
+            .iter()
+            .filter_map(|attr| attr.extract_docs())
+            .collect::<Vec<_>>();
+        let args = method.inputs().map(|pat_type| {
+            let pat = &pat_type.pat;
+            let type_spec = generator::Metadata::generate_type_spec(&pat_type.ty);
+            quote_spanned!(pat_type.span() =>
+                ::ink_metadata::MessageParamSpec::new(::core::stringify!(#pat))
+                    .of_type(#type_spec)
+                    .done()
+            )
+        });
+        let return_type = match &method.sig().output {
+            syn::ReturnType::Default => {
+                quote_spanned!(span => ::core::option::Option::None)
+            }
+            syn::ReturnType::Type(_arrow, ty) => {
+                generator::Metadata::generate_type_spec(ty)
+            }
+        };
+        quote_spanned!(span =>
+            ::ink_metadata::ChainExtensionMethodSpec::new(::core::stringify!(#ident), #func_id)
+                .args([
+                    #( #args ),*
+                ])
+                .returns(::ink_metadata::ReturnTypeSpec::new(#return_type))
+                .handle_status(#handle_status)
+                .returns_result(#returns_result)
+                .docs([
+                    #( #docs ),*
+                ])
+                .done()
+        )
+    }
 }
 
 impl GenerateCode for ChainExtension<'_> {
@@ -148,11 +240,20 @@ impl GenerateCode for ChainExtension<'_> {
             .extension
             .iter_methods()
             .map(|method| Self::generate_for_instance_method(method, error_code));
+        let func_ids = self
+            .extension
+            .iter_methods()
+            .map(|method| method.id().into_u32());
         let instance_ident = format_ident!("__ink_{}Instance", ident);
+        let metadata = self.generate_metadata();
         quote_spanned!(span =>
             #(#attrs)*
             pub enum #ident {}
 
+            impl ::ink_lang::ChainExtensionFuncIds for #ident {
+                const FUNC_IDS: &'static [::core::primitive::u32] = &[ #( #func_ids ),* ];
+            }
+
             const _: () = {
                 #[allow(non_camel_case_types)]
                 struct __ink_Private;
@@ -173,6 +274,74 @@ impl GenerateCode for ChainExtension<'_> {
                     }
                 }
             };
+
+            #metadata
+        )
+    }
+}
+
+/// Generator to create the `FromStatusCode` implementation of a chain extension error code.
+#[derive(From)]
+pub struct ChainExtensionErrorCode<'a> {
+    error_code: &'a ir::ChainExtensionErrorCode,
+}
+
+impl GenerateCode for ChainExtensionErrorCode<'_> {
+    fn generate_code(&self) -> TokenStream2 {
+        let span = self.error_code.span();
+        let ident = self.error_code.ident();
+        let variant_arms = self.error_code.iter_variants().map(|variant| {
+            let variant_ident = variant.ident();
+            let status_code = variant.status_code();
+            quote_spanned!(variant_ident.span()=>
+                #status_code => ::core::result::Result::Err(Self::#variant_ident),
+            )
+        });
+        let catch_all = self.error_code.catch_all();
+        let catch_all_ident = catch_all.ident();
+        let fallback_arm = if catch_all.has_status_code_field() {
+            quote_spanned!(catch_all_ident.span()=>
+                _ => ::core::result::Result::Err(Self::#catch_all_ident(status_code)),
+            )
+        } else {
+            quote_spanned!(catch_all_ident.span()=>
+                _ => ::core::result::Result::Err(Self::#catch_all_ident),
+            )
+        };
+        let status_codes = self
+            .error_code
+            .iter_variants()
+            .map(|variant| {
+                let variant_ident = variant.ident();
+                let status_code = variant.status_code();
+                quote_spanned!(variant_ident.span()=>
+                    ::ink_metadata::StatusCodeSpec::new(::core::stringify!(#variant_ident), #status_code)
+                )
+            })
+            .chain(core::iter::once(quote_spanned!(catch_all_ident.span()=>
+                ::ink_metadata::StatusCodeSpec::catch_all(::core::stringify!(#catch_all_ident))
+            )));
+        quote_spanned!(span=>
+            #[cfg(feature = "std")]
+            const _: () = {
+                impl ::ink_metadata::ErrorCodeMetadata for #ident {
+                    const STATUS_CODES: &'static [::ink_metadata::StatusCodeSpec] = &[
+                        #( #status_codes ),*
+                    ];
+                }
+            };
+
+            impl ::ink_env::chain_extension::FromStatusCode for #ident {
+                fn from_status_code(
+                    status_code: ::core::primitive::u32,
+                ) -> ::core::result::Result<(), Self> {
+                    match status_code {
+                        0 => ::core::result::Result::Ok(()),
+                        #( #variant_arms )*
+                        #fallback_arm
+                    }
+                }
+            }
         )
     }
 }
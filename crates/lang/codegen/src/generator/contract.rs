@@ -44,6 +44,7 @@ impl GenerateCode for Contract<'_> {
         let metadata = self.generate_code_using::<generator::Metadata>();
         let contract_reference =
             self.generate_code_using::<generator::ContractReference>();
+        let panic_strategy = self.generate_panic_strategy();
         let non_ink_items = self
             .contract
             .module()
@@ -53,6 +54,7 @@ impl GenerateCode for Contract<'_> {
         quote! {
             #( #attrs )*
             #vis mod #ident {
+                #panic_strategy
                 #env
                 #storage
                 #events
@@ -65,3 +67,35 @@ impl GenerateCode for Contract<'_> {
         }
     }
 }
+
+impl Contract<'_> {
+    /// Generates code for the panic strategy of the ink! smart contract.
+    ///
+    /// For `panic = "abort-silent"` this shadows the panicking macros of the prelude
+    /// within the ink! module by their silent counterparts provided by `ink_lang`.
+    ///
+    /// # Note
+    ///
+    /// The shadowing is only in effect at the root of the ink! module. Panics of
+    /// nested modules, of dependencies and of `core` itself, e.g. via `unwrap`,
+    /// `expect` or out of bounds indexing, still go through the `core::fmt` based
+    /// panic machinery. A silent `#[panic_handler]` cannot be installed instead
+    /// since `ink_env` already provides the panic handler of on-chain builds.
+    fn generate_panic_strategy(&self) -> TokenStream2 {
+        match self.contract.config().panic_strategy() {
+            ir::PanicStrategy::Format => quote! {},
+            ir::PanicStrategy::AbortSilent => {
+                quote! {
+                    #[allow(unused_imports)]
+                    use ::ink_lang::{
+                        __ink_silent_assert as assert,
+                        __ink_silent_assert_eq as assert_eq,
+                        __ink_silent_assert_ne as assert_ne,
+                        __ink_silent_panic as panic,
+                        __ink_silent_unreachable as unreachable,
+                    };
+                }
+            }
+        }
+    }
+}
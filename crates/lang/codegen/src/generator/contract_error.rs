@@ -0,0 +1,266 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::GenerateCode;
+use derive_more::From;
+use ir::ContractErrorVariant;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    format_ident,
+    quote,
+    quote_spanned,
+};
+
+/// Generator to create the SCALE codec, type info and `ContractError` implementations
+/// of a contract error.
+#[derive(From)]
+pub struct ContractError<'a> {
+    contract_error: &'a ir::ContractError,
+}
+
+impl GenerateCode for ContractError<'_> {
+    fn generate_code(&self) -> TokenStream2 {
+        let encode = self.generate_encode();
+        let decode = self.generate_decode();
+        let type_info = self.generate_type_info();
+        let contract_error = self.generate_contract_error();
+        let from_env_error = self.generate_from_env_error();
+        quote! {
+            #encode
+            #decode
+            #type_info
+            #contract_error
+            #from_env_error
+        }
+    }
+}
+
+impl ContractError<'_> {
+    /// Returns the identifiers bound to the fields of the variant.
+    fn field_bindings(variant: &ContractErrorVariant) -> Vec<syn::Ident> {
+        (0..variant.fields().len())
+            .map(|index| format_ident!("__ink_field_{}", index))
+            .collect()
+    }
+
+    /// Returns the pattern matching the variant and binding its fields.
+    fn variant_pattern(variant: &ContractErrorVariant) -> TokenStream2 {
+        let ident = variant.ident();
+        let bindings = Self::field_bindings(variant);
+        match variant.fields() {
+            syn::Fields::Unit => quote! { Self::#ident },
+            syn::Fields::Unnamed(_) => quote! { Self::#ident( #( #bindings ),* ) },
+            syn::Fields::Named(fields) => {
+                let names = fields.named.iter().map(|field| &field.ident);
+                quote! { Self::#ident { #( #names: #bindings ),* } }
+            }
+        }
+    }
+
+    fn generate_encode(&self) -> TokenStream2 {
+        let span = self.contract_error.span();
+        let ident = self.contract_error.ident();
+        let size_hint_arms = self.contract_error.iter_variants().map(|variant| {
+            let pattern = Self::variant_pattern(variant);
+            let bindings = Self::field_bindings(variant);
+            quote_spanned!(variant.ident().span()=>
+                #pattern => 1 #( + ::scale::Encode::size_hint(#bindings) )*,
+            )
+        });
+        let encode_arms = self.contract_error.iter_variants().map(|variant| {
+            let pattern = Self::variant_pattern(variant);
+            let bindings = Self::field_bindings(variant);
+            let error_code = variant.error_code();
+            quote_spanned!(variant.ident().span()=>
+                #pattern => {
+                    ::scale::Output::push_byte(__ink_dest, #error_code);
+                    #( ::scale::Encode::encode_to(#bindings, __ink_dest); )*
+                }
+            )
+        });
+        quote_spanned!(span=>
+            impl ::scale::Encode for #ident {
+                fn size_hint(&self) -> ::core::primitive::usize {
+                    match self {
+                        #( #size_hint_arms )*
+                    }
+                }
+
+                fn encode_to<O>(&self, __ink_dest: &mut O)
+                where
+                    O: ::scale::Output + ?::core::marker::Sized,
+                {
+                    match self {
+                        #( #encode_arms )*
+                    }
+                }
+            }
+
+            impl ::scale::EncodeLike for #ident {}
+        )
+    }
+
+    fn generate_decode(&self) -> TokenStream2 {
+        let span = self.contract_error.span();
+        let ident = self.contract_error.ident();
+        let decode_arms = self.contract_error.iter_variants().map(|variant| {
+            let variant_ident = variant.ident();
+            let error_code = variant.error_code();
+            let decode_field = quote! { ::scale::Decode::decode(__ink_input)? };
+            let constructor = match variant.fields() {
+                syn::Fields::Unit => quote! { Self::#variant_ident },
+                syn::Fields::Unnamed(fields) => {
+                    let decode_fields = fields.unnamed.iter().map(|_| &decode_field);
+                    quote! { Self::#variant_ident( #( #decode_fields ),* ) }
+                }
+                syn::Fields::Named(fields) => {
+                    let names = fields.named.iter().map(|field| &field.ident);
+                    let decode_fields = fields.named.iter().map(|_| &decode_field);
+                    quote! { Self::#variant_ident { #( #names: #decode_fields ),* } }
+                }
+            };
+            quote_spanned!(variant_ident.span()=>
+                #error_code => ::core::result::Result::Ok(#constructor),
+            )
+        });
+        quote_spanned!(span=>
+            impl ::scale::Decode for #ident {
+                fn decode<I>(__ink_input: &mut I) -> ::core::result::Result<Self, ::scale::Error>
+                where
+                    I: ::scale::Input,
+                {
+                    match ::scale::Input::read_byte(__ink_input)? {
+                        #( #decode_arms )*
+                        _ => ::core::result::Result::Err(
+                            ::core::convert::From::from("encountered unknown contract error code"),
+                        ),
+                    }
+                }
+            }
+        )
+    }
+
+    fn generate_type_info(&self) -> TokenStream2 {
+        let span = self.contract_error.span();
+        let ident = self.contract_error.ident();
+        let docs = self.contract_error.docs();
+        let variants = self.contract_error.iter_variants().map(|variant| {
+            let variant_ident = variant.ident();
+            let error_code = variant.error_code();
+            let variant_docs = variant.docs();
+            let fields = match variant.fields() {
+                syn::Fields::Unit => quote! { ::scale_info::build::Fields::unit() },
+                syn::Fields::Unnamed(fields) => {
+                    let fields = fields.unnamed.iter().map(|field| {
+                        let ty = &field.ty;
+                        quote! {
+                            .field(|f| f.ty::<#ty>().type_name(::core::stringify!(#ty)))
+                        }
+                    });
+                    quote! { ::scale_info::build::Fields::unnamed() #( #fields )* }
+                }
+                syn::Fields::Named(fields) => {
+                    let fields = fields.named.iter().map(|field| {
+                        let ty = &field.ty;
+                        let name = &field.ident;
+                        quote! {
+                            .field(|f| {
+                                f.ty::<#ty>()
+                                    .name(::core::stringify!(#name))
+                                    .type_name(::core::stringify!(#ty))
+                            })
+                        }
+                    });
+                    quote! { ::scale_info::build::Fields::named() #( #fields )* }
+                }
+            };
+            quote_spanned!(variant_ident.span()=>
+                .variant(::core::stringify!(#variant_ident), |v| {
+                    v.index(#error_code)
+                        .fields(#fields)
+                        .docs(&[ #( #variant_docs ),* ])
+                })
+            )
+        });
+        quote_spanned!(span=>
+            #[cfg(feature = "std")]
+            impl ::scale_info::TypeInfo for #ident {
+                type Identity = Self;
+
+                fn type_info() -> ::scale_info::Type {
+                    ::scale_info::Type::builder()
+                        .path(::scale_info::Path::new(
+                            ::core::stringify!(#ident),
+                            ::core::module_path!(),
+                        ))
+                        .docs(&[ #( #docs ),* ])
+                        .variant(::scale_info::build::Variants::new() #( #variants )*)
+                }
+            }
+        )
+    }
+
+    fn generate_contract_error(&self) -> TokenStream2 {
+        let span = self.contract_error.span();
+        let ident = self.contract_error.ident();
+        let error_codes = self.contract_error.iter_variants().map(|variant| {
+            let variant_ident = variant.ident();
+            let error_code = variant.error_code();
+            quote_spanned!(variant_ident.span()=>
+                (#error_code, ::core::stringify!(#variant_ident))
+            )
+        });
+        let error_code_arms = self.contract_error.iter_variants().map(|variant| {
+            let variant_ident = variant.ident();
+            let error_code = variant.error_code();
+            quote_spanned!(variant_ident.span()=>
+                Self::#variant_ident { .. } => #error_code,
+            )
+        });
+        quote_spanned!(span=>
+            impl ::ink_lang::ContractError for #ident {
+                const ERROR_CODES: &'static [(::core::primitive::u8, &'static ::core::primitive::str)] = &[
+                    #( #error_codes ),*
+                ];
+
+                fn error_code(&self) -> ::core::primitive::u8 {
+                    match self {
+                        #( #error_code_arms )*
+                    }
+                }
+            }
+        )
+    }
+
+    fn generate_from_env_error(&self) -> TokenStream2 {
+        let ident = self.contract_error.ident();
+        let variant_ident = match self.contract_error.env_error() {
+            Some(variant_ident) => variant_ident,
+            None => return quote! {},
+        };
+        quote_spanned!(variant_ident.span()=>
+            impl ::core::convert::From<::ink_env::Error> for #ident {
+                fn from(_: ::ink_env::Error) -> Self {
+                    Self::#variant_ident
+                }
+            }
+
+            impl ::core::convert::From<::ink_lang::LangError> for #ident {
+                fn from(_: ::ink_lang::LangError) -> Self {
+                    Self::#variant_ident
+                }
+            }
+        )
+    }
+}
@@ -69,6 +69,8 @@ impl GenerateCode for Dispatch<'_> {
         let constructor_decoder_type =
             self.generate_constructor_decoder_type(&constructor_spans);
         let message_decoder_type = self.generate_message_decoder_type(&message_spans);
+        let message_selector_collision_checks =
+            self.generate_message_selector_collision_checks();
         let entry_points = self.generate_entry_points(&constructor_spans, &message_spans);
         quote! {
             #amount_dispatchables
@@ -78,6 +80,7 @@ impl GenerateCode for Dispatch<'_> {
             #contract_dispatchable_messages_infos
             #constructor_decoder_type
             #message_decoder_type
+            #message_selector_collision_checks
 
             #[cfg(not(test))]
             #[cfg(not(feature = "ink-as-dependency"))]
@@ -118,6 +121,26 @@ impl Dispatch<'_> {
             .position(|item| item.has_wildcard_selector())
     }
 
+    /// Returns for every ink! message whether it is non-reentrant.
+    ///
+    /// # Note
+    ///
+    /// The ink! messages are ordered like the dispatchable message identifiers,
+    /// i.e. the ink! messages of inherent implementation blocks come first.
+    fn query_non_reentrant_messages(&self) -> Vec<bool> {
+        let (inherent_impls, trait_impls): (Vec<_>, Vec<_>) = self
+            .contract
+            .module()
+            .impls()
+            .partition(|item_impl| item_impl.trait_path().is_none());
+        inherent_impls
+            .into_iter()
+            .chain(trait_impls)
+            .flat_map(|item_impl| item_impl.iter_messages())
+            .map(|message| message.is_non_reentrant())
+            .collect()
+    }
+
     /// Returns the index of the ink! constructor which has a wildcard selector, if existent.
     fn query_wildcard_constructor(&self) -> Option<usize> {
         self.contract
@@ -211,6 +234,91 @@ impl Dispatch<'_> {
         )
     }
 
+    /// Generates compile time checks that ensure that no ink! messages have overlapping
+    /// selectors.
+    ///
+    /// # Note
+    ///
+    /// The selectors of ink! trait messages are only known at compilation time of the
+    /// ink! smart contract since they may be customized by the ink! trait definition.
+    /// Therefore overlaps involving ink! trait messages cannot be detected by the ink! IR
+    /// and would otherwise only surface as conflicting trait implementations.
+    /// The generated checks instead name both of the overlapping ink! messages.
+    fn generate_message_selector_collision_checks(&self) -> TokenStream2 {
+        let storage_ident = self.contract.module().storage().ident();
+        let (inherent_impls, trait_impls): (Vec<_>, Vec<_>) = self
+            .contract
+            .module()
+            .impls()
+            .partition(|item_impl| item_impl.trait_path().is_none());
+        let messages = inherent_impls
+            .into_iter()
+            .chain(trait_impls)
+            .flat_map(|item_impl| {
+                let trait_path = item_impl.trait_path().map(|trait_path| {
+                    trait_path
+                        .segments
+                        .iter()
+                        .map(|segment| segment.ident.to_string())
+                        .collect::<Vec<_>>()
+                        .join("::")
+                });
+                item_impl.iter_messages().map(move |message| {
+                    let label = match &trait_path {
+                        Some(trait_path) => {
+                            format!(
+                                "<{} as {}>::{}",
+                                storage_ident,
+                                trait_path,
+                                message.ident()
+                            )
+                        }
+                        None => format!("{}::{}", storage_ident, message.ident()),
+                    };
+                    (
+                        label,
+                        message.span(),
+                        trait_path.is_some(),
+                        message.has_wildcard_selector(),
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+        let checks = messages.iter().enumerate().flat_map(|(j, second)| {
+            messages[..j]
+                .iter()
+                .enumerate()
+                .filter(move |(_, first)| {
+                    // Overlaps among inherent ink! messages are already detected
+                    // by the ink! IR.
+                    (first.2 || second.2) && !first.3 && !second.3
+                })
+                .map(move |(i, first)| {
+                    let (second_label, second_span, ..) = second;
+                    let error = format!(
+                        "encountered ink! messages with overlapping selectors: `{}` and `{}`\n\
+                         hint: use #[ink(selector = S:u32)] on the messages or \
+                         #[ink(namespace = N:string)] on the trait definition to \
+                         disambiguate overlapping selectors.",
+                        first.0, second_label,
+                    );
+                    quote_spanned!(*second_span=>
+                        const _: () = {
+                            let ids = <#storage_ident as ::ink_lang::reflect::ContractDispatchableMessages<{
+                                <#storage_ident as ::ink_lang::reflect::ContractAmountDispatchables>::MESSAGES
+                            }>>::IDS;
+                            if ids[#i] == ids[#j] {
+                                ::core::panic!(#error)
+                            }
+                        };
+                    )
+                })
+        });
+        quote! {
+            #( #checks )*
+        }
+    }
+
     /// Generates code for the [`ink_lang::ContractDispatchableConstructors`] trait implementation.
     ///
     /// This trait implementation stores the selector ID of each dispatchable
@@ -270,12 +378,14 @@ impl Dispatch<'_> {
                 let input_bindings = generator::input_bindings(constructor.inputs());
                 let input_tuple_type = generator::input_types_tuple(constructor.inputs());
                 let input_tuple_bindings = generator::input_bindings_tuple(constructor.inputs());
+                let output = constructor.output();
                 quote_spanned!(constructor_span=>
                     impl ::ink_lang::reflect::DispatchableConstructorInfo<#selector_id> for #storage_ident {
                         type Input = #input_tuple_type;
+                        type Output = #output;
                         type Storage = #storage_ident;
 
-                        const CALLABLE: fn(Self::Input) -> Self::Storage = |#input_tuple_bindings| {
+                        const CALLABLE: fn(Self::Input) -> Self::Output = |#input_tuple_bindings| {
                             #storage_ident::#constructor_ident( #( #input_bindings ),* )
                         };
                         const PAYABLE: ::core::primitive::bool = #payable;
@@ -316,7 +426,11 @@ impl Dispatch<'_> {
                 let input_bindings = generator::input_bindings(message.inputs());
                 let input_tuple_type = generator::input_types_tuple(message.inputs());
                 let input_tuple_bindings = generator::input_bindings_tuple(message.inputs());
+                let allow_deprecated = message
+                    .deprecated()
+                    .map(|_| quote_spanned!(message_span=> #[allow(deprecated)]));
                 quote_spanned!(message_span=>
+                    #allow_deprecated
                     impl ::ink_lang::reflect::DispatchableMessageInfo<#selector_id> for #storage_ident {
                         type Input = #input_tuple_type;
                         type Output = #output_tuple_type;
@@ -412,6 +526,10 @@ impl Dispatch<'_> {
             self.any_constructor_accepts_payment_expr(constructor_spans);
         let any_message_accept_payment =
             self.any_message_accepts_payment_expr(message_spans);
+        let panic = match self.contract.config().panic_strategy() {
+            ir::PanicStrategy::Format => quote! { ::core::panic },
+            ir::PanicStrategy::AbortSilent => quote! { ::ink_lang::__ink_silent_panic },
+        };
         quote_spanned!(span=>
             #[cfg(not(test))]
             #[no_mangle]
@@ -419,7 +537,7 @@ impl Dispatch<'_> {
             fn deploy() {
                 if !#any_constructor_accept_payment {
                     ::ink_lang::codegen::deny_payment::<<#storage_ident as ::ink_lang::reflect::ContractEnv>::Env>()
-                        .unwrap_or_else(|error| ::core::panic!("{}", error))
+                        .unwrap_or_else(|error| #panic!("{}", error))
                 }
 
                 ::ink_env::decode_input::<
@@ -430,7 +548,7 @@ impl Dispatch<'_> {
                             as ::ink_lang::reflect::ExecuteDispatchable>::execute_dispatchable(decoder)
                     })
                     .unwrap_or_else(|error| {
-                        ::core::panic!("dispatching ink! constructor failed: {}", error)
+                        #panic!("dispatching ink! constructor failed: {}", error)
                     })
             }
 
@@ -440,7 +558,7 @@ impl Dispatch<'_> {
             fn call() {
                 if !#any_message_accept_payment {
                     ::ink_lang::codegen::deny_payment::<<#storage_ident as ::ink_lang::reflect::ContractEnv>::Env>()
-                        .unwrap_or_else(|error| ::core::panic!("{}", error))
+                        .unwrap_or_else(|error| #panic!("{}", error))
                 }
 
                 ::ink_env::decode_input::<
@@ -451,7 +569,7 @@ impl Dispatch<'_> {
                             as ::ink_lang::reflect::ExecuteDispatchable>::execute_dispatchable(decoder)
                     })
                     .unwrap_or_else(|error| {
-                        ::core::panic!("dispatching ink! message failed: {}", error)
+                        ::ink_lang::codegen::return_dispatch_error(error)
                     })
             }
         )
@@ -461,6 +579,9 @@ impl Dispatch<'_> {
     ///
     /// This type can be used in order to decode the input bytes received by a call to `deploy`
     /// into one of the available dispatchable ink! constructors and their arguments.
+    ///
+    /// The selector is looked up via binary search in a table of all constructor selectors
+    /// that is sorted at compile time instead of being compared against each of them.
     fn generate_constructor_decoder_type(
         &self,
         constructor_spans: &[proc_macro2::Span],
@@ -503,16 +624,9 @@ impl Dispatch<'_> {
         let constructor_match = (0..count_constructors).map(|index| {
             let constructor_span = constructor_spans[index];
             let constructor_ident = constructor_variant_ident(index);
-            let constructor_selector = quote_spanned!(span=>
-                <#storage_ident as ::ink_lang::reflect::DispatchableConstructorInfo<{
-                    <#storage_ident as ::ink_lang::reflect::ContractDispatchableConstructors<{
-                        <#storage_ident as ::ink_lang::reflect::ContractAmountDispatchables>::CONSTRUCTORS
-                    }>>::IDS[#index]
-                }>>::SELECTOR
-            );
             let constructor_input = expand_constructor_input(constructor_span, storage_ident, index);
             quote_spanned!(constructor_span=>
-                #constructor_selector => {
+                ::core::option::Option::Some(#index) => {
                     ::core::result::Result::Ok(Self::#constructor_ident(
                         <#constructor_input as ::scale::Decode>::decode(input)
                             .map_err(|_| ::ink_lang::reflect::DispatchError::InvalidParameters)?
@@ -582,17 +696,27 @@ impl Dispatch<'_> {
                     #( #constructors_variants ),*
                 }
 
+                static CONSTRUCTOR_SELECTORS: [
+                    (::core::primitive::u32, ::core::primitive::usize);
+                    <#storage_ident as ::ink_lang::reflect::ContractAmountDispatchables>::CONSTRUCTORS
+                ] = ::ink_lang::codegen::sort_selectors(
+                    <#storage_ident as ::ink_lang::reflect::ContractDispatchableConstructors<{
+                        <#storage_ident as ::ink_lang::reflect::ContractAmountDispatchables>::CONSTRUCTORS
+                    }>>::IDS
+                );
+
                 impl ::ink_lang::reflect::DecodeDispatch for __ink_ConstructorDecoder {
                     fn decode_dispatch<I>(input: &mut I)
                         -> ::core::result::Result<Self, ::ink_lang::reflect::DispatchError>
                     where
                         I: ::scale::Input,
                     {
-                        match <[::core::primitive::u8; 4usize] as ::scale::Decode>::decode(input)
-                            .map_err(|_| ::ink_lang::reflect::DispatchError::InvalidSelector)?
-                        {
+                        let selector =
+                            <[::core::primitive::u8; 4usize] as ::scale::Decode>::decode(input)
+                                .map_err(|_| ::ink_lang::reflect::DispatchError::InvalidSelector)?;
+                        match ::ink_lang::codegen::lookup_selector(&CONSTRUCTOR_SELECTORS, selector) {
                             #( #constructor_match , )*
-                            _invalid => #possibly_wildcard_selector_constructor
+                            _ => #possibly_wildcard_selector_constructor
                         }
                     }
                 }
@@ -627,6 +751,9 @@ impl Dispatch<'_> {
     ///
     /// This type can be used in order to decode the input bytes received by a call to `call`
     /// into one of the available dispatchable ink! messages and their arguments.
+    ///
+    /// The selector is looked up via binary search in a table of all message selectors
+    /// that is sorted at compile time instead of being compared against each of them.
     fn generate_message_decoder_type(
         &self,
         message_spans: &[proc_macro2::Span],
@@ -668,16 +795,9 @@ impl Dispatch<'_> {
         let message_match = (0..count_messages).map(|index| {
             let message_span = message_spans[index];
             let message_ident = message_variant_ident(index);
-            let message_selector = quote_spanned!(span=>
-                <#storage_ident as ::ink_lang::reflect::DispatchableMessageInfo<{
-                    <#storage_ident as ::ink_lang::reflect::ContractDispatchableMessages<{
-                        <#storage_ident as ::ink_lang::reflect::ContractAmountDispatchables>::MESSAGES
-                    }>>::IDS[#index]
-                }>>::SELECTOR
-            );
             let message_input = expand_message_input(message_span, storage_ident, index);
             quote_spanned!(message_span=>
-                #message_selector => {
+                ::core::option::Option::Some(#index) => {
                     ::core::result::Result::Ok(Self::#message_ident(
                         <#message_input as ::scale::Decode>::decode(input)
                             .map_err(|_| ::ink_lang::reflect::DispatchError::InvalidParameters)?
@@ -705,6 +825,7 @@ impl Dispatch<'_> {
             }
         };
 
+        let non_reentrant_messages = self.query_non_reentrant_messages();
         let message_execute = (0..count_messages).map(|index| {
             let message_span = message_spans[index];
             let message_ident = message_variant_ident(index);
@@ -737,6 +858,36 @@ impl Dispatch<'_> {
                 }>>::MUTATES
             );
 
+            let message_selector = quote_spanned!(message_span=>
+                <#storage_ident as ::ink_lang::reflect::DispatchableMessageInfo<{
+                    <#storage_ident as ::ink_lang::reflect::ContractDispatchableMessages<{
+                        <#storage_ident as ::ink_lang::reflect::ContractAmountDispatchables>::MESSAGES
+                    }>>::IDS[#index]
+                }>>::SELECTOR
+            );
+            let [before_call, after_call] =
+                [ir::HookKind::BeforeCall, ir::HookKind::AfterCall].map(|kind| {
+                    self.contract.module().hook(kind).map(|hook| {
+                        let hook_ident = hook.ident();
+                        quote_spanned!(message_span=>
+                            #storage_ident::#hook_ident(&contract, #message_selector);
+                        )
+                    })
+                });
+
+            let (acquire_lock, release_lock) = non_reentrant_messages[index]
+                .then(|| {
+                    (
+                        quote_spanned!(message_span=>
+                            ::ink_lang::codegen::acquire_reentrancy_lock()?;
+                        ),
+                        quote_spanned!(message_span=>
+                            ::ink_lang::codegen::release_reentrancy_lock();
+                        ),
+                    )
+                })
+                .unzip();
+
             quote_spanned!(message_span=>
                 Self::#message_ident(input) => {
                     use ::core::default::Default;
@@ -746,7 +897,11 @@ impl Dispatch<'_> {
                             <#storage_ident as ::ink_lang::reflect::ContractEnv>::Env>()?;
                     }
 
+                    #before_call
+                    #acquire_lock
                     let result: #message_output = #message_callable(&mut contract, input);
+                    #release_lock
+                    #after_call
                     let failure = ::ink_lang::is_result_type!(#message_output)
                         && ::ink_lang::is_result_err!(result);
 
@@ -754,19 +909,18 @@ impl Dispatch<'_> {
                         // We return early here since there is no need to push back the
                         // intermediate results of the contract - the transaction is going to be
                         // reverted anyways.
-                        ::ink_env::return_value::<#message_output>(
-                            ::ink_env::ReturnFlags::default().set_reverted(true), &result
+                        ::ink_env::return_value::<::ink_lang::MessageResult<#message_output>>(
+                            ::ink_env::ReturnFlags::default().set_reverted(true),
+                            &::core::result::Result::Ok(result),
                         )
                     }
 
                     push_contract(contract, #mutates_storage);
 
-                    if ::core::any::TypeId::of::<#message_output>() != ::core::any::TypeId::of::<()>() {
-                        // In case the return type is `()` we do not return a value.
-                        ::ink_env::return_value::<#message_output>(
-                            ::ink_env::ReturnFlags::default(), &result
-                        )
-                    }
+                    ::ink_env::return_value::<::ink_lang::MessageResult<#message_output>>(
+                        ::ink_env::ReturnFlags::default(),
+                        &::core::result::Result::Ok(result),
+                    )
                 }
             )
         });
@@ -778,17 +932,27 @@ impl Dispatch<'_> {
                     #( #message_variants ),*
                 }
 
+                static MESSAGE_SELECTORS: [
+                    (::core::primitive::u32, ::core::primitive::usize);
+                    <#storage_ident as ::ink_lang::reflect::ContractAmountDispatchables>::MESSAGES
+                ] = ::ink_lang::codegen::sort_selectors(
+                    <#storage_ident as ::ink_lang::reflect::ContractDispatchableMessages<{
+                        <#storage_ident as ::ink_lang::reflect::ContractAmountDispatchables>::MESSAGES
+                    }>>::IDS
+                );
+
                 impl ::ink_lang::reflect::DecodeDispatch for __ink_MessageDecoder {
                     fn decode_dispatch<I>(input: &mut I)
                         -> ::core::result::Result<Self, ::ink_lang::reflect::DispatchError>
                     where
                         I: ::scale::Input,
                     {
-                        match <[::core::primitive::u8; 4usize] as ::scale::Decode>::decode(input)
-                            .map_err(|_| ::ink_lang::reflect::DispatchError::InvalidSelector)?
-                        {
+                        let selector =
+                            <[::core::primitive::u8; 4usize] as ::scale::Decode>::decode(input)
+                                .map_err(|_| ::ink_lang::reflect::DispatchError::InvalidSelector)?;
+                        match ::ink_lang::codegen::lookup_selector(&MESSAGE_SELECTORS, selector) {
                             #( #message_match , )*
-                            _invalid => #possibly_wildcard_selector_message
+                            _ => #possibly_wildcard_selector_message
                         }
                     }
                 }
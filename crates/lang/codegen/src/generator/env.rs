@@ -15,7 +15,11 @@
 use crate::GenerateCode;
 use derive_more::From;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
+use quote::{
+    quote,
+    quote_spanned,
+};
+use syn::spanned::Spanned as _;
 
 /// Generates code for the ink! environment of the contract.
 #[derive(From)]
@@ -27,6 +31,7 @@ impl GenerateCode for Env<'_> {
     fn generate_code(&self) -> TokenStream2 {
         let env = self.contract.config().env();
         let storage_ident = self.contract.module().storage().ident();
+        let generic_impl = self.generate_generic_impl();
         quote! {
             impl ::ink_lang::reflect::ContractEnv for #storage_ident {
                 type Env = #env;
@@ -39,6 +44,41 @@ impl GenerateCode for Env<'_> {
             type Hash = <<#storage_ident as ::ink_lang::reflect::ContractEnv>::Env as ::ink_env::Environment>::Hash;
             type Timestamp = <<#storage_ident as ::ink_lang::reflect::ContractEnv>::Env as ::ink_env::Environment>::Timestamp;
             type BlockNumber = <<#storage_ident as ::ink_lang::reflect::ContractEnv>::Env as ::ink_env::Environment>::BlockNumber;
+
+            #generic_impl
         }
     }
 }
+
+impl Env<'_> {
+    /// Generates the type alias that resolves the generic parameter of the ink!
+    /// storage struct to the type given via the `impl` configuration argument.
+    ///
+    /// Also asserts that the resolved type satisfies all the bounds of the stripped
+    /// generics of the ink! storage struct and its implementation blocks.
+    fn generate_generic_impl(&self) -> TokenStream2 {
+        let generic = match self.contract.generic() {
+            Some(generic) => generic,
+            None => return quote! {},
+        };
+        let span = generic.impl_type().span();
+        let param = generic.param();
+        let impl_type = generic.impl_type();
+        let assertions = generic.bounds().iter().map(|generics| {
+            let where_clause = &generics.where_clause;
+            quote_spanned!(span=>
+                {
+                    fn assert_bounds #generics () #where_clause {}
+                    let _: fn() = assert_bounds::<#impl_type>;
+                }
+            )
+        });
+        quote_spanned!(span=>
+            type #param = #impl_type;
+
+            const _: () = {
+                #( #assertions )*
+            };
+        )
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::GenerateCode;
+use derive_more::From;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    format_ident,
+    quote,
+    quote_spanned,
+};
+use syn::spanned::Spanned as _;
+
+/// Generates code for a custom environment of ink! smart contracts.
+///
+/// All types that are not configured refer to the types of the default environment.
+#[derive(From)]
+pub struct Environment<'a> {
+    environment: &'a ir::InkEnvironment,
+}
+
+impl GenerateCode for Environment<'_> {
+    fn generate_code(&self) -> TokenStream2 {
+        let item = self.environment.item();
+        let ident = self.environment.ident();
+        let span = item.span();
+        let max_event_topics = match self.environment.max_event_topics() {
+            Some(max_event_topics) => quote! { #max_event_topics },
+            None => {
+                quote! {
+                    <::ink_env::DefaultEnvironment as ::ink_env::Environment>::MAX_EVENT_TOPICS
+                }
+            }
+        };
+        let types = ir::EnvironmentType::ALL.into_iter().map(|kind| {
+            let assoc_type = format_ident!("{}", kind.assoc_type_name());
+            match self.environment.get(kind) {
+                Some(ty) => quote_spanned!(ty.span()=> type #assoc_type = #ty;),
+                None => {
+                    quote! {
+                        type #assoc_type =
+                            <::ink_env::DefaultEnvironment as ::ink_env::Environment>::#assoc_type;
+                    }
+                }
+            }
+        });
+        quote_spanned!(span=>
+            #item
+
+            impl ::ink_env::Environment for #ident {
+                const MAX_EVENT_TOPICS: ::core::primitive::usize = #max_event_topics;
+
+                #( #types )*
+            }
+        )
+    }
+}
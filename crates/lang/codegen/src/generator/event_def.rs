@@ -0,0 +1,70 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    generator,
+    GenerateCode,
+};
+use derive_more::From;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote_spanned;
+use syn::spanned::Spanned as _;
+
+/// Generates code for an ink! event defined outside of an ink! smart contract.
+#[derive(From)]
+pub struct EventDefinition<'a> {
+    event_def: &'a ir::InkEventDefinition,
+}
+
+impl GenerateCode for EventDefinition<'_> {
+    fn generate_code(&self) -> TokenStream2 {
+        let event = self.event_def.event();
+        let span = event.span();
+        // Shared ink! events are not tied to a contract, so their topics
+        // are prefixed with the name of the event only.
+        let signature = event.ident().to_string();
+        let event_struct = generator::Events::generate_event_struct(event);
+        let len_topics_impl = generator::Events::generate_len_topics_impl(event);
+        let topics_impl = generator::Events::generate_topics_impl(event, &signature);
+        let event_metadata_impl = self.generate_event_metadata_impl(&signature);
+        quote_spanned!(span =>
+            #event_struct
+            #len_topics_impl
+            #topics_impl
+            #event_metadata_impl
+        )
+    }
+}
+
+impl EventDefinition<'_> {
+    /// Generates the `EventMetadata` trait implementation of the ink! event.
+    ///
+    /// Used by the ink! smart contracts importing the event for their metadata.
+    fn generate_event_metadata_impl(&self, signature: &str) -> TokenStream2 {
+        let event = self.event_def.event();
+        let span = event.span();
+        let event_ident = event.ident();
+        let event_spec = generator::Metadata::generate_event_spec(event, signature);
+        quote_spanned!(span =>
+            #[cfg(feature = "std")]
+            const _: () = {
+                impl ::ink_metadata::EventMetadata for #event_ident {
+                    fn event_spec() -> ::ink_metadata::EventSpec {
+                        #event_spec
+                    }
+                }
+            };
+        )
+    }
+}
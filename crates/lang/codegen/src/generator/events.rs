@@ -33,7 +33,9 @@ impl_as_ref_for_generator!(Events);
 
 impl GenerateCode for Events<'_> {
     fn generate_code(&self) -> TokenStream2 {
-        if self.contract.module().events().next().is_none() {
+        if self.contract.module().events().next().is_none()
+            && self.contract.module().shared_events().next().is_none()
+        {
             // Generate no code in case there are no event definitions.
             return TokenStream2::new()
         }
@@ -42,7 +44,9 @@ impl GenerateCode for Events<'_> {
         let topic_guards = self.generate_topic_guards();
         let topics_impls = self.generate_topics_impls();
         let event_structs = self.generate_event_structs();
+        let shared_event_imports = self.generate_shared_event_imports();
         quote! {
+            #( #shared_event_imports )*
             #emit_event_trait_impl
             #event_base
             #( #topic_guards )*
@@ -90,6 +94,12 @@ impl<'a> Events<'a> {
             .events()
             .map(|event| event.ident())
             .collect::<Vec<_>>();
+        let shared_event_idents = self
+            .contract
+            .module()
+            .shared_events()
+            .map(|shared_event| shared_event.ident())
+            .collect::<Vec<_>>();
         let base_event_ident =
             proc_macro2::Ident::new("__ink_EventBase", Span::call_site());
         quote! {
@@ -98,6 +108,7 @@ impl<'a> Events<'a> {
             #[cfg(not(feature = "__ink_dylint_EventBase"))]
             pub enum #base_event_ident {
                 #( #event_idents(#event_idents), )*
+                #( #shared_event_idents(#shared_event_idents), )*
             }
 
             const _: () = {
@@ -113,6 +124,20 @@ impl<'a> Events<'a> {
                             Self::#event_idents(event)
                         }
                     }
+
+                    impl ::ink_lang::reflect::ContractEvent for #event_idents {
+                        type Contract = #storage_ident;
+                    }
+                };
+            )*
+
+            #(
+                const _: () = {
+                    impl From<#shared_event_idents> for #base_event_ident {
+                        fn from(event: #shared_event_idents) -> Self {
+                            Self::#shared_event_idents(event)
+                        }
+                    }
                 };
             )*
 
@@ -139,6 +164,11 @@ impl<'a> Events<'a> {
                                     <#event_idents as ::ink_env::Topics>::topics::<E, B>(event, builder)
                                 }
                             )*
+                            #(
+                                Self::#shared_event_idents(event) => {
+                                    <#shared_event_idents as ::ink_env::Topics>::topics::<E, B>(event, builder)
+                                }
+                            )*
                         }
                     }
                 }
@@ -146,21 +176,43 @@ impl<'a> Events<'a> {
         }
     }
 
-    /// Generate checks to guard against too many topics in event definitions.
-    fn generate_topics_guard(&self, event: &ir::Event) -> TokenStream2 {
+    /// Generates the `use` items importing the shared ink! events.
+    fn generate_shared_event_imports(
+        &'a self,
+    ) -> impl Iterator<Item = TokenStream2> + 'a {
+        self.contract.module().shared_events().map(|shared_event| {
+            let span = shared_event.span();
+            let item_use = shared_event.item_use();
+            quote_spanned!(span =>
+                #item_use
+            )
+        })
+    }
+
+    /// Generates the `EventLenTopics` trait implementation for the given ink! event.
+    pub(crate) fn generate_len_topics_impl(event: &ir::Event) -> TokenStream2 {
         let span = event.span();
-        let storage_ident = self.contract.module().storage().ident();
         let event_ident = event.ident();
         let len_topics = event.fields().filter(|event| event.is_topic).count();
-        let max_len_topics = quote_spanned!(span=>
-            <<#storage_ident as ::ink_lang::reflect::ContractEnv>::Env
-                as ::ink_env::Environment>::MAX_EVENT_TOPICS
-        );
         quote_spanned!(span=>
             impl ::ink_lang::codegen::EventLenTopics for #event_ident {
                 type LenTopics = ::ink_lang::codegen::EventTopics<#len_topics>;
             }
+        )
+    }
 
+    /// Generate checks to guard against too many topics in event definitions.
+    fn generate_topics_guard(
+        &self,
+        span: Span,
+        event_ident: &syn::Ident,
+    ) -> TokenStream2 {
+        let storage_ident = self.contract.module().storage().ident();
+        let max_len_topics = quote_spanned!(span=>
+            <<#storage_ident as ::ink_lang::reflect::ContractEnv>::Env
+                as ::ink_env::Environment>::MAX_EVENT_TOPICS
+        );
+        quote_spanned!(span=>
             const _: () = ::ink_lang::codegen::utils::consume_type::<
                 ::ink_lang::codegen::EventRespectsTopicLimit<
                     #event_ident,
@@ -172,113 +224,167 @@ impl<'a> Events<'a> {
 
     /// Generates the guard code that protects against having too many topics defined on an ink! event.
     fn generate_topic_guards(&'a self) -> impl Iterator<Item = TokenStream2> + 'a {
-        self.contract.module().events().map(move |event| {
+        let event_guards = self.contract.module().events().map(move |event| {
             let span = event.span();
-            let topics_guard = self.generate_topics_guard(event);
+            let len_topics_impl = Self::generate_len_topics_impl(event);
+            let topics_guard = self.generate_topics_guard(span, event.ident());
             quote_spanned!(span =>
+                #len_topics_impl
                 #topics_guard
             )
-        })
+        });
+        // The `EventLenTopics` trait implementations of shared ink! events are
+        // generated alongside their definitions.
+        let shared_event_guards =
+            self.contract
+                .module()
+                .shared_events()
+                .map(move |shared_event| {
+                    self.generate_topics_guard(shared_event.span(), shared_event.ident())
+                });
+        event_guards.chain(shared_event_guards)
     }
 
     /// Generates the `Topics` trait implementations for the user defined events.
     fn generate_topics_impls(&'a self) -> impl Iterator<Item = TokenStream2> + 'a {
         let contract_ident = self.contract.module().storage().ident();
         self.contract.module().events().map(move |event| {
-            let span = event.span();
-            let event_ident = event.ident();
-            let event_signature = syn::LitByteStr::new(
-                format!("{}::{}", contract_ident, event_ident
-            ).as_bytes(), span);
-            let len_event_signature = event_signature.value().len();
-            let len_topics = event.fields().filter(|field| field.is_topic).count();
-            let topic_impls = event
-                .fields()
-                .enumerate()
-                .filter(|(_, field)| field.is_topic)
-                .map(|(n, topic_field)| {
-                    let span = topic_field.span();
-                    let field_ident = topic_field
-                        .ident()
-                        .map(quote::ToTokens::into_token_stream)
-                        .unwrap_or_else(|| quote_spanned!(span => #n));
-                    let field_type = topic_field.ty();
-                    let signature = syn::LitByteStr::new(
-                        format!("{}::{}::{}", contract_ident, event_ident,
-                            field_ident
-                        ).as_bytes(), span);
-                    quote_spanned!(span =>
-                        .push_topic::<::ink_env::topics::PrefixedValue<#field_type>>(
-                            &::ink_env::topics::PrefixedValue { value: &self.#field_ident, prefix: #signature }
-                        )
+            let signature = format!("{}::{}", contract_ident, event.ident());
+            Self::generate_topics_impl(event, &signature)
+        })
+    }
+
+    /// Returns the signature topic of the given ink! event unless it is anonymous.
+    ///
+    /// This is the first topic pushed by the `Topics` implementation generated by
+    /// [`Self::generate_topics_impl`] for the same `signature` given 32 bytes topics.
+    pub(crate) fn signature_topic(
+        event: &ir::Event,
+        signature: &str,
+    ) -> Option<[u8; 32]> {
+        if event.anonymous {
+            return None
+        }
+        // The signature is pushed as `PrefixedValue` with an empty prefix which
+        // encodes as the compact encoded length of the prefix followed by the signature.
+        let encoded = ::core::iter::once(0x00)
+            .chain(signature.bytes())
+            .collect::<Vec<u8>>();
+        let mut topic = [0x00; 32];
+        if encoded.len() <= topic.len() {
+            topic[..encoded.len()].copy_from_slice(&encoded);
+        } else {
+            ir::blake2b_256(&encoded, &mut topic);
+        }
+        Some(topic)
+    }
+
+    /// Generates the `Topics` trait implementation for the given ink! event.
+    ///
+    /// The topics of the event fields are prefixed with the event `signature`.
+    pub(crate) fn generate_topics_impl(
+        event: &ir::Event,
+        signature: &str,
+    ) -> TokenStream2 {
+        let span = event.span();
+        let event_ident = event.ident();
+        let event_signature = syn::LitByteStr::new(signature.as_bytes(), span);
+        let len_event_signature = event_signature.value().len();
+        let len_topics = event.fields().filter(|field| field.is_topic).count();
+        let topic_impls = event
+            .fields()
+            .enumerate()
+            .filter(|(_, field)| field.is_topic)
+            .map(|(n, topic_field)| {
+                let span = topic_field.span();
+                let field_ident = topic_field
+                    .ident()
+                    .map(quote::ToTokens::into_token_stream)
+                    .unwrap_or_else(|| quote_spanned!(span => #n));
+                let field_type = topic_field.ty();
+                let signature = syn::LitByteStr::new(
+                    format!("{}::{}", signature, field_ident).as_bytes(), span);
+                quote_spanned!(span =>
+                    .push_topic::<::ink_env::topics::PrefixedValue<#field_type>>(
+                        &::ink_env::topics::PrefixedValue { value: &self.#field_ident, prefix: #signature }
                     )
-                });
-            // Only include topic for event signature in case of non-anonymous event.
-            let event_signature_topic = match event.anonymous {
-                true => None,
-                false => Some(quote_spanned!(span=>
+                )
+            });
+        // Only include topic for event signature in case of non-anonymous event.
+        let event_signature_topic = match event.anonymous {
+            true => None,
+            false => {
+                Some(quote_spanned!(span=>
                     .push_topic::<::ink_env::topics::PrefixedValue<[u8; #len_event_signature]>>(
                         &::ink_env::topics::PrefixedValue { value: #event_signature, prefix: b"" }
                     )
                 ))
-            };
-            // Anonymous events require 1 fewer topics since they do not include their signature.
-            let anonymous_topics_offset = if event.anonymous { 0 } else { 1 };
-            let remaining_topics_ty = match len_topics + anonymous_topics_offset {
-                0 => quote_spanned!(span=> ::ink_env::topics::state::NoRemainingTopics),
-                n => quote_spanned!(span=> [::ink_env::topics::state::HasRemainingTopics; #n]),
-            };
-            quote_spanned!(span =>
-                const _: () = {
-                    impl ::ink_env::Topics for #event_ident {
-                        type RemainingTopics = #remaining_topics_ty;
+            }
+        };
+        // Anonymous events require 1 fewer topics since they do not include their signature.
+        let anonymous_topics_offset = if event.anonymous { 0 } else { 1 };
+        let remaining_topics_ty = match len_topics + anonymous_topics_offset {
+            0 => quote_spanned!(span=> ::ink_env::topics::state::NoRemainingTopics),
+            n => {
+                quote_spanned!(span=> [::ink_env::topics::state::HasRemainingTopics; #n])
+            }
+        };
+        quote_spanned!(span =>
+            const _: () = {
+                impl ::ink_env::Topics for #event_ident {
+                    type RemainingTopics = #remaining_topics_ty;
 
-                        fn topics<E, B>(
-                            &self,
-                            builder: ::ink_env::topics::TopicsBuilder<::ink_env::topics::state::Uninit, E, B>,
-                        ) -> <B as ::ink_env::topics::TopicsBuilderBackend<E>>::Output
-                        where
-                            E: ::ink_env::Environment,
-                            B: ::ink_env::topics::TopicsBuilderBackend<E>,
-                        {
-                            builder
-                                .build::<Self>()
-                                #event_signature_topic
-                                #(
-                                    #topic_impls
-                                )*
-                                .finish()
-                        }
+                    fn topics<E, B>(
+                        &self,
+                        builder: ::ink_env::topics::TopicsBuilder<::ink_env::topics::state::Uninit, E, B>,
+                    ) -> <B as ::ink_env::topics::TopicsBuilderBackend<E>>::Output
+                    where
+                        E: ::ink_env::Environment,
+                        B: ::ink_env::topics::TopicsBuilderBackend<E>,
+                    {
+                        builder
+                            .build::<Self>()
+                            #event_signature_topic
+                            #(
+                                #topic_impls
+                            )*
+                            .finish()
                     }
-                };
-            )
-        })
+                }
+            };
+        )
     }
 
     /// Generates all the user defined event struct definitions.
     fn generate_event_structs(&'a self) -> impl Iterator<Item = TokenStream2> + 'a {
-        self.contract.module().events().map(move |event| {
-            let span = event.span();
-            let ident = event.ident();
-            let attrs = event.attrs();
-            let fields = event.fields().map(|event_field| {
-                let span = event_field.span();
-                let attrs = event_field.attrs();
-                let vis = event_field.vis();
-                let ident = event_field.ident();
-                let ty = event_field.ty();
-                quote_spanned!(span=>
-                    #( #attrs )*
-                    #vis #ident : #ty
-                )
-            });
-            quote_spanned!(span =>
+        self.contract
+            .module()
+            .events()
+            .map(Self::generate_event_struct)
+    }
+
+    /// Generates the struct definition of the given ink! event.
+    pub(crate) fn generate_event_struct(event: &ir::Event) -> TokenStream2 {
+        let span = event.span();
+        let ident = event.ident();
+        let attrs = event.attrs();
+        let fields = event.fields().map(|event_field| {
+            let span = event_field.span();
+            let attrs = event_field.attrs();
+            let vis = event_field.vis();
+            let ident = event_field.ident();
+            let ty = event_field.ty();
+            quote_spanned!(span=>
                 #( #attrs )*
-                #[derive(scale::Encode, scale::Decode)]
-                pub struct #ident {
-                    #( #fields ),*
-                }
+                #vis #ident : #ty
             )
-        })
+        });
+        quote_spanned!(span =>
+            #( #attrs )*
+            #[derive(scale::Encode, scale::Decode)]
+            pub struct #ident {
+                #( #fields ),*
+            }
+        )
     }
 }
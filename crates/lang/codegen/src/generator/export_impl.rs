@@ -0,0 +1,101 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::GenerateCode;
+use derive_more::From;
+use ir::IsDocAttribute as _;
+use proc_macro2::{
+    Group,
+    Punct,
+    Spacing,
+    TokenStream as TokenStream2,
+    TokenTree,
+};
+use quote::{
+    quote_spanned,
+    ToTokens as _,
+};
+use syn::spanned::Spanned as _;
+
+/// Generates the extension point of an ink! implementation block exported via
+/// `#[ink::export_impl]`.
+///
+/// The extension point is a `macro_rules!` macro that is invoked by `#[ink::include_impl]`
+/// with the `#[ink_lang::include_impl]` attribute and the ink! smart contract module
+/// and hands the implementation block back to the attribute.
+#[derive(From)]
+pub struct ExportImpl<'a> {
+    export: &'a ir::InkExportImpl,
+}
+
+impl GenerateCode for ExportImpl<'_> {
+    fn generate_code(&self) -> TokenStream2 {
+        let item = self.export.item();
+        let span = item.span();
+        let ident = self.export.ident();
+        let docs = item.attrs.iter().filter(|attr| attr.is_doc_attribute());
+        let item_impl = Self::replace_crate_paths(item.to_token_stream());
+        quote_spanned!(span=>
+            #( #docs )*
+            ///
+            /// This is an ink! implementation block extension point that is included
+            /// into ink! smart contracts via `#[ink::include_impl(path::to::extension_point)]`.
+            #[macro_export]
+            macro_rules! #ident {
+                ( #[ $( $include:tt )* ] $( $module:tt )* ) => {
+                    #[ $( $include )* ( #item_impl ) ]
+                    $( $module )*
+                };
+            }
+        )
+    }
+}
+
+impl ExportImpl<'_> {
+    /// Replaces all paths starting with `crate::` by paths starting with `$crate::`.
+    ///
+    /// This way paths into the exporting crate still resolve to the exporting crate
+    /// once the implementation block is included by an ink! smart contract.
+    fn replace_crate_paths(tokens: TokenStream2) -> TokenStream2 {
+        let tokens = tokens.into_iter().collect::<Vec<_>>();
+        let mut replaced = TokenStream2::new();
+        for (n, token) in tokens.iter().enumerate() {
+            match token {
+                TokenTree::Group(group) => {
+                    let mut new_group = Group::new(
+                        group.delimiter(),
+                        Self::replace_crate_paths(group.stream()),
+                    );
+                    new_group.set_span(group.span());
+                    replaced.extend([TokenTree::Group(new_group)]);
+                }
+                TokenTree::Ident(ident) if ident == "crate" => {
+                    let is_path = matches!(
+                        tokens.get(n + 1),
+                        Some(TokenTree::Punct(punct))
+                            if punct.as_char() == ':' && punct.spacing() == Spacing::Joint
+                    );
+                    if is_path {
+                        let mut dollar = Punct::new('$', Spacing::Alone);
+                        dollar.set_span(ident.span());
+                        replaced.extend([TokenTree::Punct(dollar)]);
+                    }
+                    replaced.extend([token.clone()]);
+                }
+                _ => replaced.extend([token.clone()]),
+            }
+        }
+        replaced
+    }
+}
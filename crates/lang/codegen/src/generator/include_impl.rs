@@ -0,0 +1,52 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::GenerateCode;
+use derive_more::From;
+use ir::IncludeImplSource;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    quote,
+    ToTokens as _,
+};
+
+/// Generates code for an ink! smart contract including an implementation block
+/// exported via `#[ink::export_impl]`.
+///
+/// Invokes the extension point with the ink! smart contract module which hands the
+/// implementation block back to `#[ink_lang::include_impl]`. The implementation
+/// block is then appended to the ink! smart contract module.
+#[derive(From)]
+pub struct IncludeImpl<'a> {
+    include: &'a ir::InkIncludeImpl,
+}
+
+impl GenerateCode for IncludeImpl<'_> {
+    fn generate_code(&self) -> TokenStream2 {
+        match self.include.source() {
+            IncludeImplSource::ExtensionPoint(path) => {
+                let module = self.include.module();
+                quote! {
+                    #path! {
+                        #[::ink_lang::include_impl]
+                        #module
+                    }
+                }
+            }
+            IncludeImplSource::Impl(item_impl) => {
+                self.include.expand(item_impl).into_token_stream()
+            }
+        }
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::GenerateCode;
+use derive_more::From;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    quote,
+    quote_spanned,
+};
+
+/// Generates code for the `[ink::e2e_test]` macro.
+#[derive(From)]
+pub struct InkE2ETest<'a> {
+    /// The end-to-end test function to generate code for.
+    test: &'a ir::InkE2ETest,
+}
+
+impl GenerateCode for InkE2ETest<'_> {
+    /// Generates the code for `#[ink:e2e_test]`.
+    fn generate_code(&self) -> TokenStream2 {
+        let item_fn = &self.test.item_fn;
+        let attrs = &item_fn.attrs;
+        let sig = &item_fn.sig;
+        let fn_name = &sig.ident;
+        let fn_return_type = &sig.output;
+        let vis = &item_fn.vis;
+        let block = &item_fn.block;
+        let (client_pat, client_type) = self.test.client();
+        let expect_msg = format!(
+            "{}: connecting the ink! end-to-end test client failed",
+            fn_name
+        );
+        let connect = quote_spanned!(self.test.client_span()=>
+            let #client_pat: #client_type =
+                <#client_type as ::ink_lang::e2e::Client>::connect()
+                    .await
+                    .unwrap_or_else(|error| ::core::panic!("{}: {:?}", #expect_msg, error));
+        );
+        let block = match fn_return_type {
+            syn::ReturnType::Default => quote! { #block },
+            syn::ReturnType::Type(_, ret_type) => {
+                quote! {{
+                    let __ink_e2e_test_result: #ret_type = #block;
+                    __ink_e2e_test_result
+                }}
+            }
+        };
+        quote! {
+            #( #attrs )*
+            #[test]
+            #vis fn #fn_name() #fn_return_type {
+                ::ink_env::test::block_on(async move {
+                    #connect
+                    #block
+                })
+            }
+        }
+    }
+}
+
+impl GenerateCode for ir::InkE2ETest {
+    fn generate_code(&self) -> TokenStream2 {
+        InkE2ETest::from(self).generate_code()
+    }
+}
@@ -32,20 +32,42 @@ impl GenerateCode for InkTest<'_> {
         let sig = &item_fn.sig;
         let fn_name = &sig.ident;
         let fn_return_type = &sig.output;
-        let fn_block = &item_fn.block;
         let vis = &item_fn.vis;
         let fn_args = &sig.inputs;
+        let env = self.test.env();
         let expect_msg = format!(
             "{}: the off-chain testing environment returned an error",
             stringify!(#fn_name)
         );
+        // Bodies of `async fn` tests are driven to completion by a minimal executor.
+        let fn_block = match self.test.is_async() {
+            true => {
+                let block = &item_fn.block;
+                let block = match fn_return_type {
+                    syn::ReturnType::Default => quote! { #block },
+                    syn::ReturnType::Type(_, ret_type) => {
+                        quote! {{
+                            let __ink_test_result: #ret_type = #block;
+                            __ink_test_result
+                        }}
+                    }
+                };
+                quote! {{
+                    ::ink_env::test::block_on(async move #block)
+                }}
+            }
+            false => {
+                let block = &item_fn.block;
+                quote! { #block }
+            }
+        };
         match fn_return_type {
             syn::ReturnType::Default => {
                 quote! {
                     #( #attrs )*
                     #[test]
                     #vis fn #fn_name( #fn_args ) {
-                        ::ink_env::test::run_test::<::ink_env::DefaultEnvironment, _>(|_| {
+                        ::ink_env::test::run_test::<#env, _>(|_| {
                             {
                                 let _: () = {
                                     #fn_block
@@ -62,7 +84,7 @@ impl GenerateCode for InkTest<'_> {
                     #( #attrs )*
                     #[test]
                     #vis fn #fn_name( #fn_args ) #rarrow #ret_type {
-                        ::ink_env::test::run_test::<::ink_env::DefaultEnvironment, _>(|_| {
+                        ::ink_env::test::run_test::<#env, _>(|_| {
                             #fn_block
                         })
                     }
@@ -14,16 +14,17 @@
 
 use core::iter;
 
-use crate::GenerateCode;
+use crate::{
+    generator,
+    GenerateCode,
+};
 use derive_more::From;
-use heck::ToLowerCamelCase as _;
 use ir::{
     Callable as _,
     HexLiteral,
 };
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{
-    format_ident,
     quote,
     quote_spanned,
     ToTokens,
@@ -46,11 +47,12 @@ impl GenerateCode for ItemImpls<'_> {
             .map(|item_impl| self.generate_item_impl(item_impl));
         let inout_guards = self.generate_input_output_guards();
         let trait_message_property_guards = self.generate_trait_message_property_guards();
-        let use_emit_event =
-            self.contract.module().events().next().is_some().then(|| {
-                // Required to make `self.env().emit_event(...)` syntax available.
-                quote! { use ::ink_lang::codegen::EmitEvent as _; }
-            });
+        let use_emit_event = (self.contract.module().events().next().is_some()
+            || self.contract.module().shared_events().next().is_some())
+        .then(|| {
+            // Required to make `self.env().emit_event(...)` syntax available.
+            quote! { use ::ink_lang::codegen::EmitEvent as _; }
+        });
         quote! {
             const _: () = {
                 // Required to make `self.env()` and `Self::env()` syntax available.
@@ -74,6 +76,11 @@ impl ItemImpls<'_> {
     /// the given argument to `payable` or `selector` is equal to
     /// what the associated ink! trait definition defines for the same
     /// ink! message.
+    ///
+    /// Likewise if an ink! trait implementation block is annotated with
+    /// `#[ink(namespace = N)]` then code is generated to guard that the
+    /// namespace is equal to the namespace of the associated ink! trait
+    /// definition. Upon a mismatch the compile error names both namespaces.
     fn generate_trait_message_property_guards(&self) -> TokenStream2 {
         let storage_span = self.contract.module().storage().span();
         let storage_ident = self.contract.module().storage().ident();
@@ -114,8 +121,38 @@ impl ItemImpls<'_> {
                     #message_guard_selector
                 )
             });
+        let trait_namespace_guards = self
+            .contract
+            .module()
+            .impls()
+            .filter_map(|item_impl| {
+                let trait_path = item_impl.trait_path()?;
+                let namespace = item_impl.namespace()?;
+                let span = item_impl.span();
+                let namespace = syn::LitStr::new(
+                    &String::from_utf8_lossy(namespace.as_bytes()),
+                    span,
+                );
+                Some(quote_spanned!(span=>
+                    const _: () = {
+                        #[allow(non_camel_case_types)]
+                        type __ink_TraitInfo = <::ink_lang::reflect::TraitDefinitionRegistry<<#storage_ident as ::ink_lang::reflect::ContractEnv>::Env>
+                            as #trait_path>::__ink_TraitInfo;
+                        const GUARD: ::ink_lang::codegen::TraitNamespaceGuard =
+                            ::ink_lang::codegen::TraitNamespaceGuard::new(
+                                <__ink_TraitInfo as ::ink_lang::reflect::TraitInfo>::NAME,
+                                #namespace,
+                                <__ink_TraitInfo as ::ink_lang::reflect::TraitInfo>::NAMESPACE,
+                            );
+                        if let ::core::option::Option::Some(error) = GUARD.error() {
+                            ::core::panic!("{}", error)
+                        }
+                    };
+                ))
+            });
         quote_spanned!(storage_span=>
             #( #trait_message_guards )*
+            #( #trait_namespace_guards )*
         )
     }
 
@@ -138,8 +175,17 @@ impl ItemImpls<'_> {
                         >();
                     )
                 });
+                let constructor_error = constructor.error_type().map(|error_type| {
+                    let span = error_type.span();
+                    quote_spanned!(span=>
+                        let _: () = ::ink_lang::codegen::utils::consume_type::<
+                            ::ink_lang::codegen::DispatchOutput<#error_type>
+                        >();
+                    )
+                });
                 quote_spanned!(constructor_span=>
                     #( #constructor_inputs )*
+                    #constructor_error
                 )
             });
         let message_inout_guards = self
@@ -180,40 +226,73 @@ impl ItemImpls<'_> {
     }
 
     /// Generates the code for the given ink! message within a trait implementation block.
-    fn generate_trait_message(message: &ir::Message) -> TokenStream2 {
+    ///
+    /// Messages declared without a body inherit the default implementation of the
+    /// ink! trait definition.
+    fn generate_trait_message(
+        trait_path: &syn::Path,
+        message: &ir::Message,
+    ) -> TokenStream2 {
         let span = message.span();
         let attrs = message.attrs();
         let vis = message.visibility();
         let receiver = message.receiver();
         let ident = message.ident();
-        let output_ident =
-            format_ident!("{}Output", ident.to_string().to_lower_camel_case());
-        let inputs = message.inputs();
+        let output_ident = generator::output_ident(ident);
         let output = message
             .output()
             .cloned()
             .unwrap_or_else(|| syn::parse_quote! { () });
+        let guard = Self::generate_message_guard(message);
+        if message.inherits_default_impl() {
+            let default_impl_ident = generator::default_impl_ident(ident);
+            let input_bindings = generator::input_bindings(message.inputs());
+            let input_types = generator::input_types(message.inputs());
+            return quote_spanned!(span =>
+                type #output_ident = #output;
+
+                #( #attrs )*
+                #vis fn #ident(#receiver #( , #input_bindings : #input_types )* ) -> Self::#output_ident {
+                    #guard
+                    <Self as #trait_path>::#default_impl_ident(self #( , #input_bindings )* )
+                }
+            )
+        }
+        let inputs = message.inputs();
         let statements = message.statements();
         quote_spanned!(span =>
             type #output_ident = #output;
 
             #( #attrs )*
             #vis fn #ident(#receiver #( , #inputs )* ) -> Self::#output_ident {
+                #guard
                 #( #statements )*
             }
         )
     }
 
+    /// Generates the invocation of the guard of the given ink! message, if any.
+    ///
+    /// The error of the guard is returned from the message via the `?` operator.
+    fn generate_message_guard(message: &ir::Message) -> Option<TokenStream2> {
+        message.guard().map(|guard| {
+            let span = guard.span();
+            quote_spanned!(span =>
+                self.#guard()?;
+            )
+        })
+    }
+
     fn generate_trait_item_impl(item_impl: &ir::ItemImpl) -> TokenStream2 {
         assert!(item_impl.trait_path().is_some());
         let span = item_impl.span();
         let attrs = item_impl.attrs();
-        let messages = item_impl
-            .iter_messages()
-            .map(|cws| Self::generate_trait_message(cws.callable()));
         let trait_path = item_impl
             .trait_path()
             .expect("encountered missing trait path for trait impl block");
+        let messages = item_impl
+            .iter_messages()
+            .map(|cws| Self::generate_trait_message(trait_path, cws.callable()));
         let self_type = item_impl.self_type();
         quote_spanned!(span =>
             #( #attrs )*
@@ -238,11 +317,12 @@ impl ItemImpls<'_> {
         let vis = constructor.visibility();
         let ident = constructor.ident();
         let inputs = constructor.inputs();
+        let output = constructor.output();
         let statements = constructor.statements();
         quote_spanned!(span =>
             #( #attrs )*
             #[cfg(not(feature = "__ink_dylint_Constructor"))]
-            #vis fn #ident( #( #inputs ),* ) -> Self {
+            #vis fn #ident( #( #inputs ),* ) -> #output {
                 #( #statements )*
             }
         )
@@ -258,10 +338,16 @@ impl ItemImpls<'_> {
         let inputs = message.inputs();
         let output_arrow = message.output().map(|_| quote! { -> });
         let output = message.output();
+        let guard = Self::generate_message_guard(message);
         let statements = message.statements();
+        let deprecated = message
+            .deprecated()
+            .map(|note| quote_spanned!(span=> #[deprecated(note = #note)]));
         quote_spanned!(span =>
             #( #attrs )*
+            #deprecated
             #vis fn #ident(#receiver #( , #inputs )* ) #output_arrow #output {
+                #guard
                 #( #statements )*
             }
         )
@@ -277,6 +363,7 @@ impl ItemImpls<'_> {
         let constructors = item_impl
             .iter_constructors()
             .map(|cws| Self::generate_inherent_constructor(cws.callable()));
+        let hooks = item_impl.iter_hooks().map(ir::Hook::item);
         let other_items = item_impl
             .items()
             .iter()
@@ -288,6 +375,7 @@ impl ItemImpls<'_> {
             impl #self_type {
                 #( #constructors )*
                 #( #messages )*
+                #( #hooks )*
                 #( #other_items )*
             }
         )
@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::GenerateCode;
+use crate::{
+    generator,
+    GenerateCode,
+};
 use ::core::iter;
 use derive_more::From;
 use ir::{
@@ -39,6 +42,19 @@ impl GenerateCode for Metadata<'_> {
     fn generate_code(&self) -> TokenStream2 {
         let contract = self.generate_contract();
         let layout = self.generate_layout();
+        // The compiled Wasm code hash and the compiler version are only known to
+        // the build tool which adds them after the contract has been compiled.
+        let language = format!("ink! {}", env!("CARGO_PKG_VERSION"));
+        // The user defined metadata has already been validated to be a JSON object
+        // while parsing the ink! configuration.
+        let user = self.contract.config().metadata().map(|json| {
+            quote! {
+                .with_user(
+                    ::ink_metadata::UserMetadata::from_json(#json)
+                        .expect("encountered invalid user defined ink! metadata")
+                )
+            }
+        });
 
         quote! {
             #[cfg(feature = "std")]
@@ -48,6 +64,8 @@ impl GenerateCode for Metadata<'_> {
                 pub fn __ink_generate_metadata() -> ::ink_metadata::MetadataVersioned  {
                     <::ink_metadata::InkProject as ::core::convert::Into<::ink_metadata::MetadataVersioned>>::into(
                         ::ink_metadata::InkProject::new(#layout, #contract)
+                            .with_source(::ink_metadata::Source::new(#language))
+                            #user
                     )
                 }
             };
@@ -60,24 +78,45 @@ impl Metadata<'_> {
         let storage_span = self.contract.module().storage().span();
         let storage_ident = self.contract.module().storage().ident();
         quote_spanned!(storage_span=>
-            <#storage_ident as ::ink_storage::traits::StorageLayout>::layout(
-                &mut <::ink_primitives::KeyPtr as ::core::convert::From<::ink_primitives::Key>>::from(
-                    <::ink_primitives::Key as ::core::convert::From<[::core::primitive::u8; 32usize]>>::from([0x00_u8; 32usize])
+            ::ink_metadata::layout::Layout::Root(::ink_metadata::layout::RootLayout::new(
+                <#storage_ident as ::ink_lang::codegen::ContractRootKey>::ROOT_KEY,
+                <#storage_ident as ::ink_storage::traits::StorageLayout>::layout(
+                    &mut <::ink_primitives::KeyPtr as ::core::convert::From<::ink_primitives::Key>>::from(
+                        <#storage_ident as ::ink_lang::codegen::ContractRootKey>::ROOT_KEY
+                    )
                 )
-            )
+            ))
         )
     }
 
     fn generate_contract(&self) -> TokenStream2 {
+        let storage_ident = self.contract.module().storage().ident();
         let constructors = self.generate_constructors();
         let messages = self.generate_messages();
         let events = self.generate_events();
+        let environment = self.generate_environment();
         let docs = self
             .contract
             .module()
             .attrs()
             .iter()
             .filter_map(|attr| attr.extract_docs());
+        let manifest_fields = [
+            ("license", "CARGO_PKG_LICENSE"),
+            ("repository", "CARGO_PKG_REPOSITORY"),
+            ("homepage", "CARGO_PKG_HOMEPAGE"),
+        ]
+        .iter()
+        .map(|(field, env_var)| {
+            let field = quote::format_ident!("{}", field);
+            // Cargo sets the environment variables of unspecified fields to empty strings.
+            quote! {
+                .#field({
+                    let value: &'static ::core::primitive::str = ::core::env!(#env_var);
+                    (!value.is_empty()).then(|| value)
+                })
+            }
+        });
         quote! {
             ::ink_metadata::ContractSpec::new()
                 .constructors([
@@ -92,10 +131,71 @@ impl Metadata<'_> {
                 .docs([
                     #( #docs ),*
                 ])
+                .version(<#storage_ident as ::ink_lang::reflect::ContractVersion>::VERSION)
+                .authors(
+                    ::core::env!("CARGO_PKG_AUTHORS")
+                        .split(':')
+                        .filter(|author| !author.is_empty())
+                )
+                #( #manifest_fields )*
+                .lang_error(
+                    ::ink_metadata::TypeSpec::with_name_segs::<::ink_lang::LangError, _>(
+                        ::core::iter::IntoIterator::into_iter([
+                            ::core::stringify!(ink_lang),
+                            ::core::stringify!(LangError),
+                        ])
+                        .map(::core::convert::AsRef::as_ref)
+                    )
+                )
+                .chain_extensions({
+                    #[allow(unused_imports)]
+                    use ::ink_metadata::ChainExtensionSpecsFallback as _;
+                    ::ink_metadata::ChainExtensionSpecs::<
+                        <<#storage_ident as ::ink_lang::reflect::ContractEnv>::Env
+                            as ::ink_env::Environment>::ChainExtension
+                    >::specs()
+                })
+                .environment(#environment)
                 .done()
         }
     }
 
+    /// Generates ink! metadata for the environment types of the ink! smart contract.
+    fn generate_environment(&self) -> TokenStream2 {
+        let storage_ident = self.contract.module().storage().ident();
+        let env = quote! {
+            <#storage_ident as ::ink_lang::reflect::ContractEnv>::Env
+        };
+        let types = [
+            ("account_id", "AccountId"),
+            ("balance", "Balance"),
+            ("hash", "Hash"),
+            ("timestamp", "Timestamp"),
+            ("block_number", "BlockNumber"),
+        ]
+        .iter()
+        .map(|(field, ty)| {
+            let field = quote::format_ident!("{}", field);
+            let ty = quote::format_ident!("{}", ty);
+            quote! {
+                .#field(::ink_metadata::TypeSpec::with_name_segs::<
+                    <#env as ::ink_env::Environment>::#ty,
+                    _,
+                >(
+                    ::core::iter::IntoIterator::into_iter([::core::stringify!(#ty)])
+                        .map(::core::convert::AsRef::as_ref)
+                ))
+            }
+        });
+        quote! {
+            ::ink_metadata::EnvironmentSpec::new(
+                <#env as ::ink_env::Environment>::MAX_EVENT_TOPICS
+            )
+            #( #types )*
+            .done()
+        }
+    }
+
     /// Generates ink! metadata for all ink! smart contract constructors.
     #[allow(clippy::redundant_closure)] // We are getting arcane lifetime errors otherwise.
     fn generate_constructors(&self) -> impl Iterator<Item = TokenStream2> + '_ {
@@ -117,6 +217,7 @@ impl Metadata<'_> {
             .filter_map(|attr| attr.extract_docs());
         let selector_bytes = constructor.composed_selector().hex_lits();
         let is_payable = constructor.is_payable();
+        let is_default = constructor.is_default();
         let constructor = constructor.callable();
         let ident = constructor.ident();
         let args = constructor.inputs().map(Self::generate_dispatch_argument);
@@ -129,6 +230,7 @@ impl Metadata<'_> {
                     #( #args ),*
                 ])
                 .payable(#is_payable)
+                .default(#is_default)
                 .docs([
                     #( #docs ),*
                 ])
@@ -151,7 +253,7 @@ impl Metadata<'_> {
     }
 
     /// Generates the ink! metadata for the given type.
-    fn generate_type_spec(ty: &syn::Type) -> TokenStream2 {
+    pub(crate) fn generate_type_spec(ty: &syn::Type) -> TokenStream2 {
         fn without_display_name(ty: &syn::Type) -> TokenStream2 {
             quote! { ::ink_metadata::TypeSpec::new::<#ty>() }
         }
@@ -204,11 +306,15 @@ impl Metadata<'_> {
                     .filter_map(|attr| attr.extract_docs());
                 let selector_bytes = message.composed_selector().hex_lits();
                 let is_payable = message.is_payable();
+                let is_default = message.is_default();
                 let message = message.callable();
                 let mutates = message.receiver().is_ref_mut();
                 let ident = message.ident();
                 let args = message.inputs().map(Self::generate_dispatch_argument);
                 let ret_ty = Self::generate_return_type(message.output());
+                let deprecated = message
+                    .deprecated()
+                    .map(|note| quote_spanned!(span=> .deprecated(#note)));
                 quote_spanned!(span =>
                     ::ink_metadata::MessageSpec::from_label(::core::stringify!(#ident))
                         .selector([
@@ -220,9 +326,11 @@ impl Metadata<'_> {
                         .returns(#ret_ty)
                         .mutates(#mutates)
                         .payable(#is_payable)
+                        .default(#is_default)
                         .docs([
                             #( #docs ),*
                         ])
+                        #deprecated
                         .done()
                 )
             })
@@ -257,6 +365,7 @@ impl Metadata<'_> {
                     .inputs()
                     .map(Self::generate_dispatch_argument);
                 let mutates = message.receiver().is_ref_mut();
+                let is_default = message.is_default();
                 let local_id = message.local_id().hex_padded_suffixed();
                 let is_payable = quote! {{
                     <<::ink_lang::reflect::TraitDefinitionRegistry<<#storage_ident as ::ink_lang::reflect::ContractEnv>::Env>
@@ -270,6 +379,9 @@ impl Metadata<'_> {
                 }};
                 let ret_ty = Self::generate_return_type(message.output());
                 let label = [trait_ident.to_string(), message_ident.to_string()].join("::");
+                let deprecated = message
+                    .deprecated()
+                    .map(|note| quote_spanned!(message_span=> .deprecated(#note)));
                 quote_spanned!(message_span=>
                     ::ink_metadata::MessageSpec::from_label(#label)
                         .selector(#selector)
@@ -279,50 +391,83 @@ impl Metadata<'_> {
                         .returns(#ret_ty)
                         .mutates(#mutates)
                         .payable(#is_payable)
+                        .default(#is_default)
                         .docs([
                             #( #message_docs ),*
                         ])
+                        #deprecated
                         .done()
                 )
             })
             .collect()
     }
 
-    /// Generates ink! metadata for the given return type.
-    fn generate_return_type(ret_ty: Option<&syn::Type>) -> TokenStream2 {
-        match ret_ty {
-            None => {
-                quote! {
-                    ::ink_metadata::ReturnTypeSpec::new(::core::option::Option::None)
-                }
-            }
-            Some(ty) => {
-                let type_spec = Self::generate_type_spec(ty);
-                quote! {
-                    ::ink_metadata::ReturnTypeSpec::new(#type_spec)
-                }
-            }
+    /// Generates ink! metadata for the given return type of an ink! message.
+    ///
+    /// # Note
+    ///
+    /// ink! messages return their output wrapped in a `MessageResult` envelope
+    /// even if they do not have a return type, so that callers can tell apart
+    /// errors of the ink! language from the output of the ink! message.
+    pub(crate) fn generate_return_type(ret_ty: Option<&syn::Type>) -> TokenStream2 {
+        let ty = match ret_ty {
+            None => quote! { () },
+            Some(ty) => quote! { #ty },
+        };
+        quote! {
+            ::ink_metadata::ReturnTypeSpec::new(
+                ::ink_metadata::TypeSpec::with_name_segs::<::ink_lang::MessageResult<#ty>, _>(
+                    ::core::iter::IntoIterator::into_iter([
+                        ::core::stringify!(ink_lang),
+                        ::core::stringify!(MessageResult),
+                    ])
+                    .map(::core::convert::AsRef::as_ref)
+                )
+            )
         }
     }
 
     /// Generates ink! metadata for all user provided ink! event definitions.
-    fn generate_events(&self) -> impl Iterator<Item = TokenStream2> + '_ {
-        self.contract.module().events().map(|event| {
-            let span = event.span();
-            let ident = event.ident();
-            let docs = event.attrs().iter().filter_map(|attr| attr.extract_docs());
-            let args = Self::generate_event_args(event);
+    fn generate_events(&self) -> Vec<TokenStream2> {
+        let contract_ident = self.contract.module().storage().ident();
+        let events = self.contract.module().events().map(|event| {
+            let signature = format!("{}::{}", contract_ident, event.ident());
+            Self::generate_event_spec(event, &signature)
+        });
+        let shared_events = self.contract.module().shared_events().map(|shared_event| {
+            let span = shared_event.span();
+            let ident = shared_event.ident();
             quote_spanned!(span =>
-                ::ink_metadata::EventSpec::new(::core::stringify!(#ident))
-                    .args([
-                        #( #args ),*
-                    ])
-                    .docs([
-                        #( #docs ),*
-                    ])
-                    .done()
+                <#ident as ::ink_metadata::EventMetadata>::event_spec()
             )
-        })
+        });
+        events.chain(shared_events).collect()
+    }
+
+    /// Generates ink! metadata for a single ink! event definition.
+    ///
+    /// The `signature` must be the same as the one used for the topics of the event.
+    pub(crate) fn generate_event_spec(
+        event: &ir::Event,
+        signature: &str,
+    ) -> TokenStream2 {
+        let span = event.span();
+        let ident = event.ident();
+        let docs = event.attrs().iter().filter_map(|attr| attr.extract_docs());
+        let args = Self::generate_event_args(event);
+        let signature_topic = generator::Events::signature_topic(event, signature)
+            .map(|topic| quote_spanned!(span => .signature_topic([ #( #topic ),* ])));
+        quote_spanned!(span =>
+            ::ink_metadata::EventSpec::new(::core::stringify!(#ident))
+                .args([
+                    #( #args ),*
+                ])
+                .docs([
+                    #( #docs ),*
+                ])
+                #signature_topic
+                .done()
+        )
     }
 
     /// Generate ink! metadata for a single argument of an ink! event definition.
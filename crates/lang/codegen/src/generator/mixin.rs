@@ -0,0 +1,271 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::GenerateCode;
+use derive_more::From;
+use ir::MixinKind;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::ToTokens as _;
+
+/// Generates code for an ink! smart contract annotated with a mixin.
+///
+/// The generated code is the ink! smart contract module extended with the
+/// ink! messages, ink! events and guards of the mixin which is then expanded
+/// by `#[ink::contract]`.
+#[derive(From)]
+pub struct ContractMixin<'a> {
+    mixin: &'a ir::ContractMixin,
+}
+
+impl GenerateCode for ContractMixin<'_> {
+    fn generate_code(&self) -> TokenStream2 {
+        let (constructor_prelude, items) = match self.mixin.kind() {
+            MixinKind::Ownable => self.generate_ownable(),
+            MixinKind::AccessControl => self.generate_access_control(),
+        };
+        self.mixin
+            .expand(constructor_prelude, items)
+            .into_token_stream()
+    }
+}
+
+impl ContractMixin<'_> {
+    /// Generates the constructor prelude and the items of `#[ink::ownable]`.
+    ///
+    /// The instantiator of the ink! smart contract becomes its owner.
+    fn generate_ownable(&self) -> (Vec<syn::Stmt>, Vec<syn::Item>) {
+        let storage_ident = self.mixin.storage_ident();
+        let constructor_prelude = vec![
+            syn::parse_quote! {
+                ::ink_lang::access::set_owner::<Environment>(
+                    ::core::option::Option::Some(&Self::env().caller())
+                );
+            },
+            syn::parse_quote! {
+                Self::env().emit_event(OwnershipTransferred {
+                    previous_owner: ::core::option::Option::None,
+                    new_owner: ::core::option::Option::Some(Self::env().caller()),
+                });
+            },
+        ];
+        let items = vec![
+            syn::parse_quote! {
+                /// Emitted when the ownership of the contract is transferred.
+                #[ink(event)]
+                pub struct OwnershipTransferred {
+                    /// The previous owner of the contract.
+                    #[ink(topic)]
+                    pub previous_owner: ::core::option::Option<AccountId>,
+                    /// The new owner of the contract.
+                    ///
+                    /// `None` if the ownership has been renounced.
+                    #[ink(topic)]
+                    pub new_owner: ::core::option::Option<AccountId>,
+                }
+            },
+            syn::parse_quote! {
+                impl #storage_ident {
+                    /// Returns the owner of the contract.
+                    ///
+                    /// `None` if the ownership has been renounced.
+                    #[ink(message)]
+                    pub fn owner(&self) -> ::core::option::Option<AccountId> {
+                        ::ink_lang::access::owner::<Environment>()
+                    }
+
+                    /// Transfers the ownership of the contract to `new_owner`.
+                    ///
+                    /// Passing `None` renounces the ownership for good.
+                    #[ink(message)]
+                    pub fn transfer_ownership(
+                        &mut self,
+                        new_owner: ::core::option::Option<AccountId>,
+                    ) -> ::core::result::Result<(), ::ink_lang::access::OwnableError> {
+                        self.only_owner()?;
+                        let previous_owner = ::ink_lang::access::owner::<Environment>();
+                        ::ink_lang::access::set_owner::<Environment>(new_owner.as_ref());
+                        self.env().emit_event(OwnershipTransferred {
+                            previous_owner,
+                            new_owner,
+                        });
+                        ::core::result::Result::Ok(())
+                    }
+
+                    /// Returns an error if the caller is not the owner of the contract.
+                    ///
+                    /// Can be used as guard via `#[ink(message, guard = "only_owner")]`.
+                    pub fn only_owner(
+                        &self,
+                    ) -> ::core::result::Result<(), ::ink_lang::access::OwnableError> {
+                        let caller = self.env().caller();
+                        if ::ink_lang::access::owner::<Environment>() != ::core::option::Option::Some(caller) {
+                            return ::core::result::Result::Err(
+                                ::ink_lang::access::OwnableError::CallerIsNotOwner
+                            )
+                        }
+                        ::core::result::Result::Ok(())
+                    }
+                }
+            },
+        ];
+        (constructor_prelude, items)
+    }
+
+    /// Generates the constructor prelude and the items of `#[ink::access_control]`.
+    ///
+    /// The instantiator of the ink! smart contract is granted the admin role.
+    fn generate_access_control(&self) -> (Vec<syn::Stmt>, Vec<syn::Item>) {
+        let storage_ident = self.mixin.storage_ident();
+        let constructor_prelude = vec![
+            syn::parse_quote! {
+                ::ink_lang::access::set_role::<Environment>(
+                    ::ink_lang::access::ADMIN_ROLE,
+                    &Self::env().caller(),
+                    true,
+                );
+            },
+            syn::parse_quote! {
+                Self::env().emit_event(RoleGranted {
+                    role: ::ink_lang::access::ADMIN_ROLE,
+                    account: Self::env().caller(),
+                    sender: Self::env().caller(),
+                });
+            },
+        ];
+        let items = vec![
+            syn::parse_quote! {
+                /// Emitted when a role is granted to an account.
+                #[ink(event)]
+                pub struct RoleGranted {
+                    /// The granted role.
+                    #[ink(topic)]
+                    pub role: ::ink_lang::access::RoleType,
+                    /// The account that has been granted the role.
+                    #[ink(topic)]
+                    pub account: AccountId,
+                    /// The account that granted the role.
+                    pub sender: AccountId,
+                }
+            },
+            syn::parse_quote! {
+                /// Emitted when a role is revoked from an account.
+                #[ink(event)]
+                pub struct RoleRevoked {
+                    /// The revoked role.
+                    #[ink(topic)]
+                    pub role: ::ink_lang::access::RoleType,
+                    /// The account that has been revoked the role.
+                    #[ink(topic)]
+                    pub account: AccountId,
+                    /// The account that revoked the role.
+                    pub sender: AccountId,
+                }
+            },
+            syn::parse_quote! {
+                impl #storage_ident {
+                    /// Returns `true` if `account` has been granted `role`.
+                    #[ink(message)]
+                    pub fn has_role(
+                        &self,
+                        role: ::ink_lang::access::RoleType,
+                        account: AccountId,
+                    ) -> bool {
+                        ::ink_lang::access::has_role::<Environment>(role, &account)
+                    }
+
+                    /// Grants `role` to `account`.
+                    ///
+                    /// The caller must have the admin role.
+                    #[ink(message)]
+                    pub fn grant_role(
+                        &mut self,
+                        role: ::ink_lang::access::RoleType,
+                        account: AccountId,
+                    ) -> ::core::result::Result<(), ::ink_lang::access::AccessControlError> {
+                        self.only_admin()?;
+                        if !::ink_lang::access::has_role::<Environment>(role, &account) {
+                            ::ink_lang::access::set_role::<Environment>(role, &account, true);
+                            self.env().emit_event(RoleGranted {
+                                role,
+                                account,
+                                sender: self.env().caller(),
+                            });
+                        }
+                        ::core::result::Result::Ok(())
+                    }
+
+                    /// Revokes `role` from `account`.
+                    ///
+                    /// The caller must have the admin role.
+                    #[ink(message)]
+                    pub fn revoke_role(
+                        &mut self,
+                        role: ::ink_lang::access::RoleType,
+                        account: AccountId,
+                    ) -> ::core::result::Result<(), ::ink_lang::access::AccessControlError> {
+                        self.only_admin()?;
+                        self.remove_role(role, account);
+                        ::core::result::Result::Ok(())
+                    }
+
+                    /// Revokes `role` from the caller.
+                    #[ink(message)]
+                    pub fn renounce_role(
+                        &mut self,
+                        role: ::ink_lang::access::RoleType,
+                    ) -> ::core::result::Result<(), ::ink_lang::access::AccessControlError> {
+                        self.ensure_role(role)?;
+                        self.remove_role(role, self.env().caller());
+                        ::core::result::Result::Ok(())
+                    }
+
+                    /// Returns an error if the caller has not been granted `role`.
+                    pub fn ensure_role(
+                        &self,
+                        role: ::ink_lang::access::RoleType,
+                    ) -> ::core::result::Result<(), ::ink_lang::access::AccessControlError> {
+                        if !::ink_lang::access::has_role::<Environment>(role, &self.env().caller()) {
+                            return ::core::result::Result::Err(
+                                ::ink_lang::access::AccessControlError::MissingRole
+                            )
+                        }
+                        ::core::result::Result::Ok(())
+                    }
+
+                    /// Returns an error if the caller has not been granted the admin role.
+                    ///
+                    /// Can be used as guard via `#[ink(message, guard = "only_admin")]`.
+                    pub fn only_admin(
+                        &self,
+                    ) -> ::core::result::Result<(), ::ink_lang::access::AccessControlError> {
+                        self.ensure_role(::ink_lang::access::ADMIN_ROLE)
+                    }
+
+                    /// Revokes `role` from `account` if granted.
+                    fn remove_role(&mut self, role: ::ink_lang::access::RoleType, account: AccountId) {
+                        if ::ink_lang::access::has_role::<Environment>(role, &account) {
+                            ::ink_lang::access::set_role::<Environment>(role, &account, false);
+                            self.env().emit_event(RoleRevoked {
+                                role,
+                                account,
+                                sender: self.env().caller(),
+                            });
+                        }
+                    }
+                }
+            },
+        ];
+        (constructor_prelude, items)
+    }
+}
@@ -31,18 +31,29 @@ mod as_dependency;
 mod blake2b;
 mod chain_extension;
 mod contract;
+mod contract_error;
 mod dispatch;
 mod env;
+mod environment;
+mod event_def;
 mod events;
+mod export_impl;
+mod include_impl;
+mod ink_e2e_test;
 mod ink_test;
 mod item_impls;
+mod keccak;
 mod metadata;
+mod mixin;
+mod proxy;
+mod scale_derive;
 mod selector;
 mod storage;
 mod trait_def;
 
 pub use self::{
     arg_list::{
+        default_impl_ident,
         generate_argument_list,
         generate_reference_to_trait_info,
         input_bindings,
@@ -53,14 +64,27 @@ pub use self::{
     },
     as_dependency::ContractReference,
     blake2b::Blake2x256,
-    chain_extension::ChainExtension,
+    chain_extension::{
+        ChainExtension,
+        ChainExtensionErrorCode,
+    },
     contract::Contract,
+    contract_error::ContractError,
     dispatch::Dispatch,
     env::Env,
+    environment::Environment,
+    event_def::EventDefinition,
     events::Events,
+    export_impl::ExportImpl,
+    include_impl::IncludeImpl,
+    ink_e2e_test::InkE2ETest,
     ink_test::InkTest,
     item_impls::ItemImpls,
+    keccak::Keccak256,
     metadata::Metadata,
+    mixin::ContractMixin,
+    proxy::Proxy,
+    scale_derive::ScaleDerive,
     selector::{
         SelectorBytes,
         SelectorId,
@@ -0,0 +1,122 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::GenerateCode;
+use derive_more::From;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+/// Generates code for an upgradeable ink! proxy contract.
+///
+/// The generated code is an ink! smart contract module which is then expanded
+/// by `#[ink::contract]`.
+#[derive(From)]
+pub struct Proxy<'a> {
+    proxy: &'a ir::InkProxy,
+}
+
+impl GenerateCode for Proxy<'_> {
+    fn generate_code(&self) -> TokenStream2 {
+        let module = self.proxy.module();
+        let attrs = &module.attrs;
+        let vis = &module.vis;
+        let ident = &module.ident;
+        let env = self.proxy.config().env();
+        let panic = match self.proxy.config().panic_strategy() {
+            ir::PanicStrategy::Format => "format",
+            ir::PanicStrategy::AbortSilent => "abort-silent",
+        };
+        quote! {
+            #( #attrs )*
+            #[::ink_lang::contract(env = #env, panic = #panic)]
+            #vis mod #ident {
+                /// Emitted when the implementation of the proxy is upgraded.
+                #[ink(event)]
+                pub struct Upgraded {
+                    /// The code hash of the new implementation.
+                    #[ink(topic)]
+                    pub code_hash: Hash,
+                }
+
+                /// The upgradeable proxy contract.
+                ///
+                /// The implementation code hash and the admin are kept in dedicated
+                /// storage cells that do not collide with the storage of the implementation.
+                #[ink(storage)]
+                pub struct Proxy {}
+
+                impl Proxy {
+                    /// Instantiates the proxy with the code hash of its implementation.
+                    ///
+                    /// The caller becomes the admin of the proxy.
+                    #[ink(constructor)]
+                    pub fn new(code_hash: Hash) -> Self {
+                        ::ink_lang::proxy::set_implementation::<Environment>(&code_hash);
+                        ::ink_lang::proxy::set_admin::<Environment>(&Self::env().caller());
+                        Self {}
+                    }
+
+                    /// Returns the code hash of the implementation of the proxy.
+                    #[ink(message)]
+                    pub fn implementation(&self) -> Hash {
+                        ::ink_lang::proxy::implementation::<Environment>()
+                    }
+
+                    /// Returns the admin of the proxy.
+                    #[ink(message)]
+                    pub fn admin(&self) -> AccountId {
+                        ::ink_lang::proxy::admin::<Environment>()
+                    }
+
+                    /// Upgrades the implementation of the proxy to the given code hash.
+                    ///
+                    /// May only be called by the admin of the proxy.
+                    #[ink(message)]
+                    pub fn upgrade_to(
+                        &mut self,
+                        code_hash: Hash,
+                    ) -> ::core::result::Result<(), ::ink_lang::proxy::ProxyError> {
+                        ::ink_lang::proxy::ensure_admin::<Environment>()?;
+                        ::ink_lang::proxy::set_implementation::<Environment>(&code_hash);
+                        self.env().emit_event(Upgraded { code_hash });
+                        ::core::result::Result::Ok(())
+                    }
+
+                    /// Transfers the admin rights of the proxy to `new_admin`.
+                    ///
+                    /// May only be called by the admin of the proxy.
+                    #[ink(message)]
+                    pub fn change_admin(
+                        &mut self,
+                        new_admin: AccountId,
+                    ) -> ::core::result::Result<(), ::ink_lang::proxy::ProxyError> {
+                        ::ink_lang::proxy::ensure_admin::<Environment>()?;
+                        ::ink_lang::proxy::set_admin::<Environment>(&new_admin);
+                        ::core::result::Result::Ok(())
+                    }
+
+                    /// Delegates all calls that do not match a selector of the proxy
+                    /// to its implementation.
+                    ///
+                    /// The input is forwarded and the output of the implementation is
+                    /// returned to the caller directly via a tail call.
+                    #[ink(message, payable, selector = _)]
+                    pub fn forward(&self) {
+                        ::ink_lang::proxy::forward::<Environment>()
+                    }
+                }
+            }
+        }
+    }
+}
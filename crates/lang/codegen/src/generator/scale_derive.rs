@@ -0,0 +1,53 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::GenerateCode;
+use derive_more::From;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    quote,
+    quote_spanned,
+};
+
+/// Generates code for the `#[ink::scale_derive(..)]` macro.
+#[derive(From)]
+pub struct ScaleDerive<'a> {
+    derive: &'a ir::ScaleDerive,
+}
+
+impl GenerateCode for ScaleDerive<'_> {
+    fn generate_code(&self) -> TokenStream2 {
+        let derives = self.derive.traits().iter().map(|(kind, ident)| {
+            let span = ident.span();
+            match kind {
+                ir::ScaleDeriveTrait::Encode => {
+                    quote_spanned!(span=> #[derive(::scale::Encode)])
+                }
+                ir::ScaleDeriveTrait::Decode => {
+                    quote_spanned!(span=> #[derive(::scale::Decode)])
+                }
+                ir::ScaleDeriveTrait::TypeInfo => {
+                    quote_spanned!(span=>
+                        #[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+                    )
+                }
+            }
+        });
+        let item = self.derive.item();
+        quote! {
+            #( #derives )*
+            #item
+        }
+    }
+}
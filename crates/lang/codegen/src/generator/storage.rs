@@ -33,14 +33,17 @@ impl GenerateCode for Storage<'_> {
         let storage_span = self.contract.module().storage().span();
         let access_env_impls = self.generate_access_env_trait_impls();
         let storage_struct = self.generate_storage_struct();
-        let use_emit_event =
-            self.contract.module().events().next().is_some().then(|| {
-                // Required to allow for `self.env().emit_event(...)` in messages and constructors.
-                quote! { use ::ink_lang::codegen::EmitEvent as _; }
-            });
+        let max_root_size = self.generate_max_root_size_assertion();
+        let use_emit_event = (self.contract.module().events().next().is_some()
+            || self.contract.module().shared_events().next().is_some())
+        .then(|| {
+            // Required to allow for `self.env().emit_event(...)` in messages and constructors.
+            quote! { use ::ink_lang::codegen::EmitEvent as _; }
+        });
         quote_spanned!(storage_span =>
             #storage_struct
             #access_env_impls
+            #max_root_size
 
             const _: () = {
                 // Used to make `self.env()` and `Self::env()` available in message code.
@@ -56,6 +59,24 @@ impl GenerateCode for Storage<'_> {
 }
 
 impl Storage<'_> {
+    /// Generates a compile time assertion that the ink! storage struct does not
+    /// exceed the configured `max_root_size` in bytes.
+    fn generate_max_root_size_assertion(&self) -> TokenStream2 {
+        let max_root_size = match self.contract.config().max_root_size() {
+            Some(max_root_size) => max_root_size,
+            None => return quote! {},
+        };
+        let storage = self.contract.module().storage();
+        let span = storage.span();
+        let storage_ident = storage.ident();
+        quote_spanned!(span=>
+            const _: () = ::core::assert!(
+                ::core::mem::size_of::<#storage_ident>() <= #max_root_size,
+                "the ink! storage exceeds the `max_root_size` configured for the ink! smart contract",
+            );
+        )
+    }
+
     fn generate_access_env_trait_impls(&self) -> TokenStream2 {
         let storage_ident = &self.contract.module().storage().ident();
         quote! {
@@ -94,7 +115,21 @@ impl Storage<'_> {
         let span = storage.span();
         let ident = storage.ident();
         let attrs = storage.attrs();
-        let fields = storage.fields();
+        let definition = if storage.is_enum() {
+            let variants = storage.variants();
+            quote! {
+                pub enum #ident {
+                    #( #variants ),*
+                }
+            }
+        } else {
+            let fields = storage.fields();
+            quote! {
+                pub struct #ident {
+                    #( #fields ),*
+                }
+            }
+        };
         quote_spanned!( span =>
             #(#attrs)*
             #[cfg_attr(
@@ -104,15 +139,17 @@ impl Storage<'_> {
             #[derive(::ink_storage::traits::SpreadLayout)]
             #[cfg_attr(test, derive(::core::fmt::Debug))]
             #[cfg(not(feature = "__ink_dylint_Storage"))]
-            pub struct #ident {
-                #( #fields ),*
-            }
+            #definition
 
             const _: () = {
                 impl ::ink_lang::reflect::ContractName for #ident {
                     const NAME: &'static str = ::core::stringify!(#ident);
                 }
 
+                impl ::ink_lang::reflect::ContractVersion for #ident {
+                    const VERSION: &'static str = ::core::env!("CARGO_PKG_VERSION");
+                }
+
                 impl ::ink_lang::codegen::ContractRootKey for #ident {
                     const ROOT_KEY: ::ink_primitives::Key = ::ink_primitives::Key::new([0x00; 32]);
                 }
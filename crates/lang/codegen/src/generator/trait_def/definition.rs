@@ -15,16 +15,18 @@
 //! Generates the ink! trait definition item.
 
 use super::TraitDefinition;
-use heck::ToLowerCamelCase as _;
+use crate::generator;
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{
-    format_ident,
     quote,
     quote_spanned,
 };
 
 impl<'a> TraitDefinition<'a> {
-    fn generate_for_message(message: ir::InkTraitMessage<'a>) -> TokenStream2 {
+    fn generate_for_message(
+        message: ir::InkTraitMessage<'a>,
+        trait_with_outputs: &TokenStream2,
+    ) -> TokenStream2 {
         let span = message.span();
         let attrs = message.attrs();
         let sig = message.sig();
@@ -34,29 +36,85 @@ impl<'a> TraitDefinition<'a> {
             syn::ReturnType::Default => quote! { () },
             syn::ReturnType::Type(_, ty) => quote! { #ty },
         };
-        let output_ident =
-            format_ident!("{}Output", ident.to_string().to_lower_camel_case());
+        let output_ident = generator::output_ident(ident);
+        let default_impl = message.default_impl().map(|block| {
+            let default_impl_ident = generator::default_impl_ident(ident);
+            quote_spanned!(span =>
+                /// Default implementation of the respective trait message.
+                ///
+                /// Implementers inherit it by declaring the message without a body.
+                #[doc(hidden)]
+                fn #default_impl_ident(#inputs) -> #output
+                where
+                    Self: #trait_with_outputs,
+                    <Self as ::ink_lang::reflect::ContractEnv>::Env: 'static,
+                    for<'__ink_env> &'__ink_env Self: ::ink_lang::codegen::Env<
+                        EnvAccess = ::ink_lang::EnvAccess<
+                            '__ink_env,
+                            <Self as ::ink_lang::reflect::ContractEnv>::Env,
+                        >,
+                    >,
+                    Self: ::ink_lang::codegen::StaticEnv<
+                        EnvAccess = ::ink_lang::EnvAccess<
+                            'static,
+                            <Self as ::ink_lang::reflect::ContractEnv>::Env,
+                        >,
+                    >,
+                {
+                    // Required to make `self.env()` and `Self::env()` syntax available.
+                    #[allow(unused_imports)]
+                    use ::ink_lang::codegen::{Env as _, StaticEnv as _};
+                    #block
+                }
+            )
+        });
         quote_spanned!(span =>
             /// Output type of the respective trait message.
             type #output_ident: ::ink_lang::codegen::ImpliesReturn<#output>;
 
             #(#attrs)*
             fn #ident(#inputs) -> Self::#output_ident;
+
+            #default_impl
         )
     }
 }
 
 impl TraitDefinition<'_> {
+    /// Returns the ink! trait with the outputs of all of its messages bound to
+    /// their concrete types, e.g. `MyTrait<getOutput = bool>`.
+    ///
+    /// Default implementations require this bound in order to use the outputs
+    /// of the other messages of the ink! trait.
+    fn generate_trait_with_outputs(&self) -> TokenStream2 {
+        let item = self.trait_def.item();
+        let ident = item.ident();
+        let outputs = item
+            .iter_items()
+            .map(|(item, _)| item)
+            .flat_map(ir::InkTraitItem::filter_map_message)
+            .map(|message| {
+                let output_ident = generator::output_ident(message.ident());
+                let output = match message.output() {
+                    None => quote! { () },
+                    Some(ty) => quote! { #ty },
+                };
+                quote! { #output_ident = #output }
+            });
+        quote! { #ident< #( #outputs ),* > }
+    }
+
     pub(super) fn generate_trait_definition(&self) -> TokenStream2 {
         let item = self.trait_def.item();
         let span = item.span();
         let attrs = item.attrs();
         let ident = item.ident();
+        let trait_with_outputs = self.generate_trait_with_outputs();
         let messages = item
             .iter_items()
             .map(|(item, _)| item)
             .flat_map(ir::InkTraitItem::filter_map_message)
-            .map(Self::generate_for_message);
+            .map(|message| Self::generate_for_message(message, &trait_with_outputs));
         quote_spanned!(span =>
             #(#attrs)*
             pub trait #ident: ::ink_lang::reflect::ContractEnv {
@@ -0,0 +1,152 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::TraitDefinition;
+use crate::{
+    generator,
+    traits::GenerateCode,
+};
+use derive_more::From;
+use ir::IsDocAttribute as _;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    quote,
+    quote_spanned,
+};
+use syn::spanned::Spanned as _;
+
+impl<'a> TraitDefinition<'a> {
+    /// Generates the ink! metadata of the ink! trait definition.
+    ///
+    /// # Note
+    ///
+    /// The metadata is implemented by the trait info object for the default
+    /// environment and describes the interface of the ink! trait definition
+    /// independent of any contract implementing it. It is only generated if the
+    /// ink! trait definition opts in via its `metadata = true` configuration.
+    pub fn generate_metadata(&self) -> TokenStream2 {
+        if !self.trait_def.config().metadata() {
+            return quote! {}
+        }
+        TraitMetadata::from(*self).generate_code()
+    }
+}
+
+/// Generates code for the ink! metadata of the ink! trait definition.
+#[derive(From)]
+struct TraitMetadata<'a> {
+    trait_def: TraitDefinition<'a>,
+}
+
+impl GenerateCode for TraitMetadata<'_> {
+    fn generate_code(&self) -> TokenStream2 {
+        let span = self.trait_def.span();
+        let trait_ident = self.trait_def.trait_def.item().ident();
+        let trait_info_ident = self.trait_def.trait_info_ident();
+        let docs = self
+            .trait_def
+            .trait_def
+            .item()
+            .attrs()
+            .iter()
+            .filter_map(|attr| attr.extract_docs());
+        let messages = self.trait_def.trait_def.item().iter_items().filter_map(
+            |(item, selector)| {
+                item.filter_map_message()
+                    .map(|message| Self::generate_message(&message, selector))
+            },
+        );
+        quote_spanned!(span=>
+            #[cfg(feature = "std")]
+            const _: () = {
+                // Allows the types of the ink! trait messages to refer to the
+                // environment via `<Self as ContractEnv>::Env`.
+                impl ::ink_lang::reflect::ContractEnv
+                    for #trait_info_ident<::ink_env::DefaultEnvironment>
+                {
+                    type Env = ::ink_env::DefaultEnvironment;
+                }
+
+                impl ::ink_metadata::TraitMetadata
+                    for #trait_info_ident<::ink_env::DefaultEnvironment>
+                {
+                    fn trait_spec() -> ::ink_metadata::TraitSpec {
+                        #[allow(unused_imports)]
+                        use ::core::iter::Iterator as _;
+
+                        ::ink_metadata::TraitSpec::new(::core::stringify!(#trait_ident))
+                            .messages([
+                                #( #messages ),*
+                            ])
+                            .docs([
+                                #( #docs ),*
+                            ])
+                            .done()
+                    }
+                }
+            };
+        )
+    }
+}
+
+impl TraitMetadata<'_> {
+    /// Generates the ink! metadata of a single ink! trait message.
+    fn generate_message(
+        message: &ir::InkTraitMessage,
+        selector: ir::Selector,
+    ) -> TokenStream2 {
+        let span = message.span();
+        let ident = message.ident();
+        let docs = message
+            .attrs()
+            .iter()
+            .filter_map(|attr| attr.extract_docs())
+            .collect::<Vec<_>>();
+        let selector_bytes = selector.hex_lits();
+        let is_payable = message.ink_attrs().is_payable();
+        let mutates = message.mutates();
+        let args = message.inputs().map(|pat_type| {
+            let label = match &*pat_type.pat {
+                syn::Pat::Ident(pat_ident) => {
+                    let ident = &pat_ident.ident;
+                    quote! { ::core::stringify!(#ident) }
+                }
+                pat => quote! { ::core::stringify!(#pat) },
+            };
+            let type_spec = generator::Metadata::generate_type_spec(&pat_type.ty);
+            quote_spanned!(pat_type.span()=>
+                ::ink_metadata::MessageParamSpec::new(#label)
+                    .of_type(#type_spec)
+                    .done()
+            )
+        });
+        let ret_ty = generator::Metadata::generate_return_type(message.output());
+        quote_spanned!(span=>
+            ::ink_metadata::MessageSpec::from_label(::core::stringify!(#ident))
+                .selector([
+                    #( #selector_bytes ),*
+                ])
+                .args([
+                    #( #args ),*
+                ])
+                .returns(#ret_ty)
+                .mutates(#mutates)
+                .payable(#is_payable)
+                .docs([
+                    #( #docs ),*
+                ])
+                .done()
+        )
+    }
+}
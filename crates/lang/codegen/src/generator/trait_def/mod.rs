@@ -15,6 +15,7 @@
 mod call_builder;
 mod call_forwarder;
 mod definition;
+mod metadata;
 mod trait_registry;
 
 use crate::GenerateCode;
@@ -57,12 +58,14 @@ impl GenerateCode for TraitDefinition<'_> {
         let trait_registry = self.generate_trait_registry_impl();
         let trait_call_builder = self.generate_call_builder();
         let trait_call_forwarder = self.generate_call_forwarder();
+        let trait_metadata = self.generate_metadata();
         quote_spanned!(span =>
             #trait_definition
             const _: () = {
                 #trait_registry
                 #trait_call_builder
                 #trait_call_forwarder
+                #trait_metadata
             };
         )
     }
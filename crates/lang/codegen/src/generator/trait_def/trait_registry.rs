@@ -21,7 +21,9 @@
 
 use super::TraitDefinition;
 use crate::{
-    generator::{self,},
+    generator::{
+        self,
+    },
     traits::GenerateCode,
     EnforcedErrors,
 };
@@ -248,6 +250,13 @@ impl TraitRegistry<'_> {
         let trait_info_ident = self.trait_def.trait_info_ident();
         let trait_call_forwarder = self.trait_def.call_forwarder_ident();
         let trait_message_info = self.generate_info_for_trait_messages();
+        let trait_namespace = self
+            .trait_def
+            .trait_def
+            .item()
+            .namespace()
+            .map(syn::LitStr::value)
+            .unwrap_or_default();
         quote_spanned!(span =>
             #[doc(hidden)]
             #[allow(non_camel_case_types)]
@@ -266,6 +275,8 @@ impl TraitRegistry<'_> {
                 const PATH: &'static ::core::primitive::str = ::core::module_path!();
 
                 const NAME: &'static ::core::primitive::str = ::core::stringify!(#trait_ident);
+
+                const NAMESPACE: &'static ::core::primitive::str = #trait_namespace;
             }
 
             impl<E> ::ink_lang::codegen::TraitCallForwarder for #trait_info_ident<E>
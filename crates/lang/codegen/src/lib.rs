@@ -39,14 +39,54 @@ impl<'a> CodeGenerator for &'a ir::InkTraitDefinition {
     type Generator = generator::TraitDefinition<'a>;
 }
 
+impl<'a> CodeGenerator for &'a ir::InkEventDefinition {
+    type Generator = generator::EventDefinition<'a>;
+}
+
+impl<'a> CodeGenerator for &'a ir::ContractMixin {
+    type Generator = generator::ContractMixin<'a>;
+}
+
+impl<'a> CodeGenerator for &'a ir::InkEnvironment {
+    type Generator = generator::Environment<'a>;
+}
+
+impl<'a> CodeGenerator for &'a ir::ScaleDerive {
+    type Generator = generator::ScaleDerive<'a>;
+}
+
+impl<'a> CodeGenerator for &'a ir::InkProxy {
+    type Generator = generator::Proxy<'a>;
+}
+
+impl<'a> CodeGenerator for &'a ir::InkE2ETest {
+    type Generator = generator::InkE2ETest<'a>;
+}
+
 impl<'a> CodeGenerator for &'a ir::InkTest {
     type Generator = generator::InkTest<'a>;
 }
 
+impl<'a> CodeGenerator for &'a ir::InkExportImpl {
+    type Generator = generator::ExportImpl<'a>;
+}
+
+impl<'a> CodeGenerator for &'a ir::InkIncludeImpl {
+    type Generator = generator::IncludeImpl<'a>;
+}
+
 impl<'a> CodeGenerator for &'a ir::ChainExtension {
     type Generator = generator::ChainExtension<'a>;
 }
 
+impl<'a> CodeGenerator for &'a ir::ChainExtensionErrorCode {
+    type Generator = generator::ChainExtensionErrorCode<'a>;
+}
+
+impl<'a> CodeGenerator for &'a ir::ContractError {
+    type Generator = generator::ContractError<'a>;
+}
+
 impl<'a> CodeGenerator for &'a ir::SelectorMacro<ir::marker::SelectorId> {
     type Generator = generator::SelectorId<'a>;
 }
@@ -59,6 +99,10 @@ impl<'a> CodeGenerator for &'a ir::Blake2x256Macro {
     type Generator = generator::Blake2x256<'a>;
 }
 
+impl<'a> CodeGenerator for &'a ir::Keccak256Macro {
+    type Generator = generator::Keccak256<'a>;
+}
+
 /// Generates the entire code for the given ink! contract.
 pub fn generate_code<T>(entity: T) -> TokenStream2
 where
@@ -263,6 +263,16 @@ impl InkAttribute {
         })
     }
 
+    /// Returns the guard of the ink! attribute if any.
+    pub fn guard(&self) -> Option<syn::Ident> {
+        self.args().find_map(|arg| {
+            if let ir::AttributeArg::Guard(guard) = arg.kind() {
+                return Some(guard.clone())
+            }
+            None
+        })
+    }
+
     /// Returns the selector of the ink! attribute if any.
     pub fn selector(&self) -> Option<SelectorOrWildcard> {
         self.args().find_map(|arg| {
@@ -273,12 +283,44 @@ impl InkAttribute {
         })
     }
 
+    /// Returns the deprecation note of the ink! attribute if any.
+    pub fn deprecated(&self) -> Option<String> {
+        self.args().find_map(|arg| {
+            if let ir::AttributeArg::Deprecated(note) = arg.kind() {
+                return Some(note.clone())
+            }
+            None
+        })
+    }
+
     /// Returns `true` if the ink! attribute contains the `payable` argument.
     pub fn is_payable(&self) -> bool {
         self.args()
             .any(|arg| matches!(arg.kind(), AttributeArg::Payable))
     }
 
+    /// Returns `true` if the ink! attribute contains the `default` argument.
+    pub fn is_default(&self) -> bool {
+        self.args()
+            .any(|arg| matches!(arg.kind(), AttributeArg::Default))
+    }
+
+    /// Returns the kind of the ink! hook if the ink! attribute contains the `hook` argument.
+    pub fn hook(&self) -> Option<ir::HookKind> {
+        self.args().find_map(|arg| {
+            if let ir::AttributeArg::Hook(kind) = arg.kind() {
+                return Some(*kind)
+            }
+            None
+        })
+    }
+
+    /// Returns `true` if the ink! attribute contains the `non_reentrant` argument.
+    pub fn is_non_reentrant(&self) -> bool {
+        self.args()
+            .any(|arg| matches!(arg.kind(), AttributeArg::NonReentrant))
+    }
+
     /// Returns `true` if the ink! attribute contains the wildcard selector.
     pub fn has_wildcard_selector(&self) -> bool {
         self.args().any(|arg| {
@@ -351,6 +393,8 @@ pub enum AttributeArgKind {
     Constructor,
     /// `#[ink(payable)]`
     Payable,
+    /// `#[ink(default)]`
+    Default,
     /// `#[ink(selector = _)]`
     /// `#[ink(selector = 0xDEADBEEF)]`
     Selector,
@@ -364,6 +408,25 @@ pub enum AttributeArgKind {
     HandleStatus,
     /// `#[ink(returns_result = flag: bool)]`
     ReturnsResult,
+    /// `#[ink(status_code = N: u32)]`
+    StatusCode,
+    /// `#[ink(catch_all)]`
+    CatchAll,
+    /// `#[ink(function = N: u16)]`
+    Function,
+    /// `#[ink(guard = "my_guard")]`
+    Guard,
+    /// `#[ink(non_reentrant)]`
+    NonReentrant,
+    /// `#[ink(hook(before_call))]`
+    /// `#[ink(hook(after_call))]`
+    Hook,
+    /// `#[ink(deprecated = "note")]`
+    Deprecated,
+    /// `#[ink(error_code = N: u8)]`
+    ErrorCode,
+    /// `#[ink(env_error)]`
+    EnvError,
 }
 
 /// An ink! specific attribute flag.
@@ -405,6 +468,12 @@ pub enum AttributeArg {
     /// Applied on ink! constructors or messages in order to specify that they
     /// can receive funds from callers.
     Payable,
+    /// `#[ink(default)]`
+    ///
+    /// Applied on ink! constructors or messages in order to mark them as the
+    /// default to be preselected by user interfaces. There may be at most one
+    /// default ink! constructor and one default ink! message per contract.
+    Default,
     /// Can be either one of:
     ///
     /// - `#[ink(selector = 0xDEADBEEF)]`
@@ -450,6 +519,67 @@ pub enum AttributeArg {
     ///
     /// Default value: `true`
     ReturnsResult(bool),
+    /// `#[ink(status_code = N: u32)]`
+    ///
+    /// Applies on variants of chain extension error codes to map the status code `N`
+    /// to the variant.
+    ///
+    /// Used by the `#[derive(ChainExtensionErrorCode)]` procedural macro.
+    StatusCode(u32),
+    /// `#[ink(catch_all)]`
+    ///
+    /// Applies on the variant of chain extension error codes that all status codes
+    /// without a dedicated variant are mapped to.
+    ///
+    /// Used by the `#[derive(ChainExtensionErrorCode)]` procedural macro.
+    CatchAll,
+    /// `#[ink(function = N: u16)]`
+    ///
+    /// Applies on ink! chain extension methods of chain extensions with an extension ID
+    /// to set the function ID within the chain extension. The `func_id` parameter is then
+    /// made up of the extension ID in its upper and the function ID in its lower 16 bits.
+    ///
+    /// Used by the `#[ink::chain_extension(extension = N: u16)]` procedural macro.
+    Function(u16),
+    /// `#[ink(guard = "my_guard")]`
+    ///
+    /// Applied on ink! messages to invoke the named method of the ink! storage struct
+    /// before the body of the message. The guard takes `&self` and returns a
+    /// `Result<(), E>` whose error aborts the message via the `?` operator.
+    Guard(syn::Ident),
+    /// `#[ink(non_reentrant)]`
+    ///
+    /// Applied on ink! messages to revert the call if the contract is reentered
+    /// while the message is executing.
+    NonReentrant,
+    /// Can be either one of:
+    ///
+    /// - `#[ink(hook(before_call))]`
+    ///   Applied on a method of the ink! storage struct to invoke it with the selector
+    ///   of the called ink! message before every ink! message.
+    /// - `#[ink(hook(after_call))]`
+    ///   Applied on a method of the ink! storage struct to invoke it with the selector
+    ///   of the called ink! message after every ink! message.
+    Hook(ir::HookKind),
+    /// `#[ink(deprecated = "note")]`
+    ///
+    /// Applied on ink! messages to deprecate them. Callers of deprecated inherent
+    /// ink! messages are warned by the Rust compiler and the note is recorded in the
+    /// metadata of the ink! smart contract.
+    Deprecated(String),
+    /// `#[ink(error_code = N: u8)]`
+    ///
+    /// Applies on variants of contract errors to assign them the stable error code `N`.
+    ///
+    /// Used by the `#[derive(ContractError)]` procedural macro.
+    ErrorCode(u8),
+    /// `#[ink(env_error)]`
+    ///
+    /// Applies on the variant of contract errors that errors of the ink! environment
+    /// are converted into.
+    ///
+    /// Used by the `#[derive(ContractError)]` procedural macro.
+    EnvError,
 }
 
 impl core::fmt::Display for AttributeArgKind {
@@ -462,6 +592,7 @@ impl core::fmt::Display for AttributeArgKind {
             Self::Message => write!(f, "message"),
             Self::Constructor => write!(f, "constructor"),
             Self::Payable => write!(f, "payable"),
+            Self::Default => write!(f, "default"),
             Self::Selector => {
                 write!(f, "selector = S:[u8; 4] || _")
             }
@@ -474,6 +605,15 @@ impl core::fmt::Display for AttributeArgKind {
             Self::Implementation => write!(f, "impl"),
             Self::HandleStatus => write!(f, "handle_status"),
             Self::ReturnsResult => write!(f, "returns_result"),
+            Self::StatusCode => write!(f, "status_code = N:u32"),
+            Self::CatchAll => write!(f, "catch_all"),
+            Self::Function => write!(f, "function = N:u16"),
+            Self::Guard => write!(f, "guard = G:string"),
+            Self::NonReentrant => write!(f, "non_reentrant"),
+            Self::Hook => write!(f, "hook(before_call || after_call)"),
+            Self::Deprecated => write!(f, "deprecated = N:string"),
+            Self::ErrorCode => write!(f, "error_code = N:u8"),
+            Self::EnvError => write!(f, "env_error"),
         }
     }
 }
@@ -489,12 +629,22 @@ impl AttributeArg {
             Self::Message => AttributeArgKind::Message,
             Self::Constructor => AttributeArgKind::Constructor,
             Self::Payable => AttributeArgKind::Payable,
+            Self::Default => AttributeArgKind::Default,
             Self::Selector(_) => AttributeArgKind::Selector,
             Self::Extension(_) => AttributeArgKind::Extension,
             Self::Namespace(_) => AttributeArgKind::Namespace,
             Self::Implementation => AttributeArgKind::Implementation,
             Self::HandleStatus(_) => AttributeArgKind::HandleStatus,
             Self::ReturnsResult(_) => AttributeArgKind::ReturnsResult,
+            Self::StatusCode(_) => AttributeArgKind::StatusCode,
+            Self::CatchAll => AttributeArgKind::CatchAll,
+            Self::Function(_) => AttributeArgKind::Function,
+            Self::Guard(_) => AttributeArgKind::Guard,
+            Self::NonReentrant => AttributeArgKind::NonReentrant,
+            Self::Hook(_) => AttributeArgKind::Hook,
+            Self::Deprecated(_) => AttributeArgKind::Deprecated,
+            Self::ErrorCode(_) => AttributeArgKind::ErrorCode,
+            Self::EnvError => AttributeArgKind::EnvError,
         }
     }
 }
@@ -509,6 +659,7 @@ impl core::fmt::Display for AttributeArg {
             Self::Message => write!(f, "message"),
             Self::Constructor => write!(f, "constructor"),
             Self::Payable => write!(f, "payable"),
+            Self::Default => write!(f, "default"),
             Self::Selector(selector) => core::fmt::Display::fmt(&selector, f),
             Self::Extension(extension) => {
                 write!(f, "extension = {:?}", extension.into_u32())
@@ -519,6 +670,15 @@ impl core::fmt::Display for AttributeArg {
             Self::Implementation => write!(f, "impl"),
             Self::HandleStatus(value) => write!(f, "handle_status = {:?}", value),
             Self::ReturnsResult(value) => write!(f, "returns_result = {:?}", value),
+            Self::StatusCode(code) => write!(f, "status_code = {:?}", code),
+            Self::CatchAll => write!(f, "catch_all"),
+            Self::Function(function) => write!(f, "function = {:?}", function),
+            Self::Guard(guard) => write!(f, "guard = {:?}", guard.to_string()),
+            Self::NonReentrant => write!(f, "non_reentrant"),
+            Self::Hook(kind) => write!(f, "hook({})", kind),
+            Self::Deprecated(note) => write!(f, "deprecated = {:?}", note),
+            Self::ErrorCode(code) => write!(f, "error_code = {:?}", code),
+            Self::EnvError => write!(f, "env_error"),
         }
     }
 }
@@ -951,6 +1111,31 @@ impl TryFrom<syn::NestedMeta> for AttributeFrag {
                             }
                             return Err(format_err!(name_value, "expected string type for `namespace` argument, e.g. #[ink(namespace = \"hello\")]"))
                         }
+                        if name_value.path.is_ident("guard") {
+                            if let syn::Lit::Str(lit_str) = &name_value.lit {
+                                let guard = syn::parse_str::<syn::Ident>(&lit_str.value())
+                                    .map_err(|_error| format_err!(
+                                        lit_str,
+                                        "encountered invalid Rust identifier for guard argument",
+                                    ))?;
+                                let guard = syn::Ident::new(&guard.to_string(), lit_str.span());
+                                return Ok(AttributeFrag {
+                                    ast: meta,
+                                    arg: AttributeArg::Guard(guard),
+                                })
+                            }
+                            return Err(format_err!(name_value, "expected string type for `guard` argument, e.g. #[ink(guard = \"only_owner\")]"))
+                        }
+                        if name_value.path.is_ident("deprecated") {
+                            if let syn::Lit::Str(lit_str) = &name_value.lit {
+                                let note = lit_str.value();
+                                return Ok(AttributeFrag {
+                                    ast: meta,
+                                    arg: AttributeArg::Deprecated(note),
+                                })
+                            }
+                            return Err(format_err!(name_value, "expected string type for `deprecated` argument, e.g. #[ink(deprecated = \"use transfer_v2\")]"))
+                        }
                         if name_value.path.is_ident("extension") {
                             if let syn::Lit::Int(lit_int) = &name_value.lit {
                                 let id = lit_int.base10_parse::<u32>().map_err(|parse_err| {
@@ -988,6 +1173,51 @@ impl TryFrom<syn::NestedMeta> for AttributeFrag {
                             }
                             return Err(format_err!(name_value, "expected `bool` value type for `flag` in #[ink(returns_result = flag)]"))
                         }
+                        if name_value.path.is_ident("status_code") {
+                            if let syn::Lit::Int(lit_int) = &name_value.lit {
+                                let code = lit_int.base10_parse::<u32>().map_err(|parse_err| {
+                                    format_err!(
+                                        name_value,
+                                        "could not parse `N` in `#[ink(status_code = N)]` into a `u32` integer",
+                                    ).into_combine(parse_err)
+                                })?;
+                                return Ok(AttributeFrag {
+                                    ast: meta,
+                                    arg: AttributeArg::StatusCode(code),
+                                })
+                            }
+                            return Err(format_err!(name_value, "expected `u32` integer type for `N` in #[ink(status_code = N)]"))
+                        }
+                        if name_value.path.is_ident("error_code") {
+                            if let syn::Lit::Int(lit_int) = &name_value.lit {
+                                let code = lit_int.base10_parse::<u8>().map_err(|parse_err| {
+                                    format_err!(
+                                        name_value,
+                                        "could not parse `N` in `#[ink(error_code = N)]` into a `u8` integer",
+                                    ).into_combine(parse_err)
+                                })?;
+                                return Ok(AttributeFrag {
+                                    ast: meta,
+                                    arg: AttributeArg::ErrorCode(code),
+                                })
+                            }
+                            return Err(format_err!(name_value, "expected `u8` integer type for `N` in #[ink(error_code = N)]"))
+                        }
+                        if name_value.path.is_ident("function") {
+                            if let syn::Lit::Int(lit_int) = &name_value.lit {
+                                let id = lit_int.base10_parse::<u16>().map_err(|parse_err| {
+                                    format_err!(
+                                        name_value,
+                                        "could not parse `N` in `#[ink(function = N)]` into a `u16` integer",
+                                    ).into_combine(parse_err)
+                                })?;
+                                return Ok(AttributeFrag {
+                                    ast: meta,
+                                    arg: AttributeArg::Function(id),
+                                })
+                            }
+                            return Err(format_err!(name_value, "expected `u16` integer type for `N` in #[ink(function = N)]"))
+                        }
                         Err(format_err_spanned!(
                             meta,
                             "unknown ink! attribute argument (name = value)",
@@ -1006,7 +1236,11 @@ impl TryFrom<syn::NestedMeta> for AttributeFrag {
                                 "anonymous" => Ok(AttributeArg::Anonymous),
                                 "topic" => Ok(AttributeArg::Topic),
                                 "payable" => Ok(AttributeArg::Payable),
+                                "default" => Ok(AttributeArg::Default),
                                 "impl" => Ok(AttributeArg::Implementation),
+                                "catch_all" => Ok(AttributeArg::CatchAll),
+                                "non_reentrant" => Ok(AttributeArg::NonReentrant),
+                                "env_error" => Ok(AttributeArg::EnvError),
                                 "selector" => Err(format_err!(
                                     meta,
                                     "encountered #[ink(selector)] that is missing its u32 parameter. \
@@ -1017,6 +1251,16 @@ impl TryFrom<syn::NestedMeta> for AttributeFrag {
                                     "encountered #[ink(namespace)] that is missing its string parameter. \
                                     Did you mean #[ink(namespace = name: str)] ?"
                                 )),
+                                "guard" => Err(format_err!(
+                                    meta,
+                                    "encountered #[ink(guard)] that is missing its method name. \
+                                    Did you mean #[ink(guard = name: str)] ?"
+                                )),
+                                "deprecated" => Err(format_err!(
+                                    meta,
+                                    "encountered #[ink(deprecated)] that is missing its note. \
+                                    Did you mean #[ink(deprecated = note: str)] ?"
+                                )),
                                 "extension" => Err(format_err!(
                                     meta,
                                     "encountered #[ink(extension)] that is missing its `id` parameter. \
@@ -1032,13 +1276,61 @@ impl TryFrom<syn::NestedMeta> for AttributeFrag {
                                     "encountered #[ink(returns_result)] that is missing its `flag: bool` parameter. \
                                     Did you mean #[ink(returns_result = flag: bool)] ?"
                                 )),
+                                "function" => Err(format_err!(
+                                    meta,
+                                    "encountered #[ink(function)] that is missing its `id` parameter. \
+                                    Did you mean #[ink(function = id: u16)] ?"
+                                )),
+                                "hook" => Err(format_err!(
+                                    meta,
+                                    "encountered #[ink(hook)] that is missing its kind. \
+                                    Did you mean #[ink(hook(before_call))] or #[ink(hook(after_call))] ?"
+                                )),
+                                "status_code" => Err(format_err!(
+                                    meta,
+                                    "encountered #[ink(status_code)] that is missing its `N: u32` parameter. \
+                                    Did you mean #[ink(status_code = N: u32)] ?"
+                                )),
+                                "error_code" => Err(format_err!(
+                                    meta,
+                                    "encountered #[ink(error_code)] that is missing its `N: u8` parameter. \
+                                    Did you mean #[ink(error_code = N: u8)] ?"
+                                )),
                                 _ => Err(format_err_spanned!(
                                     meta, "unknown ink! attribute (path)"
                                 ))
                             })
                             .map(|kind| AttributeFrag { ast: meta, arg: kind, })
                     }
-                    syn::Meta::List(_) => {
+                    syn::Meta::List(meta_list) => {
+                        if meta_list.path.is_ident("hook") {
+                            let kind = match meta_list.nested.iter().collect::<Vec<_>>().as_slice() {
+                                [syn::NestedMeta::Meta(syn::Meta::Path(path))] => {
+                                    if path.is_ident("before_call") {
+                                        Some(ir::HookKind::BeforeCall)
+                                    } else if path.is_ident("after_call") {
+                                        Some(ir::HookKind::AfterCall)
+                                    } else {
+                                        None
+                                    }
+                                }
+                                _ => None,
+                            };
+                            return match kind {
+                                Some(kind) => {
+                                    Ok(AttributeFrag {
+                                        ast: meta,
+                                        arg: AttributeArg::Hook(kind),
+                                    })
+                                }
+                                None => {
+                                    Err(format_err_spanned!(
+                                        meta_list,
+                                        "expected either `before_call` or `after_call` for #[ink(hook(kind))]"
+                                    ))
+                                }
+                            }
+                        }
                         Err(format_err_spanned!(
                             meta,
                             "unknown ink! attribute argument (list)"
@@ -1311,6 +1603,153 @@ mod tests {
         );
     }
 
+    #[test]
+    fn hook_works() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(hook(before_call))]
+            },
+            Ok(test::Attribute::Ink(vec![AttributeArg::Hook(
+                ir::HookKind::BeforeCall,
+            )])),
+        );
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(hook(after_call))]
+            },
+            Ok(test::Attribute::Ink(vec![AttributeArg::Hook(
+                ir::HookKind::AfterCall,
+            )])),
+        );
+    }
+
+    #[test]
+    fn hook_invalid_kind() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(hook(before_message))]
+            },
+            Err("expected either `before_call` or `after_call` for #[ink(hook(kind))]"),
+        );
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(hook(before_call, after_call))]
+            },
+            Err("expected either `before_call` or `after_call` for #[ink(hook(kind))]"),
+        );
+    }
+
+    #[test]
+    fn hook_missing_kind() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(hook)]
+            },
+            Err("encountered #[ink(hook)] that is missing its kind. \
+                Did you mean #[ink(hook(before_call))] or #[ink(hook(after_call))] ?"),
+        );
+    }
+
+    #[test]
+    fn guard_works() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(guard = "only_owner")]
+            },
+            Ok(test::Attribute::Ink(vec![AttributeArg::Guard(
+                syn::parse_quote! {
+                    only_owner
+                },
+            )])),
+        );
+    }
+
+    #[test]
+    fn non_reentrant_works() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(non_reentrant)]
+            },
+            Ok(test::Attribute::Ink(vec![AttributeArg::NonReentrant])),
+        );
+    }
+
+    #[test]
+    fn default_works() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(default)]
+            },
+            Ok(test::Attribute::Ink(vec![AttributeArg::Default])),
+        );
+    }
+
+    #[test]
+    fn guard_invalid_identifier() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(guard = "self.only_owner")]
+            },
+            Err("encountered invalid Rust identifier for guard argument"),
+        );
+    }
+
+    #[test]
+    fn guard_invalid_type() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(guard = true)]
+            },
+            Err("expected string type for `guard` argument, e.g. #[ink(guard = \"only_owner\")]"),
+        );
+    }
+
+    #[test]
+    fn guard_missing_parameter() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(guard)]
+            },
+            Err(
+                "encountered #[ink(guard)] that is missing its method name. \
+                Did you mean #[ink(guard = name: str)] ?",
+            ),
+        );
+    }
+
+    #[test]
+    fn deprecated_works() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(deprecated = "use transfer_v2")]
+            },
+            Ok(test::Attribute::Ink(vec![AttributeArg::Deprecated(
+                "use transfer_v2".to_string(),
+            )])),
+        );
+    }
+
+    #[test]
+    fn deprecated_invalid_type() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(deprecated = true)]
+            },
+            Err("expected string type for `deprecated` argument, e.g. #[ink(deprecated = \"use transfer_v2\")]"),
+        );
+    }
+
+    #[test]
+    fn deprecated_missing_parameter() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(deprecated)]
+            },
+            Err("encountered #[ink(deprecated)] that is missing its note. \
+                Did you mean #[ink(deprecated = note: str)] ?"),
+        );
+    }
+
     #[test]
     fn extension_works() {
         assert_attribute_try_from(
@@ -1455,6 +1894,139 @@ mod tests {
         );
     }
 
+    #[test]
+    fn status_code_works() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(status_code = 42)]
+            },
+            Ok(test::Attribute::Ink(vec![AttributeArg::StatusCode(42)])),
+        );
+    }
+
+    #[test]
+    fn status_code_invalid_value_type() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(status_code = "string")]
+            },
+            Err("expected `u32` integer type for `N` in #[ink(status_code = N)]"),
+        );
+    }
+
+    #[test]
+    fn status_code_negative_integer() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(status_code = -1)]
+            },
+            Err("could not parse `N` in `#[ink(status_code = N)]` into a `u32` integer"),
+        );
+    }
+
+    #[test]
+    fn status_code_missing_parameter() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(status_code)]
+            },
+            Err(
+                "encountered #[ink(status_code)] that is missing its `N: u32` parameter. \
+                Did you mean #[ink(status_code = N: u32)] ?",
+            ),
+        );
+    }
+
+    #[test]
+    fn error_code_works() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(error_code = 42)]
+            },
+            Ok(test::Attribute::Ink(vec![AttributeArg::ErrorCode(42)])),
+        );
+    }
+
+    #[test]
+    fn error_code_too_big_integer() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(error_code = 256)]
+            },
+            Err("could not parse `N` in `#[ink(error_code = N)]` into a `u8` integer"),
+        );
+    }
+
+    #[test]
+    fn error_code_missing_parameter() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(error_code)]
+            },
+            Err(
+                "encountered #[ink(error_code)] that is missing its `N: u8` parameter. \
+                Did you mean #[ink(error_code = N: u8)] ?",
+            ),
+        );
+    }
+
+    #[test]
+    fn env_error_works() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(error_code = 1, env_error)]
+            },
+            Ok(test::Attribute::Ink(vec![
+                AttributeArg::ErrorCode(1),
+                AttributeArg::EnvError,
+            ])),
+        );
+    }
+
+    #[test]
+    fn function_works() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(function = 42)]
+            },
+            Ok(test::Attribute::Ink(vec![AttributeArg::Function(42)])),
+        );
+    }
+
+    #[test]
+    fn function_too_big_integer() {
+        let max_u16_plus_1 = (u16::MAX as u32) + 1;
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(function = #max_u16_plus_1)]
+            },
+            Err("could not parse `N` in `#[ink(function = N)]` into a `u16` integer"),
+        );
+    }
+
+    #[test]
+    fn function_missing_parameter() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(function)]
+            },
+            Err(
+                "encountered #[ink(function)] that is missing its `id` parameter. \
+                Did you mean #[ink(function = id: u16)] ?",
+            ),
+        );
+    }
+
+    #[test]
+    fn catch_all_works() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(catch_all)]
+            },
+            Ok(test::Attribute::Ink(vec![AttributeArg::CatchAll])),
+        );
+    }
+
     #[test]
     fn compound_mixed_works() {
         assert_attribute_try_from(
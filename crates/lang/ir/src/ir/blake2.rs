@@ -53,29 +53,39 @@ impl Blake2x256Macro {
     }
 }
 
+/// Parses the string or byte string literal input of a hashing macro.
+///
+/// Returns the literal together with the bytes that are to be hashed.
+pub(super) fn parse_hash_input(
+    input: TokenStream2,
+) -> Result<(syn::Lit, Vec<u8>), syn::Error> {
+    let input_span = input.span();
+    let lit = syn::parse2::<syn::Lit>(input).map_err(|error| {
+        format_err!(
+            input_span,
+            "expected string or byte string literal as input: {}",
+            error
+        )
+    })?;
+    let input_bytes = match lit {
+        syn::Lit::Str(ref lit_str) => lit_str.value().into_bytes(),
+        syn::Lit::ByteStr(ref byte_str) => byte_str.value(),
+        invalid => {
+            return Err(format_err!(
+                invalid.span(),
+                "expected string or byte string literal as input. found {:?}",
+                invalid,
+            ))
+        }
+    };
+    Ok((lit, input_bytes))
+}
+
 impl TryFrom<TokenStream2> for Blake2x256Macro {
     type Error = syn::Error;
 
     fn try_from(input: TokenStream2) -> Result<Self, Self::Error> {
-        let input_span = input.span();
-        let lit = syn::parse2::<syn::Lit>(input).map_err(|error| {
-            format_err!(
-                input_span,
-                "expected string or byte string literal as input: {}",
-                error
-            )
-        })?;
-        let input_bytes = match lit {
-            syn::Lit::Str(ref lit_str) => lit_str.value().into_bytes(),
-            syn::Lit::ByteStr(ref byte_str) => byte_str.value(),
-            invalid => {
-                return Err(format_err!(
-                    invalid.span(),
-                    "expected string or byte string literal as input. found {:?}",
-                    invalid,
-                ))
-            }
-        };
+        let (lit, input_bytes) = parse_hash_input(input)?;
         let mut output = [0u8; 32];
         blake2b_256(&input_bytes, &mut output);
         Ok(Self {
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::{
+    ast,
     error::ExtError,
     ir,
     ir::idents_lint,
@@ -185,6 +186,16 @@ impl ExtensionId {
         Self { index }
     }
 
+    /// Creates a new chain extension method ID from the ID of the chain extension
+    /// and the ID of the function within the chain extension.
+    ///
+    /// The extension ID makes up the upper and the function ID the lower 16 bits.
+    pub fn from_parts(extension: u16, function: u16) -> Self {
+        Self {
+            index: (extension as u32) << 16 | function as u32,
+        }
+    }
+
     /// Returns the underlying raw `u32` index.
     pub fn into_u32(self) -> u32 {
         self.index
@@ -195,28 +206,73 @@ impl TryFrom<syn::ItemTrait> for ChainExtension {
     type Error = syn::Error;
 
     fn try_from(item_trait: syn::ItemTrait) -> core::result::Result<Self, Self::Error> {
+        Self::analyse(item_trait, None)
+    }
+}
+
+impl ChainExtension {
+    /// Returns `Ok` if the trait matches all requirements for an ink! chain extension.
+    pub fn new(attr: TokenStream2, input: TokenStream2) -> Result<Self> {
+        let extension = Self::analyse_config(syn::parse2::<ast::AttributeArgs>(attr)?)?;
+        let item_trait = syn::parse2::<syn::ItemTrait>(input)?;
+        Self::analyse(item_trait, extension)
+    }
+
+    /// Analyses the ink! chain extension given its optional extension ID.
+    fn analyse(item_trait: syn::ItemTrait, extension: Option<u16>) -> Result<Self> {
         idents_lint::ensure_no_ink_identifiers(&item_trait)?;
         Self::analyse_properties(&item_trait)?;
-        let (error_code, methods) = Self::analyse_items(&item_trait)?;
+        let (error_code, methods) = Self::analyse_items(&item_trait, extension)?;
         Ok(Self {
             item: item_trait,
             error_code,
             methods,
         })
     }
-}
 
-impl ChainExtension {
-    /// Returns `Ok` if the trait matches all requirements for an ink! chain extension.
-    pub fn new(attr: TokenStream2, input: TokenStream2) -> Result<Self> {
-        if !attr.is_empty() {
-            return Err(format_err_spanned!(
-                attr,
-                "unexpected attribute input for ink! chain extension"
-            ))
+    /// Returns the extension ID given in `#[ink::chain_extension(extension = N: u16)]` if any.
+    ///
+    /// # Errors
+    ///
+    /// - If the extension ID is not a `u16` integer.
+    /// - If there are duplicate or unknown configuration arguments.
+    fn analyse_config(args: ast::AttributeArgs) -> Result<Option<u16>> {
+        let mut extension: Option<(u16, ast::MetaNameValue)> = None;
+        for arg in args.into_iter() {
+            if arg.name.is_ident("extension") {
+                if let Some((_, previous)) = extension {
+                    return Err(format_err_spanned!(
+                        arg,
+                        "encountered duplicate ink! `extension` configuration argument",
+                    )
+                    .into_combine(format_err_spanned!(
+                        previous,
+                        "first `extension` configuration argument here",
+                    )))
+                }
+                if let ast::PathOrLit::Lit(syn::Lit::Int(lit_int)) = &arg.value {
+                    let id = lit_int.base10_parse::<u16>().map_err(|parse_err| {
+                        format_err_spanned!(
+                            arg,
+                            "could not parse `N` in `extension = N` into a `u16` integer",
+                        )
+                        .into_combine(parse_err)
+                    })?;
+                    extension = Some((id, arg))
+                } else {
+                    return Err(format_err_spanned!(
+                        arg,
+                        "expected `u16` integer type for `N` in `extension = N`",
+                    ))
+                }
+            } else {
+                return Err(format_err_spanned!(
+                    arg,
+                    "encountered unknown or unsupported ink! chain extension configuration argument",
+                ))
+            }
         }
-        let item_trait = syn::parse2::<syn::ItemTrait>(input)?;
-        ChainExtension::try_from(item_trait)
+        Ok(extension.map(|(id, _)| id))
     }
 
     /// Analyses the properties of the ink! chain extension.
@@ -329,6 +385,10 @@ impl ChainExtension {
     ///     - All trait methods must not have a `self` receiver.
     ///     - All trait methods must have an `#[ink(extension = N: u32)]` attribute that is the ID that
     ///       corresponds with the function ID of the respective chain extension call.
+    ///     - If the chain extension has an extension ID all trait methods must instead have an
+    ///       `#[ink(function = N: u16)]` attribute. The function ID of the respective chain
+    ///       extension call is then made up of the extension ID in its upper and the function ID
+    ///       in its lower 16 bits.
     ///
     /// # Note
     ///
@@ -336,6 +396,7 @@ impl ChainExtension {
     /// as a result of this procedural macro invocation.
     fn analyse_items(
         item_trait: &syn::ItemTrait,
+        extension: Option<u16>,
     ) -> Result<(syn::TraitItemType, Vec<ChainExtensionMethod>)> {
         let mut methods = Vec::new();
         let mut seen_ids = HashMap::new();
@@ -364,7 +425,7 @@ impl ChainExtension {
                     ))
                 }
                 syn::TraitItem::Method(method_trait_item) => {
-                    let method = Self::analyse_methods(method_trait_item)?;
+                    let method = Self::analyse_methods(method_trait_item, extension)?;
                     let method_id = method.id();
                     if let Some(previous) = seen_ids.get(&method_id) {
                         return Err(format_err!(
@@ -402,12 +463,16 @@ impl ChainExtension {
     ///
     /// # Errors
     ///
-    /// - If the method is missing the `#[ink(extension = N: u32)]` attribute.
+    /// - If the method is missing the `#[ink(extension = N: u32)]` attribute, or the
+    ///   `#[ink(function = N: u16)]` attribute if the chain extension has an extension ID.
     /// - If the method has a `self` receiver.
     /// - If the method declared as `unsafe`, `const` or `async`.
     /// - If the method has some explicit API.
     /// - If the method is variadic or has generic parameters.
-    fn analyse_methods(method: &syn::TraitItemMethod) -> Result<ChainExtensionMethod> {
+    fn analyse_methods(
+        method: &syn::TraitItemMethod,
+        extension: Option<u16>,
+    ) -> Result<ChainExtensionMethod> {
         if let Some(default_impl) = &method.default {
             return Err(format_err_spanned!(
                 default_impl,
@@ -450,23 +515,55 @@ impl ChainExtension {
                 "generic ink! chain extension methods are not supported"
             ))
         }
-        match ir::first_ink_attribute(&method.attrs)?
-                .map(|attr| attr.first().kind().clone()) {
-            Some(ir::AttributeArg::Extension(extension)) => {
-                Self::analyse_chain_extension_method(method, extension)
+        match (
+            extension,
+            ir::first_ink_attribute(&method.attrs)?.map(|attr| attr.first().kind().clone()),
+        ) {
+            (None, Some(ir::AttributeArg::Extension(id))) => {
+                Self::analyse_chain_extension_method(method, &ir::AttributeArgKind::Extension, id)
+            }
+            (Some(extension), Some(ir::AttributeArg::Function(function))) => {
+                let id = ExtensionId::from_parts(extension, function);
+                Self::analyse_chain_extension_method(method, &ir::AttributeArgKind::Function, id)
+            }
+            (None, Some(ir::AttributeArg::Function(_))) => {
+                Err(format_err_spanned!(
+                    method,
+                    "encountered #[ink(function = N: u16)] on a method of an ink! chain extension without extension ID. \
+                    use #[ink(extension = N: u32)] or provide the extension ID via #[ink::chain_extension(extension = N: u16)]"
+                ))
+            }
+            (Some(_), Some(ir::AttributeArg::Extension(_))) => {
+                Err(format_err_spanned!(
+                    method,
+                    "encountered #[ink(extension = N: u32)] on a method of an ink! chain extension with extension ID. \
+                    use #[ink(function = N: u16)] instead"
+                ))
             }
-            Some(_unsupported) => {
+            (None, Some(_unsupported)) => {
                 Err(format_err_spanned!(
                     method,
                     "encountered unsupported ink! attribute for ink! chain extension method. expected #[ink(extension = N: u32)] attribute"
                 ))
             }
-            None => {
+            (Some(_), Some(_unsupported)) => {
+                Err(format_err_spanned!(
+                    method,
+                    "encountered unsupported ink! attribute for ink! chain extension method. expected #[ink(function = N: u16)] attribute"
+                ))
+            }
+            (None, None) => {
                 Err(format_err_spanned!(
                     method,
                     "missing #[ink(extension = N: u32)] flag on ink! chain extension method"
                 ))
             }
+            (Some(_), None) => {
+                Err(format_err_spanned!(
+                    method,
+                    "missing #[ink(function = N: u16)] flag on ink! chain extension method"
+                ))
+            }
         }
     }
 
@@ -477,17 +574,18 @@ impl ChainExtension {
     /// - If the chain extension method has a `self` receiver as first argument.
     fn analyse_chain_extension_method(
         item_method: &syn::TraitItemMethod,
+        id_kind: &ir::AttributeArgKind,
         extension: ExtensionId,
     ) -> Result<ChainExtensionMethod> {
         let (ink_attrs, _) = ir::sanitize_attributes(
             item_method.span(),
             item_method.attrs.clone(),
-            &ir::AttributeArgKind::Extension,
+            id_kind,
             |arg| {
                 match arg.kind() {
-                    ir::AttributeArg::Extension(_)
-                    | ir::AttributeArg::HandleStatus(_)
+                    ir::AttributeArg::HandleStatus(_)
                     | ir::AttributeArg::ReturnsResult(_) => Ok(()),
+                    other if other.kind() == *id_kind => Ok(()),
                     _ => Err(None),
                 }
             },
@@ -980,4 +1078,91 @@ mod tests {
             assert_eq!(actual, expected);
         }
     }
+
+    #[test]
+    fn chain_extension_with_extension_id_works() {
+        let chain_extension = ChainExtension::new(
+            quote::quote! { extension = 2 },
+            quote::quote! {
+                pub trait MyChainExtension {
+                    type ErrorCode = ();
+
+                    #[ink(function = 1)]
+                    fn function_a();
+                    #[ink(function = 0xFFFF, handle_status = false)]
+                    fn function_b();
+                }
+            },
+        )
+        .unwrap();
+        let ids = chain_extension
+            .iter_methods()
+            .map(|method| method.id().into_u32())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec![0x0002_0001, 0x0002_FFFF]);
+    }
+
+    #[test]
+    fn chain_extension_with_invalid_config_is_denied() {
+        let assert_config_err = |attr: TokenStream2, expected: &str| {
+            assert_eq!(
+                ChainExtension::new(
+                    attr,
+                    quote::quote! {
+                        pub trait MyChainExtension {
+                            type ErrorCode = ();
+                        }
+                    },
+                )
+                .map_err(|err| err.to_string()),
+                Err(expected.to_string())
+            )
+        };
+        assert_config_err(
+            quote::quote! { extension = 65536 },
+            "could not parse `N` in `extension = N` into a `u16` integer",
+        );
+        assert_config_err(
+            quote::quote! { extension = "1" },
+            "expected `u16` integer type for `N` in `extension = N`",
+        );
+        assert_config_err(
+            quote::quote! { extension = 1, extension = 2 },
+            "encountered duplicate ink! `extension` configuration argument",
+        );
+        assert_config_err(
+            quote::quote! { unknown = 1 },
+            "encountered unknown or unsupported ink! chain extension configuration argument",
+        );
+    }
+
+    #[test]
+    fn mixed_function_and_extension_attributes_are_denied() {
+        assert_eq!(
+            ChainExtension::new(
+                quote::quote! { extension = 1 },
+                quote::quote! {
+                    pub trait MyChainExtension {
+                        type ErrorCode = ();
+
+                        #[ink(extension = 1)]
+                        fn extension_a();
+                    }
+                },
+            )
+            .map_err(|err| err.to_string()),
+            Err("encountered #[ink(extension = N: u32)] on a method of an ink! chain extension with extension ID. \
+                use #[ink(function = N: u16)] instead".to_string())
+        );
+        assert_ink_chain_extension_eq_err!(
+            error: "encountered #[ink(function = N: u16)] on a method of an ink! chain extension without extension ID. \
+                    use #[ink(extension = N: u32)] or provide the extension ID via #[ink::chain_extension(extension = N: u16)]",
+            pub trait MyChainExtension {
+                type ErrorCode = ();
+
+                #[ink(function = 1)]
+                fn function_a();
+            }
+        );
+    }
 }
@@ -0,0 +1,428 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    error::ExtError,
+    ir,
+};
+use core::slice::Iter as SliceIter;
+use proc_macro2::TokenStream as TokenStream2;
+use std::collections::HashMap;
+use syn::{
+    spanned::Spanned as _,
+    Result,
+};
+
+/// A chain extension error code defined via `#[derive(ChainExtensionErrorCode)]`.
+///
+/// Maps the status codes returned by chain extension method calls to the
+/// variants of an enum.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ChainExtensionErrorCode {
+    item: syn::ItemEnum,
+    variants: Vec<ErrorCodeVariant>,
+    catch_all: CatchAllVariant,
+}
+
+/// A variant of a chain extension error code flagged with `#[ink(status_code = N: u32)]`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ErrorCodeVariant {
+    ident: syn::Ident,
+    status_code: u32,
+}
+
+impl ErrorCodeVariant {
+    /// Returns the identifier of the variant.
+    pub fn ident(&self) -> &syn::Ident {
+        &self.ident
+    }
+
+    /// Returns the status code that is mapped to the variant.
+    pub fn status_code(&self) -> u32 {
+        self.status_code
+    }
+}
+
+/// The variant of a chain extension error code flagged with `#[ink(catch_all)]`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct CatchAllVariant {
+    ident: syn::Ident,
+    has_status_code_field: bool,
+}
+
+impl CatchAllVariant {
+    /// Returns the identifier of the variant.
+    pub fn ident(&self) -> &syn::Ident {
+        &self.ident
+    }
+
+    /// Returns `true` if the variant carries the unmapped `u32` status code.
+    pub fn has_status_code_field(&self) -> bool {
+        self.has_status_code_field
+    }
+}
+
+impl ChainExtensionErrorCode {
+    /// Returns `Ok` if the enum matches all requirements for a chain extension error code.
+    pub fn new(input: TokenStream2) -> Result<Self> {
+        let item_enum = syn::parse2::<syn::ItemEnum>(input)?;
+        ChainExtensionErrorCode::try_from(item_enum)
+    }
+
+    /// Returns the span of the chain extension error code.
+    pub fn span(&self) -> proc_macro2::Span {
+        self.item.span()
+    }
+
+    /// Returns the identifier of the chain extension error code.
+    pub fn ident(&self) -> &proc_macro2::Ident {
+        &self.item.ident
+    }
+
+    /// Returns a slice over all variants with a dedicated status code.
+    pub fn iter_variants(&self) -> SliceIter<'_, ErrorCodeVariant> {
+        self.variants.iter()
+    }
+
+    /// Returns the catch-all variant.
+    pub fn catch_all(&self) -> &CatchAllVariant {
+        &self.catch_all
+    }
+}
+
+impl TryFrom<syn::ItemEnum> for ChainExtensionErrorCode {
+    type Error = syn::Error;
+
+    fn try_from(item_enum: syn::ItemEnum) -> core::result::Result<Self, Self::Error> {
+        if !item_enum.generics.params.is_empty() {
+            return Err(format_err_spanned!(
+                item_enum.generics.params,
+                "chain extension error codes must not be generic"
+            ))
+        }
+        let mut variants = Vec::new();
+        let mut catch_all: Option<CatchAllVariant> = None;
+        let mut seen_codes: HashMap<u32, &syn::Variant> = HashMap::new();
+        for variant in &item_enum.variants {
+            match ir::first_ink_attribute(&variant.attrs)?
+                .map(|attr| attr.first().kind().clone())
+            {
+                Some(ir::AttributeArg::StatusCode(status_code)) => {
+                    Self::ensure_single_attribute(
+                        variant,
+                        &ir::AttributeArgKind::StatusCode,
+                    )?;
+                    if !matches!(variant.fields, syn::Fields::Unit) {
+                        return Err(format_err_spanned!(
+                            variant.fields,
+                            "chain extension error code variants with a status code must not have fields"
+                        ))
+                    }
+                    if status_code == 0 {
+                        return Err(format_err_spanned!(
+                            variant,
+                            "status code 0 is reserved for successful chain extension method calls"
+                        ))
+                    }
+                    if let Some(previous) = seen_codes.get(&status_code) {
+                        return Err(format_err_spanned!(
+                            variant,
+                            "encountered duplicate status code {} for chain extension error code",
+                            status_code,
+                        )
+                        .into_combine(format_err_spanned!(
+                            previous,
+                            "first variant with the same status code here"
+                        )))
+                    }
+                    seen_codes.insert(status_code, variant);
+                    variants.push(ErrorCodeVariant {
+                        ident: variant.ident.clone(),
+                        status_code,
+                    });
+                }
+                Some(ir::AttributeArg::CatchAll) => {
+                    Self::ensure_single_attribute(
+                        variant,
+                        &ir::AttributeArgKind::CatchAll,
+                    )?;
+                    if catch_all.is_some() {
+                        return Err(format_err_spanned!(
+                            variant,
+                            "encountered duplicate catch-all variant for chain extension error code"
+                        ))
+                    }
+                    let has_status_code_field = Self::analyse_catch_all_fields(variant)?;
+                    catch_all = Some(CatchAllVariant {
+                        ident: variant.ident.clone(),
+                        has_status_code_field,
+                    });
+                }
+                Some(_unsupported) => {
+                    return Err(format_err_spanned!(
+                        variant,
+                        "encountered unsupported ink! attribute for chain extension error code variant. \
+                        expected #[ink(status_code = N: u32)] or #[ink(catch_all)] attribute"
+                    ))
+                }
+                None => {
+                    return Err(format_err_spanned!(
+                        variant,
+                        "missing #[ink(status_code = N: u32)] or #[ink(catch_all)] attribute \
+                        on chain extension error code variant"
+                    ))
+                }
+            }
+        }
+        let catch_all = match catch_all {
+            Some(catch_all) => catch_all,
+            None => {
+                return Err(format_err_spanned!(
+                    item_enum.ident,
+                    "missing #[ink(catch_all)] variant for unknown status codes \
+                    of chain extension error code"
+                ))
+            }
+        };
+        Ok(Self {
+            item: item_enum,
+            variants,
+            catch_all,
+        })
+    }
+}
+
+impl ChainExtensionErrorCode {
+    /// Ensures that the ink! attribute of the variant has no further arguments.
+    fn ensure_single_attribute(
+        variant: &syn::Variant,
+        kind: &ir::AttributeArgKind,
+    ) -> Result<()> {
+        ir::sanitize_attributes(variant.span(), variant.attrs.clone(), kind, |arg| {
+            if arg.kind().kind() == *kind {
+                return Ok(())
+            }
+            Err(None)
+        })?;
+        Ok(())
+    }
+
+    /// Returns `true` if the catch-all variant carries the status code.
+    ///
+    /// # Errors
+    ///
+    /// If the catch-all variant has fields other than a single unnamed `u32` field.
+    fn analyse_catch_all_fields(variant: &syn::Variant) -> Result<bool> {
+        match &variant.fields {
+            syn::Fields::Unit => Ok(false),
+            syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                let ty = &fields.unnamed[0].ty;
+                if *ty != syn::parse_quote!(u32) {
+                    return Err(format_err_spanned!(
+                        ty,
+                        "the field of the catch-all variant must be of type `u32`"
+                    ))
+                }
+                Ok(true)
+            }
+            fields => {
+                Err(format_err_spanned!(
+                    fields,
+                    "the catch-all variant must either have no fields or a single unnamed `u32` field"
+                ))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks if the token stream in `$error_code` results in the expected error message.
+    macro_rules! assert_error_code_eq_err {
+        ( error: $err_str:literal, $($error_code:tt)* ) => {
+            assert_eq!(
+                <ChainExtensionErrorCode as TryFrom<syn::ItemEnum>>::try_from(syn::parse_quote! {
+                    $( $error_code )*
+                })
+                .map_err(|err| err.to_string()),
+                Err(
+                    $err_str.to_string()
+                )
+            )
+        };
+    }
+
+    #[test]
+    fn generic_error_code_is_denied() {
+        assert_error_code_eq_err!(
+            error: "chain extension error codes must not be generic",
+            pub enum MyErrorCode<T> {
+                #[ink(status_code = 1)]
+                A,
+            }
+        );
+    }
+
+    #[test]
+    fn missing_attribute_is_denied() {
+        assert_error_code_eq_err!(
+            error: "missing #[ink(status_code = N: u32)] or #[ink(catch_all)] attribute \
+                    on chain extension error code variant",
+            pub enum MyErrorCode {
+                A,
+            }
+        );
+    }
+
+    #[test]
+    fn unsupported_attribute_is_denied() {
+        assert_error_code_eq_err!(
+            error: "encountered unsupported ink! attribute for chain extension error code variant. \
+                    expected #[ink(status_code = N: u32)] or #[ink(catch_all)] attribute",
+            pub enum MyErrorCode {
+                #[ink(extension = 1)]
+                A,
+            }
+        );
+    }
+
+    #[test]
+    fn conflicting_attributes_are_denied() {
+        assert_error_code_eq_err!(
+            error: "encountered conflicting ink! attribute argument",
+            pub enum MyErrorCode {
+                #[ink(status_code = 1, catch_all)]
+                A,
+            }
+        );
+    }
+
+    #[test]
+    fn zero_status_code_is_denied() {
+        assert_error_code_eq_err!(
+            error: "status code 0 is reserved for successful chain extension method calls",
+            pub enum MyErrorCode {
+                #[ink(status_code = 0)]
+                A,
+            }
+        );
+    }
+
+    #[test]
+    fn duplicate_status_codes_are_denied() {
+        assert_error_code_eq_err!(
+            error: "encountered duplicate status code 1 for chain extension error code",
+            pub enum MyErrorCode {
+                #[ink(status_code = 1)]
+                A,
+                #[ink(status_code = 1)]
+                B,
+            }
+        );
+    }
+
+    #[test]
+    fn status_code_variant_with_fields_is_denied() {
+        assert_error_code_eq_err!(
+            error: "chain extension error code variants with a status code must not have fields",
+            pub enum MyErrorCode {
+                #[ink(status_code = 1)]
+                A(u32),
+            }
+        );
+    }
+
+    #[test]
+    fn missing_catch_all_is_denied() {
+        assert_error_code_eq_err!(
+            error: "missing #[ink(catch_all)] variant for unknown status codes \
+                    of chain extension error code",
+            pub enum MyErrorCode {
+                #[ink(status_code = 1)]
+                A,
+            }
+        );
+    }
+
+    #[test]
+    fn duplicate_catch_all_is_denied() {
+        assert_error_code_eq_err!(
+            error: "encountered duplicate catch-all variant for chain extension error code",
+            pub enum MyErrorCode {
+                #[ink(catch_all)]
+                A,
+                #[ink(catch_all)]
+                B,
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_catch_all_fields_are_denied() {
+        assert_error_code_eq_err!(
+            error: "the field of the catch-all variant must be of type `u32`",
+            pub enum MyErrorCode {
+                #[ink(catch_all)]
+                A(bool),
+            }
+        );
+        assert_error_code_eq_err!(
+            error: "the catch-all variant must either have no fields or a single unnamed `u32` field",
+            pub enum MyErrorCode {
+                #[ink(catch_all)]
+                A { status_code: u32 },
+            }
+        );
+        assert_error_code_eq_err!(
+            error: "the catch-all variant must either have no fields or a single unnamed `u32` field",
+            pub enum MyErrorCode {
+                #[ink(catch_all)]
+                A(u32, u32),
+            }
+        );
+    }
+
+    #[test]
+    fn valid_error_code_works() {
+        let error_code = <ChainExtensionErrorCode as TryFrom<syn::ItemEnum>>::try_from(
+            syn::parse_quote! {
+                pub enum MyErrorCode {
+                    #[ink(status_code = 1)]
+                    InvalidKey,
+                    #[ink(status_code = 2)]
+                    CannotWriteToKey,
+                    #[ink(catch_all)]
+                    Unknown(u32),
+                }
+            },
+        )
+        .unwrap();
+        let variants = error_code
+            .iter_variants()
+            .map(|variant| (variant.ident().to_string(), variant.status_code()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            variants,
+            vec![
+                ("InvalidKey".to_string(), 1),
+                ("CannotWriteToKey".to_string(), 2)
+            ]
+        );
+        let catch_all = error_code.catch_all();
+        assert_eq!(catch_all.ident(), "Unknown");
+        assert!(catch_all.has_status_code_field());
+    }
+}
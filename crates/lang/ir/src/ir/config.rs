@@ -29,6 +29,28 @@ pub struct Config {
     /// be used to change the underlying environmental types of an ink! smart
     /// contract.
     env: Option<Environment>,
+    /// The type the generic parameter of the ink! storage struct is resolved to.
+    ///
+    /// If given the ink! storage struct and its implementation blocks may be
+    /// generic over exactly one type parameter which is then substituted by
+    /// this type.
+    generic_impl: Option<GenericImpl>,
+    /// The strategy for panics within the ink! smart contract.
+    panic: Option<PanicStrategy>,
+    /// If a `new_default` constructor is generated for the ink! storage struct.
+    ///
+    /// The generated constructor returns the `Default` value of the ink! storage
+    /// struct, which therefore must implement `Default`.
+    default_constructor: bool,
+    /// The maximum size in bytes of the ink! storage struct if configured.
+    ///
+    /// Exceeding this size bound fails compilation of the ink! smart contract.
+    max_root_size: Option<usize>,
+    /// The user defined metadata of the ink! smart contract if configured.
+    ///
+    /// This is a JSON object that is embedded into the `user` section of the
+    /// generated ink! metadata.
+    metadata: Option<String>,
     /// The set of attributes that can be passed to call builder in the codegen.
     whitelisted_attributes: WhitelistedAttributes,
 }
@@ -115,6 +137,11 @@ impl TryFrom<ast::AttributeArgs> for Config {
 
     fn try_from(args: ast::AttributeArgs) -> Result<Self, Self::Error> {
         let mut env: Option<(Environment, ast::MetaNameValue)> = None;
+        let mut generic_impl: Option<(GenericImpl, ast::MetaNameValue)> = None;
+        let mut panic: Option<(PanicStrategy, ast::MetaNameValue)> = None;
+        let mut default_constructor: Option<(bool, ast::MetaNameValue)> = None;
+        let mut max_root_size: Option<(usize, ast::MetaNameValue)> = None;
+        let mut metadata: Option<(String, ast::MetaNameValue)> = None;
         let mut whitelisted_attributes = WhitelistedAttributes::default();
 
         for arg in args.into_iter() {
@@ -130,6 +157,86 @@ impl TryFrom<ast::AttributeArgs> for Config {
                         "expected a path for `env` ink! configuration argument",
                     ))
                 }
+            } else if arg.name.is_ident("impl") {
+                if let Some((_, ast)) = generic_impl {
+                    return Err(duplicate_config_err(ast, arg, "impl"))
+                }
+                if let ast::PathOrLit::Path(path) = &arg.value {
+                    generic_impl = Some((GenericImpl { path: path.clone() }, arg))
+                } else {
+                    return Err(format_err_spanned!(
+                        arg,
+                        "expected a path for `impl` ink! configuration argument",
+                    ))
+                }
+            } else if arg.name.is_ident("panic") {
+                if let Some((_, ast)) = panic {
+                    return Err(duplicate_config_err(ast, arg, "panic"))
+                }
+                let strategy = match &arg.value {
+                    ast::PathOrLit::Lit(syn::Lit::Str(strategy)) => {
+                        match strategy.value().as_str() {
+                            "format" => Some(PanicStrategy::Format),
+                            "abort-silent" => Some(PanicStrategy::AbortSilent),
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                };
+                match strategy {
+                    Some(strategy) => panic = Some((strategy, arg)),
+                    None => {
+                        return Err(format_err_spanned!(
+                            arg,
+                            "expected \"format\" or \"abort-silent\" for `panic` ink! configuration argument",
+                        ))
+                    }
+                }
+            } else if arg.name.is_ident("default_constructor") {
+                if let Some((_, ast)) = default_constructor {
+                    return Err(duplicate_config_err(ast, arg, "default_constructor"))
+                }
+                if let ast::PathOrLit::Lit(syn::Lit::Bool(lit_bool)) = &arg.value {
+                    default_constructor = Some((lit_bool.value, arg))
+                } else {
+                    return Err(format_err_spanned!(
+                        arg,
+                        "expected a bool literal for `default_constructor` ink! configuration argument",
+                    ))
+                }
+            } else if arg.name.is_ident("max_root_size") {
+                if let Some((_, ast)) = max_root_size {
+                    return Err(duplicate_config_err(ast, arg, "max_root_size"))
+                }
+                if let ast::PathOrLit::Lit(syn::Lit::Int(lit_int)) = &arg.value {
+                    let size = lit_int.base10_parse::<usize>()?;
+                    max_root_size = Some((size, arg))
+                } else {
+                    return Err(format_err_spanned!(
+                        arg,
+                        "expected an integer for `max_root_size` ink! configuration argument",
+                    ))
+                }
+            } else if arg.name.is_ident("metadata") {
+                if let Some((_, ast)) = metadata {
+                    return Err(duplicate_config_err(ast, arg, "metadata"))
+                }
+                match &arg.value {
+                    ast::PathOrLit::Lit(syn::Lit::Str(json))
+                        if serde_json::from_str::<
+                            serde_json::Map<String, serde_json::Value>,
+                        >(&json.value())
+                        .is_ok() =>
+                    {
+                        metadata = Some((json.value(), arg))
+                    }
+                    _ => {
+                        return Err(format_err_spanned!(
+                            arg,
+                            "expected a string containing a JSON object for `metadata` ink! configuration argument",
+                        ))
+                    }
+                }
             } else if arg.name.is_ident("keep_attr") {
                 if let Err(err) = whitelisted_attributes.parse_arg_value(&arg) {
                     return Err(err)
@@ -143,6 +250,13 @@ impl TryFrom<ast::AttributeArgs> for Config {
         }
         Ok(Config {
             env: env.map(|(value, _)| value),
+            generic_impl: generic_impl.map(|(value, _)| value),
+            panic: panic.map(|(value, _)| value),
+            default_constructor: default_constructor
+                .map(|(value, _)| value)
+                .unwrap_or(false),
+            max_root_size: max_root_size.map(|(value, _)| value),
+            metadata: metadata.map(|(value, _)| value),
             whitelisted_attributes,
         })
     }
@@ -160,6 +274,38 @@ impl Config {
             .unwrap_or(Environment::default().path)
     }
 
+    /// Returns the type that the generic parameter of the ink! storage struct
+    /// is resolved to if specified.
+    pub fn generic_impl(&self) -> Option<&syn::Path> {
+        self.generic_impl
+            .as_ref()
+            .map(|generic_impl| &generic_impl.path)
+    }
+
+    /// Returns the strategy for panics within the ink! smart contract.
+    ///
+    /// Returns [`PanicStrategy::Format`] if not specified.
+    pub fn panic_strategy(&self) -> PanicStrategy {
+        self.panic.unwrap_or_default()
+    }
+
+    /// Returns `true` if a `new_default` constructor is generated for the ink!
+    /// storage struct.
+    pub fn default_constructor(&self) -> bool {
+        self.default_constructor
+    }
+
+    /// Returns the maximum size in bytes of the ink! storage struct if specified.
+    pub fn max_root_size(&self) -> Option<usize> {
+        self.max_root_size
+    }
+
+    /// Returns the user defined metadata of the ink! smart contract as JSON object
+    /// if specified.
+    pub fn metadata(&self) -> Option<&str> {
+        self.metadata.as_deref()
+    }
+
     /// Return set of attributes that can be passed to call builder in the codegen.
     pub fn whitelisted_attributes(&self) -> &WhitelistedAttributes {
         &self.whitelisted_attributes
@@ -181,6 +327,29 @@ impl Default for Environment {
     }
 }
 
+/// The type a generic ink! smart contract is instantiated with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenericImpl {
+    /// The underlying Rust type.
+    pub path: syn::Path,
+}
+
+/// The strategy for panics within an ink! smart contract.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum PanicStrategy {
+    /// Panics format their message as usual.
+    #[default]
+    Format,
+    /// Panics abort the execution immediately without formatting their message.
+    ///
+    /// Panic messages are still formatted if the `std` or `ink-debug` features
+    /// of `ink_lang` are enabled.
+    ///
+    /// This only applies to the panicking macros used at the ink! module root,
+    /// not to panics raised by `core` such as failing `unwrap` calls.
+    AbortSilent,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -213,6 +382,11 @@ mod tests {
                 env: Some(Environment {
                     path: syn::parse_quote! { ::my::env::Types },
                 }),
+                generic_impl: None,
+                panic: None,
+                default_constructor: false,
+                max_root_size: None,
+                metadata: None,
                 whitelisted_attributes: Default::default(),
             }),
         )
@@ -226,6 +400,172 @@ mod tests {
         );
     }
 
+    #[test]
+    fn generic_impl_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                impl = MyPolicy
+            },
+            Ok(Config {
+                env: None,
+                generic_impl: Some(GenericImpl {
+                    path: syn::parse_quote! { MyPolicy },
+                }),
+                panic: None,
+                default_constructor: false,
+                max_root_size: None,
+                metadata: None,
+                whitelisted_attributes: Default::default(),
+            }),
+        )
+    }
+
+    #[test]
+    fn generic_impl_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { impl = "invalid" },
+            Err("expected a path for `impl` ink! configuration argument"),
+        );
+    }
+
+    #[test]
+    fn duplicate_generic_impl_fails() {
+        assert_try_from(
+            syn::parse_quote! {
+                impl = MyPolicy,
+                impl = MyOtherPolicy,
+            },
+            Err("encountered duplicate ink! `impl` configuration argument"),
+        );
+    }
+
+    #[test]
+    fn panic_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                panic = "abort-silent"
+            },
+            Ok(Config {
+                env: None,
+                generic_impl: None,
+                panic: Some(PanicStrategy::AbortSilent),
+                default_constructor: false,
+                max_root_size: None,
+                metadata: None,
+                whitelisted_attributes: Default::default(),
+            }),
+        );
+        assert_try_from(
+            syn::parse_quote! {
+                panic = "format"
+            },
+            Ok(Config {
+                env: None,
+                generic_impl: None,
+                panic: Some(PanicStrategy::Format),
+                default_constructor: false,
+                max_root_size: None,
+                metadata: None,
+                whitelisted_attributes: Default::default(),
+            }),
+        );
+        assert_eq!(Config::default().panic_strategy(), PanicStrategy::Format);
+    }
+
+    #[test]
+    fn default_constructor_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                default_constructor = true
+            },
+            Ok(Config {
+                env: None,
+                generic_impl: None,
+                panic: None,
+                default_constructor: true,
+                max_root_size: None,
+                metadata: None,
+                whitelisted_attributes: Default::default(),
+            }),
+        )
+    }
+
+    #[test]
+    fn default_constructor_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { default_constructor = "yes" },
+            Err("expected a bool literal for `default_constructor` ink! configuration argument"),
+        );
+    }
+
+    #[test]
+    fn max_root_size_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                max_root_size = 16384
+            },
+            Ok(Config {
+                env: None,
+                generic_impl: None,
+                panic: None,
+                default_constructor: false,
+                max_root_size: Some(16384),
+                metadata: None,
+                whitelisted_attributes: Default::default(),
+            }),
+        )
+    }
+
+    #[test]
+    fn max_root_size_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { max_root_size = "16kB" },
+            Err("expected an integer for `max_root_size` ink! configuration argument"),
+        );
+        assert_try_from(
+            syn::parse_quote! { max_root_size = -1 },
+            Err("invalid digit found in string"),
+        );
+    }
+
+    #[test]
+    fn metadata_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                metadata = r#"{ "audit": { "report": "0x1234" } }"#
+            },
+            Ok(Config {
+                env: None,
+                generic_impl: None,
+                panic: None,
+                default_constructor: false,
+                max_root_size: None,
+                metadata: Some(r#"{ "audit": { "report": "0x1234" } }"#.to_string()),
+                whitelisted_attributes: Default::default(),
+            }),
+        )
+    }
+
+    #[test]
+    fn metadata_invalid_value_fails() {
+        let expected = "expected a string containing a JSON object for `metadata` ink! configuration argument";
+        assert_try_from(syn::parse_quote! { metadata = "[1, 2]" }, Err(expected));
+        assert_try_from(syn::parse_quote! { metadata = "{ invalid" }, Err(expected));
+        assert_try_from(syn::parse_quote! { metadata = 42 }, Err(expected));
+    }
+
+    #[test]
+    fn panic_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { panic = "unwind" },
+            Err("expected \"format\" or \"abort-silent\" for `panic` ink! configuration argument"),
+        );
+        assert_try_from(
+            syn::parse_quote! { panic = abort },
+            Err("expected \"format\" or \"abort-silent\" for `panic` ink! configuration argument"),
+        );
+    }
+
     #[test]
     fn unknown_arg_fails() {
         assert_try_from(
@@ -256,6 +596,11 @@ mod tests {
             },
             Ok(Config {
                 env: None,
+                generic_impl: None,
+                panic: None,
+                default_constructor: false,
+                max_root_size: None,
+                metadata: None,
                 whitelisted_attributes: attrs,
             }),
         )
@@ -41,6 +41,8 @@ pub struct Contract {
     item: ir::ItemMod,
     /// The specified ink! configuration.
     config: ir::Config,
+    /// The resolved generic parameter of the ink! storage struct if any.
+    generic: Option<ir::GenericContract>,
 }
 
 impl Contract {
@@ -63,15 +65,57 @@ impl Contract {
         ink_module: TokenStream2,
     ) -> Result<Self, syn::Error> {
         let config = syn::parse2::<ast::AttributeArgs>(ink_config)?;
-        let module = syn::parse2::<syn::ItemMod>(ink_module)?;
+        let mut module = syn::parse2::<syn::ItemMod>(ink_module)?;
         let ink_config = ir::Config::try_from(config)?;
+        let generic = ink_config
+            .generic_impl()
+            .map(|impl_type| {
+                ir::GenericContract::instantiate(&mut module, impl_type.clone())
+            })
+            .transpose()?;
+        if ink_config.default_constructor() {
+            Self::inject_default_constructor(&mut module)?;
+        }
         let ink_module = ir::ItemMod::try_from(module)?;
         Ok(Self {
             item: ink_module,
             config: ink_config,
+            generic,
         })
     }
 
+    /// Appends the `new_default` ink! constructor to the given ink! module.
+    ///
+    /// # Note
+    ///
+    /// Missing ink! storage structs are left to be reported by [`ir::ItemMod`].
+    fn inject_default_constructor(module: &mut syn::ItemMod) -> Result<(), syn::Error> {
+        let items = match &mut module.content {
+            Some((_, items)) => items,
+            None => return Ok(()),
+        };
+        let mut storage_ident = None;
+        for item in items.iter() {
+            if let Some(ident) = ir::Storage::ink_storage_ident(item)? {
+                storage_ident = Some(ident.clone());
+                break
+            }
+        }
+        if let Some(storage_ident) = storage_ident {
+            let span = storage_ident.span();
+            items.push(syn::parse_quote_spanned!(span=>
+                impl #storage_ident {
+                    /// Creates the ink! smart contract from the `Default` value of its storage.
+                    #[ink(constructor)]
+                    pub fn new_default() -> Self {
+                        <Self as ::core::default::Default>::default()
+                    }
+                }
+            ));
+        }
+        Ok(())
+    }
+
     /// Returns the ink! inline module definition.
     ///
     /// # Note
@@ -99,10 +143,51 @@ impl Contract {
     ///
     /// - `types`: To specify `Environment` different from the default environment
     ///            types.
+    /// - `impl`: To specify the type that the generic parameter of the ink!
+    ///   storage struct is resolved to.
     ///
     /// Note that we might add more configuration fields in the future if
     /// necessary.
     pub fn config(&self) -> &ir::Config {
         &self.config
     }
+
+    /// Returns the resolved generic parameter of the ink! storage struct if the
+    /// ink! smart contract was given the `impl` configuration argument.
+    pub fn generic(&self) -> Option<&ir::GenericContract> {
+        self.generic.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Callable as _;
+
+    #[test]
+    fn default_constructor_works() {
+        let contract = Contract::new(
+            quote::quote! { default_constructor = true },
+            quote::quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    #[derive(Default)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+                }
+            },
+        )
+        .unwrap();
+        let constructors = contract
+            .module()
+            .impls()
+            .flat_map(ir::ItemImpl::iter_constructors)
+            .map(|constructor| constructor.ident().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(constructors, vec!["new_default".to_string()]);
+    }
 }
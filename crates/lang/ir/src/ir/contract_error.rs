@@ -0,0 +1,333 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    error::ExtError,
+    ir,
+    ir::IsDocAttribute as _,
+};
+use core::slice::Iter as SliceIter;
+use proc_macro2::TokenStream as TokenStream2;
+use std::collections::HashMap;
+use syn::{
+    spanned::Spanned as _,
+    Result,
+};
+
+/// A contract error defined via `#[derive(ContractError)]`.
+///
+/// Assigns stable error codes to the variants of an enum. The error code of a
+/// variant is its SCALE encoded index and therefore does not change if variants
+/// are reordered, added or removed.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ContractError {
+    item: syn::ItemEnum,
+    variants: Vec<ContractErrorVariant>,
+    env_error: Option<syn::Ident>,
+}
+
+/// A variant of a contract error flagged with `#[ink(error_code = N: u8)]`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ContractErrorVariant {
+    ident: syn::Ident,
+    error_code: u8,
+    fields: syn::Fields,
+    docs: Vec<String>,
+}
+
+impl ContractErrorVariant {
+    /// Returns the identifier of the variant.
+    pub fn ident(&self) -> &syn::Ident {
+        &self.ident
+    }
+
+    /// Returns the stable error code of the variant.
+    pub fn error_code(&self) -> u8 {
+        self.error_code
+    }
+
+    /// Returns the fields of the variant.
+    pub fn fields(&self) -> &syn::Fields {
+        &self.fields
+    }
+
+    /// Returns the documentation of the variant.
+    pub fn docs(&self) -> &[String] {
+        &self.docs
+    }
+}
+
+impl ContractError {
+    /// Returns `Ok` if the enum matches all requirements for a contract error.
+    pub fn new(input: TokenStream2) -> Result<Self> {
+        let item_enum = syn::parse2::<syn::ItemEnum>(input)?;
+        ContractError::try_from(item_enum)
+    }
+
+    /// Returns the span of the contract error.
+    pub fn span(&self) -> proc_macro2::Span {
+        self.item.span()
+    }
+
+    /// Returns the identifier of the contract error.
+    pub fn ident(&self) -> &proc_macro2::Ident {
+        &self.item.ident
+    }
+
+    /// Returns the documentation of the contract error.
+    pub fn docs(&self) -> Vec<String> {
+        self.item
+            .attrs
+            .iter()
+            .filter_map(|attr| attr.extract_docs())
+            .collect()
+    }
+
+    /// Returns a slice over all variants of the contract error.
+    pub fn iter_variants(&self) -> SliceIter<'_, ContractErrorVariant> {
+        self.variants.iter()
+    }
+
+    /// Returns the identifier of the variant flagged with `#[ink(env_error)]` if any.
+    pub fn env_error(&self) -> Option<&syn::Ident> {
+        self.env_error.as_ref()
+    }
+}
+
+impl TryFrom<syn::ItemEnum> for ContractError {
+    type Error = syn::Error;
+
+    fn try_from(item_enum: syn::ItemEnum) -> core::result::Result<Self, Self::Error> {
+        if !item_enum.generics.params.is_empty() {
+            return Err(format_err_spanned!(
+                item_enum.generics.params,
+                "contract errors must not be generic"
+            ))
+        }
+        let mut variants = Vec::new();
+        let mut env_error: Option<&syn::Variant> = None;
+        let mut seen_codes: HashMap<u8, &syn::Variant> = HashMap::new();
+        for variant in &item_enum.variants {
+            if ir::first_ink_attribute(&variant.attrs)?.is_none() {
+                return Err(format_err_spanned!(
+                    variant,
+                    "missing #[ink(error_code = N: u8)] attribute on contract error variant"
+                ))
+            }
+            let (ink_attrs, other_attrs) = ir::sanitize_attributes(
+                variant.span(),
+                variant.attrs.clone(),
+                &ir::AttributeArgKind::ErrorCode,
+                |arg| {
+                    match arg.kind() {
+                        ir::AttributeArg::ErrorCode(_) | ir::AttributeArg::EnvError => {
+                            Ok(())
+                        }
+                        _ => Err(None),
+                    }
+                },
+            )?;
+            let error_code = ink_attrs
+                .args()
+                .find_map(|arg| {
+                    if let ir::AttributeArg::ErrorCode(code) = arg.kind() {
+                        return Some(*code)
+                    }
+                    None
+                })
+                .expect("encountered missing error code of contract error variant");
+            if let Some(previous) = seen_codes.get(&error_code) {
+                return Err(format_err_spanned!(
+                    variant,
+                    "encountered duplicate error code {} for contract error",
+                    error_code,
+                )
+                .into_combine(format_err_spanned!(
+                    previous,
+                    "first variant with the same error code here"
+                )))
+            }
+            seen_codes.insert(error_code, variant);
+            if ink_attrs
+                .args()
+                .any(|arg| matches!(arg.kind(), ir::AttributeArg::EnvError))
+            {
+                if let Some(previous) = env_error {
+                    return Err(format_err_spanned!(
+                        variant,
+                        "encountered duplicate #[ink(env_error)] variant for contract error"
+                    )
+                    .into_combine(format_err_spanned!(
+                        previous,
+                        "first #[ink(env_error)] variant here"
+                    )))
+                }
+                if !matches!(variant.fields, syn::Fields::Unit) {
+                    return Err(format_err_spanned!(
+                        variant.fields,
+                        "the #[ink(env_error)] variant of contract errors must not have fields"
+                    ))
+                }
+                env_error = Some(variant);
+            }
+            variants.push(ContractErrorVariant {
+                ident: variant.ident.clone(),
+                error_code,
+                fields: variant.fields.clone(),
+                docs: other_attrs
+                    .iter()
+                    .filter_map(|attr| attr.extract_docs())
+                    .collect(),
+            });
+        }
+        let env_error = env_error.map(|variant| variant.ident.clone());
+        Ok(Self {
+            item: item_enum,
+            variants,
+            env_error,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks if the token stream in `$contract_error` results in the expected error message.
+    macro_rules! assert_contract_error_eq_err {
+        ( error: $err_str:literal, $($contract_error:tt)* ) => {
+            assert_eq!(
+                <ContractError as TryFrom<syn::ItemEnum>>::try_from(syn::parse_quote! {
+                    $( $contract_error )*
+                })
+                .map_err(|err| err.to_string()),
+                Err(
+                    $err_str.to_string()
+                )
+            )
+        };
+    }
+
+    #[test]
+    fn generic_contract_error_is_denied() {
+        assert_contract_error_eq_err!(
+            error: "contract errors must not be generic",
+            pub enum Error<T> {
+                #[ink(error_code = 1)]
+                A(T),
+            }
+        );
+    }
+
+    #[test]
+    fn missing_attribute_is_denied() {
+        assert_contract_error_eq_err!(
+            error: "missing #[ink(error_code = N: u8)] attribute on contract error variant",
+            pub enum Error {
+                A,
+            }
+        );
+    }
+
+    #[test]
+    fn unsupported_attribute_is_denied() {
+        assert_contract_error_eq_err!(
+            error: "encountered conflicting ink! attribute argument",
+            pub enum Error {
+                #[ink(error_code = 1, catch_all)]
+                A,
+            }
+        );
+        assert_contract_error_eq_err!(
+            error: "unexpected first ink! attribute argument",
+            pub enum Error {
+                #[ink(env_error)]
+                A,
+            }
+        );
+    }
+
+    #[test]
+    fn duplicate_error_codes_are_denied() {
+        assert_contract_error_eq_err!(
+            error: "encountered duplicate error code 1 for contract error",
+            pub enum Error {
+                #[ink(error_code = 1)]
+                A,
+                #[ink(error_code = 1)]
+                B,
+            }
+        );
+    }
+
+    #[test]
+    fn duplicate_env_error_is_denied() {
+        assert_contract_error_eq_err!(
+            error: "encountered duplicate #[ink(env_error)] variant for contract error",
+            pub enum Error {
+                #[ink(error_code = 1, env_error)]
+                A,
+                #[ink(error_code = 2, env_error)]
+                B,
+            }
+        );
+    }
+
+    #[test]
+    fn env_error_with_fields_is_denied() {
+        assert_contract_error_eq_err!(
+            error: "the #[ink(env_error)] variant of contract errors must not have fields",
+            pub enum Error {
+                #[ink(error_code = 1, env_error)]
+                A(u32),
+            }
+        );
+    }
+
+    #[test]
+    fn valid_contract_error_works() {
+        let contract_error =
+            <ContractError as TryFrom<syn::ItemEnum>>::try_from(syn::parse_quote! {
+                /// The errors of the contract.
+                pub enum Error {
+                    /// The balance is insufficient.
+                    #[ink(error_code = 1)]
+                    InsufficientBalance { required: u128 },
+                    #[ink(error_code = 3)]
+                    Custom(u32),
+                    #[ink(error_code = 2, env_error)]
+                    Env,
+                }
+            })
+            .unwrap();
+        let variants = contract_error
+            .iter_variants()
+            .map(|variant| (variant.ident().to_string(), variant.error_code()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            variants,
+            vec![
+                ("InsufficientBalance".to_string(), 1),
+                ("Custom".to_string(), 3),
+                ("Env".to_string(), 2),
+            ]
+        );
+        assert_eq!(contract_error.docs(), vec![" The errors of the contract."]);
+        assert_eq!(
+            contract_error.iter_variants().next().unwrap().docs(),
+            &[" The balance is insufficient.".to_string()]
+        );
+        assert_eq!(contract_error.env_error().unwrap(), "Env");
+    }
+}
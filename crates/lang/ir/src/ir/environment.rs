@@ -0,0 +1,334 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::error::ExtError as _;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{
+    parse::{
+        Parse,
+        ParseStream,
+    },
+    punctuated::Punctuated,
+    Token,
+};
+
+/// A custom environment definition for ink! smart contracts.
+///
+/// Noticed by ink! through the `#[ink::environment]` annotation on a struct or enum
+/// definition. All types of the environment that are not configured are equal to
+/// the respective types of the default environment.
+///
+/// # Example
+///
+/// ```
+/// # let env = <ink_lang_ir::InkEnvironment>::new(
+/// #     quote::quote! {
+/// account_id = [u8; 16],
+/// balance = u64,
+/// max_event_topics = 3
+/// #     },
+/// #     quote::quote! {
+/// pub enum MyEnvironment {}
+/// # }).unwrap();
+/// # assert_eq!(env.max_event_topics(), Some(3));
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct InkEnvironment {
+    /// The struct or enum definition implementing the environment.
+    item: syn::Item,
+    /// The maximum number of topics of ink! events if configured.
+    max_event_topics: Option<usize>,
+    /// The configured types of the environment.
+    types: Vec<(EnvironmentType, syn::Type)>,
+}
+
+/// The types of an ink! environment that can be configured.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EnvironmentType {
+    /// The `AccountId` type configured via `account_id = T`.
+    AccountId,
+    /// The `Balance` type configured via `balance = T`.
+    Balance,
+    /// The `Hash` type configured via `hash = T`.
+    Hash,
+    /// The `Timestamp` type configured via `timestamp = T`.
+    Timestamp,
+    /// The `BlockNumber` type configured via `block_number = T`.
+    BlockNumber,
+    /// The `ChainExtension` type configured via `chain_extension = T`.
+    ChainExtension,
+}
+
+impl EnvironmentType {
+    /// All configurable types of an ink! environment.
+    pub const ALL: [Self; 6] = [
+        Self::AccountId,
+        Self::Balance,
+        Self::Hash,
+        Self::Timestamp,
+        Self::BlockNumber,
+        Self::ChainExtension,
+    ];
+
+    /// Returns the name of the configuration argument of the type.
+    pub fn arg_name(self) -> &'static str {
+        match self {
+            Self::AccountId => "account_id",
+            Self::Balance => "balance",
+            Self::Hash => "hash",
+            Self::Timestamp => "timestamp",
+            Self::BlockNumber => "block_number",
+            Self::ChainExtension => "chain_extension",
+        }
+    }
+
+    /// Returns the identifier of the associated type of the `Environment` trait.
+    pub fn assoc_type_name(self) -> &'static str {
+        match self {
+            Self::AccountId => "AccountId",
+            Self::Balance => "Balance",
+            Self::Hash => "Hash",
+            Self::Timestamp => "Timestamp",
+            Self::BlockNumber => "BlockNumber",
+            Self::ChainExtension => "ChainExtension",
+        }
+    }
+}
+
+/// A single `name = value` configuration argument of `#[ink::environment]`.
+struct EnvironmentArg {
+    name: syn::Ident,
+    value: EnvironmentArgValue,
+}
+
+/// The value of a configuration argument of `#[ink::environment]`.
+enum EnvironmentArgValue {
+    Int(syn::LitInt),
+    Type(Box<syn::Type>),
+}
+
+impl Parse for EnvironmentArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse::<syn::Ident>()?;
+        input.parse::<Token![=]>()?;
+        let value = if input.peek(syn::LitInt) {
+            EnvironmentArgValue::Int(input.parse()?)
+        } else {
+            EnvironmentArgValue::Type(Box::new(input.parse()?))
+        };
+        Ok(Self { name, value })
+    }
+}
+
+impl InkEnvironment {
+    /// Returns `Ok` if the input matches all requirements for an ink! environment.
+    pub fn new(attr: TokenStream2, input: TokenStream2) -> Result<Self, syn::Error> {
+        let args = syn::parse::Parser::parse2(
+            Punctuated::<EnvironmentArg, Token![,]>::parse_terminated,
+            attr,
+        )?;
+        let mut max_event_topics: Option<usize> = None;
+        let mut types: Vec<(EnvironmentType, syn::Type)> = Vec::new();
+        let mut seen: Vec<&syn::Ident> = Vec::new();
+        for arg in &args {
+            if let Some(first) = seen.iter().find(|seen| **seen == &arg.name) {
+                return Err(format_err_spanned!(
+                    arg.name,
+                    "encountered duplicate ink! environment configuration argument `{}`",
+                    arg.name,
+                )
+                .into_combine(format_err_spanned!(first, "first argument here")))
+            }
+            seen.push(&arg.name);
+            if arg.name == "max_event_topics" {
+                let value = match &arg.value {
+                    EnvironmentArgValue::Int(lit_int) => lit_int.base10_parse::<usize>()?,
+                    EnvironmentArgValue::Type(ty) => {
+                        return Err(format_err_spanned!(
+                            ty,
+                            "expected an integer for `max_event_topics` ink! environment configuration argument",
+                        ))
+                    }
+                };
+                max_event_topics = Some(value);
+                continue
+            }
+            let kind = EnvironmentType::ALL
+                .into_iter()
+                .find(|kind| arg.name == kind.arg_name())
+                .ok_or_else(|| {
+                    format_err_spanned!(
+                        arg.name,
+                        "encountered unknown ink! environment configuration argument `{}`",
+                        arg.name,
+                    )
+                })?;
+            match &arg.value {
+                EnvironmentArgValue::Type(ty) => types.push((kind, (**ty).clone())),
+                EnvironmentArgValue::Int(lit_int) => {
+                    return Err(format_err_spanned!(
+                    lit_int,
+                    "expected a type for `{}` ink! environment configuration argument",
+                    arg.name,
+                ))
+                }
+            }
+        }
+        let item = syn::parse2::<syn::Item>(input)?;
+        let generics = match &item {
+            syn::Item::Struct(item_struct) => &item_struct.generics,
+            syn::Item::Enum(item_enum) => &item_enum.generics,
+            _ => {
+                return Err(format_err_spanned!(
+                    item,
+                    "ink! environments must be defined by a struct or enum",
+                ))
+            }
+        };
+        if !generics.params.is_empty() {
+            return Err(format_err_spanned!(
+                generics.params,
+                "generic ink! environments are not supported",
+            ))
+        }
+        Ok(Self {
+            item,
+            max_event_topics,
+            types,
+        })
+    }
+
+    /// Returns the struct or enum definition implementing the environment.
+    pub fn item(&self) -> &syn::Item {
+        &self.item
+    }
+
+    /// Returns the identifier of the environment.
+    pub fn ident(&self) -> &syn::Ident {
+        match &self.item {
+            syn::Item::Struct(item_struct) => &item_struct.ident,
+            syn::Item::Enum(item_enum) => &item_enum.ident,
+            _ => unreachable!("ink! environments are either structs or enums"),
+        }
+    }
+
+    /// Returns the maximum number of topics of ink! events if configured.
+    pub fn max_event_topics(&self) -> Option<usize> {
+        self.max_event_topics
+    }
+
+    /// Returns the configured type of the given kind if any.
+    pub fn get(&self, kind: EnvironmentType) -> Option<&syn::Type> {
+        self.types
+            .iter()
+            .find_map(|(configured, ty)| (*configured == kind).then_some(ty))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_new_fails(attr: TokenStream2, input: TokenStream2, expected: &str) {
+        assert_eq!(
+            InkEnvironment::new(attr, input).map_err(|err| err.to_string()),
+            Err(expected.to_string()),
+        )
+    }
+
+    #[test]
+    fn new_works() {
+        let env = InkEnvironment::new(
+            quote::quote! {
+                account_id = [u8; 16],
+                chain_extension = MyChainExtension,
+                max_event_topics = 3,
+            },
+            quote::quote! {
+                pub struct MyEnvironment;
+            },
+        )
+        .unwrap();
+        assert_eq!(env.ident(), "MyEnvironment");
+        assert_eq!(env.max_event_topics(), Some(3));
+        assert_eq!(
+            env.get(EnvironmentType::AccountId),
+            Some(&syn::parse_quote! { [u8; 16] })
+        );
+        assert_eq!(
+            env.get(EnvironmentType::ChainExtension),
+            Some(&syn::parse_quote! { MyChainExtension })
+        );
+        assert_eq!(env.get(EnvironmentType::Balance), None);
+    }
+
+    #[test]
+    fn empty_config_works() {
+        let env = InkEnvironment::new(
+            quote::quote! {},
+            quote::quote! { enum MyEnvironment {} },
+        )
+        .unwrap();
+        assert_eq!(env.max_event_topics(), None);
+        assert!(EnvironmentType::ALL
+            .into_iter()
+            .all(|kind| env.get(kind).is_none()));
+    }
+
+    #[test]
+    fn unknown_arg_fails() {
+        assert_new_fails(
+            quote::quote! { gas = u64 },
+            quote::quote! { pub enum MyEnvironment {} },
+            "encountered unknown ink! environment configuration argument `gas`",
+        )
+    }
+
+    #[test]
+    fn duplicate_arg_fails() {
+        assert_new_fails(
+            quote::quote! { balance = u64, balance = u128 },
+            quote::quote! { pub enum MyEnvironment {} },
+            "encountered duplicate ink! environment configuration argument `balance`",
+        )
+    }
+
+    #[test]
+    fn invalid_arg_values_fail() {
+        assert_new_fails(
+            quote::quote! { max_event_topics = u32 },
+            quote::quote! { pub enum MyEnvironment {} },
+            "expected an integer for `max_event_topics` ink! environment configuration argument",
+        );
+        assert_new_fails(
+            quote::quote! { balance = 128 },
+            quote::quote! { pub enum MyEnvironment {} },
+            "expected a type for `balance` ink! environment configuration argument",
+        );
+    }
+
+    #[test]
+    fn invalid_item_fails() {
+        assert_new_fails(
+            quote::quote! {},
+            quote::quote! { type MyEnvironment = (); },
+            "ink! environments must be defined by a struct or enum",
+        );
+        assert_new_fails(
+            quote::quote! {},
+            quote::quote! { pub struct MyEnvironment<T>(T); },
+            "generic ink! environments are not supported",
+        );
+    }
+}
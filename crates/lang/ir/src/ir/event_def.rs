@@ -0,0 +1,171 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    ir,
+    ir::idents_lint,
+};
+use proc_macro2::TokenStream as TokenStream2;
+
+/// An ink! event definition outside of an ink! smart contract.
+///
+/// Noticed by ink! through the `#[ink::event]` annotation.
+///
+/// # Note
+///
+/// Shared ink! event definitions can be emitted by all ink! smart contracts
+/// that import them via `#[ink(event)] use path::to::Event;`. Unlike ink! events
+/// defined within an ink! smart contract their topics are not prefixed with the
+/// name of the emitting ink! smart contract.
+///
+/// # Example
+///
+/// ```
+/// # let event_def = <ink_lang_ir::InkEventDefinition>::new(
+/// #     quote::quote! {},
+/// #     quote::quote! {
+/// pub struct Transfer {
+///     #[ink(topic)]
+///     from: Option<AccountId>,
+///     #[ink(topic)]
+///     to: Option<AccountId>,
+///     value: Balance,
+/// }
+/// # }).unwrap();
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct InkEventDefinition {
+    event: ir::Event,
+}
+
+impl TryFrom<syn::ItemStruct> for InkEventDefinition {
+    type Error = syn::Error;
+
+    fn try_from(mut item_struct: syn::ItemStruct) -> Result<Self, Self::Error> {
+        idents_lint::ensure_no_ink_identifiers(&item_struct)?;
+        item_struct
+            .attrs
+            .insert(0, syn::parse_quote! { #[ink(event)] });
+        let event = <ir::Event as TryFrom<_>>::try_from(item_struct)?;
+        Ok(Self { event })
+    }
+}
+
+impl InkEventDefinition {
+    /// Returns `Ok` if the input matches all requirements for an ink! event definition.
+    pub fn new(attr: TokenStream2, input: TokenStream2) -> Result<Self, syn::Error> {
+        if !attr.is_empty() {
+            return Err(format_err_spanned!(
+                attr,
+                "unexpected attribute input for ink! event definition"
+            ))
+        }
+        let item_struct = syn::parse2::<syn::ItemStruct>(input)?;
+        InkEventDefinition::try_from(item_struct)
+    }
+
+    /// Returns the ink! event of the ink! event definition.
+    pub fn event(&self) -> &ir::Event {
+        &self.event
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Asserts that the given ink! event definition input fails with the expected error.
+    fn assert_try_from_fails(
+        attr: TokenStream2,
+        input: TokenStream2,
+        expected_err: &str,
+    ) {
+        assert_eq!(
+            InkEventDefinition::new(attr, input).map_err(|err| err.to_string()),
+            Err(expected_err.to_string()),
+        )
+    }
+
+    #[test]
+    fn simple_try_from_works() {
+        let event_def = InkEventDefinition::new(
+            quote::quote! {},
+            quote::quote! {
+                pub struct MyEvent {
+                    #[ink(topic)]
+                    field_1: i32,
+                    field_2: bool,
+                }
+            },
+        )
+        .unwrap();
+        assert_eq!(event_def.event().ident(), "MyEvent");
+        assert!(!event_def.event().anonymous);
+        let topics = event_def
+            .event()
+            .fields()
+            .map(|field| field.is_topic)
+            .collect::<Vec<_>>();
+        assert_eq!(topics, vec![true, false]);
+    }
+
+    #[test]
+    fn anonymous_try_from_works() {
+        let event_def = InkEventDefinition::new(
+            quote::quote! {},
+            quote::quote! {
+                #[ink(anonymous)]
+                pub struct MyEvent {
+                    field_1: i32,
+                }
+            },
+        )
+        .unwrap();
+        assert!(event_def.event().anonymous);
+    }
+
+    #[test]
+    fn attribute_input_fails() {
+        assert_try_from_fails(
+            quote::quote! { anonymous },
+            quote::quote! {
+                pub struct MyEvent {}
+            },
+            "unexpected attribute input for ink! event definition",
+        );
+    }
+
+    #[test]
+    fn non_pub_visibility_fails() {
+        assert_try_from_fails(
+            quote::quote! {},
+            quote::quote! {
+                struct MyEvent {}
+            },
+            "non `pub` ink! event structs are not supported",
+        );
+    }
+
+    #[test]
+    fn redundant_event_attribute_fails() {
+        assert_try_from_fails(
+            quote::quote! {},
+            quote::quote! {
+                #[ink(event)]
+                pub struct MyEvent {}
+            },
+            "encountered duplicate ink! attribute",
+        );
+    }
+}
@@ -0,0 +1,145 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use proc_macro2::TokenStream as TokenStream2;
+
+/// An ink! implementation block exported by a library crate via `#[ink::export_impl]`.
+///
+/// The implementation block is not compiled within the library crate. Instead it is
+/// exported as the extension point `name` which ink! smart contracts of other crates
+/// include via `#[ink::include_impl(path::to::name)]`. The self type of the
+/// implementation block is then replaced by the ink! storage struct of the including
+/// ink! smart contract.
+///
+/// # Example
+///
+/// ```
+/// # let export = <ink_lang_ir::InkExportImpl>::new(
+/// #     quote::quote! { flip_impl },
+/// #     quote::quote! {
+/// impl Flip for Contract {
+///     #[ink(message)]
+///     fn flip(&mut self) {
+///         self.value = !self.value;
+///     }
+/// }
+/// # }).unwrap();
+/// # assert_eq!(export.ident(), "flip_impl");
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct InkExportImpl {
+    /// The name of the extension point.
+    ident: syn::Ident,
+    /// The exported implementation block.
+    item: syn::ItemImpl,
+}
+
+impl InkExportImpl {
+    /// Returns `Ok` if the input matches all requirements for an exported ink!
+    /// implementation block.
+    pub fn new(attr: TokenStream2, input: TokenStream2) -> Result<Self, syn::Error> {
+        let ident = syn::parse2::<syn::Ident>(attr.clone()).map_err(|_| {
+            format_err_spanned!(
+                attr,
+                "expected the name of the extension point for #[ink::export_impl(name)]",
+            )
+        })?;
+        let item = syn::parse2::<syn::ItemImpl>(input)?;
+        if !item.generics.params.is_empty() {
+            return Err(format_err_spanned!(
+                item.generics.params,
+                "exported ink! implementation blocks must not be generic",
+            ))
+        }
+        if !matches!(&*item.self_ty, syn::Type::Path(_)) {
+            return Err(format_err_spanned!(
+                item.self_ty,
+                "expected a placeholder for the ink! storage struct as self type \
+                of the exported ink! implementation block",
+            ))
+        }
+        Ok(Self { ident, item })
+    }
+
+    /// Returns the name of the extension point.
+    pub fn ident(&self) -> &syn::Ident {
+        &self.ident
+    }
+
+    /// Returns the exported implementation block.
+    pub fn item(&self) -> &syn::ItemImpl {
+        &self.item
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_new_fails(attr: TokenStream2, input: TokenStream2, expected: &str) {
+        assert_eq!(
+            InkExportImpl::new(attr, input).map_err(|err| err.to_string()),
+            Err(expected.to_string()),
+        )
+    }
+
+    #[test]
+    fn new_works() {
+        let export = InkExportImpl::new(
+            quote::quote! { psp22_impl },
+            quote::quote! {
+                #[ink(namespace = "psp22")]
+                impl Psp22 for Contract {
+                    #[ink(message)]
+                    fn total_supply(&self) -> Balance {
+                        self.total_supply
+                    }
+                }
+            },
+        )
+        .unwrap();
+        assert_eq!(export.ident(), "psp22_impl");
+        assert_eq!(export.item().attrs.len(), 1);
+    }
+
+    #[test]
+    fn invalid_name_fails() {
+        let input = quote::quote! { impl Contract {} };
+        assert_new_fails(
+            quote::quote! {},
+            input.clone(),
+            "expected the name of the extension point for #[ink::export_impl(name)]",
+        );
+        assert_new_fails(
+            quote::quote! { my::flip_impl },
+            input,
+            "expected the name of the extension point for #[ink::export_impl(name)]",
+        );
+    }
+
+    #[test]
+    fn invalid_impl_fails() {
+        assert_new_fails(
+            quote::quote! { flip_impl },
+            quote::quote! { impl<T> Flip for Contract<T> {} },
+            "exported ink! implementation blocks must not be generic",
+        );
+        assert_new_fails(
+            quote::quote! { flip_impl },
+            quote::quote! { impl Flip for [u8; 32] {} },
+            "expected a placeholder for the ink! storage struct as self type \
+            of the exported ink! implementation block",
+        );
+    }
+}
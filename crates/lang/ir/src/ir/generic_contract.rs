@@ -0,0 +1,353 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::ir;
+use syn::spanned::Spanned as _;
+
+/// The generic parameter of an ink! smart contract instantiated via the
+/// `#[ink::contract(impl = MyType)]` configuration.
+///
+/// # Note
+///
+/// ink! smart contracts cannot be generic since their storage layout, their
+/// dispatch and their metadata must be known upfront. Instead the ink! storage
+/// struct and its implementation blocks may be generic over exactly one type
+/// parameter which is resolved to the type given via the `impl` configuration
+/// argument. For this the generics are stripped from the ink! storage struct and
+/// all implementation blocks for it and the type parameter becomes a type alias
+/// for the given type.
+///
+/// # Example
+///
+/// ```
+/// # let contract = <ink_lang_ir::Contract>::new(
+/// #     quote::quote! { impl = MyPolicy },
+/// #     quote::quote! {
+/// mod my_contract {
+///     pub trait Policy {
+///         fn fee(amount: u32) -> u32;
+///     }
+///
+///     pub struct MyPolicy;
+///
+///     impl Policy for MyPolicy {
+///         fn fee(amount: u32) -> u32 { amount / 100 }
+///     }
+///
+///     #[ink(storage)]
+///     pub struct MyStorage<T: Policy> {
+///         /* storage fields */
+///     }
+///
+///     impl<T: Policy> MyStorage<T> {
+///         #[ink(constructor)]
+///         pub fn new() -> Self { Self {} }
+///
+///         #[ink(message)]
+///         pub fn fee(&self, amount: u32) -> u32 { T::fee(amount) }
+///     }
+/// }
+/// # }).unwrap();
+/// # assert_eq!(contract.generic().unwrap().param(), "T");
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct GenericContract {
+    /// The identifier of the generic type parameter.
+    param: syn::Ident,
+    /// The type that the generic type parameter is resolved to.
+    impl_type: syn::Path,
+    /// The stripped generics of the ink! storage struct and its implementation blocks.
+    ///
+    /// These are required to hold for the resolved type.
+    bounds: Vec<syn::Generics>,
+}
+
+impl GenericContract {
+    /// Resolves the generic parameter of the ink! storage struct of the given
+    /// inline module to the given type.
+    ///
+    /// Strips the generics from the ink! storage struct and from all root
+    /// implementation blocks for it.
+    ///
+    /// # Errors
+    ///
+    /// - If the module has no inline body.
+    /// - If the ink! storage struct is not defined at the module root or is not
+    ///   generic over exactly one type parameter.
+    /// - If an implementation block for the ink! storage struct uses a different
+    ///   generic type parameter.
+    pub fn instantiate(
+        module: &mut syn::ItemMod,
+        impl_type: syn::Path,
+    ) -> Result<Self, syn::Error> {
+        let module_span = module.span();
+        let items = match &mut module.content {
+            Some((_, items)) => items,
+            None => {
+                return Err(format_err!(
+                    module_span,
+                    "out-of-line ink! modules are not supported, use `#[ink::contract] mod name {{ ... }}`",
+                ))
+            }
+        };
+        let mut storage = None;
+        for item in items.iter_mut() {
+            if let syn::Item::Struct(item_struct) = item {
+                if ir::Storage::is_ink_storage(item_struct)? {
+                    storage = Some(item_struct);
+                    break
+                }
+            }
+        }
+        let storage = storage.ok_or_else(|| {
+            format_err!(
+                impl_type,
+                "the `impl` ink! configuration argument requires a generic ink! \
+                 storage struct at the ink! module root",
+            )
+        })?;
+        let storage_ident = storage.ident.clone();
+        let param = Self::type_param(&storage.generics).ok_or_else(|| {
+            format_err!(
+                storage.generics.span(),
+                "the ink! storage struct must be generic over exactly one type parameter \
+                 when the `impl` ink! configuration argument is given",
+            )
+        })?;
+        let mut bounds = vec![core::mem::take(&mut storage.generics)];
+        for item in items.iter_mut() {
+            if let syn::Item::Impl(item_impl) = item {
+                if !Self::strip_self_ty_param(&mut item_impl.self_ty, &storage_ident)? {
+                    continue
+                }
+                if item_impl.generics.params.is_empty() {
+                    continue
+                }
+                match Self::type_param(&item_impl.generics) {
+                    Some(impl_param) if impl_param == param => (),
+                    _ => {
+                        return Err(format_err!(
+                            item_impl.generics.span(),
+                            "implementation blocks for a generic ink! storage struct \
+                             must be generic over its type parameter `{}` only",
+                            param,
+                        ))
+                    }
+                }
+                bounds.push(core::mem::take(&mut item_impl.generics));
+            }
+        }
+        Ok(Self {
+            param,
+            impl_type,
+            bounds,
+        })
+    }
+
+    /// Returns the identifier of the single generic type parameter if any.
+    fn type_param(generics: &syn::Generics) -> Option<syn::Ident> {
+        if generics.params.len() != 1 {
+            return None
+        }
+        match generics.params.first() {
+            Some(syn::GenericParam::Type(type_param)) => Some(type_param.ident.clone()),
+            _ => None,
+        }
+    }
+
+    /// Strips the generic arguments from the given implementation block self type
+    /// if it refers to the ink! storage struct.
+    ///
+    /// Returns `true` if the self type refers to the ink! storage struct.
+    fn strip_self_ty_param(
+        self_ty: &mut syn::Type,
+        storage_ident: &syn::Ident,
+    ) -> Result<bool, syn::Error> {
+        let type_path = match self_ty {
+            syn::Type::Path(type_path) if type_path.qself.is_none() => type_path,
+            _ => return Ok(false),
+        };
+        let segment = match type_path.path.segments.last_mut() {
+            Some(segment) if &segment.ident == storage_ident => segment,
+            _ => return Ok(false),
+        };
+        if let syn::PathArguments::Parenthesized(args) = &segment.arguments {
+            return Err(format_err_spanned!(
+                args,
+                "encountered unexpected arguments for the generic ink! storage struct",
+            ))
+        }
+        segment.arguments = syn::PathArguments::None;
+        Ok(true)
+    }
+
+    /// Returns the identifier of the generic type parameter.
+    pub fn param(&self) -> &syn::Ident {
+        &self.param
+    }
+
+    /// Returns the type that the generic type parameter is resolved to.
+    pub fn impl_type(&self) -> &syn::Path {
+        &self.impl_type
+    }
+
+    /// Returns the stripped generics of the ink! storage struct and its
+    /// implementation blocks.
+    ///
+    /// The resolved type must satisfy all of their bounds.
+    pub fn bounds(&self) -> &[syn::Generics] {
+        &self.bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn instantiate(
+        module: syn::ItemMod,
+    ) -> Result<(syn::ItemMod, GenericContract), String> {
+        let mut module = module;
+        GenericContract::instantiate(&mut module, syn::parse_quote! { MyPolicy })
+            .map(|generic| (module, generic))
+            .map_err(|err| err.to_string())
+    }
+
+    #[test]
+    fn instantiate_works() {
+        let (module, generic) = instantiate(syn::parse_quote! {
+            mod my_module {
+                #[ink(storage)]
+                pub struct MyStorage<T: Policy> {}
+
+                impl<T: Policy> MyStorage<T> {
+                    #[ink(message)]
+                    pub fn my_message(&self) {}
+                }
+
+                impl<T> MyTrait for MyStorage<T>
+                where
+                    T: Policy + Default,
+                {
+                    #[ink(message)]
+                    fn my_message(&self) {}
+                }
+
+                impl MyStorage {}
+            }
+        })
+        .unwrap();
+        let expected: syn::ItemMod = syn::parse_quote! {
+            mod my_module {
+                #[ink(storage)]
+                pub struct MyStorage {}
+
+                impl MyStorage {
+                    #[ink(message)]
+                    pub fn my_message(&self) {}
+                }
+
+                impl MyTrait for MyStorage {
+                    #[ink(message)]
+                    fn my_message(&self) {}
+                }
+
+                impl MyStorage {}
+            }
+        };
+        assert_eq!(module, expected);
+        assert_eq!(generic.param(), "T");
+        assert_eq!(generic.impl_type(), &syn::parse_quote! { MyPolicy });
+        assert_eq!(generic.bounds().len(), 3);
+    }
+
+    #[test]
+    fn non_generic_storage_fails() {
+        assert_eq!(
+            instantiate(syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+                }
+            })
+            .map(|_| ()),
+            Err(
+                "the ink! storage struct must be generic over exactly one type \
+                 parameter when the `impl` ink! configuration argument is given"
+                    .to_string()
+            ),
+        );
+        assert_eq!(
+            instantiate(syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage<T, U> {}
+                }
+            })
+            .map(|_| ()),
+            Err(
+                "the ink! storage struct must be generic over exactly one type \
+                 parameter when the `impl` ink! configuration argument is given"
+                    .to_string()
+            ),
+        );
+        assert_eq!(
+            instantiate(syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage<const N: usize> {}
+                }
+            })
+            .map(|_| ()),
+            Err(
+                "the ink! storage struct must be generic over exactly one type \
+                 parameter when the `impl` ink! configuration argument is given"
+                    .to_string()
+            ),
+        );
+    }
+
+    #[test]
+    fn missing_storage_fails() {
+        assert_eq!(
+            instantiate(syn::parse_quote! {
+                mod my_module {}
+            })
+            .map(|_| ()),
+            Err(
+                "the `impl` ink! configuration argument requires a generic ink! \
+                 storage struct at the ink! module root"
+                    .to_string()
+            ),
+        );
+    }
+
+    #[test]
+    fn mismatching_impl_param_fails() {
+        assert_eq!(
+            instantiate(syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage<T: Policy> {}
+
+                    impl<U: Policy> MyStorage<U> {}
+                }
+            })
+            .map(|_| ()),
+            Err("implementation blocks for a generic ink! storage struct \
+                 must be generic over its type parameter `T` only"
+                .to_string()),
+        );
+    }
+}
@@ -0,0 +1,233 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::mixin::parse_contract_module;
+use proc_macro2::{
+    Group,
+    Span,
+    TokenStream as TokenStream2,
+    TokenTree,
+};
+use quote::ToTokens as _;
+use syn::{
+    parse::Parser as _,
+    Token,
+};
+
+/// The source of the implementation block included via `#[ink::include_impl]`.
+#[derive(Debug, PartialEq, Eq)]
+pub enum IncludeImplSource {
+    /// The path to an extension point exported via `#[ink::export_impl]`.
+    ///
+    /// The extension point is invoked with the ink! smart contract module and
+    /// hands its implementation block back to `#[ink::include_impl]`.
+    ExtensionPoint(syn::Path),
+    /// The implementation block handed back by an extension point.
+    Impl(syn::ItemImpl),
+}
+
+/// An ink! smart contract module including an implementation block that has been
+/// exported by another crate via `#[ink::export_impl]`.
+///
+/// # Note
+///
+/// Just like mixins this must be applied above the `#[ink::contract]` attribute.
+#[derive(Debug, PartialEq, Eq)]
+pub struct InkIncludeImpl {
+    /// The source of the included implementation block.
+    source: IncludeImplSource,
+    /// The annotated ink! smart contract module.
+    module: syn::ItemMod,
+    /// The identifier of the ink! storage struct.
+    storage_ident: syn::Ident,
+}
+
+impl InkIncludeImpl {
+    /// Returns `Ok` if the input matches all requirements for an ink! smart
+    /// contract including an exported implementation block.
+    pub fn new(attr: TokenStream2, input: TokenStream2) -> Result<Self, syn::Error> {
+        let source = (|input: syn::parse::ParseStream| {
+            if input.peek(Token![impl]) || input.peek(Token![#]) {
+                return input.parse().map(IncludeImplSource::Impl)
+            }
+            input.parse().map(IncludeImplSource::ExtensionPoint)
+        })
+        .parse2(attr.clone())
+        .map_err(|_| {
+            format_err_spanned!(
+                attr,
+                "expected a path to an extension point exported via #[ink::export_impl]",
+            )
+        })?;
+        let (module, storage_ident) = parse_contract_module(&"include_impl", input)?;
+        Ok(Self {
+            source,
+            module,
+            storage_ident,
+        })
+    }
+
+    /// Returns the source of the included implementation block.
+    pub fn source(&self) -> &IncludeImplSource {
+        &self.source
+    }
+
+    /// Returns the annotated ink! smart contract module.
+    pub fn module(&self) -> &syn::ItemMod {
+        &self.module
+    }
+
+    /// Returns the identifier of the ink! storage struct.
+    pub fn storage_ident(&self) -> &syn::Ident {
+        &self.storage_ident
+    }
+
+    /// Returns the ink! smart contract module with the given implementation block
+    /// appended for the ink! storage struct.
+    ///
+    /// # Note
+    ///
+    /// The implementation block is handed back by the `macro_rules!` extension point
+    /// whose hygiene would hide identifiers such as `self` from the code generated by
+    /// `#[ink::contract]`. Therefore all tokens of the implementation block except for
+    /// `$crate` are resolved at the ink! storage struct.
+    pub fn expand(&self, item_impl: &syn::ItemImpl) -> syn::ItemMod {
+        let storage_ident = &self.storage_ident;
+        let tokens = respan(item_impl.to_token_stream(), storage_ident.span());
+        let mut item_impl = syn::parse2::<syn::ItemImpl>(tokens)
+            .expect("encountered invalid respanned implementation block");
+        item_impl.self_ty = Box::new(syn::parse_quote! { #storage_ident });
+        let mut module = self.module.clone();
+        module
+            .content
+            .as_mut()
+            .expect("encountered out-of-line ink! module")
+            .1
+            .push(syn::Item::Impl(item_impl));
+        module
+    }
+}
+
+/// Resolves all tokens except for `$crate` at the given span.
+fn respan(tokens: TokenStream2, span: Span) -> TokenStream2 {
+    tokens
+        .into_iter()
+        .map(|token| {
+            match token {
+                TokenTree::Group(group) => {
+                    let mut new_group =
+                        Group::new(group.delimiter(), respan(group.stream(), span));
+                    new_group.set_span(group.span().resolved_at(span));
+                    TokenTree::Group(new_group)
+                }
+                TokenTree::Ident(ident) if ident == "$crate" => TokenTree::Ident(ident),
+                mut token => {
+                    token.set_span(token.span().resolved_at(span));
+                    token
+                }
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contract_module() -> TokenStream2 {
+        quote::quote! {
+            #[ink::contract]
+            mod my_module {
+                #[ink(storage)]
+                pub struct MyStorage {}
+            }
+        }
+    }
+
+    #[test]
+    fn extension_point_works() {
+        let include = InkIncludeImpl::new(
+            quote::quote! { my_library::flip_impl },
+            contract_module(),
+        )
+        .unwrap();
+        assert_eq!(
+            include.source(),
+            &IncludeImplSource::ExtensionPoint(
+                syn::parse_quote! { my_library::flip_impl }
+            )
+        );
+        assert_eq!(include.storage_ident(), "MyStorage");
+    }
+
+    #[test]
+    fn expand_works() {
+        let include = InkIncludeImpl::new(
+            quote::quote! {
+                #[ink(namespace = "flip")]
+                impl Flip for Contract {
+                    #[ink(message)]
+                    fn flip(&mut self) {}
+                }
+            },
+            contract_module(),
+        )
+        .unwrap();
+        let item_impl = match include.source() {
+            IncludeImplSource::Impl(item_impl) => item_impl,
+            IncludeImplSource::ExtensionPoint(_) => panic!("expected an included impl"),
+        };
+        let expected: syn::ItemMod = syn::parse_quote! {
+            #[ink::contract]
+            mod my_module {
+                #[ink(storage)]
+                pub struct MyStorage {}
+
+                #[ink(namespace = "flip")]
+                impl Flip for MyStorage {
+                    #[ink(message)]
+                    fn flip(&mut self) {}
+                }
+            }
+        };
+        assert_eq!(include.expand(item_impl), expected);
+    }
+
+    #[test]
+    fn invalid_source_fails() {
+        assert_eq!(
+            InkIncludeImpl::new(quote::quote! { "flip_impl" }, contract_module())
+                .map_err(|err| err.to_string()),
+            Err(
+                "expected a path to an extension point exported via #[ink::export_impl]"
+                    .to_string()
+            ),
+        );
+    }
+
+    #[test]
+    fn missing_contract_attribute_fails() {
+        assert_eq!(
+            InkIncludeImpl::new(
+                quote::quote! { my_library::flip_impl },
+                quote::quote! { mod my_module {} },
+            )
+            .map_err(|err| err.to_string()),
+            Err(
+                "the ink! `include_impl` mixin must be applied above `#[ink::contract]`"
+                    .to_string()
+            ),
+        );
+    }
+}
@@ -0,0 +1,149 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    ast,
+    ir::idents_lint,
+};
+use proc_macro2::TokenStream as TokenStream2;
+use syn::spanned::Spanned as _;
+
+/// The ink! end-to-end test with all required information.
+pub struct InkE2ETest {
+    /// The function which was annotated.
+    pub item_fn: syn::ItemFn,
+}
+
+impl TryFrom<syn::ItemFn> for InkE2ETest {
+    type Error = syn::Error;
+
+    fn try_from(item_fn: syn::ItemFn) -> Result<Self, Self::Error> {
+        idents_lint::ensure_no_ink_identifiers(&item_fn)?;
+        if item_fn.sig.asyncness.is_none() {
+            return Err(format_err_spanned!(
+                item_fn.sig,
+                "ink! end-to-end tests must be `async fn`",
+            ))
+        }
+        if !item_fn.sig.generics.params.is_empty() {
+            return Err(format_err_spanned!(
+                item_fn.sig.generics,
+                "ink! end-to-end tests must not be generic",
+            ))
+        }
+        match item_fn.sig.inputs.len() {
+            1 => (),
+            _ => {
+                return Err(format_err_spanned!(
+                    item_fn.sig,
+                    "ink! end-to-end tests must take the client as their only argument",
+                ))
+            }
+        }
+        if let Some(syn::FnArg::Receiver(receiver)) = item_fn.sig.inputs.first() {
+            return Err(format_err_spanned!(
+                receiver,
+                "ink! end-to-end tests must not have a `self` receiver",
+            ))
+        }
+        Ok(Self { item_fn })
+    }
+}
+
+impl InkE2ETest {
+    /// Returns `Ok` if the function matches all requirements for an ink! end-to-end test.
+    pub fn new(attr: TokenStream2, input: TokenStream2) -> Result<Self, syn::Error> {
+        let args = syn::parse2::<ast::AttributeArgs>(attr)?;
+        if let Some(arg) = args.into_iter().next() {
+            return Err(format_err_spanned!(
+                arg,
+                "encountered unknown or unsupported ink! e2e test configuration argument",
+            ))
+        }
+        let item_fn = syn::parse2::<syn::ItemFn>(input)?;
+        InkE2ETest::try_from(item_fn)
+    }
+
+    /// Returns the pattern and the type of the client argument.
+    pub fn client(&self) -> (&syn::Pat, &syn::Type) {
+        match self.item_fn.sig.inputs.first() {
+            Some(syn::FnArg::Typed(pat_type)) => (&pat_type.pat, &pat_type.ty),
+            _ => {
+                unreachable!(
+                    "checked upon construction that the client is a typed argument"
+                )
+            }
+        }
+    }
+
+    /// Returns the span of the client argument.
+    pub fn client_span(&self) -> proc_macro2::Span {
+        self.item_fn.sig.inputs.span()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_new_fails(attr: TokenStream2, input: TokenStream2, expected: &str) {
+        assert_eq!(
+            InkE2ETest::new(attr, input)
+                .map(|_| ())
+                .map_err(|err| err.to_string()),
+            Err(expected.to_string()),
+        )
+    }
+
+    #[test]
+    fn client_argument_works() {
+        let test = InkE2ETest::new(
+            quote::quote! {},
+            quote::quote! { async fn it_works(mut client: OffChainClient) {} },
+        )
+        .unwrap();
+        let (pat, ty) = test.client();
+        assert_eq!(pat, &syn::parse_quote! { mut client });
+        assert_eq!(ty, &syn::parse_quote! { OffChainClient });
+    }
+
+    #[test]
+    fn invalid_tests_fail() {
+        assert_new_fails(
+            quote::quote! { timeout = 42 },
+            quote::quote! { async fn it_works(client: C) {} },
+            "encountered unknown or unsupported ink! e2e test configuration argument",
+        );
+        assert_new_fails(
+            quote::quote! {},
+            quote::quote! { fn it_works(client: C) {} },
+            "ink! end-to-end tests must be `async fn`",
+        );
+        assert_new_fails(
+            quote::quote! {},
+            quote::quote! { async fn it_works<C>(client: C) {} },
+            "ink! end-to-end tests must not be generic",
+        );
+        assert_new_fails(
+            quote::quote! {},
+            quote::quote! { async fn it_works() {} },
+            "ink! end-to-end tests must take the client as their only argument",
+        );
+        assert_new_fails(
+            quote::quote! {},
+            quote::quote! { async fn it_works(client: C, other: C) {} },
+            "ink! end-to-end tests must take the client as their only argument",
+        );
+    }
+}
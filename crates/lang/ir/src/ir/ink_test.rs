@@ -12,13 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::ir::idents_lint;
+use crate::{
+    ast,
+    error::ExtError as _,
+    ir::idents_lint,
+};
 use proc_macro2::TokenStream as TokenStream2;
 
 /// The ink! test with all required information.
 pub struct InkTest {
     /// The function which was annotated.
     pub item_fn: syn::ItemFn,
+    /// The environment the test is run in if configured via `env = Path`.
+    env: Option<syn::Path>,
 }
 
 impl TryFrom<syn::ItemFn> for InkTest {
@@ -26,20 +32,111 @@ impl TryFrom<syn::ItemFn> for InkTest {
 
     fn try_from(item_fn: syn::ItemFn) -> Result<Self, Self::Error> {
         idents_lint::ensure_no_ink_identifiers(&item_fn)?;
-        Ok(Self { item_fn })
+        Ok(Self { item_fn, env: None })
     }
 }
 
 impl InkTest {
-    /// Returns `Ok` if the trait matches all requirements for an ink! trait definition.
+    /// Returns `Ok` if the function matches all requirements for an ink! test.
     pub fn new(attr: TokenStream2, input: TokenStream2) -> Result<Self, syn::Error> {
-        if !attr.is_empty() {
-            return Err(format_err_spanned!(
-                attr,
-                "unexpected attribute input for ink! trait definition"
-            ))
+        let args = syn::parse2::<ast::AttributeArgs>(attr)?;
+        let mut env: Option<(syn::Path, ast::MetaNameValue)> = None;
+        for arg in args.into_iter() {
+            if arg.name.is_ident("env") {
+                if let Some((_, fst)) = env {
+                    return Err(format_err_spanned!(
+                        arg,
+                        "encountered duplicate ink! `env` configuration argument",
+                    )
+                    .into_combine(format_err_spanned!(
+                        fst,
+                        "first `env` configuration argument here",
+                    )))
+                }
+                if let ast::PathOrLit::Path(path) = &arg.value {
+                    env = Some((path.clone(), arg))
+                } else {
+                    return Err(format_err_spanned!(
+                        arg,
+                        "expected a path for `env` ink! test configuration argument",
+                    ))
+                }
+            } else {
+                return Err(format_err_spanned!(
+                    arg,
+                    "encountered unknown or unsupported ink! test configuration argument",
+                ))
+            }
         }
         let item_fn = syn::parse2::<syn::ItemFn>(input)?;
-        InkTest::try_from(item_fn)
+        let mut test = InkTest::try_from(item_fn)?;
+        test.env = env.map(|(path, _)| path);
+        Ok(test)
+    }
+
+    /// Returns the environment the test is run in.
+    ///
+    /// This is the `DefaultEnvironment` of `ink_env` if not configured otherwise.
+    pub fn env(&self) -> syn::Path {
+        self.env
+            .clone()
+            .unwrap_or_else(|| syn::parse_quote! { ::ink_env::DefaultEnvironment })
+    }
+
+    /// Returns `true` if the test function is an `async fn`.
+    pub fn is_async(&self) -> bool {
+        self.item_fn.sig.asyncness.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_new_fails(attr: TokenStream2, expected: &str) {
+        assert_eq!(
+            InkTest::new(attr, quote::quote! { fn it_works() {} })
+                .map(|_| ())
+                .map_err(|err| err.to_string()),
+            Err(expected.to_string()),
+        )
+    }
+
+    #[test]
+    fn default_env_works() {
+        let test =
+            InkTest::new(quote::quote! {}, quote::quote! { fn it_works() {} }).unwrap();
+        assert_eq!(
+            test.env(),
+            syn::parse_quote! { ::ink_env::DefaultEnvironment }
+        );
+        assert!(!test.is_async());
+    }
+
+    #[test]
+    fn custom_env_and_async_works() {
+        let test = InkTest::new(
+            quote::quote! { env = crate::MyEnvironment },
+            quote::quote! { async fn it_works() {} },
+        )
+        .unwrap();
+        assert_eq!(test.env(), syn::parse_quote! { crate::MyEnvironment });
+        assert!(test.is_async());
+    }
+
+    #[test]
+    fn invalid_args_fail() {
+        assert_new_fails(
+            quote::quote! { env = "MyEnvironment" },
+            "expected a path for `env` ink! test configuration argument",
+        );
+        assert_new_fails(
+            quote::quote! { env = MyEnvironment, env = OtherEnvironment },
+            "encountered duplicate ink! `env` configuration argument",
+        );
+        assert_new_fails(
+            quote::quote! { timeout = 42 },
+            "encountered unknown or unsupported ink! test configuration argument",
+        );
     }
 }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod event;
+mod shared_event;
 mod storage;
 
 #[cfg(test)]
@@ -20,6 +21,7 @@ mod tests;
 
 pub use self::{
     event::Event,
+    shared_event::SharedEvent,
     storage::Storage,
 };
 
@@ -85,6 +87,20 @@ impl TryFrom<syn::Item> for Item {
                     }
                 }
             }
+            syn::Item::Enum(item_enum)
+                if ir::Storage::is_ink_storage_enum(&item_enum)? =>
+            {
+                <ir::Storage as TryFrom<_>>::try_from(item_enum)
+                    .map(Into::into)
+                    .map(Self::Ink)
+            }
+            syn::Item::Use(item_use)
+                if ir::SharedEvent::is_ink_shared_event(&item_use)? =>
+            {
+                <ir::SharedEvent as TryFrom<_>>::try_from(item_use)
+                    .map(Into::into)
+                    .map(Self::Ink)
+            }
             syn::Item::Impl(item_impl) => {
                 if !ir::ItemImpl::is_ink_impl_block(&item_impl)? {
                     return Ok(Self::Rust(item_impl.into()))
@@ -155,6 +171,8 @@ pub enum InkItem {
     Storage(ir::Storage),
     /// An ink! event definition.
     Event(ir::Event),
+    /// An import of an ink! event defined outside of the ink! module.
+    SharedEvent(ir::SharedEvent),
     /// An ink! implementation block.
     ImplBlock(ir::ItemImpl),
 }
@@ -166,6 +184,7 @@ impl quote::ToTokens for InkItem {
         match self {
             Self::Storage(storage) => storage.to_tokens(tokens),
             Self::Event(event) => event.to_tokens(tokens),
+            Self::SharedEvent(shared_event) => shared_event.to_tokens(tokens),
             Self::ImplBlock(impl_block) => impl_block.to_tokens(tokens),
         }
     }
@@ -186,6 +205,12 @@ impl InkItem {
                     return Ok(true)
                 }
             }
+            syn::Item::Enum(item_enum) => {
+                return ir::Storage::is_ink_storage_enum(item_enum)
+            }
+            syn::Item::Use(item_use) => {
+                return ir::SharedEvent::is_ink_shared_event(item_use)
+            }
             syn::Item::Impl(item_impl) => {
                 return ir::ItemImpl::is_ink_impl_block(item_impl)
             }
@@ -207,6 +232,12 @@ impl From<ir::Event> for InkItem {
     }
 }
 
+impl From<ir::SharedEvent> for InkItem {
+    fn from(shared_event: ir::SharedEvent) -> Self {
+        Self::SharedEvent(shared_event)
+    }
+}
+
 impl From<ir::ItemImpl> for InkItem {
     fn from(impl_block: ir::ItemImpl) -> Self {
         Self::ImplBlock(impl_block)
@@ -244,6 +275,21 @@ impl InkItem {
         self.filter_map_event_item().is_some()
     }
 
+    /// Returns `Some` if `self` is an import of a shared ink! event.
+    ///
+    /// Otherwise, returns `None`.
+    pub fn filter_map_shared_event_item(&self) -> Option<&ir::SharedEvent> {
+        match self {
+            InkItem::SharedEvent(shared_event) => Some(shared_event),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the ink! specific item is an import of a shared ink! event.
+    pub fn is_shared_event_item(&self) -> bool {
+        self.filter_map_shared_event_item().is_some()
+    }
+
     /// Returns `Some` if `self` is an ink! implementation block.
     ///
     /// Otherwise, returns `None`.
@@ -0,0 +1,120 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::ir;
+use proc_macro2::Ident;
+use syn::spanned::Spanned as _;
+
+/// An import of an ink! event defined outside of the ink! smart contract.
+///
+/// Noticed by ink! through the `#[ink(event)]` annotation on a `use` item.
+///
+/// # Note
+///
+/// The imported event must be defined via `#[ink::event]`. The ink! smart
+/// contract can then emit it just like its own ink! events.
+///
+/// # Example
+///
+/// ```
+/// # <ink_lang_ir::SharedEvent as TryFrom<syn::ItemUse>>::try_from(syn::parse_quote! {
+/// #[ink(event)]
+/// use erc20_events::Transfer;
+/// # }).unwrap();
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct SharedEvent {
+    item: syn::ItemUse,
+    ident: Ident,
+}
+
+impl quote::ToTokens for SharedEvent {
+    /// We mainly implement this trait for this ink! type to have a derived
+    /// [`Spanned`](`syn::spanned::Spanned`) implementation for it.
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.item.to_tokens(tokens)
+    }
+}
+
+impl SharedEvent {
+    /// Returns `true` if the first ink! annotation on the given `use` item is
+    /// `#[ink(event)]`.
+    ///
+    /// # Errors
+    ///
+    /// If the first found ink! attribute is malformed.
+    pub(super) fn is_ink_shared_event(
+        item_use: &syn::ItemUse,
+    ) -> Result<bool, syn::Error> {
+        if !ir::contains_ink_attributes(&item_use.attrs) {
+            return Ok(false)
+        }
+        let attr = ir::first_ink_attribute(&item_use.attrs)?
+            .expect("missing expected ink! attribute for use item");
+        Ok(matches!(attr.first().kind(), ir::AttributeArg::Event))
+    }
+
+    /// Returns the name under which the given `use` tree imports a single item.
+    fn imported_ident(tree: &syn::UseTree) -> Result<&Ident, syn::Error> {
+        match tree {
+            syn::UseTree::Path(use_path) => Self::imported_ident(&use_path.tree),
+            syn::UseTree::Name(use_name) => Ok(&use_name.ident),
+            syn::UseTree::Rename(use_rename) => Ok(&use_rename.rename),
+            syn::UseTree::Glob(_) | syn::UseTree::Group(_) => {
+                Err(format_err_spanned!(
+                    tree,
+                    "ink! event imports must import exactly one event, e.g. `use path::to::Event;`",
+                ))
+            }
+        }
+    }
+}
+
+impl TryFrom<syn::ItemUse> for SharedEvent {
+    type Error = syn::Error;
+
+    fn try_from(item_use: syn::ItemUse) -> Result<Self, Self::Error> {
+        let (_, other_attrs) = ir::sanitize_attributes(
+            item_use.span(),
+            item_use.attrs,
+            &ir::AttributeArgKind::Event,
+            |arg| {
+                match arg.kind() {
+                    ir::AttributeArg::Event => Ok(()),
+                    _ => Err(None),
+                }
+            },
+        )?;
+        let ident = Self::imported_ident(&item_use.tree)?.clone();
+        Ok(Self {
+            item: syn::ItemUse {
+                attrs: other_attrs,
+                ..item_use
+            },
+            ident,
+        })
+    }
+}
+
+impl SharedEvent {
+    /// Returns the identifier under which the event is imported.
+    pub fn ident(&self) -> &Ident {
+        &self.ident
+    }
+
+    /// Returns the `use` item importing the event without its ink! attributes.
+    pub fn item_use(&self) -> &syn::ItemUse {
+        &self.item
+    }
+}
@@ -19,7 +19,7 @@ use crate::{
 use proc_macro2::Ident;
 use syn::spanned::Spanned as _;
 
-/// An ink! storage struct definition.
+/// An ink! storage struct or enum definition.
 ///
 /// Noticed by ink! through the `#[ink(storage)]` annotation.
 ///
@@ -28,6 +28,9 @@ use syn::spanned::Spanned as _;
 /// An ink! smart contract must have exactly one storage definition.
 /// The storage definition must be found in the root of the ink! module.
 ///
+/// An ink! storage enum allows to model an ink! smart contract as an explicit
+/// state machine whose states carry different storage fields.
+///
 /// # Example
 ///
 /// ```
@@ -41,15 +44,25 @@ use syn::spanned::Spanned as _;
 /// ```
 #[derive(Debug, PartialEq, Eq)]
 pub struct Storage {
-    /// The underlying `struct` Rust item.
-    ast: syn::ItemStruct,
+    /// The underlying `struct` or `enum` Rust item.
+    ast: StorageItem,
+}
+
+/// The underlying Rust item of an ink! storage definition.
+#[derive(Debug, PartialEq, Eq)]
+enum StorageItem {
+    Struct(syn::ItemStruct),
+    Enum(syn::ItemEnum),
 }
 
 impl quote::ToTokens for Storage {
     /// We mainly implement this trait for this ink! type to have a derived
     /// [`Spanned`](`syn::spanned::Spanned`) implementation for it.
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        self.ast.to_tokens(tokens)
+        match &self.ast {
+            StorageItem::Struct(item_struct) => item_struct.to_tokens(tokens),
+            StorageItem::Enum(item_enum) => item_enum.to_tokens(tokens),
+        }
     }
 }
 
@@ -60,29 +73,65 @@ impl Storage {
     /// # Errors
     ///
     /// If the first found ink! attribute is malformed.
-    pub(super) fn is_ink_storage(
+    pub(crate) fn is_ink_storage(
         item_struct: &syn::ItemStruct,
     ) -> Result<bool, syn::Error> {
-        if !ir::contains_ink_attributes(&item_struct.attrs) {
+        Self::has_ink_storage_attribute(&item_struct.attrs)
+    }
+
+    /// Returns `true` if the first ink! annotation on the given enum is
+    /// `#[ink(storage)]`.
+    ///
+    /// # Errors
+    ///
+    /// If the first found ink! attribute is malformed.
+    pub(crate) fn is_ink_storage_enum(
+        item_enum: &syn::ItemEnum,
+    ) -> Result<bool, syn::Error> {
+        Self::has_ink_storage_attribute(&item_enum.attrs)
+    }
+
+    /// Returns the identifier of the given item if it is an ink! storage struct
+    /// or enum definition.
+    ///
+    /// # Errors
+    ///
+    /// If the first found ink! attribute is malformed.
+    pub(crate) fn ink_storage_ident(
+        item: &syn::Item,
+    ) -> Result<Option<&Ident>, syn::Error> {
+        match item {
+            syn::Item::Struct(item_struct) if Self::is_ink_storage(item_struct)? => {
+                Ok(Some(&item_struct.ident))
+            }
+            syn::Item::Enum(item_enum) if Self::is_ink_storage_enum(item_enum)? => {
+                Ok(Some(&item_enum.ident))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn has_ink_storage_attribute(attrs: &[syn::Attribute]) -> Result<bool, syn::Error> {
+        if !ir::contains_ink_attributes(attrs) {
             return Ok(false)
         }
         // At this point we know that there must be at least one ink!
-        // attribute. This can be either the ink! storage struct,
+        // attribute. This can be either the ink! storage definition,
         // an ink! event or an invalid ink! attribute.
-        let attr = ir::first_ink_attribute(&item_struct.attrs)?
+        let attr = ir::first_ink_attribute(attrs)?
             .expect("missing expected ink! attribute for struct");
         Ok(matches!(attr.first().kind(), ir::AttributeArg::Storage))
     }
-}
-
-impl TryFrom<syn::ItemStruct> for Storage {
-    type Error = syn::Error;
 
-    fn try_from(item_struct: syn::ItemStruct) -> Result<Self, Self::Error> {
-        let struct_span = item_struct.span();
+    /// Returns the non-ink! attributes of the item or an error if its ink! attributes
+    /// are not just `#[ink(storage)]`.
+    fn sanitize_attributes(
+        span: proc_macro2::Span,
+        attrs: Vec<syn::Attribute>,
+    ) -> Result<Vec<syn::Attribute>, syn::Error> {
         let (_ink_attrs, other_attrs) = ir::sanitize_attributes(
-            struct_span,
-            item_struct.attrs,
+            span,
+            attrs,
             &ir::AttributeArgKind::Storage,
             |arg| {
                 match arg.kind() {
@@ -91,6 +140,16 @@ impl TryFrom<syn::ItemStruct> for Storage {
                 }
             },
         )?;
+        Ok(other_attrs)
+    }
+}
+
+impl TryFrom<syn::ItemStruct> for Storage {
+    type Error = syn::Error;
+
+    fn try_from(item_struct: syn::ItemStruct) -> Result<Self, Self::Error> {
+        let struct_span = item_struct.span();
+        let other_attrs = Self::sanitize_attributes(struct_span, item_struct.attrs)?;
         if !item_struct.generics.params.is_empty() {
             return Err(format_err_spanned!(
                 item_struct.generics.params,
@@ -99,28 +158,103 @@ impl TryFrom<syn::ItemStruct> for Storage {
         }
         utils::ensure_pub_visibility("storage structs", struct_span, &item_struct.vis)?;
         Ok(Self {
-            ast: syn::ItemStruct {
+            ast: StorageItem::Struct(syn::ItemStruct {
                 attrs: other_attrs,
                 ..item_struct
-            },
+            }),
+        })
+    }
+}
+
+impl TryFrom<syn::ItemEnum> for Storage {
+    type Error = syn::Error;
+
+    fn try_from(item_enum: syn::ItemEnum) -> Result<Self, Self::Error> {
+        let enum_span = item_enum.span();
+        let other_attrs = Self::sanitize_attributes(enum_span, item_enum.attrs)?;
+        if !item_enum.generics.params.is_empty() {
+            return Err(format_err_spanned!(
+                item_enum.generics.params,
+                "generic ink! storage enums are not supported",
+            ))
+        }
+        if item_enum.variants.is_empty() {
+            return Err(format_err!(
+                enum_span,
+                "ink! storage enums must have at least one variant",
+            ))
+        }
+        if let Some(variant) = item_enum
+            .variants
+            .iter()
+            .find(|variant| variant.discriminant.is_some())
+        {
+            return Err(format_err_spanned!(
+                variant,
+                "ink! storage enum variants must not have explicit discriminants",
+            ))
+        }
+        utils::ensure_pub_visibility("storage enums", enum_span, &item_enum.vis)?;
+        Ok(Self {
+            ast: StorageItem::Enum(syn::ItemEnum {
+                attrs: other_attrs,
+                ..item_enum
+            }),
         })
     }
 }
 
 impl Storage {
-    /// Returns the non-ink! attributes of the ink! storage struct.
+    /// Returns the non-ink! attributes of the ink! storage definition.
     pub fn attrs(&self) -> &[syn::Attribute] {
-        &self.ast.attrs
+        match &self.ast {
+            StorageItem::Struct(item_struct) => &item_struct.attrs,
+            StorageItem::Enum(item_enum) => &item_enum.attrs,
+        }
     }
 
-    /// Returns the identifier of the storage struct.
+    /// Returns the identifier of the storage definition.
     pub fn ident(&self) -> &Ident {
-        &self.ast.ident
+        match &self.ast {
+            StorageItem::Struct(item_struct) => &item_struct.ident,
+            StorageItem::Enum(item_enum) => &item_enum.ident,
+        }
+    }
+
+    /// Returns `true` if the ink! storage is defined by an enum.
+    pub fn is_enum(&self) -> bool {
+        matches!(self.ast, StorageItem::Enum(_))
     }
 
-    /// Returns an iterator yielding all fields of the storage struct.
-    pub fn fields(&self) -> syn::punctuated::Iter<syn::Field> {
-        self.ast.fields.iter()
+    /// Returns an iterator yielding all fields of the storage struct or of all
+    /// variants of the storage enum.
+    pub fn fields(&self) -> impl Iterator<Item = &syn::Field> {
+        let (struct_fields, enum_fields) = match &self.ast {
+            StorageItem::Struct(item_struct) => (Some(item_struct.fields.iter()), None),
+            StorageItem::Enum(item_enum) => {
+                let fields = item_enum
+                    .variants
+                    .iter()
+                    .flat_map(|variant| variant.fields.iter());
+                (None, Some(fields))
+            }
+        };
+        struct_fields
+            .into_iter()
+            .flatten()
+            .chain(enum_fields.into_iter().flatten())
+    }
+
+    /// Returns an iterator yielding all variants of the storage enum.
+    ///
+    /// Yields no variants for ink! storage structs.
+    pub fn variants(&self) -> impl Iterator<Item = &syn::Variant> {
+        match &self.ast {
+            StorageItem::Struct(_) => None,
+            StorageItem::Enum(item_enum) => Some(item_enum.variants.iter()),
+        }
+        .into_iter()
+        .flatten()
     }
 }
 
@@ -218,6 +352,68 @@ mod tests {
         )
     }
 
+    #[test]
+    fn storage_enum_works() {
+        let storage = <Storage as TryFrom<syn::ItemEnum>>::try_from(syn::parse_quote! {
+            #[ink(storage)]
+            pub enum MyStorage {
+                Uninitialized,
+                Active { owner: AccountId, value: u32 },
+                Frozen(AccountId),
+            }
+        })
+        .unwrap();
+        assert!(storage.is_enum());
+        assert_eq!(storage.ident(), "MyStorage");
+        assert_eq!(storage.variants().count(), 3);
+        assert_eq!(storage.fields().count(), 3);
+    }
+
+    fn assert_enum_try_from_fails(item_enum: syn::ItemEnum, expected: &str) {
+        assert_eq!(
+            Storage::try_from(item_enum).map_err(|err| err.to_string()),
+            Err(expected.to_string())
+        )
+    }
+
+    #[test]
+    fn invalid_storage_enum_fails() {
+        assert_enum_try_from_fails(
+            syn::parse_quote! {
+                #[ink(storage)]
+                pub enum GenericStorage<T> {
+                    Active(T),
+                }
+            },
+            "generic ink! storage enums are not supported",
+        );
+        assert_enum_try_from_fails(
+            syn::parse_quote! {
+                #[ink(storage)]
+                pub enum EmptyStorage {}
+            },
+            "ink! storage enums must have at least one variant",
+        );
+        assert_enum_try_from_fails(
+            syn::parse_quote! {
+                #[ink(storage)]
+                pub enum MyStorage {
+                    Uninitialized = 1,
+                }
+            },
+            "ink! storage enum variants must not have explicit discriminants",
+        );
+        assert_enum_try_from_fails(
+            syn::parse_quote! {
+                #[ink(storage)]
+                enum PrivateStorage {
+                    Uninitialized,
+                }
+            },
+            "non `pub` ink! storage enums are not supported",
+        );
+    }
+
     #[test]
     fn non_pub_storage_struct() {
         assert_try_from_fails(
@@ -45,6 +45,40 @@ fn simple_event_works() {
     ))
 }
 
+#[test]
+fn shared_event_works() {
+    let event_imports: Vec<syn::Item> = vec![
+        syn::parse_quote! {
+            #[ink(event)]
+            use shared::MyEvent;
+        },
+        syn::parse_quote! {
+            #[ink(event)]
+            pub use shared::events::MyEvent as MyRenamedEvent;
+        },
+    ];
+    for event_import in event_imports {
+        assert!(matches!(
+            <ir::Item as TryFrom<_>>::try_from(event_import)
+                .map_err(|err| err.to_string()),
+            Ok(ir::Item::Ink(ir::InkItem::SharedEvent(_)))
+        ))
+    }
+}
+
+#[test]
+fn shared_event_multiple_imports_fails() {
+    let event_import: syn::Item = syn::parse_quote! {
+        #[ink(event)]
+        use shared::{MyEvent1, MyEvent2};
+    };
+    assert_eq!(
+        <ir::Item as TryFrom<_>>::try_from(event_import).map_err(|err| err.to_string()),
+        Err("ink! event imports must import exactly one event, e.g. `use path::to::Event;`"
+            .to_string())
+    )
+}
+
 #[test]
 fn simple_rust_item_works() {
     let rust_items: Vec<syn::Item> = vec![
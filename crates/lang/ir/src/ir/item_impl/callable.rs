@@ -120,6 +120,10 @@ where
         <C as Callable>::has_wildcard_selector(self.callable)
     }
 
+    fn is_default(&self) -> bool {
+        <C as Callable>::is_default(self.callable)
+    }
+
     fn visibility(&self) -> Visibility {
         <C as Callable>::visibility(self.callable)
     }
@@ -169,6 +173,13 @@ pub trait Callable {
     /// Returns `true` if the ink! callable is flagged as a wildcard selector.
     fn has_wildcard_selector(&self) -> bool;
 
+    /// Returns `true` if the ink! callable is flagged as default.
+    ///
+    /// # Note
+    ///
+    /// Flagging as default is done using the `#[ink(default)]` attribute.
+    fn is_default(&self) -> bool;
+
     /// Returns the visibility of the ink! callable.
     fn visibility(&self) -> Visibility;
 
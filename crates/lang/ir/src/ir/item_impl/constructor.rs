@@ -66,6 +66,8 @@ pub struct Constructor {
     pub(super) item: syn::ImplItemMethod,
     /// If the ink! constructor can receive funds.
     is_payable: bool,
+    /// If the ink! constructor is the default to be preselected by user interfaces.
+    is_default: bool,
     /// An optional user provided selector.
     ///
     /// # Note
@@ -92,14 +94,41 @@ impl Constructor {
         }) if path.is_ident("Self"))
     }
 
-    /// Ensures that the return type of the ink! constructor is `Self`.
+    /// Returns the `E` of the given type if it is `Result<Self, E>`.
+    fn result_self_error_type(ty: &syn::Type) -> Option<&syn::Type> {
+        let path = match ty {
+            syn::Type::Path(syn::TypePath { qself: None, path }) => path,
+            _ => return None,
+        };
+        let segment = path.segments.last()?;
+        if segment.ident != "Result" {
+            return None
+        }
+        let args = match &segment.arguments {
+            syn::PathArguments::AngleBracketed(args) if args.args.len() == 2 => {
+                &args.args
+            }
+            _ => return None,
+        };
+        match (&args[0], &args[1]) {
+            (syn::GenericArgument::Type(ok), syn::GenericArgument::Type(err))
+                if Self::type_is_self_val(ok) =>
+            {
+                Some(err)
+            }
+            _ => None,
+        }
+    }
+
+    /// Ensures that the return type of the ink! constructor is either `Self`
+    /// or `Result<Self, E>`.
     ///
     /// Returns an appropriate error otherwise.
     ///
     /// # Errors
     ///
-    /// If the ink! constructor does not return `Self` or is missing a return
-    /// type entirely.
+    /// If the ink! constructor does not return `Self` or `Result<Self, E>`
+    /// or is missing a return type entirely.
     fn ensure_valid_return_type(
         method_item: &syn::ImplItemMethod,
     ) -> Result<(), syn::Error> {
@@ -111,10 +140,12 @@ impl Constructor {
                 ))
             }
             syn::ReturnType::Type(_, return_type) => {
-                if !Self::type_is_self_val(return_type.as_ref()) {
+                if !Self::type_is_self_val(return_type.as_ref())
+                    && Self::result_self_error_type(return_type.as_ref()).is_none()
+                {
                     return Err(format_err_spanned!(
                         return_type,
-                        "ink! constructors must return Self",
+                        "ink! constructors must return Self or Result<Self, E>",
                     ))
                 }
             }
@@ -159,6 +190,7 @@ impl Constructor {
                 match arg.kind() {
                     ir::AttributeArg::Constructor
                     | ir::AttributeArg::Payable
+                    | ir::AttributeArg::Default
                     | ir::AttributeArg::Selector(_) => Ok(()),
                     _ => Err(None),
                 }
@@ -176,10 +208,12 @@ impl TryFrom<syn::ImplItemMethod> for Constructor {
         Self::ensure_no_self_receiver(&method_item)?;
         let (ink_attrs, other_attrs) = Self::sanitize_attributes(&method_item)?;
         let is_payable = ink_attrs.is_payable();
+        let is_default = ink_attrs.is_default();
         let selector = ink_attrs.selector();
         Ok(Constructor {
             selector,
             is_payable,
+            is_default,
             item: syn::ImplItemMethod {
                 attrs: other_attrs,
                 ..method_item
@@ -215,6 +249,10 @@ impl Callable for Constructor {
         self.is_payable
     }
 
+    fn is_default(&self) -> bool {
+        self.is_default
+    }
+
     fn visibility(&self) -> Visibility {
         match &self.item.vis {
             syn::Visibility::Public(vis_public) => Visibility::Public(vis_public.clone()),
@@ -241,6 +279,30 @@ impl Constructor {
     pub fn attrs(&self) -> &[syn::Attribute] {
         &self.item.attrs
     }
+
+    /// Returns the return type of the ink! constructor.
+    ///
+    /// This is either `Self` or `Result<Self, E>`.
+    pub fn output(&self) -> &syn::Type {
+        match &self.item.sig.output {
+            syn::ReturnType::Type(_, return_type) => return_type,
+            syn::ReturnType::Default => {
+                unreachable!("encountered ink! constructor without return type")
+            }
+        }
+    }
+
+    /// Returns the error type `E` if the ink! constructor returns `Result<Self, E>`.
+    ///
+    /// Instantiation is reverted if such a fallible ink! constructor returns an error.
+    pub fn error_type(&self) -> Option<&syn::Type> {
+        Self::result_self_error_type(self.output())
+    }
+
+    /// Returns `true` if the ink! constructor returns `Result<Self, E>`.
+    pub fn is_fallible(&self) -> bool {
+        self.error_type().is_some()
+    }
 }
 
 #[cfg(test)]
@@ -390,12 +452,55 @@ mod tests {
                 #[ink(constructor)]
                 fn my_constructor(input1: i32, input2: i64, input3: u32, input4: u64) -> Self {}
             },
+            // fallible
+            syn::parse_quote! {
+                #[ink(constructor)]
+                pub fn my_constructor() -> Result<Self, Error> {}
+            },
+            // fallible + fully qualified result type
+            syn::parse_quote! {
+                #[ink(constructor)]
+                pub fn my_constructor() -> ::core::result::Result<Self, Error> {}
+            },
         ];
         for item_method in item_methods {
             assert!(<ir::Constructor as TryFrom<_>>::try_from(item_method).is_ok());
         }
     }
 
+    #[test]
+    fn is_fallible_works() {
+        let test_inputs: Vec<(Option<syn::Type>, syn::ImplItemMethod)> = vec![
+            (
+                None,
+                syn::parse_quote! {
+                    #[ink(constructor)]
+                    fn my_constructor() -> Self {}
+                },
+            ),
+            (
+                Some(syn::parse_quote! { Error }),
+                syn::parse_quote! {
+                    #[ink(constructor)]
+                    fn my_constructor() -> Result<Self, Error> {}
+                },
+            ),
+            (
+                Some(syn::parse_quote! { (u8, String) }),
+                syn::parse_quote! {
+                    #[ink(constructor)]
+                    fn my_constructor() -> core::result::Result<Self, (u8, String)> {}
+                },
+            ),
+        ];
+        for (expected_error, item_method) in test_inputs {
+            let constructor =
+                <ir::Constructor as TryFrom<_>>::try_from(item_method).unwrap();
+            assert_eq!(constructor.error_type(), expected_error.as_ref());
+            assert_eq!(constructor.is_fallible(), expected_error.is_some());
+        }
+    }
+
     fn assert_try_from_fails(item_method: syn::ImplItemMethod, expected_err: &str) {
         assert_eq!(
             <ir::Constructor as TryFrom<_>>::try_from(item_method)
@@ -438,11 +543,22 @@ mod tests {
             },
             syn::parse_quote! {
                 #[ink(constructor)]
-                pub fn my_constructor() -> Result<Self, ()> {}
+                pub fn my_constructor() -> Result<i32, ()> {}
+            },
+            syn::parse_quote! {
+                #[ink(constructor)]
+                pub fn my_constructor() -> Result<Self> {}
+            },
+            syn::parse_quote! {
+                #[ink(constructor)]
+                pub fn my_constructor() -> Option<Self> {}
             },
         ];
         for item_method in item_methods {
-            assert_try_from_fails(item_method, "ink! constructors must return Self")
+            assert_try_from_fails(
+                item_method,
+                "ink! constructors must return Self or Result<Self, E>",
+            )
         }
     }
 
@@ -0,0 +1,251 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::ir;
+use proc_macro2::Ident;
+use syn::spanned::Spanned as _;
+
+/// The kind of an ink! hook.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HookKind {
+    /// The hook is invoked before every ink! message.
+    BeforeCall,
+    /// The hook is invoked after every ink! message.
+    AfterCall,
+}
+
+impl core::fmt::Display for HookKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        match self {
+            Self::BeforeCall => write!(f, "before_call"),
+            Self::AfterCall => write!(f, "after_call"),
+        }
+    }
+}
+
+/// An ink! hook that is invoked around every ink! message.
+///
+/// # Example
+///
+/// ```
+/// # <ink_lang_ir::ItemImpl as TryFrom<syn::ItemImpl>>::try_from(syn::parse_quote! {
+/// impl MyStorage {
+///     #[ink(hook(before_call))]
+///     fn before_call(&self, selector: [u8; 4]) {
+///         /* hook implementation goes here */
+///     }
+/// }
+/// # }).unwrap();
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct Hook {
+    /// The underlying Rust method item.
+    item: syn::ImplItemMethod,
+    /// The kind of the ink! hook.
+    kind: HookKind,
+}
+
+impl quote::ToTokens for Hook {
+    /// We mainly implement this trait for this ink! type to have a derived
+    /// [`Spanned`](`syn::spanned::Spanned`) implementation for it.
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.item.to_tokens(tokens)
+    }
+}
+
+impl Hook {
+    /// Ensures that the signature of the ink! hook is `fn(&self, selector: [u8; 4])`.
+    ///
+    /// The type of the selector parameter is checked by the Rust compiler.
+    fn ensure_valid_signature(
+        method_item: &syn::ImplItemMethod,
+    ) -> Result<(), syn::Error> {
+        let sig = &method_item.sig;
+        if !sig.generics.params.is_empty() {
+            return Err(format_err_spanned!(
+                sig.generics.params,
+                "ink! hooks must not be generic",
+            ))
+        }
+        if let Some(asyncness) = sig.asyncness {
+            return Err(format_err_spanned!(
+                asyncness,
+                "ink! hooks must not be async",
+            ))
+        }
+        let mut fn_args = sig.inputs.iter();
+        match fn_args.next() {
+            Some(syn::FnArg::Receiver(receiver))
+                if receiver.reference.is_some() && receiver.mutability.is_none() => {}
+            Some(arg) => {
+                return Err(format_err_spanned!(
+                    arg,
+                    "ink! hooks must have a `&self` receiver",
+                ))
+            }
+            None => {
+                return Err(format_err_spanned!(
+                    sig,
+                    "ink! hooks must have a `&self` receiver",
+                ))
+            }
+        }
+        if fn_args.len() != 1 {
+            return Err(format_err_spanned!(
+                sig.inputs,
+                "ink! hooks must take exactly one `[u8; 4]` selector parameter",
+            ))
+        }
+        if let syn::ReturnType::Type(_, ret_type) = &sig.output {
+            return Err(format_err_spanned!(
+                ret_type,
+                "ink! hooks must not return a value",
+            ))
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<syn::ImplItemMethod> for Hook {
+    type Error = syn::Error;
+
+    fn try_from(method_item: syn::ImplItemMethod) -> Result<Self, Self::Error> {
+        Self::ensure_valid_signature(&method_item)?;
+        let (ink_attrs, other_attrs) = ir::sanitize_attributes(
+            method_item.span(),
+            method_item.attrs.clone(),
+            &ir::AttributeArgKind::Hook,
+            |arg| {
+                match arg.kind() {
+                    ir::AttributeArg::Hook(_) => Ok(()),
+                    _ => Err(None),
+                }
+            },
+        )?;
+        let kind = ink_attrs
+            .hook()
+            .expect("encountered missing kind of ink! hook");
+        Ok(Self {
+            kind,
+            item: syn::ImplItemMethod {
+                attrs: other_attrs,
+                ..method_item
+            },
+        })
+    }
+}
+
+impl Hook {
+    /// Returns the kind of the ink! hook.
+    pub fn kind(&self) -> HookKind {
+        self.kind
+    }
+
+    /// Returns the identifier of the ink! hook.
+    pub fn ident(&self) -> &Ident {
+        &self.item.sig.ident
+    }
+
+    /// Returns the underlying Rust method item of the ink! hook.
+    ///
+    /// # Note
+    ///
+    /// The ink! attributes of the hook have already been stripped.
+    pub fn item(&self) -> &syn::ImplItemMethod {
+        &self.item
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_try_from_fails(item_method: syn::ImplItemMethod, expected: &str) {
+        assert_eq!(
+            <Hook as TryFrom<_>>::try_from(item_method).map_err(|err| err.to_string()),
+            Err(expected.to_string()),
+        )
+    }
+
+    #[test]
+    fn try_from_works() {
+        let hook = <Hook as TryFrom<syn::ImplItemMethod>>::try_from(syn::parse_quote! {
+            /// Invoked after every ink! message.
+            #[ink(hook(after_call))]
+            fn after_call(&self, selector: [u8; 4]) {}
+        })
+        .unwrap();
+        assert_eq!(hook.kind(), HookKind::AfterCall);
+        assert_eq!(hook.ident(), "after_call");
+        assert_eq!(hook.item().attrs.len(), 1);
+    }
+
+    #[test]
+    fn invalid_receiver_fails() {
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(hook(before_call))]
+                fn before_call(&mut self, selector: [u8; 4]) {}
+            },
+            "ink! hooks must have a `&self` receiver",
+        );
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(hook(before_call))]
+                fn before_call(selector: [u8; 4]) {}
+            },
+            "ink! hooks must have a `&self` receiver",
+        );
+    }
+
+    #[test]
+    fn invalid_inputs_fails() {
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(hook(before_call))]
+                fn before_call(&self) {}
+            },
+            "ink! hooks must take exactly one `[u8; 4]` selector parameter",
+        );
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(hook(before_call))]
+                fn before_call(&self, selector: [u8; 4], caller: AccountId) {}
+            },
+            "ink! hooks must take exactly one `[u8; 4]` selector parameter",
+        );
+    }
+
+    #[test]
+    fn return_value_fails() {
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(hook(before_call))]
+                fn before_call(&self, selector: [u8; 4]) -> bool { true }
+            },
+            "ink! hooks must not return a value",
+        );
+    }
+
+    #[test]
+    fn conflicting_attributes_fails() {
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(hook(before_call), payable)]
+                fn before_call(&self, selector: [u8; 4]) {}
+            },
+            "encountered conflicting ink! attribute argument",
+        );
+    }
+}
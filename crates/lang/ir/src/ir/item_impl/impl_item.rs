@@ -14,6 +14,7 @@
 
 use super::{
     Constructor,
+    Hook,
     Message,
 };
 use crate::{
@@ -28,6 +29,7 @@ use syn::spanned::Spanned as _;
 /// Can be either
 /// - an ink! [`ir::Constructor`](`crate::ir::Constructor`)
 /// - an ink! [`ir::Message`](`crate::ir::Message`)
+/// - an ink! [`ir::Hook`](`crate::ir::Hook`)
 /// - or any other non-ink! item.
 ///
 /// # Note
@@ -40,6 +42,8 @@ pub enum ImplItem {
     Constructor(Constructor),
     /// A `#[ink(message)]` marked method.
     Message(Message),
+    /// A `#[ink(hook(..))]` marked method.
+    Hook(Hook),
     /// Any other implementation block item.
     Other(syn::ImplItem),
 }
@@ -51,6 +55,7 @@ impl quote::ToTokens for ImplItem {
         match self {
             Self::Constructor(constructor) => constructor.to_tokens(tokens),
             Self::Message(message) => message.to_tokens(tokens),
+            Self::Hook(hook) => hook.to_tokens(tokens),
             Self::Other(other) => other.to_tokens(tokens),
         }
     }
@@ -78,6 +83,9 @@ impl TryFrom<syn::ImplItem> for ImplItem {
                             .map(Into::into)
                             .map(Self::Constructor)
                     }
+                    ir::AttributeArg::Hook(_) => {
+                        <Hook as TryFrom<_>>::try_from(method_item).map(Self::Hook)
+                    }
                     _ => Err(format_err_spanned!(
                         method_item,
                         "encountered invalid ink! attribute at this point, expected either \
@@ -137,6 +145,16 @@ impl ImplItem {
         }
     }
 
+    /// Returns `Some` if `self` is an ink! hook.
+    ///
+    /// Otherwise, returns `None`.
+    pub fn filter_map_hook(&self) -> Option<&Hook> {
+        match self {
+            ImplItem::Hook(hook) => Some(hook),
+            _ => None,
+        }
+    }
+
     /// Returns `true` if the `impl` block item is a non ink! specific item.
     pub fn is_other_item(&self) -> bool {
         self.filter_map_other_item().is_some()
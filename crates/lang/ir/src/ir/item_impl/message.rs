@@ -98,6 +98,8 @@ pub struct Message {
     pub(super) item: syn::ImplItemMethod,
     /// If the ink! message can receive funds.
     is_payable: bool,
+    /// If the ink! message is the default to be preselected by user interfaces.
+    is_default: bool,
     /// An optional user provided selector.
     ///
     /// # Note
@@ -105,6 +107,12 @@ pub struct Message {
     /// This overrides the computed selector, even when using a manual namespace
     /// for the parent implementation block.
     selector: Option<SelectorOrWildcard>,
+    /// An optional guard method that is invoked before the body of the message.
+    guard: Option<Ident>,
+    /// If the ink! message reverts on reentrant calls.
+    is_non_reentrant: bool,
+    /// The deprecation note of the ink! message if it is deprecated.
+    deprecated: Option<String>,
 }
 
 impl quote::ToTokens for Message {
@@ -185,7 +193,11 @@ impl Message {
                 match arg.kind() {
                     ir::AttributeArg::Message
                     | ir::AttributeArg::Payable
-                    | ir::AttributeArg::Selector(_) => Ok(()),
+                    | ir::AttributeArg::Default
+                    | ir::AttributeArg::Selector(_)
+                    | ir::AttributeArg::Guard(_)
+                    | ir::AttributeArg::NonReentrant
+                    | ir::AttributeArg::Deprecated(_) => Ok(()),
                     _ => Err(None),
                 }
             },
@@ -202,10 +214,18 @@ impl TryFrom<syn::ImplItemMethod> for Message {
         Self::ensure_not_return_self(&method_item)?;
         let (ink_attrs, other_attrs) = Self::sanitize_attributes(&method_item)?;
         let is_payable = ink_attrs.is_payable();
+        let is_default = ink_attrs.is_default();
         let selector = ink_attrs.selector();
+        let guard = ink_attrs.guard();
+        let is_non_reentrant = ink_attrs.is_non_reentrant();
+        let deprecated = ink_attrs.deprecated();
         Ok(Self {
             is_payable,
+            is_default,
             selector,
+            guard,
+            is_non_reentrant,
+            deprecated,
             item: syn::ImplItemMethod {
                 attrs: other_attrs,
                 ..method_item
@@ -241,6 +261,10 @@ impl Callable for Message {
         self.is_payable
     }
 
+    fn is_default(&self) -> bool {
+        self.is_default
+    }
+
     fn visibility(&self) -> Visibility {
         match &self.item.vis {
             syn::Visibility::Public(vis_public) => Visibility::Public(vis_public.clone()),
@@ -283,6 +307,24 @@ impl Message {
         }
     }
 
+    /// Returns the guard method of the ink! message if any.
+    ///
+    /// The guard is invoked with the `self` receiver before the body of the message.
+    pub fn guard(&self) -> Option<&Ident> {
+        self.guard.as_ref()
+    }
+
+    /// Returns `true` if the ink! message reverts when the contract is reentered
+    /// while it is executing.
+    pub fn is_non_reentrant(&self) -> bool {
+        self.is_non_reentrant
+    }
+
+    /// Returns the deprecation note of the ink! message if it is deprecated.
+    pub fn deprecated(&self) -> Option<&str> {
+        self.deprecated.as_deref()
+    }
+
     /// Returns the return type of the ink! message if any.
     pub fn output(&self) -> Option<&syn::Type> {
         match &self.item.sig.output {
@@ -291,6 +333,14 @@ impl Message {
         }
     }
 
+    /// Returns `true` if the ink! message is declared without a body.
+    ///
+    /// Such ink! messages inherit the default implementation of the ink! trait
+    /// definition and are only allowed in ink! trait implementation blocks.
+    pub fn inherits_default_impl(&self) -> bool {
+        utils::is_omitted_body(&self.item.block)
+    }
+
     /// Returns a local ID unique to the ink! message with respect to its implementation block.
     ///
     /// # Note
@@ -394,6 +444,113 @@ mod tests {
         }
     }
 
+    #[test]
+    fn guard_works() {
+        let test_inputs: Vec<(Option<Ident>, syn::ImplItemMethod)> = vec![
+            // No guard.
+            (
+                None,
+                syn::parse_quote! {
+                    #[ink(message)]
+                    fn my_message(&self) {}
+                },
+            ),
+            // Normalized ink! attribute.
+            (
+                Some(syn::parse_quote! { only_owner }),
+                syn::parse_quote! {
+                    #[ink(message, guard = "only_owner")]
+                    pub fn my_message(&mut self) -> Result<(), Error> {}
+                },
+            ),
+            // Different ink! attributes.
+            (
+                Some(syn::parse_quote! { only_owner }),
+                syn::parse_quote! {
+                    #[ink(message, payable)]
+                    #[ink(guard = "only_owner")]
+                    pub fn my_message(&self) -> Result<(), Error> {}
+                },
+            ),
+        ];
+        for (expected_guard, item_method) in test_inputs {
+            let message = <ir::Message as TryFrom<_>>::try_from(item_method).unwrap();
+            assert_eq!(message.guard(), expected_guard.as_ref());
+        }
+    }
+
+    #[test]
+    fn deprecated_works() {
+        let test_inputs: Vec<(Option<&str>, syn::ImplItemMethod)> = vec![
+            // Not deprecated by default.
+            (
+                None,
+                syn::parse_quote! {
+                    #[ink(message)]
+                    fn my_message(&self) {}
+                },
+            ),
+            // Normalized ink! attribute.
+            (
+                Some("use my_message_v2"),
+                syn::parse_quote! {
+                    #[ink(message, deprecated = "use my_message_v2")]
+                    pub fn my_message(&self) {}
+                },
+            ),
+            // Different ink! attributes.
+            (
+                Some("use my_message_v2"),
+                syn::parse_quote! {
+                    #[ink(message, payable)]
+                    #[ink(deprecated = "use my_message_v2")]
+                    pub fn my_message(&mut self) {}
+                },
+            ),
+        ];
+        for (expected_note, item_method) in test_inputs {
+            let message = <ir::Message as TryFrom<_>>::try_from(item_method).unwrap();
+            assert_eq!(message.deprecated(), expected_note);
+        }
+    }
+
+    #[test]
+    fn is_non_reentrant_works() {
+        let test_inputs: Vec<(bool, syn::ImplItemMethod)> = vec![
+            // Reentrant by default.
+            (
+                false,
+                syn::parse_quote! {
+                    #[ink(message)]
+                    fn my_message(&mut self) {}
+                },
+            ),
+            // Normalized ink! attribute.
+            (
+                true,
+                syn::parse_quote! {
+                    #[ink(message, non_reentrant)]
+                    pub fn my_message(&mut self) {}
+                },
+            ),
+            // Different ink! attributes.
+            (
+                true,
+                syn::parse_quote! {
+                    #[ink(message, payable)]
+                    #[ink(non_reentrant)]
+                    pub fn my_message(&mut self) {}
+                },
+            ),
+        ];
+        for (expect_non_reentrant, item_method) in test_inputs {
+            let is_non_reentrant = <ir::Message as TryFrom<_>>::try_from(item_method)
+                .unwrap()
+                .is_non_reentrant();
+            assert_eq!(is_non_reentrant, expect_non_reentrant);
+        }
+    }
+
     #[test]
     fn is_payable_works() {
         let test_inputs: Vec<(bool, syn::ImplItemMethod)> = vec![
@@ -17,6 +17,7 @@ use crate::{
     ir,
     ir::attrs::Attrs as _,
 };
+use heck::ToSnakeCase as _;
 use proc_macro2::{
     Ident,
     Span,
@@ -24,6 +25,7 @@ use proc_macro2::{
 
 mod callable;
 mod constructor;
+mod hook;
 mod impl_item;
 mod iter;
 mod message;
@@ -41,6 +43,10 @@ pub use self::{
         Visibility,
     },
     constructor::Constructor,
+    hook::{
+        Hook,
+        HookKind,
+    },
     impl_item::ImplItem,
     iter::{
         IterConstructors,
@@ -78,6 +84,10 @@ pub struct ItemImpl {
     /// A namespace to disambiguate trait implementation blocks with equal
     /// names. Generally can be used to change computation of message and
     /// constructor selectors of the implementation block.
+    ///
+    /// The selectors of ink! trait messages are defined by their ink! trait
+    /// definition. On trait implementation blocks the namespace therefore has
+    /// to be equal to the namespace of the implemented ink! trait definition.
     namespace: Option<ir::Namespace>,
 }
 
@@ -194,9 +204,9 @@ impl ItemImpl {
                     let attr = ir::first_ink_attribute(&method_item.attrs)?
                         .expect("missing expected ink! attribute for struct");
                     match attr.first().kind() {
-                        ir::AttributeArg::Constructor | ir::AttributeArg::Message => {
-                            return Ok(true)
-                        }
+                        ir::AttributeArg::Constructor
+                        | ir::AttributeArg::Message
+                        | ir::AttributeArg::Hook(_) => return Ok(true),
                         _ => continue 'repeat,
                     }
                 }
@@ -276,6 +286,12 @@ impl TryFrom<syn::ItemImpl> for ItemImpl {
                         "message",
                         is_trait_impl,
                     )?;
+                    if message.inherits_default_impl() && !is_trait_impl {
+                        return Err(format_err!(
+                            message.item.span(),
+                            "ink! messages without body are only allowed in trait impl blocks",
+                        ))
+                    }
                 }
                 ir::ImplItem::Constructor(constructor) => {
                     ensure_valid_visibility(
@@ -284,6 +300,18 @@ impl TryFrom<syn::ItemImpl> for ItemImpl {
                         "constructor",
                         is_trait_impl,
                     )?;
+                    if ir::utils::is_omitted_body(&constructor.item.block) {
+                        return Err(format_err!(
+                            constructor.item.span(),
+                            "ink! constructors must have a body",
+                        ))
+                    }
+                }
+                ir::ImplItem::Hook(hook) if is_trait_impl => {
+                    return Err(format_err!(
+                        hook.span(),
+                        "ink! hooks are only allowed in inherent impl blocks",
+                    ))
                 }
                 _ => (),
             }
@@ -305,12 +333,6 @@ impl TryFrom<syn::ItemImpl> for ItemImpl {
             })?;
             namespace = normalized.namespace();
         }
-        if namespace.is_some() && is_trait_impl {
-            return Err(format_err!(
-                impl_block_span,
-                "namespace ink! property is not allowed on ink! trait implementation blocks",
-            ))
-        }
         Ok(Self {
             attrs: other_attrs,
             defaultness: item_impl.defaultness,
@@ -353,6 +375,15 @@ impl ItemImpl {
             .map(|segment| &segment.ident)
     }
 
+    /// Returns the snake case name of the trait if this is a trait implementation block.
+    ///
+    /// The contract reference names its conversions into and from the ink! trait
+    /// reference after it, e.g. `as_psp22` and `from_psp22_ref` for a trait `Psp22`.
+    pub fn trait_snake_name(&self) -> Option<String> {
+        self.trait_ident()
+            .map(|trait_ident| trait_ident.to_string().to_snake_case())
+    }
+
     /// Returns the namespace of the implementation block if any has been provided.
     pub fn namespace(&self) -> Option<&ir::Namespace> {
         self.namespace.as_ref()
@@ -368,6 +399,11 @@ impl ItemImpl {
         IterConstructors::new(self)
     }
 
+    /// Returns an iterator yielding the ink! hooks of the implementation block.
+    pub fn iter_hooks(&self) -> impl Iterator<Item = &ir::Hook> {
+        self.items.iter().filter_map(ir::ImplItem::filter_map_hook)
+    }
+
     /// Returns a slice over the shared references of the items of the `impl`.
     pub fn items(&self) -> &[ir::ImplItem] {
         &self.items
@@ -206,6 +206,41 @@ fn visibility_fails() {
     );
 }
 
+#[test]
+fn omitted_body_fails() {
+    assert_try_from_item_impl_fails(
+        syn::parse_quote! {
+            impl MyStorage {
+                #[ink(message)]
+                pub fn my_message(&self);
+            }
+        },
+        "ink! messages without body are only allowed in trait impl blocks",
+    );
+    assert_try_from_item_impl_fails(
+        syn::parse_quote! {
+            impl MyStorage {
+                #[ink(constructor)]
+                pub fn my_constructor() -> Self;
+            }
+        },
+        "ink! constructors must have a body",
+    );
+}
+
+#[test]
+fn hook_in_trait_impl_fails() {
+    assert_try_from_item_impl_fails(
+        syn::parse_quote! {
+            impl MyTrait for MyStorage {
+                #[ink(hook(before_call))]
+                fn before_call(&self, selector: [u8; 4]) {}
+            }
+        },
+        "ink! hooks are only allowed in inherent impl blocks",
+    );
+}
+
 #[test]
 fn try_from_works() {
     let item_impls: Vec<syn::ItemImpl> = vec![
@@ -229,6 +264,12 @@ fn try_from_works() {
                 fn my_message(&self) {}
             }
         },
+        syn::parse_quote! {
+            impl MyTrait for MyStorage {
+                #[ink(message)]
+                fn my_message(&self);
+            }
+        },
     ];
     for item_impl in item_impls {
         assert!(<ir::ItemImpl as TryFrom<syn::ItemImpl>>::try_from(item_impl).is_ok())
@@ -253,3 +294,22 @@ fn namespace_works() {
         ))
     )
 }
+
+#[test]
+fn trait_impl_namespace_works() {
+    let impl_block: ir::ItemImpl =
+        <ir::ItemImpl as TryFrom<syn::ItemImpl>>::try_from(syn::parse_quote! {
+            #[ink(namespace = "my_namespace")]
+            impl MyTrait for MyStorage {
+                #[ink(message)]
+                fn my_message(&self) {}
+            }
+        })
+        .unwrap();
+    assert_eq!(
+        impl_block.namespace,
+        Some(ir::Namespace::from(
+            "my_namespace".to_string().as_bytes().to_vec()
+        ))
+    )
+}
@@ -290,6 +290,192 @@ impl ItemMod {
         }
         Ok(())
     }
+
+    /// Ensures that at most one ink! message, as well as at most one ink! constructor,
+    /// is flagged as default.
+    fn ensure_at_most_one_default(items: &[ir::Item]) -> Result<(), syn::Error> {
+        use crate::error::ExtError as _;
+        let mut default_message: Option<&ir::Message> = None;
+        let mut default_constructor: Option<&ir::Constructor> = None;
+        for item_impl in items
+            .iter()
+            .filter_map(ir::Item::map_ink_item)
+            .filter_map(ir::InkItem::filter_map_impl_block)
+        {
+            for message in item_impl.iter_messages() {
+                if !message.is_default() {
+                    continue
+                }
+                if let Some(first) = default_message {
+                    return Err(format_err!(
+                        message.callable().span(),
+                        "encountered multiple default ink! messages",
+                    )
+                    .into_combine(format_err!(
+                        first.span(),
+                        "first default ink! message here",
+                    )))
+                }
+                default_message = Some(message.callable());
+            }
+            for constructor in item_impl.iter_constructors() {
+                if !constructor.is_default() {
+                    continue
+                }
+                if let Some(first) = default_constructor {
+                    return Err(format_err!(
+                        constructor.callable().span(),
+                        "encountered multiple default ink! constructors",
+                    )
+                    .into_combine(format_err!(
+                        first.span(),
+                        "first default ink! constructor here",
+                    )))
+                }
+                default_constructor = Some(constructor.callable());
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures that there is at most one ink! hook of each kind.
+    fn ensure_at_most_one_hook_per_kind(items: &[ir::Item]) -> Result<(), syn::Error> {
+        let mut hooks = <HashMap<ir::HookKind, &ir::Hook>>::new();
+        for hook in items
+            .iter()
+            .filter_map(ir::Item::map_ink_item)
+            .filter_map(ir::InkItem::filter_map_impl_block)
+            .flat_map(ir::ItemImpl::iter_hooks)
+        {
+            if let Some(overlap) = hooks.insert(hook.kind(), hook) {
+                use crate::error::ExtError as _;
+                return Err(format_err!(
+                    hook.span(),
+                    "encountered multiple ink! `{}` hooks",
+                    hook.kind(),
+                )
+                .into_combine(format_err!(
+                    overlap.span(),
+                    "first ink! `{}` hook here",
+                    overlap.kind(),
+                )))
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures that the conversions of the contract reference into and from the ink!
+    /// trait references do not collide.
+    ///
+    /// They are named after the implemented ink! traits, e.g. `as_psp22` and
+    /// `from_psp22_ref`, and therefore require unique trait names that do not clash
+    /// with the names of inherent ink! messages.
+    fn ensure_unique_trait_ref_casts(items: &[ir::Item]) -> Result<(), syn::Error> {
+        use crate::error::ExtError as _;
+        let impls = items
+            .iter()
+            .filter_map(ir::Item::map_ink_item)
+            .filter_map(ir::InkItem::filter_map_impl_block)
+            .collect::<Vec<_>>();
+        let mut trait_impls = <HashMap<String, &ir::ItemImpl>>::new();
+        for item_impl in &impls {
+            let trait_name = match item_impl.trait_snake_name() {
+                Some(trait_name) => trait_name,
+                None => continue,
+            };
+            if let Some(overlap) = trait_impls.insert(trait_name.clone(), item_impl) {
+                return Err(format_err!(
+                    item_impl.span(),
+                    "encountered multiple implemented ink! traits named `{}` \
+                    which would share the `as_{}` and `from_{}_ref` conversions of the contract reference",
+                    trait_name,
+                    trait_name,
+                    trait_name,
+                )
+                .into_combine(format_err!(
+                    overlap.span(),
+                    "first ink! trait implementation here",
+                )))
+            }
+        }
+        for message in impls
+            .iter()
+            .filter(|item_impl| item_impl.trait_path().is_none())
+            .flat_map(|item_impl| item_impl.iter_messages())
+        {
+            let message_name = message.ident().to_string();
+            for (trait_name, item_impl) in &trait_impls {
+                if message_name == format!("as_{}", trait_name)
+                    || message_name == format!("from_{}_ref", trait_name)
+                {
+                    return Err(format_err!(
+                        message.callable().span(),
+                        "ink! message `{}` collides with the conversion of the contract reference \
+                        for the implemented ink! trait `{}`",
+                        message_name,
+                        item_impl
+                            .trait_ident()
+                            .expect("encountered ink! trait implementation without trait"),
+                    )
+                    .into_combine(format_err!(
+                        item_impl.span(),
+                        "ink! trait implementation here",
+                    )))
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ItemMod {
+    /// Moves the ink! items of inline submodules into the root of the ink! module.
+    ///
+    /// The hoisted ink! items directly follow the submodule they have been defined
+    /// in, which keeps all remaining non-ink! items. This allows to split large
+    /// ink! smart contracts into several inline submodules.
+    ///
+    /// # Errors
+    ///
+    /// If invalid or malformed ink! attributes are encountered.
+    fn hoist_submodule_ink_items(
+        items: Vec<syn::Item>,
+    ) -> Result<Vec<syn::Item>, syn::Error> {
+        let mut hoisted = Vec::with_capacity(items.len());
+        for item in items {
+            match item {
+                syn::Item::Mod(syn::ItemMod {
+                    content: Some((brace, sub_items)),
+                    attrs,
+                    vis,
+                    mod_token,
+                    ident,
+                    semi,
+                }) => {
+                    let mut rust_items = Vec::new();
+                    let mut ink_items = Vec::new();
+                    for sub_item in Self::hoist_submodule_ink_items(sub_items)? {
+                        if ir::InkItem::is_ink_item(&sub_item)? {
+                            ink_items.push(sub_item);
+                        } else {
+                            rust_items.push(sub_item);
+                        }
+                    }
+                    hoisted.push(syn::Item::Mod(syn::ItemMod {
+                        attrs,
+                        vis,
+                        mod_token,
+                        ident,
+                        content: Some((brace, rust_items)),
+                        semi,
+                    }));
+                    hoisted.extend(ink_items);
+                }
+                item => hoisted.push(item),
+            }
+        }
+        Ok(hoisted)
+    }
 }
 
 impl TryFrom<syn::ItemMod> for ItemMod {
@@ -321,7 +507,7 @@ impl TryFrom<syn::ItemMod> for ItemMod {
             }
             return Err(error)
         }
-        let items = items
+        let items = Self::hoist_submodule_ink_items(items)?
             .into_iter()
             .map(<ir::Item as TryFrom<syn::Item>>::try_from)
             .collect::<Result<Vec<_>, syn::Error>>()?;
@@ -330,6 +516,9 @@ impl TryFrom<syn::ItemMod> for ItemMod {
         Self::ensure_contains_constructor(module_span, &items)?;
         Self::ensure_no_overlapping_selectors(&items)?;
         Self::ensure_only_one_wildcard_selector(&items)?;
+        Self::ensure_at_most_one_default(&items)?;
+        Self::ensure_at_most_one_hook_per_kind(&items)?;
+        Self::ensure_unique_trait_ref_casts(&items)?;
         Ok(Self {
             attrs: other_attrs,
             vis: module.vis,
@@ -376,7 +565,7 @@ impl ItemMod {
     ///
     /// The storage definition is the struct that has been annotated with
     /// `#[ink(storage)]`. This struct is required to be defined in the root
-    /// of the ink! inline module.
+    /// of the ink! inline module or in one of its inline submodules.
     ///
     /// # Panics
     ///
@@ -471,11 +660,23 @@ impl ItemMod {
         IterItemImpls::new(self)
     }
 
+    /// Returns the ink! hook of the given kind if any.
+    pub fn hook(&self, kind: ir::HookKind) -> Option<&ir::Hook> {
+        self.impls()
+            .flat_map(ir::ItemImpl::iter_hooks)
+            .find(|hook| hook.kind() == kind)
+    }
+
     /// Returns an iterator yielding all event definitions in this ink! module.
     pub fn events(&self) -> IterEvents {
         IterEvents::new(self)
     }
 
+    /// Returns an iterator yielding all imports of shared ink! events in this ink! module.
+    pub fn shared_events(&self) -> IterSharedEvents<'_> {
+        IterSharedEvents::new(self)
+    }
+
     /// Returns all non-ink! attributes of the ink! module.
     pub fn attrs(&self) -> &[syn::Attribute] {
         &self.attrs
@@ -552,6 +753,39 @@ impl<'a> Iterator for IterEvents<'a> {
     }
 }
 
+/// Iterator yielding all imports of shared ink! events within the ink!
+/// [`ItemMod`](`crate::ir::ItemMod`).
+pub struct IterSharedEvents<'a> {
+    items_iter: IterInkItems<'a>,
+}
+
+impl<'a> IterSharedEvents<'a> {
+    /// Creates a new shared ink! events iterator.
+    fn new(ink_module: &'a ItemMod) -> Self {
+        Self {
+            items_iter: IterInkItems::new(ink_module),
+        }
+    }
+}
+
+impl<'a> Iterator for IterSharedEvents<'a> {
+    type Item = &'a ir::SharedEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        'repeat: loop {
+            match self.items_iter.next() {
+                None => return None,
+                Some(ink_item) => {
+                    if let Some(shared_event) = ink_item.filter_map_shared_event_item() {
+                        return Some(shared_event)
+                    }
+                    continue 'repeat
+                }
+            }
+        }
+    }
+}
+
 /// Iterator yielding all ink! implementation block definitions within the ink!
 /// [`ItemMod`](`crate::ir::ItemMod`).
 pub struct IterItemImpls<'a> {
@@ -879,6 +1113,220 @@ mod tests {
         );
     }
 
+    #[test]
+    fn default_callables_work() {
+        use ir::Callable as _;
+        let item_mod =
+            <ir::ItemMod as TryFrom<syn::ItemMod>>::try_from(syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+
+                        #[ink(constructor, default)]
+                        pub fn my_default_constructor() -> Self {}
+
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+
+                        #[ink(message, default)]
+                        pub fn my_default_message(&self) {}
+                    }
+                }
+            })
+            .unwrap();
+        let default_messages = item_mod
+            .impls()
+            .flat_map(|item_impl| item_impl.iter_messages())
+            .filter(|message| message.is_default())
+            .map(|message| message.ident().to_string())
+            .collect::<Vec<_>>();
+        let default_constructors = item_mod
+            .impls()
+            .flat_map(|item_impl| item_impl.iter_constructors())
+            .filter(|constructor| constructor.is_default())
+            .map(|constructor| constructor.ident().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(default_messages, vec!["my_default_message"]);
+        assert_eq!(default_constructors, vec!["my_default_constructor"]);
+    }
+
+    #[test]
+    fn multiple_default_messages_fails() {
+        assert_fail(
+            syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+
+                        #[ink(message, default)]
+                        pub fn my_message1(&self) {}
+                    }
+
+                    impl MyStorage {
+                        #[ink(message, default)]
+                        pub fn my_message2(&self) {}
+                    }
+                }
+            },
+            "encountered multiple default ink! messages",
+        );
+    }
+
+    #[test]
+    fn multiple_default_constructors_fails() {
+        assert_fail(
+            syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor, default)]
+                        pub fn my_constructor1() -> Self {}
+
+                        #[ink(constructor, default)]
+                        pub fn my_constructor2() -> Self {}
+
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+                }
+            },
+            "encountered multiple default ink! constructors",
+        );
+    }
+
+    #[test]
+    fn hooks_work() {
+        let item_mod =
+            <ir::ItemMod as TryFrom<syn::ItemMod>>::try_from(syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+
+                    impl MyStorage {
+                        #[ink(hook(before_call))]
+                        fn before_call(&self, selector: [u8; 4]) {}
+                    }
+                }
+            })
+            .unwrap();
+        assert_eq!(
+            item_mod
+                .hook(ir::HookKind::BeforeCall)
+                .map(|hook| hook.ident().to_string()),
+            Some("before_call".to_string()),
+        );
+        assert!(item_mod.hook(ir::HookKind::AfterCall).is_none());
+    }
+
+    #[test]
+    fn multiple_hooks_of_same_kind_fails() {
+        assert_fail(
+            syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+
+                        #[ink(hook(after_call))]
+                        fn after_call_1(&self, selector: [u8; 4]) {}
+
+                        #[ink(hook(after_call))]
+                        fn after_call_2(&self, selector: [u8; 4]) {}
+                    }
+                }
+            },
+            "encountered multiple ink! `after_call` hooks",
+        );
+    }
+
+    #[test]
+    fn trait_impls_with_same_name_fail() {
+        assert_fail(
+            syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+                    }
+
+                    impl a::Erc20 for MyStorage {
+                        #[ink(message)]
+                        fn total_supply(&self) -> Balance {}
+                    }
+
+                    impl b::Erc20 for MyStorage {
+                        #[ink(message)]
+                        fn balance_of(&self, owner: AccountId) -> Balance {}
+                    }
+                }
+            },
+            "encountered multiple implemented ink! traits named `erc20` \
+            which would share the `as_erc20` and `from_erc20_ref` conversions of the contract reference",
+        );
+    }
+
+    #[test]
+    fn message_colliding_with_trait_ref_cast_fails() {
+        for message_ident in [
+            quote::format_ident!("as_erc20"),
+            quote::format_ident!("from_erc20_ref"),
+        ] {
+            assert_fail(
+                syn::parse_quote! {
+                    mod my_module {
+                        #[ink(storage)]
+                        pub struct MyStorage {}
+
+                        impl MyStorage {
+                            #[ink(constructor)]
+                            pub fn my_constructor() -> Self {}
+
+                            #[ink(message)]
+                            pub fn #message_ident(&self) {}
+                        }
+
+                        impl Erc20 for MyStorage {
+                            #[ink(message)]
+                            fn total_supply(&self) -> Balance {}
+                        }
+                    }
+                },
+                &format!(
+                    "ink! message `{}` collides with the conversion of the contract reference \
+                    for the implemented ink! trait `Erc20`",
+                    message_ident,
+                ),
+            );
+        }
+    }
+
     #[test]
     fn wildcard_selector_on_constructor_works() {
         assert!(
@@ -920,4 +1368,63 @@ mod tests {
             "encountered ink! attribute arguments with equal kinds",
         );
     }
+
+    #[test]
+    fn submodule_ink_items_are_hoisted() {
+        let item_mod =
+            <ir::ItemMod as TryFrom<syn::ItemMod>>::try_from(syn::parse_quote! {
+                mod my_module {
+                    mod storage {
+                        #[ink(storage)]
+                        pub struct MyStorage {}
+                    }
+
+                    mod messages {
+                        use super::*;
+
+                        impl MyStorage {
+                            #[ink(constructor)]
+                            pub fn my_constructor() -> Self {}
+                        }
+
+                        mod nested {
+                            #[ink(event)]
+                            pub struct MyEvent {}
+
+                            impl MyStorage {
+                                #[ink(message)]
+                                pub fn my_message(&self) {}
+                            }
+                        }
+
+                        fn helper() {}
+                    }
+                }
+            })
+            .unwrap();
+        assert_eq!(item_mod.storage().ident(), "MyStorage");
+        assert_eq!(item_mod.events().count(), 1);
+        assert_eq!(item_mod.impls().count(), 2);
+        // The submodules keep their non-ink! items.
+        let rust_items = item_mod
+            .items()
+            .iter()
+            .filter_map(ir::Item::map_rust_item)
+            .map(|item| {
+                match item {
+                    syn::Item::Mod(item_mod) => {
+                        (
+                            item_mod.ident.to_string(),
+                            item_mod.content.as_ref().unwrap().1.len(),
+                        )
+                    }
+                    _ => panic!("encountered unexpected non-ink! item"),
+                }
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rust_items,
+            vec![("storage".to_string(), 0), ("messages".to_string(), 3)]
+        );
+    }
 }
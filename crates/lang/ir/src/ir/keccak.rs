@@ -0,0 +1,97 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use proc_macro2::TokenStream as TokenStream2;
+
+/// Computes the KECCAK 256-bit hash for the given input and stores it in output.
+pub fn keccak_256(input: &[u8], output: &mut [u8; 32]) {
+    use ::sha3::{
+        digest::Digest as _,
+        Keccak256,
+    };
+
+    let mut keccak = Keccak256::new();
+    keccak.update(input);
+    let result = keccak.finalize();
+    output.copy_from_slice(&result);
+}
+
+/// Computes the KECCAK-256 bit hash of a string or byte string literal.
+///
+/// # Note
+///
+/// This is mainly used for analysis and codegen of the `keccak256!` macro.
+#[derive(Debug)]
+pub struct Keccak256Macro {
+    hash: [u8; 32],
+    input: syn::Lit,
+}
+
+impl Keccak256Macro {
+    /// Returns the computed hash.
+    pub fn hash(&self) -> [u8; 32] {
+        self.hash
+    }
+
+    /// Returns the literal input of the KECCAK hash.
+    pub fn input(&self) -> &syn::Lit {
+        &self.input
+    }
+}
+
+impl TryFrom<TokenStream2> for Keccak256Macro {
+    type Error = syn::Error;
+
+    fn try_from(input: TokenStream2) -> Result<Self, Self::Error> {
+        let (lit, input_bytes) = super::blake2::parse_hash_input(input)?;
+        let mut output = [0u8; 32];
+        keccak_256(&input_bytes, &mut output);
+        Ok(Self {
+            hash: output,
+            input: lit,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keccak_256_works() {
+        let mut output = [0u8; 32];
+        keccak_256(b"", &mut output);
+        assert_eq!(
+            output,
+            [
+                0xC5, 0xD2, 0x46, 0x01, 0x86, 0xF7, 0x23, 0x3C, 0x92, 0x7E, 0x7D, 0xB2,
+                0xDC, 0xC7, 0x03, 0xC0, 0xE5, 0x00, 0xB6, 0x53, 0xCA, 0x82, 0x27, 0x3B,
+                0x7B, 0xFA, 0xD8, 0x04, 0x5D, 0x85, 0xA4, 0x70,
+            ]
+        );
+    }
+
+    #[test]
+    fn string_and_byte_string_inputs_are_equal() {
+        let from_str = Keccak256Macro::try_from(quote::quote! { "hello" }).unwrap();
+        let from_bytes = Keccak256Macro::try_from(quote::quote! { b"hello" }).unwrap();
+        assert_eq!(from_str.hash(), from_bytes.hash());
+    }
+
+    #[test]
+    fn invalid_input_fails() {
+        assert!(Keccak256Macro::try_from(quote::quote! { 42 }).is_err());
+        assert!(Keccak256Macro::try_from(quote::quote! {}).is_err());
+    }
+}
@@ -0,0 +1,331 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::ir;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::spanned::Spanned as _;
+
+/// The kinds of ink! smart contract mixins.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MixinKind {
+    /// The `#[ink::ownable]` mixin.
+    Ownable,
+    /// The `#[ink::access_control]` mixin.
+    AccessControl,
+}
+
+impl core::fmt::Display for MixinKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Ownable => write!(f, "ownable"),
+            Self::AccessControl => write!(f, "access_control"),
+        }
+    }
+}
+
+/// An ink! smart contract module annotated with a mixin such as `#[ink::ownable]`.
+///
+/// # Note
+///
+/// Mixins inject ink! messages, ink! events and guards into the ink! smart
+/// contract before it is expanded by `#[ink::contract]`. Therefore they must
+/// be applied above the `#[ink::contract]` attribute.
+///
+/// # Example
+///
+/// ```
+/// # let mixin = <ink_lang_ir::ContractMixin>::new(
+/// #     ink_lang_ir::MixinKind::Ownable,
+/// #     quote::quote! {},
+/// #     quote::quote! {
+/// #[ink::contract]
+/// mod my_contract {
+///     #[ink(storage)]
+///     pub struct MyStorage {
+///         /* storage fields */
+///     }
+///
+///     impl MyStorage {
+///         #[ink(constructor)]
+///         pub fn new() -> Self { Self {} }
+///     }
+/// }
+/// # }).unwrap();
+/// # assert_eq!(mixin.storage_ident(), "MyStorage");
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct ContractMixin {
+    /// The kind of the mixin.
+    kind: MixinKind,
+    /// The annotated ink! smart contract module.
+    module: syn::ItemMod,
+    /// The identifier of the ink! storage struct.
+    storage_ident: syn::Ident,
+}
+
+impl ContractMixin {
+    /// Returns `Ok` if the input matches all requirements for an ink! smart
+    /// contract mixin.
+    pub fn new(
+        kind: MixinKind,
+        attr: TokenStream2,
+        input: TokenStream2,
+    ) -> Result<Self, syn::Error> {
+        if !attr.is_empty() {
+            return Err(format_err_spanned!(
+                attr,
+                "unexpected attribute input for the ink! `{}` mixin",
+                kind,
+            ))
+        }
+        let (module, storage_ident) = parse_contract_module(&kind, input)?;
+        Ok(Self {
+            kind,
+            module,
+            storage_ident,
+        })
+    }
+
+    /// Returns the kind of the mixin.
+    pub fn kind(&self) -> MixinKind {
+        self.kind
+    }
+
+    /// Returns the annotated ink! smart contract module.
+    pub fn module(&self) -> &syn::ItemMod {
+        &self.module
+    }
+
+    /// Returns the identifier of the ink! storage struct.
+    pub fn storage_ident(&self) -> &syn::Ident {
+        &self.storage_ident
+    }
+
+    /// Returns the ink! smart contract module with the given statements prepended
+    /// to the bodies of all ink! constructors and the given items appended.
+    ///
+    /// # Note
+    ///
+    /// Malformed ink! attributes are left to be reported by `#[ink::contract]`.
+    pub fn expand(
+        &self,
+        constructor_prelude: Vec<syn::Stmt>,
+        items: Vec<syn::Item>,
+    ) -> syn::ItemMod {
+        let mut module = self.module.clone();
+        let module_items = &mut module
+            .content
+            .as_mut()
+            .expect("encountered out-of-line ink! module")
+            .1;
+        for item in module_items.iter_mut() {
+            let item_impl = match item {
+                syn::Item::Impl(item_impl) => item_impl,
+                _ => continue,
+            };
+            for impl_item in &mut item_impl.items {
+                let method = match impl_item {
+                    syn::ImplItem::Method(method) => method,
+                    _ => continue,
+                };
+                let is_constructor = ir::first_ink_attribute(&method.attrs)
+                    .ok()
+                    .flatten()
+                    .map(|attr| {
+                        matches!(attr.first().kind(), ir::AttributeArg::Constructor)
+                    })
+                    .unwrap_or(false);
+                if is_constructor {
+                    method
+                        .block
+                        .stmts
+                        .splice(0..0, constructor_prelude.iter().cloned());
+                }
+            }
+        }
+        module_items.extend(items);
+        module
+    }
+}
+
+/// Parses the ink! smart contract module that the ink! mixin `name` is applied to.
+///
+/// Returns the module along with the identifier of its ink! storage struct.
+pub(super) fn parse_contract_module(
+    name: &dyn core::fmt::Display,
+    input: TokenStream2,
+) -> Result<(syn::ItemMod, syn::Ident), syn::Error> {
+    let module = syn::parse2::<syn::ItemMod>(input)?;
+    let is_contract = module.attrs.iter().any(|attr| {
+        attr.path
+            .segments
+            .last()
+            .map(|segment| segment.ident == "contract")
+            .unwrap_or(false)
+    });
+    if !is_contract {
+        return Err(format_err_spanned!(
+            module,
+            "the ink! `{}` mixin must be applied above `#[ink::contract]`",
+            name,
+        ))
+    }
+    let items = match &module.content {
+        Some((_, items)) => items,
+        None => {
+        return Err(format_err_spanned!(
+            module,
+            "out-of-line ink! modules are not supported, use `#[ink::contract] mod name {{ ... }}`",
+        ))
+        }
+    };
+    let mut storage_ident = None;
+    for item in items {
+        if let Some(ident) = ir::Storage::ink_storage_ident(item)? {
+            storage_ident = Some(ident.clone());
+            break
+        }
+    }
+    let storage_ident = storage_ident.ok_or_else(|| {
+        format_err!(
+            module.span(),
+            "the ink! `{}` mixin requires an ink! storage definition at the ink! module root",
+            name,
+        )
+    })?;
+    Ok((module, storage_ident))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_new_fails(attr: TokenStream2, input: TokenStream2, expected: &str) {
+        assert_eq!(
+            ContractMixin::new(MixinKind::Ownable, attr, input)
+                .map_err(|err| err.to_string()),
+            Err(expected.to_string()),
+        )
+    }
+
+    #[test]
+    fn new_works() {
+        let mixin = ContractMixin::new(
+            MixinKind::AccessControl,
+            quote::quote! {},
+            quote::quote! {
+                #[ink::contract]
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+                }
+            },
+        )
+        .unwrap();
+        assert_eq!(mixin.kind(), MixinKind::AccessControl);
+        assert_eq!(mixin.storage_ident(), "MyStorage");
+    }
+
+    #[test]
+    fn attribute_input_fails() {
+        assert_new_fails(
+            quote::quote! { owner = "alice" },
+            quote::quote! {
+                #[ink::contract]
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+                }
+            },
+            "unexpected attribute input for the ink! `ownable` mixin",
+        )
+    }
+
+    #[test]
+    fn missing_contract_attribute_fails() {
+        assert_new_fails(
+            quote::quote! {},
+            quote::quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+                }
+            },
+            "the ink! `ownable` mixin must be applied above `#[ink::contract]`",
+        )
+    }
+
+    #[test]
+    fn missing_storage_fails() {
+        assert_new_fails(
+            quote::quote! {},
+            quote::quote! {
+                #[ink::contract]
+                mod my_module {}
+            },
+            "the ink! `ownable` mixin requires an ink! storage definition at the ink! module root",
+        )
+    }
+
+    #[test]
+    fn expand_works() {
+        let mixin = ContractMixin::new(
+            MixinKind::Ownable,
+            quote::quote! {},
+            quote::quote! {
+                #[ink::contract]
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor, payable)]
+                        pub fn new() -> Self {
+                            Self {}
+                        }
+
+                        #[ink(message)]
+                        pub fn message(&self) {}
+                    }
+                }
+            },
+        )
+        .unwrap();
+        let expanded = mixin.expand(
+            vec![syn::parse_quote! { init(); }],
+            vec![syn::parse_quote! { fn appended() {} }],
+        );
+        let expected: syn::ItemMod = syn::parse_quote! {
+            #[ink::contract]
+            mod my_module {
+                #[ink(storage)]
+                pub struct MyStorage {}
+
+                impl MyStorage {
+                    #[ink(constructor, payable)]
+                    pub fn new() -> Self {
+                        init();
+                        Self {}
+                    }
+
+                    #[ink(message)]
+                    pub fn message(&self) {}
+                }
+
+                fn appended() {}
+            }
+        };
+        assert_eq!(expanded, expected);
+    }
+}
@@ -17,13 +17,25 @@
 mod attrs;
 mod blake2;
 mod chain_extension;
+mod chain_extension_error_code;
 mod config;
 mod contract;
+mod contract_error;
+mod environment;
+mod event_def;
+mod export_impl;
+mod generic_contract;
 mod idents_lint;
+mod include_impl;
+mod ink_e2e_test;
 mod ink_test;
 mod item;
 mod item_impl;
 mod item_mod;
+mod keccak;
+mod mixin;
+mod proxy;
+mod scale_derive;
 mod selector;
 mod trait_def;
 pub mod utils;
@@ -64,13 +76,38 @@ pub use self::{
         ChainExtensionMethod,
         ExtensionId,
     },
-    config::Config,
+    chain_extension_error_code::{
+        CatchAllVariant,
+        ChainExtensionErrorCode,
+        ErrorCodeVariant,
+    },
+    config::{
+        Config,
+        PanicStrategy,
+    },
     contract::Contract,
+    contract_error::{
+        ContractError,
+        ContractErrorVariant,
+    },
+    environment::{
+        EnvironmentType,
+        InkEnvironment,
+    },
+    event_def::InkEventDefinition,
+    export_impl::InkExportImpl,
+    generic_contract::GenericContract,
+    include_impl::{
+        IncludeImplSource,
+        InkIncludeImpl,
+    },
+    ink_e2e_test::InkE2ETest,
     ink_test::InkTest,
     item::{
         Event,
         InkItem,
         Item,
+        SharedEvent,
         Storage,
     },
     item_impl::{
@@ -78,6 +115,8 @@ pub use self::{
         CallableKind,
         CallableWithSelector,
         Constructor,
+        Hook,
+        HookKind,
         ImplItem,
         InputsIter,
         ItemImpl,
@@ -91,6 +130,20 @@ pub use self::{
         ItemMod,
         IterEvents,
         IterItemImpls,
+        IterSharedEvents,
+    },
+    keccak::{
+        keccak_256,
+        Keccak256Macro,
+    },
+    mixin::{
+        ContractMixin,
+        MixinKind,
+    },
+    proxy::InkProxy,
+    scale_derive::{
+        ScaleDerive,
+        ScaleDeriveTrait,
     },
     selector::{
         Selector,
@@ -0,0 +1,143 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    ast,
+    ir,
+};
+use proc_macro2::TokenStream as TokenStream2;
+
+/// An upgradeable proxy contract generated by ink!.
+///
+/// Noticed by ink! through the `#[ink::proxy]` annotation on an empty inline module.
+///
+/// # Example
+///
+/// ```
+/// # let proxy = <ink_lang_ir::InkProxy>::new(
+/// #     quote::quote! {},
+/// #     quote::quote! {
+/// pub mod my_proxy {}
+/// # }).unwrap();
+/// # assert_eq!(proxy.module().ident, "my_proxy");
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct InkProxy {
+    /// The empty inline module that is filled with the proxy contract.
+    module: syn::ItemMod,
+    /// The ink! configuration of the proxy contract.
+    config: ir::Config,
+}
+
+impl InkProxy {
+    /// Returns `Ok` if the input matches all requirements for an ink! proxy contract.
+    pub fn new(attr: TokenStream2, input: TokenStream2) -> Result<Self, syn::Error> {
+        let args = syn::parse2::<ast::AttributeArgs>(attr)?;
+        let config = ir::Config::try_from(args)?;
+        if config.generic_impl().is_some() {
+            return Err(format_err_spanned!(
+                config.generic_impl().cloned(),
+                "the `impl` ink! configuration argument is not supported for ink! proxy contracts",
+            ))
+        }
+        let module = syn::parse2::<syn::ItemMod>(input)?;
+        match &module.content {
+            Some((_, items)) if items.is_empty() => (),
+            Some((_, items)) => {
+                return Err(format_err_spanned!(
+                    items[0],
+                    "ink! proxy modules must be empty since the proxy contract is generated",
+                ))
+            }
+            None => {
+                return Err(format_err_spanned!(
+                    module,
+                    "out-of-line ink! modules are not supported, use `#[ink::proxy] mod name {{}}`",
+                ))
+            }
+        }
+        Ok(Self { module, config })
+    }
+
+    /// Returns the empty inline module of the proxy contract.
+    pub fn module(&self) -> &syn::ItemMod {
+        &self.module
+    }
+
+    /// Returns the ink! configuration of the proxy contract.
+    pub fn config(&self) -> &ir::Config {
+        &self.config
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_new_fails(attr: TokenStream2, input: TokenStream2, expected: &str) {
+        assert_eq!(
+            InkProxy::new(attr, input).map_err(|err| err.to_string()),
+            Err(expected.to_string()),
+        )
+    }
+
+    #[test]
+    fn new_works() {
+        let proxy = InkProxy::new(
+            quote::quote! { env = MyEnvironment },
+            quote::quote! {
+                /// My proxy contract.
+                pub mod my_proxy {}
+            },
+        )
+        .unwrap();
+        assert_eq!(proxy.module().ident, "my_proxy");
+        assert_eq!(proxy.config().env(), syn::parse_quote! { MyEnvironment });
+    }
+
+    #[test]
+    fn non_empty_module_fails() {
+        assert_new_fails(
+            quote::quote! {},
+            quote::quote! {
+                mod my_proxy {
+                    fn helper() {}
+                }
+            },
+            "ink! proxy modules must be empty since the proxy contract is generated",
+        )
+    }
+
+    #[test]
+    fn out_of_line_module_fails() {
+        assert_new_fails(
+            quote::quote! {},
+            quote::quote! {
+                mod my_proxy;
+            },
+            "out-of-line ink! modules are not supported, use `#[ink::proxy] mod name {}`",
+        )
+    }
+
+    #[test]
+    fn generic_impl_fails() {
+        assert_new_fails(
+            quote::quote! { impl = MyPolicy },
+            quote::quote! {
+                mod my_proxy {}
+            },
+            "the `impl` ink! configuration argument is not supported for ink! proxy contracts",
+        )
+    }
+}
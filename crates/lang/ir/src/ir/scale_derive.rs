@@ -0,0 +1,184 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{
+    punctuated::Punctuated,
+    spanned::Spanned as _,
+    Token,
+};
+
+/// The SCALE traits that can be derived via `#[ink::scale_derive]`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ScaleDeriveTrait {
+    /// The `scale::Encode` trait.
+    Encode,
+    /// The `scale::Decode` trait.
+    Decode,
+    /// The `scale_info::TypeInfo` trait.
+    TypeInfo,
+}
+
+impl core::fmt::Display for ScaleDeriveTrait {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Encode => write!(f, "Encode"),
+            Self::Decode => write!(f, "Decode"),
+            Self::TypeInfo => write!(f, "TypeInfo"),
+        }
+    }
+}
+
+/// A type annotated with `#[ink::scale_derive(..)]`.
+///
+/// # Example
+///
+/// ```
+/// # let derive = <ink_lang_ir::ScaleDerive>::new(
+/// #     quote::quote! {
+/// Encode, Decode, TypeInfo
+/// #     },
+/// #     quote::quote! {
+/// pub enum Error {
+///     InsufficientBalance,
+/// }
+/// # }).unwrap();
+/// # assert_eq!(derive.traits().len(), 3);
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct ScaleDerive {
+    /// The annotated struct, enum or union definition.
+    item: syn::Item,
+    /// The traits to derive together with the identifiers that requested them.
+    traits: Vec<(ScaleDeriveTrait, syn::Ident)>,
+}
+
+impl ScaleDerive {
+    /// Returns `Ok` if the input matches all requirements for `#[ink::scale_derive]`.
+    pub fn new(attr: TokenStream2, input: TokenStream2) -> Result<Self, syn::Error> {
+        let attr_span = attr.span();
+        let idents = syn::parse::Parser::parse2(
+            Punctuated::<syn::Ident, Token![,]>::parse_terminated,
+            attr,
+        )?;
+        if idents.is_empty() {
+            return Err(format_err!(
+                attr_span,
+                "expected at least one of `Encode`, `Decode` or `TypeInfo` for #[ink::scale_derive(..)]",
+            ))
+        }
+        let mut traits: Vec<(ScaleDeriveTrait, syn::Ident)> = Vec::new();
+        for ident in idents {
+            let kind = match ident.to_string().as_str() {
+                "Encode" => ScaleDeriveTrait::Encode,
+                "Decode" => ScaleDeriveTrait::Decode,
+                "TypeInfo" => ScaleDeriveTrait::TypeInfo,
+                _ => {
+                    return Err(format_err_spanned!(
+                        ident,
+                        "expected `Encode`, `Decode` or `TypeInfo` for #[ink::scale_derive(..)]",
+                    ))
+                }
+            };
+            if traits.iter().any(|(seen, _)| *seen == kind) {
+                return Err(format_err_spanned!(
+                    ident,
+                    "encountered duplicate `{}` in #[ink::scale_derive(..)]",
+                    kind,
+                ))
+            }
+            traits.push((kind, ident));
+        }
+        let item = syn::parse2::<syn::Item>(input)?;
+        if !matches!(
+            item,
+            syn::Item::Struct(_) | syn::Item::Enum(_) | syn::Item::Union(_)
+        ) {
+            return Err(format_err_spanned!(
+                item,
+                "#[ink::scale_derive(..)] can only be applied to struct, enum or union definitions",
+            ))
+        }
+        Ok(Self { item, traits })
+    }
+
+    /// Returns the annotated struct, enum or union definition.
+    pub fn item(&self) -> &syn::Item {
+        &self.item
+    }
+
+    /// Returns the traits to derive in the order they were given.
+    ///
+    /// Each trait is accompanied by the identifier that requested it.
+    pub fn traits(&self) -> &[(ScaleDeriveTrait, syn::Ident)] {
+        &self.traits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_new_fails(attr: TokenStream2, input: TokenStream2, expected: &str) {
+        assert_eq!(
+            ScaleDerive::new(attr, input).map_err(|err| err.to_string()),
+            Err(expected.to_string()),
+        )
+    }
+
+    #[test]
+    fn new_works() {
+        let derive = ScaleDerive::new(
+            quote::quote! { TypeInfo, Encode },
+            quote::quote! { pub struct Balance(u128); },
+        )
+        .unwrap();
+        assert_eq!(
+            derive
+                .traits()
+                .iter()
+                .map(|(kind, _)| *kind)
+                .collect::<Vec<_>>(),
+            vec![ScaleDeriveTrait::TypeInfo, ScaleDeriveTrait::Encode],
+        );
+    }
+
+    #[test]
+    fn invalid_traits_fail() {
+        assert_new_fails(
+            quote::quote! {},
+            quote::quote! { pub struct Balance(u128); },
+            "expected at least one of `Encode`, `Decode` or `TypeInfo` for #[ink::scale_derive(..)]",
+        );
+        assert_new_fails(
+            quote::quote! { Encode, Debug },
+            quote::quote! { pub struct Balance(u128); },
+            "expected `Encode`, `Decode` or `TypeInfo` for #[ink::scale_derive(..)]",
+        );
+        assert_new_fails(
+            quote::quote! { Encode, Encode },
+            quote::quote! { pub struct Balance(u128); },
+            "encountered duplicate `Encode` in #[ink::scale_derive(..)]",
+        );
+    }
+
+    #[test]
+    fn invalid_item_fails() {
+        assert_new_fails(
+            quote::quote! { Encode },
+            quote::quote! { fn balance() {} },
+            "#[ink::scale_derive(..)] can only be applied to struct, enum or union definitions",
+        );
+    }
+}
@@ -32,6 +32,13 @@ pub struct TraitDefinitionConfig {
     namespace: Option<syn::LitStr>,
     /// The set of attributes that can be passed to call builder and forwarder in the codegen.
     whitelisted_attributes: WhitelistedAttributes,
+    /// Tells if the interface-only ink! metadata of the ink! trait definition is generated.
+    ///
+    /// # Note
+    ///
+    /// This is opt-in since the generated metadata requires all types of the ink!
+    /// trait messages to implement `scale_info::TypeInfo`.
+    metadata: bool,
 }
 
 impl TraitDefinitionConfig {
@@ -72,6 +79,7 @@ impl TryFrom<ast::AttributeArgs> for TraitDefinitionConfig {
     fn try_from(args: ast::AttributeArgs) -> Result<Self, Self::Error> {
         let mut namespace: Option<(syn::LitStr, ast::MetaNameValue)> = None;
         let mut whitelisted_attributes = WhitelistedAttributes::default();
+        let mut metadata: Option<(bool, ast::MetaNameValue)> = None;
         for arg in args.into_iter() {
             if arg.name.is_ident("namespace") {
                 if let Some((_, meta_name_value)) = namespace {
@@ -95,6 +103,18 @@ impl TryFrom<ast::AttributeArgs> for TraitDefinitionConfig {
                 if let Err(err) = whitelisted_attributes.parse_arg_value(&arg) {
                     return Err(err)
                 }
+            } else if arg.name.is_ident("metadata") {
+                if let Some((_, meta_name_value)) = metadata {
+                    return Err(duplicate_config_err(meta_name_value, arg, "metadata"))
+                }
+                if let ast::PathOrLit::Lit(syn::Lit::Bool(lit_bool)) = &arg.value {
+                    metadata = Some((lit_bool.value, arg))
+                } else {
+                    return Err(format_err_spanned!(
+                        arg,
+                        "expected a bool literal for `metadata` ink! trait definition configuration argument",
+                    ))
+                }
             } else {
                 return Err(format_err_spanned!(
                     arg,
@@ -105,6 +125,7 @@ impl TryFrom<ast::AttributeArgs> for TraitDefinitionConfig {
         Ok(TraitDefinitionConfig {
             namespace: namespace.map(|(value, _)| value),
             whitelisted_attributes,
+            metadata: metadata.map(|(value, _)| value).unwrap_or(false),
         })
     }
 }
@@ -120,4 +141,10 @@ impl TraitDefinitionConfig {
     pub fn whitelisted_attributes(&self) -> &WhitelistedAttributes {
         &self.whitelisted_attributes
     }
+
+    /// Returns `true` if the interface-only ink! metadata of the ink! trait
+    /// definition is generated.
+    pub fn metadata(&self) -> bool {
+        self.metadata
+    }
 }
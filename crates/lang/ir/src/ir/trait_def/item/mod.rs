@@ -47,6 +47,7 @@ use syn::{
 #[derive(Debug, PartialEq, Eq)]
 pub struct InkItemTrait {
     item: syn::ItemTrait,
+    namespace: Option<syn::LitStr>,
     message_selectors: HashMap<syn::Ident, Selector>,
 }
 
@@ -64,13 +65,14 @@ impl InkItemTrait {
     /// Creates a new ink! item trait from the given configuration and trait definition.
     pub fn new(
         config: &TraitDefinitionConfig,
-        item_trait: syn::ItemTrait,
+        mut item_trait: syn::ItemTrait,
     ) -> Result<Self> {
         idents_lint::ensure_no_ink_identifiers(&item_trait)?;
         Self::analyse_properties(&item_trait)?;
         Self::analyse_items(&item_trait)?;
+        let namespace = Self::extract_namespace(config, &mut item_trait)?;
         let mut message_selectors = <HashMap<syn::Ident, Selector>>::new();
-        Self::extract_selectors(config, &item_trait, &mut message_selectors)?;
+        Self::extract_selectors(&item_trait, namespace.as_ref(), &mut message_selectors)?;
         if message_selectors.is_empty() {
             return Err(format_err!(
                 item_trait.span(),
@@ -79,6 +81,7 @@ impl InkItemTrait {
         }
         Ok(Self {
             item: item_trait,
+            namespace,
             message_selectors,
         })
     }
@@ -100,6 +103,14 @@ impl InkItemTrait {
         &self.item.ident
     }
 
+    /// Returns the namespace of the ink! trait definition if any.
+    ///
+    /// The namespace is either given as `namespace` configuration argument of the
+    /// ink! trait definition or via the `#[ink(namespace = N)]` ink! attribute.
+    pub fn namespace(&self) -> Option<&syn::LitStr> {
+        self.namespace.as_ref()
+    }
+
     /// Returns an iterator yielding the ink! specific items of the ink! trait definition.
     pub fn iter_items(&self) -> IterInkTraitItems {
         IterInkTraitItems::new(self)
@@ -156,7 +167,6 @@ impl InkItemTrait {
     ///     - associated types (`type`)
     ///     - macros definitions or usages
     ///     - unknown token sequences (verbatim)
-    ///     - methods with default implementations
     /// - If the trait contains methods which do not respect the ink! trait definition requirements:
     ///     - All trait methods need to be declared as either `#[ink(message)]` or `#[ink(constructor)]`
     ///       and need to respect their respective rules.
@@ -215,12 +225,6 @@ impl InkItemTrait {
     /// - If the method does not respect the properties of either an
     ///   ink! message or ink! constructor.
     fn analyse_trait_method(method: &syn::TraitItemMethod) -> Result<()> {
-        if let Some(default_impl) = &method.default {
-            return Err(format_err_spanned!(
-                default_impl,
-                "ink! trait methods with default implementations are not supported"
-            ))
-        }
         if let Some(constness) = &method.sig.constness {
             return Err(format_err_spanned!(
                 constness,
@@ -319,6 +323,49 @@ impl InkItemTrait {
         Ok(())
     }
 
+    /// Extracts the namespace of the ink! trait definition.
+    ///
+    /// Removes the `#[ink(namespace = N)]` ink! attribute from the ink! trait definition
+    /// since it must not be part of the generated trait.
+    ///
+    /// # Errors
+    ///
+    /// - If the ink! trait definition has ink! attributes other than `namespace`.
+    /// - If the namespace is given as configuration argument as well as ink! attribute.
+    fn extract_namespace(
+        config: &TraitDefinitionConfig,
+        item_trait: &mut syn::ItemTrait,
+    ) -> Result<Option<syn::LitStr>> {
+        let (ink_attrs, rust_attrs) = ir::sanitize_optional_attributes(
+            item_trait.span(),
+            item_trait.attrs.iter().cloned(),
+            |arg| {
+                match arg.kind() {
+                    ir::AttributeArg::Namespace(_) => Ok(()),
+                    _ => Err(None),
+                }
+            },
+        )?;
+        item_trait.attrs = rust_attrs;
+        let ink_attrs = match ink_attrs {
+            Some(ink_attrs) => ink_attrs,
+            None => return Ok(config.namespace().cloned()),
+        };
+        if config.namespace().is_some() {
+            return Err(format_err!(
+                ink_attrs.span(),
+                "encountered ink! namespace attribute for ink! trait definition with \
+                namespace configuration argument"
+            ))
+        }
+        let namespace = ink_attrs.namespace().map(|namespace| {
+            let namespace = String::from_utf8(namespace.as_bytes().to_vec())
+                .expect("encountered invalid UTF-8 ink! namespace");
+            syn::LitStr::new(&namespace, ink_attrs.span())
+        });
+        Ok(namespace)
+    }
+
     /// Extract selectors for ink! trait constructors and messages.
     ///
     /// The composed or manually specified selectors are stored into the provided
@@ -334,23 +381,11 @@ impl InkItemTrait {
     /// or ink! messages. Note that overlaps between ink! constructor and message
     /// selectors are allowed.
     fn extract_selectors(
-        config: &TraitDefinitionConfig,
         item_trait: &syn::ItemTrait,
+        namespace: Option<&syn::LitStr>,
         message_selectors: &mut HashMap<syn::Ident, Selector>,
     ) -> Result<()> {
         let mut seen_message_selectors = <HashMap<Selector, syn::Ident>>::new();
-        let (_ink_attrs, _) = ir::sanitize_optional_attributes(
-            item_trait.span(),
-            item_trait.attrs.iter().cloned(),
-            |arg| {
-                match arg.kind() {
-                    ir::AttributeArg::Namespace(_) => Ok(()),
-                    _ => Err(None),
-                }
-            },
-        )
-        .expect("encountered unexpected invalid attributes on ink! trait definition");
-        let namespace = config.namespace();
         let ident = &item_trait.ident;
         let trait_prefix = TraitPrefix::new(ident, namespace);
         for callable in IterInkTraitItemsRaw::from_raw(item_trait) {
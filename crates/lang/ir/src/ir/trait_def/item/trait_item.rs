@@ -115,6 +115,14 @@ impl<'a> InkTraitMessage<'a> {
         &self.item.sig
     }
 
+    /// Returns the default implementation of the ink! trait message if any.
+    ///
+    /// Implementers of the ink! trait definition inherit the default implementation
+    /// by declaring the ink! message without a body.
+    pub fn default_impl(&self) -> Option<&syn::Block> {
+        self.item.default.as_ref()
+    }
+
     /// Returns the `self` receiver of the ink! trait message.
     ///
     /// Returns `Ref` for `&self` messages and `RefMut` for `&mut self` messages.
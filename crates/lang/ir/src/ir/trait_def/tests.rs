@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use super::*;
+use quote::quote;
 
 /// Checks if the token stream in `$trait_def` results in the expected error message.
 macro_rules! assert_ink_trait_eq_err {
@@ -126,21 +127,14 @@ fn trait_def_containing_non_flagged_method_is_denied() {
 }
 
 #[test]
-fn trait_def_containing_default_implemented_methods_is_denied() {
+fn trait_def_containing_default_implemented_constructor_is_denied() {
     assert_ink_trait_eq_err!(
-        error: "ink! trait methods with default implementations are not supported",
+        error: "ink! trait definitions must not have constructors",
         pub trait MyTrait {
             #[ink(constructor)]
             fn default_implemented() -> Self {}
         }
     );
-    assert_ink_trait_eq_err!(
-        error: "ink! trait methods with default implementations are not supported",
-        pub trait MyTrait {
-            #[ink(message)]
-            fn default_implemented(&self) {}
-        }
-    );
 }
 
 #[test]
@@ -353,6 +347,69 @@ fn trait_def_with_namespace_is_ok() {
     )
 }
 
+#[test]
+fn trait_def_namespace_attribute_works() {
+    let with_attribute =
+        <InkItemTrait as TryFrom<syn::ItemTrait>>::try_from(syn::parse_quote! {
+            #[ink(namespace = "my_namespace")]
+            pub trait MyTrait {
+                #[ink(message)]
+                fn my_message(&self);
+            }
+        })
+        .unwrap();
+    let with_config = InkItemTrait::new(
+        &TraitDefinitionConfig::default().with_namespace("my_namespace"),
+        syn::parse_quote! {
+            pub trait MyTrait {
+                #[ink(message)]
+                fn my_message(&self);
+            }
+        },
+    )
+    .unwrap();
+    let selectors = |ink_trait: &InkItemTrait| {
+        ink_trait
+            .iter_items()
+            .map(|(_, selector)| selector)
+            .collect::<Vec<_>>()
+    };
+    assert!(with_attribute.attrs().is_empty());
+    assert_eq!(
+        with_attribute.namespace().map(syn::LitStr::value),
+        Some("my_namespace".to_string())
+    );
+    assert_eq!(selectors(&with_attribute), selectors(&with_config));
+    assert_eq!(
+        selectors(&with_attribute),
+        vec![crate::Selector::compute(
+            b"my_namespace::MyTrait::my_message"
+        )]
+    );
+}
+
+#[test]
+fn trait_def_with_conflicting_namespaces_is_denied() {
+    assert_eq!(
+        InkItemTrait::new(
+            &TraitDefinitionConfig::default().with_namespace("my_namespace"),
+            syn::parse_quote! {
+                #[ink(namespace = "my_namespace")]
+                pub trait MyTrait {
+                    #[ink(message)]
+                    fn my_message(&self);
+                }
+            },
+        )
+        .map_err(|err| err.to_string()),
+        Err(
+            "encountered ink! namespace attribute for ink! trait definition with \
+            namespace configuration argument"
+                .to_string()
+        )
+    );
+}
+
 #[test]
 fn trait_def_with_selectors_ok() {
     assert!(
@@ -444,3 +501,80 @@ fn iter_messages_works() {
     let expected = vec!["message_1".to_string(), "message_2".to_string()];
     assert_eq!(actual, expected);
 }
+
+#[test]
+fn default_implemented_messages_work() {
+    let ink_trait =
+        <InkItemTrait as TryFrom<syn::ItemTrait>>::try_from(syn::parse_quote! {
+            pub trait MyTrait {
+                #[ink(message)]
+                fn message_1(&self) -> bool;
+                #[ink(message)]
+                fn message_2(&self) -> bool {
+                    !self.message_1()
+                }
+            }
+        })
+        .unwrap();
+    let actual = ink_trait
+        .iter_items()
+        .map(|(item, _)| item)
+        .flat_map(|item| {
+            item.filter_map_message().map(|message| {
+                (
+                    message.sig().ident.to_string(),
+                    message.default_impl().is_some(),
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+    let expected = vec![
+        ("message_1".to_string(), false),
+        ("message_2".to_string(), true),
+    ];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn trait_def_metadata_config_works() {
+    let trait_def = |config| {
+        InkTraitDefinition::new(
+            config,
+            quote! {
+                pub trait MyTrait {
+                    #[ink(message)]
+                    fn my_message(&self);
+                }
+            },
+        )
+        .unwrap()
+    };
+    assert!(!trait_def(quote! {}).config().metadata());
+    assert!(!trait_def(quote! { metadata = false }).config().metadata());
+    assert!(trait_def(quote! { metadata = true }).config().metadata());
+}
+
+#[test]
+fn trait_def_invalid_metadata_config_is_denied() {
+    let error = |config| {
+        InkTraitDefinition::new(
+            config,
+            quote! {
+                pub trait MyTrait {
+                    #[ink(message)]
+                    fn my_message(&self);
+                }
+            },
+        )
+        .map_err(|err| err.to_string())
+        .unwrap_err()
+    };
+    assert_eq!(
+        error(quote! { metadata = "true" }),
+        "expected a bool literal for `metadata` ink! trait definition configuration argument",
+    );
+    assert_eq!(
+        error(quote! { metadata = true, metadata = false }),
+        "encountered duplicate ink! trait definition `metadata` configuration argument",
+    );
+}
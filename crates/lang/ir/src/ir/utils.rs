@@ -55,3 +55,16 @@ pub fn local_message_id(ident: &syn::Ident) -> u32 {
     let selector = Selector::compute(&input);
     selector.into_be_u32()
 }
+
+/// Returns `true` if the block is the omitted body of a method declared as
+/// `fn f(..);` within an implementation block.
+///
+/// # Note
+///
+/// `syn` parses such methods with a block that only consists of the verbatim `;`.
+pub fn is_omitted_body(block: &syn::Block) -> bool {
+    match &block.stmts[..] {
+        [syn::Stmt::Item(syn::Item::Verbatim(verbatim))] => verbatim.to_string() == ";",
+        _ => false,
+    }
+}
@@ -37,22 +37,40 @@ mod literal;
 pub use self::{
     ir::{
         blake2b_256,
+        keccak_256,
         marker,
         utils,
         Blake2x256Macro,
         Callable,
         CallableKind,
         CallableWithSelector,
+        CatchAllVariant,
         ChainExtension,
+        ChainExtensionErrorCode,
         ChainExtensionMethod,
         Config,
         Constructor,
         Contract,
+        ContractError,
+        ContractErrorVariant,
+        ContractMixin,
+        EnvironmentType,
+        ErrorCodeVariant,
         Event,
         ExtensionId,
+        GenericContract,
+        Hook,
+        HookKind,
         ImplItem,
+        IncludeImplSource,
+        InkE2ETest,
+        InkEnvironment,
+        InkEventDefinition,
+        InkExportImpl,
+        InkIncludeImpl,
         InkItem,
         InkItemTrait,
+        InkProxy,
         InkTest,
         InkTraitDefinition,
         InkTraitItem,
@@ -67,12 +85,20 @@ pub use self::{
         IterInkTraitItems,
         IterItemImpls,
         IterMessages,
+        IterSharedEvents,
+        Keccak256Macro,
         Message,
+        MixinKind,
         Namespace,
+        PanicStrategy,
         Receiver,
+        ScaleDerive,
+        ScaleDeriveTrait,
         Selector,
         SelectorMacro,
+        SharedEvent,
         Storage,
+        TraitPrefix,
         Visibility,
     },
     literal::HexLiteral,
@@ -27,3 +27,15 @@ pub fn generate_or_err(attr: TokenStream2, input: TokenStream2) -> Result<TokenS
     let chain_extension = ink_lang_ir::ChainExtension::new(attr, input)?;
     Ok(generate_code(&chain_extension))
 }
+
+pub fn generate_error_code(input: TokenStream2) -> TokenStream2 {
+    match generate_error_code_or_err(input) {
+        Ok(tokens) => tokens,
+        Err(err) => err.to_compile_error(),
+    }
+}
+
+pub fn generate_error_code_or_err(input: TokenStream2) -> Result<TokenStream2> {
+    let error_code = ink_lang_ir::ChainExtensionErrorCode::new(input)?;
+    Ok(generate_code(&error_code))
+}
@@ -17,7 +17,17 @@ extern crate proc_macro;
 mod blake2b;
 mod chain_extension;
 mod contract;
+mod contract_error;
+mod environment;
+mod event;
+mod export_impl;
+mod include_impl;
+mod ink_e2e_test;
 mod ink_test;
+mod keccak;
+mod mixin;
+mod proxy;
+mod scale_derive;
 mod selector;
 mod trait_def;
 
@@ -49,12 +59,59 @@ pub fn blake2x256(input: TokenStream) -> TokenStream {
     blake2b::generate_blake2x256_hash(input.into()).into()
 }
 
+/// Computes and expands into the KECCAK 256-bit hash of the string input.
+///
+/// # Note
+///
+/// - The computation takes place at compilation time of the crate.
+/// - The returned value is of type `[u8; 32]`.
+///
+/// This allows to precompute hashes that would otherwise be computed at runtime,
+/// e.g. role identifiers or event signature topics that are compatible with
+/// Ethereum tooling, without paying for the hashing in the smart contract.
+///
+/// # Example
+///
+/// ```
+/// # use ink_lang_macro::keccak256;
+/// # use ink_lang_ir::keccak_256;
+/// const MINTER_ROLE: [u8; 32] = keccak256!("MINTER_ROLE");
+///
+/// assert_eq!(
+///     MINTER_ROLE,
+///     {
+///         let mut output = [0u8; 32];
+///         keccak_256(b"MINTER_ROLE", &mut output);
+///         output
+///     }
+/// );
+/// ```
+#[proc_macro]
+pub fn keccak256(input: TokenStream) -> TokenStream {
+    keccak::generate_keccak256_hash(input.into()).into()
+}
+
 /// Computes the ink! selector of the string and expands into its `u32` representation.
 ///
 /// # Note
 ///
 /// The computation takes place at compilation time of the crate.
 ///
+/// The input is the label of the ink! message or constructor:
+///
+/// - For ink! messages and constructors defined in inherent implementation blocks
+///   this is their identifier, e.g. `"flip"`.
+/// - For ink! messages defined in implementations of ink! trait definitions this is
+///   the identifier of the trait definition and the message separated by `::`, e.g.
+///   `"Erc20::transfer"`. If the ink! trait definition has a custom namespace given
+///   via `#[ink(namespace = "..")]` it is prepended in the same way, e.g.
+///   `"my_namespace::Erc20::transfer"`.
+///
+/// This allows to refer to selectors without hard coding magic numbers, e.g. when
+/// building raw cross-contract calls, in allow-lists or when forwarding calls from
+/// a wildcard selector message. Note that selectors customized via
+/// `#[ink(selector = ..)]` are not taken into account.
+///
 /// # Example
 ///
 /// ```
@@ -63,6 +120,10 @@ pub fn blake2x256(input: TokenStream) -> TokenStream {
 ///     selector_id!("hello"),
 ///     843960066,
 /// );
+/// assert_eq!(
+///     selector_id!("Erc20::transfer"),
+///     u32::from_be_bytes(ink_lang_macro::selector_bytes!("Erc20::transfer")),
+/// );
 /// ```
 #[proc_macro]
 pub fn selector_id(input: TokenStream) -> TokenStream {
@@ -75,6 +136,21 @@ pub fn selector_id(input: TokenStream) -> TokenStream {
 ///
 /// The computation takes place at compilation time of the crate.
 ///
+/// The input is the label of the ink! message or constructor:
+///
+/// - For ink! messages and constructors defined in inherent implementation blocks
+///   this is their identifier, e.g. `"flip"`.
+/// - For ink! messages defined in implementations of ink! trait definitions this is
+///   the identifier of the trait definition and the message separated by `::`, e.g.
+///   `"Erc20::transfer"`. If the ink! trait definition has a custom namespace given
+///   via `#[ink(namespace = "..")]` it is prepended in the same way, e.g.
+///   `"my_namespace::Erc20::transfer"`.
+///
+/// This allows to refer to selectors without hard coding magic numbers, e.g. when
+/// building raw cross-contract calls, in allow-lists or when forwarding calls from
+/// a wildcard selector message. Note that selectors customized via
+/// `#[ink(selector = ..)]` are not taken into account.
+///
 /// # Example
 ///
 /// ```
@@ -157,6 +233,10 @@ pub fn selector_bytes(input: TokenStream) -> TokenStream {
 ///     that it exposes to the ink! smart contract and the mirrored types used in the runtime
 ///     must be aligned with respect to SCALE encoding and semantics.
 ///
+///     The environment types as well as `MAX_EVENT_TOPICS` are recorded in the `environment`
+///     section of the contract metadata so that user interfaces know how to encode arguments.
+///     Therefore the types must implement `scale_info::TypeInfo` when compiled with the `std` feature.
+///
 ///     **Usage Example:**
 ///
 ///     Given a custom `Environment` implementation:
@@ -203,8 +283,229 @@ pub fn selector_bytes(input: TokenStream) -> TokenStream {
 ///     }
 ///     ```
 ///
+///     Custom environments that only differ in some of their types from the default
+///     environment can also be defined via [`macro@environment`].
+///
 ///     **Default value:** `DefaultEnvironment` defined in `ink_env` crate.
 ///
+/// - `impl: Type`
+///
+///     Tells the ink! code generator which type to resolve the generic parameter of the
+///     ink! storage struct to. This allows to write template smart contracts that are
+///     generic over a type implementing some trait and to instantiate them without
+///     copy-pasting their definition.
+///
+///     If given, the ink! storage struct must be generic over exactly one type parameter.
+///     All implementation blocks for the ink! storage struct may then either be generic over
+///     the same type parameter or refer to it directly. The ink! code generator strips all
+///     of these generics and resolves the type parameter to the given type which must
+///     satisfy all of their trait bounds.
+///
+///     Note that since the type parameter is resolved to a concrete type, associated types
+///     of its traits must be accessed via fully qualified syntax, e.g. `<T as Trait>::Type`.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(impl = LinearFee)]
+///     mod my_contract {
+///         pub trait FeePolicy {
+///             fn fee(amount: Balance) -> Balance;
+///         }
+///
+///         pub struct LinearFee;
+///
+///         impl FeePolicy for LinearFee {
+///             fn fee(amount: Balance) -> Balance {
+///                 amount / 100
+///             }
+///         }
+///
+///         #[ink(storage)]
+///         pub struct MyStorage<P: FeePolicy> {
+///             collected: Balance,
+///         }
+///
+///         impl<P: FeePolicy> MyStorage<P> {
+///             #[ink(constructor)]
+///             pub fn new() -> Self {
+///                 Self { collected: 0 }
+///             }
+///
+///             #[ink(message)]
+///             pub fn charge(&mut self, amount: Balance) -> Balance {
+///                 let fee = P::fee(amount);
+///                 self.collected += fee;
+///                 amount - fee
+///             }
+///         }
+///     }
+///     ```
+///
+///     **Default value:** None.
+///
+/// - `panic: String`
+///
+///     Tells the ink! code generator how panics within the ink! smart contract are handled.
+///     Either `"format"` or `"abort-silent"`.
+///
+///     With `"abort-silent"` the `panic!`, `assert!`, `assert_eq!`, `assert_ne!` and
+///     `unreachable!` macros used at the ink! module root as well as the panics of the
+///     generated dispatch code abort the execution immediately without formatting their
+///     message. This strips the panic strings and the formatting machinery from the Wasm
+///     blob. Panic messages are still formatted if the `std` or `ink-debug` features of
+///     `ink_lang` are enabled so that off-chain tests and debug builds remain informative.
+///
+///     Note that only the macros used at the ink! module root are replaced. Panics of
+///     nested modules of the ink! module, of dependencies and of `core` itself, e.g. via
+///     `unwrap`, `expect` or out of bounds indexing, are not affected and still format
+///     their message via `core::fmt`. Avoid these in order to keep `core::fmt` out of the
+///     Wasm blob.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(panic = "abort-silent")]
+///     mod my_contract {
+///         #[ink(storage)]
+///         pub struct MyStorage {
+///             value: u32,
+///         }
+///
+///         impl MyStorage {
+///             #[ink(constructor)]
+///             pub fn new(value: u32) -> Self {
+///                 assert!(value > 0, "value must be positive but is {}", value);
+///                 Self { value }
+///             }
+///
+///             #[ink(message)]
+///             pub fn get(&self) -> u32 {
+///                 self.value
+///             }
+///         }
+///     }
+///     ```
+///
+///     **Default value:** `"format"`.
+///
+/// - `default_constructor: bool`
+///
+///     Tells the ink! code generator to generate a `new_default` ink! constructor that
+///     instantiates the ink! smart contract from the `Default` value of its storage
+///     struct. The ink! storage struct must implement `Default` for this, e.g. by
+///     deriving it if all of its fields implement `Default`. This is useful for simple
+///     contracts and test deployments that do not need a hand-written constructor.
+///
+///     Note that the constructor is generated by `#[ink::contract]` and therefore
+///     mixins such as `#[ink::ownable]` do not initialize their state within it.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(default_constructor = true)]
+///     mod counter {
+///         #[ink(storage)]
+///         #[derive(Default)]
+///         pub struct Counter {
+///             value: u32,
+///         }
+///
+///         impl Counter {
+///             #[ink(message)]
+///             pub fn get(&self) -> u32 {
+///                 self.value
+///             }
+///         }
+///     }
+///
+///     # ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+///     let counter = counter::Counter::new_default();
+///     assert_eq!(counter.get(), 0);
+///     # Ok(())
+///     # }).unwrap();
+///     ```
+///
+///     **Default value:** `false`.
+///
+/// - `max_root_size: usize`
+///
+///     Tells the ink! code generator to assert at compile time that the ink! storage
+///     struct occupies at most the given number of bytes. Compilation of the ink! smart
+///     contract fails if the ink! storage struct grows past this bound, e.g. because a
+///     field was added that no longer fits into the static buffer or the deposit budget
+///     of the contract.
+///
+///     The bound is checked against the in-memory size of the ink! storage struct.
+///     This covers all fields that are stored inline, whereas the contents of lazily
+///     stored or dynamically sized fields such as `Mapping` or `Vec` only account for
+///     the size of their handle.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(max_root_size = 64)]
+///     mod flipper {
+///         #[ink(storage)]
+///         pub struct Flipper {
+///             value: bool,
+///             owner: AccountId,
+///         }
+///
+///         impl Flipper {
+///             #[ink(constructor)]
+///             pub fn new() -> Self {
+///                 Self {
+///                     value: false,
+///                     owner: Self::env().caller(),
+///                 }
+///             }
+///
+///             #[ink(message)]
+///             pub fn get(&self) -> bool {
+///                 self.value
+///             }
+///         }
+///     }
+///     ```
+///
+///     **Default value:** No size bound is asserted.
+///
+/// - `metadata: String`
+///
+///     Embeds the given JSON object under the `user` key of the generated ink!
+///     metadata. This allows to ship team specific data alongside the contract,
+///     e.g. the hash of an audit report or hints for user interfaces, without
+///     extending the ink! metadata format. The given string must contain a JSON
+///     object, otherwise compilation fails. Binary data such as SCALE encoded
+///     values can be embedded as hex encoded strings.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(metadata = r#"{ "audit": { "report": "0x1234" } }"#)]
+///     mod flipper {
+///         #[ink(storage)]
+///         pub struct Flipper {
+///             value: bool,
+///         }
+///
+///         impl Flipper {
+///             #[ink(constructor)]
+///             pub fn new() -> Self {
+///                 Self { value: false }
+///             }
+///
+///             #[ink(message)]
+///             pub fn get(&self) -> bool {
+///                 self.value
+///             }
+///         }
+///     }
+///     ```
+///
+///     **Default value:** No user defined metadata is embedded.
+///
 /// ## Analysis
 ///
 /// The `#[ink::contract]` macro fully analyses its input smart contract
@@ -212,7 +513,7 @@ pub fn selector_bytes(input: TokenStream) -> TokenStream {
 ///
 /// Some example rules include but are not limited to:
 ///
-/// - There must be exactly one `#[ink(storage)]` struct.
+/// - There must be exactly one `#[ink(storage)]` struct or enum.
 ///
 ///     This struct defines the layout of the storage that the ink! smart contract operates on.
 ///     The user is able to use a variety of built-in facilities, combine them in various ways
@@ -239,6 +540,41 @@ pub fn selector_bytes(input: TokenStream) -> TokenStream {
 ///     }
 ///     ```
 ///
+///     **Storage Enums:**
+///
+///     The ink! storage may also be defined by an enum. This allows to model an ink! smart
+///     contract as a state machine whose states carry different storage fields. The active
+///     state is stored as a `u8` discriminant followed by the fields of its variant.
+///     Storage enums must not be generic and their variants must not have explicit
+///     discriminants.
+///
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract]
+///     mod auction {
+///         #[ink(storage)]
+///         pub enum Auction {
+///             Pending,
+///             Running { highest_bid: Balance },
+///             Closed(AccountId),
+///         }
+///
+///         impl Auction {
+///             #[ink(constructor)]
+///             pub fn new() -> Self {
+///                 Self::Pending
+///             }
+///
+///             #[ink(message)]
+///             pub fn start(&mut self) {
+///                 if let Self::Pending = self {
+///                     *self = Self::Running { highest_bid: 0 };
+///                 }
+///             }
+///         }
+///     }
+///     ```
+///
 /// - There must be at least one `#[ink(constructor)]` defined method.
 ///
 ///     Methods flagged with `#[ink(constructor)]` are special in that they are dispatchable
@@ -269,6 +605,62 @@ pub fn selector_bytes(input: TokenStream) -> TokenStream {
 ///     # }
 ///     ```
 ///
+///     **Fallible constructors:**
+///
+///     An ink! constructor may also return `Result<Self, E>` where `E` implements
+///     `scale::Encode` and `scale::Decode`. If such a constructor returns `Err` the
+///     instantiation is reverted, any transferred value is refunded and
+///     `Result::<(), E>::Err` is SCALE encoded into the output buffer of the instantiation.
+///     Instantiations via the generated contract reference decode it and fail with
+///     `InstantiateError::Constructor(E)` instead of trapping the instantiating contract.
+///
+///     ```
+///     # use ink_lang as ink;
+///     # #[ink::contract]
+///     # mod flipper {
+///         # #[ink(storage)]
+///         # pub struct Flipper {
+///         #     value: bool,
+///         # }
+///     #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+///     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+///     pub enum Error {
+///         AlreadyFlipped,
+///     }
+///
+///     impl Flipper {
+///         #[ink(constructor)]
+///         pub fn try_new(initial_value: bool) -> Result<Self, Error> {
+///             if initial_value {
+///                 return Err(Error::AlreadyFlipped)
+///             }
+///             Ok(Flipper { value: initial_value })
+///         }
+///         # #[ink(message)]
+///         # pub fn message(&self) {}
+///     }
+///     # }
+///     #
+///     # use flipper::{Flipper, FlipperRef};
+///     # use ink_lang::fixtures::Fixtures;
+///     # ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+///     # let mut fixtures = Fixtures::<ink_env::DefaultEnvironment>::new();
+///     # let code_hash = fixtures.register::<Flipper>();
+///     # let _ = fixtures.deploy::<Flipper>().instantiate(FlipperRef::try_new(false));
+///     # let result = FlipperRef::try_new(true)
+///     #     .code_hash(code_hash)
+///     #     .gas_limit(0)
+///     #     .endowment(0)
+///     #     .salt_bytes([0xFF])
+///     #     .instantiate();
+///     # assert!(matches!(
+///     #     result,
+///     #     Err(ink_env::call::InstantiateError::Constructor(flipper::Error::AlreadyFlipped))
+///     # ));
+///     # Ok(())
+///     # }).unwrap();
+///     ```
+///
 /// - There must be at least one `#[ink(message)]` defined method.
 ///
 ///     Methods flagged with `#[ink(message)]` are special in that they are dispatchable
@@ -281,6 +673,12 @@ pub fn selector_bytes(input: TokenStream) -> TokenStream {
 ///
 ///     - An ink! message with a `&self` receiver may only read state whereas an ink! message
 ///       with a `&mut self` receiver may mutate the contract's storage.
+///     - The output of an ink! message is returned in an `ink_lang::MessageResult` envelope
+///       whose `Err` variant is reserved for errors of the ink! language itself, e.g. if the
+///       input could not be decoded. An ink! message returning `Result<T, E>` reverts the
+///       call if it returns `Err`. Call builders and contract references decode the envelope.
+///       The ink! metadata describes the envelope as the return type of every ink! message
+///       and the `ink_lang::LangError` type under the `langError` key of the contract spec.
 ///
 ///     **Example:**
 ///
@@ -321,9 +719,6 @@ pub fn selector_bytes(input: TokenStream) -> TokenStream {
 ///     Authors of ink! smart contracts can make an ink! message payable by adding the `payable`
 ///     flag to it. An example below:
 ///
-///     Note that ink! constructors are always implicitly payable and thus cannot be flagged
-///     as such.
-///
 ///     ```
 ///     # use ink_lang as ink;
 ///     # #[ink::contract]
@@ -353,14 +748,10 @@ pub fn selector_bytes(input: TokenStream) -> TokenStream {
 ///     # }
 ///     ```
 ///
-///     **Controlling the messages selector:**
-///
-///     Every ink! message and ink! constructor has a unique selector with which the
-///     message or constructor can be uniquely identified within the ink! smart contract.
-///     These selectors are mainly used to drive the contract's dispatch upon calling it.
+///     **Payable Constructors:**
 ///
-///     An ink! smart contract author can control the selector of an ink! message or ink!
-///     constructor using the `selector` flag. An example is shown below:
+///     The same holds for ink! constructors: an ink! constructor rejects any value that is
+///     transferred upon instantiation unless it is flagged as `payable`.
 ///
 ///     ```
 ///     # use ink_lang as ink;
@@ -371,21 +762,70 @@ pub fn selector_bytes(input: TokenStream) -> TokenStream {
 ///         #     value: bool,
 ///         # }
 ///     impl Flipper {
+///         /// Rejects any endowment.
 ///         #[ink(constructor)]
-///         #[ink(selector = 0xDEADBEEF)] // Works on constructors as well.
 ///         pub fn new(initial_value: bool) -> Self {
-///             Flipper { value: false }
+///             Flipper { value: initial_value }
 ///         }
 ///
-///         # /// Flips the current value.
+///         /// Accepts an endowment, e.g. to fund the contract upon instantiation.
+///         #[ink(constructor, payable)]
+///         pub fn new_funded(initial_value: bool) -> Self {
+///             Flipper { value: initial_value }
+///         }
 ///         # #[ink(message)]
-///         # #[ink(selector = 0xCAFEBABE)] // You can either specify selector out-of-line.
-///         # pub fn flip(&mut self) {
-///         #     self.value = !self.value;
+///         # pub fn message(&self) {}
+///     }
+///     # }
+///     #
+///     # use flipper::{Flipper, FlipperRef};
+///     # use ink_lang::fixtures::Fixtures;
+///     # ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+///     # let mut fixtures = Fixtures::<ink_env::DefaultEnvironment>::new();
+///     # let code_hash = fixtures.register::<Flipper>();
+///     # let _ = fixtures.deploy::<Flipper>().endowment(10).instantiate(FlipperRef::new_funded(true));
+///     # let result = FlipperRef::new(true)
+///     #     .code_hash(code_hash)
+///     #     .gas_limit(0)
+///     #     .endowment(10)
+///     #     .salt_bytes([0xFF])
+///     #     .instantiate();
+///     # assert!(matches!(
+///     #     result,
+///     #     Err(ink_env::call::InstantiateError::Env(ink_env::Error::CalleeTrapped))
+///     # ));
+///     # Ok(())
+///     # }).unwrap();
+///     ```
+///
+///     **Default Constructors and Messages:**
+///
+///     At most one ink! constructor and one ink! message can be flagged as `default`.
+///     The flag is recorded in the contract metadata, next to the `payable` and `mutates`
+///     flags, so that user interfaces can preselect them.
+///
+///     ```
+///     # use ink_lang as ink;
+///     # #[ink::contract]
+///     # mod flipper {
+///         # #[ink(storage)]
+///         # pub struct Flipper {
+///         #     value: bool,
 ///         # }
-///         #
+///     impl Flipper {
+///         #[ink(constructor, default)]
+///         pub fn new(initial_value: bool) -> Self {
+///             Flipper { value: initial_value }
+///         }
+///
+///         /// Flips the current value.
+///         #[ink(message, default)]
+///         pub fn flip(&mut self) {
+///             self.value = !self.value;
+///         }
+///
 ///         /// Returns the current value.
-///         #[ink(message, selector = 0xFEEDBEEF)] // ...or specify selector inline.
+///         #[ink(message)]
 ///         pub fn get(&self) -> bool {
 ///             self.value
 ///         }
@@ -393,63 +833,541 @@ pub fn selector_bytes(input: TokenStream) -> TokenStream {
 ///     # }
 ///     ```
 ///
-/// ## Interacting with the Contract Executor
-///
-/// The `ink_env` crate provides facilities to interact with the contract executor that
-/// connects ink! smart contracts with the outer world.
-///
-/// For example it is possible to query the current call's caller via:
-/// ```
-/// # ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
-/// let caller = ink_env::caller::<ink_env::DefaultEnvironment>();
-/// # let _caller = caller;
-/// # Ok(())
-/// # }).unwrap();
-/// ```
-///
-/// However, ink! provides a much simpler way to interact with the contract executor
-/// via its environment accessor. An example below:
+///     **Guarded Messages:**
 ///
-/// ```
-/// # use ink_lang as ink;
-/// #
-/// #[ink::contract]
-/// mod greeter {
-///     use ink_env::debug_println;
+///     Checks shared by multiple ink! messages, e.g. access control, can be factored out
+///     into a guard. A guard is a method of the ink! storage struct that takes `&self` and
+///     returns `Result<(), E>`. Flagging an ink! message with `guard = "name"` invokes the
+///     guard before the body of the message and returns its error via the `?` operator.
+///     Therefore the guarded message must return a `Result` whose error type implements
+///     `From<E>`, and it reverts upon such an error just like any other failing message.
 ///
-///     #[ink(storage)]
-///     pub struct Greeter;
+///     ```
+///     # use ink_lang as ink;
+///     # #[ink::contract]
+///     # mod flipper {
+///         # #[ink(storage)]
+///         # pub struct Flipper {
+///         #     value: bool,
+///         #     owner: AccountId,
+///         # }
+///     #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+///     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+///     pub enum Error {
+///         NotOwner,
+///     }
 ///
-///     impl Greeter {
-///         #[ink(constructor)]
-///         pub fn new() -> Self {
-///             let caller = Self::env().caller();
-///             debug_println!("thanks for instantiation {:?}", caller);
-///             Greeter {}
+///     impl Flipper {
+///         # #[ink(constructor)]
+///         # pub fn new() -> Self {
+///         #     Flipper { value: false, owner: Self::env().caller() }
+///         # }
+///         /// Flips the current value if called by the owner.
+///         #[ink(message, guard = "only_owner")]
+///         pub fn flip(&mut self) -> Result<(), Error> {
+///             self.value = !self.value;
+///             Ok(())
 ///         }
 ///
-///         #[ink(message, payable)]
-///         pub fn fund(&self) {
-///             let caller = self.env().caller();
-///             let value = self.env().transferred_value();
-///             debug_println!("thanks for the funding of {:?} from {:?}", value, caller);
+///         fn only_owner(&self) -> Result<(), Error> {
+///             if self.env().caller() != self.owner {
+///                 return Err(Error::NotOwner)
+///             }
+///             Ok(())
 ///         }
 ///     }
-/// }
-/// ```
-///
-/// ## Events
+///     # }
+///     #
+///     # use flipper::{Error, Flipper};
+///     # ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|accounts| {
+///     # let mut flipper = Flipper::new();
+///     # assert_eq!(flipper.flip(), Ok(()));
+///     # ink_env::test::ExecutionContext::<ink_env::DefaultEnvironment>::build()
+///     #     .caller(accounts.bob)
+///     #     .apply();
+///     # assert_eq!(flipper.flip(), Err(Error::NotOwner));
+///     # Ok(())
+///     # }).unwrap();
+///     ```
 ///
-/// An ink! smart contract may define events that it can emit during contract execution.
-/// Emitting events can be used by third party tools to query information about a contract's
-/// execution and state.
+///     **Non-reentrant Messages:**
 ///
-/// The following example ink! contract shows how an event `Transferred` is defined and
-/// emitted in the `#[ink(constructor)]`.
+///     ink! messages that call other contracts can be flagged as `non_reentrant`. Such a
+///     message holds a lock in the contract storage while it executes and any call that
+///     reenters the contract into a `non_reentrant` ink! message in the meantime traps.
+///     The lock is shared by all `non_reentrant` ink! messages of the contract.
 ///
-/// ```
-/// # use ink_lang as ink;
-/// #
+///     ```
+///     # use ink_lang as ink;
+///     # #[ink::contract]
+///     # mod flipper {
+///         # use ink_env::call::{build_call, Call, CallFlags, ExecutionInput, Selector};
+///         # #[ink(storage)]
+///         # pub struct Flipper {
+///         #     value: bool,
+///         # }
+///     impl Flipper {
+///         # #[ink(constructor)]
+///         # pub fn new() -> Self {
+///         #     Flipper { value: false }
+///         # }
+///         /// Flips the current value and notifies `observer` about it.
+///         ///
+///         /// Returns `false` if notifying the observer failed.
+///         #[ink(message, non_reentrant)]
+///         pub fn flip(&mut self, observer: AccountId) -> bool {
+///             self.value = !self.value;
+///             build_call::<Environment>()
+///                 .call_type(Call::new().callee(observer))
+///                 .call_flags(CallFlags::default().set_allow_reentry(true))
+///                 .exec_input(
+///                     ExecutionInput::new(Selector::new(ink_lang::selector_bytes!("flip")))
+///                         .push_arg(observer),
+///                 )
+///                 .returns::<bool>()
+///                 .fire()
+///                 .is_ok()
+///         }
+///         # #[ink(message)]
+///         # pub fn get(&self) -> bool {
+///         #     self.value
+///         # }
+///     }
+///     # }
+///     #
+///     # use flipper::{Flipper, FlipperRef};
+///     # use ink_lang::{fixtures::Fixtures, ToAccountId as _};
+///     # ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+///     # let mut fixtures = Fixtures::<ink_env::DefaultEnvironment>::new();
+///     # let mut flipper = fixtures.deploy::<Flipper>().instantiate(FlipperRef::new());
+///     # let observer = flipper.to_account_id();
+///     // Flipping with itself as observer reenters `flip` and thus fails to notify.
+///     # assert!(!flipper.flip(observer));
+///     # assert!(flipper.get());
+///     # assert!(!flipper.flip(observer));
+///     # assert!(!flipper.get());
+///     # Ok(())
+///     # }).unwrap();
+///     ```
+///
+///     **Message Hooks:**
+///
+///     Cross-cutting concerns of all ink! messages, e.g. pausing a contract or collecting
+///     metrics, can be implemented once per contract via hooks. A hook is a method of the
+///     ink! storage struct in an inherent implementation block that takes `&self` and the
+///     selector of the called ink! message as `[u8; 4]`. The method flagged with
+///     `#[ink(hook(before_call))]` is invoked before and the method flagged with
+///     `#[ink(hook(after_call))]` is invoked after every ink! message that is dispatched.
+///     There may be at most one hook of each kind. A hook can revert the call by panicking.
+///
+///     Note that hooks are only invoked upon dispatch, i.e. not when calling the methods
+///     of the ink! storage struct directly, and that constructors are not hooked.
+///
+///     ```
+///     # use ink_lang as ink;
+///     # #[ink::contract]
+///     # mod flipper {
+///         # #[ink(storage)]
+///         # pub struct Flipper {
+///         #     value: bool,
+///         #     paused: bool,
+///         # }
+///     impl Flipper {
+///         # #[ink(constructor)]
+///         # pub fn new() -> Self {
+///         #     Flipper { value: false, paused: false }
+///         # }
+///         # #[ink(message)]
+///         # pub fn flip(&mut self) {
+///         #     self.value = !self.value;
+///         # }
+///         #[ink(message)]
+///         pub fn set_paused(&mut self, paused: bool) {
+///             self.paused = paused;
+///         }
+///
+///         /// Reverts all messages but `set_paused` while the contract is paused.
+///         #[ink(hook(before_call))]
+///         fn ensure_not_paused(&self, selector: [u8; 4]) {
+///             if self.paused && selector != ink_lang::selector_bytes!("set_paused") {
+///                 panic!("the contract is paused")
+///             }
+///         }
+///     }
+///     # }
+///     #
+///     # use flipper::{Flipper, FlipperRef};
+///     # use ink_lang::{codegen::TraitCallBuilder as _, fixtures::Fixtures};
+///     # ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+///     # let mut fixtures = Fixtures::<ink_env::DefaultEnvironment>::new();
+///     # let mut flipper = fixtures.deploy::<Flipper>().instantiate(FlipperRef::new());
+///     # assert!(flipper.call_mut().flip().fire().is_ok());
+///     # flipper.set_paused(true);
+///     # assert!(flipper.call_mut().flip().fire().is_err());
+///     # flipper.set_paused(false);
+///     # assert!(flipper.call_mut().flip().fire().is_ok());
+///     # Ok(())
+///     # }).unwrap();
+///     ```
+///
+///     **Deprecated Messages:**
+///
+///     Legacy ink! messages can be flagged as `deprecated` with a note. The note is
+///     recorded in the `deprecated` field of the message in the contract metadata so
+///     that UIs and SDK generators can flag them. For ink! messages of inherent
+///     implementation blocks the Rust compiler additionally warns all callers of the
+///     message, of its `ContractRef` forwarder and of its call builder.
+///
+///     ```
+///     # use ink_lang as ink;
+///     # #[ink::contract]
+///     # mod flipper {
+///         # #[ink(storage)]
+///         # pub struct Flipper {
+///         #     value: bool,
+///         # }
+///     impl Flipper {
+///         # #[ink(constructor)]
+///         # pub fn new() -> Self {
+///         #     Flipper { value: false }
+///         # }
+///         #[ink(message, deprecated = "use `set` instead")]
+///         pub fn flip(&mut self) {
+///             self.value = !self.value;
+///         }
+///
+///         #[ink(message)]
+///         pub fn set(&mut self, value: bool) {
+///             self.value = value;
+///         }
+///     }
+///     # }
+///     ```
+///
+///     **Gas and Value Overrides:**
+///
+///     The `ContractRef` of an ink! smart contract calls its inherent ink! messages with
+///     the short-hand notation, e.g. `piggy_bank.balance()`. Its implementation of the
+///     `ink_lang::CallOverrides` trait returns a short-hand caller that overrides the gas
+///     limit, the proof size limit and the value transferred with the call without
+///     dropping down to the call builder. The calls of the short-hand caller return the
+///     error of the call instead of panicking.
+///
+///     ```
+///     # use ink_lang as ink;
+///     # #[ink::contract]
+///     # mod piggy_bank {
+///         # #[ink(storage)]
+///         # pub struct PiggyBank {}
+///     impl PiggyBank {
+///         # #[ink(constructor)]
+///         # pub fn new() -> Self {
+///         #     Self {}
+///         # }
+///         #[ink(message, payable)]
+///         pub fn deposit(&mut self) -> Balance {
+///             self.env().transferred_value()
+///         }
+///     }
+///     # }
+///     #
+///     # use piggy_bank::{PiggyBank, PiggyBankRef};
+///     # use ink_lang::fixtures::Fixtures;
+///     # ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+///     # let mut fixtures = Fixtures::<ink_env::DefaultEnvironment>::new();
+///     # let piggy_bank = fixtures.deploy::<PiggyBank>().instantiate(PiggyBankRef::new());
+///     use ink_lang::CallOverrides as _;
+///
+///     let deposited = piggy_bank.with_gas_limit(5_000).with_value(10).deposit();
+///     assert_eq!(deposited, Ok(10));
+///     # Ok(())
+///     # }).unwrap();
+///     ```
+///
+///     **Fallible Short-Hand Calls:**
+///
+///     Short-hand calls panic if the called contract traps, runs out of gas or reverts.
+///     The short-hand caller returned by `try_call` of the `ink_lang::CallOverrides`
+///     trait provides all inherent ink! messages as well but returns the error of the
+///     call instead.
+///
+///     ```
+///     # use ink_lang as ink;
+///     # #[ink::contract]
+///     # mod vault {
+///         # #[ink(storage)]
+///         # pub struct Vault {
+///         #     locked: bool,
+///         # }
+///     impl Vault {
+///         # #[ink(constructor)]
+///         # pub fn new() -> Self {
+///         #     Self { locked: true }
+///         # }
+///         #[ink(message)]
+///         pub fn withdraw(&mut self) -> Balance {
+///             if self.locked {
+///                 panic!("the vault is locked")
+///             }
+///             self.env().balance()
+///         }
+///     }
+///     # }
+///     #
+///     # use vault::{Vault, VaultRef};
+///     # use ink_lang::fixtures::Fixtures;
+///     # ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+///     # let mut fixtures = Fixtures::<ink_env::DefaultEnvironment>::new();
+///     # let mut vault = fixtures.deploy::<Vault>().instantiate(VaultRef::new());
+///     use ink_lang::CallOverrides as _;
+///
+///     match vault.try_call().withdraw() {
+///         Ok(_balance) => unreachable!("the vault is locked"),
+///         Err(_error) => { /* handle the failed call */ }
+///     }
+///     # Ok(())
+///     # }).unwrap();
+///     ```
+///
+///     **Delegate References:**
+///
+///     Besides its `ContractRef` every ink! smart contract provides a `DelegateRef` that
+///     is constructed from a code hash via the `ink_lang::FromCodeHash` trait. Its inherent
+///     ink! messages perform delegate calls into the referenced code which executes against
+///     the storage of the calling contract and return the `ink_env::Error` of a failed
+///     delegate call. This allows to share logic libraries between contracts with a
+///     compatible storage layout.
+///
+///     Note that ink! messages taking `&mut self` write back the storage of the calling
+///     contract after they return and thereby overwrite all changes of the delegate call.
+///     Messages that delegate calls should therefore take `&self`.
+///
+///     ```
+///     # use ink_lang as ink;
+///     # #[ink::contract]
+///     # mod counter {
+///         # #[ink(storage)]
+///         # pub struct Counter {
+///         #     value: u32,
+///         # }
+///     impl Counter {
+///         # #[ink(constructor)]
+///         # pub fn new() -> Self {
+///         #     Self { value: 0 }
+///         # }
+///         #[ink(message)]
+///         pub fn inc(&mut self, by: u32) -> u32 {
+///             self.value += by;
+///             self.value
+///         }
+///
+///         /// Increments the value using the logic of the given code.
+///         #[ink(message)]
+///         pub fn inc_with(&self, logic: Hash, by: u32) -> u32 {
+///             use ink_lang::FromCodeHash as _;
+///             CounterDelegateRef::from_code_hash(logic)
+///                 .inc(by)
+///                 .unwrap_or_else(|error| panic!("delegate call failed: {:?}", error))
+///         }
+///         # #[ink(message)]
+///         # pub fn get(&self) -> u32 {
+///         #     self.value
+///         # }
+///     }
+///     # }
+///     #
+///     # use counter::{Counter, CounterRef};
+///     # use ink_lang::fixtures::Fixtures;
+///     # ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+///     # let mut fixtures = Fixtures::<ink_env::DefaultEnvironment>::new();
+///     # let logic = fixtures.register::<Counter>();
+///     # let counter = fixtures.deploy::<Counter>().instantiate(CounterRef::new());
+///     # assert_eq!(counter.inc_with(logic, 2), 2);
+///     # assert_eq!(counter.inc_with(logic, 3), 5);
+///     # assert_eq!(counter.get(), 5);
+///     # Ok(())
+///     # }).unwrap();
+///     ```
+///
+///     **Trait References:**
+///
+///     For every ink! trait implemented by an ink! smart contract its `ContractRef`
+///     provides an `as_trait` method, e.g. `as_psp22` for an ink! trait named `Psp22`,
+///     returning a reference to the same contract that only exposes the ink! trait
+///     messages. Such an ink! trait reference can be converted back into the
+///     `ContractRef` using its `from_trait_ref` constructor, e.g. `from_psp22_ref`.
+///
+///     Since these conversions are named after the last segment of the ink! trait path,
+///     an ink! smart contract must not implement multiple ink! traits of the same name,
+///     e.g. `a::Erc20` and `b::Erc20`, nor define inherent ink! messages named like
+///     the conversions.
+///
+///     ```
+///     # use ink_lang as ink;
+///     # #[ink::contract]
+///     # mod flipper {
+///     #     #[ink_lang::trait_definition]
+///     #     pub trait Flip {
+///     #         #[ink(message)]
+///     #         fn flip(&mut self);
+///     #     }
+///     #
+///         # #[ink(storage)]
+///         # pub struct Flipper {
+///         #     value: bool,
+///         # }
+///     # impl Flipper {
+///     #     #[ink(constructor)]
+///     #     pub fn new() -> Self {
+///     #         Self { value: false }
+///     #     }
+///     #
+///     #     #[ink(message)]
+///     #     pub fn get(&self) -> bool {
+///     #         self.value
+///     #     }
+///     # }
+///     impl Flip for Flipper {
+///         #[ink(message)]
+///         fn flip(&mut self) {
+///             self.value = !self.value;
+///         }
+///     }
+///     # }
+///     #
+///     # use flipper::{Flip as _, Flipper, FlipperRef};
+///     # use ink_lang::fixtures::Fixtures;
+///     # ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+///     # let mut fixtures = Fixtures::<ink_env::DefaultEnvironment>::new();
+///     # let flipper = fixtures.deploy::<Flipper>().instantiate(FlipperRef::new());
+///     let mut flip = flipper.as_flip();
+///     flip.flip();
+///     let flipper = FlipperRef::from_flip_ref(flip);
+///     assert!(flipper.get());
+///     # Ok(())
+///     # }).unwrap();
+///     ```
+///
+///     **Mocking Contracts:**
+///
+///     With the `std` feature every ink! smart contract also provides a `Mock` that
+///     allows unit tests of dependent contracts to substitute canned outputs for its
+///     ink! messages. Calls of the mocked messages through a `ContractRef` to the mocked
+///     account return these outputs in the off-chain environment regardless of their
+///     arguments, without the contract having to be instantiated.
+///
+///     The mock has a method for every ink! message which is prefixed with `mock_`,
+///     e.g. `mock_total_supply`. Methods of ink! trait messages are additionally
+///     prefixed with the snake case name of their ink! trait, e.g. `mock_erc20_transfer`.
+///
+///     ```ignore
+///     #[ink::test]
+///     fn total_supply_works() {
+///         let accounts = ink_env::test::default_accounts::<ink_env::DefaultEnvironment>();
+///         let token = Erc20Mock::new(accounts.bob);
+///         token.mock_total_supply(1_000).mock_balance_of(100);
+///         let mut wallet = Wallet::new(token.contract_ref());
+///         assert_eq!(wallet.total_supply(), 1_000);
+///     }
+///     ```
+///
+///     **Controlling the messages selector:**
+///
+///     Every ink! message and ink! constructor has a unique selector with which the
+///     message or constructor can be uniquely identified within the ink! smart contract.
+///     These selectors are mainly used to drive the contract's dispatch upon calling it.
+///
+///     An ink! smart contract author can control the selector of an ink! message or ink!
+///     constructor using the `selector` flag. An example is shown below:
+///
+///     ```
+///     # use ink_lang as ink;
+///     # #[ink::contract]
+///     # mod flipper {
+///         # #[ink(storage)]
+///         # pub struct Flipper {
+///         #     value: bool,
+///         # }
+///     impl Flipper {
+///         #[ink(constructor)]
+///         #[ink(selector = 0xDEADBEEF)] // Works on constructors as well.
+///         pub fn new(initial_value: bool) -> Self {
+///             Flipper { value: false }
+///         }
+///
+///         # /// Flips the current value.
+///         # #[ink(message)]
+///         # #[ink(selector = 0xCAFEBABE)] // You can either specify selector out-of-line.
+///         # pub fn flip(&mut self) {
+///         #     self.value = !self.value;
+///         # }
+///         #
+///         /// Returns the current value.
+///         #[ink(message, selector = 0xFEEDBEEF)] // ...or specify selector inline.
+///         pub fn get(&self) -> bool {
+///             self.value
+///         }
+///     }
+///     # }
+///     ```
+///
+/// ## Interacting with the Contract Executor
+///
+/// The `ink_env` crate provides facilities to interact with the contract executor that
+/// connects ink! smart contracts with the outer world.
+///
+/// For example it is possible to query the current call's caller via:
+/// ```
+/// # ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+/// let caller = ink_env::caller::<ink_env::DefaultEnvironment>();
+/// # let _caller = caller;
+/// # Ok(())
+/// # }).unwrap();
+/// ```
+///
+/// However, ink! provides a much simpler way to interact with the contract executor
+/// via its environment accessor. An example below:
+///
+/// ```
+/// # use ink_lang as ink;
+/// #
+/// #[ink::contract]
+/// mod greeter {
+///     use ink_env::debug_println;
+///
+///     #[ink(storage)]
+///     pub struct Greeter;
+///
+///     impl Greeter {
+///         #[ink(constructor)]
+///         pub fn new() -> Self {
+///             let caller = Self::env().caller();
+///             debug_println!("thanks for instantiation {:?}", caller);
+///             Greeter {}
+///         }
+///
+///         #[ink(message, payable)]
+///         pub fn fund(&self) {
+///             let caller = self.env().caller();
+///             let value = self.env().transferred_value();
+///             debug_println!("thanks for the funding of {:?} from {:?}", value, caller);
+///         }
+///     }
+/// }
+/// ```
+///
+/// ## Events
+///
+/// An ink! smart contract may define events that it can emit during contract execution.
+/// Emitting events can be used by third party tools to query information about a contract's
+/// execution and state.
+///
+/// The following example ink! contract shows how an event `Transferred` is defined and
+/// emitted in the `#[ink(constructor)]`.
+///
+/// ```
+/// # use ink_lang as ink;
+/// #
 /// #[ink::contract]
 /// mod erc20 {
 ///     /// Defines an event that is emitted every time value is transferred.
@@ -486,183 +1404,918 @@ pub fn selector_bytes(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
-/// ## Example: Flipper
+/// Event fields annotated with `#[ink(topic)]` are additionally published as topics of the
+/// event so that they can be efficiently filtered for. Every event also has a topic for
+/// its signature unless it is declared as `#[ink(event, anonymous)]`.
 ///
-/// The below code shows the complete implementation of the so-called Flipper
-/// ink! smart contract.
+/// The number of `#[ink(topic)]` fields of an event is checked at compile time against the
+/// `MAX_EVENT_TOPICS` of the contract's environment. Therefore the below event fails to
+/// compile instead of failing to be emitted at runtime since the default environment
+/// allows for at most 4 topics:
+///
+/// ```compile_fail
+/// # use ink_lang as ink;
+/// #
+/// #[ink::contract]
+/// mod contract {
+///     #[ink(event, anonymous)]
+///     pub struct TooManyTopics {
+///         #[ink(topic)]
+///         arg_1: u8,
+///         #[ink(topic)]
+///         arg_2: u8,
+///         #[ink(topic)]
+///         arg_3: u8,
+///         #[ink(topic)]
+///         arg_4: u8,
+///         #[ink(topic)]
+///         arg_5: u8,
+///     }
+///     # #[ink(storage)]
+///     # pub struct Contract {}
+///     # impl Contract {
+///     #     #[ink(constructor)]
+///     #     pub fn new() -> Self { Self {} }
+///     #     #[ink(message)]
+///     #     pub fn message(&self) {}
+///     # }
+/// }
+/// ```
+///
+/// Events shared by multiple ink! smart contracts can instead be defined once outside of
+/// them using [`#[ink::event]`](`macro@crate::event`) and imported into each contract via
+/// `#[ink(event)] use path::to::Event;`.
+///
+/// ## Submodules
+///
+/// Large ink! smart contracts can be split into inline submodules of the ink! module.
+/// All ink! items, i.e. the `#[ink(storage)]` struct, ink! events and ink! implementation
+/// blocks, found in inline submodules are moved into the root of the ink! module while all
+/// other items stay in their submodule. Therefore names used by the moved ink! items are
+/// resolved relative to the root of the ink! module.
+///
+/// ```
+/// # use ink_lang as ink;
+/// #
+/// #[ink::contract]
+/// mod flipper {
+///     mod storage {
+///         #[ink(storage)]
+///         pub struct Flipper {
+///             value: bool,
+///         }
+///     }
+///
+///     mod messages {
+///         impl Flipper {
+///             #[ink(constructor)]
+///             pub fn new() -> Self {
+///                 Self { value: false }
+///             }
+///
+///             #[ink(message)]
+///             pub fn flip(&mut self) {
+///                 self.value = utils::negate(self.value);
+///             }
+///         }
+///     }
+///
+///     mod utils {
+///         pub fn negate(value: bool) -> bool {
+///             !value
+///         }
+///     }
+/// }
+/// ```
+///
+/// **Note:** Procedural macros cannot see the contents of out-of-line modules, such as
+/// `mod messages;` that is defined in its own file. Hence ink! items must not be defined
+/// in them.
+///
+/// ## Example: Flipper
+///
+/// The below code shows the complete implementation of the so-called Flipper
+/// ink! smart contract.
 /// For us it acts as the "Hello, World!" of the ink! smart contracts because
 /// it is minimal while still providing some more or less useful functionality.
 ///
-/// It controls a single `bool` value that can be either `false` or `true`
-/// and allows the user to flip this value using the `Flipper::flip` message
-/// or retrieve the current value using `Flipper::get`.
+/// It controls a single `bool` value that can be either `false` or `true`
+/// and allows the user to flip this value using the `Flipper::flip` message
+/// or retrieve the current value using `Flipper::get`.
+///
+/// ```
+/// use ink_lang as ink;
+///
+/// #[ink::contract]
+/// pub mod flipper {
+///     #[ink(storage)]
+///     pub struct Flipper {
+///         value: bool,
+///     }
+///
+///     impl Flipper {
+///         /// Creates a new flipper smart contract initialized with the given value.
+///         #[ink(constructor)]
+///         pub fn new(init_value: bool) -> Self {
+///             Self { value: init_value }
+///         }
+///
+///         /// Flips the current value of the Flipper's boolean.
+///         #[ink(message)]
+///         pub fn flip(&mut self) {
+///             self.value = !self.value;
+///         }
+///
+///         /// Returns the current value of the Flipper's boolean.
+///         #[ink(message)]
+///         pub fn get(&self) -> bool {
+///             self.value
+///         }
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn contract(attr: TokenStream, item: TokenStream) -> TokenStream {
+    contract::generate(attr.into(), item.into()).into()
+}
+
+/// Marks trait definitions to ink! as special ink! trait definitions.
+///
+/// There are some restrictions that apply to ink! trait definitions that
+/// this macro checks. Also ink! trait definitions are required to have specialized
+/// structure so that the main [`#[ink::contract]`](`macro@crate::contract`) macro can
+/// properly generate code for its implementations.
+///
+/// # Example
+///
+/// # Trait definition:
+///
+/// ```
+/// use ink_lang as ink;
+/// # type Balance = <ink_env::DefaultEnvironment as ink_env::Environment>::Balance;
+/// # type AccountId = <ink_env::DefaultEnvironment as ink_env::Environment>::AccountId;
+///
+/// #[ink::trait_definition]
+/// pub trait Erc20 {
+///     /// Returns the total supply of the ERC-20 smart contract.
+///     #[ink(message)]
+///     fn total_supply(&self) -> Balance;
+///
+///     /// Transfers balance from the caller to the given address.
+///     #[ink(message)]
+///     fn transfer(&mut self, amount: Balance, to: AccountId) -> bool;
+///
+///     // etc.
+/// }
+/// ```
+///
+/// # Trait implementation
+///
+/// Given the above trait definition you can implement it as shown below:
+///
+/// ```
+/// # use ink_lang as ink;
+/// #
+/// #[ink::contract]
+/// mod base_erc20 {
+/// #    // We somehow cannot put the trait in the doc-test crate root due to bugs.
+/// #    #[ink_lang::trait_definition]
+/// #    pub trait Erc20 {
+/// #       /// Returns the total supply of the ERC-20 smart contract.
+/// #       #[ink(message)]
+/// #       fn total_supply(&self) -> Balance;
+/// #
+/// #       /// Transfers balance from the caller to the given address.
+/// #       #[ink(message)]
+/// #       fn transfer(&mut self, amount: Balance, to: AccountId) -> bool;
+/// #    }
+/// #
+///     #[ink(storage)]
+///     pub struct BaseErc20 {
+///         total_supply: Balance,
+///     }
+///
+///     impl BaseErc20 {
+///         #[ink(constructor)]
+///         pub fn new(initial_supply: Balance) -> Self {
+///             Self { total_supply: initial_supply }
+///         }
+///     }
+///
+///     impl Erc20 for BaseErc20 {
+///         /// Returns the total supply of the ERC-20 smart contract.
+///         #[ink(message)]
+///         fn total_supply(&self) -> Balance {
+///             self.total_supply
+///         }
+///
+///         #[ink(message)]
+///         fn transfer(&mut self, amount: Balance, to: AccountId) -> bool {
+///             unimplemented!()
+///         }
+///     }
+/// }
+/// ```
+///
+/// # Default implementations
+///
+/// ink! trait messages may provide a default implementation. Implementers inherit
+/// it by declaring the message without a body and override it by providing a body.
+/// Inherited messages are dispatched and called just like any other ink! message.
+///
+/// Default implementations may call the other messages of the ink! trait
+/// definition and access the environment via `self.env()` or `Self::env()`.
+/// Since they are shared by all implementers, the types yielded by the environment
+/// are those of the generic `<Self as ContractEnv>::Env`.
+///
+/// ```
+/// # use ink_lang as ink;
+/// #
+/// #[ink::contract]
+/// mod base_erc20 {
+///     #[ink_lang::trait_definition]
+///     pub trait Erc20 {
+///         /// Returns the total supply of the ERC-20 smart contract.
+///         #[ink(message)]
+///         fn total_supply(&self) -> Balance;
+///
+///         /// Returns `true` if the ERC-20 smart contract has no supply.
+///         #[ink(message)]
+///         fn is_empty(&self) -> bool {
+///             self.total_supply() == 0
+///         }
+///
+///         /// Returns `true` if the ERC-20 smart contract calls itself.
+///         #[ink(message)]
+///         fn is_self_call(&self) -> bool {
+///             self.env().caller() == self.env().account_id()
+///         }
+///     }
+///
+///     #[ink(storage)]
+///     pub struct BaseErc20 {
+///         total_supply: Balance,
+///     }
+///
+///     impl BaseErc20 {
+///         #[ink(constructor)]
+///         pub fn new(initial_supply: Balance) -> Self {
+///             Self { total_supply: initial_supply }
+///         }
+///     }
+///
+///     impl Erc20 for BaseErc20 {
+///         #[ink(message)]
+///         fn total_supply(&self) -> Balance {
+///             self.total_supply
+///         }
+///
+///         /// Inherits the default implementation.
+///         #[ink(message)]
+///         fn is_empty(&self) -> bool;
+///
+///         /// Overrides the default implementation.
+///         #[ink(message)]
+///         fn is_self_call(&self) -> bool {
+///             false
+///         }
+///     }
+/// }
+///
+/// use base_erc20::{BaseErc20, Erc20 as _};
+///
+/// # ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+/// assert!(BaseErc20::new(0).is_empty());
+/// assert!(!BaseErc20::new(100).is_empty());
+/// assert!(!BaseErc20::new(100).is_self_call());
+/// # Ok(())
+/// # }).unwrap();
+/// ```
+///
+/// # Metadata
+///
+/// When compiled with the `std` feature an ink! trait definition with the
+/// `metadata = true` header argument also provides its interface-only ink! metadata,
+/// i.e. the messages, selectors and types of the trait without a concrete contract
+/// implementing it. It is returned by `ink_lang::trait_metadata!(dyn Trait)` and allows
+/// standards to publish their canonical machine-readable interface, e.g. for wallets
+/// to code against.
+///
+/// ## Header Arguments
+///
+/// The `#[ink::trait_definition]` macro can be provided with some additional comma-separated
+/// header arguments:
+///
+/// - `namespace: String`
+///
+///     The namespace configuration parameter is used to influence the generated
+///     selectors of the ink! trait messages. This is useful to disambiguate
+///     ink! trait definitions with equal names.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::trait_definition(namespace = "foo")]
+///     pub trait TraitDefinition {
+///         #[ink(message)]
+///         fn message1(&self);
+///
+///         #[ink(message, selector = 42)]
+///         fn message2(&self);
+///     }
+///     ```
+///
+///     The namespace can also be provided as `#[ink(namespace = "foo")]` attribute
+///     of the ink! trait definition.
+///
+///     Implementations of the ink! trait definition may repeat its namespace as
+///     `#[ink(namespace = "foo")]` attribute on their `impl` block. The selectors
+///     of the implemented ink! messages are then checked to be composed with this
+///     namespace, so changing the namespace of the ink! trait definition does not
+///     silently change the selectors of the implementing contracts.
+///
+///     **Default value:** Empty.
+///
+/// - `keep_attr: String`
+///
+///     Tells the ink! code generator which attributes should be passed to call builders.
+///     Call builders are used to doing cross-contract calls and are automatically
+///     generated for contracts.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::trait_definition(keep_attr = "foo, bar")]
+///     pub trait Storage {
+///         #[ink(message)]
+///     //  #[foo]
+///         fn message1(&self);
+///
+///         #[ink(message)]
+///     //  #[bar]
+///         fn message2(&self);
+///     }
+///     ```
+///
+///     **Allowed attributes by default:** `cfg`, `cfg_attr`, `allow`, `warn`, `deny`, `forbid`,
+///         `deprecated`, `must_use`, `doc`, `rustfmt`.
+///
+/// - `metadata: bool`
+///
+///     Tells the ink! code generator to generate the interface-only ink! metadata of
+///     the ink! trait definition. This requires all input and output types of its
+///     ink! messages to implement `scale_info::TypeInfo`.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::trait_definition(metadata = true)]
+///     pub trait Flip {
+///         #[ink(message)]
+///         fn flip(&mut self);
+///     }
+///     ```
+///
+///     **Default value:** `false`.
+#[proc_macro_attribute]
+pub fn trait_definition(attr: TokenStream, item: TokenStream) -> TokenStream {
+    trait_def::analyze(attr.into(), item.into()).into()
+}
+
+/// Defines an ink! event outside of an ink! smart contract.
+///
+/// Such an event can be emitted by all ink! smart contracts that import it via
+/// `#[ink(event)] use path::to::Event;` within their `#[ink::contract]` module,
+/// e.g. from a crate shared by all token contracts.
+///
+/// The event struct supports the same `#[ink(topic)]` field and `#[ink(anonymous)]`
+/// struct attributes as ink! events defined within an ink! smart contract.
+/// Unlike those its topics are not prefixed with the name of the emitting ink! smart
+/// contract so that the event has an identical topic layout and metadata in all of them.
+///
+/// # Example
+///
+/// ```
+/// # use ink_lang as ink;
+/// mod events {
+///     # use ink_lang as ink;
+///     use ink_env::AccountId;
+///
+///     /// Emitted every time value is transferred.
+///     #[ink::event]
+///     pub struct Transferred {
+///         #[ink(topic)]
+///         pub from: Option<AccountId>,
+///         #[ink(topic)]
+///         pub to: Option<AccountId>,
+///         pub value: u128,
+///     }
+/// }
+///
+/// #[ink::contract]
+/// mod erc20 {
+///     #[ink(event)]
+///     use crate::events::Transferred;
+///
+///     #[ink(storage)]
+///     pub struct Erc20 {
+///         total_supply: Balance,
+///     }
+///
+///     impl Erc20 {
+///         #[ink(constructor)]
+///         pub fn new(initial_supply: Balance) -> Self {
+///             Self::env().emit_event(Transferred {
+///                 from: None,
+///                 to: Some(Self::env().caller()),
+///                 value: initial_supply,
+///             });
+///             Self { total_supply: initial_supply }
+///         }
+///
+///         #[ink(message)]
+///         pub fn total_supply(&self) -> Balance {
+///             self.total_supply
+///         }
+///     }
+/// }
+/// #
+/// # fn main() {
+/// # ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+/// # let _ = erc20::Erc20::new(1_000);
+/// # assert_eq!(ink_env::test::recorded_events().count(), 1);
+/// # Ok(())
+/// # }).unwrap();
+/// # }
+/// ```
+#[proc_macro_attribute]
+pub fn event(attr: TokenStream, item: TokenStream) -> TokenStream {
+    event::generate(attr.into(), item.into()).into()
+}
+
+/// Derives the SCALE codec and type information traits for a struct, enum or union.
+///
+/// Accepts any of `Encode`, `Decode` and `TypeInfo` as comma-separated arguments and
+/// expands into the respective derives of the `scale` and `scale_info` crates that
+/// the ink! smart contract crate depends on. The `TypeInfo` derive is only enabled
+/// with the `std` feature of the ink! smart contract crate since the type information
+/// is solely required for generating the contract metadata.
+///
+/// # Example
+///
+/// ```
+/// # use ink_lang as ink;
+/// #[ink::scale_derive(Encode, Decode, TypeInfo)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Error {
+///     InsufficientBalance,
+///     InsufficientAllowance,
+/// }
+/// # use scale::{Decode as _, Encode as _};
+/// # let encoded = Error::InsufficientAllowance.encode();
+/// # assert_eq!(Error::decode(&mut &encoded[..]), Ok(Error::InsufficientAllowance));
+/// ```
+///
+/// This is equivalent to the following more verbose definition:
+///
+/// ```
+/// #[derive(scale::Encode, scale::Decode)]
+/// #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Error {
+///     InsufficientBalance,
+///     InsufficientAllowance,
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn scale_derive(attr: TokenStream, item: TokenStream) -> TokenStream {
+    scale_derive::generate(attr.into(), item.into()).into()
+}
+
+/// Implements the `Environment` trait of `ink_env` for a custom environment definition.
+///
+/// The macro is applied to a struct or enum definition and takes the types of the
+/// environment as comma-separated `name = Type` header arguments. All types that are
+/// not provided are equal to the respective types of the `DefaultEnvironment` so that
+/// only those types have to be specified that differ from the default environment.
+///
+/// The following header arguments are supported:
+///
+/// - `account_id = Type`, `balance = Type`, `hash = Type`, `timestamp = Type`,
+///   `block_number = Type` and `chain_extension = Type`
+///
+///     The `AccountId`, `Balance`, `Hash`, `Timestamp`, `BlockNumber` and
+///     `ChainExtension` associated types of the environment respectively.
+///
+/// - `max_event_topics = N`
+///
+///     The maximum number of topics of ink! events.
+///
+/// # Example
+///
+/// ```
+/// # use ink_lang as ink;
+/// #[ink::environment(balance = u64, max_event_topics = 2)]
+/// pub enum MyEnvironment {}
+///
+/// #[ink::contract(env = crate::MyEnvironment)]
+/// mod my_contract {
+///     #[ink(storage)]
+///     pub struct MyContract {
+///         value: Balance,
+///     }
+///
+///     impl MyContract {
+///         #[ink(constructor)]
+///         pub fn new() -> Self {
+///             Self { value: Self::env().balance() }
+///         }
+///
+///         #[ink(message)]
+///         pub fn value(&self) -> Balance {
+///             self.value
+///         }
+///     }
+/// }
+///
+/// # fn main() {
+/// use ink_env::Environment;
+///
+/// let balance: <MyEnvironment as Environment>::Balance = 42u64;
+/// let account_id: <MyEnvironment as Environment>::AccountId = [0x01; 32].into();
+/// assert_eq!(<MyEnvironment as Environment>::MAX_EVENT_TOPICS, 2);
+/// # let _ = (balance, account_id);
+/// # }
+/// ```
+#[proc_macro_attribute]
+pub fn environment(attr: TokenStream, item: TokenStream) -> TokenStream {
+    environment::generate(attr.into(), item.into()).into()
+}
+
+/// Makes an ink! smart contract ownable.
+///
+/// The instantiator of the ink! smart contract becomes its owner. The macro injects
+/// the following items into the ink! smart contract:
+///
+/// - `#[ink(message)] fn owner(&self) -> Option<AccountId>`
+/// - `#[ink(message)] fn transfer_ownership(&mut self, new_owner: Option<AccountId>)`
+///   which may only be called by the owner. Passing `None` renounces the ownership.
+/// - `fn only_owner(&self)` to be used as guard via `#[ink(guard = "only_owner")]`.
+/// - The `OwnershipTransferred` ink! event.
+///
+/// The fallible items return `Result<(), ink_lang::access::OwnableError>`.
+///
+/// # Note
+///
+/// The owner is kept in a dedicated contract storage cell so that neither the ink!
+/// storage struct nor the ink! constructors need to be altered. The macro must be
+/// applied above the `#[ink::contract]` attribute.
+///
+/// # Example
+///
+/// ```
+/// # use ink_lang as ink;
+/// #[ink::ownable]
+/// #[ink::contract]
+/// mod vault {
+///     #[ink(storage)]
+///     pub struct Vault {
+///         limit: Balance,
+///     }
+///
+///     impl Vault {
+///         #[ink(constructor)]
+///         pub fn new() -> Self {
+///             Self { limit: 0 }
+///         }
+///
+///         #[ink(message, guard = "only_owner")]
+///         pub fn set_limit(&mut self, limit: Balance) -> Result<(), ink_lang::access::OwnableError> {
+///             self.limit = limit;
+///             Ok(())
+///         }
+///     }
+/// }
+///
+/// use ink_lang::access::OwnableError;
+/// use vault::Vault;
+///
+/// # fn main() {
+/// ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|accounts| {
+///     let mut vault = Vault::new();
+///     assert_eq!(vault.owner(), Some(accounts.alice));
+///     assert_eq!(vault.transfer_ownership(Some(accounts.bob)), Ok(()));
+///     assert_eq!(vault.set_limit(100), Err(OwnableError::CallerIsNotOwner));
+///     ink_env::test::ExecutionContext::<ink_env::DefaultEnvironment>::build()
+///         .caller(accounts.bob)
+///         .apply();
+///     assert_eq!(vault.set_limit(100), Ok(()));
+///     Ok(())
+/// })
+/// .unwrap();
+/// # }
+/// ```
+#[proc_macro_attribute]
+pub fn ownable(attr: TokenStream, item: TokenStream) -> TokenStream {
+    mixin::generate(ink_lang_ir::MixinKind::Ownable, attr.into(), item.into()).into()
+}
+
+/// Adds role based access control to an ink! smart contract.
+///
+/// Roles are identified by a `RoleType` which is an alias for `u32`. The instantiator of
+/// the ink! smart contract is granted the `ADMIN_ROLE` that allows to grant and revoke all
+/// roles. The macro injects the following items into the ink! smart contract:
+///
+/// - `#[ink(message)] fn has_role(&self, role: RoleType, account: AccountId) -> bool`
+/// - `#[ink(message)] fn grant_role(&mut self, role: RoleType, account: AccountId)`
+///   and `#[ink(message)] fn revoke_role(&mut self, role: RoleType, account: AccountId)`
+///   which may only be called by admins.
+/// - `#[ink(message)] fn renounce_role(&mut self, role: RoleType)` to revoke a role
+///   of the caller.
+/// - `fn ensure_role(&self, role: RoleType)` to check the roles of the caller and
+///   `fn only_admin(&self)` to be used as guard via `#[ink(guard = "only_admin")]`.
+/// - The `RoleGranted` and `RoleRevoked` ink! events.
+///
+/// The fallible items return `Result<(), ink_lang::access::AccessControlError>`.
+///
+/// # Note
+///
+/// The granted roles are kept in dedicated contract storage cells so that neither the
+/// ink! storage struct nor the ink! constructors need to be altered. The macro must be
+/// applied above the `#[ink::contract]` attribute.
 ///
-/// ```
-/// use ink_lang as ink;
+/// # Example
 ///
+/// ```
+/// # use ink_lang as ink;
+/// #[ink::access_control]
 /// #[ink::contract]
-/// pub mod flipper {
+/// mod token {
+///     use ink_lang::access::{
+///         AccessControlError,
+///         RoleType,
+///     };
+///
+///     pub const MINTER: RoleType = 1;
+///
 ///     #[ink(storage)]
-///     pub struct Flipper {
-///         value: bool,
+///     pub struct Token {
+///         total_supply: Balance,
 ///     }
 ///
-///     impl Flipper {
-///         /// Creates a new flipper smart contract initialized with the given value.
+///     impl Token {
 ///         #[ink(constructor)]
-///         pub fn new(init_value: bool) -> Self {
-///             Self { value: init_value }
+///         pub fn new() -> Self {
+///             Self { total_supply: 0 }
 ///         }
 ///
-///         /// Flips the current value of the Flipper's boolean.
-///         #[ink(message)]
-///         pub fn flip(&mut self) {
-///             self.value = !self.value;
+///         #[ink(message, guard = "only_minter")]
+///         pub fn mint(&mut self, amount: Balance) -> Result<(), AccessControlError> {
+///             self.total_supply += amount;
+///             Ok(())
 ///         }
 ///
-///         /// Returns the current value of the Flipper's boolean.
-///         #[ink(message)]
-///         pub fn get(&self) -> bool {
-///             self.value
+///         pub fn only_minter(&self) -> Result<(), AccessControlError> {
+///             self.ensure_role(MINTER)
 ///         }
 ///     }
 /// }
+///
+/// use ink_lang::access::AccessControlError;
+/// use token::{Token, MINTER};
+///
+/// # fn main() {
+/// ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|accounts| {
+///     let mut token = Token::new();
+///     assert_eq!(token.mint(100), Err(AccessControlError::MissingRole));
+///     assert_eq!(token.grant_role(MINTER, accounts.alice), Ok(()));
+///     assert_eq!(token.mint(100), Ok(()));
+///     ink_env::test::ExecutionContext::<ink_env::DefaultEnvironment>::build()
+///         .caller(accounts.bob)
+///         .apply();
+///     assert_eq!(
+///         token.grant_role(MINTER, accounts.bob),
+///         Err(AccessControlError::MissingRole)
+///     );
+///     Ok(())
+/// })
+/// .unwrap();
+/// # }
 /// ```
 #[proc_macro_attribute]
-pub fn contract(attr: TokenStream, item: TokenStream) -> TokenStream {
-    contract::generate(attr.into(), item.into()).into()
+pub fn access_control(attr: TokenStream, item: TokenStream) -> TokenStream {
+    mixin::generate(
+        ink_lang_ir::MixinKind::AccessControl,
+        attr.into(),
+        item.into(),
+    )
+    .into()
 }
 
-/// Marks trait definitions to ink! as special ink! trait definitions.
+/// Exports an implementation block of ink! messages to be included by ink! smart
+/// contracts of other crates.
+///
+/// This allows library crates to ship implementations of ink! trait definitions, e.g.
+/// an audited PSP22 engine, that downstream ink! smart contracts include without
+/// vendoring their source code. The implementation block is exported as the extension
+/// point given by `#[ink::export_impl(name)]` which is a `#[macro_export]` macro at the
+/// root of the library crate. It is not compiled within the library crate itself.
+///
+/// ink! smart contracts include the implementation block via
+/// `#[ink::include_impl(path::to::name)]` which must be applied above the
+/// `#[ink::contract]` attribute. The self type of the implementation block is merely a
+/// placeholder that is replaced by the ink! storage struct of the including ink! smart
+/// contract. Therefore the implementation block should refer to the ink! storage struct
+/// via `Self` only.
 ///
-/// There are some restrictions that apply to ink! trait definitions that
-/// this macro checks. Also ink! trait definitions are required to have specialized
-/// structure so that the main [`#[ink::contract]`](`macro@crate::contract`) macro can
-/// properly generate code for its implementations.
+/// # Note
+///
+/// - The implementation block is expanded within the ink! smart contract module. Paths
+///   starting with `crate::` are resolved within the exporting library crate while all
+///   other paths, e.g. the implemented ink! trait definition or the environmental types
+///   such as `Balance`, are resolved within the ink! smart contract module.
+/// - The implementation block may access fields of the ink! storage struct. These are
+///   requirements that the including ink! smart contracts have to fulfill.
+/// - An ink! smart contract may include multiple implementation blocks by applying
+///   `#[ink::include_impl]` multiple times.
 ///
 /// # Example
 ///
-/// # Trait definition:
+/// The library crate `flip_engine` exports an implementation of the `Flip` ink! trait
+/// definition for all ink! storage structs with a `value: bool` field:
 ///
 /// ```
-/// use ink_lang as ink;
-/// # type Balance = <ink_env::DefaultEnvironment as ink_env::Environment>::Balance;
-/// # type AccountId = <ink_env::DefaultEnvironment as ink_env::Environment>::AccountId;
-///
+/// # use ink_lang as ink;
 /// #[ink::trait_definition]
-/// pub trait Erc20 {
-///     /// Returns the total supply of the ERC-20 smart contract.
+/// pub trait Flip {
 ///     #[ink(message)]
-///     fn total_supply(&self) -> Balance;
+///     fn flip(&mut self);
 ///
-///     /// Transfers balance from the caller to the given address.
 ///     #[ink(message)]
-///     fn transfer(&mut self, amount: Balance, to: AccountId) -> bool;
+///     fn get(&self) -> bool;
+/// }
 ///
-///     // etc.
+/// #[ink::export_impl(flip_impl)]
+/// impl Flip for Contract {
+///     #[ink(message)]
+///     fn flip(&mut self) {
+///         self.value = !self.value;
+///     }
+///
+///     #[ink(message)]
+///     fn get(&self) -> bool {
+///         self.value
+///     }
 /// }
+/// #
+/// # #[ink::include_impl(flip_impl)]
+/// # #[ink::contract]
+/// # mod flipper {
+/// #     use super::Flip;
+/// #
+/// #     #[ink(storage)]
+/// #     pub struct Flipper {
+/// #         value: bool,
+/// #     }
+/// #
+/// #     impl Flipper {
+/// #         #[ink(constructor)]
+/// #         pub fn new() -> Self {
+/// #             Self { value: false }
+/// #         }
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// #     let mut flipper = flipper::Flipper::new();
+/// #     flipper.flip();
+/// #     assert!(flipper.get());
+/// # }
 /// ```
 ///
-/// # Trait implementation
+/// A downstream ink! smart contract then includes the implementation block:
 ///
-/// Given the above trait definition you can implement it as shown below:
+/// ```ignore
+/// use ink_lang as ink;
 ///
-/// ```
-/// # use ink_lang as ink;
-/// #
+/// #[ink::include_impl(flip_engine::flip_impl)]
 /// #[ink::contract]
-/// mod base_erc20 {
-/// #    // We somehow cannot put the trait in the doc-test crate root due to bugs.
-/// #    #[ink_lang::trait_definition]
-/// #    pub trait Erc20 {
-/// #       /// Returns the total supply of the ERC-20 smart contract.
-/// #       #[ink(message)]
-/// #       fn total_supply(&self) -> Balance;
-/// #
-/// #       /// Transfers balance from the caller to the given address.
-/// #       #[ink(message)]
-/// #       fn transfer(&mut self, amount: Balance, to: AccountId) -> bool;
-/// #    }
-/// #
+/// mod flipper {
+///     use flip_engine::Flip;
+///
 ///     #[ink(storage)]
-///     pub struct BaseErc20 {
-///         total_supply: Balance,
+///     pub struct Flipper {
+///         value: bool,
 ///     }
 ///
-///     impl BaseErc20 {
+///     impl Flipper {
 ///         #[ink(constructor)]
-///         pub fn new(initial_supply: Balance) -> Self {
-///             Self { total_supply: initial_supply }
-///         }
-///     }
-///
-///     impl Erc20 for BaseErc20 {
-///         /// Returns the total supply of the ERC-20 smart contract.
-///         #[ink(message)]
-///         fn total_supply(&self) -> Balance {
-///             self.total_supply
-///         }
-///
-///         #[ink(message)]
-///         fn transfer(&mut self, amount: Balance, to: AccountId) -> bool {
-///             unimplemented!()
+///         pub fn new() -> Self {
+///             Self { value: false }
 ///         }
 ///     }
 /// }
 /// ```
+#[proc_macro_attribute]
+pub fn export_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
+    export_impl::generate(attr.into(), item.into()).into()
+}
+
+/// Includes an implementation block exported via `#[ink::export_impl]` into an ink!
+/// smart contract.
 ///
-/// ## Header Arguments
+/// Must be applied above the `#[ink::contract]` attribute. See the documentation of
+/// [`macro@export_impl`] for further details.
 ///
-/// The `#[ink::trait_definition]` macro can be provided with some additional comma-separated
-/// header arguments:
+/// # Example
 ///
-/// - `namespace: String`
+/// ```ignore
+/// use ink_lang as ink;
 ///
-///     The namespace configuration parameter is used to influence the generated
-///     selectors of the ink! trait messages. This is useful to disambiguate
-///     ink! trait definitions with equal names.
+/// #[ink::include_impl(psp22_engine::psp22_impl)]
+/// #[ink::contract]
+/// mod token {
+///     use psp22_engine::PSP22;
 ///
-///     **Usage Example:**
-///     ```
-///     # use ink_lang as ink;
-///     #[ink::trait_definition(namespace = "foo")]
-///     pub trait TraitDefinition {
-///         #[ink(message)]
-///         fn message1(&self);
+///     #[ink(storage)]
+///     pub struct Token {
+///         total_supply: Balance,
+///         balances: ink_storage::Mapping<AccountId, Balance>,
+///     }
 ///
-///         #[ink(message, selector = 42)]
-///         fn message2(&self);
+///     impl Token {
+///         #[ink(constructor)]
+///         pub fn new(total_supply: Balance) -> Self {
+///             let mut balances = ink_storage::Mapping::default();
+///             balances.insert(Self::env().caller(), &total_supply);
+///             Self { total_supply, balances }
+///         }
 ///     }
-///     ```
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn include_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
+    include_impl::generate(attr.into(), item.into()).into()
+}
+
+/// Generates an upgradeable proxy contract into an empty inline module.
 ///
-///     **Default value:** Empty.
+/// The proxy contract executes all calls that do not match one of its own selectors
+/// with the code of its implementation contract via a delegate call. The input of the
+/// call is forwarded and the delegate call is a tail call so that the output of the
+/// implementation is returned to the caller directly. Since the implementation operates
+/// on the storage of the proxy, the implementation code hash and the admin of the proxy
+/// are kept in dedicated storage cells that do not collide with the implementation storage.
 ///
-/// - `keep_attr: String`
+/// The generated `Proxy` contract provides:
 ///
-///     Tells the ink! code generator which attributes should be passed to call builders.
-///     Call builders are used to doing cross-contract calls and are automatically
-///     generated for contracts.
+/// - `#[ink(constructor)] fn new(code_hash: Hash)` that makes the caller the admin.
+/// - `#[ink(message)] fn implementation(&self) -> Hash`
+/// - `#[ink(message)] fn admin(&self) -> AccountId`
+/// - `#[ink(message)] fn upgrade_to(&mut self, code_hash: Hash)` and
+///   `#[ink(message)] fn change_admin(&mut self, new_admin: AccountId)` which may only be
+///   called by the admin and return `Result<(), ink_lang::proxy::ProxyError>`.
+/// - The `Upgraded` ink! event.
 ///
-///     **Usage Example:**
-///     ```
-///     # use ink_lang as ink;
-///     #[ink::trait_definition(keep_attr = "foo, bar")]
-///     pub trait Storage {
-///         #[ink(message)]
-///     //  #[foo]
-///         fn message1(&self);
+/// # Note
 ///
-///         #[ink(message)]
-///     //  #[bar]
-///         fn message2(&self);
-///     }
-///     ```
+/// Messages of the implementation whose selectors collide with the selectors of the
+/// proxy messages above are shadowed by the proxy.
 ///
-///     **Allowed attributes by default:** `cfg`, `cfg_attr`, `allow`, `warn`, `deny`, `forbid`,
-///         `deprecated`, `must_use`, `doc`, `rustfmt`.
+/// The macro accepts the `env` header argument of `#[ink::contract]`.
+///
+/// # Example
+///
+/// ```
+/// # use ink_lang as ink;
+/// /// An upgradeable deployment of the flipper contract.
+/// #[ink::proxy]
+/// pub mod flipper_proxy {}
+///
+/// use flipper_proxy::Proxy;
+/// use ink_lang::proxy::ProxyError;
+///
+/// # fn main() {
+/// ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|accounts| {
+///     let mut proxy = Proxy::new([0x01; 32].into());
+///     assert_eq!(proxy.admin(), accounts.alice);
+///     assert_eq!(proxy.upgrade_to([0x02; 32].into()), Ok(()));
+///     assert_eq!(proxy.implementation(), [0x02; 32].into());
+///     ink_env::test::ExecutionContext::<ink_env::DefaultEnvironment>::build()
+///         .caller(accounts.bob)
+///         .apply();
+///     assert_eq!(
+///         proxy.upgrade_to([0x03; 32].into()),
+///         Err(ProxyError::CallerIsNotAdmin)
+///     );
+///     Ok(())
+/// })
+/// .unwrap();
+/// # }
+/// ```
 #[proc_macro_attribute]
-pub fn trait_definition(attr: TokenStream, item: TokenStream) -> TokenStream {
-    trait_def::analyze(attr.into(), item.into()).into()
+pub fn proxy(attr: TokenStream, item: TokenStream) -> TokenStream {
+    proxy::generate(attr.into(), item.into()).into()
 }
 
 /// Defines a unit test that makes use of ink!'s off-chain testing capabilities.
@@ -703,13 +2356,89 @@ pub fn trait_definition(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///     fn test2() -> Result<(), ink_env::Error> {
 ///         // test code that returns a Rust Result type
 ///     }
+///
+///     // Unit test with an `async fn` body that can await futures.
+///     #[ink::test]
+///     async fn test3() {
+///         // test code that may `.await` futures comes here
+///     }
 /// }
 /// ```
+///
+/// ## Header Arguments
+///
+/// The `#[ink::test]` macro can be provided with some additional comma-separated
+/// header arguments:
+///
+/// - `env: impl Environment`
+///
+///     Tells the ink! code generator which environment the off-chain testing
+///     environment is set up with. This must be the environment of the tested
+///     ink! smart contracts if they use a custom environment. Its `AccountId`
+///     type must be constructible from `[u8; 32]` for the default accounts.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     # #[ink::environment(balance = u64)]
+///     # pub enum MyEnvironment {}
+///     #[cfg(test)]
+///     mod tests {
+///         # use ink_lang as ink;
+///         #[ink::test(env = crate::MyEnvironment)]
+///         fn it_works() {
+///             // test code using the custom environment comes here
+///         }
+///     }
+///     ```
+///
+///     **Default value:** `DefaultEnvironment` defined in `ink_env` crate.
+///
+/// ## Async Tests
+///
+/// The test function may be an `async fn`. Its body is then driven to completion
+/// by a minimal executor on the test thread, see `ink_env::test::block_on`.
+/// This allows to `.await` futures within ink! tests, e.g. of clients used by
+/// end-to-end tests, while still setting up the off-chain testing environment.
 #[proc_macro_attribute]
 pub fn test(attr: TokenStream, item: TokenStream) -> TokenStream {
     ink_test::generate(attr.into(), item.into()).into()
 }
 
+/// Defines an end-to-end test of ink! smart contracts.
+///
+/// End-to-end tests deploy contracts and call their messages through a client of
+/// a contracts enabled chain instead of calling the Rust methods of the contract.
+///
+/// The test must be an `async fn` taking the client as its only argument. The type
+/// of the client must implement `ink_lang::e2e::Client`. The client is connected
+/// before the body of the test is run and the body is driven to completion on the
+/// test thread.
+///
+/// Only available with the `e2e-tests` crate feature of `ink_lang`.
+///
+/// # Example
+///
+/// ```
+/// # use ink_lang as ink;
+/// #[cfg(test)]
+/// mod e2e_tests {
+///     # use ink_lang as ink;
+///     use ink_lang::e2e::OffChainClient;
+///
+///     #[ink::e2e_test]
+///     async fn it_works(mut client: OffChainClient) {
+///         // code instantiating and calling contracts via the client comes here
+///     }
+/// }
+/// ```
+///
+/// See the `ink_lang::e2e` module for a complete example.
+#[proc_macro_attribute]
+pub fn e2e_test(attr: TokenStream, item: TokenStream) -> TokenStream {
+    ink_e2e_test::generate(attr.into(), item.into()).into()
+}
+
 /// Defines the interface for a chain extension.
 ///
 /// # Structure
@@ -770,6 +2499,29 @@ pub fn test(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// # }
 /// ```
 ///
+/// ## Details: Extension ID
+///
+/// Chain extensions may be registered under an extension ID in the runtime.
+/// In this case the `func_id` of a chain extension method call is made up of the
+/// extension ID in its upper 16 bits and the function ID within the chain extension
+/// in its lower 16 bits.
+///
+/// The extension ID can be provided as `extension = N: u16` argument to the procedural
+/// macro. The chain extension methods are then flagged with `ink(function = N: u16)`
+/// instead of `ink(extension = N: u32)` and their `func_id` is computed automatically:
+///
+/// ```
+/// # use ink_lang as ink;
+/// #[ink::chain_extension(extension = 12)]
+/// pub trait MyChainExtension {
+///     type ErrorCode = i32;
+///
+///     /// Called with `func_id` `0x000C_0001`.
+///     #[ink(function = 1, handle_status = false, returns_result = false)]
+///     fn read(key: &[u8]) -> Vec<u8>;
+/// }
+/// ```
+///
 /// ## Details: `handle_status`
 ///
 /// Default value: `true`
@@ -1132,6 +2884,16 @@ pub fn test(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// # Metadata
+///
+/// The ink! metadata of a contract describes the chain extensions of its environment
+/// including the function IDs, input and output types as well as the error code of
+/// all chain extension methods. This allows front-ends and chains to verify upon
+/// deployment that the target runtime provides the required chain extensions.
+/// The status codes are described as well if the error code derives
+/// `ink::ChainExtensionErrorCode`. Therefore the types used by a chain extension
+/// must implement `scale_info::TypeInfo` when compiling with the `std` feature.
+///
 /// # Technical Limitations
 ///
 /// - Due to technical limitations it is not possible to refer to the `ErrorCode` associated type
@@ -1145,5 +2907,116 @@ pub fn chain_extension(attr: TokenStream, item: TokenStream) -> TokenStream {
     chain_extension::generate(attr.into(), item.into()).into()
 }
 
+/// Derives the `ink_env::chain_extension::FromStatusCode` implementation of a
+/// chain extension error code enum.
+///
+/// The status code `0` always signals success. Every other status code is mapped to
+/// the variant flagged with the respective `#[ink(status_code = N: u32)]` attribute.
+///
+/// Status codes without a dedicated variant are mapped to the variant flagged with
+/// `#[ink(catch_all)]` which is required so that unknown status codes can be handled
+/// by the caller. The catch-all variant may optionally carry the unmapped status code
+/// as a single unnamed `u32` field.
+///
+/// The mapping of status codes to variants is also recorded in the ink! metadata of
+/// contracts using a chain extension with this error code.
+///
+/// # Example
+///
+/// ```
+/// # use ink_lang as ink;
+/// # use ink_env::chain_extension::FromStatusCode;
+/// #[derive(Debug, PartialEq, Eq, ink::ChainExtensionErrorCode)]
+/// pub enum ReadWriteErrorCode {
+///     #[ink(status_code = 1)]
+///     InvalidKey,
+///     #[ink(status_code = 2)]
+///     CannotWriteToKey,
+///     #[ink(catch_all)]
+///     Unknown(u32),
+/// }
+///
+/// assert_eq!(ReadWriteErrorCode::from_status_code(0), Ok(()));
+/// assert_eq!(
+///     ReadWriteErrorCode::from_status_code(2),
+///     Err(ReadWriteErrorCode::CannotWriteToKey),
+/// );
+/// assert_eq!(
+///     ReadWriteErrorCode::from_status_code(42),
+///     Err(ReadWriteErrorCode::Unknown(42)),
+/// );
+/// ```
+///
+/// # Errors
+///
+/// - If the enum is generic.
+/// - If a variant is flagged with neither `#[ink(status_code = N: u32)]` nor `#[ink(catch_all)]`.
+/// - If a variant is flagged with the reserved status code `0`.
+/// - If multiple variants are flagged with the same status code.
+/// - If a variant with a status code has fields.
+/// - If there is no catch-all variant or if there are multiple catch-all variants.
+#[proc_macro_derive(ChainExtensionErrorCode, attributes(ink))]
+pub fn chain_extension_error_code(input: TokenStream) -> TokenStream {
+    chain_extension::generate_error_code(input.into()).into()
+}
+
+/// Derives the SCALE codec, `scale_info::TypeInfo` and `ink_lang::ContractError`
+/// implementations of a contract error enum with stable error codes.
+///
+/// Every variant must be flagged with `#[ink(error_code = N: u8)]`. The error code is
+/// the SCALE encoded index of the variant and therefore the first byte of the output
+/// of a message that reverted with the error. Since the error codes are independent of
+/// the order of the variants they stay stable as the contract error evolves.
+///
+/// The error codes are recorded as the variant indices of the contract error type in
+/// the metadata of the contract which allows clients to map the output of reverted
+/// calls back to the respective variant.
+///
+/// Up to one unit variant may additionally be flagged with `#[ink(env_error)]` in order
+/// to derive `From<ink_env::Error>` and `From<ink_lang::LangError>` for the contract
+/// error. Both conversions map to that variant and discard the original error which
+/// allows to use the `?` operator on the results of environmental functions and
+/// cross-contract calls.
+///
+/// # Example
+///
+/// ```
+/// # use ink_lang as ink;
+/// use ink::ContractError as _;
+/// use scale::{Decode as _, Encode as _};
+///
+/// #[derive(Debug, PartialEq, Eq, ink::ContractError)]
+/// pub enum Error {
+///     /// The balance of the caller is insufficient.
+///     #[ink(error_code = 1)]
+///     InsufficientBalance { required: u128 },
+///     /// The caller is not the owner of the contract.
+///     #[ink(error_code = 2)]
+///     NotOwner,
+///     /// An environmental function or cross-contract call failed.
+///     #[ink(error_code = 255, env_error)]
+///     Env,
+/// }
+///
+/// let error = Error::InsufficientBalance { required: 42 };
+/// assert_eq!(error.error_code(), 1);
+/// assert_eq!(error.encode(), (1u8, 42u128).encode());
+/// assert_eq!(Error::decode(&mut &[2u8][..]), Ok(Error::NotOwner));
+/// assert_eq!(Error::error_name(255), Some("Env"));
+/// assert_eq!(Error::from(ink_env::Error::CalleeReverted), Error::Env);
+/// ```
+///
+/// # Errors
+///
+/// - If the enum is generic.
+/// - If a variant is not flagged with `#[ink(error_code = N: u8)]`.
+/// - If multiple variants are flagged with the same error code.
+/// - If multiple variants are flagged with `#[ink(env_error)]`.
+/// - If the variant flagged with `#[ink(env_error)]` has fields.
+#[proc_macro_derive(ContractError, attributes(ink))]
+pub fn contract_error(input: TokenStream) -> TokenStream {
+    contract_error::generate(input.into()).into()
+}
+
 #[cfg(test)]
 pub use contract::generate_or_err;
@@ -0,0 +1,158 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Authorization utilities backing the `#[ink::ownable]` and `#[ink::access_control]`
+//! mixins.
+//!
+//! The owner and the granted roles of an ink! smart contract are kept in dedicated
+//! contract storage cells outside of its storage struct under reserved keys, see
+//! `reserved_key!`. Therefore the mixins do not need to alter the storage struct of
+//! the ink! smart contract. They prepend statements to all of its constructors
+//! though, which make the instantiator the owner or grant it the admin role.
+
+use ink_env::{
+    hash::{
+        Blake2x256,
+        HashOutput,
+    },
+    Environment,
+};
+use ink_primitives::Key;
+
+/// The reserved storage key of the owner of an ink! smart contract.
+const OWNER_KEY: Key = reserved_key!("ink_lang::ownable::owner");
+
+/// The prefix of the storage keys of the granted roles of an ink! smart contract.
+///
+/// The keys are derived from the prefix like reserved keys, see `reserved_key!`.
+const ROLE_KEY_PREFIX: &[u8] = b"ink_lang::access_control::role";
+
+/// The type of the roles of an ink! smart contract using `#[ink::access_control]`.
+pub type RoleType = u32;
+
+/// The role that is allowed to grant and revoke all roles.
+///
+/// This role is granted to the instantiator of the ink! smart contract.
+pub const ADMIN_ROLE: RoleType = 0;
+
+/// Errors returned by the messages and guards of `#[ink::ownable]`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum OwnableError {
+    /// The caller is not the owner of the ink! smart contract.
+    CallerIsNotOwner,
+}
+
+/// Errors returned by the messages and guards of `#[ink::access_control]`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum AccessControlError {
+    /// The caller is missing the required role.
+    MissingRole,
+}
+
+/// Returns the owner of the ink! smart contract if any.
+pub fn owner<E>() -> Option<E::AccountId>
+where
+    E: Environment,
+{
+    ink_env::get_contract_storage::<E::AccountId>(&OWNER_KEY)
+        .expect("could not properly decode the contract owner")
+}
+
+/// Sets the owner of the ink! smart contract.
+///
+/// Passing `None` renounces the ownership for good.
+pub fn set_owner<E>(owner: Option<&E::AccountId>)
+where
+    E: Environment,
+{
+    match owner {
+        Some(owner) => ink_env::set_contract_storage(&OWNER_KEY, owner),
+        None => ink_env::clear_contract_storage(&OWNER_KEY),
+    }
+}
+
+/// Returns `true` if `account` has been granted `role`.
+pub fn has_role<E>(role: RoleType, account: &E::AccountId) -> bool
+where
+    E: Environment,
+{
+    ink_env::get_contract_storage::<()>(&role_key::<E>(role, account))
+        .ok()
+        .flatten()
+        .is_some()
+}
+
+/// Grants `role` to `account` if `granted` is `true` or revokes it otherwise.
+pub fn set_role<E>(role: RoleType, account: &E::AccountId, granted: bool)
+where
+    E: Environment,
+{
+    let key = role_key::<E>(role, account);
+    if granted {
+        ink_env::set_contract_storage(&key, &())
+    } else {
+        ink_env::clear_contract_storage(&key)
+    }
+}
+
+/// Returns the storage key under which the grant of `role` to `account` is kept.
+fn role_key<E>(role: RoleType, account: &E::AccountId) -> Key
+where
+    E: Environment,
+{
+    let mut output = <Blake2x256 as HashOutput>::Type::default();
+    ink_env::hash_encoded::<Blake2x256, _>(
+        &(ROLE_KEY_PREFIX, role, account),
+        &mut output,
+    );
+    Key::new(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ink_env::DefaultEnvironment;
+
+    #[test]
+    fn owner_works() {
+        ink_env::test::run_test::<DefaultEnvironment, _>(|accounts| {
+            assert_eq!(owner::<DefaultEnvironment>(), None);
+            set_owner::<DefaultEnvironment>(Some(&accounts.alice));
+            assert_eq!(owner::<DefaultEnvironment>(), Some(accounts.alice));
+            set_owner::<DefaultEnvironment>(None);
+            assert_eq!(owner::<DefaultEnvironment>(), None);
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn roles_work() {
+        ink_env::test::run_test::<DefaultEnvironment, _>(|accounts| {
+            assert!(!has_role::<DefaultEnvironment>(ADMIN_ROLE, &accounts.alice));
+            set_role::<DefaultEnvironment>(ADMIN_ROLE, &accounts.alice, true);
+            set_role::<DefaultEnvironment>(1, &accounts.bob, true);
+            assert!(has_role::<DefaultEnvironment>(ADMIN_ROLE, &accounts.alice));
+            assert!(!has_role::<DefaultEnvironment>(1, &accounts.alice));
+            assert!(has_role::<DefaultEnvironment>(1, &accounts.bob));
+            assert!(!has_role::<DefaultEnvironment>(ADMIN_ROLE, &accounts.bob));
+            set_role::<DefaultEnvironment>(ADMIN_ROLE, &accounts.alice, false);
+            assert!(!has_role::<DefaultEnvironment>(ADMIN_ROLE, &accounts.alice));
+            Ok(())
+        })
+        .unwrap()
+    }
+}
@@ -0,0 +1,357 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Benchmarking of the weights of ink! smart contract messages.
+//!
+//! A [`Benchmark`] calls every message of an ink! smart contract in the
+//! off-chain environment with the inputs supplied for it and reports the
+//! heaviest call per message:
+//!
+//! - `ref_time` is the gas consumed by the call according to the
+//!   [`GasSchedule`][`ink_env::test::GasSchedule`] of the off-chain environment,
+//!   including the base cost of the call itself.
+//! - `proof_size` approximates the storage proof of the call by the size of the
+//!   keys and values of all distinct storage cells read by it.
+//!
+//! Every call starts from the state right after the contract has been created,
+//! so the reported weights do not depend on the order of the calls. Supply inputs
+//! that drive the messages into their most expensive paths in order to obtain
+//! worst-case weights that can be published and tracked for regressions.
+//!
+//! The off-chain environment only approximates the weights of a real chain.
+//! The numbers are meant to compare messages and versions of a contract.
+//!
+//! Only available with the `ink-bench` crate feature.
+//!
+//! # Example
+//!
+//! ```
+//! use ink_lang as ink;
+//!
+//! #[ink::contract]
+//! mod counter {
+//!     #[ink(storage)]
+//!     pub struct Counter {
+//!         value: u32,
+//!     }
+//!
+//!     impl Counter {
+//!         #[ink(constructor)]
+//!         pub fn new() -> Self {
+//!             Self { value: 0 }
+//!         }
+//!
+//!         #[ink(message)]
+//!         pub fn inc(&mut self, by: u32) {
+//!             self.value += by;
+//!         }
+//!
+//!         #[ink(message)]
+//!         pub fn get(&self) -> u32 {
+//!             self.value
+//!         }
+//!     }
+//! }
+//!
+//! use counter::Counter;
+//! use ink_lang::bench::Benchmark;
+//!
+//! # ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+//! let table = Benchmark::new(Counter::new)
+//!     .inputs(ink::selector_bytes!("inc"), [scale::Encode::encode(&1u32)])
+//!     .run();
+//! println!("{}", table);
+//!
+//! let inc = table.get(ink::selector_bytes!("inc")).unwrap();
+//! let get = table.get(ink::selector_bytes!("get")).unwrap();
+//! assert_eq!(inc.samples, 1);
+//! assert!(inc.ref_time > get.ref_time);
+//! assert!(get.proof_size > 0);
+//! # Ok(())
+//! # }).unwrap();
+//! ```
+
+use crate::{
+    codegen::{
+        register_contract,
+        ContractRootKey,
+    },
+    reflect::{
+        ContractConstructorDecoder,
+        ContractDispatchableMessages,
+        ContractEnv,
+        ContractMessageDecoder,
+    },
+};
+use core::fmt;
+use ink_env::{
+    call::{
+        build_call,
+        Call,
+        ExecutionInput,
+        Selector,
+    },
+    test::EnvCall,
+    Environment,
+};
+use ink_primitives::Key;
+use ink_storage::traits::{
+    push_spread_root,
+    SpreadLayout,
+};
+use std::collections::{
+    BTreeSet,
+    HashMap,
+};
+
+/// The code hash under which the benchmarked contract is registered.
+const CODE_HASH: [u8; 32] = [0xB0; 32];
+
+/// The account of the benchmarked contract.
+const CONTRACT_ACCOUNT: [u8; 32] = [0xB1; 32];
+
+/// The environment of the contract `C`.
+type EnvOf<C> = <C as ContractEnv>::Env;
+
+/// The account ID type of the contract `C`.
+type AccountIdOf<C> = <EnvOf<C> as Environment>::AccountId;
+
+/// The benchmarked weight of a message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageWeight {
+    /// The selector of the message.
+    pub selector: [u8; 4],
+    /// The maximum gas consumed by a call of the message.
+    pub ref_time: u64,
+    /// The maximum size of the storage read by a call of the message.
+    pub proof_size: u64,
+    /// The amount of calls of the message that neither trapped nor reverted.
+    ///
+    /// The weights only account for these calls.
+    pub samples: usize,
+}
+
+/// The benchmarked weights of all messages of a contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeightTable {
+    /// The weights in the order of the messages of the contract.
+    weights: Vec<MessageWeight>,
+}
+
+impl WeightTable {
+    /// Returns the weight of the message with the given `selector`.
+    pub fn get(&self, selector: [u8; 4]) -> Option<&MessageWeight> {
+        self.weights
+            .iter()
+            .find(|weight| weight.selector == selector)
+    }
+
+    /// Returns the weights of all messages.
+    pub fn iter(&self) -> impl Iterator<Item = &MessageWeight> {
+        self.weights.iter()
+    }
+}
+
+impl fmt::Display for WeightTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<10} {:>20} {:>12} {:>8}",
+            "selector", "ref_time", "proof_size", "samples"
+        )?;
+        for weight in &self.weights {
+            writeln!(
+                f,
+                "0x{:<8} {:>20} {:>12} {:>8}",
+                hex(&weight.selector),
+                weight.ref_time,
+                weight.proof_size,
+                weight.samples
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the lower case hex representation of `bytes`.
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// The arguments of a message passed to the contract as they are.
+struct RawInput<'a>(&'a [u8]);
+
+impl scale::Encode for RawInput<'_> {
+    fn size_hint(&self) -> usize {
+        self.0.len()
+    }
+
+    fn encode_to<T: scale::Output + ?Sized>(&self, dest: &mut T) {
+        dest.write(self.0)
+    }
+}
+
+/// Measures the weights of the messages of an ink! smart contract.
+///
+/// See the [module documentation](self) for an example.
+pub struct Benchmark<Contract> {
+    /// Creates the contract that is benchmarked.
+    constructor: fn() -> Contract,
+    /// The SCALE encoded arguments each message is called with.
+    inputs: HashMap<[u8; 4], Vec<Vec<u8>>>,
+}
+
+impl<Contract> Benchmark<Contract>
+where
+    Contract: ContractEnv
+        + ContractRootKey
+        + SpreadLayout
+        + ContractConstructorDecoder
+        + ContractMessageDecoder,
+    AccountIdOf<Contract>: From<[u8; 32]>,
+    <EnvOf<Contract> as Environment>::Hash: From<[u8; 32]>,
+{
+    /// Creates a benchmark for the contract created by `constructor`.
+    pub fn new(constructor: fn() -> Contract) -> Self {
+        Self {
+            constructor,
+            inputs: HashMap::new(),
+        }
+    }
+
+    /// Adds SCALE encoded arguments the message with the given `selector` is called with.
+    ///
+    /// Messages without inputs are called once without arguments.
+    pub fn inputs<I>(mut self, selector: [u8; 4], inputs: I) -> Self
+    where
+        I: IntoIterator<Item = Vec<u8>>,
+    {
+        self.inputs.entry(selector).or_default().extend(inputs);
+        self
+    }
+
+    /// Creates the contract and measures the weights of all of its messages.
+    #[allow(deprecated)]
+    pub fn run<const MESSAGES: usize>(&self) -> WeightTable
+    where
+        Contract: ContractDispatchableMessages<MESSAGES>,
+    {
+        let caller_account = ink_env::test::callee::<EnvOf<Contract>>();
+        let contract_account = AccountIdOf::<Contract>::from(CONTRACT_ACCOUNT);
+        register_contract::<Contract>(CODE_HASH.into());
+        ink_env::test::set_code_hash::<EnvOf<Contract>>(
+            contract_account.clone(),
+            CODE_HASH.into(),
+        );
+        ink_env::test::set_callee::<EnvOf<Contract>>(contract_account.clone());
+        let contract = (self.constructor)();
+        push_spread_root::<Contract>(&contract, &<Contract as ContractRootKey>::ROOT_KEY);
+        ink_env::test::set_callee::<EnvOf<Contract>>(caller_account);
+        let initial = ink_env::test::snapshot();
+
+        let no_arguments = [Vec::new()];
+        let weights = <Contract as ContractDispatchableMessages<MESSAGES>>::IDS
+            .iter()
+            .map(|id| {
+                let selector = id.to_be_bytes();
+                let inputs = self
+                    .inputs
+                    .get(&selector)
+                    .map(|inputs| &inputs[..])
+                    .unwrap_or(&no_arguments[..]);
+                let mut weight = MessageWeight {
+                    selector,
+                    ref_time: 0,
+                    proof_size: 0,
+                    samples: 0,
+                };
+                for input in inputs {
+                    if let Some((ref_time, proof_size)) =
+                        self.measure(&contract_account, selector, input)
+                    {
+                        weight.ref_time = weight.ref_time.max(ref_time);
+                        weight.proof_size = weight.proof_size.max(proof_size);
+                        weight.samples += 1;
+                    }
+                    ink_env::test::restore(&initial);
+                }
+                weight
+            })
+            .collect();
+        WeightTable { weights }
+    }
+
+    /// Calls the message with the given `selector` and `input`.
+    ///
+    /// Returns the consumed gas and the size of the read storage or `None` if the
+    /// call trapped or reverted.
+    fn measure(
+        &self,
+        contract_account: &AccountIdOf<Contract>,
+        selector: [u8; 4],
+        input: &[u8],
+    ) -> Option<(u64, u64)> {
+        let before = ink_env::test::snapshot();
+        ink_env::test::start_call_trace();
+        let gas_before = ink_env::test::gas_consumed();
+        let result = build_call::<EnvOf<Contract>>()
+            .call_type(Call::new().callee(contract_account.clone()))
+            .exec_input(
+                ExecutionInput::new(Selector::new(selector)).push_arg(RawInput(input)),
+            )
+            .returns::<()>()
+            .fire();
+        let ref_time = ink_env::test::gas_consumed().saturating_sub(gas_before);
+        let trace = ink_env::test::stop_call_trace();
+        result.ok()?;
+
+        // The cells are measured as they were before the call.
+        ink_env::test::restore(&before);
+        let read_cells = trace
+            .into_iter()
+            .filter_map(|call| {
+                match call {
+                    EnvCall::GetStorage { contract, key } => Some((contract, key)),
+                    _ => None,
+                }
+            })
+            .collect::<BTreeSet<_>>();
+        let proof_size = read_cells
+            .into_iter()
+            .map(|(contract, key)| {
+                let value_len = cell_len::<EnvOf<Contract>>(&contract, &key);
+                (key.len() + value_len) as u64
+            })
+            .sum();
+        Some((ref_time, proof_size))
+    }
+}
+
+/// Returns the length of the value stored under the SCALE encoded `key` of the
+/// SCALE encoded `contract` account.
+fn cell_len<E>(contract: &[u8], key: &[u8]) -> usize
+where
+    E: Environment,
+{
+    let account_id = <E::AccountId as scale::Decode>::decode(&mut &contract[..]);
+    let key = <[u8; 32]>::try_from(key);
+    match (account_id, key) {
+        (Ok(account_id), Ok(key)) => {
+            ink_env::test::get_contract_storage_raw::<E>(&account_id, &Key::from(key))
+                .map(|value| value.len())
+                .unwrap_or_default()
+        }
+        _ => 0,
+    }
+}
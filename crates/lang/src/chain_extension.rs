@@ -19,6 +19,84 @@
 /// # Note
 ///
 /// This trait is automatically implemented when using `#[ink::chain_extension]` procedural macro.
+///
+/// # Combining Chain Extensions
+///
+/// The trait is also implemented for tuples of up to 6 chain extensions which allows
+/// a contract to use several chain extensions at once. The instance of such a tuple is
+/// the tuple of the instances of its elements. Combined chain extensions that share a
+/// function ID fail to compile once they are instantiated, e.g. by a contract calling
+/// `self.env().extension()`. Merely defining such a combination is not an error.
+///
+/// ```
+/// # use ink_lang as ink;
+/// # use ink_env::Environment;
+/// #[ink::chain_extension]
+/// pub trait Random {
+///     type ErrorCode = ExtensionError;
+///
+///     #[ink(extension = 1, returns_result = false, handle_status = false)]
+///     fn random(subject: [u8; 32]) -> [u8; 32];
+/// }
+///
+/// #[ink::chain_extension]
+/// pub trait Time {
+///     type ErrorCode = ExtensionError;
+///
+///     #[ink(extension = 2, returns_result = false, handle_status = false)]
+///     fn now() -> u64;
+/// }
+///
+/// #[derive(scale::Encode, scale::Decode, scale_info::TypeInfo)]
+/// pub struct ExtensionError;
+///
+/// impl ink_env::chain_extension::FromStatusCode for ExtensionError {
+///     fn from_status_code(status_code: u32) -> Result<(), Self> {
+///         match status_code {
+///             0 => Ok(()),
+///             _ => Err(Self),
+///         }
+///     }
+/// }
+///
+/// pub enum CustomEnvironment {}
+///
+/// impl Environment for CustomEnvironment {
+///     const MAX_EVENT_TOPICS: usize =
+///         <ink_env::DefaultEnvironment as Environment>::MAX_EVENT_TOPICS;
+///
+///     type AccountId = <ink_env::DefaultEnvironment as Environment>::AccountId;
+///     type Balance = <ink_env::DefaultEnvironment as Environment>::Balance;
+///     type Hash = <ink_env::DefaultEnvironment as Environment>::Hash;
+///     type BlockNumber = <ink_env::DefaultEnvironment as Environment>::BlockNumber;
+///     type Timestamp = <ink_env::DefaultEnvironment as Environment>::Timestamp;
+///
+///     type ChainExtension = (Random, Time);
+/// }
+///
+/// #[ink::contract(env = crate::CustomEnvironment)]
+/// mod my_contract {
+///     #[ink(storage)]
+///     pub struct MyContract {}
+///
+///     impl MyContract {
+///         #[ink(constructor)]
+///         pub fn new() -> Self {
+///             Self {}
+///         }
+///
+///         #[ink(message)]
+///         pub fn timed_random(&self) -> [u8; 32] {
+///             let (random, time) = self.env().extension();
+///             let mut subject = [0x00; 32];
+///             subject[..8].copy_from_slice(&time.now().to_le_bytes());
+///             random.random(subject)
+///         }
+///     }
+/// }
+/// #
+/// # fn main() {}
+/// ```
 pub trait ChainExtensionInstance {
     /// The type of the chain extension instance.
     type Instance;
@@ -27,14 +105,83 @@ pub trait ChainExtensionInstance {
     fn instantiate() -> Self::Instance;
 }
 
-/// Implemented by chain extension types.
+/// Provides the function IDs of all methods of a chain extension.
 ///
-/// Every chain extension defines a set of chain extension methods
-/// that share a common error code type.
-pub trait ChainExtension {
-    /// The error code that determines whether a chain extension method call was successful.
-    type ErrorCode: ink_env::chain_extension::FromStatusCode;
+/// # Note
+///
+/// This trait is automatically implemented when using `#[ink::chain_extension]` procedural macro.
+/// It is used to ensure that combined chain extensions have disjoint function IDs.
+pub trait ChainExtensionFuncIds {
+    /// The function IDs of all chain extension methods.
+    const FUNC_IDS: &'static [u32];
+}
+
+/// Returns `true` if no two of the given sets of function IDs share a function ID.
+const fn func_ids_are_disjoint(sets: &[&[u32]]) -> bool {
+    let mut i = 0;
+    while i < sets.len() {
+        let mut j = i + 1;
+        while j < sets.len() {
+            let mut x = 0;
+            while x < sets[i].len() {
+                let mut y = 0;
+                while y < sets[j].len() {
+                    if sets[i][x] == sets[j][y] {
+                        return false
+                    }
+                    y += 1;
+                }
+                x += 1;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Asserts at compile time that the combined chain extensions have disjoint function IDs.
+///
+/// # Note
+///
+/// The assertion is only evaluated upon the instantiation of the combined chain
+/// extensions since associated constants of generic implementations are evaluated
+/// lazily.
+trait AssertDisjointFuncIds {
+    const ASSERT: ();
+}
+
+macro_rules! impl_chain_extension_instance_for_tuple {
+    ( $( $ty:ident ),* ) => {
+        impl<$( $ty ),*> AssertDisjointFuncIds for ( $( $ty, )* )
+        where
+            $( $ty: ChainExtensionFuncIds, )*
+        {
+            const ASSERT: () = assert!(
+                func_ids_are_disjoint(&[ $( <$ty as ChainExtensionFuncIds>::FUNC_IDS ),* ]),
+                "encountered combined chain extensions with overlapping function IDs",
+            );
+        }
+
+        impl<$( $ty ),*> ChainExtensionInstance for ( $( $ty, )* )
+        where
+            $( $ty: ChainExtensionFuncIds + ChainExtensionInstance, )*
+        {
+            type Instance = ( $( <$ty as ChainExtensionInstance>::Instance, )* );
+
+            fn instantiate() -> Self::Instance {
+                #[allow(clippy::let_unit_value)]
+                let _ = <Self as AssertDisjointFuncIds>::ASSERT;
+                ( $( <$ty as ChainExtensionInstance>::instantiate(), )* )
+            }
+        }
+    };
 }
+impl_chain_extension_instance_for_tuple!(A, B);
+impl_chain_extension_instance_for_tuple!(A, B, C);
+impl_chain_extension_instance_for_tuple!(A, B, C, D);
+impl_chain_extension_instance_for_tuple!(A, B, C, D, E);
+impl_chain_extension_instance_for_tuple!(A, B, C, D, E, F);
 
 /// Only implemented for `Result<T, E>`.
 ///
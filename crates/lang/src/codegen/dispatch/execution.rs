@@ -13,8 +13,11 @@
 // limitations under the License.
 
 use crate::reflect::{
+    ContractConstructorDecoder,
     ContractEnv,
+    ContractMessageDecoder,
     DispatchError,
+    ExecuteDispatchable,
 };
 use core::{
     convert::Infallible,
@@ -27,6 +30,8 @@ use ink_env::{
 use ink_primitives::{
     Key,
     KeyPtr,
+    LangError,
+    MessageResult,
 };
 use ink_storage::traits::{
     push_spread_root,
@@ -64,6 +69,95 @@ where
     Ok(())
 }
 
+/// Decodes the input and executes the selected ink! constructor of the contract.
+///
+/// # Note
+///
+/// This mirrors the `deploy` entry point generated for the ink! smart contract.
+///
+/// # Panics
+///
+/// If the input cannot be decoded or the dispatch of the ink! constructor failed.
+pub fn execute_deploy<Contract>()
+where
+    Contract: ContractConstructorDecoder,
+{
+    ink_env::decode_input::<<Contract as ContractConstructorDecoder>::Type>()
+        .map_err(|_| DispatchError::CouldNotReadInput)
+        .and_then(ExecuteDispatchable::execute_dispatchable)
+        .unwrap_or_else(|error| {
+            ::core::panic!("dispatching ink! constructor failed: {}", error)
+        })
+}
+
+/// Decodes the input and executes the selected ink! message of the contract.
+///
+/// # Note
+///
+/// This mirrors the `call` entry point generated for the ink! smart contract.
+///
+/// # Panics
+///
+/// If the dispatch of the ink! message failed for other reasons than invalid input.
+pub fn execute_call<Contract>()
+where
+    Contract: ContractMessageDecoder,
+{
+    ink_env::decode_input::<<Contract as ContractMessageDecoder>::Type>()
+        .map_err(|_| DispatchError::CouldNotReadInput)
+        .and_then(ExecuteDispatchable::execute_dispatchable)
+        .unwrap_or_else(|error| return_dispatch_error(error))
+}
+
+/// Returns the failed dispatch of an ink! message to its caller.
+///
+/// # Note
+///
+/// If the input of the ink! message could not be decoded this reverts the call
+/// and returns [`LangError::CouldNotReadInput`] as the error of the
+/// [`MessageResult`] envelope, so that callers can tell it apart from the
+/// output of the ink! message.
+///
+/// # Panics
+///
+/// If the dispatch failed for another reason, e.g. because an unpayable
+/// ink! message was paid or a non-reentrant ink! message was reentered.
+/// This traps the contract execution.
+pub fn return_dispatch_error(error: DispatchError) -> ! {
+    match error {
+        DispatchError::InvalidSelector
+        | DispatchError::UnknownSelector
+        | DispatchError::InvalidParameters
+        | DispatchError::CouldNotReadInput => {
+            ink_env::return_value::<MessageResult<()>>(
+                ReturnFlags::default().set_reverted(true),
+                &Err(LangError::CouldNotReadInput),
+            )
+        }
+        DispatchError::PaidUnpayableMessage | DispatchError::ReentrantCall => {
+            ::core::panic!("dispatching ink! message failed: {}", error)
+        }
+    }
+}
+
+/// Registers the code of the ink! smart contract under `code_hash` in the
+/// off-chain environment.
+///
+/// Afterwards instances of the contract can be created and called via the
+/// usual cross-contract calling facilities within off-chain tests.
+#[cfg(feature = "std")]
+pub fn register_contract<Contract>(
+    code_hash: <<Contract as ContractEnv>::Env as Environment>::Hash,
+) where
+    Contract: ContractEnv + ContractConstructorDecoder + ContractMessageDecoder,
+{
+    ink_env::test::register_contract::<<Contract as ContractEnv>::Env>(
+        code_hash,
+        execute_deploy::<Contract>,
+        execute_call::<Contract>,
+    )
+}
+
 /// Configuration for execution of ink! constructor.
 #[derive(Debug, Copy, Clone)]
 pub struct ExecuteConstructorConfig {
@@ -104,12 +198,16 @@ where
         Err(_) => {
             // Constructor is fallible and failed.
             //
-            // We need to revert the state of the transaction.
+            // We need to revert the state of the transaction and hand the
+            // error back to the instantiator as `Result::<(), E>::Err`.
             ink_env::return_value::<
-                <private::Seal<R> as ConstructorReturnType<Contract>>::ReturnValue,
+                Result<
+                    (),
+                    &<private::Seal<R> as ConstructorReturnType<Contract>>::ReturnValue,
+                >,
             >(
                 ReturnFlags::default().set_reverted(true),
-                result.return_value(),
+                &Err(result.return_value()),
             )
         }
     }
@@ -160,10 +258,10 @@ mod private {
 ///
 /// # Note
 ///
-/// Currently the only allowed types are `()` and `Result<(), E>`
+/// Currently the only allowed types are `C` and `Result<C, E>`
 /// where `E` is some unspecified error type.
-/// If the contract initializer returns `Result::Err` the utility
-/// method that is used to initialize an ink! smart contract will
+/// If the constructor returns `Result::Err` the utility
+/// method that is used to execute an ink! constructor will
 /// revert the state of the contract instantiation.
 pub trait ConstructorReturnType<C>: private::Sealed {
     /// Is `true` if `Self` is `Result<C, E>`.
@@ -176,12 +274,12 @@ pub trait ConstructorReturnType<C>: private::Sealed {
     /// For infallible constructors this is `core::convert::Infallible`.
     type Error;
 
-    /// The type of the return value of the constructor.
+    /// The type of the value returned by a failed constructor.
     ///
     /// # Note
     ///
     /// For infallible constructors this is `()` whereas for fallible
-    /// constructors this is the actual return value. Since we only ever
+    /// constructors this is the error type `E`. Since we only ever
     /// return a value in case of `Result::Err` the `Result::Ok` value
     /// does not matter.
     type ReturnValue;
@@ -193,7 +291,7 @@ pub trait ConstructorReturnType<C>: private::Sealed {
     /// For infallible constructor returns this always yields `Ok`.
     fn as_result(&self) -> Result<&C, &Self::Error>;
 
-    /// Returns the actual return value of the constructor.
+    /// Returns the error value of the failed constructor.
     ///
     /// # Note
     ///
@@ -221,7 +319,7 @@ impl<C> ConstructorReturnType<C> for private::Seal<C> {
 impl<C, E> ConstructorReturnType<C> for private::Seal<Result<C, E>> {
     const IS_RESULT: bool = true;
     type Error = E;
-    type ReturnValue = Result<C, E>;
+    type ReturnValue = E;
 
     #[inline]
     fn as_result(&self) -> Result<&C, &Self::Error> {
@@ -230,7 +328,10 @@ impl<C, E> ConstructorReturnType<C> for private::Seal<Result<C, E>> {
 
     #[inline]
     fn return_value(&self) -> &Self::ReturnValue {
-        &self.0
+        match &self.0 {
+            Err(error) => error,
+            Ok(_) => unreachable!("encountered return value of a succeeded constructor"),
+        }
     }
 }
 
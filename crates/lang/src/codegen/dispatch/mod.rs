@@ -14,17 +14,32 @@
 
 mod execution;
 mod info;
+mod reentrancy;
+mod selector_table;
 mod type_check;
 
+#[cfg(feature = "std")]
+pub use self::execution::register_contract;
 pub use self::{
     execution::{
         deny_payment,
+        execute_call,
         execute_constructor,
+        execute_deploy,
         initialize_contract,
+        return_dispatch_error,
         ContractRootKey,
         ExecuteConstructorConfig,
     },
     info::ContractCallBuilder,
+    reentrancy::{
+        acquire_reentrancy_lock,
+        release_reentrancy_lock,
+    },
+    selector_table::{
+        lookup_selector,
+        sort_selectors,
+    },
     type_check::{
         DispatchInput,
         DispatchOutput,
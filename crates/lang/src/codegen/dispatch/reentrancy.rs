@@ -0,0 +1,72 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::reflect::DispatchError;
+use ink_primitives::Key;
+
+/// The reserved storage key of the reentrancy lock of an ink! smart contract.
+const REENTRANCY_LOCK_KEY: Key = reserved_key!("ink_lang::reentrancy_lock");
+
+/// Acquires the reentrancy lock for a non-reentrant ink! message.
+///
+/// # Note
+///
+/// The lock is kept in the contract storage so that it is observed by calls
+/// that reenter the contract while the ink! message is executing.
+///
+/// # Errors
+///
+/// If the lock is already held by a non-reentrant ink! message further up
+/// the call stack.
+#[inline]
+pub fn acquire_reentrancy_lock() -> Result<(), DispatchError> {
+    if ink_env::get_contract_storage::<()>(&REENTRANCY_LOCK_KEY)
+        .ok()
+        .flatten()
+        .is_some()
+    {
+        return Err(DispatchError::ReentrantCall)
+    }
+    ink_env::set_contract_storage(&REENTRANCY_LOCK_KEY, &());
+    Ok(())
+}
+
+/// Releases the reentrancy lock acquired by [`acquire_reentrancy_lock`].
+///
+/// # Note
+///
+/// This must be called before the ink! message returns its output since
+/// returning from the contract execution does not run any destructors.
+#[inline]
+pub fn release_reentrancy_lock() {
+    ink_env::clear_contract_storage(&REENTRANCY_LOCK_KEY)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ink_env::DefaultEnvironment;
+
+    #[test]
+    fn lock_denies_reentrant_calls_until_released() {
+        ink_env::test::run_test::<DefaultEnvironment, _>(|_| {
+            assert_eq!(acquire_reentrancy_lock(), Ok(()));
+            assert_eq!(acquire_reentrancy_lock(), Err(DispatchError::ReentrantCall));
+            release_reentrancy_lock();
+            assert_eq!(acquire_reentrancy_lock(), Ok(()));
+            Ok(())
+        })
+        .unwrap()
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Sorts the given selector IDs of dispatchable ink! messages or constructors.
+///
+/// Returns a table that maps each selector ID to the index of its dispatchable
+/// sorted by selector ID so that dispatch can look up selectors via binary search.
+///
+/// # Note
+///
+/// This is evaluated at compile time so that the sorted table is embedded into
+/// the ink! smart contract as static data.
+pub const fn sort_selectors<const N: usize>(ids: [u32; N]) -> [(u32, usize); N] {
+    let mut table = [(0, 0); N];
+    let mut i = 0;
+    while i < N {
+        table[i] = (ids[i], i);
+        i += 1;
+    }
+    let mut i = 1;
+    while i < N {
+        let mut j = i;
+        while j > 0 && table[j - 1].0 > table[j].0 {
+            let swapped = table[j - 1];
+            table[j - 1] = table[j];
+            table[j] = swapped;
+            j -= 1;
+        }
+        i += 1;
+    }
+    table
+}
+
+/// Returns the index of the dispatchable with the given selector if any.
+///
+/// The table must be sorted by [`sort_selectors`].
+#[inline]
+pub fn lookup_selector(table: &[(u32, usize)], selector: [u8; 4]) -> Option<usize> {
+    let id = u32::from_be_bytes(selector);
+    table
+        .binary_search_by_key(&id, |&(id, _)| id)
+        .ok()
+        .map(|position| table[position].1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sort_selectors_works() {
+        const TABLE: [(u32, usize); 5] = sort_selectors([
+            0xCAFE_BABE,
+            0x0000_0001,
+            0xDEAD_BEEF,
+            0x0000_0000,
+            0x8000_0000,
+        ]);
+        assert_eq!(
+            TABLE,
+            [
+                (0x0000_0000, 3),
+                (0x0000_0001, 1),
+                (0x8000_0000, 4),
+                (0xCAFE_BABE, 0),
+                (0xDEAD_BEEF, 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn lookup_selector_works() {
+        let ids = [
+            0xCAFE_BABE,
+            0x0000_0001,
+            0xDEAD_BEEF,
+            0x0000_0000,
+            0x8000_0000,
+        ];
+        let table = sort_selectors(ids);
+        for (index, id) in ids.iter().enumerate() {
+            assert_eq!(lookup_selector(&table, id.to_be_bytes()), Some(index));
+        }
+        assert_eq!(lookup_selector(&table, [0x00, 0x00, 0x00, 0x02]), None);
+        assert_eq!(lookup_selector(&[], [0x00; 4]), None);
+    }
+}
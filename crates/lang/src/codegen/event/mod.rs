@@ -13,8 +13,15 @@
 // limitations under the License.
 
 mod emit;
+#[cfg(feature = "std")]
+mod recorded;
 mod topics;
 
+#[cfg(feature = "std")]
+pub use self::recorded::{
+    assert_emitted,
+    recorded_events,
+};
 pub use self::{
     emit::EmitEvent,
     topics::{
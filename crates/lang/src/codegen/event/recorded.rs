@@ -0,0 +1,78 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::reflect::{
+    ContractEnv,
+    ContractEvent,
+    ContractEventBase,
+};
+
+/// The base event type of the ink! smart contract that defines `Event`.
+type BaseEventOf<Event> = <<Event as ContractEvent>::Contract as ContractEventBase>::Type;
+
+/// Returns the events recorded by the off-chain environment in order, decoded into
+/// the base event type of the `Contract`.
+///
+/// # Panics
+///
+/// If a recorded event cannot be decoded into the base event type, e.g. because
+/// it has been emitted by another contract.
+pub fn recorded_events<Contract>(
+) -> impl Iterator<Item = <Contract as ContractEventBase>::Type>
+where
+    Contract: ContractEventBase,
+    <Contract as ContractEventBase>::Type: scale::Decode,
+{
+    ink_env::test::recorded_events().map(|event| {
+        ink_env::test::decode_event(&event).unwrap_or_else(|error| {
+            panic!(
+                "encountered invalid contract event data buffer: {:?}",
+                error
+            )
+        })
+    })
+}
+
+/// Asserts that `event` has been recorded by the off-chain environment.
+///
+/// # Note
+///
+/// Use [`assert_emitted!`][`crate::assert_emitted`] instead of calling this directly.
+///
+/// # Panics
+///
+/// If no recorded event matches the encoding and the topics of `event`.
+#[track_caller]
+pub fn assert_emitted<Event>(event: Event)
+where
+    Event: ContractEvent + Into<BaseEventOf<Event>>,
+    <Event as ContractEvent>::Contract: ContractEnv,
+    BaseEventOf<Event>: scale::Encode + ink_env::Topics,
+{
+    let event: BaseEventOf<Event> = event.into();
+    let data = scale::Encode::encode(&event);
+    let topics = ink_env::test::event_topics::<
+        <<Event as ContractEvent>::Contract as ContractEnv>::Env,
+        _,
+    >(&event);
+    let mut matching_data = ink_env::test::recorded_events()
+        .filter(|recorded| recorded.data == data)
+        .peekable();
+    if matching_data.peek().is_none() {
+        panic!("expected event has not been emitted")
+    }
+    if !matching_data.any(|recorded| recorded.topics == topics) {
+        panic!("expected event has been emitted with unexpected topics")
+    }
+}
@@ -18,14 +18,30 @@ mod dispatch;
 mod env;
 mod event;
 mod implies_return;
+mod panic;
 mod trait_def;
 pub mod utils;
 
+#[cfg(feature = "std")]
+pub use self::{
+    dispatch::register_contract,
+    event::{
+        assert_emitted,
+        recorded_events,
+    },
+};
 pub use self::{
     dispatch::{
+        acquire_reentrancy_lock,
         deny_payment,
+        execute_call,
         execute_constructor,
+        execute_deploy,
         initialize_contract,
+        lookup_selector,
+        release_reentrancy_lock,
+        return_dispatch_error,
+        sort_selectors,
         ContractCallBuilder,
         ContractRootKey,
         DispatchInput,
@@ -44,11 +60,13 @@ pub use self::{
         RespectTopicLimit,
     },
     implies_return::ImpliesReturn,
+    panic::abort,
     trait_def::{
         TraitCallBuilder,
         TraitCallForwarder,
         TraitCallForwarderFor,
         TraitMessagePayable,
         TraitMessageSelector,
+        TraitNamespaceGuard,
     },
 };
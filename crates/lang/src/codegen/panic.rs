@@ -0,0 +1,157 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Silent panics for ink! smart contracts using `#[ink::contract(panic = "abort-silent")]`.
+//!
+//! The macros below replace the panicking macros of `core` within such ink! smart
+//! contracts. Unless the `std` or `ink-debug` features are enabled they abort the
+//! execution immediately without formatting their message so that neither the panic
+//! strings nor the `core::fmt` machinery end up in the Wasm blob.
+//!
+//! The panic messages are still type checked in a branch that is never taken so
+//! that switching the panic strategy never introduces new errors or warnings.
+//!
+//! # Limitations
+//!
+//! The macros only shadow their `core` counterparts at the root of the ink! module.
+//! They do not affect panics raised by `core` itself, e.g. by `Option::unwrap`,
+//! `Result::expect`, out of bounds indexing or arithmetic overflow, nor the panics
+//! of nested modules and dependencies. These still construct their messages via
+//! `core::fmt` and are handled by the panic handler of `ink_env`. Therefore
+//! `core::fmt` might still end up in the Wasm blob unless such panics are avoided.
+
+/// Aborts the execution of the ink! smart contract without formatting a panic message.
+#[cfg(target_arch = "wasm32")]
+#[inline(always)]
+pub fn abort() -> ! {
+    core::arch::wasm32::unreachable()
+}
+
+/// Aborts the execution of the ink! smart contract without formatting a panic message.
+#[cfg(not(target_arch = "wasm32"))]
+#[inline(always)]
+pub fn abort() -> ! {
+    core::panic!()
+}
+
+#[cfg(any(feature = "std", feature = "ink-debug"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ink_silent_panic {
+    ($($arg:tt)*) => {
+        ::core::panic!($($arg)*)
+    };
+}
+
+#[cfg(not(any(feature = "std", feature = "ink-debug")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ink_silent_panic {
+    ($($arg:tt)*) => {{
+        if false {
+            ::core::panic!($($arg)*)
+        }
+        $crate::codegen::abort()
+    }};
+}
+
+#[cfg(any(feature = "std", feature = "ink-debug"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ink_silent_assert {
+    ($($arg:tt)*) => {
+        ::core::assert!($($arg)*)
+    };
+}
+
+#[cfg(not(any(feature = "std", feature = "ink-debug")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ink_silent_assert {
+    ($cond:expr $(, $($arg:tt)*)?) => {
+        if !$cond {
+            if false {
+                ::core::panic!($($($arg)*)?)
+            }
+            $crate::codegen::abort()
+        }
+    };
+}
+
+#[cfg(any(feature = "std", feature = "ink-debug"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ink_silent_assert_eq {
+    ($($arg:tt)*) => {
+        ::core::assert_eq!($($arg)*)
+    };
+}
+
+#[cfg(not(any(feature = "std", feature = "ink-debug")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ink_silent_assert_eq {
+    ($left:expr, $right:expr $(, $($arg:tt)*)?) => {
+        if !($left == $right) {
+            if false {
+                ::core::panic!($($($arg)*)?)
+            }
+            $crate::codegen::abort()
+        }
+    };
+}
+
+#[cfg(any(feature = "std", feature = "ink-debug"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ink_silent_assert_ne {
+    ($($arg:tt)*) => {
+        ::core::assert_ne!($($arg)*)
+    };
+}
+
+#[cfg(not(any(feature = "std", feature = "ink-debug")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ink_silent_assert_ne {
+    ($left:expr, $right:expr $(, $($arg:tt)*)?) => {
+        if $left == $right {
+            if false {
+                ::core::panic!($($($arg)*)?)
+            }
+            $crate::codegen::abort()
+        }
+    };
+}
+
+#[cfg(any(feature = "std", feature = "ink-debug"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ink_silent_unreachable {
+    ($($arg:tt)*) => {
+        ::core::unreachable!($($arg)*)
+    };
+}
+
+#[cfg(not(any(feature = "std", feature = "ink-debug")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ink_silent_unreachable {
+    ($($arg:tt)*) => {{
+        if false {
+            ::core::panic!($($arg)*)
+        }
+        $crate::codegen::abort()
+    }};
+}
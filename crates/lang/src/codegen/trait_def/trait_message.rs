@@ -31,3 +31,96 @@ pub struct TraitMessagePayable<const IS_PAYABLE: bool>;
 /// selector of the ink! trait message matches the selector of
 /// the same ink! message as defined by the ink! trait message.
 pub struct TraitMessageSelector<const SELECTOR_ID: u32>;
+
+/// Used as `namespace` property guard for ink! trait implementation blocks.
+///
+/// # Note
+///
+/// When an ink! trait implementation block is annotated with `#[ink(namespace = N)]`
+/// a compile time check is generated by ink! to guard that the namespace
+/// matches the namespace of the implemented ink! trait definition.
+///
+/// Upon a mismatch [`TraitNamespaceGuard::error`] yields a message naming both
+/// namespaces which the generated check uses as its compile time panic message.
+pub struct TraitNamespaceGuard {
+    message: [u8; Self::CAPACITY],
+    len: usize,
+    matches: bool,
+}
+
+impl TraitNamespaceGuard {
+    /// The maximum length of the error message in bytes.
+    const CAPACITY: usize = 512;
+
+    /// Creates a guard checking the `given` namespace of the ink! trait
+    /// implementation block against the `expected` namespace of the ink!
+    /// trait definition with the name `trait_name`.
+    pub const fn new(trait_name: &str, given: &str, expected: &str) -> Self {
+        let guard = Self {
+            message: [0x00; Self::CAPACITY],
+            len: 0,
+            matches: str_eq(given, expected),
+        };
+        let guard = guard
+            .push("the namespace \"")
+            .push(given)
+            .push("\" of the ink! trait implementation block does not match ");
+        if expected.is_empty() {
+            guard
+                .push("the ink! trait definition `")
+                .push(trait_name)
+                .push("` which has no namespace")
+        } else {
+            guard
+                .push("the namespace \"")
+                .push(expected)
+                .push("\" of the ink! trait definition `")
+                .push(trait_name)
+                .push("`")
+        }
+    }
+
+    /// Appends `text` to the error message, truncating it at the capacity.
+    const fn push(mut self, text: &str) -> Self {
+        let bytes = text.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() && self.len < Self::CAPACITY {
+            self.message[self.len] = bytes[i];
+            self.len += 1;
+            i += 1;
+        }
+        self
+    }
+
+    /// Returns the error message if the namespaces do not match.
+    pub const fn error(&self) -> Option<&str> {
+        if self.matches {
+            return None
+        }
+        match core::str::from_utf8(self.message.split_at(self.len).0) {
+            Ok(message) => Some(message),
+            // Truncation at the capacity might have split a multi-byte character.
+            Err(_) => {
+                Some("the namespace of the ink! trait implementation block does not match the namespace of the ink! trait definition")
+            }
+        }
+    }
+}
+
+/// Returns `true` if both strings are equal.
+///
+/// Required since `PartialEq` for `str` is not usable in `const` contexts.
+const fn str_eq(lhs: &str, rhs: &str) -> bool {
+    let (lhs, rhs) = (lhs.as_bytes(), rhs.as_bytes());
+    if lhs.len() != rhs.len() {
+        return false
+    }
+    let mut i = 0;
+    while i < lhs.len() {
+        if lhs[i] != rhs[i] {
+            return false
+        }
+        i += 1;
+    }
+    true
+}
@@ -0,0 +1,239 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Commit-reveal utilities on top of the chain randomness.
+//!
+//! The seed returned by [`ink_env::random`] is only unpredictable for commitments
+//! that were made *before* the block number it reports. Using it directly (e.g. for
+//! a lottery draw in the same block a ticket was bought) lets a participant observe
+//! the seed before committing. A [`Commitment`] records the hash of a secret value
+//! together with the block it was made in, and [`Commitment::reveal`] only yields
+//! randomness once the chain seed is known to postdate the commitment.
+
+use ink_env::{
+    hash::{
+        Blake2x256,
+        HashOutput,
+    },
+    Clear,
+    Environment,
+};
+use ink_storage::traits::{
+    PackedLayout,
+    SpreadLayout,
+};
+
+/// Errors that can occur upon revealing a [`Commitment`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum RevealError {
+    /// The revealed value and salt do not match the commitment.
+    InvalidReveal,
+    /// The chain randomness is not yet determined after the commitment block.
+    ///
+    /// Retry the reveal in a later block.
+    RandomnessNotYetAvailable,
+    /// The chain randomness could not be retrieved.
+    RandomnessUnavailable,
+}
+
+/// A commitment to a secret value made at a specific block.
+///
+/// Store the commitment in the contract and reveal the committed value
+/// together with its salt in a later block.
+///
+/// # Example
+///
+/// ```
+/// # use ink_env::DefaultEnvironment;
+/// use ink_lang::commit_reveal::Commitment;
+///
+/// # ink_env::test::run_test::<DefaultEnvironment, _>(|_| {
+/// let commitment = Commitment::new::<DefaultEnvironment, _>(&42u32, b"salt");
+/// assert!(commitment.verify(&42u32, b"salt"));
+/// assert!(!commitment.verify(&43u32, b"salt"));
+/// # Ok(())
+/// # }).unwrap();
+/// ```
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    scale::Encode,
+    scale::Decode,
+    SpreadLayout,
+    PackedLayout,
+)]
+#[cfg_attr(
+    feature = "std",
+    derive(::scale_info::TypeInfo, ::ink_storage::traits::StorageLayout)
+)]
+pub struct Commitment<Hash, BlockNumber> {
+    /// The hash of the committed value and salt.
+    hash: Hash,
+    /// The block number at which the commitment was made.
+    block_number: BlockNumber,
+}
+
+impl<Hash, BlockNumber> Commitment<Hash, BlockNumber>
+where
+    Hash: Copy + Clear + PartialEq + AsRef<[u8]> + AsMut<[u8]>,
+    BlockNumber: Copy + PartialOrd,
+{
+    /// Commits to `value` and `salt` at the current block.
+    ///
+    /// # Note
+    ///
+    /// The salt must be kept secret until the reveal, otherwise the committed
+    /// value can be brute forced by other parties.
+    pub fn new<E, V>(value: &V, salt: &[u8]) -> Self
+    where
+        E: Environment<Hash = Hash, BlockNumber = BlockNumber>,
+        V: scale::Encode + ?Sized,
+    {
+        Self {
+            hash: Self::compute_hash(value, salt),
+            block_number: ink_env::block_number::<E>(),
+        }
+    }
+
+    /// Returns the commitment hash.
+    pub fn hash(&self) -> Hash {
+        self.hash
+    }
+
+    /// Returns the block number at which the commitment was made.
+    pub fn block_number(&self) -> BlockNumber {
+        self.block_number
+    }
+
+    /// Returns `true` if `value` and `salt` match the commitment.
+    pub fn verify<V>(&self, value: &V, salt: &[u8]) -> bool
+    where
+        V: scale::Encode + ?Sized,
+    {
+        Self::compute_hash(value, salt) == self.hash
+    }
+
+    /// Reveals the commitment and returns a random seed bound to it.
+    ///
+    /// The commitment hash is used as the randomness subject so that every
+    /// commitment yields an independent seed.
+    ///
+    /// # Errors
+    ///
+    /// - If `value` and `salt` do not match the commitment.
+    /// - If the chain randomness has not yet been determined after the
+    ///   commitment block. The reveal should then be retried in a later block.
+    /// - If the chain randomness could not be retrieved.
+    pub fn reveal<E, V>(&self, value: &V, salt: &[u8]) -> Result<Hash, RevealError>
+    where
+        E: Environment<Hash = Hash, BlockNumber = BlockNumber>,
+        V: scale::Encode + ?Sized,
+    {
+        if !self.verify(value, salt) {
+            return Err(RevealError::InvalidReveal)
+        }
+        let (seed, known_since) = ink_env::random::<E>(self.hash.as_ref())
+            .map_err(|_| RevealError::RandomnessUnavailable)?;
+        if known_since <= self.block_number {
+            return Err(RevealError::RandomnessNotYetAvailable)
+        }
+        Ok(seed)
+    }
+
+    /// Computes the Blake2x256 hash of the SCALE encoded `(value, salt)` pair.
+    fn compute_hash<V>(value: &V, salt: &[u8]) -> Hash
+    where
+        V: scale::Encode + ?Sized,
+    {
+        let mut output = <Blake2x256 as HashOutput>::Type::default();
+        ink_env::hash_encoded::<Blake2x256, _>(&(value, salt), &mut output);
+        let mut hash = <Hash as Clear>::clear();
+        let len = core::cmp::min(hash.as_ref().len(), output.len());
+        hash.as_mut()[..len].copy_from_slice(&output[..len]);
+        hash
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ink_env::DefaultEnvironment;
+
+    type DefaultCommitment = Commitment<
+        <DefaultEnvironment as Environment>::Hash,
+        <DefaultEnvironment as Environment>::BlockNumber,
+    >;
+
+    #[test]
+    fn verify_works() {
+        ink_env::test::run_test::<DefaultEnvironment, _>(|_| {
+            let commitment =
+                DefaultCommitment::new::<DefaultEnvironment, _>(&1337u64, b"salt");
+            assert!(commitment.verify(&1337u64, b"salt"));
+            assert!(!commitment.verify(&1338u64, b"salt"));
+            assert!(!commitment.verify(&1337u64, b"pepper"));
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn reveal_rejects_invalid_value() {
+        ink_env::test::run_test::<DefaultEnvironment, _>(|_| {
+            let commitment =
+                DefaultCommitment::new::<DefaultEnvironment, _>(&true, b"salt");
+            assert_eq!(
+                commitment.reveal::<DefaultEnvironment, _>(&false, b"salt"),
+                Err(RevealError::InvalidReveal)
+            );
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn reveal_requires_later_randomness() {
+        ink_env::test::run_test::<DefaultEnvironment, _>(|_| {
+            let commitment =
+                DefaultCommitment::new::<DefaultEnvironment, _>(&true, b"salt");
+            assert_eq!(
+                commitment.reveal::<DefaultEnvironment, _>(&true, b"salt"),
+                Err(RevealError::RandomnessNotYetAvailable)
+            );
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn reveal_works_in_later_block() {
+        ink_env::test::run_test::<DefaultEnvironment, _>(|_| {
+            let commitment =
+                DefaultCommitment::new::<DefaultEnvironment, _>(&true, b"salt");
+            ink_env::test::advance_block::<DefaultEnvironment>();
+            let seed = commitment
+                .reveal::<DefaultEnvironment, _>(&true, b"salt")
+                .expect("the randomness must be available in a later block");
+            let (expected, _) =
+                ink_env::random::<DefaultEnvironment>(commitment.hash().as_ref())?;
+            assert_eq!(seed, expected);
+            Ok(())
+        })
+        .unwrap()
+    }
+}
@@ -0,0 +1,33 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Implemented by contract errors derived via `#[derive(ink_lang::ContractError)]`.
+///
+/// Every variant of a contract error has a stable error code that is its SCALE
+/// encoded index. This allows clients to map the output of reverted calls back to
+/// the respective variant even if the contract error evolves over time.
+pub trait ContractError: scale::Encode + scale::Decode {
+    /// The error codes and names of all variants of the contract error.
+    const ERROR_CODES: &'static [(u8, &'static str)];
+
+    /// Returns the error code of the contract error.
+    fn error_code(&self) -> u8;
+
+    /// Returns the name of the variant with the given error code if any.
+    fn error_name(error_code: u8) -> Option<&'static str> {
+        Self::ERROR_CODES
+            .iter()
+            .find_map(|(code, name)| (*code == error_code).then(|| *name))
+    }
+}
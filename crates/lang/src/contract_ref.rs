@@ -24,3 +24,121 @@ where
     /// Returns the underlying account identifier of the instantiated contract.
     fn to_account_id(&self) -> <T as Environment>::AccountId;
 }
+
+/// Implemented by the delegate references of contracts that are compiled as
+/// dependencies.
+///
+/// Allows to create them from the hash of the code that is delegated to.
+pub trait FromCodeHash<T>
+where
+    T: Environment,
+{
+    /// Creates the delegate reference to the code with the given hash.
+    fn from_code_hash(code_hash: <T as Environment>::Hash) -> Self;
+}
+
+/// Implemented by the delegate references of contracts that are compiled as
+/// dependencies.
+///
+/// Allows them to return the hash of the code that is delegated to.
+pub trait ToCodeHash<T>
+where
+    T: Environment,
+{
+    /// Returns the hash of the code that is delegated to.
+    fn to_code_hash(&self) -> <T as Environment>::Hash;
+}
+
+/// Implemented by the references of contracts that are compiled as dependencies.
+///
+/// Returns short-hand callers of the inherent ink! messages of the contract that
+/// return the errors of failed calls instead of panicking and optionally override
+/// the call parameters. The short-hand callers implement this trait as well, so
+/// that overrides can be chained.
+///
+/// # Note
+///
+/// Since these are trait methods ink! messages may share their names. The inherent
+/// ink! message is called then and the override remains available via the fully
+/// qualified syntax, e.g. `CallOverrides::with_value(&contract_ref, 10)`.
+pub trait CallOverrides<T>
+where
+    T: Environment,
+{
+    /// The short-hand caller of the ink! messages with the overridden call parameters.
+    type Caller;
+
+    /// Returns a short-hand caller that performs calls without further overrides.
+    fn try_call(&self) -> Self::Caller;
+
+    /// Returns a short-hand caller that performs calls with the given gas limit.
+    fn with_gas_limit(&self, gas_limit: u64) -> Self::Caller;
+
+    /// Returns a short-hand caller that performs calls with the given limit of the
+    /// proof size, i.e. the second dimension of the weight.
+    fn with_proof_size_limit(&self, proof_size_limit: u64) -> Self::Caller;
+
+    /// Returns a short-hand caller that transfers the given value with its calls.
+    fn with_value(&self, transferred_value: <T as Environment>::Balance) -> Self::Caller;
+}
+
+/// Creates a reference to the contract at the given account for calling the messages
+/// of an ink! trait definition.
+///
+/// This allows to call any contract implementing the ink! trait without depending on
+/// the crate that defines the contract. The selectors of the trait messages are known
+/// at compile time and the returned reference implements the ink! trait itself.
+///
+/// The optional third argument sets the environment and defaults to
+/// [`DefaultEnvironment`](ink_env::DefaultEnvironment).
+///
+/// # Example
+///
+/// ```
+/// use ink_lang as ink;
+///
+/// #[ink::trait_definition]
+/// pub trait Flip {
+///     #[ink(message)]
+///     fn flip(&mut self);
+///
+///     #[ink(message)]
+///     fn get(&self) -> bool;
+/// }
+///
+/// /// Flips every given contract implementing `Flip`.
+/// fn flip_all(flippers: &mut [impl Flip]) {
+///     for flipper in flippers {
+///         flipper.flip();
+///     }
+/// }
+///
+/// # use ink_lang::ToAccountId as _;
+/// let account_id: ink_env::AccountId = [0x01; 32].into();
+/// let flipper = ink::contract_ref!(dyn Flip, account_id);
+/// assert_eq!(flipper.to_account_id(), account_id);
+/// # let _ = || flip_all(&mut [flipper]);
+/// ```
+///
+/// # Note
+///
+/// Using a trait that is not an ink! trait definition fails to compile.
+#[macro_export]
+macro_rules! contract_ref {
+    // Internal rule resolving the reference type of the ink! trait definition.
+    ( @type dyn $trait_path:path ) => {
+        $crate::contract_ref!(@type dyn $trait_path, ::ink_env::DefaultEnvironment)
+    };
+    ( @type dyn $trait_path:path, $env:ty ) => {
+        <<$crate::reflect::TraitDefinitionRegistry<$env>
+            as $trait_path>::__ink_TraitInfo
+            as $crate::codegen::TraitCallForwarder>::Forwarder
+    };
+    ( dyn $trait_path:path, $account_id:expr $(,)? ) => {
+        $crate::contract_ref!(dyn $trait_path, $account_id, ::ink_env::DefaultEnvironment)
+    };
+    ( dyn $trait_path:path, $account_id:expr, $env:ty $(,)? ) => {
+        <$crate::contract_ref!(@type dyn $trait_path, $env)
+            as ::ink_env::call::FromAccountId<$env>>::from_account_id($account_id)
+    };
+}
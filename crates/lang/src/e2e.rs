@@ -0,0 +1,345 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! End-to-end testing of ink! smart contracts.
+//!
+//! End-to-end tests deploy contracts and call their messages through a [`Client`]
+//! just like users of a chain do, instead of calling the Rust methods of the contract
+//! directly. This exercises the dispatch, the SCALE encoding of inputs and outputs,
+//! value transfers and emitted events of the contract as a whole.
+//!
+//! End-to-end tests are `async fn`s annotated with `#[ink::e2e_test]` that take the
+//! client as their only argument. The client is connected before the test body runs.
+//!
+//! The [`OffChainClient`] executes the contracts in the off-chain environment of
+//! `ink_env`. Clients of contracts enabled nodes implement the same [`Client`] trait,
+//! so tests can be run against them by changing the type of the client argument.
+//!
+//! Only available with the `e2e-tests` crate feature.
+//!
+//! # Example
+//!
+//! ```
+//! use ink_lang as ink;
+//!
+//! #[ink::contract]
+//! mod flipper {
+//!     #[ink(storage)]
+//!     pub struct Flipper {
+//!         value: bool,
+//!     }
+//!
+//!     impl Flipper {
+//!         #[ink(constructor)]
+//!         pub fn new(init_value: bool) -> Self {
+//!             Self { value: init_value }
+//!         }
+//!
+//!         #[ink(message)]
+//!         pub fn flip(&mut self) {
+//!             self.value = !self.value;
+//!         }
+//!
+//!         #[ink(message)]
+//!         pub fn get(&self) -> bool {
+//!             self.value
+//!         }
+//!     }
+//! }
+//!
+//! use ink_env::call::{
+//!     ExecutionInput,
+//!     Selector,
+//! };
+//! use ink_lang::e2e::{
+//!     Client as _,
+//!     OffChainClient,
+//! };
+//!
+//! #[ink::e2e_test]
+//! async fn flip_works(mut client: OffChainClient) -> Result<(), ink_env::Error> {
+//!     let alice = ink_env::test::default_accounts::<ink_env::DefaultEnvironment>().alice;
+//!     let code_hash = client.upload::<flipper::Flipper>();
+//!     let flipper = client
+//!         .instantiate(
+//!             &alice,
+//!             code_hash,
+//!             0,
+//!             ExecutionInput::new(Selector::new(ink::selector_bytes!("new")))
+//!                 .push_arg(false),
+//!             b"salt",
+//!         )
+//!         .await?
+//!         .account_id;
+//!     client
+//!         .call::<_, ()>(
+//!             &alice,
+//!             &flipper,
+//!             0,
+//!             ExecutionInput::new(Selector::new(ink::selector_bytes!("flip"))),
+//!         )
+//!         .await?;
+//!     let get = client
+//!         .call::<_, bool>(
+//!             &alice,
+//!             &flipper,
+//!             0,
+//!             ExecutionInput::new(Selector::new(ink::selector_bytes!("get"))),
+//!         )
+//!         .await?;
+//!     assert!(get.return_value);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{
+    codegen::register_contract,
+    reflect::{
+        ContractConstructorDecoder,
+        ContractEnv,
+        ContractMessageDecoder,
+    },
+};
+use core::{
+    fmt,
+    future::Future,
+    marker::PhantomData,
+};
+use ink_env::{
+    call::{
+        build_call,
+        build_create,
+        Call,
+        ExecutionInput,
+        FromAccountId,
+    },
+    hash::Blake2x256,
+    test::EmittedEvent,
+    Environment,
+};
+
+/// The account ID type of the environment of the client `C`.
+type AccountIdOf<C> = <<C as Client>::Env as Environment>::AccountId;
+
+/// The balance type of the environment of the client `C`.
+type BalanceOf<C> = <<C as Client>::Env as Environment>::Balance;
+
+/// The hash type of the environment of the client `C`.
+type HashOf<C> = <<C as Client>::Env as Environment>::Hash;
+
+/// The result of a successful instantiation of a contract.
+pub struct InstantiationResult<E>
+where
+    E: Environment,
+{
+    /// The account ID of the instantiated contract.
+    pub account_id: E::AccountId,
+    /// The events emitted during the instantiation.
+    pub events: Vec<EmittedEvent>,
+}
+
+/// The result of a successful call of a contract message.
+pub struct CallResult<R> {
+    /// The decoded return value of the message.
+    pub return_value: R,
+    /// The events emitted during the call.
+    pub events: Vec<EmittedEvent>,
+}
+
+/// A client of a contracts enabled chain used by end-to-end tests.
+///
+/// All interactions with the chain are signed by the account given as `signer`,
+/// which pays for the transferred `value`.
+pub trait Client: Sized {
+    /// The environment of the chain.
+    type Env: Environment;
+    /// The error returned if an interaction with the chain fails.
+    type Error: fmt::Debug;
+
+    /// Connects to the chain.
+    ///
+    /// This is called by `#[ink::e2e_test]` before the body of the test is run.
+    fn connect() -> impl Future<Output = Result<Self, Self::Error>>;
+
+    /// Instantiates the contract code at `code_hash` with the constructor `input`,
+    /// the endowment `value` and the `salt` for the contract address.
+    fn instantiate<Args>(
+        &mut self,
+        signer: &AccountIdOf<Self>,
+        code_hash: HashOf<Self>,
+        value: BalanceOf<Self>,
+        input: ExecutionInput<Args>,
+        salt: &[u8],
+    ) -> impl Future<Output = Result<InstantiationResult<Self::Env>, Self::Error>>
+    where
+        Args: scale::Encode;
+
+    /// Calls the message of the contract at `callee` given by `input` and
+    /// transfers `value` to it.
+    ///
+    /// The return value of the message is decoded as `R`.
+    fn call<Args, R>(
+        &mut self,
+        signer: &AccountIdOf<Self>,
+        callee: &AccountIdOf<Self>,
+        value: BalanceOf<Self>,
+        input: ExecutionInput<Args>,
+    ) -> impl Future<Output = Result<CallResult<R>, Self::Error>>
+    where
+        Args: scale::Encode,
+        R: scale::Decode;
+
+    /// Returns the free balance of `account`.
+    fn balance(
+        &self,
+        account: &AccountIdOf<Self>,
+    ) -> impl Future<Output = Result<BalanceOf<Self>, Self::Error>>;
+}
+
+/// The account ID of a contract instantiated by the [`OffChainClient`].
+struct Instantiated<E>(E::AccountId)
+where
+    E: Environment;
+
+impl<E> FromAccountId<E> for Instantiated<E>
+where
+    E: Environment,
+{
+    fn from_account_id(account_id: E::AccountId) -> Self {
+        Self(account_id)
+    }
+}
+
+/// A [`Client`] executing the contracts in the off-chain environment.
+///
+/// Connecting resets the off-chain environment to its default configuration,
+/// i.e. the default accounts are funded as by `ink_env::test::run_test`.
+/// Contracts are made known to the client via [`OffChainClient::upload`].
+pub struct OffChainClient<E = ink_env::DefaultEnvironment> {
+    _phantom: PhantomData<fn() -> E>,
+}
+
+impl<E> OffChainClient<E>
+where
+    E: Environment,
+    E::AccountId: From<[u8; 32]>,
+    E::Hash: From<[u8; 32]>,
+{
+    /// Uploads the code of the ink! smart contract `Contract` and returns its code hash.
+    ///
+    /// The code hash is derived from the type name of `Contract`.
+    pub fn upload<Contract>(&mut self) -> E::Hash
+    where
+        Contract:
+            ContractEnv<Env = E> + ContractConstructorDecoder + ContractMessageDecoder,
+    {
+        let mut code_hash = [0x00; 32];
+        ink_env::hash_bytes::<Blake2x256>(
+            core::any::type_name::<Contract>().as_bytes(),
+            &mut code_hash,
+        );
+        register_contract::<Contract>(code_hash.into());
+        code_hash.into()
+    }
+
+    /// Runs `f` on behalf of `signer` and returns its result with the emitted events.
+    #[allow(deprecated)]
+    fn signed<F, R>(signer: &E::AccountId, f: F) -> (R, Vec<EmittedEvent>)
+    where
+        F: FnOnce() -> R,
+    {
+        let emitted_before = ink_env::test::recorded_events().count();
+        let callee = ink_env::test::callee::<E>();
+        ink_env::test::set_callee::<E>(signer.clone());
+        let result = f();
+        ink_env::test::set_callee::<E>(callee);
+        let events = ink_env::test::recorded_events()
+            .skip(emitted_before)
+            .collect();
+        (result, events)
+    }
+}
+
+impl<E> Client for OffChainClient<E>
+where
+    E: Environment,
+    E::AccountId: From<[u8; 32]>,
+    E::Hash: From<[u8; 32]>,
+{
+    type Env = E;
+    type Error = ink_env::Error;
+
+    async fn connect() -> Result<Self, Self::Error> {
+        ink_env::test::run_test::<E, _>(|_| Ok(()))?;
+        Ok(Self {
+            _phantom: PhantomData,
+        })
+    }
+
+    async fn instantiate<Args>(
+        &mut self,
+        signer: &E::AccountId,
+        code_hash: E::Hash,
+        value: E::Balance,
+        input: ExecutionInput<Args>,
+        salt: &[u8],
+    ) -> Result<InstantiationResult<E>, Self::Error>
+    where
+        Args: scale::Encode,
+    {
+        let (result, events) = Self::signed(signer, || {
+            build_create::<E, Instantiated<E>>()
+                .code_hash(code_hash)
+                .gas_limit(0)
+                .endowment(value)
+                .exec_input(input)
+                .salt_bytes(salt)
+                .params()
+                .instantiate()
+        });
+        Ok(InstantiationResult {
+            account_id: result?.0,
+            events,
+        })
+    }
+
+    async fn call<Args, R>(
+        &mut self,
+        signer: &E::AccountId,
+        callee: &E::AccountId,
+        value: E::Balance,
+        input: ExecutionInput<Args>,
+    ) -> Result<CallResult<R>, Self::Error>
+    where
+        Args: scale::Encode,
+        R: scale::Decode,
+    {
+        let (result, events) = Self::signed(signer, || {
+            build_call::<E>()
+                .call_type(Call::new().callee(callee.clone()))
+                .transferred_value(value)
+                .exec_input(input)
+                .returns::<R>()
+                .fire()
+        });
+        Ok(CallResult {
+            return_value: result?,
+            events,
+        })
+    }
+
+    async fn balance(&self, account: &E::AccountId) -> Result<E::Balance, Self::Error> {
+        Ok(Self::signed(account, ink_env::balance::<E>).0)
+    }
+}
@@ -16,10 +16,12 @@ use crate::ChainExtensionInstance;
 use core::marker::PhantomData;
 use ink_env::{
     call::{
+        utils::ConstructorReturnType,
         Call,
         CallParams,
         CreateParams,
         DelegateCall,
+        InstantiateError,
     },
     hash::{
         CryptoHash,
@@ -27,7 +29,9 @@ use ink_env::{
     },
     Environment,
     Error,
+    ExistenceRequirement,
     Result,
+    Weight,
 };
 use ink_eth_compatibility::ECDSAPublicKey;
 
@@ -169,10 +173,10 @@ where
     /// ///   - the price for the gas
     /// #[ink(message)]
     /// pub fn addition_gas_cost(&self, rhs: i32, lhs: i32) -> (i32, u64, Balance) {
-    ///     let before = self.env().gas_left();
+    ///     let before = self.env().gas_left().ref_time();
     ///     let result = rhs + lhs;
-    ///     let after = self.env().gas_left();
-    ///     let gas_used = after - before;
+    ///     let after = self.env().gas_left().ref_time();
+    ///     let gas_used = before - after;
     ///     let gas_cost = self.env().weight_to_fee(gas_used);
     ///     (result, gas_used, gas_cost)
     /// }
@@ -188,7 +192,7 @@ where
         ink_env::weight_to_fee::<E>(gas)
     }
 
-    /// Returns the amount of gas left for the contract execution.
+    /// Returns the weight left for the contract execution.
     ///
     /// # Example
     ///
@@ -207,13 +211,13 @@ where
     /// #
     /// /// Returns a tuple of
     /// ///   - the result of adding the `rhs` to the `lhs` and
-    /// ///   - the gas used for this addition operation.
+    /// ///   - the `ref_time` used for this addition operation.
     /// #[ink(message)]
     /// pub fn addition_gas_cost(&self, rhs: i32, lhs: i32) -> (i32, u64) {
     ///     let before = self.env().gas_left();
     ///     let result = rhs + lhs;
     ///     let after = self.env().gas_left();
-    ///     (result, after - before)
+    ///     (result, before.ref_time() - after.ref_time())
     /// }
     /// #
     /// #     }
@@ -223,7 +227,7 @@ where
     /// # Note
     ///
     /// For more details visit: [`ink_env::gas_left`]
-    pub fn gas_left(self) -> u64 {
+    pub fn gas_left(self) -> Weight {
         ink_env::gas_left::<E>()
     }
 
@@ -423,6 +427,41 @@ where
         ink_env::minimum_balance::<E>()
     }
 
+    /// Emits an event with topics computed at runtime and the given raw event data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ink_lang as ink;
+    /// # #[ink::contract]
+    /// # pub mod my_contract {
+    /// #     #[ink(storage)]
+    /// #     pub struct MyContract { }
+    /// #
+    /// #     impl MyContract {
+    /// #         #[ink(constructor)]
+    /// #         pub fn new() -> Self {
+    /// #             Self {}
+    /// #         }
+    /// #
+    /// /// Notifies every subscriber of the given keys about the new value.
+    /// #[ink(message)]
+    /// pub fn notify(&self, keys: Vec<Hash>, value: u32) {
+    ///     let data = scale::Encode::encode(&value);
+    ///     self.env().emit_event_with_topics(&keys, &data);
+    /// }
+    /// #
+    /// #     }
+    /// # }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`ink_env::emit_event_with_topics`]
+    pub fn emit_event_with_topics(self, topics: &[E::Hash], data: &[u8]) {
+        ink_env::emit_event_with_topics::<E>(topics, data)
+    }
+
     /// Instantiates another contract.
     ///
     /// # Example
@@ -484,15 +523,16 @@ where
     /// # Note
     ///
     /// For more details visit: [`ink_env::instantiate_contract`]
-    pub fn instantiate_contract<Args, Salt, C>(
+    pub fn instantiate_contract<Args, Salt, C, RetType>(
         self,
-        params: &CreateParams<E, Args, Salt, C>,
-    ) -> Result<E::AccountId>
+        params: &CreateParams<E, Args, Salt, C, RetType>,
+    ) -> core::result::Result<E::AccountId, InstantiateError<RetType::Error>>
     where
         Args: scale::Encode,
         Salt: AsRef<[u8]>,
+        RetType: ConstructorReturnType<C>,
     {
-        ink_env::instantiate_contract::<E, Args, Salt, C>(params)
+        ink_env::instantiate_contract::<E, Args, Salt, C, RetType>(params)
     }
 
     /// Invokes a contract message and returns its result.
@@ -685,6 +725,53 @@ where
         ink_env::transfer::<E>(destination, value)
     }
 
+    /// Transfers value from the contract to the destination account ID while
+    /// respecting the given existence requirement.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ink_lang as ink;
+    /// # #[ink::contract]
+    /// # pub mod my_contract {
+    /// #     #[ink(storage)]
+    /// #     pub struct MyContract { }
+    /// #
+    /// #     impl MyContract {
+    /// #         #[ink(constructor)]
+    /// #         pub fn new() -> Self {
+    /// #             Self {}
+    /// #         }
+    /// #
+    /// /// Transfers the token amount ten to the caller without reaping the contract.
+    /// #[ink(message)]
+    /// pub fn give_me_ten(&mut self) -> bool {
+    ///     let value: Balance = 10;
+    ///     self.env()
+    ///         .transfer_with_existence(
+    ///             self.env().caller(),
+    ///             value,
+    ///             ink_env::ExistenceRequirement::KeepAlive,
+    ///         )
+    ///         .is_ok()
+    /// }
+    /// #
+    /// #     }
+    /// # }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`ink_env::transfer_with_existence`]
+    pub fn transfer_with_existence(
+        self,
+        destination: E::AccountId,
+        value: E::Balance,
+        existence: ExistenceRequirement,
+    ) -> Result<()> {
+        ink_env::transfer_with_existence::<E>(destination, value, existence)
+    }
+
     /// Returns a random hash seed.
     ///
     /// # Example
@@ -838,6 +925,45 @@ where
             .map_err(|_| Error::EcdsaRecoveryFailed)
     }
 
+    /// Verifies a sr25519 `signature` of the given `message` against the public key `pub_key`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ink_lang as ink;
+    /// # #[ink::contract]
+    /// # pub mod my_contract {
+    /// #     #[ink(storage)]
+    /// #     pub struct MyContract { }
+    /// #
+    /// #     impl MyContract {
+    /// #         #[ink(constructor)]
+    /// #         pub fn new() -> Self {
+    /// #             Self {}
+    /// #         }
+    /// #
+    /// /// Returns `true` if `signature` is a valid signature of `message` by `pub_key`.
+    /// #[ink(message)]
+    /// pub fn verify(&self, signature: [u8; 64], message: Vec<u8>, pub_key: [u8; 32]) -> bool {
+    ///     self.env().sr25519_verify(&signature, &message, &pub_key).is_ok()
+    /// }
+    /// #
+    /// #     }
+    /// # }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`ink_env::sr25519_verify`]
+    pub fn sr25519_verify(
+        self,
+        signature: &[u8; 64],
+        message: &[u8],
+        pub_key: &[u8; 32],
+    ) -> Result<()> {
+        ink_env::sr25519_verify(signature, message, pub_key)
+    }
+
     /// Checks whether a specified account belongs to a contract.
     ///
     /// # Example
@@ -965,4 +1091,100 @@ where
     pub fn own_code_hash(self) -> Result<E::Hash> {
         ink_env::own_code_hash::<E>()
     }
+
+    /// Returns the instantiation nonce of the chain.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ink_lang as ink;
+    /// # #[ink::contract]
+    /// # pub mod my_contract {
+    /// #     #[ink(storage)]
+    /// #     pub struct MyContract { }
+    /// #
+    /// #     impl MyContract {
+    /// #         #[ink(constructor)]
+    /// #         pub fn new() -> Self {
+    /// #             Self {}
+    /// #         }
+    /// #
+    /// /// Returns a salt that is unique for every instantiation on the chain.
+    /// #[ink(message)]
+    /// pub fn unique_salt(&self) -> [u8; 8] {
+    ///     self.env().instantiation_nonce().to_le_bytes()
+    /// }
+    /// #    }
+    /// # }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`ink_env::instantiation_nonce`]
+    pub fn instantiation_nonce(self) -> u64 {
+        ink_env::instantiation_nonce::<E>()
+    }
+
+    /// Returns the hash of the block with the given block number.
+    ///
+    /// Returns `None` if the block is not within the runtime's window of recent blocks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ink_lang as ink;
+    /// # #[ink::contract]
+    /// # pub mod my_contract {
+    /// #     #[ink(storage)]
+    /// #     pub struct MyContract { }
+    /// #
+    /// #     impl MyContract {
+    /// #         #[ink(constructor)]
+    /// #         pub fn new() -> Self {
+    /// #             Self {}
+    /// #         }
+    /// #
+    /// /// Returns the hash of the previous block if it is known.
+    /// #[ink(message)]
+    /// pub fn parent_hash(&self) -> Option<Hash> {
+    ///     let block_number = self.env().block_number().checked_sub(1)?;
+    ///     self.env().block_hash(block_number)
+    /// }
+    /// #    }
+    /// # }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`ink_env::block_hash`]
+    pub fn block_hash(self, block_number: E::BlockNumber) -> Option<E::Hash> {
+        ink_env::block_hash::<E>(block_number)
+    }
+
+    /// Executes the given XCM message locally with the contract as the origin.
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`ink_env::xcm_execute`]
+    pub fn xcm_execute<M>(self, msg: &M) -> Result<()>
+    where
+        M: scale::Encode,
+    {
+        ink_env::xcm_execute::<E, M>(msg)
+    }
+
+    /// Sends the given XCM message to `dest` with the contract as the origin.
+    ///
+    /// Returns the hash of the sent message.
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`ink_env::xcm_send`]
+    pub fn xcm_send<D, M>(self, dest: &D, msg: &M) -> Result<[u8; 32]>
+    where
+        D: scale::Encode,
+        M: scale::Encode,
+    {
+        ink_env::xcm_send::<E, D, M>(dest, msg)
+    }
 }
@@ -0,0 +1,318 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fixtures for off-chain tests of interacting ink! smart contracts.
+//!
+//! [`Fixtures`] registers the code of ink! smart contracts in the off-chain
+//! environment and instantiates them, each at its own account and with its own
+//! endowment. The returned contract references are wired to the off-chain
+//! environment, so calling their messages executes the instantiated contracts
+//! just like cross-contract calls do on-chain.
+//!
+//! Contracts that instantiate other contracts themselves receive the code hashes
+//! of [`Fixtures::register`]ed contracts.
+//!
+//! # Example
+//!
+//! ```
+//! use ink_lang as ink;
+//!
+//! #[ink::contract]
+//! mod counter {
+//!     #[ink(storage)]
+//!     pub struct Counter {
+//!         value: u32,
+//!     }
+//!
+//!     impl Counter {
+//!         #[ink(constructor, payable)]
+//!         pub fn new(value: u32) -> Self {
+//!             Self { value }
+//!         }
+//!
+//!         #[ink(message)]
+//!         pub fn inc(&mut self) {
+//!             self.value += 1;
+//!         }
+//!
+//!         #[ink(message)]
+//!         pub fn get(&self) -> u32 {
+//!             self.value
+//!         }
+//!     }
+//! }
+//!
+//! use counter::{Counter, CounterRef};
+//! use ink_lang::{fixtures::Fixtures, ToAccountId as _};
+//!
+//! # ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+//! let mut fixtures = Fixtures::<ink_env::DefaultEnvironment>::new();
+//! let mut first = fixtures
+//!     .deploy::<Counter>()
+//!     .endowment(1_000)
+//!     .instantiate(CounterRef::new(41));
+//! let second = fixtures.deploy::<Counter>().instantiate(CounterRef::new(0));
+//!
+//! first.inc();
+//! assert_eq!(first.get(), 42);
+//! assert_eq!(second.get(), 0);
+//! assert_ne!(first.to_account_id(), second.to_account_id());
+//! assert_eq!(
+//!     ink_env::test::get_account_balance::<ink_env::DefaultEnvironment>(
+//!         first.to_account_id()
+//!     ),
+//!     Ok(1_000)
+//! );
+//! # Ok(())
+//! # }).unwrap();
+//! ```
+
+use crate::{
+    codegen::register_contract,
+    reflect::{
+        ContractConstructorDecoder,
+        ContractEnv,
+        ContractMessageDecoder,
+        ContractReference,
+    },
+};
+use core::marker::PhantomData;
+use ink_env::{
+    call::{
+        state::Salt,
+        utils::{
+            ConstructorReturnType,
+            ReturnType,
+            Set,
+            Unset,
+            Unwrap,
+        },
+        CreateBuilder,
+        ExecutionInput,
+        FromAccountId,
+    },
+    hash::{
+        Blake2x256,
+        HashOutput,
+    },
+    Environment,
+};
+
+/// The reference type of the ink! smart contract `C`.
+type RefOf<C> = <C as ContractReference>::Type;
+
+/// Instantiates ink! smart contracts in the off-chain environment.
+///
+/// See the [module documentation](self) for an example.
+pub struct Fixtures<E>
+where
+    E: Environment,
+{
+    /// The account that instantiates the contracts.
+    deployer: E::AccountId,
+    /// The amount of contracts instantiated so far, used to derive their salts.
+    instantiated: u32,
+}
+
+impl<E> Fixtures<E>
+where
+    E: Environment,
+    E::AccountId: From<[u8; 32]>,
+    E::Hash: From<[u8; 32]>,
+{
+    /// Creates fixtures whose contracts are instantiated by the default account
+    /// `alice`.
+    pub fn new() -> Self {
+        Self {
+            deployer: ink_env::test::default_accounts::<E>().alice,
+            instantiated: 0,
+        }
+    }
+
+    /// Sets the account that instantiates the contracts.
+    ///
+    /// The deployer pays the endowments of the instantiated contracts.
+    pub fn deployer(mut self, deployer: E::AccountId) -> Self {
+        self.deployer = deployer;
+        self
+    }
+
+    /// Registers the code of the ink! smart contract `Contract` and returns its code
+    /// hash.
+    ///
+    /// The code hash is the BLAKE2 hash of the type name of the contract.
+    pub fn register<Contract>(&mut self) -> E::Hash
+    where
+        Contract:
+            ContractEnv<Env = E> + ContractConstructorDecoder + ContractMessageDecoder,
+    {
+        let code_hash = default_code_hash::<Contract>();
+        register_contract::<Contract>(code_hash);
+        code_hash
+    }
+
+    /// Starts the deployment of an instance of the ink! smart contract `Contract`.
+    ///
+    /// By default the code of the contract is registered under the BLAKE2 hash of
+    /// its type name and no endowment is transferred.
+    pub fn deploy<Contract>(&mut self) -> Deployment<'_, E, Contract>
+    where
+        Contract: ContractEnv<Env = E>
+            + ContractReference
+            + ContractConstructorDecoder
+            + ContractMessageDecoder,
+        RefOf<Contract>: FromAccountId<E>,
+    {
+        Deployment {
+            fixtures: self,
+            code_hash: default_code_hash::<Contract>(),
+            endowment: E::Balance::from(0u32),
+            salt: None,
+            contract: PhantomData,
+        }
+    }
+}
+
+/// Returns the code hash under which the code of `Contract` is registered by default.
+fn default_code_hash<Contract>() -> <<Contract as ContractEnv>::Env as Environment>::Hash
+where
+    Contract: ContractEnv,
+    <<Contract as ContractEnv>::Env as Environment>::Hash: From<[u8; 32]>,
+{
+    let mut code_hash = <Blake2x256 as HashOutput>::Type::default();
+    ink_env::hash_bytes::<Blake2x256>(
+        core::any::type_name::<Contract>().as_bytes(),
+        &mut code_hash,
+    );
+    code_hash.into()
+}
+
+impl<E> Default for Fixtures<E>
+where
+    E: Environment,
+    E::AccountId: From<[u8; 32]>,
+    E::Hash: From<[u8; 32]>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The deployment of an instance of the ink! smart contract `Contract`.
+///
+/// Created by [`Fixtures::deploy`].
+pub struct Deployment<'a, E, Contract>
+where
+    E: Environment,
+{
+    /// The fixtures that instantiate the contract.
+    fixtures: &'a mut Fixtures<E>,
+    /// The code hash under which the code of the contract is registered.
+    code_hash: E::Hash,
+    /// The value transferred to the contract upon instantiation.
+    endowment: E::Balance,
+    /// The salt of the contract address if set explicitly.
+    salt: Option<Vec<u8>>,
+    /// The instantiated contract.
+    contract: PhantomData<fn() -> Contract>,
+}
+
+impl<'a, E, Contract> Deployment<'a, E, Contract>
+where
+    E: Environment,
+    E::AccountId: From<[u8; 32]>,
+    Contract: ContractEnv<Env = E>
+        + ContractReference
+        + ContractConstructorDecoder
+        + ContractMessageDecoder,
+    RefOf<Contract>: FromAccountId<E>,
+{
+    /// Sets the code hash under which the code of the contract is registered.
+    ///
+    /// Contracts deployed under different code hashes have distinct code from
+    /// the perspective of the environment, e.g. for `set_code_hash`.
+    pub fn code_hash(mut self, code_hash: E::Hash) -> Self {
+        self.code_hash = code_hash;
+        self
+    }
+
+    /// Sets the value transferred from the deployer to the contract.
+    pub fn endowment(mut self, endowment: E::Balance) -> Self {
+        self.endowment = endowment;
+        self
+    }
+
+    /// Sets the salt from which the account of the contract is derived.
+    ///
+    /// By default every deployment of the fixtures uses a distinct salt.
+    pub fn salt(mut self, salt: &[u8]) -> Self {
+        self.salt = Some(salt.to_vec());
+        self
+    }
+
+    /// Instantiates the contract with the given `constructor` call.
+    ///
+    /// Usually the `constructor` is a constructor of the contract reference,
+    /// e.g. `FlipperRef::new(false)`.
+    ///
+    /// # Panics
+    ///
+    /// If the instantiation fails, e.g. because the constructor traps or returns
+    /// an error or the deployer cannot pay the endowment.
+    #[allow(clippy::type_complexity)]
+    #[allow(deprecated)]
+    pub fn instantiate<Args, RetType, Ret>(
+        self,
+        constructor: CreateBuilder<
+            E,
+            Unset<E::Hash>,
+            Unset<u64>,
+            Unset<E::Balance>,
+            Set<ExecutionInput<Args>>,
+            Unset<Salt>,
+            RefOf<Contract>,
+            RetType,
+        >,
+    ) -> RefOf<Contract>
+    where
+        Args: scale::Encode,
+        RetType: Unwrap<Output = ReturnType<Ret>>,
+        Ret: ConstructorReturnType<RefOf<Contract>>,
+        Ret::Error: core::fmt::Debug,
+    {
+        register_contract::<Contract>(self.code_hash);
+        let fixtures = self.fixtures;
+        let salt = self
+            .salt
+            .unwrap_or_else(|| fixtures.instantiated.to_le_bytes().to_vec());
+        fixtures.instantiated += 1;
+
+        let callee = ink_env::test::callee::<E>();
+        ink_env::test::set_callee::<E>(fixtures.deployer.clone());
+        let result = constructor
+            .code_hash(self.code_hash)
+            .gas_limit(0)
+            .endowment(self.endowment)
+            .salt_bytes(salt)
+            .instantiate();
+        ink_env::test::set_callee::<E>(callee);
+        result.unwrap_or_else(|error| {
+            panic!(
+                "instantiating `{}` failed: {:?}",
+                core::any::type_name::<Contract>(),
+                error
+            )
+        })
+    }
+}
@@ -0,0 +1,297 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fuzzing of ink! smart contracts in the off-chain environment.
+//!
+//! A [`Fuzzer`] performs a sequence of [`FuzzOp`]s, e.g. calls to the messages of
+//! an ink! smart contract, and checks user supplied invariants after every call.
+//! The operations are usually generated by a fuzzing engine such as `cargo fuzz`
+//! via their [`Arbitrary`] implementation. The engine then explores unexpected call
+//! orderings and arguments and shrinks the operations of failing runs.
+//!
+//! Calls whose arguments cannot be decoded trap just like they would on-chain
+//! and leave the storage of the contract unchanged.
+//!
+//! # Example
+//!
+//! ```
+//! use ink_lang as ink;
+//!
+//! #[ink::contract]
+//! mod counter {
+//!     #[ink(storage)]
+//!     pub struct Counter {
+//!         value: u32,
+//!     }
+//!
+//!     impl Counter {
+//!         #[ink(constructor)]
+//!         pub fn new() -> Self {
+//!             Self { value: 0 }
+//!         }
+//!
+//!         #[ink(message)]
+//!         pub fn inc(&mut self, by: u8) {
+//!             self.value += by as u32;
+//!         }
+//!
+//!         #[ink(message)]
+//!         pub fn reset(&mut self) {
+//!             self.value = 0;
+//!         }
+//!
+//!         #[ink(message)]
+//!         pub fn get(&self) -> u32 {
+//!             self.value
+//!         }
+//!     }
+//! }
+//!
+//! use counter::Counter;
+//! use ink_lang::fuzz::{
+//!     FuzzOp,
+//!     Fuzzer,
+//! };
+//!
+//! # ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+//! // The operations would usually be generated by a fuzzing engine.
+//! let ops = [
+//!     FuzzOp::Call { message: 0, input: vec![42] },
+//!     FuzzOp::Call { message: 1, input: vec![] },
+//!     FuzzOp::AdvanceBlock,
+//!     FuzzOp::Call { message: 0, input: vec![7] },
+//!     FuzzOp::Call { message: 2, input: vec![] },
+//! ];
+//! let calls = Fuzzer::new(Counter::new)
+//!     .invariant("bounded by the increments", |counter: &Counter| {
+//!         counter.get() <= 255 * 5
+//!     })
+//!     .run(&ops[..]);
+//! assert_eq!(calls.len(), 4);
+//! assert!(calls.iter().all(|call| call.succeeded));
+//! # Ok(())
+//! # }).unwrap();
+//! ```
+
+use crate::{
+    codegen::{
+        register_contract,
+        ContractRootKey,
+    },
+    reflect::{
+        ContractConstructorDecoder,
+        ContractDispatchableMessages,
+        ContractEnv,
+        ContractMessageDecoder,
+    },
+};
+use arbitrary::Arbitrary;
+use ink_env::{
+    call::{
+        build_call,
+        Call,
+        ExecutionInput,
+        Selector,
+    },
+    Environment,
+};
+use ink_storage::traits::{
+    pull_spread_root,
+    push_spread_root,
+    SpreadLayout,
+};
+
+/// The code hash under which the fuzzed contract is registered.
+const CODE_HASH: [u8; 32] = [0xF0; 32];
+
+/// The account of the fuzzed contract.
+const CONTRACT_ACCOUNT: [u8; 32] = [0xF1; 32];
+
+/// The environment of the contract `C`.
+type EnvOf<C> = <C as ContractEnv>::Env;
+
+/// The account ID type of the contract `C`.
+type AccountIdOf<C> = <EnvOf<C> as Environment>::AccountId;
+
+/// An operation performed on the fuzzed contract.
+#[derive(Debug, Clone, PartialEq, Eq, Arbitrary)]
+pub enum FuzzOp {
+    /// Calls a message of the contract.
+    Call {
+        /// Selects the called message among the messages of the contract.
+        message: u8,
+        /// The bytes from which the arguments of the message are decoded.
+        input: Vec<u8>,
+    },
+    /// Advances the chain by a single block.
+    AdvanceBlock,
+}
+
+/// A call to a message of the fuzzed contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzCall {
+    /// The selector of the called message.
+    pub selector: [u8; 4],
+    /// The bytes from which the arguments of the message are decoded.
+    pub input: Vec<u8>,
+    /// Yields `true` if the call neither trapped nor reverted.
+    pub succeeded: bool,
+}
+
+/// The arguments of a message passed to the contract as they are.
+struct RawInput<'a>(&'a [u8]);
+
+impl scale::Encode for RawInput<'_> {
+    fn size_hint(&self) -> usize {
+        self.0.len()
+    }
+
+    fn encode_to<T: scale::Output + ?Sized>(&self, dest: &mut T) {
+        dest.write(self.0)
+    }
+}
+
+/// An invariant of the fuzzed contract.
+struct Invariant<Contract> {
+    /// The name of the invariant used to report violations.
+    name: &'static str,
+    /// Yields `true` if the invariant holds for the contract.
+    holds: Box<dyn Fn(&Contract) -> bool>,
+}
+
+/// Executes sequences of messages of an ink! smart contract and checks invariants.
+///
+/// See the [module documentation](self) for an example.
+pub struct Fuzzer<Contract> {
+    /// Creates the contract that is fuzzed.
+    constructor: fn() -> Contract,
+    /// The invariants checked after the contract has been created and after every call.
+    invariants: Vec<Invariant<Contract>>,
+    /// The maximum amount of calls performed per run.
+    max_calls: usize,
+}
+
+impl<Contract> Fuzzer<Contract>
+where
+    Contract: ContractEnv
+        + ContractRootKey
+        + SpreadLayout
+        + ContractConstructorDecoder
+        + ContractMessageDecoder,
+    AccountIdOf<Contract>: From<[u8; 32]>,
+    <EnvOf<Contract> as Environment>::Hash: From<[u8; 32]>,
+{
+    /// Creates a fuzzer for the contract created by `constructor`.
+    ///
+    /// By default at most 100 calls are performed per run.
+    pub fn new(constructor: fn() -> Contract) -> Self {
+        Self {
+            constructor,
+            invariants: Vec::new(),
+            max_calls: 100,
+        }
+    }
+
+    /// Adds an invariant that has to hold for the contract after every call.
+    pub fn invariant<F>(mut self, name: &'static str, holds: F) -> Self
+    where
+        F: Fn(&Contract) -> bool + 'static,
+    {
+        self.invariants.push(Invariant {
+            name,
+            holds: Box::new(holds),
+        });
+        self
+    }
+
+    /// Sets the maximum amount of calls performed per run.
+    pub fn max_calls(mut self, max_calls: usize) -> Self {
+        self.max_calls = max_calls;
+        self
+    }
+
+    /// Creates the contract and performs the `ops` on it.
+    ///
+    /// Returns the performed calls in order.
+    ///
+    /// # Panics
+    ///
+    /// If an invariant is violated.
+    #[allow(deprecated)]
+    pub fn run<const MESSAGES: usize>(&self, ops: &[FuzzOp]) -> Vec<FuzzCall>
+    where
+        Contract: ContractDispatchableMessages<MESSAGES>,
+    {
+        let caller_account = ink_env::test::callee::<EnvOf<Contract>>();
+        let contract_account = AccountIdOf::<Contract>::from(CONTRACT_ACCOUNT);
+        register_contract::<Contract>(CODE_HASH.into());
+        ink_env::test::set_code_hash::<EnvOf<Contract>>(
+            contract_account.clone(),
+            CODE_HASH.into(),
+        );
+        ink_env::test::set_callee::<EnvOf<Contract>>(contract_account.clone());
+        let contract = (self.constructor)();
+        push_spread_root::<Contract>(&contract, &<Contract as ContractRootKey>::ROOT_KEY);
+        ink_env::test::set_callee::<EnvOf<Contract>>(caller_account.clone());
+        self.check_invariants(&contract, &[]);
+
+        let mut calls = Vec::new();
+        for op in ops {
+            if MESSAGES == 0 || calls.len() >= self.max_calls {
+                break
+            }
+            let (message, args) = match op {
+                FuzzOp::Call { message, input } => (*message, &input[..]),
+                FuzzOp::AdvanceBlock => {
+                    ink_env::test::advance_block::<EnvOf<Contract>>();
+                    continue
+                }
+            };
+            let id = <Contract as ContractDispatchableMessages<MESSAGES>>::IDS
+                [usize::from(message) % MESSAGES];
+            let selector = id.to_be_bytes();
+            let result = build_call::<EnvOf<Contract>>()
+                .call_type(Call::new().callee(contract_account.clone()))
+                .exec_input(
+                    ExecutionInput::new(Selector::new(selector)).push_arg(RawInput(args)),
+                )
+                .returns::<()>()
+                .fire();
+            calls.push(FuzzCall {
+                selector,
+                input: args.to_vec(),
+                succeeded: result.is_ok(),
+            });
+
+            ink_env::test::set_callee::<EnvOf<Contract>>(contract_account.clone());
+            let contract =
+                pull_spread_root::<Contract>(&<Contract as ContractRootKey>::ROOT_KEY);
+            ink_env::test::set_callee::<EnvOf<Contract>>(caller_account.clone());
+            self.check_invariants(&contract, &calls[..]);
+        }
+        calls
+    }
+
+    /// Checks all invariants for the `contract` after the `calls` were performed.
+    fn check_invariants(&self, contract: &Contract, calls: &[FuzzCall]) {
+        for invariant in &self.invariants {
+            if !(invariant.holds)(contract) {
+                panic!(
+                    "invariant `{}` violated after calls: {:#?}",
+                    invariant.name, calls
+                )
+            }
+        }
+    }
+}
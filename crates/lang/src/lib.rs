@@ -18,6 +18,20 @@
 #[doc(hidden)]
 pub mod result_info;
 
+/// Returns the storage key reserved by ink! under the given `label`.
+///
+/// Some features of ink! keep their state in dedicated contract storage cells
+/// outside of the storage struct of the ink! smart contract, e.g. the owner of
+/// `#[ink::ownable]` or the lock of `#[ink(non_reentrant)]` messages. Their keys are
+/// the BLAKE-2 256-bit hashes of labels prefixed with `ink_lang::`, which makes
+/// collisions with the storage cells of the ink! smart contract and with other
+/// reserved keys practically impossible.
+macro_rules! reserved_key {
+    ($label:literal) => {
+        ::ink_primitives::Key::new(::ink_lang_macro::blake2x256!($label))
+    };
+}
+
 #[cfg_attr(not(feature = "show-codegen-docs"), doc(hidden))]
 pub mod codegen;
 
@@ -26,28 +40,267 @@ pub mod utils {
     // We want to expose this function without making users go through
     // the `codgen` module
     pub use super::codegen::initialize_contract;
+    #[cfg(feature = "std")]
+    pub use super::codegen::{
+        recorded_events,
+        register_contract,
+    };
 }
 
+pub mod access;
+#[cfg(feature = "ink-bench")]
+pub mod bench;
+pub mod commit_reveal;
+#[cfg(feature = "e2e-tests")]
+pub mod e2e;
+#[cfg(feature = "std")]
+pub mod fixtures;
+#[cfg(feature = "ink-fuzz")]
+pub mod fuzz;
+pub mod proxy;
 pub mod reflect;
 
 mod chain_extension;
+mod contract_error;
 mod contract_ref;
 mod env_access;
+mod trait_metadata;
 
 pub use self::{
     chain_extension::{
+        ChainExtensionFuncIds,
         ChainExtensionInstance,
         IsResultType,
     },
-    contract_ref::ToAccountId,
+    contract_error::ContractError,
+    contract_ref::{
+        CallOverrides,
+        FromCodeHash,
+        ToAccountId,
+        ToCodeHash,
+    },
     env_access::EnvAccess,
 };
+pub use ink_primitives::{
+    LangError,
+    MessageResult,
+};
+/// Appends a formatted string, prefixed with the source location of the invocation,
+/// to the `debug_message` buffer.
+///
+/// This expands to [`ink_env::debug_println!`] and therefore compiles to nothing unless
+/// the `ink-debug` feature is enabled, so release builds do not pay for the formatting.
+///
+/// # Example
+///
+/// ```
+/// # use ink_lang as ink;
+/// # ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+/// let balance = 100;
+/// ink::debug_println!("balance: {}", balance);
+///
+/// let recorded = ink_env::test::recorded_debug_messages()
+///     .into_iter()
+///     .collect::<Vec<_>>();
+/// assert!(recorded[0].ends_with("] balance: 100\n"));
+/// # Ok(())
+/// # }).unwrap();
+/// ```
+#[macro_export]
+macro_rules! debug_println {
+    () => (
+        ::ink_env::debug_println!("[{}:{}]", ::core::file!(), ::core::line!())
+    );
+    ($($arg:tt)*) => (
+        ::ink_env::debug_println!(
+            "[{}:{}] {}",
+            ::core::file!(),
+            ::core::line!(),
+            ::core::format_args!($($arg)*)
+        )
+    );
+}
+
+/// Asserts that the given ink! event has been emitted in the off-chain environment.
+///
+/// The event matches a recorded event if both its encoding and its topics are equal.
+/// Use [`utils::recorded_events`] in order to inspect the recorded events directly.
+///
+/// # Example
+///
+/// ```
+/// use ink_lang as ink;
+///
+/// #[ink::contract]
+/// mod flipper {
+///     #[ink(storage)]
+///     pub struct Flipper {
+///         value: bool,
+///     }
+///
+///     #[ink(event)]
+///     pub struct Flipped {
+///         #[ink(topic)]
+///         pub by: AccountId,
+///         pub value: bool,
+///     }
+///
+///     impl Flipper {
+///         #[ink(constructor)]
+///         pub fn new() -> Self {
+///             Self { value: false }
+///         }
+///
+///         #[ink(message)]
+///         pub fn flip(&mut self) {
+///             self.value = !self.value;
+///             self.env().emit_event(Flipped {
+///                 by: self.env().caller(),
+///                 value: self.value,
+///             });
+///         }
+///     }
+/// }
+///
+/// use flipper::{Flipper, Flipped};
+///
+/// # ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|accounts| {
+/// let mut flipper = Flipper::new();
+/// flipper.flip();
+/// ink::assert_emitted!(Flipped { by: accounts.alice, value: true });
+/// assert_eq!(ink::utils::recorded_events::<Flipper>().count(), 1);
+/// # Ok(())
+/// # }).unwrap();
+/// ```
+///
+/// # Panics
+///
+/// If the event has not been emitted or has been emitted with other topics.
+#[macro_export]
+macro_rules! assert_emitted {
+    ($event:expr $(,)?) => {
+        $crate::codegen::assert_emitted($event)
+    };
+}
+
+/// Asserts that the given ink! message call reverts with an error matching the pattern.
+///
+/// Messages returning `Err` revert their state on-chain. The macro accepts any
+/// pattern for the error, e.g. a unit variant or `Error::Custom(_)`.
+///
+/// # Example
+///
+/// ```
+/// use ink_lang as ink;
+///
+/// #[ink::contract]
+/// mod vault {
+///     #[ink(storage)]
+///     pub struct Vault {
+///         deposit: Balance,
+///     }
+///
+///     #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+///     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+///     pub enum Error {
+///         InsufficientDeposit { available: Balance },
+///     }
+///
+///     impl Vault {
+///         #[ink(constructor)]
+///         pub fn new(deposit: Balance) -> Self {
+///             Self { deposit }
+///         }
+///
+///         #[ink(message)]
+///         pub fn withdraw(&mut self, amount: Balance) -> Result<Balance, Error> {
+///             if amount > self.deposit {
+///                 return Err(Error::InsufficientDeposit {
+///                     available: self.deposit,
+///                 })
+///             }
+///             self.deposit -= amount;
+///             Ok(self.deposit)
+///         }
+///     }
+/// }
+///
+/// use vault::{Error, Vault};
+///
+/// # ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+/// let mut vault = Vault::new(100);
+/// ink::assert_revert!(
+///     vault.withdraw(101),
+///     Error::InsufficientDeposit { available: 100 }
+/// );
+/// let left = ink::assert_ok!(vault.withdraw(40));
+/// assert_eq!(left, 60);
+/// # Ok(())
+/// # }).unwrap();
+/// ```
+///
+/// # Panics
+///
+/// If the message call succeeds or fails with an error not matching the pattern.
+#[macro_export]
+macro_rules! assert_revert {
+    ($message:expr, $error:pat $(,)?) => {
+        match $message {
+            ::core::result::Result::Err($error) => {}
+            result => {
+                ::core::panic!(
+                    "assertion failed: `{}` reverts\n expected: Err({})\n    found: {:?}",
+                    ::core::stringify!($message),
+                    ::core::stringify!($error),
+                    result,
+                )
+            }
+        }
+    };
+}
+
+/// Asserts that the given ink! message call succeeds and evaluates to its `Ok` value.
+///
+/// See [`assert_revert!`] for an example.
+///
+/// # Panics
+///
+/// If the message call returns an error.
+#[macro_export]
+macro_rules! assert_ok {
+    ($message:expr $(,)?) => {
+        match $message {
+            ::core::result::Result::Ok(value) => value,
+            ::core::result::Result::Err(error) => {
+                ::core::panic!(
+                    "assertion failed: `{}` succeeds\n    found: Err({:?})",
+                    ::core::stringify!($message),
+                    error,
+                )
+            }
+        }
+    };
+}
+
+#[cfg(feature = "e2e-tests")]
+pub use ink_lang_macro::e2e_test;
 pub use ink_lang_macro::{
+    access_control,
     blake2x256,
     chain_extension,
     contract,
+    environment,
+    event,
+    export_impl,
+    include_impl,
+    keccak256,
+    ownable,
+    proxy,
+    scale_derive,
     selector_bytes,
     selector_id,
     test,
     trait_definition,
+    ChainExtensionErrorCode,
+    ContractError,
 };
@@ -0,0 +1,147 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Utilities backing the upgradeable proxy contracts generated by `#[ink::proxy]`.
+//!
+//! A proxy contract delegates all calls that do not match one of its own selectors
+//! to the code of its implementation contract. Since delegated calls operate on the
+//! storage of the proxy, the implementation code hash and the admin of the proxy are
+//! kept in dedicated contract storage cells that do not collide with the storage of
+//! the implementation contract.
+
+use ink_env::{
+    call::{
+        build_call,
+        DelegateCall,
+    },
+    Environment,
+};
+use ink_primitives::Key;
+
+/// The reserved storage key of the implementation code hash of a proxy contract.
+const IMPLEMENTATION_KEY: Key = reserved_key!("ink_lang::proxy::implementation");
+
+/// The reserved storage key of the admin of a proxy contract.
+const ADMIN_KEY: Key = reserved_key!("ink_lang::proxy::admin");
+
+/// Errors returned by the messages of proxy contracts generated by `#[ink::proxy]`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum ProxyError {
+    /// The caller is not the admin of the proxy contract.
+    CallerIsNotAdmin,
+}
+
+/// Returns the code hash of the implementation of the proxy contract.
+///
+/// # Panics
+///
+/// If the proxy contract has not yet been initialized.
+pub fn implementation<E>() -> E::Hash
+where
+    E: Environment,
+{
+    ink_env::get_contract_storage::<E::Hash>(&IMPLEMENTATION_KEY)
+        .expect("could not properly decode the proxy implementation")
+        .expect("encountered uninitialized proxy implementation")
+}
+
+/// Sets the code hash of the implementation of the proxy contract.
+pub fn set_implementation<E>(code_hash: &E::Hash)
+where
+    E: Environment,
+{
+    ink_env::set_contract_storage(&IMPLEMENTATION_KEY, code_hash)
+}
+
+/// Returns the admin of the proxy contract.
+///
+/// # Panics
+///
+/// If the proxy contract has not yet been initialized.
+pub fn admin<E>() -> E::AccountId
+where
+    E: Environment,
+{
+    ink_env::get_contract_storage::<E::AccountId>(&ADMIN_KEY)
+        .expect("could not properly decode the proxy admin")
+        .expect("encountered uninitialized proxy admin")
+}
+
+/// Sets the admin of the proxy contract.
+pub fn set_admin<E>(admin: &E::AccountId)
+where
+    E: Environment,
+{
+    ink_env::set_contract_storage(&ADMIN_KEY, admin)
+}
+
+/// Returns an error if the caller is not the admin of the proxy contract.
+pub fn ensure_admin<E>() -> Result<(), ProxyError>
+where
+    E: Environment,
+{
+    if ink_env::caller::<E>() != admin::<E>() {
+        return Err(ProxyError::CallerIsNotAdmin)
+    }
+    Ok(())
+}
+
+/// Delegates the current call to the implementation of the proxy contract.
+///
+/// # Note
+///
+/// The input of the current call is forwarded to the implementation and the call
+/// is a tail call. Therefore the output of the implementation is returned to the
+/// caller directly and this function never returns.
+///
+/// # Panics
+///
+/// If the delegated call fails.
+pub fn forward<E>() -> !
+where
+    E: Environment,
+{
+    let code_hash = implementation::<E>();
+    build_call::<E>()
+        .call_type(DelegateCall::new().code_hash(code_hash))
+        .forward_input()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ink_env::DefaultEnvironment;
+
+    #[test]
+    fn implementation_and_admin_work() {
+        ink_env::test::run_test::<DefaultEnvironment, _>(|accounts| {
+            let code_hash = [0x42; 32].into();
+            set_implementation::<DefaultEnvironment>(&code_hash);
+            set_admin::<DefaultEnvironment>(&accounts.bob);
+            assert_eq!(implementation::<DefaultEnvironment>(), code_hash);
+            assert_eq!(admin::<DefaultEnvironment>(), accounts.bob);
+            assert_eq!(
+                ensure_admin::<DefaultEnvironment>(),
+                Err(ProxyError::CallerIsNotAdmin)
+            );
+            ink_env::test::ExecutionContext::<DefaultEnvironment>::build()
+                .caller(accounts.bob)
+                .apply();
+            assert_eq!(ensure_admin::<DefaultEnvironment>(), Ok(()));
+            Ok(())
+        })
+        .unwrap()
+    }
+}
@@ -50,6 +50,50 @@ pub trait ContractName {
     const NAME: &'static str;
 }
 
+/// Stores the version of the ink! smart contract.
+///
+/// # Note
+///
+/// The version is the `version` of the package that defines the ink! smart contract
+/// as specified in its `Cargo.toml`. It is also available on the contract reference
+/// so that users of a contract reference can check which interface revision it targets.
+///
+/// # Usage
+///
+/// ```
+/// use ink_lang as ink;
+///
+/// #[ink::contract]
+/// pub mod contract {
+///     #[ink(storage)]
+///     pub struct Contract {}
+///
+///     impl Contract {
+///         #[ink(constructor)]
+///         pub fn constructor() -> Self { Self {} }
+///
+///         #[ink(message)]
+///         pub fn message(&self) {}
+///     }
+/// }
+///
+/// use contract::{Contract, ContractRef};
+///
+/// # use ink_lang::reflect::ContractVersion;
+/// assert_eq!(
+///     <Contract as ContractVersion>::VERSION,
+///     env!("CARGO_PKG_VERSION"),
+/// );
+/// assert_eq!(
+///     <ContractRef as ContractVersion>::VERSION,
+///     <Contract as ContractVersion>::VERSION,
+/// );
+/// ```
+pub trait ContractVersion {
+    /// The version of the ink! smart contract.
+    const VERSION: &'static str;
+}
+
 /// Stores the used host environment type of the ink! smart contract.
 ///
 /// # Note
@@ -292,6 +292,11 @@ pub trait DispatchableMessageInfo<const ID: u32> {
 ///             Contract {}
 ///         }
 ///
+///         #[ink(constructor)]
+///         pub fn constructor3(input: bool) -> Result<Self, u8> {
+///             Ok(Contract {})
+///         }
+///
 ///         #[ink(message)]
 ///         pub fn message(&self) {}
 ///     }
@@ -304,12 +309,12 @@ pub trait DispatchableMessageInfo<const ID: u32> {
 /// /// # Note
 /// ///
 /// /// The `In` and `Out` generic parameters describe the input and output types.
-/// fn assert_constructor_info<In, const ID: u32>(
+/// fn assert_constructor_info<In, Out, const ID: u32>(
 ///     selector: [u8; 4],
 ///     label: &str,
 /// )
 /// where
-///     Contract: DispatchableConstructorInfo<{ID}, Input = In>,
+///     Contract: DispatchableConstructorInfo<{ID}, Input = In, Output = Out>,
 /// {
 ///     assert_eq!(
 ///         <Contract as DispatchableConstructorInfo<{ID}>>::SELECTOR,
@@ -322,22 +327,30 @@ pub trait DispatchableMessageInfo<const ID: u32> {
 /// }
 ///
 /// fn main() {
-///     assert_constructor_info::<(), {selector_id!("constructor1")}>(
+///     assert_constructor_info::<(), Contract, {selector_id!("constructor1")}>(
 ///         selector_bytes!("constructor1"), "constructor1"
 ///     );
-///     assert_constructor_info::<(i32, i64), 0xC0DECAFE_u32>(
+///     assert_constructor_info::<(i32, i64), Contract, 0xC0DECAFE_u32>(
 ///         [0xC0, 0xDE, 0xCA, 0xFE], "constructor2"
 ///     );
+///     assert_constructor_info::<bool, Result<Contract, u8>, {selector_id!("constructor3")}>(
+///         selector_bytes!("constructor3"), "constructor3"
+///     );
 /// }
 /// ```
 pub trait DispatchableConstructorInfo<const ID: u32> {
     /// Reflects the input types of the dispatchable ink! constructor.
     type Input;
+    /// Reflects the output type of the dispatchable ink! constructor.
+    ///
+    /// This is either the ink! storage struct type or `Result<Storage, E>`
+    /// for fallible ink! constructors.
+    type Output;
     /// The ink! storage struct type.
     type Storage;
 
     /// The closure that can be used to dispatch into the dispatchable ink! constructor.
-    const CALLABLE: fn(Self::Input) -> Self::Storage;
+    const CALLABLE: fn(Self::Input) -> Self::Output;
 
     /// Yields `true` if the dispatchable ink! constructor is payable.
     const PAYABLE: bool;
@@ -532,6 +545,8 @@ pub enum DispatchError {
     CouldNotReadInput,
     /// Invalidly paid an unpayable dispatchable.
     PaidUnpayableMessage,
+    /// Reentered a non-reentrant dispatchable while it was executing.
+    ReentrantCall,
 }
 
 impl Display for DispatchError {
@@ -550,6 +565,7 @@ impl DispatchError {
             Self::InvalidParameters => "unable to decode input",
             Self::CouldNotReadInput => "could not read input",
             Self::PaidUnpayableMessage => "paid an unpayable message",
+            Self::ReentrantCall => "reentered a non-reentrant message",
         }
     }
 }
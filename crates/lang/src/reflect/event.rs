@@ -50,3 +50,47 @@ pub trait ContractEventBase {
     /// The generated base event enum.
     type Type;
 }
+
+/// Defines the ink! smart contract an ink! event belongs to.
+///
+/// This allows to convert an ink! event into the base event type of its contract,
+/// e.g. in order to compare it with the events recorded in off-chain tests.
+///
+/// # Note
+///
+/// This trait is automatically implemented for all ink! events by the ink! codegen.
+///
+/// # Usage
+///
+/// ```
+/// use ink_lang as ink;
+///
+/// #[ink::contract]
+/// pub mod contract {
+///     #[ink(storage)]
+///     pub struct Contract {}
+///
+///     #[ink(event)]
+///     pub struct Event1 {}
+///
+///     impl Contract {
+///         #[ink(constructor)]
+///         pub fn constructor() -> Self { Self {} }
+///
+///         #[ink(message)]
+///         pub fn message(&self) {}
+///     }
+/// }
+///
+/// use contract::{Contract, Event1};
+/// # use ink_lang::reflect::ContractEvent;
+///
+/// const _: fn() = || {
+///     fn assert_contract<E: ContractEvent<Contract = Contract>>() {}
+///     assert_contract::<Event1>();
+/// };
+/// ```
+pub trait ContractEvent {
+    /// The ink! smart contract that defines the event.
+    type Contract: ContractEventBase;
+}
@@ -33,6 +33,7 @@ pub use self::{
         ContractEnv,
         ContractName,
         ContractReference,
+        ContractVersion,
     },
     dispatch::{
         ContractAmountDispatchables,
@@ -46,7 +47,10 @@ pub use self::{
         DispatchableMessageInfo,
         ExecuteDispatchable,
     },
-    event::ContractEventBase,
+    event::{
+        ContractEvent,
+        ContractEventBase,
+    },
     trait_def::{
         TraitDefinitionRegistry,
         TraitInfo,
@@ -151,4 +151,9 @@ pub trait TraitInfo {
     ///
     /// This is just for convenience.
     const NAME: &'static str;
+
+    /// The namespace of the ink! trait definition.
+    ///
+    /// This is empty if the ink! trait definition has no namespace.
+    const NAMESPACE: &'static str;
 }
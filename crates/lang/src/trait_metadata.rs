@@ -0,0 +1,54 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Returns the interface-only ink! metadata of an ink! trait definition.
+///
+/// The returned [`InkInterface`](ink_metadata::InkInterface) describes the messages,
+/// selectors and types of the ink! trait definition without a concrete contract
+/// implementing it. This allows crates that only provide ink! trait definitions,
+/// e.g. for standards, to publish their canonical machine-readable interface.
+///
+/// # Example
+///
+/// ```ignore
+/// use ink_lang as ink;
+///
+/// /// Flips a boolean value.
+/// #[ink::trait_definition(metadata = true)]
+/// pub trait Flip {
+///     #[ink(message)]
+///     fn flip(&mut self);
+///
+///     #[ink(message)]
+///     fn get(&self) -> bool;
+/// }
+///
+/// let interface = ink::trait_metadata!(dyn Flip);
+/// println!("{}", serde_json::to_string_pretty(&interface).unwrap());
+/// ```
+///
+/// # Note
+///
+/// The ink! metadata of ink! trait definitions is only generated if they are configured
+/// with `metadata = true` and the crate defining them is compiled with its `std` feature
+/// and depends on `ink_metadata`. Using any other trait fails to compile.
+#[macro_export]
+macro_rules! trait_metadata {
+    ( dyn $trait_path:path $(,)? ) => {
+        ::ink_metadata::InkInterface::new(<<$crate::reflect::TraitDefinitionRegistry<
+            ::ink_env::DefaultEnvironment,
+        > as $trait_path>::__ink_TraitInfo as ::ink_metadata::TraitMetadata>::trait_spec(
+        ))
+    };
+}
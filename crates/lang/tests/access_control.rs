@@ -0,0 +1,165 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ink_lang as ink;
+
+#[ink::ownable]
+#[ink::access_control]
+#[ink::contract]
+mod pausable {
+    use ink_lang::access::{
+        AccessControlError,
+        OwnableError,
+        RoleType,
+    };
+
+    pub const PAUSER: RoleType = 1;
+
+    #[ink(storage)]
+    pub struct Pausable {
+        paused: bool,
+    }
+
+    impl Pausable {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self { paused: false }
+        }
+
+        #[ink(message, guard = "only_pauser")]
+        pub fn pause(&mut self) -> Result<(), AccessControlError> {
+            self.paused = true;
+            Ok(())
+        }
+
+        #[ink(message, guard = "only_owner")]
+        pub fn unpause(&mut self) -> Result<(), OwnableError> {
+            self.paused = false;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn is_paused(&self) -> bool {
+            self.paused
+        }
+
+        pub fn only_pauser(&self) -> Result<(), AccessControlError> {
+            self.ensure_role(PAUSER)
+        }
+    }
+}
+
+use ink_env::{
+    test::ExecutionContext,
+    AccountId,
+    DefaultEnvironment,
+};
+use ink_lang::access::{
+    AccessControlError,
+    OwnableError,
+    ADMIN_ROLE,
+};
+use pausable::{
+    Pausable,
+    PAUSER,
+};
+
+fn set_caller(caller: AccountId) {
+    ExecutionContext::<DefaultEnvironment>::build()
+        .caller(caller)
+        .apply();
+}
+
+#[test]
+fn only_owner_rejects_other_callers() {
+    ink_env::test::run_test::<DefaultEnvironment, _>(|accounts| {
+        let mut pausable = Pausable::new();
+        assert_eq!(pausable.owner(), Some(accounts.alice));
+        set_caller(accounts.bob);
+        assert_eq!(
+            pausable.transfer_ownership(Some(accounts.bob)),
+            Err(OwnableError::CallerIsNotOwner)
+        );
+        assert_eq!(pausable.unpause(), Err(OwnableError::CallerIsNotOwner));
+        assert_eq!(pausable.owner(), Some(accounts.alice));
+        Ok(())
+    })
+    .unwrap()
+}
+
+#[test]
+fn only_owner_accepts_owner() {
+    ink_env::test::run_test::<DefaultEnvironment, _>(|accounts| {
+        let mut pausable = Pausable::new();
+        assert_eq!(pausable.unpause(), Ok(()));
+        assert_eq!(pausable.transfer_ownership(Some(accounts.bob)), Ok(()));
+        assert_eq!(pausable.owner(), Some(accounts.bob));
+        // The previous owner lost its privileges to the new owner.
+        assert_eq!(pausable.unpause(), Err(OwnableError::CallerIsNotOwner));
+        set_caller(accounts.bob);
+        assert_eq!(pausable.unpause(), Ok(()));
+        Ok(())
+    })
+    .unwrap()
+}
+
+#[test]
+fn grant_role_rejects_non_admin() {
+    ink_env::test::run_test::<DefaultEnvironment, _>(|accounts| {
+        let mut pausable = Pausable::new();
+        set_caller(accounts.bob);
+        assert_eq!(
+            pausable.grant_role(PAUSER, accounts.bob),
+            Err(AccessControlError::MissingRole)
+        );
+        assert_eq!(
+            pausable.revoke_role(ADMIN_ROLE, accounts.alice),
+            Err(AccessControlError::MissingRole)
+        );
+        assert!(!pausable.has_role(PAUSER, accounts.bob));
+        assert!(pausable.has_role(ADMIN_ROLE, accounts.alice));
+        Ok(())
+    })
+    .unwrap()
+}
+
+#[test]
+fn role_guard_rejects_caller_without_role() {
+    ink_env::test::run_test::<DefaultEnvironment, _>(|accounts| {
+        let mut pausable = Pausable::new();
+        // Not even the admin may pause without the pauser role.
+        assert_eq!(pausable.pause(), Err(AccessControlError::MissingRole));
+        assert_eq!(pausable.grant_role(PAUSER, accounts.bob), Ok(()));
+        set_caller(accounts.charlie);
+        assert_eq!(pausable.pause(), Err(AccessControlError::MissingRole));
+        assert!(!pausable.is_paused());
+        Ok(())
+    })
+    .unwrap()
+}
+
+#[test]
+fn role_guard_accepts_caller_with_role() {
+    ink_env::test::run_test::<DefaultEnvironment, _>(|accounts| {
+        let mut pausable = Pausable::new();
+        assert_eq!(pausable.grant_role(PAUSER, accounts.bob), Ok(()));
+        set_caller(accounts.bob);
+        assert_eq!(pausable.pause(), Ok(()));
+        assert!(pausable.is_paused());
+        assert_eq!(pausable.renounce_role(PAUSER), Ok(()));
+        assert_eq!(pausable.pause(), Err(AccessControlError::MissingRole));
+        Ok(())
+    })
+    .unwrap()
+}
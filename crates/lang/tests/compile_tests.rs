@@ -19,6 +19,9 @@ fn ui_tests() {
     t.pass("tests/ui/blake2b/pass/*.rs");
     t.compile_fail("tests/ui/blake2b/fail/*.rs");
 
+    t.pass("tests/ui/keccak256/pass/*.rs");
+    t.compile_fail("tests/ui/keccak256/fail/*.rs");
+
     t.pass("tests/ui/selector_id/pass/*.rs");
     t.compile_fail("tests/ui/selector_id/fail/*.rs");
 
@@ -32,4 +35,7 @@ fn ui_tests() {
     t.compile_fail("tests/ui/trait_def/fail/*.rs");
 
     t.pass("tests/ui/chain_extension/E-01-simple.rs");
+    t.pass("tests/ui/chain_extension/E-02-combined.rs");
+    t.pass("tests/ui/chain_extension/E-03-error-code.rs");
+    t.pass("tests/ui/chain_extension/E-04-metadata.rs");
 }
@@ -0,0 +1,98 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ink_lang as ink;
+use ink_lang::ContractError as _;
+use scale::{
+    Decode as _,
+    Encode as _,
+};
+use scale_info::{
+    form::PortableForm,
+    IntoPortable as _,
+    Registry,
+    TypeDef,
+};
+
+/// The errors of the contract.
+#[derive(Debug, PartialEq, Eq, ink::ContractError)]
+pub enum Error {
+    /// The balance of the caller is insufficient.
+    #[ink(error_code = 3)]
+    InsufficientBalance { required: u128 },
+    #[ink(error_code = 1)]
+    Custom(u32, bool),
+    #[ink(error_code = 2, env_error)]
+    Env,
+}
+
+#[test]
+fn encoding_uses_error_codes() {
+    let errors = [
+        Error::InsufficientBalance { required: 42 },
+        Error::Custom(7, true),
+        Error::Env,
+    ];
+    for error in errors {
+        let encoded = error.encode();
+        assert_eq!(encoded[0], error.error_code());
+        assert_eq!(encoded.len(), error.size_hint());
+        assert_eq!(Error::decode(&mut &encoded[..]), Ok(error));
+    }
+    assert_eq!(Error::Custom(7, true).encode(), (1u8, 7u32, true).encode());
+    assert!(Error::decode(&mut &[0u8][..]).is_err());
+}
+
+#[test]
+fn error_codes_work() {
+    assert_eq!(
+        Error::ERROR_CODES,
+        &[(3, "InsufficientBalance"), (1, "Custom"), (2, "Env")]
+    );
+    assert_eq!(Error::error_name(1), Some("Custom"));
+    assert_eq!(Error::error_name(0), None);
+}
+
+#[test]
+fn from_env_errors_works() {
+    assert_eq!(Error::from(ink_env::Error::TransferFailed), Error::Env);
+    assert_eq!(
+        Error::from(ink_lang::LangError::CouldNotReadInput),
+        Error::Env
+    );
+}
+
+#[test]
+fn type_info_records_error_codes() {
+    let ty = scale_info::meta_type::<Error>()
+        .type_info()
+        .into_portable(&mut Registry::new());
+    assert_eq!(ty.path.ident(), Some("Error".to_string()));
+    let variants = match ty.type_def {
+        TypeDef::<PortableForm>::Variant(variants) => variants.variants,
+        _ => panic!("expected a variant type definition for the contract error"),
+    };
+    let variants = variants
+        .iter()
+        .map(|variant| (variant.name.clone(), variant.index, variant.fields.len()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        variants,
+        vec![
+            ("InsufficientBalance".to_string(), 3, 1),
+            ("Custom".to_string(), 1, 2),
+            ("Env".to_string(), 2, 0),
+        ]
+    );
+}
@@ -0,0 +1,144 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "e2e-tests")]
+
+use ink_env::{
+    call::{
+        ExecutionInput,
+        Selector,
+    },
+    DefaultEnvironment,
+};
+use ink_lang as ink;
+use ink_lang::e2e::{
+    Client as _,
+    OffChainClient,
+};
+
+#[ink::contract]
+mod piggy_bank {
+    #[ink(storage)]
+    pub struct PiggyBank {
+        deposits: u32,
+    }
+
+    #[ink(event)]
+    pub struct Deposited {
+        #[ink(topic)]
+        from: AccountId,
+        value: Balance,
+    }
+
+    impl PiggyBank {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self { deposits: 0 }
+        }
+
+        #[ink(message, payable)]
+        pub fn deposit(&mut self) {
+            self.deposits += 1;
+            self.env().emit_event(Deposited {
+                from: self.env().caller(),
+                value: self.env().transferred_value(),
+            });
+        }
+
+        #[ink(message)]
+        pub fn deposits(&self) -> u32 {
+            self.deposits
+        }
+    }
+}
+
+fn message(selector: [u8; 4]) -> ExecutionInput<ink_env::call::utils::EmptyArgumentList> {
+    ExecutionInput::new(Selector::new(selector))
+}
+
+#[ink::e2e_test]
+async fn instantiate_and_call_works(
+    mut client: OffChainClient,
+) -> Result<(), ink_env::Error> {
+    let accounts = ink_env::test::default_accounts::<DefaultEnvironment>();
+    let code_hash = client.upload::<piggy_bank::PiggyBank>();
+    let piggy_bank = client
+        .instantiate(
+            &accounts.alice,
+            code_hash,
+            0,
+            message(ink::selector_bytes!("new")),
+            &[],
+        )
+        .await?
+        .account_id;
+
+    let bob_balance = client.balance(&accounts.bob).await?;
+    let deposited = client
+        .call::<_, ()>(
+            &accounts.bob,
+            &piggy_bank,
+            10,
+            message(ink::selector_bytes!("deposit")),
+        )
+        .await?;
+    assert_eq!(deposited.events.len(), 1);
+    assert_eq!(client.balance(&accounts.bob).await?, bob_balance - 10);
+    assert_eq!(client.balance(&piggy_bank).await?, 10);
+
+    let deposits = client
+        .call::<_, u32>(
+            &accounts.alice,
+            &piggy_bank,
+            0,
+            message(ink::selector_bytes!("deposits")),
+        )
+        .await?;
+    assert_eq!(deposits.return_value, 1);
+    assert!(deposits.events.is_empty());
+    Ok(())
+}
+
+#[ink::e2e_test]
+async fn failed_calls_are_errors(mut client: OffChainClient) {
+    let accounts = ink_env::test::default_accounts::<DefaultEnvironment>();
+    let code_hash = client.upload::<piggy_bank::PiggyBank>();
+    let piggy_bank = client
+        .instantiate(
+            &accounts.alice,
+            code_hash,
+            0,
+            message(ink::selector_bytes!("new")),
+            &[],
+        )
+        .await
+        .expect("instantiation must succeed")
+        .account_id;
+
+    // The message is not payable.
+    let result = client
+        .call::<_, u32>(
+            &accounts.alice,
+            &piggy_bank,
+            10,
+            message(ink::selector_bytes!("deposits")),
+        )
+        .await;
+    assert!(result.is_err());
+    // No message has this selector.
+    let result = client
+        .call::<_, ()>(&accounts.alice, &piggy_bank, 0, message([0xFF; 4]))
+        .await;
+    assert!(result.is_err());
+}
@@ -0,0 +1,148 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "ink-fuzz")]
+
+use ink_lang as ink;
+use ink_lang::fuzz::{
+    FuzzOp,
+    Fuzzer,
+};
+use std::panic::{
+    catch_unwind,
+    AssertUnwindSafe,
+};
+
+#[ink::contract]
+mod counter {
+    #[ink(storage)]
+    pub struct Counter {
+        value: u32,
+    }
+
+    impl Counter {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self { value: 0 }
+        }
+
+        #[ink(constructor)]
+        pub fn overflowing() -> Self {
+            Self { value: 100 }
+        }
+
+        #[ink(message)]
+        pub fn inc(&mut self, by: u8) {
+            self.value += by as u32;
+        }
+
+        #[ink(message)]
+        pub fn reset(&mut self, reset: bool) {
+            if reset {
+                self.value = 0;
+            }
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> u32 {
+            self.value
+        }
+    }
+}
+
+use counter::Counter;
+
+/// Returns the operation calling the message at `message` with `input`.
+fn call(message: u8, input: &[u8]) -> FuzzOp {
+    FuzzOp::Call {
+        message,
+        input: input.to_vec(),
+    }
+}
+
+/// Runs the fuzzer with an invariant bounding the counter by `10` and returns the
+/// message of the reported violation.
+fn violation(constructor: fn() -> Counter, ops: &[FuzzOp]) -> Option<String> {
+    let mut violation = None;
+    ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            Fuzzer::new(constructor)
+                .invariant("below ten", |counter: &Counter| counter.get() < 10)
+                .run(ops)
+        }));
+        violation = result.err().map(|panic| {
+            panic
+                .downcast_ref::<String>()
+                .cloned()
+                .expect("violations are reported via formatted panic messages")
+        });
+        Ok(())
+    })
+    .unwrap();
+    violation
+}
+
+#[test]
+fn invariant_holding_is_not_reported() {
+    let ops = [call(0, &[5]), call(1, &[0]), call(0, &[4])];
+    assert_eq!(violation(Counter::new, &ops), None);
+}
+
+#[test]
+fn violated_invariant_is_reported_with_calls() {
+    let ops = [call(0, &[5]), call(0, &[5]), call(0, &[5])];
+    let violation = violation(Counter::new, &ops)
+        .expect("the third increment violates the invariant");
+    assert!(violation.starts_with("invariant `below ten` violated after calls"));
+    // Only the calls up to the violation are reported.
+    assert_eq!(violation.matches("selector").count(), 2);
+    assert!(violation.contains("succeeded: true"));
+}
+
+#[test]
+fn violated_invariant_after_construction_is_reported() {
+    let violation = violation(Counter::overflowing, &[call(0, &[5])])
+        .expect("the constructor violates the invariant");
+    assert_eq!(violation, "invariant `below ten` violated after calls: []");
+}
+
+#[test]
+fn undecodable_arguments_fail_the_call() {
+    ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+        // Every message is called with the invalid encoding of a `bool`.
+        let ops = [call(0, &[2]), call(1, &[2]), call(2, &[2])];
+        let calls = Fuzzer::new(Counter::new).run(&ops);
+        assert_eq!(calls.len(), 3);
+        let failed = calls
+            .iter()
+            .filter(|call| !call.succeeded)
+            .map(|call| call.selector)
+            .collect::<Vec<_>>();
+        assert_eq!(failed, [ink::selector_bytes!("reset")]);
+        Ok(())
+    })
+    .unwrap()
+}
+
+#[test]
+fn advancing_blocks_is_not_reported_as_call() {
+    ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+        let ops = [FuzzOp::AdvanceBlock, call(2, &[]), FuzzOp::AdvanceBlock];
+        let calls = Fuzzer::new(Counter::new).run(&ops);
+        assert_eq!(calls.len(), 1);
+        assert_eq!(ink_env::block_number::<ink_env::DefaultEnvironment>(), 2);
+        Ok(())
+    })
+    .unwrap()
+}
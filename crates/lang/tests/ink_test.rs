@@ -0,0 +1,68 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{
+        Context,
+        Poll,
+    },
+};
+use ink_lang as ink;
+
+#[ink::environment(balance = u64, block_number = u64)]
+pub enum MyEnvironment {}
+
+/// A future that is ready after it has been polled once.
+struct YieldOnce(bool);
+
+impl Future for YieldOnce {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        if self.0 {
+            return Poll::Ready(())
+        }
+        self.0 = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+#[ink::test(env = crate::MyEnvironment)]
+fn custom_env_works() {
+    let balance: u64 = ink_env::balance::<MyEnvironment>();
+    assert_eq!(balance, 1_000_000);
+    assert_eq!(ink_env::caller::<MyEnvironment>(), [0x01; 32].into());
+}
+
+#[ink::test]
+async fn async_works() {
+    YieldOnce(false).await;
+    assert_eq!(
+        ink_env::caller::<ink_env::DefaultEnvironment>(),
+        [0x01; 32].into()
+    );
+}
+
+#[ink::test(env = MyEnvironment)]
+async fn async_with_result_works() -> Result<(), ink_env::Error> {
+    YieldOnce(false).await;
+    let balance = ink_env::test::get_account_balance::<ink_env::DefaultEnvironment>(
+        [0x02; 32].into(),
+    )?;
+    assert_eq!(balance, 1_000);
+    Ok(())
+}
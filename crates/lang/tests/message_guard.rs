@@ -0,0 +1,93 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ink_lang as ink;
+
+#[ink::contract]
+mod guarded {
+    #[ink(storage)]
+    pub struct Guarded {
+        owner: AccountId,
+        value: bool,
+    }
+
+    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        NotOwner,
+    }
+
+    impl Guarded {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self {
+                owner: Self::env().caller(),
+                value: false,
+            }
+        }
+
+        #[ink(message, guard = "only_owner")]
+        pub fn flip(&mut self) -> Result<(), Error> {
+            self.value = !self.value;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> bool {
+            self.value
+        }
+
+        fn only_owner(&self) -> Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner)
+            }
+            Ok(())
+        }
+    }
+}
+
+use guarded::{
+    Error,
+    Guarded,
+};
+use ink_env::{
+    test::ExecutionContext,
+    DefaultEnvironment,
+};
+
+#[test]
+fn guard_accepts_authorized_caller() {
+    ink_env::test::run_test::<DefaultEnvironment, _>(|_| {
+        let mut guarded = Guarded::new();
+        assert_eq!(guarded.flip(), Ok(()));
+        assert!(guarded.get());
+        Ok(())
+    })
+    .unwrap()
+}
+
+#[test]
+fn guard_rejects_unauthorized_caller() {
+    ink_env::test::run_test::<DefaultEnvironment, _>(|accounts| {
+        let mut guarded = Guarded::new();
+        ExecutionContext::<DefaultEnvironment>::build()
+            .caller(accounts.bob)
+            .apply();
+        assert_eq!(guarded.flip(), Err(Error::NotOwner));
+        // The body of the message must not run if its guard fails.
+        assert!(!guarded.get());
+        Ok(())
+    })
+    .unwrap()
+}
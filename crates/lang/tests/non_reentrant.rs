@@ -0,0 +1,122 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ink_lang as ink;
+
+#[ink::contract]
+mod guard {
+    use ink_env::{
+        call::{
+            build_call,
+            Call,
+            ExecutionInput,
+            Selector,
+        },
+        CallFlags,
+    };
+
+    #[ink(storage)]
+    pub struct Guard {}
+
+    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Failed,
+    }
+
+    impl Guard {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self {}
+        }
+
+        /// Returns whether reentering this message succeeded if `reenter` is set.
+        #[ink(message, non_reentrant)]
+        pub fn guarded(&mut self, reenter: bool) -> bool {
+            !reenter || self.reenter(ink_lang::selector_bytes!("guarded"))
+        }
+
+        /// Returns whether reentering this message succeeded if `reenter` is set.
+        #[ink(message)]
+        pub fn unguarded(&mut self, reenter: bool) -> bool {
+            !reenter || self.reenter(ink_lang::selector_bytes!("unguarded"))
+        }
+
+        /// Returns whether calling the reentrant message from within a
+        /// non-reentrant message succeeded.
+        #[ink(message, non_reentrant)]
+        pub fn guarded_calls_unguarded(&mut self) -> bool {
+            self.reenter(ink_lang::selector_bytes!("unguarded"))
+        }
+
+        #[ink(message, non_reentrant)]
+        pub fn fail(&mut self) -> Result<(), Error> {
+            Err(Error::Failed)
+        }
+
+        /// Calls the message with `selector` of this contract without reentering it again.
+        fn reenter(&self, selector: [u8; 4]) -> bool {
+            build_call::<Environment>()
+                .call_type(Call::new().callee(self.env().account_id()))
+                .call_flags(CallFlags::default().set_allow_reentry(true))
+                .exec_input(ExecutionInput::new(Selector::new(selector)).push_arg(false))
+                .returns::<bool>()
+                .fire()
+                == Ok(true)
+        }
+    }
+}
+
+use guard::{
+    Guard,
+    GuardRef,
+};
+use ink_env::DefaultEnvironment;
+
+fn instantiate() -> GuardRef {
+    let code_hash = [0x01; 32].into();
+    ink_lang::utils::register_contract::<Guard>(code_hash);
+    GuardRef::new()
+        .code_hash(code_hash)
+        .endowment(0)
+        .salt_bytes([])
+        .instantiate()
+        .expect("instantiation must succeed")
+}
+
+#[test]
+fn reentering_non_reentrant_message_is_denied() {
+    ink_env::test::run_test::<DefaultEnvironment, _>(|_| {
+        let mut guard = instantiate();
+        assert!(guard.guarded(false));
+        assert!(!guard.guarded(true));
+        // Only non-reentrant messages take the lock into account.
+        assert!(guard.unguarded(true));
+        assert!(guard.guarded_calls_unguarded());
+        Ok(())
+    })
+    .unwrap()
+}
+
+#[test]
+fn lock_is_released_after_err_return() {
+    ink_env::test::run_test::<DefaultEnvironment, _>(|_| {
+        let mut guard = instantiate();
+        assert_eq!(guard.fail(), Err(guard::Error::Failed));
+        assert!(guard.guarded(false));
+        assert!(!guard.guarded(true));
+        Ok(())
+    })
+    .unwrap()
+}
@@ -0,0 +1,68 @@
+use ink_env::Environment;
+use ink_lang as ink;
+
+#[ink::chain_extension]
+pub trait Random {
+    type ErrorCode = ExtensionError;
+
+    #[ink(extension = 1, returns_result = false, handle_status = false)]
+    fn random(subject: [u8; 32]) -> [u8; 32];
+}
+
+#[ink::chain_extension]
+pub trait Time {
+    type ErrorCode = ExtensionError;
+
+    #[ink(extension = 2, returns_result = false, handle_status = false)]
+    fn now() -> u64;
+}
+
+#[derive(scale::Encode, scale::Decode, scale_info::TypeInfo)]
+pub struct ExtensionError;
+
+impl ink_env::chain_extension::FromStatusCode for ExtensionError {
+    fn from_status_code(status_code: u32) -> Result<(), Self> {
+        match status_code {
+            0 => Ok(()),
+            _ => Err(Self),
+        }
+    }
+}
+
+pub enum CustomEnvironment {}
+
+impl Environment for CustomEnvironment {
+    const MAX_EVENT_TOPICS: usize =
+        <ink_env::DefaultEnvironment as Environment>::MAX_EVENT_TOPICS;
+
+    type AccountId = <ink_env::DefaultEnvironment as Environment>::AccountId;
+    type Balance = <ink_env::DefaultEnvironment as Environment>::Balance;
+    type Hash = <ink_env::DefaultEnvironment as Environment>::Hash;
+    type BlockNumber = <ink_env::DefaultEnvironment as Environment>::BlockNumber;
+    type Timestamp = <ink_env::DefaultEnvironment as Environment>::Timestamp;
+
+    type ChainExtension = (Random, Time);
+}
+
+#[ink::contract(env = crate::CustomEnvironment)]
+mod my_contract {
+    #[ink(storage)]
+    pub struct MyContract {}
+
+    impl MyContract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self {}
+        }
+
+        #[ink(message)]
+        pub fn timed_random(&self) -> [u8; 32] {
+            let (random, time) = self.env().extension();
+            let mut subject = [0x00; 32];
+            subject[..8].copy_from_slice(&time.now().to_le_bytes());
+            random.random(subject)
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,46 @@
+use ink_env::chain_extension::FromStatusCode;
+use ink_lang as ink;
+
+#[ink::chain_extension]
+pub trait RuntimeReadWrite {
+    type ErrorCode = ReadWriteErrorCode;
+
+    /// Reads from runtime storage.
+    #[ink(extension = 1, returns_result = false)]
+    fn read(key: &[u8]) -> Vec<u8>;
+}
+
+/// The shared error code for the read write chain extension.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    scale::Encode,
+    scale::Decode,
+    scale_info::TypeInfo,
+    ink::ChainExtensionErrorCode,
+)]
+pub enum ReadWriteErrorCode {
+    #[ink(status_code = 1)]
+    InvalidKey,
+    #[ink(status_code = 2)]
+    CannotWriteToKey,
+    #[ink(status_code = 3)]
+    CannotReadFromKey,
+    #[ink(catch_all)]
+    Unknown,
+}
+
+fn main() {
+    assert_eq!(ReadWriteErrorCode::from_status_code(0), Ok(()));
+    assert_eq!(
+        ReadWriteErrorCode::from_status_code(3),
+        Err(ReadWriteErrorCode::CannotReadFromKey)
+    );
+    assert_eq!(
+        ReadWriteErrorCode::from_status_code(4),
+        Err(ReadWriteErrorCode::Unknown)
+    );
+}
@@ -0,0 +1,97 @@
+use ink_env::Environment;
+use ink_lang as ink;
+use ink_metadata::{
+    ChainExtensionMetadata,
+    ChainExtensionsMetadata,
+    ErrorCodeMetadata,
+    StatusCodeSpec,
+};
+
+/// Provides randomness.
+#[ink::chain_extension]
+pub trait FetchRandom {
+    type ErrorCode = RandomReadErr;
+
+    /// Fetches randomness for the given subject.
+    #[ink(extension = 1101, returns_result = false)]
+    fn fetch_random(subject: [u8; 32]) -> [u8; 32];
+}
+
+#[ink::chain_extension]
+pub trait Time {
+    type ErrorCode = TimeErr;
+
+    #[ink(extension = 2, returns_result = false, handle_status = false)]
+    fn now() -> u64;
+}
+
+#[derive(
+    Debug,
+    PartialEq,
+    Eq,
+    scale::Encode,
+    scale::Decode,
+    scale_info::TypeInfo,
+    ink::ChainExtensionErrorCode,
+)]
+pub enum RandomReadErr {
+    #[ink(status_code = 1)]
+    FailGetRandomSource,
+    #[ink(catch_all)]
+    Unknown(u32),
+}
+
+/// An error code without `#[derive(ChainExtensionErrorCode)]` does not describe its status codes.
+#[derive(scale::Encode, scale::Decode, scale_info::TypeInfo)]
+pub struct TimeErr;
+
+impl ink_env::chain_extension::FromStatusCode for TimeErr {
+    fn from_status_code(status_code: u32) -> Result<(), Self> {
+        match status_code {
+            0 => Ok(()),
+            _ => Err(Self),
+        }
+    }
+}
+
+pub enum CustomEnvironment {}
+
+impl Environment for CustomEnvironment {
+    const MAX_EVENT_TOPICS: usize =
+        <ink_env::DefaultEnvironment as Environment>::MAX_EVENT_TOPICS;
+
+    type AccountId = <ink_env::DefaultEnvironment as Environment>::AccountId;
+    type Balance = <ink_env::DefaultEnvironment as Environment>::Balance;
+    type Hash = <ink_env::DefaultEnvironment as Environment>::Hash;
+    type BlockNumber = <ink_env::DefaultEnvironment as Environment>::BlockNumber;
+    type Timestamp = <ink_env::DefaultEnvironment as Environment>::Timestamp;
+
+    type ChainExtension = (FetchRandom, Time);
+}
+
+fn main() {
+    assert_eq!(
+        <RandomReadErr as ErrorCodeMetadata>::STATUS_CODES,
+        &[
+            StatusCodeSpec::new("FailGetRandomSource", 1),
+            StatusCodeSpec::catch_all("Unknown"),
+        ]
+    );
+
+    let spec = <FetchRandom as ChainExtensionMetadata>::chain_extension_spec();
+    assert_eq!(spec.label(), &"FetchRandom");
+    assert_eq!(spec.docs(), &[" Provides randomness."]);
+    assert_eq!(spec.status_codes(), <RandomReadErr as ErrorCodeMetadata>::STATUS_CODES);
+    let method = &spec.methods()[0];
+    assert_eq!(method.label(), &"fetch_random");
+    assert_eq!(method.func_id(), 1101);
+    assert_eq!(method.args()[0].label(), &"subject");
+    assert!(method.handle_status());
+    assert!(!method.returns_result());
+
+    let specs = <<CustomEnvironment as Environment>::ChainExtension as ChainExtensionsMetadata>::chain_extension_specs();
+    assert_eq!(specs.len(), 2);
+    assert_eq!(specs[1].label(), &"Time");
+    assert_eq!(specs[1].methods()[0].func_id(), 2);
+    assert!(specs[1].status_codes().is_empty());
+}
@@ -0,0 +1,14 @@
+use ink_lang as ink;
+
+#[ink::contract(default_constructor = true)]
+mod contract {
+    #[ink(storage)]
+    pub struct Contract {}
+
+    impl Contract {
+        #[ink(message)]
+        pub fn message(&self) {}
+    }
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+use ink_lang as ink;
+
+#[ink::contract(max_root_size = 32)]
+mod contract {
+    #[ink(storage)]
+    pub struct Contract {
+        owner: AccountId,
+        admin: AccountId,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self {
+                owner: Self::env().caller(),
+                admin: Self::env().caller(),
+            }
+        }
+
+        #[ink(message)]
+        pub fn message(&self) {}
+    }
+}
+
+fn main() {}
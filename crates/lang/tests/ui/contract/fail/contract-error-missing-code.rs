@@ -0,0 +1,10 @@
+use ink_lang as ink;
+
+#[derive(ink::ContractError)]
+pub enum Error {
+    #[ink(error_code = 1)]
+    InsufficientBalance,
+    NotOwner,
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+#![deny(deprecated)]
+
+use ink_lang as ink;
+
+#[ink::contract]
+mod contract {
+    #[ink(storage)]
+    pub struct Contract {}
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self {}
+        }
+
+        #[ink(message, deprecated = "use `message_v2` instead")]
+        pub fn message(&self) {}
+
+        #[ink(message)]
+        pub fn message_v2(&self) {
+            self.message()
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+use ink_lang as ink;
+
+#[ink::contract]
+mod contract {
+    #[ink(storage)]
+    pub struct Contract {}
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self {}
+        }
+
+        #[ink(message, default)]
+        pub fn message1(&self) {}
+
+        #[ink(message, default)]
+        pub fn message2(&self) {}
+    }
+}
+
+fn main() {}
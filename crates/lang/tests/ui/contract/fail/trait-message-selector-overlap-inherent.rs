@@ -0,0 +1,36 @@
+mod foo {
+    use ink_lang as ink;
+
+    #[ink::trait_definition]
+    pub trait TraitDefinition {
+        #[ink(message, selector = 42)]
+        fn message1(&self);
+    }
+}
+
+use ink_lang as ink;
+
+#[ink::contract]
+pub mod contract {
+    use super::foo::TraitDefinition;
+
+    #[ink(storage)]
+    pub struct Contract {}
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self {}
+        }
+
+        #[ink(message, selector = 42)]
+        pub fn message2(&self) {}
+    }
+
+    impl TraitDefinition for Contract {
+        #[ink(message)]
+        fn message1(&self) {}
+    }
+}
+
+fn main() {}
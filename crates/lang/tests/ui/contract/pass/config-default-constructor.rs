@@ -0,0 +1,32 @@
+use ink_lang as ink;
+
+#[ink::contract(default_constructor = true)]
+mod contract {
+    #[ink(storage)]
+    #[derive(Default)]
+    pub struct Contract {
+        value: bool,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(value: bool) -> Self {
+            Self { value }
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> bool {
+            self.value
+        }
+    }
+}
+
+use contract::{
+    Contract,
+    ContractRef,
+};
+
+fn main() {
+    let _ = Contract::new_default();
+    let _ = ContractRef::new_default();
+}
@@ -0,0 +1,26 @@
+use ink_lang as ink;
+
+#[ink::environment(balance = u64, block_number = u64, max_event_topics = 3)]
+pub enum CustomEnv {}
+
+#[ink::contract(env = super::CustomEnv)]
+mod contract {
+    #[ink(storage)]
+    pub struct Contract {}
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self {}
+        }
+
+        #[ink(message)]
+        pub fn message(&self) -> BlockNumber {
+            let balance: u64 = self.env().balance();
+            let _ = balance;
+            self.env().block_number()
+        }
+    }
+}
+
+fn main() {}
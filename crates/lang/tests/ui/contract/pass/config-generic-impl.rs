@@ -0,0 +1,61 @@
+use ink_lang as ink;
+
+#[ink::trait_definition]
+pub trait Vesting {
+    #[ink(message)]
+    fn releasable(&self) -> u128;
+}
+
+#[ink::contract(impl = LinearSchedule)]
+mod contract {
+    pub trait Schedule {
+        const PERIODS: u32;
+
+        fn vested(total: Balance, elapsed: u32) -> Balance;
+    }
+
+    pub struct LinearSchedule;
+
+    impl Schedule for LinearSchedule {
+        const PERIODS: u32 = 10;
+
+        fn vested(total: Balance, elapsed: u32) -> Balance {
+            total * elapsed.min(Self::PERIODS) as Balance / Self::PERIODS as Balance
+        }
+    }
+
+    #[ink(storage)]
+    pub struct Contract<S: Schedule> {
+        total: Balance,
+        elapsed: u32,
+    }
+
+    impl<S: Schedule> Contract<S> {
+        #[ink(constructor)]
+        pub fn new(total: Balance) -> Self {
+            Self { total, elapsed: 0 }
+        }
+
+        #[ink(message)]
+        pub fn periods(&self) -> u32 {
+            S::PERIODS
+        }
+
+        #[ink(message)]
+        pub fn advance(&mut self) {
+            self.elapsed += 1;
+        }
+    }
+
+    impl<S> super::Vesting for Contract<S>
+    where
+        S: Schedule,
+    {
+        #[ink(message)]
+        fn releasable(&self) -> Balance {
+            S::vested(self.total, self.elapsed)
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+use ink_lang as ink;
+
+#[ink::contract(max_root_size = 16384)]
+mod contract {
+    #[ink(storage)]
+    pub struct Contract {
+        owner: AccountId,
+        balances: ink_storage::Mapping<AccountId, Balance>,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self {
+                owner: Self::env().caller(),
+                balances: Default::default(),
+            }
+        }
+
+        #[ink(message)]
+        pub fn message(&self) {}
+    }
+}
+
+fn main() {}
@@ -0,0 +1,38 @@
+use ink_lang as ink;
+
+#[ink::contract(panic = "abort-silent")]
+mod contract {
+    #[ink(storage)]
+    pub struct Contract {
+        value: u32,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor(value: u32) -> Self {
+            assert!(value != 0);
+            assert!(value != 1, "value must not be {}", 1);
+            Self { value }
+        }
+
+        #[ink(message)]
+        pub fn set(&mut self, value: u32) {
+            assert_eq!(self.value, value, "value did not change");
+            assert_ne!(self.value, 0);
+            if value == 0 {
+                panic!("value must not be zero")
+            }
+            self.value = value;
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> u32 {
+            match self.value {
+                0 => unreachable!(),
+                value => value,
+            }
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,62 @@
+use ink_lang as ink;
+
+#[ink::contract]
+mod contract {
+    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        InvalidValue,
+    }
+
+    #[ink(storage)]
+    pub struct Contract {
+        value: bool,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(value: bool) -> Result<Self, Error> {
+            if !value {
+                return Err(Error::InvalidValue)
+            }
+            Ok(Self { value })
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> bool {
+            self.value
+        }
+    }
+}
+
+use contract::{
+    Contract,
+    ContractRef,
+    Error,
+};
+use ink_env::{
+    call::InstantiateError,
+    DefaultEnvironment,
+};
+
+fn main() {
+    ink_env::test::run_test::<DefaultEnvironment, _>(|_| {
+        let code_hash = [0x01; 32].into();
+        ink_lang::utils::register_contract::<Contract>(code_hash);
+        let instantiate = |value: bool| {
+            ContractRef::new(value)
+                .code_hash(code_hash)
+                .endowment(0)
+                .salt_bytes([value as u8])
+                .instantiate()
+        };
+        assert_eq!(
+            instantiate(false).map(|_| ()),
+            Err(InstantiateError::Constructor(Error::InvalidValue))
+        );
+        let contract = instantiate(true).expect("instantiation must succeed");
+        assert!(contract.get());
+        Ok(())
+    })
+    .unwrap()
+}
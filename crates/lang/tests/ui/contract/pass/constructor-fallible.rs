@@ -0,0 +1,50 @@
+use ink_lang as ink;
+
+#[ink::contract]
+mod contract {
+    #[derive(scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        InvalidValue,
+    }
+
+    #[ink(storage)]
+    pub struct Contract {
+        value: bool,
+    }
+
+    impl Contract {
+        #[ink(constructor, selector = 0)]
+        pub fn constructor(value: bool) -> Result<Self, Error> {
+            if !value {
+                return Err(Error::InvalidValue)
+            }
+            Ok(Self { value })
+        }
+
+        #[ink(constructor, selector = 1)]
+        pub fn constructor_infallible() -> Self {
+            Self { value: true }
+        }
+
+        #[ink(message)]
+        pub fn message(&self) -> bool {
+            self.value
+        }
+    }
+}
+
+use contract::{
+    Contract,
+    Error,
+};
+
+fn main() {
+    fn assert_output<const ID: u32, Output>()
+    where
+        Contract: ::ink_lang::reflect::DispatchableConstructorInfo<ID, Output = Output>,
+    {
+    }
+    assert_output::<0, Result<Contract, Error>>();
+    assert_output::<1, Contract>();
+}
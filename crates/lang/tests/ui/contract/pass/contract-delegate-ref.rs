@@ -0,0 +1,52 @@
+use ink_lang as ink;
+
+#[ink::contract]
+mod contract {
+    use ink_lang::{
+        FromCodeHash as _,
+        ToCodeHash as _,
+    };
+
+    #[ink(storage)]
+    pub struct Contract {
+        value: u32,
+        logic: ContractDelegateRef,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor(logic: Hash) -> Self {
+            Self {
+                value: 0,
+                logic: ContractDelegateRef::from_code_hash(logic),
+            }
+        }
+
+        #[ink(message)]
+        pub fn inc(&mut self, by: u32) {
+            self.value += by;
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> u32 {
+            self.value
+        }
+
+        #[ink(message)]
+        pub fn delegate_inc(&self, by: u32) -> bool {
+            self.logic.inc(by).is_ok() && self.logic.get().is_ok()
+        }
+
+        #[ink(message)]
+        pub fn logic(&self) -> Hash {
+            self.logic.to_code_hash()
+        }
+
+        #[ink(message)]
+        pub fn code_hash(&self) -> Hash {
+            self.logic.to_code_hash()
+        }
+    }
+}
+
+fn main() {}
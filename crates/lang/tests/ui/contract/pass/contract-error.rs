@@ -0,0 +1,34 @@
+use ink_lang as ink;
+
+#[ink::contract]
+mod contract {
+    #[derive(Debug, PartialEq, Eq, ink_lang::ContractError)]
+    pub enum Error {
+        #[ink(error_code = 1)]
+        InsufficientBalance { required: Balance },
+        #[ink(error_code = 2, env_error)]
+        Env,
+    }
+
+    #[ink(storage)]
+    pub struct Contract {}
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self {}
+        }
+
+        #[ink(message)]
+        pub fn transfer(&mut self, to: AccountId, value: Balance) -> Result<(), Error> {
+            let balance = self.env().balance();
+            if value > balance {
+                return Err(Error::InsufficientBalance { required: value })
+            }
+            self.env().transfer(to, value)?;
+            Ok(())
+        }
+    }
+}
+
+fn main() {}
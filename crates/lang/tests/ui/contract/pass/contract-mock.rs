@@ -0,0 +1,65 @@
+use ink_lang as ink;
+
+#[ink::trait_definition]
+pub trait Flip {
+    #[ink(message)]
+    fn flip(&mut self);
+}
+
+#[ink::contract]
+mod contract {
+    #[ink(storage)]
+    pub struct Contract {
+        value: bool,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self { value: false }
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> bool {
+            self.value
+        }
+
+        #[ink(message)]
+        pub fn checked_get(&self) -> Result<bool, u8> {
+            Ok(self.value)
+        }
+
+        #[ink(message)]
+        pub fn new(&self) -> bool {
+            !self.value
+        }
+    }
+
+    impl super::Flip for Contract {
+        #[ink(message)]
+        fn flip(&mut self) {
+            self.value = !self.value;
+        }
+    }
+}
+
+use contract::ContractMock;
+use ink_env::DefaultEnvironment;
+use Flip as _;
+
+fn main() {
+    ink_env::test::run_test::<DefaultEnvironment, _>(|accounts| {
+        let mock = ContractMock::new(accounts.bob);
+        mock.mock_get(true).mock_checked_get(Err(7)).mock_flip_flip();
+        let mut contract = mock.contract_ref();
+        assert!(contract.get());
+        assert_eq!(contract.checked_get(), Err(7));
+        contract.flip();
+        mock.mock_get(false);
+        assert!(!contract.get());
+        mock.mock_new(true);
+        assert!(contract.new());
+        Ok(())
+    })
+    .unwrap()
+}
@@ -0,0 +1,45 @@
+use ink_lang as ink;
+
+#[ink::contract]
+mod contract {
+    #[ink(storage)]
+    pub struct Contract {
+        value: bool,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self { value: false }
+        }
+
+        #[ink(message)]
+        pub fn flip(&mut self) {
+            self.value = !self.value;
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> bool {
+            self.value
+        }
+    }
+}
+
+use contract::ContractRef;
+use ink_env::call::CallFlags;
+use ink_lang::codegen::TraitCallBuilder as _;
+
+fn call_with_flags(contract: &mut ContractRef) -> Result<bool, ink_env::Error> {
+    contract.call_mut().flip().allow_reentry().fire()?;
+    contract
+        .call_mut()
+        .flip()
+        .call_flags(CallFlags::default().set_clone_input(true))
+        .allow_reentry()
+        .fire()?;
+    contract.call().get().tail_call().fire()
+}
+
+fn main() {
+    let _ = call_with_flags;
+}
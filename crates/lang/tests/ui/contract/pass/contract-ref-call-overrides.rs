@@ -0,0 +1,53 @@
+use ink_lang as ink;
+
+#[ink::contract]
+mod contract {
+    #[ink(storage)]
+    pub struct Contract {
+        value: Balance,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self { value: 0 }
+        }
+
+        #[ink(message, payable)]
+        pub fn deposit(&mut self) {
+            self.value += self.env().transferred_value();
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> Balance {
+            self.value
+        }
+
+        #[ink(message)]
+        pub fn with_gas_limit(&self) -> bool {
+            true
+        }
+    }
+}
+
+use contract::{
+    ContractRef,
+    ContractRefOverrides,
+};
+use ink_lang::CallOverrides;
+
+fn deposit_and_get(contract: &ContractRef) -> Result<u128, ink_env::Error> {
+    contract
+        .with_value(100)
+        .with_proof_size_limit(1_000)
+        .deposit()?;
+    let overrides: ContractRefOverrides =
+        CallOverrides::with_gas_limit(contract, 1_000).with_value(0);
+    let _: bool = contract.with_gas_limit();
+    let _: Result<bool, ink_env::Error> = overrides.clone().with_gas_limit();
+    overrides.get()
+}
+
+fn main() {
+    let _ = deposit_and_get;
+}
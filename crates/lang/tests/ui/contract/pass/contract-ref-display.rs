@@ -0,0 +1,38 @@
+use ink_lang as ink;
+
+#[ink::contract]
+mod contract {
+    #[ink(storage)]
+    pub struct Contract {}
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self {}
+        }
+
+        #[ink(message)]
+        pub fn account_id(&self) -> AccountId {
+            self.env().account_id()
+        }
+    }
+}
+
+use contract::ContractRef;
+use ink_env::call::FromAccountId as _;
+use ink_lang::{
+    codegen::TraitCallBuilder as _,
+    ToAccountId as _,
+};
+
+fn main() {
+    let account_id = [0x01; 32].into();
+    let contract = ContractRef::from_account_id(account_id);
+    assert_eq!(contract.to_account_id(), account_id);
+    assert_eq!(contract.call().to_account_id(), account_id);
+    assert_eq!(
+        contract.to_string(),
+        "Contract(5C62Ck4UrFPiBtoCmeSrgF7x9yv9mn38446dhCpsi2mLHiFT)"
+    );
+    assert_eq!(contract.call().to_string(), contract.to_string());
+}
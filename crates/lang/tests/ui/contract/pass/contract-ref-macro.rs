@@ -0,0 +1,42 @@
+use ink_lang as ink;
+
+#[ink::trait_definition]
+pub trait Flip {
+    #[ink(message)]
+    fn flip(&mut self);
+
+    #[ink(message)]
+    fn get(&self) -> bool;
+}
+
+#[ink::contract]
+mod caller {
+    use super::Flip;
+
+    #[ink(storage)]
+    pub struct Caller {
+        flipper: AccountId,
+    }
+
+    impl Caller {
+        #[ink(constructor)]
+        pub fn new(flipper: AccountId) -> Self {
+            Self { flipper }
+        }
+
+        #[ink(message)]
+        pub fn flip_and_get(&mut self) -> bool {
+            let mut flipper = ink_lang::contract_ref!(dyn Flip, self.flipper, Environment);
+            flipper.flip();
+            flipper.get()
+        }
+    }
+}
+
+use ink_lang::ToAccountId as _;
+
+fn main() {
+    let account_id = [0x01; 32].into();
+    let flipper = ink::contract_ref!(dyn Flip, account_id);
+    assert_eq!(flipper.to_account_id(), account_id);
+}
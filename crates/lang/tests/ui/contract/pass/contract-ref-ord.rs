@@ -0,0 +1,32 @@
+use ink_lang as ink;
+
+#[ink::contract]
+mod contract {
+    #[ink(storage)]
+    pub struct Contract {}
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self {}
+        }
+
+        #[ink(message)]
+        pub fn message(&self) {}
+    }
+}
+
+use contract::ContractRef;
+use ink_env::call::FromAccountId as _;
+use ink_lang::codegen::TraitCallBuilder as _;
+
+fn main() {
+    let lhs = ContractRef::from_account_id([0x01; 32].into());
+    let rhs = ContractRef::from_account_id([0x02; 32].into());
+    assert!(lhs < rhs);
+    assert!(lhs.call() < rhs.call());
+    let mut refs = vec![rhs.clone(), lhs.clone(), rhs.clone()];
+    refs.sort();
+    refs.dedup();
+    assert_eq!(refs, vec![lhs, rhs]);
+}
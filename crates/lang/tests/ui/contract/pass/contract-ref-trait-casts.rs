@@ -0,0 +1,62 @@
+use ink_lang as ink;
+
+#[ink::trait_definition]
+pub trait Flip {
+    #[ink(message)]
+    fn flip(&mut self);
+}
+
+#[ink::trait_definition]
+pub trait GetValue {
+    #[ink(message)]
+    fn get(&self) -> bool;
+}
+
+#[ink::contract]
+mod contract {
+    #[ink(storage)]
+    pub struct Contract {
+        value: bool,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self { value: false }
+        }
+    }
+
+    impl super::Flip for Contract {
+        #[ink(message)]
+        fn flip(&mut self) {
+            self.value = !self.value;
+        }
+    }
+
+    impl super::GetValue for Contract {
+        #[ink(message)]
+        fn get(&self) -> bool {
+            self.value
+        }
+    }
+}
+
+use contract::ContractRef;
+use ink_env::{
+    call::FromAccountId as _,
+    DefaultEnvironment,
+};
+use ink_lang::ToAccountId as _;
+
+fn main() {
+    let account_id = [0x42; 32].into();
+    let contract = ContractRef::from_account_id(account_id);
+    let flip = contract.as_flip();
+    let get_value = contract.as_get_value();
+    assert_eq!(
+        ink_lang::ToAccountId::<DefaultEnvironment>::to_account_id(&flip),
+        account_id
+    );
+    assert_eq!(ContractRef::from_get_value_ref(get_value), contract);
+    assert_eq!(ContractRef::from_flip_ref(flip).to_account_id(), account_id);
+}
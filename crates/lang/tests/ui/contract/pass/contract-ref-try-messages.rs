@@ -0,0 +1,46 @@
+use ink_lang as ink;
+
+#[ink::contract]
+mod contract {
+    #[ink(storage)]
+    pub struct Contract {
+        value: bool,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self { value: false }
+        }
+
+        #[ink(message)]
+        pub fn flip(&mut self) {
+            self.value = !self.value;
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> bool {
+            self.value
+        }
+
+        #[ink(message)]
+        pub fn try_get(&self) -> Option<bool> {
+            Some(self.value)
+        }
+    }
+}
+
+use contract::ContractRef;
+use ink_lang::CallOverrides as _;
+
+fn flip_and_get(contract: &mut ContractRef) -> Result<bool, ink_env::Error> {
+    let _: Result<(), ink_env::Error> = contract.try_call().flip();
+    contract.with_gas_limit(5_000).flip()?;
+    let _: Option<bool> = contract.try_get();
+    let _: Result<Option<bool>, ink_env::Error> = contract.try_call().try_get();
+    contract.try_call().get()
+}
+
+fn main() {
+    let _ = flip_and_get;
+}
@@ -0,0 +1,33 @@
+use ink_lang as ink;
+
+#[ink::contract]
+mod contract {
+    #[ink(storage)]
+    pub struct Contract {}
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self {}
+        }
+
+        #[ink(message)]
+        pub fn message(&self) {}
+    }
+}
+
+use contract::{
+    Contract,
+    ContractRef,
+};
+use ink_env::call::FromAccountId as _;
+use ink_lang::reflect::ContractVersion;
+
+fn main() {
+    assert_eq!(<Contract as ContractVersion>::VERSION, env!("CARGO_PKG_VERSION"));
+    assert_eq!(<ContractRef as ContractVersion>::VERSION, env!("CARGO_PKG_VERSION"));
+    let contract = ContractRef::from_account_id([0x01; 32].into());
+    let debug = format!("{:?}", contract);
+    assert!(debug.starts_with("ContractRef { version: "));
+    assert!(debug.contains(&format!("{:?}", env!("CARGO_PKG_VERSION"))));
+}
@@ -0,0 +1,108 @@
+use ink_lang as ink;
+use ink_lang::{
+    reflect::{
+        ContractConstructorDecoder,
+        ContractMessageDecoder,
+        DecodeDispatch,
+        DispatchError,
+    },
+    selector_bytes,
+};
+
+#[ink::trait_definition]
+pub trait Counter {
+    #[ink(message)]
+    fn inc(&mut self);
+
+    #[ink(message, selector = 0x00000001)]
+    fn get(&self) -> u32;
+}
+
+#[ink::contract]
+pub mod contract {
+    #[ink(storage)]
+    pub struct Contract {
+        value: u32,
+    }
+
+    impl Contract {
+        #[ink(constructor, selector = 0xFFFFFFFF)]
+        pub fn last() -> Self {
+            Self { value: 0 }
+        }
+
+        #[ink(constructor, selector = 0x00000000)]
+        pub fn first() -> Self {
+            Self { value: 0 }
+        }
+
+        #[ink(constructor)]
+        pub fn other() -> Self {
+            Self { value: 0 }
+        }
+
+        #[ink(message, selector = 0xFFFFFFFF)]
+        pub fn last_message(&self) {}
+
+        #[ink(message, selector = 0x80000000)]
+        pub fn middle_message(&self) {}
+
+        #[ink(message, selector = 0x00000000)]
+        pub fn first_message(&self) {}
+
+        #[ink(message)]
+        pub fn other_message(&self) {}
+    }
+
+    impl super::Counter for Contract {
+        #[ink(message)]
+        fn inc(&mut self) {
+            self.value += 1;
+        }
+
+        #[ink(message)]
+        fn get(&self) -> u32 {
+            self.value
+        }
+    }
+}
+
+use contract::Contract;
+
+fn decode_constructor(selector: [u8; 4]) -> Result<(), DispatchError> {
+    <<Contract as ContractConstructorDecoder>::Type as DecodeDispatch>::decode_dispatch(
+        &mut &selector[..],
+    )
+    .map(|_| ())
+}
+
+fn decode_message(selector: [u8; 4]) -> Result<(), DispatchError> {
+    <<Contract as ContractMessageDecoder>::Type as DecodeDispatch>::decode_dispatch(
+        &mut &selector[..],
+    )
+    .map(|_| ())
+}
+
+fn main() {
+    for selector in [[0xFF; 4], [0x00; 4], selector_bytes!("other")] {
+        assert_eq!(decode_constructor(selector), Ok(()));
+    }
+    assert_eq!(
+        decode_constructor([0x80, 0x00, 0x00, 0x00]),
+        Err(DispatchError::UnknownSelector)
+    );
+    for selector in [
+        [0xFF; 4],
+        [0x80, 0x00, 0x00, 0x00],
+        [0x00; 4],
+        selector_bytes!("other_message"),
+        selector_bytes!("Counter::inc"),
+        [0x00, 0x00, 0x00, 0x01],
+    ] {
+        assert_eq!(decode_message(selector), Ok(()));
+    }
+    assert_eq!(
+        decode_message([0x00, 0x00, 0x00, 0x02]),
+        Err(DispatchError::UnknownSelector)
+    );
+}
@@ -0,0 +1,53 @@
+use ink_lang as ink;
+
+mod events {
+    use ink_lang as ink;
+
+    #[ink::event]
+    pub struct SharedEvent {
+        #[ink(topic)]
+        pub arg_1: i8,
+        pub arg_2: i16,
+    }
+
+    #[ink::event]
+    #[ink(anonymous)]
+    pub struct AnonymousSharedEvent {
+        #[ink(topic)]
+        pub arg_1: i8,
+    }
+}
+
+#[ink::contract]
+mod contract {
+    #[ink(event)]
+    use crate::events::SharedEvent;
+
+    #[ink(event)]
+    use crate::events::AnonymousSharedEvent as RenamedEvent;
+
+    #[ink(storage)]
+    pub struct Contract {}
+
+    #[ink(event)]
+    pub struct Event {}
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self::env().emit_event(SharedEvent { arg_1: 1, arg_2: 2 });
+            Self::env().emit_event(RenamedEvent { arg_1: 1 });
+            Self::env().emit_event(Event {});
+            Self {}
+        }
+
+        #[ink(message)]
+        pub fn message(&self) {
+            self.env().emit_event(SharedEvent { arg_1: 1, arg_2: 2 });
+            self.env().emit_event(RenamedEvent { arg_1: 1 });
+            self.env().emit_event(Event {});
+        }
+    }
+}
+
+fn main() {}
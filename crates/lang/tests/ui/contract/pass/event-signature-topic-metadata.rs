@@ -0,0 +1,86 @@
+use ink_lang as ink;
+
+mod events {
+    use ink_lang as ink;
+
+    #[ink::event]
+    pub struct Short {
+        #[ink(topic)]
+        pub value: u8,
+    }
+
+    #[ink::event]
+    pub struct EventWithSignatureLongerThanTopic {
+        #[ink(topic)]
+        pub value: u8,
+    }
+
+    #[ink::event]
+    #[ink(anonymous)]
+    pub struct Anonymous {
+        #[ink(topic)]
+        pub value: u8,
+    }
+}
+
+#[ink::contract]
+mod contract {
+    #[ink(event)]
+    use crate::events::Anonymous;
+    #[ink(event)]
+    use crate::events::EventWithSignatureLongerThanTopic;
+    #[ink(event)]
+    use crate::events::Short;
+
+    #[ink(storage)]
+    pub struct Contract {}
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self {}
+        }
+
+        #[ink(message)]
+        pub fn emit(&self) {
+            self.env().emit_event(Short { value: 1 });
+            self.env()
+                .emit_event(EventWithSignatureLongerThanTopic { value: 1 });
+            self.env().emit_event(Anonymous { value: 1 });
+        }
+    }
+}
+
+use events::{
+    Anonymous,
+    EventWithSignatureLongerThanTopic,
+    Short,
+};
+use ink_metadata::EventMetadata;
+
+fn main() {
+    ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+        contract::Contract::constructor().emit();
+        let emitted = ink_env::test::recorded_events().collect::<Vec<_>>();
+        let signature_topic = |spec: ink_metadata::EventSpec| {
+            spec.signature_topic()
+                .map(|topic| topic.to_bytes().to_vec())
+        };
+        assert_eq!(
+            signature_topic(<Short as EventMetadata>::event_spec()),
+            Some(emitted[0].topics[0].clone())
+        );
+        assert_eq!(
+            signature_topic(
+                <EventWithSignatureLongerThanTopic as EventMetadata>::event_spec()
+            ),
+            Some(emitted[1].topics[0].clone())
+        );
+        assert_eq!(
+            signature_topic(<Anonymous as EventMetadata>::event_spec()),
+            None
+        );
+        Ok(())
+    })
+    .unwrap()
+}
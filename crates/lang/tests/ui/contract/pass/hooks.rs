@@ -0,0 +1,41 @@
+use ink_lang as ink;
+
+#[ink::contract]
+mod contract {
+    #[ink(storage)]
+    pub struct Contract {
+        paused: bool,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self { paused: false }
+        }
+
+        #[ink(message)]
+        pub fn message_1(&self) {}
+
+        #[ink(message)]
+        pub fn message_2(&mut self, paused: bool) {
+            self.paused = paused;
+        }
+    }
+
+    impl Contract {
+        /// Reverts all messages but `message_2` while the contract is paused.
+        #[ink(hook(before_call))]
+        fn ensure_not_paused(&self, selector: [u8; 4]) {
+            if self.paused && selector != ::ink_lang::selector_bytes!("message_2") {
+                panic!("the contract is paused")
+            }
+        }
+
+        #[ink(hook(after_call))]
+        fn after_call(&self, _selector: [u8; 4]) {
+            ink_env::debug_println!("executed message");
+        }
+    }
+}
+
+fn main() {}
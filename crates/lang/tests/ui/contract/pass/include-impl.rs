@@ -0,0 +1,60 @@
+use ink_lang as ink;
+
+#[ink::trait_definition]
+pub trait Flip {
+    #[ink(message)]
+    fn flip(&mut self);
+
+    #[ink(message)]
+    fn get(&self) -> bool;
+}
+
+pub fn invert(value: bool) -> bool {
+    !value
+}
+
+#[ink::export_impl(flip_impl)]
+impl Flip for Contract {
+    #[ink(message)]
+    fn flip(&mut self) {
+        self.value = crate::invert(self.value);
+    }
+
+    #[ink(message)]
+    fn get(&self) -> bool {
+        self.value
+    }
+}
+
+#[ink::export_impl(counter_impl)]
+impl Contract {
+    #[ink(message)]
+    pub fn increment(&mut self) {
+        self.count += 1;
+    }
+}
+
+#[ink::include_impl(flip_impl)]
+#[ink::include_impl(counter_impl)]
+#[ink::contract]
+mod contract {
+    use super::Flip;
+
+    #[ink(storage)]
+    pub struct Contract {
+        value: bool,
+        count: u32,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self {
+                value: false,
+                count: 0,
+            }
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,35 @@
+#![deny(deprecated)]
+
+use ink_lang as ink;
+
+#[ink::trait_definition]
+pub trait Legacy {
+    #[ink(message)]
+    fn legacy(&self);
+}
+
+#[ink::contract]
+mod contract {
+    #[ink(storage)]
+    pub struct Contract {}
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self {}
+        }
+
+        #[ink(message, deprecated = "use `message_v2` instead")]
+        pub fn message(&self) {}
+
+        #[ink(message)]
+        pub fn message_v2(&self) {}
+    }
+
+    impl super::Legacy for Contract {
+        #[ink(message, deprecated = "no longer supported")]
+        fn legacy(&self) {}
+    }
+}
+
+fn main() {}
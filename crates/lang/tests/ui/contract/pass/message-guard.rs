@@ -0,0 +1,45 @@
+use ink_lang as ink;
+
+#[ink::contract]
+mod contract {
+    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        NotOwner,
+    }
+
+    #[ink(storage)]
+    pub struct Contract {
+        owner: AccountId,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self {
+                owner: Self::env().caller(),
+            }
+        }
+
+        #[ink(message, guard = "only_owner")]
+        pub fn message(&self) -> Result<(), Error> {
+            Ok(())
+        }
+
+        #[ink(message)]
+        #[ink(guard = "only_owner")]
+        pub fn message_mut(&mut self, new_owner: AccountId) -> Result<(), Error> {
+            self.owner = new_owner;
+            Ok(())
+        }
+
+        fn only_owner(&self) -> Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner)
+            }
+            Ok(())
+        }
+    }
+}
+
+fn main() {}
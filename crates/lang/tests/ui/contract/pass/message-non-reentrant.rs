@@ -0,0 +1,42 @@
+use ink_lang as ink;
+
+#[ink::trait_definition]
+pub trait Counter {
+    #[ink(message)]
+    fn inc(&mut self);
+}
+
+#[ink::contract]
+mod contract {
+    #[ink(storage)]
+    pub struct Contract {
+        value: u32,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self { value: 0 }
+        }
+
+        #[ink(message, non_reentrant)]
+        pub fn message(&self) -> u32 {
+            self.value
+        }
+
+        #[ink(message, payable)]
+        #[ink(non_reentrant)]
+        pub fn message_mut(&mut self, value: u32) {
+            self.value = value;
+        }
+    }
+
+    impl super::Counter for Contract {
+        #[ink(message, non_reentrant)]
+        fn inc(&mut self) {
+            self.value += 1;
+        }
+    }
+}
+
+fn main() {}
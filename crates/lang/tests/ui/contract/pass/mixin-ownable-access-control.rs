@@ -0,0 +1,49 @@
+use ink_lang as ink;
+
+#[ink::ownable]
+#[ink::access_control]
+#[ink::contract]
+mod contract {
+    use ink_lang::access::{
+        AccessControlError,
+        OwnableError,
+        RoleType,
+    };
+
+    pub const PAUSER: RoleType = 1;
+
+    #[ink(storage)]
+    pub struct Contract {
+        paused: bool,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self { paused: false }
+        }
+
+        #[ink(constructor, payable)]
+        pub fn paused() -> Self {
+            Self { paused: true }
+        }
+
+        #[ink(message, guard = "only_pauser")]
+        pub fn pause(&mut self) -> Result<(), AccessControlError> {
+            self.paused = true;
+            Ok(())
+        }
+
+        #[ink(message, guard = "only_owner")]
+        pub fn unpause(&mut self) -> Result<(), OwnableError> {
+            self.paused = false;
+            Ok(())
+        }
+
+        pub fn only_pauser(&self) -> Result<(), AccessControlError> {
+            self.ensure_role(PAUSER)
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,51 @@
+use ink_lang as ink;
+
+#[ink::contract]
+mod contract {
+    mod storage {
+        #[ink(storage)]
+        pub struct Contract {
+            value: bool,
+        }
+    }
+
+    mod events {
+        #[ink(event)]
+        pub struct Flipped {
+            #[ink(topic)]
+            value: bool,
+        }
+    }
+
+    mod constructors {
+        impl Contract {
+            #[ink(constructor)]
+            pub fn constructor() -> Self {
+                Self { value: false }
+            }
+        }
+    }
+
+    mod messages {
+        impl Contract {
+            #[ink(message)]
+            pub fn flip(&mut self) {
+                self.value = helpers::negate(self.value);
+                self.env().emit_event(Flipped { value: self.value });
+            }
+
+            #[ink(message)]
+            pub fn get(&self) -> bool {
+                self.value
+            }
+        }
+    }
+
+    mod helpers {
+        pub fn negate(value: bool) -> bool {
+            !value
+        }
+    }
+}
+
+fn main() {}
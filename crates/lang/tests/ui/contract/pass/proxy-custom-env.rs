@@ -0,0 +1,23 @@
+use ink_lang as ink;
+
+pub struct CustomEnv;
+
+impl ink_env::Environment for CustomEnv {
+    const MAX_EVENT_TOPICS: usize = 3;
+    type AccountId = [u8; 32];
+    type Balance = u64;
+    type Hash = [u8; 32];
+    type Timestamp = u64;
+    type BlockNumber = u64;
+    type ChainExtension = ();
+}
+
+/// An upgradeable proxy contract.
+#[ink::proxy(env = super::CustomEnv)]
+pub mod proxy {}
+
+fn main() {
+    use proxy::Proxy;
+    let _: fn([u8; 32]) -> Proxy = Proxy::new;
+    let _: fn(&Proxy) -> [u8; 32] = Proxy::implementation;
+}
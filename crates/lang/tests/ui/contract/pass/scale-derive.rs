@@ -0,0 +1,37 @@
+use ink_lang as ink;
+
+#[ink::contract]
+mod contract {
+    #[ink_lang::scale_derive(Encode, Decode, TypeInfo)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub enum Error {
+        InsufficientBalance,
+    }
+
+    #[ink_lang::scale_derive(Encode, Decode, TypeInfo)]
+    pub struct Transfer {
+        to: AccountId,
+        value: Balance,
+    }
+
+    #[ink(storage)]
+    pub struct Contract {}
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self {}
+        }
+
+        #[ink(message)]
+        pub fn transfer(&mut self, transfer: Transfer) -> Result<(), Error> {
+            let _ = transfer.to;
+            if transfer.value > 0 {
+                return Err(Error::InsufficientBalance)
+            }
+            Ok(())
+        }
+    }
+}
+
+fn main() {}
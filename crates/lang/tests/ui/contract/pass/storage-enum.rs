@@ -0,0 +1,46 @@
+use ink_lang as ink;
+
+#[ink::contract]
+mod contract {
+    /// The lifecycle of the contract modelled as a state machine.
+    #[ink(storage)]
+    pub enum Contract {
+        Uninitialized,
+        Active { owner: AccountId, value: i32 },
+        Frozen(AccountId),
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self::Uninitialized
+        }
+
+        #[ink(message)]
+        pub fn activate(&mut self, value: i32) {
+            if let Self::Uninitialized = self {
+                *self = Self::Active {
+                    owner: self.env().caller(),
+                    value,
+                };
+            }
+        }
+
+        #[ink(message)]
+        pub fn freeze(&mut self) {
+            if let Self::Active { owner, .. } = self {
+                *self = Self::Frozen(*owner);
+            }
+        }
+
+        #[ink(message)]
+        pub fn value(&self) -> Option<i32> {
+            match self {
+                Self::Active { value, .. } => Some(*value),
+                Self::Uninitialized | Self::Frozen(_) => None,
+            }
+        }
+    }
+}
+
+fn main() {}
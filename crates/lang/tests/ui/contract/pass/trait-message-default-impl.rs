@@ -0,0 +1,55 @@
+use flipper::Flipper;
+use ink_lang as ink;
+
+#[ink::trait_definition]
+pub trait Flip {
+    #[ink(message)]
+    fn set(&mut self, value: bool);
+
+    #[ink(message)]
+    fn get(&self) -> bool;
+
+    #[ink(message)]
+    fn flip(&mut self) {
+        let value = self.get();
+        self.set(!value);
+    }
+}
+
+#[ink::contract]
+mod flipper {
+    use super::Flip;
+
+    #[ink(storage)]
+    pub struct Flipper {
+        value: bool,
+    }
+
+    impl Flipper {
+        #[ink(constructor)]
+        pub fn new(init_value: bool) -> Self {
+            Self { value: init_value }
+        }
+    }
+
+    impl Flip for Flipper {
+        #[ink(message)]
+        fn set(&mut self, value: bool) {
+            self.value = value;
+        }
+
+        #[ink(message)]
+        fn get(&self) -> bool {
+            self.value
+        }
+
+        #[ink(message)]
+        fn flip(&mut self);
+    }
+}
+
+fn main() {
+    let mut flipper = Flipper::new(false);
+    <Flipper as Flip>::flip(&mut flipper);
+    assert!(<Flipper as Flip>::get(&flipper));
+}
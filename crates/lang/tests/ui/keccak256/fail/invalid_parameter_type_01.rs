@@ -0,0 +1,5 @@
+use ink_lang as ink;
+
+const _: [u8; 32] = ink::keccak256!(true);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use ink_lang as ink;
+
+const INPUT: &str = "test";
+const _: [u8; 32] = ink::keccak256!(INPUT);
+
+fn main() {}
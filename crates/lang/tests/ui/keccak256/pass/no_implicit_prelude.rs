@@ -0,0 +1,5 @@
+#![no_implicit_prelude]
+
+const _: [::core::primitive::u8; 32] = ::ink_lang::keccak256!("test");
+
+fn main() {}
@@ -0,0 +1,41 @@
+use ink_env::Environment;
+use ink_lang as ink;
+use ink_lang::reflect::ContractEnv;
+
+/// A standard for flippable values.
+#[ink::trait_definition(namespace = "flip", metadata = true)]
+pub trait Flip {
+    /// Flips the value.
+    #[ink(message, payable)]
+    fn flip(&mut self);
+
+    /// Returns the value.
+    #[ink(message)]
+    fn get(&self) -> bool;
+
+    /// Returns the owner of the value.
+    #[ink(message, selector = 0xCAFEBABE)]
+    fn owner(&self, of: <<Self as ContractEnv>::Env as Environment>::AccountId) -> bool;
+}
+
+fn main() {
+    let interface = ink::trait_metadata!(dyn Flip);
+    let spec = interface.spec();
+    assert_eq!(spec.label(), "Flip");
+    assert_eq!(spec.docs(), &[" A standard for flippable values.".to_string()]);
+
+    let messages = spec.messages();
+    assert_eq!(messages.len(), 3);
+    assert_eq!(messages[0].label(), "flip");
+    assert!(messages[0].mutates());
+    assert!(messages[0].payable());
+    assert_eq!(
+        messages[0].selector().to_bytes(),
+        ink::selector_bytes!("flip::Flip::flip")
+    );
+    assert_eq!(messages[1].label(), "get");
+    assert!(!messages[1].mutates());
+    assert!(!messages[1].payable());
+    assert_eq!(messages[2].selector().to_bytes(), [0xCA, 0xFE, 0xBA, 0xBE]);
+    assert_eq!(messages[2].args()[0].label(), "of");
+}
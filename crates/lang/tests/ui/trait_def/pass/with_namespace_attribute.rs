@@ -0,0 +1,10 @@
+use ink_lang as ink;
+
+#[ink::trait_definition]
+#[ink(namespace = "my_trait_namespace")]
+pub trait WithNamespace {
+    #[ink(message)]
+    fn message(&self);
+}
+
+fn main() {}
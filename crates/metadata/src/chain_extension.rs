@@ -0,0 +1,145 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    ChainExtensionSpec,
+    StatusCodeSpec,
+};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Provides the ink! metadata of a chain extension.
+///
+/// # Note
+///
+/// This trait is automatically implemented for chain extensions defined via
+/// `#[ink::chain_extension]`.
+pub trait ChainExtensionMetadata {
+    /// Returns the ink! metadata of the chain extension.
+    fn chain_extension_spec() -> ChainExtensionSpec;
+}
+
+/// Provides the ink! metadata of all chain extensions of an environment.
+///
+/// # Note
+///
+/// This is implemented for every chain extension providing [`ChainExtensionMetadata`]
+/// as well as for tuples of those since environments may combine several chain
+/// extensions.
+pub trait ChainExtensionsMetadata {
+    /// Returns the ink! metadata of the chain extensions.
+    fn chain_extension_specs() -> Vec<ChainExtensionSpec>;
+}
+
+impl<T> ChainExtensionsMetadata for T
+where
+    T: ChainExtensionMetadata,
+{
+    fn chain_extension_specs() -> Vec<ChainExtensionSpec> {
+        Vec::from([<T as ChainExtensionMetadata>::chain_extension_spec()])
+    }
+}
+
+macro_rules! impl_chain_extensions_metadata_for_tuple {
+    ( $( $ty:ident ),* ) => {
+        impl<$( $ty ),*> ChainExtensionsMetadata for ( $( $ty, )* )
+        where
+            $( $ty: ChainExtensionMetadata, )*
+        {
+            fn chain_extension_specs() -> Vec<ChainExtensionSpec> {
+                Vec::from([ $( <$ty as ChainExtensionMetadata>::chain_extension_spec() ),* ])
+            }
+        }
+    };
+}
+impl_chain_extensions_metadata_for_tuple!(A, B);
+impl_chain_extensions_metadata_for_tuple!(A, B, C);
+impl_chain_extensions_metadata_for_tuple!(A, B, C, D);
+impl_chain_extensions_metadata_for_tuple!(A, B, C, D, E);
+impl_chain_extensions_metadata_for_tuple!(A, B, C, D, E, F);
+
+/// Provides the status codes of a chain extension error code.
+///
+/// # Note
+///
+/// This trait is automatically implemented for error codes using
+/// `#[derive(ChainExtensionErrorCode)]`.
+pub trait ErrorCodeMetadata {
+    /// The status codes and the error code variants they map to.
+    const STATUS_CODES: &'static [StatusCodeSpec];
+}
+
+/// Provides the ink! metadata of the chain extensions of type `T`.
+///
+/// # Note
+///
+/// Chain extensions are not required to be defined via `#[ink::chain_extension]`.
+/// Therefore this falls back to no chain extension metadata at all if `T` does not
+/// implement [`ChainExtensionsMetadata`] via [`ChainExtensionSpecsFallback`].
+pub struct ChainExtensionSpecs<T> {
+    marker: core::marker::PhantomData<fn() -> T>,
+}
+
+impl<T> ChainExtensionSpecs<T>
+where
+    T: ChainExtensionsMetadata,
+{
+    // We need to allow for dead code at this point because
+    // the Rust compiler thinks this function is unused even
+    // though it acts as the specialized case for detection.
+    #[allow(dead_code)]
+    pub fn specs() -> Vec<ChainExtensionSpec> {
+        <T as ChainExtensionsMetadata>::chain_extension_specs()
+    }
+}
+
+/// Provides no chain extension metadata for chain extensions without [`ChainExtensionsMetadata`].
+pub trait ChainExtensionSpecsFallback {
+    fn specs() -> Vec<ChainExtensionSpec> {
+        Vec::new()
+    }
+}
+impl<T> ChainExtensionSpecsFallback for ChainExtensionSpecs<T> {}
+
+/// Provides the status codes of the chain extension error code of type `T`.
+///
+/// # Note
+///
+/// Chain extension error codes are not required to use `#[derive(ChainExtensionErrorCode)]`.
+/// Therefore this falls back to no status codes at all if `T` does not implement
+/// [`ErrorCodeMetadata`] via [`StatusCodeSpecsFallback`].
+pub struct StatusCodeSpecs<T> {
+    marker: core::marker::PhantomData<fn() -> T>,
+}
+
+impl<T> StatusCodeSpecs<T>
+where
+    T: ErrorCodeMetadata,
+{
+    // We need to allow for dead code at this point because
+    // the Rust compiler thinks this function is unused even
+    // though it acts as the specialized case for detection.
+    #[allow(dead_code)]
+    pub fn specs() -> Vec<StatusCodeSpec> {
+        <T as ErrorCodeMetadata>::STATUS_CODES.to_vec()
+    }
+}
+
+/// Provides no status codes for error codes without [`ErrorCodeMetadata`].
+pub trait StatusCodeSpecsFallback {
+    fn specs() -> Vec<StatusCodeSpec> {
+        Vec::new()
+    }
+}
+impl<T> StatusCodeSpecsFallback for StatusCodeSpecs<T> {}
@@ -69,6 +69,11 @@ pub enum Layout<F: Form = MetaForm> {
     Struct(StructLayout<F>),
     /// An enum layout with a discriminant telling which variant is layed out.
     Enum(EnumLayout<F>),
+    /// A layout resolved from a concrete root key.
+    ///
+    /// This is used for the root of the contract storage as well as for lazily
+    /// loaded cells and mappings that address their contents via their root key.
+    Root(RootLayout<F>),
 }
 
 /// A pointer into some storage region.
@@ -173,6 +178,9 @@ impl IntoPortable for Layout {
             Layout::Enum(enum_layout) => {
                 Layout::Enum(enum_layout.into_portable(registry))
             }
+            Layout::Root(root_layout) => {
+                Layout::Root(root_layout.into_portable(registry))
+            }
         }
     }
 }
@@ -192,6 +200,60 @@ where
     }
 }
 
+/// A layout whose values are resolved starting from a concrete root key.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "F::Type: Serialize, F::String: Serialize",
+    deserialize = "F::Type: DeserializeOwned, F::String: DeserializeOwned"
+))]
+#[serde(rename_all = "camelCase")]
+pub struct RootLayout<F: Form = MetaForm> {
+    /// The root key of the layout.
+    root_key: LayoutKey,
+    /// The layout of the values stored from the root key on.
+    layout: Box<Layout<F>>,
+}
+
+impl RootLayout {
+    /// Creates a new root layout.
+    pub fn new<K, L>(root_key: K, layout: L) -> Self
+    where
+        K: Into<LayoutKey>,
+        L: Into<Layout>,
+    {
+        Self {
+            root_key: root_key.into(),
+            layout: Box::new(layout.into()),
+        }
+    }
+}
+
+impl<F> RootLayout<F>
+where
+    F: Form,
+{
+    /// Returns the root key of the layout.
+    pub fn root_key(&self) -> &LayoutKey {
+        &self.root_key
+    }
+
+    /// Returns the layout of the values stored from the root key on.
+    pub fn layout(&self) -> &Layout<F> {
+        &self.layout
+    }
+}
+
+impl IntoPortable for RootLayout {
+    type Output = RootLayout<PortableForm>;
+
+    fn into_portable(self, registry: &mut Registry) -> Self::Output {
+        RootLayout {
+            root_key: self.root_key,
+            layout: Box::new(self.layout.into_portable(registry)),
+        }
+    }
+}
+
 /// A hashing layout potentially hitting all cells of the storage.
 ///
 /// Every hashing layout has an offset and a strategy to compute its keys.
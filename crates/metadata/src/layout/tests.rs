@@ -365,3 +365,40 @@ fn unbounded_layout_works() {
     };
     assert_eq!(json, expected);
 }
+
+fn root_layout(key_ptr: &mut KeyPtr) -> Layout {
+    let root_key = *key_ptr.advance_by(1);
+    RootLayout::new(root_key, CellLayout::new::<i32>(LayoutKey::from(root_key))).into()
+}
+
+#[test]
+fn root_layout_works() {
+    let layout = root_layout(&mut KeyPtr::from(Key::from([0x01; 32])));
+    let mut registry = Registry::new();
+    let compacted = layout.into_portable(&mut registry);
+    let json = serde_json::to_value(&compacted).unwrap();
+    let expected = serde_json::json! {
+        {
+            "root": {
+                "layout": {
+                    "cell": {
+                        "key": "0x\
+                            0101010101010101\
+                            0101010101010101\
+                            0101010101010101\
+                            0101010101010101",
+                        "ty": 0
+                    }
+                },
+                "rootKey": "0x\
+                    0101010101010101\
+                    0101010101010101\
+                    0101010101010101\
+                    0101010101010101",
+            }
+        }
+    };
+    assert_eq!(json, expected);
+    let deserialized: Layout<PortableForm> = serde_json::from_value(json).unwrap();
+    assert_eq!(deserialized, compacted);
+}
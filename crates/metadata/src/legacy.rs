@@ -0,0 +1,312 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Legacy versions of the ink! metadata and their migrations to the current version.
+//!
+//! Legacy metadata is kept in its serialized form and upgraded one version at a
+//! time by rewriting it into the format of the next version:
+//!
+//! - Version 1 refers to types by their 1-based position in the type registry and
+//!   describes enum variants by their optional `discriminant`. Version 2 introduced
+//!   explicit 0-based type identifiers and variant indices.
+//! - Version 2 names constructors and messages by their path segments, e.g.
+//!   `["Erc20", "transfer"]`, and their arguments and events by `name`. Version 3
+//!   replaced these by a single `label`, e.g. `"Erc20::transfer"`, and made the
+//!   `payable` flag of constructors explicit.
+
+use crate::InkProject;
+#[cfg(not(feature = "std"))]
+use alloc::{
+    string::{
+        String,
+        ToString,
+    },
+    vec::Vec,
+};
+use core::fmt;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_json::{
+    Map,
+    Value,
+};
+
+/// Version 1 of the contract metadata.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct MetadataV1(Map<String, Value>);
+
+/// Version 2 of the contract metadata.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct MetadataV2(Map<String, Value>);
+
+/// An error that occurred while migrating legacy ink! metadata.
+#[derive(Debug)]
+pub enum MigrationError {
+    /// The legacy metadata is missing a mandatory field.
+    MissingField(&'static str),
+    /// A type of version 1 metadata refers to the invalid type position `0`.
+    InvalidTypeRef,
+    /// The migrated metadata does not conform to the current metadata format.
+    Invalid(serde_json::Error),
+}
+
+impl fmt::Display for MigrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingField(field) => {
+                write!(f, "legacy metadata is missing the `{}` field", field)
+            }
+            Self::InvalidTypeRef => {
+                write!(f, "legacy metadata refers to the invalid type position 0")
+            }
+            Self::Invalid(error) => {
+                write!(f, "migrated metadata is invalid: {}", error)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MigrationError {}
+
+impl TryFrom<MetadataV1> for MetadataV2 {
+    type Error = MigrationError;
+
+    fn try_from(MetadataV1(mut metadata): MetadataV1) -> Result<Self, Self::Error> {
+        let types = match metadata.remove("types") {
+            Some(Value::Array(types)) => types,
+            _ => return Err(MigrationError::MissingField("types")),
+        };
+        let types = types
+            .into_iter()
+            .enumerate()
+            .map(|(id, mut ty)| {
+                if let Some(def) = ty.get_mut("def") {
+                    migrate_type_def_v1(def)?;
+                }
+                if let Some(Value::Array(params)) = ty.get_mut("params") {
+                    for param in params {
+                        match param.get_mut("type") {
+                            Some(Value::Null) | None => (),
+                            Some(ty) => decrement_type_ref(ty)?,
+                        }
+                    }
+                }
+                let mut portable = Map::new();
+                portable.insert("id".to_string(), Value::from(id));
+                portable.insert("type".to_string(), ty);
+                Ok(Value::Object(portable))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        metadata.insert("types".to_string(), Value::Array(types));
+        if let Some(storage) = metadata.get_mut("storage") {
+            migrate_layout_v1(storage)?;
+        }
+        if let Some(spec) = metadata.get_mut("spec") {
+            migrate_type_specs_v1(spec)?;
+        }
+        Ok(MetadataV2(metadata))
+    }
+}
+
+impl InkProject {
+    /// Upgrades version 1 of the contract metadata to the current version.
+    pub fn from_v1(metadata: MetadataV1) -> Result<Self, MigrationError> {
+        MetadataV2::try_from(metadata).and_then(Self::from_v2)
+    }
+
+    /// Upgrades version 2 of the contract metadata to the current version.
+    pub fn from_v2(MetadataV2(mut metadata): MetadataV2) -> Result<Self, MigrationError> {
+        let spec = metadata
+            .get_mut("spec")
+            .ok_or(MigrationError::MissingField("spec"))?;
+        for constructor in array_field(spec, "constructors") {
+            migrate_label_v2(constructor);
+            if let Some(constructor) = constructor.as_object_mut() {
+                constructor.entry("payable").or_insert(Value::Bool(false));
+            }
+            for arg in array_field(constructor, "args") {
+                migrate_label_v2(arg);
+            }
+        }
+        for message in array_field(spec, "messages") {
+            migrate_label_v2(message);
+            for arg in array_field(message, "args") {
+                migrate_label_v2(arg);
+            }
+        }
+        for event in array_field(spec, "events") {
+            migrate_label_v2(event);
+            for arg in array_field(event, "args") {
+                migrate_label_v2(arg);
+            }
+        }
+        serde_json::from_value(Value::Object(metadata)).map_err(MigrationError::Invalid)
+    }
+}
+
+/// Returns the elements of the array field with the given name of a JSON object.
+fn array_field<'a>(
+    value: &'a mut Value,
+    field: &str,
+) -> impl Iterator<Item = &'a mut Value> {
+    value
+        .get_mut(field)
+        .and_then(Value::as_array_mut)
+        .into_iter()
+        .flatten()
+}
+
+/// Replaces the `name` of a version 2 spec by the `label` of version 3.
+///
+/// Names given as path segments are joined with `::`.
+fn migrate_label_v2(value: &mut Value) {
+    let object = match value {
+        Value::Object(object) => object,
+        _ => return,
+    };
+    let label = match object.remove("name") {
+        Some(Value::Array(segments)) => {
+            let segments = segments
+                .iter()
+                .filter_map(Value::as_str)
+                .collect::<Vec<_>>();
+            Value::from(segments.join("::"))
+        }
+        Some(name) => name,
+        None => return,
+    };
+    object.entry("label").or_insert(label);
+}
+
+/// Converts a 1-based type position of version 1 to a 0-based type identifier.
+fn decrement_type_ref(ty: &mut Value) -> Result<(), MigrationError> {
+    match ty.as_u64() {
+        Some(position) if position > 0 => {
+            *ty = Value::from(position - 1);
+            Ok(())
+        }
+        _ => Err(MigrationError::InvalidTypeRef),
+    }
+}
+
+/// Migrates the type references and variant indices of a version 1 type definition.
+fn migrate_type_def_v1(def: &mut Value) -> Result<(), MigrationError> {
+    let def = match def {
+        Value::Object(def) => def,
+        _ => return Ok(()),
+    };
+    for (kind, def) in def.iter_mut() {
+        match kind.as_str() {
+            "composite" => {
+                for field in array_field(def, "fields") {
+                    migrate_field_v1(field)?;
+                }
+            }
+            "variant" => {
+                for (position, variant) in array_field(def, "variants").enumerate() {
+                    for field in array_field(variant, "fields") {
+                        migrate_field_v1(field)?;
+                    }
+                    if let Value::Object(variant) = variant {
+                        let index = variant
+                            .remove("discriminant")
+                            .unwrap_or_else(|| Value::from(position));
+                        variant.entry("index").or_insert(index);
+                    }
+                }
+            }
+            "sequence" | "array" | "compact" => {
+                if let Some(ty) = def.get_mut("type") {
+                    decrement_type_ref(ty)?;
+                }
+            }
+            "tuple" => {
+                if let Value::Array(fields) = def {
+                    for ty in fields {
+                        decrement_type_ref(ty)?;
+                    }
+                }
+            }
+            "bitsequence" => {
+                for field in ["bit_store_type", "bit_order_type"] {
+                    if let Some(ty) = def.get_mut(field) {
+                        decrement_type_ref(ty)?;
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+    Ok(())
+}
+
+/// Migrates the type reference of a version 1 struct or enum variant field.
+fn migrate_field_v1(field: &mut Value) -> Result<(), MigrationError> {
+    match field.get_mut("type") {
+        Some(ty) => decrement_type_ref(ty),
+        None => Ok(()),
+    }
+}
+
+/// Migrates the type references of the cells of a version 1 storage layout.
+fn migrate_layout_v1(layout: &mut Value) -> Result<(), MigrationError> {
+    match layout {
+        Value::Object(layout) => {
+            for (key, value) in layout.iter_mut() {
+                if key == "ty" {
+                    decrement_type_ref(value)?;
+                } else {
+                    migrate_layout_v1(value)?;
+                }
+            }
+        }
+        Value::Array(layouts) => {
+            for layout in layouts {
+                migrate_layout_v1(layout)?;
+            }
+        }
+        _ => (),
+    }
+    Ok(())
+}
+
+/// Migrates the type references of all type specs of a version 1 contract spec.
+///
+/// Type specs are identified by their `displayName` next to the referenced `type`.
+fn migrate_type_specs_v1(spec: &mut Value) -> Result<(), MigrationError> {
+    match spec {
+        Value::Object(spec) => {
+            if spec.contains_key("displayName") {
+                if let Some(ty) = spec.get_mut("type") {
+                    decrement_type_ref(ty)?;
+                }
+            }
+            for value in spec.values_mut() {
+                migrate_type_specs_v1(value)?;
+            }
+        }
+        Value::Array(specs) => {
+            for spec in specs {
+                migrate_type_specs_v1(spec)?;
+            }
+        }
+        _ => (),
+    }
+    Ok(())
+}
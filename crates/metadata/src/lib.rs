@@ -20,27 +20,66 @@ extern crate alloc;
 #[cfg(test)]
 mod tests;
 
+mod chain_extension;
 pub mod layout;
+mod legacy;
+#[cfg(feature = "solidity")]
+pub mod solidity;
+mod source;
 mod specs;
+#[cfg(feature = "typescript")]
+pub mod typescript;
+mod user;
 mod utils;
 
-pub use self::specs::{
-    ConstructorSpec,
-    ConstructorSpecBuilder,
-    ContractSpec,
-    ContractSpecBuilder,
-    DisplayName,
-    EventParamSpec,
-    EventParamSpecBuilder,
-    EventSpec,
-    EventSpecBuilder,
-    MessageParamSpec,
-    MessageParamSpecBuilder,
-    MessageSpec,
-    MessageSpecBuilder,
-    ReturnTypeSpec,
-    Selector,
-    TypeSpec,
+pub use self::{
+    chain_extension::{
+        ChainExtensionMetadata,
+        ChainExtensionSpecs,
+        ChainExtensionSpecsFallback,
+        ChainExtensionsMetadata,
+        ErrorCodeMetadata,
+        StatusCodeSpecs,
+        StatusCodeSpecsFallback,
+    },
+    legacy::{
+        MetadataV1,
+        MetadataV2,
+        MigrationError,
+    },
+    source::{
+        CodeHash,
+        Source,
+    },
+    specs::{
+        ChainExtensionMethodSpec,
+        ChainExtensionMethodSpecBuilder,
+        ChainExtensionSpec,
+        ChainExtensionSpecBuilder,
+        ConstructorSpec,
+        ConstructorSpecBuilder,
+        ContractSpec,
+        ContractSpecBuilder,
+        DisplayName,
+        EnvironmentSpec,
+        EnvironmentSpecBuilder,
+        EventParamSpec,
+        EventParamSpecBuilder,
+        EventSpec,
+        EventSpecBuilder,
+        MessageParamSpec,
+        MessageParamSpecBuilder,
+        MessageSpec,
+        MessageSpecBuilder,
+        ReturnTypeSpec,
+        Selector,
+        SignatureTopic,
+        StatusCodeSpec,
+        TraitSpec,
+        TraitSpecBuilder,
+        TypeSpec,
+    },
+    user::UserMetadata,
 };
 
 use impl_serde::serialize as serde_hex;
@@ -69,13 +108,31 @@ pub enum MetadataVersioned {
     /// Version 0 placeholder. Represents the original non-versioned metadata format.
     V0(MetadataVersionDeprecated),
     /// Version 1 of the contract metadata.
-    V1(MetadataVersionDeprecated),
+    V1(MetadataV1),
     /// Version 2 of the contract metadata.
-    V2(MetadataVersionDeprecated),
+    V2(MetadataV2),
     /// Version 3 of the contract metadata.
     V3(InkProject),
 }
 
+impl MetadataVersioned {
+    /// Upgrades the metadata to the current version.
+    ///
+    /// This allows tools to consume metadata produced by earlier ink! releases.
+    ///
+    /// # Errors
+    ///
+    /// If the legacy metadata does not conform to its version.
+    pub fn into_latest(self) -> Result<InkProject, MigrationError> {
+        match self {
+            MetadataVersioned::V0(deprecated) => match deprecated {},
+            MetadataVersioned::V1(metadata) => InkProject::from_v1(metadata),
+            MetadataVersioned::V2(metadata) => InkProject::from_v2(metadata),
+            MetadataVersioned::V3(ink_project) => Ok(ink_project),
+        }
+    }
+}
+
 impl From<InkProject> for MetadataVersioned {
     fn from(ink_project: InkProject) -> Self {
         MetadataVersioned::V3(ink_project)
@@ -95,6 +152,12 @@ pub struct InkProject {
     /// The layout of the storage data structure
     layout: layout::Layout<PortableForm>,
     spec: ContractSpec<PortableForm>,
+    /// Describes how the contract was built.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    source: Option<Source>,
+    /// User defined metadata, e.g. audit report hashes or UI hints.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    user: Option<UserMetadata>,
 }
 
 impl InkProject {
@@ -109,6 +172,30 @@ impl InkProject {
             layout: layout.into().into_portable(&mut registry),
             spec: spec.into().into_portable(&mut registry),
             registry: registry.into(),
+            source: None,
+            user: None,
+        }
+    }
+
+    /// Sets the description of how the contract was built.
+    ///
+    /// Build tools use this to add the hash of the compiled Wasm code as well as
+    /// the compiler and build tool versions to the metadata.
+    pub fn with_source(self, source: Source) -> Self {
+        Self {
+            source: Some(source),
+            ..self
+        }
+    }
+
+    /// Sets the user defined metadata embedded under the `user` key.
+    ///
+    /// This is the place for team specific data that is not covered by the
+    /// ink! metadata format, e.g. audit report hashes or hints for user interfaces.
+    pub fn with_user(self, user: UserMetadata) -> Self {
+        Self {
+            user: Some(user),
+            ..self
         }
     }
 }
@@ -128,4 +215,73 @@ impl InkProject {
     pub fn spec(&self) -> &ContractSpec<PortableForm> {
         &self.spec
     }
+
+    /// Returns the description of how the contract was built if any.
+    pub fn source(&self) -> Option<&Source> {
+        self.source.as_ref()
+    }
+
+    /// Returns the user defined metadata if any.
+    pub fn user(&self) -> Option<&UserMetadata> {
+        self.user.as_ref()
+    }
+}
+
+/// The interface-only ink! metadata of an ink! trait definition.
+///
+/// In contrast to [`InkProject`] this does not describe a concrete contract but only the
+/// messages, selectors and types of an ink! trait definition. Standards can publish this
+/// as their canonical machine-readable interface.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InkInterface {
+    #[serde(flatten)]
+    registry: PortableRegistry,
+    spec: TraitSpec<PortableForm>,
+}
+
+impl InkInterface {
+    /// Creates the interface-only ink! metadata for the given trait specification.
+    pub fn new<S>(spec: S) -> Self
+    where
+        S: Into<TraitSpec>,
+    {
+        let mut registry = Registry::new();
+
+        Self {
+            spec: spec.into().into_portable(&mut registry),
+            registry: registry.into(),
+        }
+    }
+
+    /// Returns a read-only registry of types in the ink! trait definition.
+    pub fn registry(&self) -> &PortableRegistry {
+        &self.registry
+    }
+
+    /// Returns the specification of the ink! trait definition.
+    pub fn spec(&self) -> &TraitSpec<PortableForm> {
+        &self.spec
+    }
+}
+
+/// Provides the ink! metadata of an ink! trait definition.
+///
+/// # Note
+///
+/// This trait is automatically implemented for ink! trait definitions defined via
+/// `#[ink::trait_definition]` and is used to generate their [`InkInterface`].
+pub trait TraitMetadata {
+    /// Returns the ink! metadata of the ink! trait definition.
+    fn trait_spec() -> TraitSpec;
+}
+
+/// Provides the ink! metadata of an ink! event defined outside of an ink! smart contract.
+///
+/// # Note
+///
+/// This trait is automatically implemented for ink! events defined via `#[ink::event]`
+/// so that all ink! smart contracts emitting them describe them identically.
+pub trait EventMetadata {
+    /// Returns the ink! metadata of the event.
+    fn event_spec() -> EventSpec;
 }
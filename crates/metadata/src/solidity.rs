@@ -0,0 +1,545 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Exports the metadata of an ink! smart contract as best-effort Solidity ABI.
+//!
+//! This allows environments bridging to EVM tooling to consume the interface of
+//! ink! smart contracts. The exported ABI describes the constructor, messages and
+//! events of the contract as well as the variants of the error types returned by
+//! its messages.
+//!
+//! # Note
+//!
+//! The Solidity ABI describes the interface of the contract only. ink! smart contracts
+//! still expect their inputs to be SCALE encoded and identify their messages by their
+//! ink! selectors which in general differ from the selectors derived from the Solidity
+//! signatures.
+//!
+//! Constructs without Solidity equivalent are mapped on a best-effort basis and reported
+//! as [`Diagnostic`]s:
+//!
+//! - Only the first constructor is exported since Solidity contracts have a single one.
+//! - `Option<T>` is mapped to a `(bool isSome, T value)` tuple.
+//! - Enums carrying data, `char`s, bit sequences and recursive types are mapped to
+//!   `bytes` containing their SCALE encoding.
+//! - Messages returning `Result<T, E>` are mapped to functions returning `T` and
+//!   reverting with the errors described by the variants of `E`.
+
+use crate::{
+    InkProject,
+    MessageParamSpec,
+};
+use core::fmt;
+use scale_info::{
+    form::PortableForm,
+    Field,
+    PortableRegistry,
+    TypeDef,
+    TypeDefPrimitive,
+    TypeDefVariant,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+/// Exports the Solidity ABI of the given ink! project.
+pub fn generate(project: &InkProject) -> SolidityAbi {
+    let mut exporter = Exporter {
+        registry: project.registry(),
+        abi: Vec::new(),
+        diagnostics: Vec::new(),
+        stack: Vec::new(),
+    };
+    exporter.export(project);
+    SolidityAbi {
+        abi: exporter.abi,
+        diagnostics: exporter.diagnostics,
+    }
+}
+
+/// The Solidity ABI of an ink! smart contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolidityAbi {
+    abi: Vec<AbiItem>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl SolidityAbi {
+    /// Returns the items of the Solidity ABI.
+    ///
+    /// Serializing them yields the Solidity ABI JSON.
+    pub fn abi(&self) -> &[AbiItem] {
+        &self.abi
+    }
+
+    /// Returns the diagnostics for all constructs that do not map to the Solidity ABI.
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+}
+
+/// An item of the Solidity ABI.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum AbiItem {
+    /// The constructor of the contract.
+    #[serde(rename_all = "camelCase")]
+    Constructor {
+        inputs: Vec<AbiParam>,
+        state_mutability: StateMutability,
+    },
+    /// A message of the contract.
+    #[serde(rename_all = "camelCase")]
+    Function {
+        name: String,
+        inputs: Vec<AbiParam>,
+        outputs: Vec<AbiParam>,
+        state_mutability: StateMutability,
+    },
+    /// An event of the contract.
+    Event {
+        name: String,
+        inputs: Vec<AbiParam>,
+        anonymous: bool,
+    },
+    /// An error a message of the contract reverts with.
+    Error { name: String, inputs: Vec<AbiParam> },
+}
+
+/// A parameter of a Solidity ABI item.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AbiParam {
+    name: String,
+    #[serde(rename = "type")]
+    ty: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    components: Vec<AbiParam>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    indexed: Option<bool>,
+}
+
+impl AbiParam {
+    /// Returns the name of the parameter.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the Solidity type of the parameter, e.g. `uint128` or `tuple`.
+    pub fn ty(&self) -> &str {
+        &self.ty
+    }
+
+    /// Returns the components of the parameter if it is a tuple.
+    pub fn components(&self) -> &[AbiParam] {
+        &self.components
+    }
+
+    /// Returns `Some` if the parameter is an event parameter.
+    pub fn indexed(&self) -> Option<bool> {
+        self.indexed
+    }
+}
+
+/// The state mutability of a Solidity function.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StateMutability {
+    View,
+    Nonpayable,
+    Payable,
+}
+
+impl StateMutability {
+    fn new(mutates: bool, payable: bool) -> Self {
+        match (mutates, payable) {
+            (_, true) => Self::Payable,
+            (true, false) => Self::Nonpayable,
+            (false, false) => Self::View,
+        }
+    }
+}
+
+/// Reports a construct of the ink! metadata that does not map to the Solidity ABI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    location: String,
+    message: String,
+}
+
+impl Diagnostic {
+    /// Returns where the construct occurs, e.g. ``message `transfer` argument `to` ``.
+    pub fn location(&self) -> &str {
+        &self.location
+    }
+
+    /// Returns why and how the construct has been mapped.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.location, self.message)
+    }
+}
+
+/// Exports the Solidity ABI of a single ink! project.
+struct Exporter<'a> {
+    registry: &'a PortableRegistry,
+    abi: Vec<AbiItem>,
+    diagnostics: Vec<Diagnostic>,
+    /// The named types currently being mapped, used to detect recursive types.
+    stack: Vec<u32>,
+}
+
+impl<'a> Exporter<'a> {
+    fn export(&mut self, project: &InkProject) {
+        let spec = project.spec();
+        let mut constructors = spec.constructors().iter();
+        if let Some(constructor) = constructors.next() {
+            let location = format!("constructor `{}`", constructor.label());
+            let inputs = self.params(&location, constructor.args());
+            self.abi.push(AbiItem::Constructor {
+                inputs,
+                state_mutability: StateMutability::new(true, *constructor.payable()),
+            });
+        }
+        for constructor in constructors {
+            self.diagnose(
+                format!("constructor `{}`", constructor.label()),
+                "Solidity contracts have a single constructor, only the first one has been exported",
+            );
+        }
+        let mut errors = Vec::new();
+        for message in spec.messages() {
+            let location = format!("message `{}`", message.label());
+            let inputs = self.params(&location, message.args());
+            let mut outputs = Vec::new();
+            if let Some(return_type) = message.return_type().opt_type() {
+                let ok = self.unwrap_results(&location, return_type.ty().id, &mut errors);
+                if !self.is_unit(ok) {
+                    outputs.push(self.param(&format!("{} output", location), "", ok));
+                }
+            }
+            self.abi.push(AbiItem::Function {
+                name: message.label().clone(),
+                inputs,
+                outputs,
+                state_mutability: StateMutability::new(
+                    message.mutates(),
+                    message.payable(),
+                ),
+            });
+        }
+        for event in spec.events() {
+            let location = format!("event `{}`", event.label());
+            let inputs = event
+                .args()
+                .iter()
+                .map(|arg| {
+                    let location = format!("{} field `{}`", location, arg.label());
+                    AbiParam {
+                        indexed: Some(arg.indexed()),
+                        ..self.param(&location, arg.label(), arg.ty().ty().id)
+                    }
+                })
+                .collect();
+            self.abi.push(AbiItem::Event {
+                name: event.label().clone(),
+                inputs,
+                anonymous: event.signature_topic().is_none(),
+            });
+        }
+        self.abi.extend(errors);
+    }
+
+    /// Strips all `Result` layers off the type with the given identifier and collects
+    /// the variants of their error types as Solidity errors.
+    ///
+    /// Returns the identifier of the innermost `Ok` type.
+    fn unwrap_results(
+        &mut self,
+        location: &str,
+        id: u32,
+        errors: &mut Vec<AbiItem>,
+    ) -> u32 {
+        let registry = self.registry;
+        let (ok, err) = match registry.resolve(id) {
+            Some(ty) if ty.path.segments == ["Result"] => {
+                match &ty.type_def {
+                    TypeDef::Variant(variant) => {
+                        match (field_of(variant, "Ok"), field_of(variant, "Err")) {
+                            (Some(ok), Some(err)) => (ok, err),
+                            _ => return id,
+                        }
+                    }
+                    _ => return id,
+                }
+            }
+            _ => return id,
+        };
+        match registry.resolve(err).map(|ty| &ty.type_def) {
+            Some(TypeDef::Variant(variant)) => {
+                for variant in &variant.variants {
+                    let exists = errors.iter().any(|error| {
+                        matches!(error, AbiItem::Error { name, .. } if *name == variant.name)
+                    });
+                    if exists {
+                        continue
+                    }
+                    let location = format!("{} error `{}`", location, variant.name);
+                    let inputs = self.fields(&location, &variant.fields);
+                    errors.push(AbiItem::Error {
+                        name: variant.name.clone(),
+                        inputs,
+                    });
+                }
+            }
+            _ => {
+                let location = format!("{} error", location);
+                let inputs = vec![self.param(&location, "error", err)];
+                errors.push(AbiItem::Error {
+                    name: String::from("Error"),
+                    inputs,
+                });
+            }
+        }
+        self.unwrap_results(location, ok, errors)
+    }
+
+    /// Maps the arguments of a constructor or message to Solidity parameters.
+    fn params(
+        &mut self,
+        location: &str,
+        args: &[MessageParamSpec<PortableForm>],
+    ) -> Vec<AbiParam> {
+        args.iter()
+            .map(|arg| {
+                let location = format!("{} argument `{}`", location, arg.label());
+                self.param(&location, arg.label(), arg.ty().ty().id)
+            })
+            .collect()
+    }
+
+    /// Maps the fields of a struct or enum variant to Solidity parameters.
+    ///
+    /// Unnamed fields are mapped to unnamed parameters.
+    fn fields(
+        &mut self,
+        location: &str,
+        fields: &[Field<PortableForm>],
+    ) -> Vec<AbiParam> {
+        fields
+            .iter()
+            .enumerate()
+            .map(|(n, field)| {
+                let name = field.name.as_deref().unwrap_or_default();
+                let location = match &field.name {
+                    Some(name) => format!("{} field `{}`", location, name),
+                    None => format!("{} field `{}`", location, n),
+                };
+                self.param(&location, name, field.ty.id)
+            })
+            .collect()
+    }
+
+    /// Maps the type with the given identifier to a Solidity parameter.
+    fn param(&mut self, location: &str, name: &str, id: u32) -> AbiParam {
+        let (ty, components) = self.ty(location, id);
+        AbiParam {
+            name: String::from(name),
+            ty,
+            components,
+            indexed: None,
+        }
+    }
+
+    /// Returns the Solidity type and tuple components of the type with the given identifier.
+    fn ty(&mut self, location: &str, id: u32) -> (String, Vec<AbiParam>) {
+        let registry = self.registry;
+        let ty = match registry.resolve(id) {
+            Some(ty) => ty,
+            None => {
+                self.diagnose(
+                    location,
+                    format!("unknown type #{}, mapped to `bytes`", id),
+                );
+                return (String::from("bytes"), Vec::new())
+            }
+        };
+        if self.stack.contains(&id) {
+            self.diagnose(
+                location,
+                "recursive types have no Solidity equivalent, mapped to `bytes`",
+            );
+            return (String::from("bytes"), Vec::new())
+        }
+        self.stack.push(id);
+        let mapped = match &ty.type_def {
+            TypeDef::Composite(composite) => {
+                match composite.fields.as_slice() {
+                    [field] if field.name.is_none() => self.ty(location, field.ty.id),
+                    [] => (String::from("tuple"), Vec::new()),
+                    fields => (String::from("tuple"), self.fields(location, fields)),
+                }
+            }
+            TypeDef::Variant(variant) => self.variant(location, variant),
+            TypeDef::Sequence(sequence) => {
+                if self.is_byte(sequence.type_param.id) {
+                    (String::from("bytes"), Vec::new())
+                } else {
+                    let (ty, components) = self.ty(location, sequence.type_param.id);
+                    (format!("{}[]", ty), components)
+                }
+            }
+            TypeDef::Array(array) => {
+                if self.is_byte(array.type_param.id) && (1..=32).contains(&array.len) {
+                    (format!("bytes{}", array.len), Vec::new())
+                } else {
+                    let (ty, components) = self.ty(location, array.type_param.id);
+                    (format!("{}[{}]", ty, array.len), components)
+                }
+            }
+            TypeDef::Tuple(tuple) => {
+                let components = tuple
+                    .fields
+                    .iter()
+                    .map(|field| self.param(location, "", field.id))
+                    .collect();
+                (String::from("tuple"), components)
+            }
+            TypeDef::Primitive(primitive) => {
+                if let TypeDefPrimitive::Char = primitive {
+                    self.diagnose(
+                        location,
+                        "`char` has no Solidity equivalent, mapped to `bytes`",
+                    );
+                }
+                (String::from(primitive_type(primitive)), Vec::new())
+            }
+            TypeDef::Compact(compact) => self.ty(location, compact.type_param.id),
+            TypeDef::BitSequence(_) => {
+                self.diagnose(
+                    location,
+                    "bit sequences have no Solidity equivalent, mapped to `bytes`",
+                );
+                (String::from("bytes"), Vec::new())
+            }
+        };
+        self.stack.pop();
+        mapped
+    }
+
+    /// Returns the Solidity type and tuple components of an enum.
+    fn variant(
+        &mut self,
+        location: &str,
+        variant: &TypeDefVariant<PortableForm>,
+    ) -> (String, Vec<AbiParam>) {
+        if let (Some(none), Some(some), 2) = (
+            variant.variants.iter().find(|v| v.name == "None"),
+            field_of(variant, "Some"),
+            variant.variants.len(),
+        ) {
+            if none.fields.is_empty() {
+                self.diagnose(
+                    location,
+                    "`Option` has no Solidity equivalent, mapped to `(bool isSome, T value)`",
+                );
+                let components = vec![
+                    AbiParam {
+                        name: String::from("isSome"),
+                        ty: String::from("bool"),
+                        components: Vec::new(),
+                        indexed: None,
+                    },
+                    self.param(location, "value", some),
+                ];
+                return (String::from("tuple"), components)
+            }
+        }
+        if variant.variants.iter().all(|v| v.fields.is_empty()) {
+            return (String::from("uint8"), Vec::new())
+        }
+        self.diagnose(
+            location,
+            "enums carrying data have no Solidity equivalent, mapped to `bytes`",
+        );
+        (String::from("bytes"), Vec::new())
+    }
+
+    /// Returns `true` if the type with the given identifier is `u8`.
+    fn is_byte(&self, id: u32) -> bool {
+        matches!(
+            self.registry.resolve(id).map(|ty| &ty.type_def),
+            Some(TypeDef::Primitive(TypeDefPrimitive::U8))
+        )
+    }
+
+    /// Returns `true` if the type with the given identifier is the unit type `()`.
+    fn is_unit(&self, id: u32) -> bool {
+        matches!(
+            self.registry.resolve(id).map(|ty| &ty.type_def),
+            Some(TypeDef::Tuple(tuple)) if tuple.fields.is_empty()
+        )
+    }
+
+    fn diagnose<L, M>(&mut self, location: L, message: M)
+    where
+        L: Into<String>,
+        M: Into<String>,
+    {
+        self.diagnostics.push(Diagnostic {
+            location: location.into(),
+            message: message.into(),
+        })
+    }
+}
+
+/// Returns the type identifier of the single field of the enum variant with the given name.
+fn field_of(variant: &TypeDefVariant<PortableForm>, name: &str) -> Option<u32> {
+    variant
+        .variants
+        .iter()
+        .find(|v| v.name == name)
+        .and_then(|v| {
+            match v.fields.as_slice() {
+                [field] => Some(field.ty.id),
+                _ => None,
+            }
+        })
+}
+
+/// Returns the Solidity type of the given primitive type.
+fn primitive_type(primitive: &TypeDefPrimitive) -> &'static str {
+    match primitive {
+        TypeDefPrimitive::Bool => "bool",
+        TypeDefPrimitive::Str => "string",
+        TypeDefPrimitive::Char => "bytes",
+        TypeDefPrimitive::U8 => "uint8",
+        TypeDefPrimitive::U16 => "uint16",
+        TypeDefPrimitive::U32 => "uint32",
+        TypeDefPrimitive::U64 => "uint64",
+        TypeDefPrimitive::U128 => "uint128",
+        TypeDefPrimitive::U256 => "uint256",
+        TypeDefPrimitive::I8 => "int8",
+        TypeDefPrimitive::I16 => "int16",
+        TypeDefPrimitive::I32 => "int32",
+        TypeDefPrimitive::I64 => "int64",
+        TypeDefPrimitive::I128 => "int128",
+        TypeDefPrimitive::I256 => "int256",
+    }
+}
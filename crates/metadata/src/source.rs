@@ -0,0 +1,140 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::serde_hex;
+use ink_prelude::string::String;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+/// Describes how the contract was built.
+///
+/// The ink! code generation records the ink! version of the contract. Build tools
+/// add the hash of the resulting Wasm code as well as the versions of the compiler
+/// and of themselves once the contract has been compiled.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Source {
+    /// The hash of the compiled Wasm code.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    hash: Option<CodeHash>,
+    /// The language and its version the contract is written in, e.g. `ink! 3.0.1`.
+    language: String,
+    /// The compiler and its version used to compile the contract, e.g. `rustc 1.61.0`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    compiler: Option<String>,
+    /// The build tool and its version used to build the contract, e.g. `cargo-contract 1.3.0`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    build_tool: Option<String>,
+}
+
+impl Source {
+    /// Creates a new source description for the given language and version.
+    pub fn new<L>(language: L) -> Self
+    where
+        L: Into<String>,
+    {
+        Self {
+            hash: None,
+            language: language.into(),
+            compiler: None,
+            build_tool: None,
+        }
+    }
+
+    /// Sets the hash of the compiled Wasm code.
+    pub fn with_hash<H>(self, hash: H) -> Self
+    where
+        H: Into<CodeHash>,
+    {
+        Self {
+            hash: Some(hash.into()),
+            ..self
+        }
+    }
+
+    /// Sets the compiler and its version used to compile the contract.
+    pub fn with_compiler<C>(self, compiler: C) -> Self
+    where
+        C: Into<String>,
+    {
+        Self {
+            compiler: Some(compiler.into()),
+            ..self
+        }
+    }
+
+    /// Sets the build tool and its version used to build the contract.
+    pub fn with_build_tool<B>(self, build_tool: B) -> Self
+    where
+        B: Into<String>,
+    {
+        Self {
+            build_tool: Some(build_tool.into()),
+            ..self
+        }
+    }
+
+    /// Returns the hash of the compiled Wasm code if known.
+    pub fn hash(&self) -> Option<&CodeHash> {
+        self.hash.as_ref()
+    }
+
+    /// Returns the language and its version the contract is written in.
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    /// Returns the compiler and its version used to compile the contract if known.
+    pub fn compiler(&self) -> Option<&str> {
+        self.compiler.as_deref()
+    }
+
+    /// Returns the build tool and its version used to build the contract if known.
+    pub fn build_tool(&self) -> Option<&str> {
+        self.build_tool.as_deref()
+    }
+}
+
+/// The 32 byte hash of the compiled Wasm code of a contract.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::From)]
+pub struct CodeHash([u8; 32]);
+
+impl serde::Serialize for CodeHash {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde_hex::serialize(&self.0, serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for CodeHash {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let mut arr = [0; 32];
+        serde_hex::deserialize_check_len(d, serde_hex::ExpectedLen::Exact(&mut arr[..]))?;
+        Ok(arr.into())
+    }
+}
+
+impl CodeHash {
+    /// Returns the underlying code hash bytes.
+    pub fn to_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
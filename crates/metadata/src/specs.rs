@@ -54,6 +54,38 @@ pub struct ContractSpec<F: Form = MetaForm> {
     events: Vec<EventSpec<F>>,
     /// The contract documentation.
     docs: Vec<F::String>,
+    /// The version of the contract's interface, usually taken from its `Cargo.toml`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    version: Option<F::String>,
+    /// The authors of the contract.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    authors: Vec<F::String>,
+    /// The license of the contract.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    license: Option<F::String>,
+    /// The URL of the source code repository of the contract.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    repository: Option<F::String>,
+    /// The URL of the homepage of the contract.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    homepage: Option<F::String>,
+    /// The type of the errors emitted by the ink! language itself.
+    ///
+    /// Every ink! message returns its output wrapped in a `Result` whose error
+    /// variant is of this type, so that dispatch failures such as undecodable
+    /// input can be told apart from the output of the message.
+    #[serde(rename = "langError", default, skip_serializing_if = "Option::is_none")]
+    lang_error: Option<TypeSpec<F>>,
+    /// The chain extensions used by the contract.
+    #[serde(
+        rename = "chainExtensions",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    chain_extensions: Vec<ChainExtensionSpec<F>>,
+    /// The environment types of the contract.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    environment: Option<EnvironmentSpec<F>>,
 }
 
 impl IntoPortable for ContractSpec {
@@ -77,6 +109,26 @@ impl IntoPortable for ContractSpec {
                 .map(|event| event.into_portable(registry))
                 .collect::<Vec<_>>(),
             docs: registry.map_into_portable(self.docs),
+            version: self.version.map(|version| version.into_portable(registry)),
+            authors: registry.map_into_portable(self.authors),
+            license: self.license.map(|license| license.into_portable(registry)),
+            repository: self
+                .repository
+                .map(|repository| repository.into_portable(registry)),
+            homepage: self
+                .homepage
+                .map(|homepage| homepage.into_portable(registry)),
+            lang_error: self
+                .lang_error
+                .map(|lang_error| lang_error.into_portable(registry)),
+            chain_extensions: self
+                .chain_extensions
+                .into_iter()
+                .map(|chain_extension| chain_extension.into_portable(registry))
+                .collect::<Vec<_>>(),
+            environment: self
+                .environment
+                .map(|environment| environment.into_portable(registry)),
         }
     }
 }
@@ -104,6 +156,46 @@ where
     pub fn docs(&self) -> &[F::String] {
         &self.docs
     }
+
+    /// Returns the version of the contract if any.
+    pub fn version(&self) -> Option<&F::String> {
+        self.version.as_ref()
+    }
+
+    /// Returns the authors of the contract.
+    pub fn authors(&self) -> &[F::String] {
+        &self.authors
+    }
+
+    /// Returns the license of the contract if any.
+    pub fn license(&self) -> Option<&F::String> {
+        self.license.as_ref()
+    }
+
+    /// Returns the URL of the source code repository of the contract if any.
+    pub fn repository(&self) -> Option<&F::String> {
+        self.repository.as_ref()
+    }
+
+    /// Returns the URL of the homepage of the contract if any.
+    pub fn homepage(&self) -> Option<&F::String> {
+        self.homepage.as_ref()
+    }
+
+    /// Returns the type of the errors emitted by the ink! language itself if any.
+    pub fn lang_error(&self) -> Option<&TypeSpec<F>> {
+        self.lang_error.as_ref()
+    }
+
+    /// Returns the chain extensions used by the contract.
+    pub fn chain_extensions(&self) -> &[ChainExtensionSpec<F>] {
+        &self.chain_extensions
+    }
+
+    /// Returns the environment types of the contract if any.
+    pub fn environment(&self) -> Option<&EnvironmentSpec<F>> {
+        self.environment.as_ref()
+    }
 }
 
 /// The message builder is ready to finalize construction.
@@ -182,6 +274,117 @@ impl<S> ContractSpecBuilder<S> {
             ..self
         }
     }
+
+    /// Sets the version of the contract specification.
+    pub fn version(self, version: &'static str) -> Self {
+        debug_assert!(self.spec.version.is_none());
+        Self {
+            spec: ContractSpec {
+                version: Some(version),
+                ..self.spec
+            },
+            ..self
+        }
+    }
+
+    /// Sets the authors of the contract specification.
+    pub fn authors<A>(self, authors: A) -> Self
+    where
+        A: IntoIterator<Item = &'static str>,
+    {
+        debug_assert!(self.spec.authors.is_empty());
+        Self {
+            spec: ContractSpec {
+                authors: authors.into_iter().collect::<Vec<_>>(),
+                ..self.spec
+            },
+            ..self
+        }
+    }
+
+    /// Sets the license of the contract specification.
+    pub fn license<L>(self, license: L) -> Self
+    where
+        L: Into<Option<&'static str>>,
+    {
+        debug_assert!(self.spec.license.is_none());
+        Self {
+            spec: ContractSpec {
+                license: license.into(),
+                ..self.spec
+            },
+            ..self
+        }
+    }
+
+    /// Sets the URL of the source code repository of the contract specification.
+    pub fn repository<R>(self, repository: R) -> Self
+    where
+        R: Into<Option<&'static str>>,
+    {
+        debug_assert!(self.spec.repository.is_none());
+        Self {
+            spec: ContractSpec {
+                repository: repository.into(),
+                ..self.spec
+            },
+            ..self
+        }
+    }
+
+    /// Sets the URL of the homepage of the contract specification.
+    pub fn homepage<H>(self, homepage: H) -> Self
+    where
+        H: Into<Option<&'static str>>,
+    {
+        debug_assert!(self.spec.homepage.is_none());
+        Self {
+            spec: ContractSpec {
+                homepage: homepage.into(),
+                ..self.spec
+            },
+            ..self
+        }
+    }
+
+    /// Sets the type of the errors emitted by the ink! language itself.
+    pub fn lang_error(self, lang_error: TypeSpec) -> Self {
+        debug_assert!(self.spec.lang_error.is_none());
+        Self {
+            spec: ContractSpec {
+                lang_error: Some(lang_error),
+                ..self.spec
+            },
+            ..self
+        }
+    }
+
+    /// Sets the chain extensions used by the contract.
+    pub fn chain_extensions<C>(self, chain_extensions: C) -> Self
+    where
+        C: IntoIterator<Item = ChainExtensionSpec>,
+    {
+        debug_assert!(self.spec.chain_extensions.is_empty());
+        Self {
+            spec: ContractSpec {
+                chain_extensions: chain_extensions.into_iter().collect::<Vec<_>>(),
+                ..self.spec
+            },
+            ..self
+        }
+    }
+
+    /// Sets the environment types of the contract.
+    pub fn environment(self, environment: EnvironmentSpec) -> Self {
+        debug_assert!(self.spec.environment.is_none());
+        Self {
+            spec: ContractSpec {
+                environment: Some(environment),
+                ..self.spec
+            },
+            ..self
+        }
+    }
 }
 
 impl ContractSpecBuilder<Valid> {
@@ -208,6 +411,14 @@ impl ContractSpec {
                 messages: Vec::new(),
                 events: Vec::new(),
                 docs: Vec::new(),
+                version: None,
+                authors: Vec::new(),
+                license: None,
+                repository: None,
+                homepage: None,
+                lang_error: None,
+                chain_extensions: Vec::new(),
+                environment: None,
             },
             marker: PhantomData,
         }
@@ -229,6 +440,9 @@ pub struct ConstructorSpec<F: Form = MetaForm> {
     pub selector: Selector,
     /// If the constructor accepts any `value` from the caller.
     pub payable: bool,
+    /// If the constructor is the default to be preselected by user interfaces.
+    #[serde(default)]
+    pub default: bool,
     /// The parameters of the deployment handler.
     pub args: Vec<MessageParamSpec<F>>,
     /// The deployment handler documentation.
@@ -243,6 +457,7 @@ impl IntoPortable for ConstructorSpec {
             label: self.label.into_portable(registry),
             selector: self.selector,
             payable: self.payable,
+            default: self.default,
             args: self
                 .args
                 .into_iter()
@@ -274,6 +489,11 @@ where
         &self.payable
     }
 
+    /// Returns true if the constructor is the default to be preselected by user interfaces.
+    pub fn default(&self) -> bool {
+        self.default
+    }
+
     /// Returns the parameters of the deployment handler.
     pub fn args(&self) -> &[MessageParamSpec<F>] {
         &self.args
@@ -308,6 +528,7 @@ impl ConstructorSpec {
                 label,
                 selector: Selector::default(),
                 payable: Default::default(),
+                default: false,
                 args: Vec::new(),
                 docs: Vec::new(),
             },
@@ -370,6 +591,13 @@ impl<S, P> ConstructorSpecBuilder<S, P> {
         this.spec.docs = docs.into_iter().map(str::trim).collect::<Vec<_>>();
         this
     }
+
+    /// Sets if the constructor is the default to be preselected by user interfaces.
+    pub fn default(self, is_default: bool) -> Self {
+        let mut this = self;
+        this.spec.default = is_default;
+        this
+    }
 }
 
 impl ConstructorSpecBuilder<state::Selector, state::IsPayable> {
@@ -398,12 +626,18 @@ pub struct MessageSpec<F: Form = MetaForm> {
     mutates: bool,
     /// If the message accepts any `value` from the caller.
     payable: bool,
+    /// If the message is the default to be preselected by user interfaces.
+    #[serde(default)]
+    default: bool,
     /// The parameters of the message.
     args: Vec<MessageParamSpec<F>>,
     /// The return type of the message.
     return_type: ReturnTypeSpec<F>,
     /// The message documentation.
     docs: Vec<F::String>,
+    /// The deprecation note of the message if it is deprecated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    deprecated: Option<F::String>,
 }
 
 /// Type state for builders to tell that some mandatory state has not yet been set
@@ -440,9 +674,11 @@ impl MessageSpec {
                 selector: Selector::default(),
                 mutates: false,
                 payable: false,
+                default: false,
                 args: Vec::new(),
                 return_type: ReturnTypeSpec::new(None),
                 docs: Vec::new(),
+                deprecated: None,
             },
             marker: PhantomData,
         }
@@ -476,6 +712,11 @@ where
         self.payable
     }
 
+    /// Returns true if the message is the default to be preselected by user interfaces.
+    pub fn default(&self) -> bool {
+        self.default
+    }
+
     /// Returns the parameters of the message.
     pub fn args(&self) -> &[MessageParamSpec<F>] {
         &self.args
@@ -490,6 +731,11 @@ where
     pub fn docs(&self) -> &[F::String] {
         &self.docs
     }
+
+    /// Returns the deprecation note of the message if it is deprecated.
+    pub fn deprecated(&self) -> Option<&F::String> {
+        self.deprecated.as_ref()
+    }
 }
 
 /// A builder for messages.
@@ -589,6 +835,21 @@ impl<S, M, P, R> MessageSpecBuilder<S, M, P, R> {
         this.spec.docs = docs.into_iter().collect::<Vec<_>>();
         this
     }
+
+    /// Marks the message as deprecated with the given deprecation note.
+    pub fn deprecated(self, note: &'static str) -> Self {
+        let mut this = self;
+        debug_assert!(this.spec.deprecated.is_none());
+        this.spec.deprecated = Some(note);
+        this
+    }
+
+    /// Sets if the message is the default to be preselected by user interfaces.
+    pub fn default(self, is_default: bool) -> Self {
+        let mut this = self;
+        this.spec.default = is_default;
+        this
+    }
 }
 
 impl
@@ -609,6 +870,7 @@ impl IntoPortable for MessageSpec {
             selector: self.selector,
             mutates: self.mutates,
             payable: self.payable,
+            default: self.default,
             args: self
                 .args
                 .into_iter()
@@ -616,6 +878,7 @@ impl IntoPortable for MessageSpec {
                 .collect::<Vec<_>>(),
             return_type: self.return_type.into_portable(registry),
             docs: registry.map_into_portable(self.docs),
+            deprecated: self.deprecated.map(|note| note.into_portable(registry)),
         }
     }
 }
@@ -626,6 +889,7 @@ impl IntoPortable for MessageSpec {
     serialize = "F::Type: Serialize, F::String: Serialize",
     deserialize = "F::Type: DeserializeOwned, F::String: DeserializeOwned"
 ))]
+#[serde(rename_all = "camelCase")]
 pub struct EventSpec<F: Form = MetaForm> {
     /// The label of the event.
     label: F::String,
@@ -633,6 +897,11 @@ pub struct EventSpec<F: Form = MetaForm> {
     args: Vec<EventParamSpec<F>>,
     /// The event documentation.
     docs: Vec<F::String>,
+    /// The signature topic of the event.
+    ///
+    /// This is always the first topic of the event and is not set for anonymous events.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    signature_topic: Option<SignatureTopic>,
 }
 
 /// An event specification builder.
@@ -664,6 +933,17 @@ impl EventSpecBuilder {
         this
     }
 
+    /// Sets the signature topic of the event specification.
+    pub fn signature_topic<T>(self, topic: T) -> Self
+    where
+        T: Into<SignatureTopic>,
+    {
+        let mut this = self;
+        debug_assert!(this.spec.signature_topic.is_none());
+        this.spec.signature_topic = Some(topic.into());
+        this
+    }
+
     /// Finalizes building the event specification.
     pub fn done(self) -> EventSpec {
         self.spec
@@ -682,6 +962,7 @@ impl IntoPortable for EventSpec {
                 .map(|arg| arg.into_portable(registry))
                 .collect::<Vec<_>>(),
             docs: registry.map_into_portable(self.docs),
+            signature_topic: self.signature_topic,
         }
     }
 }
@@ -694,6 +975,7 @@ impl EventSpec {
                 label,
                 args: Vec::new(),
                 docs: Vec::new(),
+                signature_topic: None,
             },
         }
     }
@@ -717,6 +999,632 @@ where
     pub fn docs(&self) -> &[F::String] {
         &self.docs
     }
+
+    /// The signature topic of the event if it is not anonymous.
+    pub fn signature_topic(&self) -> Option<&SignatureTopic> {
+        self.signature_topic.as_ref()
+    }
+}
+
+/// Describes an ink! trait definition independent of any contract implementing it.
+///
+/// Allows to publish the canonical interface of a standard, e.g. for wallets to code
+/// against, without a concrete contract.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "F::Type: Serialize, F::String: Serialize",
+    deserialize = "F::Type: DeserializeOwned, F::String: DeserializeOwned"
+))]
+pub struct TraitSpec<F: Form = MetaForm> {
+    /// The label of the ink! trait definition.
+    label: F::String,
+    /// The messages of the ink! trait definition.
+    messages: Vec<MessageSpec<F>>,
+    /// The ink! trait definition documentation.
+    docs: Vec<F::String>,
+}
+
+/// A trait specification builder.
+#[must_use]
+pub struct TraitSpecBuilder {
+    spec: TraitSpec,
+}
+
+impl TraitSpecBuilder {
+    /// Sets the messages of the trait specification.
+    pub fn messages<M>(self, messages: M) -> Self
+    where
+        M: IntoIterator<Item = MessageSpec>,
+    {
+        let mut this = self;
+        debug_assert!(this.spec.messages.is_empty());
+        this.spec.messages = messages.into_iter().collect::<Vec<_>>();
+        this
+    }
+
+    /// Sets the documentation of the trait specification.
+    pub fn docs<D>(self, docs: D) -> Self
+    where
+        D: IntoIterator<Item = &'static str>,
+    {
+        let mut this = self;
+        debug_assert!(this.spec.docs.is_empty());
+        this.spec.docs = docs.into_iter().collect::<Vec<_>>();
+        this
+    }
+
+    /// Finalizes building the trait specification.
+    pub fn done(self) -> TraitSpec {
+        assert!(
+            !self.spec.messages.is_empty(),
+            "must have at least one message"
+        );
+        self.spec
+    }
+}
+
+impl IntoPortable for TraitSpec {
+    type Output = TraitSpec<PortableForm>;
+
+    fn into_portable(self, registry: &mut Registry) -> Self::Output {
+        TraitSpec {
+            label: self.label.into_portable(registry),
+            messages: self
+                .messages
+                .into_iter()
+                .map(|message| message.into_portable(registry))
+                .collect::<Vec<_>>(),
+            docs: registry.map_into_portable(self.docs),
+        }
+    }
+}
+
+impl TraitSpec {
+    /// Creates a new trait specification builder.
+    pub fn new(label: &'static str) -> TraitSpecBuilder {
+        TraitSpecBuilder {
+            spec: Self {
+                label,
+                messages: Vec::new(),
+                docs: Vec::new(),
+            },
+        }
+    }
+}
+
+impl<F> TraitSpec<F>
+where
+    F: Form,
+{
+    /// Returns the label of the ink! trait definition.
+    pub fn label(&self) -> &F::String {
+        &self.label
+    }
+
+    /// Returns the messages of the ink! trait definition.
+    pub fn messages(&self) -> &[MessageSpec<F>] {
+        &self.messages
+    }
+
+    /// Returns the ink! trait definition documentation.
+    pub fn docs(&self) -> &[F::String] {
+        &self.docs
+    }
+}
+
+/// Describes a chain extension used by a contract.
+///
+/// Allows front-ends and chains to verify at deployment time that the chain
+/// extensions required by a contract are provided by the target runtime.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "F::Type: Serialize, F::String: Serialize",
+    deserialize = "F::Type: DeserializeOwned, F::String: DeserializeOwned"
+))]
+#[serde(rename_all = "camelCase")]
+pub struct ChainExtensionSpec<F: Form = MetaForm> {
+    /// The label of the chain extension.
+    label: F::String,
+    /// The methods of the chain extension.
+    methods: Vec<ChainExtensionMethodSpec<F>>,
+    /// The type of the error code shared by all methods of the chain extension.
+    error_code: TypeSpec<F>,
+    /// The status codes returned by the chain extension and the error codes they map to.
+    ///
+    /// Empty if the error code does not describe its status codes.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    status_codes: Vec<StatusCodeSpec<F>>,
+    /// The chain extension documentation.
+    docs: Vec<F::String>,
+}
+
+/// A chain extension specification builder.
+#[must_use]
+pub struct ChainExtensionSpecBuilder {
+    spec: ChainExtensionSpec,
+}
+
+impl ChainExtensionSpecBuilder {
+    /// Sets the methods of the chain extension specification.
+    pub fn methods<M>(self, methods: M) -> Self
+    where
+        M: IntoIterator<Item = ChainExtensionMethodSpec>,
+    {
+        let mut this = self;
+        debug_assert!(this.spec.methods.is_empty());
+        this.spec.methods = methods.into_iter().collect::<Vec<_>>();
+        this
+    }
+
+    /// Sets the status codes of the chain extension specification.
+    pub fn status_codes<S>(self, status_codes: S) -> Self
+    where
+        S: IntoIterator<Item = StatusCodeSpec>,
+    {
+        let mut this = self;
+        debug_assert!(this.spec.status_codes.is_empty());
+        this.spec.status_codes = status_codes.into_iter().collect::<Vec<_>>();
+        this
+    }
+
+    /// Sets the documentation of the chain extension specification.
+    pub fn docs<D>(self, docs: D) -> Self
+    where
+        D: IntoIterator<Item = &'static str>,
+    {
+        let mut this = self;
+        debug_assert!(this.spec.docs.is_empty());
+        this.spec.docs = docs.into_iter().collect::<Vec<_>>();
+        this
+    }
+
+    /// Finalizes building the chain extension specification.
+    pub fn done(self) -> ChainExtensionSpec {
+        self.spec
+    }
+}
+
+impl IntoPortable for ChainExtensionSpec {
+    type Output = ChainExtensionSpec<PortableForm>;
+
+    fn into_portable(self, registry: &mut Registry) -> Self::Output {
+        ChainExtensionSpec {
+            label: self.label.into_portable(registry),
+            methods: self
+                .methods
+                .into_iter()
+                .map(|method| method.into_portable(registry))
+                .collect::<Vec<_>>(),
+            error_code: self.error_code.into_portable(registry),
+            status_codes: self
+                .status_codes
+                .into_iter()
+                .map(|status_code| status_code.into_portable(registry))
+                .collect::<Vec<_>>(),
+            docs: registry.map_into_portable(self.docs),
+        }
+    }
+}
+
+impl ChainExtensionSpec {
+    /// Creates a new chain extension specification builder.
+    pub fn new(label: &'static str, error_code: TypeSpec) -> ChainExtensionSpecBuilder {
+        ChainExtensionSpecBuilder {
+            spec: Self {
+                label,
+                methods: Vec::new(),
+                error_code,
+                status_codes: Vec::new(),
+                docs: Vec::new(),
+            },
+        }
+    }
+}
+
+impl<F> ChainExtensionSpec<F>
+where
+    F: Form,
+{
+    /// Returns the label of the chain extension.
+    pub fn label(&self) -> &F::String {
+        &self.label
+    }
+
+    /// Returns the methods of the chain extension.
+    pub fn methods(&self) -> &[ChainExtensionMethodSpec<F>] {
+        &self.methods
+    }
+
+    /// Returns the type of the error code of the chain extension.
+    pub fn error_code(&self) -> &TypeSpec<F> {
+        &self.error_code
+    }
+
+    /// Returns the status codes of the chain extension.
+    pub fn status_codes(&self) -> &[StatusCodeSpec<F>] {
+        &self.status_codes
+    }
+
+    /// Returns the chain extension documentation.
+    pub fn docs(&self) -> &[F::String] {
+        &self.docs
+    }
+}
+
+/// Describes a method of a chain extension.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "F::Type: Serialize, F::String: Serialize",
+    deserialize = "F::Type: DeserializeOwned, F::String: DeserializeOwned"
+))]
+#[serde(rename_all = "camelCase")]
+pub struct ChainExtensionMethodSpec<F: Form = MetaForm> {
+    /// The label of the chain extension method.
+    label: F::String,
+    /// The function ID under which the chain extension method is called.
+    func_id: u32,
+    /// The parameters of the chain extension method.
+    args: Vec<MessageParamSpec<F>>,
+    /// The return type of the chain extension method.
+    return_type: ReturnTypeSpec<F>,
+    /// If the status code returned by the chain is checked against the error code.
+    handle_status: bool,
+    /// If the chain extension method returns a `Result` that is decoded as such.
+    returns_result: bool,
+    /// The chain extension method documentation.
+    docs: Vec<F::String>,
+}
+
+/// A chain extension method specification builder.
+#[must_use]
+pub struct ChainExtensionMethodSpecBuilder {
+    spec: ChainExtensionMethodSpec,
+}
+
+impl ChainExtensionMethodSpecBuilder {
+    /// Sets the input arguments of the chain extension method specification.
+    pub fn args<A>(self, args: A) -> Self
+    where
+        A: IntoIterator<Item = MessageParamSpec>,
+    {
+        let mut this = self;
+        debug_assert!(this.spec.args.is_empty());
+        this.spec.args = args.into_iter().collect::<Vec<_>>();
+        this
+    }
+
+    /// Sets the return type of the chain extension method specification.
+    pub fn returns(self, return_type: ReturnTypeSpec) -> Self {
+        let mut this = self;
+        this.spec.return_type = return_type;
+        this
+    }
+
+    /// Sets if the chain extension method handles the status code.
+    pub fn handle_status(self, handle_status: bool) -> Self {
+        let mut this = self;
+        this.spec.handle_status = handle_status;
+        this
+    }
+
+    /// Sets if the chain extension method returns a `Result`.
+    pub fn returns_result(self, returns_result: bool) -> Self {
+        let mut this = self;
+        this.spec.returns_result = returns_result;
+        this
+    }
+
+    /// Sets the documentation of the chain extension method specification.
+    pub fn docs<D>(self, docs: D) -> Self
+    where
+        D: IntoIterator<Item = &'static str>,
+    {
+        let mut this = self;
+        debug_assert!(this.spec.docs.is_empty());
+        this.spec.docs = docs.into_iter().collect::<Vec<_>>();
+        this
+    }
+
+    /// Finalizes building the chain extension method specification.
+    pub fn done(self) -> ChainExtensionMethodSpec {
+        self.spec
+    }
+}
+
+impl IntoPortable for ChainExtensionMethodSpec {
+    type Output = ChainExtensionMethodSpec<PortableForm>;
+
+    fn into_portable(self, registry: &mut Registry) -> Self::Output {
+        ChainExtensionMethodSpec {
+            label: self.label.into_portable(registry),
+            func_id: self.func_id,
+            args: self
+                .args
+                .into_iter()
+                .map(|arg| arg.into_portable(registry))
+                .collect::<Vec<_>>(),
+            return_type: self.return_type.into_portable(registry),
+            handle_status: self.handle_status,
+            returns_result: self.returns_result,
+            docs: registry.map_into_portable(self.docs),
+        }
+    }
+}
+
+impl ChainExtensionMethodSpec {
+    /// Creates a new chain extension method specification builder.
+    ///
+    /// By default the method neither handles the status code nor returns a `Result`.
+    pub fn new(label: &'static str, func_id: u32) -> ChainExtensionMethodSpecBuilder {
+        ChainExtensionMethodSpecBuilder {
+            spec: Self {
+                label,
+                func_id,
+                args: Vec::new(),
+                return_type: ReturnTypeSpec::new(None),
+                handle_status: false,
+                returns_result: false,
+                docs: Vec::new(),
+            },
+        }
+    }
+}
+
+impl<F> ChainExtensionMethodSpec<F>
+where
+    F: Form,
+{
+    /// Returns the label of the chain extension method.
+    pub fn label(&self) -> &F::String {
+        &self.label
+    }
+
+    /// Returns the function ID of the chain extension method.
+    pub fn func_id(&self) -> u32 {
+        self.func_id
+    }
+
+    /// Returns the parameters of the chain extension method.
+    pub fn args(&self) -> &[MessageParamSpec<F>] {
+        &self.args
+    }
+
+    /// Returns the return type of the chain extension method.
+    pub fn return_type(&self) -> &ReturnTypeSpec<F> {
+        &self.return_type
+    }
+
+    /// Returns `true` if the chain extension method handles the status code.
+    pub fn handle_status(&self) -> bool {
+        self.handle_status
+    }
+
+    /// Returns `true` if the chain extension method returns a `Result`.
+    pub fn returns_result(&self) -> bool {
+        self.returns_result
+    }
+
+    /// Returns the chain extension method documentation.
+    pub fn docs(&self) -> &[F::String] {
+        &self.docs
+    }
+}
+
+/// Describes a status code returned by a chain extension and the error code it maps to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "F::Type: Serialize, F::String: Serialize",
+    deserialize = "F::Type: DeserializeOwned, F::String: DeserializeOwned"
+))]
+#[serde(rename_all = "camelCase")]
+pub struct StatusCodeSpec<F: Form = MetaForm> {
+    /// The label of the error code variant the status code maps to.
+    label: F::String,
+    /// The status code or `None` if the error code variant catches all unknown status codes.
+    status_code: Option<u32>,
+}
+
+impl IntoPortable for StatusCodeSpec {
+    type Output = StatusCodeSpec<PortableForm>;
+
+    fn into_portable(self, registry: &mut Registry) -> Self::Output {
+        StatusCodeSpec {
+            label: self.label.into_portable(registry),
+            status_code: self.status_code,
+        }
+    }
+}
+
+impl StatusCodeSpec {
+    /// Creates a new status code specification.
+    pub const fn new(label: &'static str, status_code: u32) -> Self {
+        Self {
+            label,
+            status_code: Some(status_code),
+        }
+    }
+
+    /// Creates a new specification for an error code variant catching all unknown status codes.
+    pub const fn catch_all(label: &'static str) -> Self {
+        Self {
+            label,
+            status_code: None,
+        }
+    }
+}
+
+impl<F> StatusCodeSpec<F>
+where
+    F: Form,
+{
+    /// Returns the label of the error code variant.
+    pub fn label(&self) -> &F::String {
+        &self.label
+    }
+
+    /// Returns the status code or `None` if it catches all unknown status codes.
+    pub fn status_code(&self) -> Option<u32> {
+        self.status_code
+    }
+}
+
+/// Describes the environment types of a contract.
+///
+/// Contracts built against a custom environment may use different types than the
+/// default environment, e.g. a 20 bytes wide `AccountId` or a `u64` wide `Balance`.
+/// User interfaces need to know them in order to encode arguments for the contract.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "F::Type: Serialize, F::String: Serialize",
+    deserialize = "F::Type: DeserializeOwned, F::String: DeserializeOwned"
+))]
+#[serde(rename_all = "camelCase")]
+pub struct EnvironmentSpec<F: Form = MetaForm> {
+    /// The type of an address.
+    account_id: TypeSpec<F>,
+    /// The type of balances.
+    balance: TypeSpec<F>,
+    /// The type of hashes.
+    hash: TypeSpec<F>,
+    /// The type of timestamps.
+    timestamp: TypeSpec<F>,
+    /// The type of block numbers.
+    block_number: TypeSpec<F>,
+    /// The maximum number of topics an event may have.
+    max_event_topics: usize,
+}
+
+impl IntoPortable for EnvironmentSpec {
+    type Output = EnvironmentSpec<PortableForm>;
+
+    fn into_portable(self, registry: &mut Registry) -> Self::Output {
+        EnvironmentSpec {
+            account_id: self.account_id.into_portable(registry),
+            balance: self.balance.into_portable(registry),
+            hash: self.hash.into_portable(registry),
+            timestamp: self.timestamp.into_portable(registry),
+            block_number: self.block_number.into_portable(registry),
+            max_event_topics: self.max_event_topics,
+        }
+    }
+}
+
+/// A builder for environment specifications.
+///
+/// # Developer Note
+///
+/// All environment types must be set before the specification can be finalized.
+#[must_use]
+pub struct EnvironmentSpecBuilder {
+    account_id: Option<TypeSpec>,
+    balance: Option<TypeSpec>,
+    hash: Option<TypeSpec>,
+    timestamp: Option<TypeSpec>,
+    block_number: Option<TypeSpec>,
+    max_event_topics: usize,
+}
+
+impl EnvironmentSpecBuilder {
+    /// Sets the type of an address.
+    pub fn account_id(self, account_id: TypeSpec) -> Self {
+        Self {
+            account_id: Some(account_id),
+            ..self
+        }
+    }
+
+    /// Sets the type of balances.
+    pub fn balance(self, balance: TypeSpec) -> Self {
+        Self {
+            balance: Some(balance),
+            ..self
+        }
+    }
+
+    /// Sets the type of hashes.
+    pub fn hash(self, hash: TypeSpec) -> Self {
+        Self {
+            hash: Some(hash),
+            ..self
+        }
+    }
+
+    /// Sets the type of timestamps.
+    pub fn timestamp(self, timestamp: TypeSpec) -> Self {
+        Self {
+            timestamp: Some(timestamp),
+            ..self
+        }
+    }
+
+    /// Sets the type of block numbers.
+    pub fn block_number(self, block_number: TypeSpec) -> Self {
+        Self {
+            block_number: Some(block_number),
+            ..self
+        }
+    }
+
+    /// Finalizes construction of the environment specification.
+    pub fn done(self) -> EnvironmentSpec {
+        EnvironmentSpec {
+            account_id: self.account_id.expect("must have an account id type"),
+            balance: self.balance.expect("must have a balance type"),
+            hash: self.hash.expect("must have a hash type"),
+            timestamp: self.timestamp.expect("must have a timestamp type"),
+            block_number: self.block_number.expect("must have a block number type"),
+            max_event_topics: self.max_event_topics,
+        }
+    }
+}
+
+impl EnvironmentSpec {
+    /// Creates a new environment specification builder.
+    pub fn new(max_event_topics: usize) -> EnvironmentSpecBuilder {
+        EnvironmentSpecBuilder {
+            account_id: None,
+            balance: None,
+            hash: None,
+            timestamp: None,
+            block_number: None,
+            max_event_topics,
+        }
+    }
+}
+
+impl<F> EnvironmentSpec<F>
+where
+    F: Form,
+{
+    /// Returns the type of an address.
+    pub fn account_id(&self) -> &TypeSpec<F> {
+        &self.account_id
+    }
+
+    /// Returns the type of balances.
+    pub fn balance(&self) -> &TypeSpec<F> {
+        &self.balance
+    }
+
+    /// Returns the type of hashes.
+    pub fn hash(&self) -> &TypeSpec<F> {
+        &self.hash
+    }
+
+    /// Returns the type of timestamps.
+    pub fn timestamp(&self) -> &TypeSpec<F> {
+        &self.timestamp
+    }
+
+    /// Returns the type of block numbers.
+    pub fn block_number(&self) -> &TypeSpec<F> {
+        &self.block_number
+    }
+
+    /// Returns the maximum number of topics an event may have.
+    pub fn max_event_topics(&self) -> usize {
+        self.max_event_topics
+    }
 }
 
 /// The 4 byte selector to identify constructors and messages
@@ -750,6 +1658,40 @@ impl Selector {
     }
 }
 
+/// The 32 byte topic identifying the signature of an event.
+///
+/// Emitted as the first topic of every non-anonymous event so that indexers
+/// can filter for events of a specific type.
+#[derive(Debug, Default, PartialEq, Eq, derive_more::From)]
+pub struct SignatureTopic([u8; 32]);
+
+impl serde::Serialize for SignatureTopic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde_hex::serialize(&self.0, serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for SignatureTopic {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let mut arr = [0; 32];
+        serde_hex::deserialize_check_len(d, serde_hex::ExpectedLen::Exact(&mut arr[..]))?;
+        Ok(arr.into())
+    }
+}
+
+impl SignatureTopic {
+    /// Returns the underlying signature topic bytes.
+    pub fn to_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
 /// Describes the syntactical name of a type at a given type position.
 ///
 /// This is important when trying to work with type aliases.
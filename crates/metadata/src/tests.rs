@@ -42,6 +42,7 @@ fn spec_constructor_selector_must_serialize_to_hex() {
         json!({
             "label": "foo",
             "payable": true,
+            "default": false,
             "selector": "0x075bcd15",
             "args": [],
             "docs": []
@@ -125,6 +126,7 @@ fn spec_contract_json() {
                     "docs": [],
                     "label": "new",
                     "payable": true,
+                    "default": false,
                     "selector": "0x5ebd88d6"
                 },
                 {
@@ -132,6 +134,7 @@ fn spec_contract_json() {
                     "docs": [],
                     "label": "default",
                     "payable": false,
+                    "default": false,
                     "selector": "0x0222ff18"
                 }
             ],
@@ -153,6 +156,7 @@ fn spec_contract_json() {
                     "docs": [],
                     "mutates": true,
                     "payable": true,
+                    "default": false,
                     "label": "inc",
                     "returnType": null,
                     "selector": "0xe7d0590f"
@@ -162,6 +166,7 @@ fn spec_contract_json() {
                     "docs": [],
                     "mutates": false,
                     "payable": false,
+                    "default": false,
                     "label": "get",
                     "returnType": {
                         "displayName": [
@@ -199,6 +204,7 @@ fn trim_docs() {
         json!({
             "label": "foo",
             "payable": false,
+            "default": false,
             "selector": "0x075bcd15",
             "args": [],
             "docs": ["foobar"]
@@ -206,3 +212,973 @@ fn trim_docs() {
     );
     assert_eq!(deserialized.docs, compact_spec.docs);
 }
+
+#[test]
+fn deprecated_message_spec_works() {
+    // given
+    let spec = MessageSpec::from_label("transfer")
+        .selector(123_456_789u32.to_be_bytes())
+        .mutates(true)
+        .payable(false)
+        .returns(ReturnTypeSpec::new(None))
+        .deprecated("use transfer_v2")
+        .done();
+    let mut registry = Registry::new();
+    let compact_spec = spec.into_portable(&mut registry);
+
+    // when
+    let json = serde_json::to_value(&compact_spec).unwrap();
+    let deserialized: MessageSpec<PortableForm> =
+        serde_json::from_value(json.clone()).unwrap();
+
+    // then
+    assert_eq!(
+        json,
+        json!({
+            "label": "transfer",
+            "selector": "0x075bcd15",
+            "mutates": true,
+            "payable": false,
+            "default": false,
+            "args": [],
+            "returnType": null,
+            "docs": [],
+            "deprecated": "use transfer_v2"
+        })
+    );
+    assert_eq!(deserialized, compact_spec);
+    assert_eq!(
+        deserialized.deprecated().map(String::as_str),
+        Some("use transfer_v2")
+    );
+}
+
+#[test]
+fn contract_spec_version_works() {
+    // given
+    let spec = ContractSpec::new()
+        .constructors(vec![ConstructorSpec::from_label("new")
+            .selector([0u8; 4])
+            .payable(false)
+            .args(Vec::new())
+            .docs(Vec::new())
+            .done()])
+        .messages(vec![MessageSpec::from_label("get")
+            .selector([1u8; 4])
+            .mutates(false)
+            .payable(false)
+            .args(Vec::new())
+            .docs(Vec::new())
+            .returns(ReturnTypeSpec::new(None))
+            .done()])
+        .version("1.2.3")
+        .done();
+    let mut registry = Registry::new();
+    let compact_spec = spec.into_portable(&mut registry);
+
+    // when
+    let json = serde_json::to_value(&compact_spec).unwrap();
+    let deserialized: ContractSpec<PortableForm> =
+        serde_json::from_value(json.clone()).unwrap();
+
+    // then
+    assert_eq!(json["version"], json!("1.2.3"));
+    assert_eq!(deserialized, compact_spec);
+    assert_eq!(deserialized.version().map(String::as_str), Some("1.2.3"));
+}
+
+#[test]
+fn event_spec_signature_topic_works() {
+    // given
+    let spec = EventSpec::new("Transfer")
+        .args(vec![EventParamSpec::new("from")
+            .of_type(TypeSpec::with_name_segs::<u32, _>(
+                vec!["u32"].into_iter().map(AsRef::as_ref),
+            ))
+            .indexed(true)
+            .docs(vec!["The sender."])
+            .done()])
+        .docs(vec!["Emitted on transfers."])
+        .signature_topic([0x01; 32])
+        .done();
+    let mut registry = Registry::new();
+    let compact_spec = spec.into_portable(&mut registry);
+
+    // when
+    let json = serde_json::to_value(&compact_spec).unwrap();
+    let deserialized: EventSpec<PortableForm> =
+        serde_json::from_value(json.clone()).unwrap();
+
+    // then
+    assert_eq!(
+        json,
+        json!({
+            "label": "Transfer",
+            "args": [
+                {
+                    "label": "from",
+                    "indexed": true,
+                    "type": {
+                        "displayName": ["u32"],
+                        "type": 0
+                    },
+                    "docs": ["The sender."]
+                }
+            ],
+            "docs": ["Emitted on transfers."],
+            "signatureTopic": "0x0101010101010101010101010101010101010101010101010101010101010101"
+        })
+    );
+    assert_eq!(deserialized, compact_spec);
+    assert_eq!(
+        deserialized.signature_topic().map(SignatureTopic::to_bytes),
+        Some(&[0x01; 32][..])
+    );
+}
+
+#[test]
+fn contract_spec_provenance_works() {
+    // given
+    let spec = ContractSpec::new()
+        .constructors(vec![ConstructorSpec::from_label("new")
+            .selector([0u8; 4])
+            .payable(false)
+            .args(Vec::new())
+            .docs(Vec::new())
+            .done()])
+        .messages(vec![MessageSpec::from_label("get")
+            .selector([1u8; 4])
+            .mutates(false)
+            .payable(false)
+            .args(Vec::new())
+            .docs(Vec::new())
+            .returns(ReturnTypeSpec::new(None))
+            .done()])
+        .docs(vec!["A contract."])
+        .authors(vec!["Alice <alice@example.com>", "Bob"])
+        .license("Apache-2.0")
+        .repository("https://github.com/example/contract")
+        .homepage(None)
+        .done();
+    let mut registry = Registry::new();
+    let compact_spec = spec.into_portable(&mut registry);
+
+    // when
+    let json = serde_json::to_value(&compact_spec).unwrap();
+    let deserialized: ContractSpec<PortableForm> =
+        serde_json::from_value(json.clone()).unwrap();
+
+    // then
+    assert_eq!(json["docs"], json!(["A contract."]));
+    assert_eq!(json["authors"], json!(["Alice <alice@example.com>", "Bob"]));
+    assert_eq!(json["license"], json!("Apache-2.0"));
+    assert_eq!(
+        json["repository"],
+        json!("https://github.com/example/contract")
+    );
+    assert!(json.get("homepage").is_none());
+    assert_eq!(deserialized, compact_spec);
+    assert_eq!(
+        deserialized.license().map(String::as_str),
+        Some("Apache-2.0")
+    );
+    assert_eq!(deserialized.homepage(), None);
+}
+
+#[test]
+fn contract_spec_lang_error_works() {
+    // given
+    #[derive(scale_info::TypeInfo)]
+    #[allow(dead_code)]
+    enum LangError {
+        CouldNotReadInput = 1,
+    }
+    type MessageResult<T> = Result<T, LangError>;
+    let spec = ContractSpec::new()
+        .constructors(vec![ConstructorSpec::from_label("new")
+            .selector([0u8; 4])
+            .payable(false)
+            .args(Vec::new())
+            .docs(Vec::new())
+            .done()])
+        .messages(vec![MessageSpec::from_label("get")
+            .selector([1u8; 4])
+            .mutates(false)
+            .payable(false)
+            .args(Vec::new())
+            .docs(Vec::new())
+            .returns(ReturnTypeSpec::new(TypeSpec::with_name_segs::<
+                MessageResult<bool>,
+                _,
+            >(
+                vec!["ink_lang", "MessageResult"]
+                    .into_iter()
+                    .map(AsRef::as_ref),
+            )))
+            .done()])
+        .lang_error(TypeSpec::with_name_segs::<LangError, _>(
+            vec!["ink_lang", "LangError"].into_iter().map(AsRef::as_ref),
+        ))
+        .done();
+    let mut registry = Registry::new();
+    let compact_spec = spec.into_portable(&mut registry);
+    let registry: PortableRegistry = registry.into();
+
+    // when
+    let json = serde_json::to_value(&compact_spec).unwrap();
+    let deserialized: ContractSpec<PortableForm> =
+        serde_json::from_value(json.clone()).unwrap();
+
+    // then
+    assert_eq!(
+        json["langError"]["displayName"],
+        json!(["ink_lang", "LangError"])
+    );
+    assert_eq!(
+        json["messages"][0]["returnType"]["displayName"],
+        json!(["ink_lang", "MessageResult"])
+    );
+    assert_eq!(deserialized, compact_spec);
+    let lang_error = deserialized.lang_error().expect("must have a lang error");
+    assert_eq!(
+        registry
+            .resolve(lang_error.ty().id)
+            .map(|ty| ty.path.ident()),
+        Some(Some("LangError".to_string()))
+    );
+}
+
+#[test]
+fn chain_extension_spec_works() {
+    // given
+    let spec = ChainExtensionSpec::new(
+        "RandExtension",
+        TypeSpec::with_name_str::<u8>("RandomReadErr"),
+    )
+    .methods(vec![ChainExtensionMethodSpec::new("fetch_random", 1101)
+        .args(vec![MessageParamSpec::new("subject")
+            .of_type(TypeSpec::with_name_str::<[u8; 32]>("Subject"))
+            .done()])
+        .returns(ReturnTypeSpec::new(TypeSpec::with_name_segs::<
+            Result<[u8; 32], u8>,
+            _,
+        >(
+            vec!["Result"].into_iter().map(AsRef::as_ref),
+        )))
+        .handle_status(true)
+        .returns_result(true)
+        .docs(vec!["Fetches randomness."])
+        .done()])
+    .status_codes(vec![
+        StatusCodeSpec::new("FailGetRandomSource", 1),
+        StatusCodeSpec::catch_all("Unknown"),
+    ])
+    .docs(vec!["Provides randomness."])
+    .done();
+    let mut registry = Registry::new();
+
+    // when
+    let portable_spec = spec.into_portable(&mut registry);
+    let json = serde_json::to_value(&portable_spec).unwrap();
+    let deserialized: ChainExtensionSpec<PortableForm> =
+        serde_json::from_value(json.clone()).unwrap();
+
+    // then
+    assert_eq!(
+        json,
+        json!({
+            "label": "RandExtension",
+            "methods": [
+                {
+                    "label": "fetch_random",
+                    "funcId": 1101,
+                    "args": [
+                        {
+                            "label": "subject",
+                            "type": {
+                                "displayName": ["Subject"],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "returnType": {
+                        "displayName": ["Result"],
+                        "type": 2
+                    },
+                    "handleStatus": true,
+                    "returnsResult": true,
+                    "docs": ["Fetches randomness."]
+                }
+            ],
+            "errorCode": {
+                "displayName": ["RandomReadErr"],
+                "type": 1
+            },
+            "statusCodes": [
+                {
+                    "label": "FailGetRandomSource",
+                    "statusCode": 1
+                },
+                {
+                    "label": "Unknown",
+                    "statusCode": null
+                }
+            ],
+            "docs": ["Provides randomness."]
+        })
+    );
+    assert_eq!(deserialized, portable_spec);
+}
+
+#[test]
+fn ink_project_source_works() {
+    // given
+    let spec = ContractSpec::new()
+        .constructors(vec![ConstructorSpec::from_label("new")
+            .selector([0u8; 4])
+            .payable(false)
+            .args(Vec::new())
+            .docs(Vec::new())
+            .done()])
+        .messages(vec![MessageSpec::from_label("get")
+            .selector([1u8; 4])
+            .mutates(false)
+            .payable(false)
+            .args(Vec::new())
+            .docs(Vec::new())
+            .returns(ReturnTypeSpec::new(None))
+            .done()])
+        .done();
+    let layout = layout::CellLayout::new::<i32>(layout::LayoutKey::from(
+        ink_primitives::Key::from([0x00; 32]),
+    ));
+    let source = Source::new("ink! 3.0.1")
+        .with_hash([0x01; 32])
+        .with_compiler("rustc 1.61.0")
+        .with_build_tool("cargo-contract 1.3.0");
+
+    // when
+    let project = InkProject::new(layout, spec).with_source(source.clone());
+    let json = serde_json::to_value(&project).unwrap();
+
+    // then
+    assert_eq!(
+        json["source"],
+        json!({
+            "hash": "0x0101010101010101010101010101010101010101010101010101010101010101",
+            "language": "ink! 3.0.1",
+            "compiler": "rustc 1.61.0",
+            "buildTool": "cargo-contract 1.3.0"
+        })
+    );
+    let deserialized: InkProject = serde_json::from_value(json).unwrap();
+    assert_eq!(deserialized.source(), Some(&source));
+    assert_eq!(
+        serde_json::from_value::<Source>(json!({ "language": "ink! 3.0.1" })).unwrap(),
+        Source::new("ink! 3.0.1")
+    );
+}
+
+#[test]
+fn ink_project_user_metadata_works() {
+    // given
+    let spec = ContractSpec::new()
+        .constructors(vec![ConstructorSpec::from_label("new")
+            .selector([0u8; 4])
+            .payable(false)
+            .args(Vec::new())
+            .docs(Vec::new())
+            .done()])
+        .messages(vec![MessageSpec::from_label("get")
+            .selector([1u8; 4])
+            .mutates(false)
+            .payable(false)
+            .args(Vec::new())
+            .docs(Vec::new())
+            .returns(ReturnTypeSpec::new(None))
+            .done()])
+        .done();
+    let layout = layout::CellLayout::new::<i32>(layout::LayoutKey::from(
+        ink_primitives::Key::from([0x00; 32]),
+    ));
+    let user =
+        UserMetadata::from_json(r#"{ "audit": { "report": "0x1234" }, "ui": [1, 2] }"#)
+            .unwrap();
+
+    // when
+    let project = InkProject::new(layout, spec).with_user(user.clone());
+    let json = serde_json::to_value(&project).unwrap();
+
+    // then
+    assert_eq!(
+        json["user"],
+        json!({
+            "audit": { "report": "0x1234" },
+            "ui": [1, 2]
+        })
+    );
+    let deserialized: InkProject = serde_json::from_value(json).unwrap();
+    assert_eq!(deserialized.user(), Some(&user));
+    assert!(UserMetadata::from_json("[1, 2]").is_err());
+    assert!(UserMetadata::from_json("{ invalid").is_err());
+}
+
+#[test]
+fn ink_interface_works() {
+    // given
+    let spec = TraitSpec::new("Flip")
+        .messages(vec![
+            MessageSpec::from_label("flip")
+                .selector([0x63, 0x3a, 0xa5, 0x51])
+                .mutates(true)
+                .payable(false)
+                .args(Vec::new())
+                .docs(Vec::new())
+                .returns(ReturnTypeSpec::new(None))
+                .done(),
+            MessageSpec::from_label("get")
+                .selector([0x2f, 0x86, 0x5b, 0xd9])
+                .mutates(false)
+                .payable(false)
+                .args(Vec::new())
+                .docs(Vec::new())
+                .returns(ReturnTypeSpec::new(TypeSpec::with_name_str::<bool>("bool")))
+                .done(),
+        ])
+        .docs(vec!["Flips a value."])
+        .done();
+
+    // when
+    let interface = InkInterface::new(spec);
+    let json = serde_json::to_value(&interface).unwrap();
+
+    // then
+    assert_eq!(
+        json,
+        json!({
+            "types": [
+                {
+                    "id": 0,
+                    "type": {
+                        "def": {
+                            "primitive": "bool"
+                        }
+                    }
+                }
+            ],
+            "spec": {
+                "label": "Flip",
+                "messages": [
+                    {
+                        "args": [],
+                        "docs": [],
+                        "label": "flip",
+                        "mutates": true,
+                        "payable": false,
+                        "default": false,
+                        "returnType": null,
+                        "selector": "0x633aa551"
+                    },
+                    {
+                        "args": [],
+                        "docs": [],
+                        "label": "get",
+                        "mutates": false,
+                        "payable": false,
+                        "default": false,
+                        "returnType": {
+                            "displayName": ["bool"],
+                            "type": 0
+                        },
+                        "selector": "0x2f865bd9"
+                    }
+                ],
+                "docs": ["Flips a value."]
+            }
+        })
+    );
+    let deserialized: InkInterface = serde_json::from_value(json).unwrap();
+    assert_eq!(deserialized.spec(), interface.spec());
+}
+
+#[cfg(feature = "typescript")]
+#[test]
+fn typescript_bindings_work() {
+    #[derive(scale_info::TypeInfo)]
+    #[allow(dead_code)]
+    struct AccountId([u8; 32]);
+
+    #[derive(scale_info::TypeInfo)]
+    #[allow(dead_code)]
+    enum Error {
+        InsufficientBalance,
+    }
+
+    // given
+    let account_id = || {
+        TypeSpec::with_name_segs::<AccountId, _>(
+            vec!["AccountId"].into_iter().map(AsRef::as_ref),
+        )
+    };
+    let spec = ContractSpec::new()
+        .constructors(vec![ConstructorSpec::from_label("new")
+            .selector([0x9b, 0xae, 0x9d, 0x5e])
+            .payable(false)
+            .args(vec![MessageParamSpec::new("total_supply")
+                .of_type(TypeSpec::new::<u128>())
+                .done()])
+            .docs(vec![" Creates a new token."])
+            .done()])
+        .messages(vec![
+            MessageSpec::from_label("transfer")
+                .selector([0x84, 0xa1, 0x5d, 0xa1])
+                .mutates(true)
+                .payable(false)
+                .args(vec![
+                    MessageParamSpec::new("to").of_type(account_id()).done(),
+                    MessageParamSpec::new("value")
+                        .of_type(TypeSpec::new::<u128>())
+                        .done(),
+                ])
+                .docs(Vec::new())
+                .returns(ReturnTypeSpec::new(TypeSpec::new::<Result<(), Error>>()))
+                .done(),
+            MessageSpec::from_label("Erc20::balance_of")
+                .selector([0x0f, 0x75, 0x5a, 0x56])
+                .mutates(false)
+                .payable(false)
+                .args(vec![MessageParamSpec::new("new")
+                    .of_type(account_id())
+                    .done()])
+                .docs(Vec::new())
+                .returns(ReturnTypeSpec::new(TypeSpec::new::<Option<u128>>()))
+                .done(),
+        ])
+        .events(vec![EventSpec::new("Transfer")
+            .args(vec![
+                EventParamSpec::new("from")
+                    .of_type(TypeSpec::new::<Option<AccountId>>())
+                    .indexed(true)
+                    .done(),
+                EventParamSpec::new("value")
+                    .of_type(TypeSpec::new::<u128>())
+                    .done(),
+            ])
+            .docs(Vec::new())
+            .done()])
+        .docs(Vec::new())
+        .done();
+    let layout = layout::CellLayout::new::<i32>(layout::LayoutKey::from(
+        ink_primitives::Key::from([0x00; 32]),
+    ));
+    let project = InkProject::new(layout, spec);
+
+    // when
+    let bindings = typescript::generate("erc20", &project);
+
+    // then
+    for expected in [
+        "export type AccountId = Bytes;",
+        "export type Error = 'InsufficientBalance';",
+        "export interface TransferEvent {\n  from: AccountId | null;\n  value: BigIntish;\n}",
+        "export type Erc20Event = { Transfer: TransferEvent };",
+        "    new: '0x9bae9d5e',",
+        "    erc20BalanceOf: '0x0f755a56',",
+        "  erc20BalanceOf(caller: string, options: ContractOptions, new_: AccountId): \
+            Promise<QueryOutcome<BigIntish | null>>;",
+        "  transfer(caller: string, options: ContractOptions, to: AccountId, value: BigIntish): \
+            Promise<QueryOutcome<{ Ok: null } | { Err: Error }>>;",
+        "export interface Erc20Tx {\n  transfer(options: ContractOptions, to: AccountId, value: BigIntish): \
+            SubmittableExtrinsic<'promise'>;\n}",
+        "  /**\n   * Creates a new token.\n   */\n  new(options: BlueprintOptions, totalSupply: BigIntish): \
+            SubmittableExtrinsic<'promise'>;",
+        "export interface Erc20Contract {\n  query: Erc20Query;\n  tx: Erc20Tx;\n}",
+    ] {
+        assert!(
+            bindings.contains(expected),
+            "expected bindings to contain:\n{}",
+            expected
+        );
+    }
+}
+
+#[cfg(feature = "solidity")]
+#[test]
+fn solidity_abi_works() {
+    #[derive(scale_info::TypeInfo)]
+    #[allow(dead_code)]
+    struct AccountId([u8; 32]);
+
+    #[derive(scale_info::TypeInfo)]
+    #[allow(dead_code)]
+    enum Error {
+        InsufficientBalance { required: u128 },
+    }
+
+    #[derive(scale_info::TypeInfo)]
+    #[allow(dead_code)]
+    enum Op {
+        Burn(u128),
+    }
+
+    // given
+    let account_id = || {
+        TypeSpec::with_name_segs::<AccountId, _>(
+            vec!["AccountId"].into_iter().map(AsRef::as_ref),
+        )
+    };
+    let constructor = |label| {
+        ConstructorSpec::from_label(label)
+            .selector([0x9b, 0xae, 0x9d, 0x5e])
+            .payable(true)
+            .args(vec![MessageParamSpec::new("total_supply")
+                .of_type(TypeSpec::new::<u128>())
+                .done()])
+            .docs(Vec::new())
+            .done()
+    };
+    let spec = ContractSpec::new()
+        .constructors(vec![constructor("new"), constructor("default")])
+        .messages(vec![
+            MessageSpec::from_label("transfer")
+                .selector([0x84, 0xa1, 0x5d, 0xa1])
+                .mutates(true)
+                .payable(false)
+                .args(vec![
+                    MessageParamSpec::new("to").of_type(account_id()).done(),
+                    MessageParamSpec::new("memo")
+                        .of_type(TypeSpec::new::<Option<Vec<u8>>>())
+                        .done(),
+                ])
+                .docs(Vec::new())
+                .returns(ReturnTypeSpec::new(TypeSpec::new::<Result<(), Error>>()))
+                .done(),
+            MessageSpec::from_label("apply")
+                .selector([0x0f, 0x75, 0x5a, 0x56])
+                .mutates(false)
+                .payable(false)
+                .args(vec![MessageParamSpec::new("op")
+                    .of_type(TypeSpec::new::<Op>())
+                    .done()])
+                .docs(Vec::new())
+                .returns(ReturnTypeSpec::new(TypeSpec::new::<(bool, u32)>()))
+                .done(),
+        ])
+        .events(vec![EventSpec::new("Transfer")
+            .args(vec![EventParamSpec::new("to")
+                .of_type(account_id())
+                .indexed(true)
+                .done()])
+            .docs(Vec::new())
+            .done()])
+        .docs(Vec::new())
+        .done();
+    let layout = layout::CellLayout::new::<i32>(layout::LayoutKey::from(
+        ink_primitives::Key::from([0x00; 32]),
+    ));
+    let project = InkProject::new(layout, spec);
+
+    // when
+    let abi = solidity::generate(&project);
+    let json = serde_json::to_value(abi.abi()).unwrap();
+
+    // then
+    assert_eq!(
+        json,
+        json!([
+            {
+                "type": "constructor",
+                "inputs": [{ "name": "total_supply", "type": "uint128" }],
+                "stateMutability": "payable"
+            },
+            {
+                "type": "function",
+                "name": "transfer",
+                "inputs": [
+                    { "name": "to", "type": "bytes32" },
+                    {
+                        "name": "memo",
+                        "type": "tuple",
+                        "components": [
+                            { "name": "isSome", "type": "bool" },
+                            { "name": "value", "type": "bytes" }
+                        ]
+                    }
+                ],
+                "outputs": [],
+                "stateMutability": "nonpayable"
+            },
+            {
+                "type": "function",
+                "name": "apply",
+                "inputs": [{ "name": "op", "type": "bytes" }],
+                "outputs": [{
+                    "name": "",
+                    "type": "tuple",
+                    "components": [
+                        { "name": "", "type": "bool" },
+                        { "name": "", "type": "uint32" }
+                    ]
+                }],
+                "stateMutability": "view"
+            },
+            {
+                "type": "event",
+                "name": "Transfer",
+                "inputs": [{ "name": "to", "type": "bytes32", "indexed": true }],
+                "anonymous": true
+            },
+            {
+                "type": "error",
+                "name": "InsufficientBalance",
+                "inputs": [{ "name": "required", "type": "uint128" }]
+            }
+        ])
+    );
+    let diagnostics = abi
+        .diagnostics()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        diagnostics,
+        vec![
+            "constructor `default`: Solidity contracts have a single constructor, \
+                only the first one has been exported",
+            "message `transfer` argument `memo`: `Option` has no Solidity equivalent, \
+                mapped to `(bool isSome, T value)`",
+            "message `apply` argument `op`: enums carrying data have no Solidity \
+                equivalent, mapped to `bytes`",
+        ]
+    );
+}
+
+#[test]
+fn metadata_v1_upgrade_works() {
+    // given
+    let json = json!({
+        "V1": {
+            "types": [
+                { "def": { "primitive": "bool" } },
+                {
+                    "path": ["Option"],
+                    "params": [{ "name": "T", "type": 1 }],
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                { "name": "None", "discriminant": 0 },
+                                { "name": "Some", "fields": [{ "type": 1 }], "discriminant": 1 }
+                            ]
+                        }
+                    }
+                }
+            ],
+            "storage": {
+                "struct": {
+                    "fields": [{
+                        "name": "value",
+                        "layout": {
+                            "cell": {
+                                "key": "0x0000000000000000000000000000000000000000000000000000000000000000",
+                                "ty": 1
+                            }
+                        }
+                    }]
+                }
+            },
+            "spec": {
+                "constructors": [{
+                    "name": ["new"],
+                    "selector": "0x9bae9d5e",
+                    "args": [{
+                        "name": "init_value",
+                        "type": { "displayName": ["bool"], "type": 1 }
+                    }],
+                    "docs": []
+                }],
+                "messages": [{
+                    "name": ["Flip", "get"],
+                    "selector": "0x2f865bd9",
+                    "mutates": false,
+                    "payable": false,
+                    "args": [],
+                    "returnType": { "displayName": ["Option"], "type": 2 },
+                    "docs": []
+                }],
+                "events": [{
+                    "name": "Flipped",
+                    "args": [{
+                        "name": "value",
+                        "indexed": true,
+                        "type": { "displayName": ["bool"], "type": 1 },
+                        "docs": []
+                    }],
+                    "docs": []
+                }],
+                "docs": []
+            }
+        }
+    });
+
+    // when
+    let metadata: MetadataVersioned = serde_json::from_value(json).unwrap();
+    let project = metadata.into_latest().unwrap();
+
+    // then
+    let json = serde_json::to_value(&project).unwrap();
+    assert_eq!(
+        json["types"],
+        json!([
+            { "id": 0, "type": { "def": { "primitive": "bool" } } },
+            {
+                "id": 1,
+                "type": {
+                    "path": ["Option"],
+                    "params": [{ "name": "T", "type": 0 }],
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                { "name": "None", "index": 0 },
+                                { "name": "Some", "fields": [{ "type": 0 }], "index": 1 }
+                            ]
+                        }
+                    }
+                }
+            }
+        ])
+    );
+    assert_eq!(
+        json["storage"]["struct"]["fields"][0]["layout"]["cell"]["ty"],
+        0
+    );
+    assert_eq!(
+        json["spec"]["constructors"],
+        json!([{
+            "label": "new",
+            "selector": "0x9bae9d5e",
+            "payable": false,
+            "default": false,
+            "args": [{
+                "label": "init_value",
+                "type": { "displayName": ["bool"], "type": 0 }
+            }],
+            "docs": []
+        }])
+    );
+    assert_eq!(json["spec"]["messages"][0]["label"], "Flip::get");
+    assert_eq!(json["spec"]["messages"][0]["returnType"]["type"], 1);
+    assert_eq!(json["spec"]["events"][0]["label"], "Flipped");
+    assert_eq!(json["spec"]["events"][0]["args"][0]["label"], "value");
+    assert_eq!(json["spec"]["events"][0]["args"][0]["type"]["type"], 0);
+}
+
+#[test]
+fn metadata_v1_upgrade_rejects_invalid_type_refs() {
+    // given
+    let metadata = serde_json::from_value::<MetadataV1>(json!({
+        "types": [{ "def": { "sequence": { "type": 0 } } }],
+        "storage": {},
+        "spec": {}
+    }))
+    .unwrap();
+
+    // when
+    let result = InkProject::from_v1(metadata);
+
+    // then
+    assert!(matches!(result, Err(MigrationError::InvalidTypeRef)));
+}
+
+#[test]
+fn contract_spec_environment_works() {
+    // given
+    let spec = ContractSpec::new()
+        .constructors(vec![ConstructorSpec::from_label("new")
+            .selector([0u8; 4])
+            .payable(false)
+            .args(Vec::new())
+            .docs(Vec::new())
+            .done()])
+        .messages(vec![MessageSpec::from_label("get")
+            .selector([1u8; 4])
+            .mutates(false)
+            .payable(false)
+            .args(Vec::new())
+            .docs(Vec::new())
+            .returns(ReturnTypeSpec::new(None))
+            .done()])
+        .environment(
+            EnvironmentSpec::new(3)
+                .account_id(TypeSpec::with_name_segs::<[u8; 20], _>(
+                    vec!["AccountId"].into_iter().map(AsRef::as_ref),
+                ))
+                .balance(TypeSpec::with_name_segs::<u64, _>(
+                    vec!["Balance"].into_iter().map(AsRef::as_ref),
+                ))
+                .hash(TypeSpec::with_name_segs::<[u8; 32], _>(
+                    vec!["Hash"].into_iter().map(AsRef::as_ref),
+                ))
+                .timestamp(TypeSpec::with_name_segs::<u64, _>(
+                    vec!["Timestamp"].into_iter().map(AsRef::as_ref),
+                ))
+                .block_number(TypeSpec::with_name_segs::<u32, _>(
+                    vec!["BlockNumber"].into_iter().map(AsRef::as_ref),
+                ))
+                .done(),
+        )
+        .done();
+    let mut registry = Registry::new();
+
+    // when
+    let portable_spec = spec.into_portable(&mut registry);
+    let json = serde_json::to_value(&portable_spec).unwrap();
+    let deserialized: ContractSpec<PortableForm> =
+        serde_json::from_value(json.clone()).unwrap();
+
+    // then
+    assert_eq!(
+        json["environment"],
+        json!({
+            "accountId": { "displayName": ["AccountId"], "type": 0 },
+            "balance": { "displayName": ["Balance"], "type": 2 },
+            "hash": { "displayName": ["Hash"], "type": 3 },
+            "timestamp": { "displayName": ["Timestamp"], "type": 2 },
+            "blockNumber": { "displayName": ["BlockNumber"], "type": 4 },
+            "maxEventTopics": 3
+        })
+    );
+    assert_eq!(deserialized, portable_spec);
+    assert_eq!(deserialized.environment().unwrap().max_event_topics(), 3);
+}
+
+#[test]
+fn default_constructor_and_message_spec_works() {
+    // given
+    let constructor = ConstructorSpec::from_label("new")
+        .selector([0u8; 4])
+        .payable(false)
+        .default(true)
+        .done();
+    let message = MessageSpec::from_label("flip")
+        .selector([1u8; 4])
+        .mutates(true)
+        .payable(false)
+        .default(true)
+        .returns(ReturnTypeSpec::new(None))
+        .done();
+    let mut registry = Registry::new();
+
+    // when
+    let constructor =
+        serde_json::to_value(constructor.into_portable(&mut registry)).unwrap();
+    let message = serde_json::to_value(message.into_portable(&mut registry)).unwrap();
+
+    // then
+    assert_eq!(constructor["default"], true);
+    assert_eq!(message["default"], true);
+    assert_eq!(message["mutates"], true);
+    assert_eq!(message["payable"], false);
+    let deserialized: MessageSpec<PortableForm> =
+        serde_json::from_value(message).unwrap();
+    assert!(deserialized.default());
+}
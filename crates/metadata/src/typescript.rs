@@ -0,0 +1,563 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Generates TypeScript bindings for an ink! smart contract from its metadata.
+//!
+//! The generated bindings describe the types of the contract as well as typed
+//! call and query stubs for its constructors and messages. They are meant to be
+//! used together with the `ContractPromise` and `CodePromise` of
+//! [`@polkadot/api-contract`](https://polkadot.js.org/docs/api-contract) so that
+//! dApps no longer need to maintain hand written bindings that drift from the contract.
+//!
+//! # Note
+//!
+//! Types are mapped to their polkadot-js JSON representation:
+//!
+//! - Integers of up to 32 bits are mapped to `number`, wider integers to `BigIntish`.
+//! - Byte sequences and byte arrays, e.g. `AccountId` or `Hash`, are mapped to `Bytes`
+//!   which accepts a `Uint8Array` or a `0x` prefixed hex string.
+//! - `Option<T>` is mapped to `T | null`.
+//! - Enums with unit variants only are mapped to a union of their variant names,
+//!   other enums to a union of single keyed objects, e.g. `{ Ok: T } | { Err: E }`.
+//! - Struct fields and message names are converted to camel case just like
+//!   polkadot-js does.
+
+use crate::{
+    InkProject,
+    Selector,
+};
+use scale_info::{
+    form::PortableForm,
+    Field,
+    PortableRegistry,
+    Type,
+    TypeDef,
+    TypeDefPrimitive,
+};
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+};
+
+/// Generates the TypeScript bindings for the ink! smart contract with the given name.
+///
+/// The name is used as prefix for the generated contract interfaces, e.g.
+/// `FlipperQuery`, `FlipperTx` and `FlipperContract` for a contract named `flipper`.
+pub fn generate(name: &str, project: &InkProject) -> String {
+    Generator::new(project).generate(&pascal_case(name))
+}
+
+/// Generates the TypeScript bindings of a single ink! project.
+struct Generator<'a> {
+    project: &'a InkProject,
+    /// The TypeScript names of all named types in the type registry.
+    names: BTreeMap<u32, String>,
+}
+
+impl<'a> Generator<'a> {
+    /// Creates a new generator and assigns unique names to all named types.
+    ///
+    /// Types sharing the same name, e.g. different instantiations of the same
+    /// generic type, are disambiguated by appending their type identifier.
+    fn new(project: &'a InkProject) -> Self {
+        let registry = project.registry();
+        let mut occurrences = BTreeMap::<String, usize>::new();
+        for ty in &registry.types {
+            if let Some(name) = type_name(&ty.ty) {
+                *occurrences.entry(name).or_default() += 1;
+            }
+        }
+        let names = registry
+            .types
+            .iter()
+            .filter_map(|ty| {
+                let name = type_name(&ty.ty)?;
+                let name = if occurrences[&name] > 1 {
+                    format!("{}{}", name, ty.id)
+                } else {
+                    name
+                };
+                Some((ty.id, name))
+            })
+            .collect();
+        Self { project, names }
+    }
+
+    fn registry(&self) -> &PortableRegistry {
+        self.project.registry()
+    }
+
+    /// Generates the TypeScript bindings of the ink! project.
+    fn generate(&self, name: &str) -> String {
+        let mut out = String::new();
+        out.push_str(PRELUDE);
+        self.generate_types(&mut out);
+        self.generate_events(&mut out, name);
+        self.generate_selectors(&mut out, name);
+        self.generate_queries(&mut out, name);
+        self.generate_transactions(&mut out, name);
+        self.generate_constructors(&mut out, name);
+        write_docs(&mut out, "", self.project.spec().docs());
+        let _ = writeln!(
+            out,
+            "export interface {name}Contract {{\n  \
+                query: {name}Query;\n  \
+                tx: {name}Tx;\n\
+            }}",
+            name = name,
+        );
+        out
+    }
+
+    /// Generates a TypeScript declaration for every named type of the registry.
+    fn generate_types(&self, out: &mut String) {
+        for ty in &self.registry().types {
+            let name = match self.names.get(&ty.id) {
+                Some(name) => name,
+                None => continue,
+            };
+            write_docs(out, "", &ty.ty.docs);
+            let _ = writeln!(
+                out,
+                "export type {} = {};\n",
+                name,
+                self.type_def(&ty.ty.type_def)
+            );
+        }
+    }
+
+    /// Generates an interface for every event of the contract as well as a union
+    /// of all of them.
+    fn generate_events(&self, out: &mut String, name: &str) {
+        let events = self.project.spec().events();
+        if events.is_empty() {
+            return
+        }
+        for event in events {
+            write_docs(out, "", event.docs());
+            let _ = writeln!(out, "export interface {}Event {{", event.label());
+            for arg in event.args() {
+                write_docs(out, "  ", arg.docs());
+                let _ = writeln!(
+                    out,
+                    "  {}: {};",
+                    camel_case(arg.label()),
+                    self.type_ref(arg.ty().ty().id)
+                );
+            }
+            out.push_str("}\n\n");
+        }
+        let variants = events
+            .iter()
+            .map(|event| format!("{{ {label}: {label}Event }}", label = event.label()))
+            .collect::<Vec<_>>();
+        let _ = writeln!(
+            out,
+            "export type {}Event = {};\n",
+            name,
+            variants.join(" | ")
+        );
+    }
+
+    /// Generates the selectors of all constructors and messages.
+    fn generate_selectors(&self, out: &mut String, name: &str) {
+        let spec = self.project.spec();
+        let _ = writeln!(out, "export const {}Selectors = {{", name);
+        out.push_str("  constructors: {\n");
+        for constructor in spec.constructors() {
+            let _ = writeln!(
+                out,
+                "    {}: '{}',",
+                camel_case(constructor.label()),
+                selector_hex(constructor.selector())
+            );
+        }
+        out.push_str("  },\n  messages: {\n");
+        for message in spec.messages() {
+            let _ = writeln!(
+                out,
+                "    {}: '{}',",
+                camel_case(message.label()),
+                selector_hex(message.selector())
+            );
+        }
+        out.push_str("  },\n} as const;\n\n");
+    }
+
+    /// Generates the typed query stubs of all messages.
+    ///
+    /// Mirrors `ContractPromise.query` which dry-runs a message on behalf of a caller.
+    fn generate_queries(&self, out: &mut String, name: &str) {
+        let _ = writeln!(out, "export interface {}Query {{", name);
+        for message in self.project.spec().messages() {
+            write_docs(out, "  ", message.docs());
+            let output = message
+                .return_type()
+                .opt_type()
+                .map(|ty| self.type_ref(ty.ty().id))
+                .unwrap_or_else(|| String::from("null"));
+            let _ = writeln!(
+                out,
+                "  {}(caller: string, options: ContractOptions{}): Promise<QueryOutcome<{}>>;",
+                camel_case(message.label()),
+                self.params(message.args()),
+                output
+            );
+        }
+        out.push_str("}\n\n");
+    }
+
+    /// Generates the typed transaction stubs of all mutating messages.
+    ///
+    /// Mirrors `ContractPromise.tx` which creates an extrinsic calling a message.
+    fn generate_transactions(&self, out: &mut String, name: &str) {
+        let _ = writeln!(out, "export interface {}Tx {{", name);
+        for message in self.project.spec().messages() {
+            if !message.mutates() {
+                continue
+            }
+            write_docs(out, "  ", message.docs());
+            let _ = writeln!(
+                out,
+                "  {}(options: ContractOptions{}): SubmittableExtrinsic<'promise'>;",
+                camel_case(message.label()),
+                self.params(message.args())
+            );
+        }
+        out.push_str("}\n\n");
+    }
+
+    /// Generates the typed deployment stubs of all constructors.
+    ///
+    /// Mirrors `CodePromise.tx` which creates an extrinsic instantiating the contract.
+    fn generate_constructors(&self, out: &mut String, name: &str) {
+        let _ = writeln!(out, "export interface {}Constructors {{", name);
+        for constructor in self.project.spec().constructors() {
+            write_docs(out, "  ", constructor.docs());
+            let _ = writeln!(
+                out,
+                "  {}(options: BlueprintOptions{}): SubmittableExtrinsic<'promise'>;",
+                camel_case(constructor.label()),
+                self.params(constructor.args())
+            );
+        }
+        out.push_str("}\n\n");
+    }
+
+    /// Returns the TypeScript parameter list of the given arguments.
+    ///
+    /// The returned list starts with a separating comma unless it is empty.
+    fn params(&self, args: &[crate::MessageParamSpec<PortableForm>]) -> String {
+        args.iter()
+            .map(|arg| {
+                format!(
+                    ", {}: {}",
+                    escape_ident(camel_case(arg.label())),
+                    self.type_ref(arg.ty().ty().id)
+                )
+            })
+            .collect()
+    }
+
+    /// Returns a reference to the type with the given identifier.
+    ///
+    /// Named types are referred to by their name, all other types are inlined.
+    fn type_ref(&self, id: u32) -> String {
+        if let Some(name) = self.names.get(&id) {
+            return name.clone()
+        }
+        match self.registry().resolve(id) {
+            Some(ty) => self.type_def(&ty.type_def),
+            None => String::from("unknown"),
+        }
+    }
+
+    /// Returns the TypeScript representation of the given type definition.
+    fn type_def(&self, type_def: &TypeDef<PortableForm>) -> String {
+        match type_def {
+            TypeDef::Composite(composite) => self.fields(&composite.fields),
+            TypeDef::Variant(variant) => {
+                if let Some(some) = option_inner(variant) {
+                    return format!("{} | null", self.type_ref(some))
+                }
+                if variant.variants.is_empty() {
+                    return String::from("never")
+                }
+                if variant.variants.iter().all(|v| v.fields.is_empty()) {
+                    return variant
+                        .variants
+                        .iter()
+                        .map(|v| format!("'{}'", v.name))
+                        .collect::<Vec<_>>()
+                        .join(" | ")
+                }
+                variant
+                    .variants
+                    .iter()
+                    .map(|v| format!("{{ {}: {} }}", v.name, self.fields(&v.fields)))
+                    .collect::<Vec<_>>()
+                    .join(" | ")
+            }
+            TypeDef::Sequence(sequence) => {
+                if self.is_byte(sequence.type_param.id) {
+                    return String::from("Bytes")
+                }
+                format!("Array<{}>", self.type_ref(sequence.type_param.id))
+            }
+            TypeDef::Array(array) => {
+                if self.is_byte(array.type_param.id) {
+                    return String::from("Bytes")
+                }
+                format!("Array<{}>", self.type_ref(array.type_param.id))
+            }
+            TypeDef::Tuple(tuple) => {
+                if tuple.fields.is_empty() {
+                    return String::from("null")
+                }
+                let fields = tuple
+                    .fields
+                    .iter()
+                    .map(|field| self.type_ref(field.id))
+                    .collect::<Vec<_>>();
+                format!("[{}]", fields.join(", "))
+            }
+            TypeDef::Primitive(primitive) => String::from(primitive_type(primitive)),
+            TypeDef::Compact(compact) => self.type_ref(compact.type_param.id),
+            TypeDef::BitSequence(_) => String::from("Bytes"),
+        }
+    }
+
+    /// Returns the TypeScript representation of the fields of a struct or enum variant.
+    ///
+    /// Named fields are mapped to an object, a single unnamed field to the type of
+    /// the field itself and multiple unnamed fields to a tuple.
+    fn fields(&self, fields: &[Field<PortableForm>]) -> String {
+        match fields {
+            [] => String::from("null"),
+            [field] if field.name.is_none() => self.type_ref(field.ty.id),
+            fields if fields.iter().all(|field| field.name.is_none()) => {
+                let fields = fields
+                    .iter()
+                    .map(|field| self.type_ref(field.ty.id))
+                    .collect::<Vec<_>>();
+                format!("[{}]", fields.join(", "))
+            }
+            fields => {
+                let fields = fields
+                    .iter()
+                    .map(|field| {
+                        format!(
+                            "{}: {}",
+                            camel_case(field.name.as_deref().unwrap_or_default()),
+                            self.type_ref(field.ty.id)
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                format!("{{ {} }}", fields.join("; "))
+            }
+        }
+    }
+
+    /// Returns `true` if the type with the given identifier is `u8`.
+    fn is_byte(&self, id: u32) -> bool {
+        matches!(
+            self.registry().resolve(id).map(|ty| &ty.type_def),
+            Some(TypeDef::Primitive(TypeDefPrimitive::U8))
+        )
+    }
+}
+
+/// The imports and helper types shared by all generated bindings.
+const PRELUDE: &str = "\
+// This file has been generated from the ink! metadata of the contract.
+// Do not edit it by hand, regenerate it instead.
+
+import type { BlueprintOptions, ContractCallOutcome, ContractOptions } from '@polkadot/api-contract/types';
+import type { SubmittableExtrinsic } from '@polkadot/api/types';
+import type { Codec } from '@polkadot/types/types';
+
+/** Integers wider than 32 bits. */
+export type BigIntish = bigint | number | string;
+
+/** Bytes given as `Uint8Array` or `0x` prefixed hex string. */
+export type Bytes = Uint8Array | string;
+
+/** The outcome of a query whose decoded output conforms to `T`. */
+export interface QueryOutcome<T> extends Omit<ContractCallOutcome, 'output'> {
+  output: (Codec & { toJSON(): T }) | null;
+}
+
+";
+
+/// Returns the name of the given type if it is a struct or enum.
+///
+/// Returns `None` for types that are always inlined such as `Option` and `Result`.
+fn type_name(ty: &Type<PortableForm>) -> Option<String> {
+    match &ty.type_def {
+        TypeDef::Composite(_) | TypeDef::Variant(_) => (),
+        _ => return None,
+    }
+    let segments = &ty.path.segments;
+    match segments.as_slice() {
+        [] => None,
+        [prelude] if prelude == "Option" || prelude == "Result" => None,
+        segments => segments.last().map(|name| pascal_case(name)),
+    }
+}
+
+/// Returns the type identifier of `T` if the given enum is an `Option<T>`.
+fn option_inner(variant: &scale_info::TypeDefVariant<PortableForm>) -> Option<u32> {
+    match variant.variants.as_slice() {
+        [none, some]
+            if none.name == "None"
+                && none.fields.is_empty()
+                && some.name == "Some"
+                && some.fields.len() == 1 =>
+        {
+            Some(some.fields[0].ty.id)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the TypeScript representation of the given primitive type.
+fn primitive_type(primitive: &TypeDefPrimitive) -> &'static str {
+    match primitive {
+        TypeDefPrimitive::Bool => "boolean",
+        TypeDefPrimitive::Char | TypeDefPrimitive::Str => "string",
+        TypeDefPrimitive::U8
+        | TypeDefPrimitive::U16
+        | TypeDefPrimitive::U32
+        | TypeDefPrimitive::I8
+        | TypeDefPrimitive::I16
+        | TypeDefPrimitive::I32 => "number",
+        TypeDefPrimitive::U64
+        | TypeDefPrimitive::U128
+        | TypeDefPrimitive::U256
+        | TypeDefPrimitive::I64
+        | TypeDefPrimitive::I128
+        | TypeDefPrimitive::I256 => "BigIntish",
+    }
+}
+
+/// Writes the given documentation as JSDoc comment with the given indentation.
+fn write_docs(out: &mut String, indent: &str, docs: &[String]) {
+    if docs.iter().all(|line| line.trim().is_empty()) {
+        return
+    }
+    let _ = writeln!(out, "{}/**", indent);
+    for line in docs {
+        let line = line.trim_end().replace("*/", "*\\/");
+        if line.trim().is_empty() {
+            let _ = writeln!(out, "{} *", indent);
+        } else {
+            let _ = writeln!(out, "{} * {}", indent, line.trim_start());
+        }
+    }
+    let _ = writeln!(out, "{} */", indent);
+}
+
+/// Returns the selector as `0x` prefixed hex string.
+fn selector_hex(selector: &Selector) -> String {
+    selector
+        .to_bytes()
+        .iter()
+        .fold(String::from("0x"), |mut hex, byte| {
+            let _ = write!(hex, "{:02x}", byte);
+            hex
+        })
+}
+
+/// Converts the given identifier to camel case, e.g. `total_supply` to `totalSupply`.
+///
+/// The `::` separating the trait and message names of trait messages is treated
+/// like an underscore, e.g. `PSP22::transfer` is converted to `psp22Transfer`.
+fn camel_case(ident: &str) -> String {
+    let pascal = pascal_case(ident);
+    let mut chars = pascal.chars();
+    let mut camel = String::with_capacity(pascal.len());
+    // Lower the leading run of upper case characters, e.g. `PSP22` to `psp22`.
+    for c in chars.by_ref() {
+        if c.is_uppercase() {
+            camel.extend(c.to_lowercase());
+            continue
+        }
+        camel.push(c);
+        break
+    }
+    camel.extend(chars);
+    camel
+}
+
+/// Converts the given identifier to pascal case, e.g. `total_supply` to `TotalSupply`.
+fn pascal_case(ident: &str) -> String {
+    ident
+        .split(['_', ':', '-'])
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Appends an underscore to identifiers that are reserved words in TypeScript.
+fn escape_ident(ident: String) -> String {
+    const RESERVED: &[&str] = &[
+        "break",
+        "case",
+        "catch",
+        "class",
+        "const",
+        "continue",
+        "debugger",
+        "default",
+        "delete",
+        "do",
+        "else",
+        "enum",
+        "export",
+        "extends",
+        "false",
+        "finally",
+        "for",
+        "function",
+        "if",
+        "import",
+        "in",
+        "instanceof",
+        "new",
+        "null",
+        "return",
+        "super",
+        "switch",
+        "this",
+        "throw",
+        "true",
+        "try",
+        "typeof",
+        "var",
+        "void",
+        "while",
+        "with",
+    ];
+    if RESERVED.contains(&ident.as_str()) {
+        format!("{}_", ident)
+    } else {
+        ident
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ink_prelude::string::String;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_json::{
+    Map,
+    Value,
+};
+
+/// User defined metadata of an ink! smart contract.
+///
+/// Contracts use this to embed arbitrary team specific data into their metadata,
+/// e.g. the hash of an audit report or hints for user interfaces, without having
+/// to extend the metadata format itself. The data is required to be a JSON object
+/// and is neither interpreted nor validated any further. Binary data such as
+/// SCALE encoded values can be embedded as hex encoded strings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct UserMetadata(Map<String, Value>);
+
+impl UserMetadata {
+    /// Creates new user defined metadata from the given JSON object.
+    pub fn new(object: Map<String, Value>) -> Self {
+        Self(object)
+    }
+
+    /// Parses user defined metadata from the given JSON string.
+    ///
+    /// # Errors
+    ///
+    /// If the string is not valid JSON or does not represent a JSON object.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json).map(Self)
+    }
+
+    /// Returns the user defined metadata as JSON object.
+    pub fn as_map(&self) -> &Map<String, Value> {
+        &self.0
+    }
+}
+
+impl From<Map<String, Value>> for UserMetadata {
+    fn from(object: Map<String, Value>) -> Self {
+        Self::new(object)
+    }
+}
@@ -0,0 +1,37 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// An error emitted by the ink! language itself instead of the called ink! message.
+///
+/// # Note
+///
+/// The encoding of this type is part of the ink! calling ABI. New variants
+/// may be added, but existing variants must keep their indices.
+#[non_exhaustive]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum LangError {
+    /// Failed to read the execution input for the dispatchable, e.g. since the
+    /// selector is unknown or the parameters could not be decoded.
+    CouldNotReadInput = 1u32,
+}
+
+/// The envelope in which every ink! message returns its output to the caller.
+///
+/// The outer `Result` tells apart errors of the ink! language from the
+/// output of the ink! message, which is always wrapped in `Ok`. Therefore
+/// an ink! message returning `Result<T, E>` returns `Ok(Err(error))` upon
+/// failure, with the revert flag set.
+pub type MessageResult<T> = ::core::result::Result<T, LangError>;
@@ -25,6 +25,7 @@
 
 mod key;
 mod key_ptr;
+mod lang_error;
 
 #[cfg(test)]
 mod tests;
@@ -32,4 +33,8 @@ mod tests;
 pub use self::{
     key::Key,
     key_ptr::KeyPtr,
+    lang_error::{
+        LangError,
+        MessageResult,
+    },
 };
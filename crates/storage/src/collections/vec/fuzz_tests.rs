@@ -18,6 +18,10 @@
 use super::Vec as StorageVec;
 use crate::{
     test_utils::FuzzCollection,
+    testing::{
+        check_vec_against_model,
+        VecOp,
+    },
     traits::{
         KeyPtr,
         PackedLayout,
@@ -96,9 +100,6 @@ fn fuzz_binary_search(mut std_vec: Vec<i32>) {
 
     for x in original_std_vec {
         // when
-        let index = std_vec
-            .binary_search(&x)
-            .expect("`x` must be found in `Vec`") as u32;
         let ink_index = ink_vec
             .binary_search(&x)
             .expect("`x` must be found in `StorageVec`");
@@ -107,8 +108,9 @@ fn fuzz_binary_search(mut std_vec: Vec<i32>) {
             .expect("`x` must be found in `StorageVec`");
 
         // then
-        assert_eq!(index, ink_index);
-        assert_eq!(index, ink_index_by);
+        // Any of the matching elements may be found if `x` occurs multiple times.
+        assert_eq!(std_vec[ink_index as usize], x);
+        assert_eq!(std_vec[ink_index_by as usize], x);
     }
 }
 
@@ -153,15 +155,13 @@ fn fuzz_binary_search_by_key(mut std_vec: Vec<(i32, i32)>) {
 
     for (_x, y) in original_std_vec {
         // when
-        let std_index = std_vec
-            .binary_search_by_key(&y, |&(_a, b)| b)
-            .expect("`y` must be found in `Vec`") as u32;
         let ink_index = ink_vec
             .binary_search_by_key(&y, |&(_a, b)| b)
             .expect("`y` must be found in `StorageVec`");
 
         // then
-        assert_eq!(std_index, ink_index);
+        // Any of the matching elements may be found if `y` occurs multiple times.
+        assert_eq!(std_vec[ink_index as usize].1, y);
     }
 }
 #[quickcheck]
@@ -189,3 +189,8 @@ fn fuzz_binary_search_by_key_nonexistent(std_vec: Vec<(i32, i32)>) {
     // then
     assert_eq!(std_err_index, ink_err_index);
 }
+
+#[quickcheck]
+fn fuzz_vec_behaves_like_std_vec(ops: Vec<VecOp<u32>>) {
+    check_vec_against_model(&ops)
+}
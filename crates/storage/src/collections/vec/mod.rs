@@ -462,9 +462,7 @@ where
     ///
     /// This operation does not preserve ordering but is constant time.
     pub fn swap_remove(&mut self, n: u32) -> Option<T> {
-        if self.is_empty() {
-            return None
-        }
+        let n = self.within_bounds(n)?;
         self.elems.swap(n, self.len() - 1);
         self.pop()
     }
@@ -480,9 +478,7 @@ where
     /// no need to return the removed element since it avoids a contract storage
     /// read for some use cases.
     pub fn swap_remove_drop(&mut self, n: u32) -> Option<()> {
-        if self.is_empty() {
-            return None
-        }
+        let n = self.within_bounds(n)?;
         self.elems.put(n, None);
         let last_index = self.len() - 1;
         let last = self.elems.put_get(last_index, None);
@@ -359,6 +359,16 @@ fn swap_remove_drop_works() {
     assert_eq_slice(&vec, &[]);
 }
 
+#[test]
+fn swap_remove_out_of_bounds_does_not_mutate() {
+    let mut vec = vec_from_slice(&[b'a', b'b']);
+
+    assert_eq!(vec.swap_remove(2), None);
+    assert_eq_slice(&vec, &[b'a', b'b']);
+    assert_eq!(vec.swap_remove_drop(2), None);
+    assert_eq_slice(&vec, &[b'a', b'b']);
+}
+
 #[test]
 fn spread_layout_push_pull_works() -> ink_env::Result<()> {
     ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
@@ -146,14 +146,24 @@ where
 #[cfg(feature = "std")]
 const _: () = {
     use crate::traits::StorageLayout;
-    use ink_metadata::layout::Layout;
+    use ink_metadata::layout::{
+        Layout,
+        LayoutKey,
+        RootLayout,
+    };
 
     impl<T> StorageLayout for LazyCell<T>
     where
         T: StorageLayout + SpreadLayout,
     {
         fn layout(key_ptr: &mut KeyPtr) -> Layout {
-            <T as StorageLayout>::layout(key_ptr)
+            // Note: The lazy cell loads its value starting from the key that the
+            //       key pointer is going to yield next.
+            let root_key = LayoutKey::from(key_ptr.clone().advance_by(0));
+            Layout::Root(RootLayout::new(
+                root_key,
+                <T as StorageLayout>::layout(key_ptr),
+            ))
         }
     }
 };
@@ -218,6 +218,7 @@ const _: () = {
         CellLayout,
         Layout,
         LayoutKey,
+        RootLayout,
     };
 
     impl<K, V> StorageLayout for Mapping<K, V>
@@ -226,9 +227,13 @@ const _: () = {
         V: scale_info::TypeInfo + 'static,
     {
         fn layout(key_ptr: &mut KeyPtr) -> Layout {
-            Layout::Cell(CellLayout::new::<Self>(LayoutKey::from(
-                key_ptr.advance_by(1),
-            )))
+            // Note: The values are stored packed under the hash of the encoded
+            //       root key and their respective key, see `Mapping::storage_key`.
+            let root_key = *key_ptr.advance_by(1);
+            Layout::Root(RootLayout::new(
+                root_key,
+                CellLayout::new::<V>(LayoutKey::from(root_key)),
+            ))
         }
     }
 };
@@ -311,4 +316,10 @@ mod tests {
         })
         .unwrap()
     }
+
+    #[cfg(feature = "ink-fuzz-tests")]
+    #[quickcheck]
+    fn fuzz_mapping_behaves_like_btree_map(ops: Vec<crate::testing::MappingOp<u8, u32>>) {
+        crate::testing::check_mapping_against_model(&ops);
+    }
 }
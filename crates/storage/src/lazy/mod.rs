@@ -33,6 +33,9 @@ mod lazy_array;
 mod lazy_cell;
 mod lazy_imap;
 
+#[cfg(all(test, feature = "ink-fuzz-tests"))]
+mod fuzz_tests;
+
 #[doc(inline)]
 pub use self::lazy_array::LazyArray;
 use self::{
@@ -81,7 +84,7 @@ const _: () = {
         T: StorageLayout + SpreadLayout,
     {
         fn layout(key_ptr: &mut KeyPtr) -> Layout {
-            <T as StorageLayout>::layout(key_ptr)
+            <LazyCell<T> as StorageLayout>::layout(key_ptr)
         }
     }
 };
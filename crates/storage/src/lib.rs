@@ -58,6 +58,9 @@ pub(crate) mod lazy;
 
 mod pack;
 
+#[cfg(feature = "std")]
+pub mod testing;
+
 #[cfg(test)]
 mod hashmap_entry_api_tests;
 
@@ -0,0 +1,391 @@
+// Copyright 2018-2022 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Model-based testing of the storage collections.
+//!
+//! A sequence of operations is applied to a storage collection as well as to a
+//! model from the standard library, e.g. a `BTreeMap` for a [`Mapping`] or a `Vec`
+//! for the lazy storage `Vec`, and the results are compared after every operation. Together with a property testing
+//! framework this verifies round-trips and invariants of the collections for
+//! arbitrary sequences of operations.
+//!
+//! With the `quickcheck` crate feature the operations implement
+//! `quickcheck::Arbitrary`, so that they can be generated directly:
+//!
+//! ```ignore
+//! #[quickcheck]
+//! fn mapping_behaves_like_btree_map(ops: Vec<MappingOp<u8, u32>>) {
+//!     ink_storage::testing::check_mapping_against_model(&ops);
+//! }
+//! ```
+
+use crate::{
+    traits::{
+        pull_spread_root,
+        push_spread_root,
+        PackedLayout,
+        SpreadLayout,
+    },
+    Lazy,
+    Mapping,
+    Vec as StorageVec,
+};
+use core::fmt::Debug;
+use ink_primitives::Key;
+use std::collections::BTreeMap;
+
+/// An operation on a [`Mapping`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MappingOp<K, V> {
+    /// Inserts the value under the key.
+    Insert(K, V),
+    /// Reads the value under the key.
+    Get(K),
+    /// Removes the value under the key.
+    Remove(K),
+}
+
+#[cfg(feature = "quickcheck")]
+impl<K, V> quickcheck::Arbitrary for MappingOp<K, V>
+where
+    K: quickcheck::Arbitrary,
+    V: quickcheck::Arbitrary,
+{
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        match u8::arbitrary(g) % 3 {
+            0 => Self::Insert(K::arbitrary(g), V::arbitrary(g)),
+            1 => Self::Get(K::arbitrary(g)),
+            _ => Self::Remove(K::arbitrary(g)),
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        match self.clone() {
+            Self::Insert(key, value) => {
+                let shrunk_values = value
+                    .shrink()
+                    .map(move |value| Self::Insert(key.clone(), value));
+                Box::new(shrunk_values)
+            }
+            Self::Get(key) => Box::new(key.shrink().map(Self::Get)),
+            Self::Remove(key) => Box::new(key.shrink().map(Self::Remove)),
+        }
+    }
+}
+
+/// Applies `ops` to a [`Mapping`] and to a `BTreeMap` and asserts that both agree.
+///
+/// The operations are applied in a fresh off-chain environment. After every
+/// operation all entries of the model are read back from the contract storage.
+///
+/// # Panics
+///
+/// If the `Mapping` and the model disagree.
+pub fn check_mapping_against_model<K, V>(ops: &[MappingOp<K, V>])
+where
+    K: PackedLayout + Ord + Clone + Debug,
+    V: PackedLayout + scale::EncodeLike + Clone + PartialEq + Debug,
+{
+    ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+        let mut mapping = Mapping::<K, V>::default();
+        let mut model = BTreeMap::new();
+        for (index, op) in ops.iter().enumerate() {
+            match op {
+                MappingOp::Insert(key, value) => {
+                    mapping.insert(key, value);
+                    model.insert(key.clone(), value.clone());
+                }
+                MappingOp::Get(key) => {
+                    assert_eq!(
+                        mapping.get(key).as_ref(),
+                        model.get(key),
+                        "`get` of {:?} disagrees after operation {}",
+                        key,
+                        index
+                    );
+                }
+                MappingOp::Remove(key) => {
+                    mapping.remove(key);
+                    model.remove(key);
+                }
+            }
+            for (key, value) in &model {
+                assert_eq!(
+                    mapping.get(key).as_ref(),
+                    Some(value),
+                    "entry {:?} does not round-trip after operation {}",
+                    key,
+                    index
+                );
+            }
+        }
+        Ok(())
+    })
+    .unwrap()
+}
+
+/// An operation on a lazy storage value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LazyOp<T> {
+    /// Sets the value.
+    Set(T),
+    /// Reads the value.
+    Get,
+    /// Pushes the value to the contract storage and pulls it again.
+    Sync,
+}
+
+#[cfg(feature = "quickcheck")]
+impl<T> quickcheck::Arbitrary for LazyOp<T>
+where
+    T: quickcheck::Arbitrary,
+{
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        match u8::arbitrary(g) % 3 {
+            0 => Self::Set(T::arbitrary(g)),
+            1 => Self::Get,
+            _ => Self::Sync,
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        match self {
+            Self::Set(value) => Box::new(value.shrink().map(Self::Set)),
+            Self::Get | Self::Sync => quickcheck::empty_shrinker(),
+        }
+    }
+}
+
+/// Applies `ops` to a lazy storage value and to a plain value, both starting out
+/// as `initial`, and asserts that both agree.
+///
+/// The operations are applied in a fresh off-chain environment.
+///
+/// # Panics
+///
+/// If the lazy storage value and the model disagree.
+pub fn check_lazy_against_model<T>(initial: T, ops: &[LazyOp<T>])
+where
+    T: SpreadLayout + Clone + PartialEq + Debug,
+{
+    ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+        let root_key = Key::from([0x42; 32]);
+        let mut lazy = Lazy::new(initial.clone());
+        let mut model = initial;
+        for (index, op) in ops.iter().enumerate() {
+            match op {
+                LazyOp::Set(value) => {
+                    Lazy::set(&mut lazy, value.clone());
+                    model = value.clone();
+                }
+                LazyOp::Get => {
+                    assert_eq!(
+                        Lazy::get(&lazy),
+                        &model,
+                        "`get` disagrees after operation {}",
+                        index
+                    );
+                }
+                LazyOp::Sync => {
+                    push_spread_root(&lazy, &root_key);
+                    let pulled = pull_spread_root(&root_key);
+                    // Dropping a value pulled from the contract storage clears its
+                    // storage, so the replaced value must not be dropped.
+                    core::mem::forget(core::mem::replace(&mut lazy, pulled));
+                }
+            }
+        }
+        assert_eq!(Lazy::get(&lazy), &model);
+        Ok(())
+    })
+    .unwrap()
+}
+
+/// An operation on a lazy storage `Vec`.
+///
+/// Indices are taken modulo the length of the model plus one, so that most
+/// operations hit an element while out of bounds accesses are still covered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VecOp<T> {
+    /// Appends the value.
+    Push(T),
+    /// Removes the last value.
+    Pop,
+    /// Replaces the value at the index.
+    Set(u32, T),
+    /// Reads the value at the index.
+    Get(u32),
+    /// Removes the value at the index and replaces it with the last value.
+    SwapRemove(u32),
+}
+
+#[cfg(feature = "quickcheck")]
+impl<T> quickcheck::Arbitrary for VecOp<T>
+where
+    T: quickcheck::Arbitrary,
+{
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        match u8::arbitrary(g) % 5 {
+            0 => Self::Push(T::arbitrary(g)),
+            1 => Self::Pop,
+            2 => Self::Set(u32::arbitrary(g), T::arbitrary(g)),
+            3 => Self::Get(u32::arbitrary(g)),
+            _ => Self::SwapRemove(u32::arbitrary(g)),
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        match self.clone() {
+            Self::Push(value) => Box::new(value.shrink().map(Self::Push)),
+            Self::Pop => quickcheck::empty_shrinker(),
+            Self::Set(at, value) => {
+                Box::new(value.shrink().map(move |value| Self::Set(at, value)))
+            }
+            Self::Get(at) => Box::new(at.shrink().map(Self::Get)),
+            Self::SwapRemove(at) => Box::new(at.shrink().map(Self::SwapRemove)),
+        }
+    }
+}
+
+/// Applies `ops` to a lazy storage `Vec` and to a `Vec` and asserts that both agree.
+///
+/// The operations are applied in a fresh off-chain environment. Afterwards the
+/// storage `Vec` is pushed to and pulled from the contract storage.
+///
+/// # Panics
+///
+/// If the storage `Vec` and the model disagree.
+pub fn check_vec_against_model<T>(ops: &[VecOp<T>])
+where
+    T: PackedLayout + Clone + PartialEq + Debug,
+{
+    ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+        let mut storage_vec = StorageVec::<T>::new();
+        let mut model = Vec::<T>::new();
+        let index = |at: u32, len: usize| at % (len as u32 + 1);
+        for (op_index, op) in ops.iter().enumerate() {
+            match op {
+                VecOp::Push(value) => {
+                    storage_vec.push(value.clone());
+                    model.push(value.clone());
+                }
+                VecOp::Pop => {
+                    assert_eq!(
+                        storage_vec.pop(),
+                        model.pop(),
+                        "`pop` disagrees after operation {}",
+                        op_index
+                    );
+                }
+                VecOp::Set(at, value) => {
+                    let at = index(*at, model.len());
+                    let result = storage_vec.set(at, value.clone());
+                    match model.get_mut(at as usize) {
+                        Some(slot) => {
+                            assert!(result.is_ok());
+                            *slot = value.clone();
+                        }
+                        None => assert!(result.is_err()),
+                    }
+                }
+                VecOp::Get(at) => {
+                    let at = index(*at, model.len());
+                    assert_eq!(
+                        storage_vec.get(at),
+                        model.get(at as usize),
+                        "`get` of {} disagrees after operation {}",
+                        at,
+                        op_index
+                    );
+                }
+                VecOp::SwapRemove(at) => {
+                    let at = index(*at, model.len());
+                    let expected = if (at as usize) < model.len() {
+                        Some(model.swap_remove(at as usize))
+                    } else {
+                        None
+                    };
+                    assert_eq!(
+                        storage_vec.swap_remove(at),
+                        expected,
+                        "`swap_remove` of {} disagrees after operation {}",
+                        at,
+                        op_index
+                    );
+                }
+            }
+            assert_eq!(
+                storage_vec.len() as usize,
+                model.len(),
+                "length disagrees after operation {}",
+                op_index
+            );
+        }
+
+        let root_key = Key::from([0x42; 32]);
+        push_spread_root(&storage_vec, &root_key);
+        let pulled: StorageVec<T> = pull_spread_root(&root_key);
+        assert_eq!(pulled.iter().cloned().collect::<Vec<_>>(), model);
+        Ok(())
+    })
+    .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mapping_agrees_with_model() {
+        check_mapping_against_model(&[
+            MappingOp::Insert(1u8, 10u32),
+            MappingOp::Insert(2, 20),
+            MappingOp::Get(1),
+            MappingOp::Insert(1, 11),
+            MappingOp::Remove(2),
+            MappingOp::Get(2),
+            MappingOp::Remove(3),
+            MappingOp::Get(1),
+        ]);
+    }
+
+    #[test]
+    fn lazy_agrees_with_model() {
+        check_lazy_against_model(
+            0u32,
+            &[
+                LazyOp::Sync,
+                LazyOp::Sync,
+                LazyOp::Get,
+                LazyOp::Set(42),
+                LazyOp::Sync,
+                LazyOp::Get,
+            ],
+        );
+    }
+
+    #[test]
+    fn vec_agrees_with_model() {
+        check_vec_against_model(&[
+            VecOp::Push(1u32),
+            VecOp::SwapRemove(1),
+            VecOp::Push(2),
+            VecOp::Set(0, 3),
+            VecOp::Get(1),
+            VecOp::SwapRemove(0),
+            VecOp::Pop,
+            VecOp::Pop,
+        ]);
+    }
+}
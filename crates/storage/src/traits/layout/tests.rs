@@ -11,3 +11,63 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
+
+use super::StorageLayout;
+use crate::{
+    traits::KeyPtr,
+    Lazy,
+    Mapping,
+};
+use ink_metadata::layout::{
+    CellLayout,
+    FieldLayout,
+    Layout,
+    LayoutKey,
+    RootLayout,
+    StructLayout,
+};
+use ink_primitives::Key;
+
+#[test]
+fn mapping_layout_is_rooted_at_its_key() {
+    let mut key_ptr = KeyPtr::from(Key::from([0x01; 32]));
+    let _ = <u8 as StorageLayout>::layout(&mut key_ptr);
+    let layout = <Mapping<u8, i32> as StorageLayout>::layout(&mut key_ptr);
+    let mut root_key = Key::from([0x01; 32]);
+    root_key += 1;
+    assert_eq!(
+        layout,
+        Layout::Root(RootLayout::new(
+            root_key,
+            CellLayout::new::<i32>(LayoutKey::from(root_key)),
+        ))
+    );
+}
+
+#[test]
+fn lazy_layout_is_rooted_at_its_key() {
+    let mut key_ptr = KeyPtr::from(Key::from([0x01; 32]));
+    let _ = <u8 as StorageLayout>::layout(&mut key_ptr);
+    let layout = <Lazy<(i32, bool)> as StorageLayout>::layout(&mut key_ptr);
+    let mut root_key = Key::from([0x01; 32]);
+    root_key += 1;
+    let mut second_key = root_key;
+    second_key += 1;
+    assert_eq!(
+        layout,
+        Layout::Root(RootLayout::new(
+            root_key,
+            StructLayout::new([
+                FieldLayout::new(None, CellLayout::new::<i32>(LayoutKey::from(root_key))),
+                FieldLayout::new(
+                    None,
+                    CellLayout::new::<bool>(LayoutKey::from(second_key))
+                ),
+            ]),
+        ))
+    );
+    // The key pointer continues after the lazily loaded value.
+    let mut next_key = second_key;
+    next_key += 1;
+    assert_eq!(key_ptr.advance_by(1), &next_key);
+}
@@ -109,8 +109,10 @@ pub mod give_me {
             // Push the new execution context which sets Eve as caller and
             // the `mock_transferred_value` as the value which the contract
             // will see as transferred to it.
-            set_sender(accounts.eve);
-            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(10);
+            ink_env::test::ExecutionContext::<ink_env::DefaultEnvironment>::build()
+                .caller(accounts.eve)
+                .value(10)
+                .apply();
 
             // then
             // there must be no panic
@@ -128,8 +130,10 @@ pub mod give_me {
             // Push the new execution context which sets Eve as caller and
             // the `mock_transferred_value` as the value which the contract
             // will see as transferred to it.
-            set_sender(accounts.eve);
-            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(13);
+            ink_env::test::ExecutionContext::<ink_env::DefaultEnvironment>::build()
+                .caller(accounts.eve)
+                .value(13)
+                .apply();
 
             // then
             give_me.was_it_ten();
@@ -150,7 +154,9 @@ pub mod give_me {
         }
 
         fn set_sender(sender: AccountId) {
-            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(sender);
+            ink_env::test::ExecutionContext::<ink_env::DefaultEnvironment>::build()
+                .caller(sender)
+                .apply();
         }
 
         fn default_accounts(
@@ -138,4 +138,41 @@ mod delegator {
             }
         }
     }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use accumulator::Accumulator;
+        use adder::Adder;
+        use ink_lang as ink;
+        use ink_lang::fixtures::Fixtures;
+        use subber::Subber;
+
+        #[ink::test]
+        fn change_delegates_to_adder_and_subber() {
+            // given
+            let mut fixtures = Fixtures::<ink_env::DefaultEnvironment>::new();
+            let accumulator_code_hash = fixtures.register::<Accumulator>();
+            let adder_code_hash = fixtures.register::<Adder>();
+            let subber_code_hash = fixtures.register::<Subber>();
+            let mut delegator =
+                fixtures
+                    .deploy::<Delegator>()
+                    .instantiate(DelegatorRef::new(
+                        10,
+                        1,
+                        accumulator_code_hash,
+                        adder_code_hash,
+                        subber_code_hash,
+                    ));
+
+            // when
+            delegator.change(5);
+            delegator.switch();
+            delegator.change(3);
+
+            // then
+            assert_eq!(delegator.get(), 12);
+        }
+    }
 }
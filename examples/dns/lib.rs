@@ -187,7 +187,9 @@ mod dns {
         }
 
         fn set_next_caller(caller: AccountId) {
-            ink_env::test::set_caller::<Environment>(caller);
+            ink_env::test::ExecutionContext::<Environment>::build()
+                .caller(caller)
+                .apply();
         }
 
         #[ink::test]
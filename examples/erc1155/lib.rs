@@ -594,7 +594,9 @@ mod erc1155 {
         use ink_lang as ink;
 
         fn set_sender(sender: AccountId) {
-            ink_env::test::set_caller::<Environment>(sender);
+            ink_env::test::ExecutionContext::<Environment>::build()
+                .caller(sender)
+                .apply();
         }
 
         fn default_accounts() -> ink_env::test::DefaultAccounts<Environment> {
@@ -380,8 +380,10 @@ mod erc20 {
 
             // Set the contract as callee and Bob as caller.
             let contract = ink_env::account_id::<ink_env::DefaultEnvironment>();
-            ink_env::test::set_callee::<ink_env::DefaultEnvironment>(contract);
-            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(accounts.bob);
+            ink_env::test::ExecutionContext::<ink_env::DefaultEnvironment>::build()
+                .caller(accounts.bob)
+                .callee(contract)
+                .apply();
 
             // Bob fails to transfers 10 tokens to Eve.
             assert_eq!(
@@ -425,8 +427,10 @@ mod erc20 {
 
             // Set the contract as callee and Bob as caller.
             let contract = ink_env::account_id::<ink_env::DefaultEnvironment>();
-            ink_env::test::set_callee::<ink_env::DefaultEnvironment>(contract);
-            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(accounts.bob);
+            ink_env::test::ExecutionContext::<ink_env::DefaultEnvironment>::build()
+                .caller(accounts.bob)
+                .callee(contract)
+                .apply();
 
             // Bob transfers tokens from Alice to Eve.
             assert_eq!(
@@ -467,8 +471,10 @@ mod erc20 {
 
             // Get contract address.
             let callee = ink_env::account_id::<ink_env::DefaultEnvironment>();
-            ink_env::test::set_callee::<ink_env::DefaultEnvironment>(callee);
-            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(accounts.bob);
+            ink_env::test::ExecutionContext::<ink_env::DefaultEnvironment>::build()
+                .caller(accounts.bob)
+                .callee(callee)
+                .apply();
 
             // Bob tries to transfer tokens from Alice to Eve.
             let emitted_events_before = ink_env::test::recorded_events().count();
@@ -638,7 +638,9 @@ mod erc721 {
         }
 
         fn set_caller(sender: AccountId) {
-            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(sender);
+            ink_env::test::ExecutionContext::<ink_env::DefaultEnvironment>::build()
+                .caller(sender)
+                .apply();
         }
     }
 }
@@ -67,7 +67,6 @@ mod multisig {
     use ink_env::call::{
         build_call,
         Call,
-        ExecutionInput,
     };
     use ink_prelude::vec::Vec;
     use ink_storage::{
@@ -78,7 +77,6 @@ mod multisig {
         },
         Mapping,
     };
-    use scale::Output;
 
     /// Tune this to your liking but be wary that allowing too many owners will not perform well.
     const MAX_OWNERS: u32 = 50;
@@ -87,17 +85,6 @@ mod multisig {
     const WRONG_TRANSACTION_ID: &str =
         "The user specified an invalid transaction id. Abort.";
 
-    /// A wrapper that allows us to encode a blob of bytes.
-    ///
-    /// We use this to pass the set of untyped (bytes) parameters to the `CallBuilder`.
-    struct CallInput<'a>(&'a [u8]);
-
-    impl<'a> scale::Encode for CallInput<'a> {
-        fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
-            dest.write(self.0);
-        }
-    }
-
     /// Indicates whether a transaction is already confirmed or needs further confirmations.
     #[derive(scale::Encode, scale::Decode, Clone, Copy, SpreadLayout, PackedLayout)]
     #[cfg_attr(
@@ -546,9 +533,7 @@ mod multisig {
                         .gas_limit(t.gas_limit)
                         .transferred_value(t.transferred_value),
                 )
-                .exec_input(
-                    ExecutionInput::new(t.selector.into()).push_arg(CallInput(&t.input)),
-                )
+                .exec_input_raw(t.selector.into(), &t.input)
                 .returns::<()>()
                 .fire()
                 .map_err(|_| Error::TransactionFailed);
@@ -578,9 +563,7 @@ mod multisig {
                         .gas_limit(t.gas_limit)
                         .transferred_value(t.transferred_value),
                 )
-                .exec_input(
-                    ExecutionInput::new(t.selector.into()).push_arg(CallInput(&t.input)),
-                )
+                .exec_input_raw(t.selector.into(), &t.input)
                 .returns::<Vec<u8>>()
                 .fire()
                 .map_err(|_| Error::TransactionFailed);
@@ -739,7 +722,9 @@ mod multisig {
         }
 
         fn set_caller(sender: AccountId) {
-            ink_env::test::set_caller::<Environment>(sender);
+            ink_env::test::ExecutionContext::<Environment>::build()
+                .caller(sender)
+                .apply();
         }
 
         fn set_from_wallet() {
@@ -764,7 +749,9 @@ mod multisig {
         fn build_contract() -> Multisig {
             // Set the contract's address as `WALLET`.
             let callee: AccountId = AccountId::from(WALLET);
-            ink_env::test::set_callee::<ink_env::DefaultEnvironment>(callee);
+            ink_env::test::ExecutionContext::<ink_env::DefaultEnvironment>::build()
+                .callee(callee)
+                .apply();
 
             let accounts = default_accounts();
             let owners = vec![accounts.alice, accounts.bob, accounts.eve];
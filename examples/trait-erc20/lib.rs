@@ -551,7 +551,9 @@ mod erc20 {
         }
 
         fn set_caller(sender: AccountId) {
-            ink_env::test::set_caller::<Environment>(sender);
+            ink_env::test::ExecutionContext::<Environment>::build()
+                .caller(sender)
+                .apply();
         }
 
         /// For calculating the event topic hash.
@@ -124,25 +124,10 @@ pub mod upgradeable_contract {
         pub fn forward(&self) -> u32 {
             ink_env::call::build_call::<ink_env::DefaultEnvironment>()
                 .call_type(DelegateCall::new().code_hash(self.proxy.forward_to))
-                .call_flags(
-                    ink_env::CallFlags::default()
-                        // We don't plan to use the input data after the delegated call, so the 
-                        // input data can be forwarded to delegated contract to reduce the gas usage.
-                        .set_forward_input(true)
-                        // We don't plan to return back to that contract after execution, so we 
-                        // marked delegated call as "tail", to end the execution of the contract.
-                        .set_tail_call(true),
-                )
-                .fire()
-                .unwrap_or_else(|err| {
-                    panic!(
-                        "delegate call to {:?} failed due to {:?}",
-                        self.proxy.forward_to, err
-                    )
-                });
-            unreachable!(
-                "the forwarded call will never return since `tail_call` was set"
-            );
+                // We don't plan to use the input data after the delegated call and don't
+                // plan to return back to this contract after its execution, so the input
+                // data is forwarded to the delegated contract within a tail call.
+                .forward_input()
         }
     }
 }
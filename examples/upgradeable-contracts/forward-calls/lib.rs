@@ -78,21 +78,7 @@ pub mod proxy {
                         .transferred_value(self.env().transferred_value())
                         .gas_limit(0),
                 )
-                .call_flags(
-                    ink_env::CallFlags::default()
-                        .set_forward_input(true)
-                        .set_tail_call(true),
-                )
-                .fire()
-                .unwrap_or_else(|err| {
-                    panic!(
-                        "cross-contract call to {:?} failed due to {:?}",
-                        self.forward_to, err
-                    )
-                });
-            unreachable!(
-                "the forwarded call will never return since `tail_call` was set"
-            );
+                .forward_input()
         }
     }
 }